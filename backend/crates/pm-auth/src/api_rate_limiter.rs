@@ -0,0 +1,71 @@
+use crate::{AuthError, RateLimitConfig, Result as AuthErrorResult};
+
+use std::net::IpAddr;
+use std::num::NonZeroU32;
+use std::panic::Location;
+use std::time::Duration;
+
+use error_location::ErrorLocation;
+use governor::{DefaultKeyedRateLimiter, Quota, RateLimiter, clock::Clock};
+
+/// Buckets tracked per limiter before refilled ones are dropped
+const MAX_TRACKED_KEYS: usize = 10_000;
+
+/// REST API rate limiter with one token bucket per user and one per client IP
+pub struct ApiRateLimiter {
+    per_user: DefaultKeyedRateLimiter<String>,
+    per_ip: DefaultKeyedRateLimiter<IpAddr>,
+}
+
+impl ApiRateLimiter {
+    pub fn new(config: &RateLimitConfig) -> Self {
+        Self {
+            per_user: RateLimiter::keyed(quota(config.api_requests_per_user, config.window_secs)),
+            per_ip: RateLimiter::keyed(quota(config.api_requests_per_ip, config.window_secs)),
+        }
+    }
+
+    /// Take a token from the user's bucket when the caller is identified,
+    /// and from the IP's bucket when the client address is known. The error
+    /// says how long to wait.
+    #[track_caller]
+    pub fn check(&self, user_id: Option<&str>, ip: Option<IpAddr>) -> AuthErrorResult<()> {
+        if let Some(user_id) = user_id {
+            if self.per_user.len() > MAX_TRACKED_KEYS {
+                self.per_user.retain_recent();
+            }
+            if let Err(not_until) = self.per_user.check_key(&user_id.to_string()) {
+                let wait = not_until.wait_time_from(self.per_user.clock().now());
+                return Err(too_many_requests("user", wait));
+            }
+        }
+
+        if let Some(ip) = ip {
+            if self.per_ip.len() > MAX_TRACKED_KEYS {
+                self.per_ip.retain_recent();
+            }
+            if let Err(not_until) = self.per_ip.check_key(&ip) {
+                let wait = not_until.wait_time_from(self.per_ip.clock().now());
+                return Err(too_many_requests("client IP", wait));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn quota(max_requests: u32, window_secs: u64) -> Quota {
+    Quota::with_period(Duration::from_secs(window_secs) / max_requests.max(1))
+        .unwrap()
+        .allow_burst(NonZeroU32::new(max_requests.max(1)).unwrap())
+}
+
+#[track_caller]
+fn too_many_requests(scope: &str, wait: Duration) -> AuthError {
+    AuthError::TooManyRequests {
+        scope: scope.to_string(),
+        // Round up so a client that waits this long is never refused again
+        retry_after_secs: wait.as_secs() + u64::from(wait.subsec_nanos() > 0),
+        location: ErrorLocation::from(Location::caller()),
+    }
+}
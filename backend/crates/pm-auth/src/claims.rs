@@ -17,6 +17,9 @@ pub struct Claims {
     /// Optional: User roles for authorization                                                                                                                                   
     #[serde(default)]
     pub roles: Vec<String>,
+    /// Optional: Tenant the token was issued for (multi-tenant hosting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<String>,
 }
 
 impl Claims {
@@ -32,6 +32,13 @@ pub enum AuthError {
         location: ErrorLocation,
     },
 
+    #[error("Too many requests for this {scope}: retry after {retry_after_secs}s {location}")]
+    TooManyRequests {
+        scope: String,
+        retry_after_secs: u64,
+        location: ErrorLocation,
+    },
+
     #[error("Invalid claim '{claim}': {message} {location}")]
     InvalidClaim {
         claim: String,
@@ -57,7 +64,7 @@ impl AuthError {
             Self::MissingHeader { .. } => "MISSING_AUTH_HEADER",
             Self::InvalidScheme { .. } => "INVALID_AUTH_SCHEME",
             Self::JwtDecode { .. } => "JWT_DECODE_FAILED",
-            Self::RateLimitExceeded { .. } => "RATE_LIMIT_EXCEEDED",
+            Self::RateLimitExceeded { .. } | Self::TooManyRequests { .. } => "RATE_LIMIT_EXCEEDED",
             Self::InvalidClaim { .. } => "INVALID_CLAIM",
         }
     }
@@ -1,3 +1,4 @@
+pub mod api_rate_limiter;
 pub mod claims;
 pub mod connection_rate_limiter;
 pub mod error;
@@ -6,6 +7,7 @@ pub mod jwt_validator;
 pub mod rate_limit_config;
 pub mod rate_limiter_factory;
 
+pub use api_rate_limiter::ApiRateLimiter;
 pub use claims::Claims;
 pub use connection_rate_limiter::ConnectionRateLimiter;
 pub use error::{AuthError, Result};
@@ -5,6 +5,10 @@ pub struct RateLimitConfig {
     pub max_requests: u32,
     /// Window duration in seconds                                                                                                                                               
     pub window_secs: u64,
+    /// REST requests per window for each user with a valid bearer token
+    pub api_requests_per_user: u32,
+    /// REST requests per window from each client IP
+    pub api_requests_per_ip: u32,
 }
 
 impl Default for RateLimitConfig {
@@ -12,6 +16,8 @@ impl Default for RateLimitConfig {
         Self {
             max_requests: 100, // 100 messages
             window_secs: 60,   // per minute
+            api_requests_per_user: 600,
+            api_requests_per_ip: 1200,
         }
     }
 }
@@ -1,6 +1,6 @@
-use crate::{ConnectionRateLimiter, RateLimitConfig};
+use crate::{ApiRateLimiter, ConnectionRateLimiter, RateLimitConfig};
 
-/// Factory for creating per-connection rate limiters                                                                                                                            
+/// Factory for creating per-connection and REST API rate limiters                                                                                                                            
 #[derive(Clone)]
 pub struct RateLimiterFactory {
     config: RateLimitConfig,
@@ -14,6 +14,11 @@ impl RateLimiterFactory {
     pub fn create(&self) -> ConnectionRateLimiter {
         ConnectionRateLimiter::new(self.config.clone())
     }
+
+    /// Create the limiter shared by all REST API requests
+    pub fn create_api_limiter(&self) -> ApiRateLimiter {
+        ApiRateLimiter::new(&self.config)
+    }
 }
 
 impl Default for RateLimiterFactory {
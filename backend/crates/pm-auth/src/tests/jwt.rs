@@ -18,6 +18,7 @@ fn valid_claims() -> Claims {
         exp: chrono::Utc::now().timestamp() + 3600,
         iat: chrono::Utc::now().timestamp(),
         roles: vec!["user".to_string()],
+        tenant_id: None,
     }
 }
 
@@ -1,10 +1,13 @@
-use crate::{ConnectionRateLimiter, RateLimitConfig};
+use crate::{ApiRateLimiter, AuthError, ConnectionRateLimiter, RateLimitConfig};
+
+use std::net::{IpAddr, Ipv4Addr};
 
 #[test]
 fn given_rate_limiter_when_under_limit_then_allows_requests() {
     let config = RateLimitConfig {
         max_requests: 10,
         window_secs: 1,
+        ..Default::default()
     };
     let limiter = ConnectionRateLimiter::new(config);
 
@@ -19,6 +22,7 @@ fn given_rate_limiter_when_burst_exceeds_limit_then_rejects() {
     let config = RateLimitConfig {
         max_requests: 2,
         window_secs: 1,
+        ..Default::default()
     };
     let limiter = ConnectionRateLimiter::new(config);
 
@@ -37,3 +41,48 @@ fn given_rate_limiter_when_burst_exceeds_limit_then_rejects() {
     }
     assert!(hit_limit, "Expected rate limit to be enforced");
 }
+
+#[test]
+fn given_api_limiter_when_user_exceeds_quota_then_rejects_with_retry_after() {
+    let config = RateLimitConfig {
+        window_secs: 60,
+        api_requests_per_user: 2,
+        ..Default::default()
+    };
+    let limiter = ApiRateLimiter::new(&config);
+
+    assert!(limiter.check(Some("alice"), None).is_ok());
+    assert!(limiter.check(Some("alice"), None).is_ok());
+    match limiter.check(Some("alice"), None) {
+        Err(AuthError::TooManyRequests {
+            scope,
+            retry_after_secs,
+            ..
+        }) => {
+            assert_eq!(scope, "user");
+            // One token refills every 30 seconds
+            assert!((1..=30).contains(&retry_after_secs));
+        }
+        other => panic!("Expected TooManyRequests, got {:?}", other),
+    }
+
+    // Other users have their own bucket
+    assert!(limiter.check(Some("bob"), None).is_ok());
+}
+
+#[test]
+fn given_api_limiter_when_ip_exceeds_quota_then_rejects_across_users() {
+    let config = RateLimitConfig {
+        window_secs: 60,
+        api_requests_per_ip: 1,
+        ..Default::default()
+    };
+    let limiter = ApiRateLimiter::new(&config);
+    let ip = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+    assert!(limiter.check(Some("alice"), Some(ip)).is_ok());
+    assert!(matches!(
+        limiter.check(Some("bob"), Some(ip)),
+        Err(AuthError::TooManyRequests { .. })
+    ));
+}
@@ -0,0 +1,16 @@
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+pub enum BackupCommands {
+    /// Take an online backup of the database now
+    Create,
+
+    /// List backups, newest first
+    List,
+
+    /// Restore a backup; the server shuts down and applies it on next start
+    Restore {
+        /// Backup file name, as shown by `pm backup list`
+        name: String,
+    },
+}
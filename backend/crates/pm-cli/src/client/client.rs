@@ -1,11 +1,11 @@
-use crate::{CliClientResult, ClientError};
+use crate::{CliClientResult, ClientError, ListOptions};
 
 use std::panic::Location;
 
 use error_location::ErrorLocation;
 use reqwest::{Client as ReqwestClient, Method};
 use serde::Serialize;
-use serde_json::Value;
+use serde_json::{Map, Value};
 
 /// HTTP client for the pm-server REST API
 pub struct Client {
@@ -40,6 +40,46 @@ impl Client {
         req
     }
 
+    /// GET a list endpoint. With `options.all`, follow `next_cursor` to the
+    /// last page and merge every page's `key` array into the first response.
+    async fn list(
+        &self,
+        path: &str,
+        mut params: Vec<String>,
+        key: &str,
+        options: &ListOptions,
+    ) -> CliClientResult<Value> {
+        params.extend(options.query_params());
+        let url = |params: &[String]| match params.is_empty() {
+            true => path.to_string(),
+            false => format!("{}?{}", path, params.join("&")),
+        };
+
+        let mut response = self
+            .execute(self.request(Method::GET, &url(&params)))
+            .await?;
+        if !options.all {
+            return Ok(response);
+        }
+
+        params.retain(|p| !p.starts_with("cursor="));
+        while let Some(cursor) = response["next_cursor"].as_str().map(str::to_string) {
+            let mut page_params = params.clone();
+            page_params.push(format!("cursor={}", cursor));
+            let mut page = self
+                .execute(self.request(Method::GET, &url(&page_params)))
+                .await?;
+            if let (Some(items), Some(more)) =
+                (response[key].as_array_mut(), page[key].as_array_mut())
+            {
+                items.append(more);
+            }
+            response["total"] = page["total"].take();
+            response["next_cursor"] = page["next_cursor"].take();
+        }
+        Ok(response)
+    }
+
     /// Execute request and handle errors
     async fn execute(&self, req: reqwest::RequestBuilder) -> CliClientResult<Value> {
         let response = req.send().await?;
@@ -47,38 +87,43 @@ impl Client {
         let body: Value = response.json().await?;
 
         // Check for error response
-        #[allow(clippy::collapsible_if)]
-        if !status.is_success() {
-            if let Some(error) = body.get("error") {
-                let code = error
-                    .get("code")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("UNKNOWN")
-                    .to_string();
-                let message = error
-                    .get("message")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("Unknown error")
-                    .to_string();
-                return Err(ClientError::Api {
-                    code,
-                    message,
-                    location: ErrorLocation::from(Location::caller()),
-                });
-            }
+        if !status.is_success()
+            && let Some(error) = body.get("error")
+        {
+            return Err(Self::api_error(error));
         }
 
         Ok(body)
     }
 
+    /// Map a server `{"error": {...}}` body to a client error
+    #[track_caller]
+    fn api_error(error: &Value) -> ClientError {
+        let code = error
+            .get("code")
+            .and_then(|v| v.as_str())
+            .unwrap_or("UNKNOWN")
+            .to_string();
+        let message = error
+            .get("message")
+            .and_then(|v| v.as_str())
+            .unwrap_or("Unknown error")
+            .to_string();
+        ClientError::Api {
+            code,
+            message,
+            location: ErrorLocation::from(Location::caller()),
+        }
+    }
+
     // =========================================================================
     // Project Operations
     // =========================================================================
 
-    /// List all projects
-    pub async fn list_projects(&self) -> CliClientResult<Value> {
-        let req = self.request(Method::GET, "/api/v1/projects");
-        self.execute(req).await
+    /// List projects
+    pub async fn list_projects(&self, options: &ListOptions) -> CliClientResult<Value> {
+        self.list("/api/v1/projects", Vec::new(), "projects", options)
+            .await
     }
 
     /// Get a project by ID
@@ -93,6 +138,7 @@ impl Client {
         title: &str,
         key: &str,
         description: Option<&str>,
+        template_id: Option<&str>,
     ) -> CliClientResult<Value> {
         #[derive(Serialize)]
         struct CreateRequest<'a> {
@@ -100,12 +146,15 @@ impl Client {
             key: &'a str,
             #[serde(skip_serializing_if = "Option::is_none")]
             description: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            template_id: Option<&'a str>,
         }
 
         let body = CreateRequest {
             title,
             key,
             description,
+            template_id,
         };
         let req = self.request(Method::POST, "/api/v1/projects").json(&body);
         self.execute(req).await
@@ -143,6 +192,29 @@ impl Client {
         self.execute(req).await
     }
 
+    /// Rename a project's key; the old key keeps resolving
+    pub async fn rename_project_key(
+        &self,
+        id: &str,
+        key: &str,
+        expected_version: i32,
+    ) -> CliClientResult<Value> {
+        #[derive(Serialize)]
+        struct RenameKeyRequest<'a> {
+            key: &'a str,
+            expected_version: i32,
+        }
+
+        let body = RenameKeyRequest {
+            key,
+            expected_version,
+        };
+        let req = self
+            .request(Method::PUT, &format!("/api/v1/projects/{}/key", id))
+            .json(&body);
+        self.execute(req).await
+    }
+
     /// Delete a project
     pub async fn delete_project(&self, id: &str) -> CliClientResult<Value> {
         let req = self.request(Method::DELETE, &format!("/api/v1/projects/{}", id));
@@ -154,12 +226,13 @@ impl Client {
     // =========================================================================
 
     /// List sprints in a project
-    pub async fn list_sprints(&self, project_id: &str) -> CliClientResult<Value> {
-        let req = self.request(
-            Method::GET,
-            &format!("/api/v1/projects/{}/sprints", project_id),
-        );
-        self.execute(req).await
+    pub async fn list_sprints(
+        &self,
+        project_id: &str,
+        options: &ListOptions,
+    ) -> CliClientResult<Value> {
+        let path = format!("/api/v1/projects/{}/sprints", project_id);
+        self.list(&path, Vec::new(), "sprints", options).await
     }
 
     /// Get a sprint by ID
@@ -168,6 +241,12 @@ impl Client {
         self.execute(req).await
     }
 
+    /// Get estimate totals for a sprint
+    pub async fn get_sprint_summary(&self, id: &str) -> CliClientResult<Value> {
+        let req = self.request(Method::GET, &format!("/api/v1/sprints/{}/summary", id));
+        self.execute(req).await
+    }
+
     /// Create a new sprint
     pub async fn create_sprint(
         &self,
@@ -257,6 +336,45 @@ impl Client {
         self.execute(req).await
     }
 
+    /// Context pack URL for a work item
+    fn work_item_context_url(id: &str, format: &str, max_chars: Option<usize>) -> String {
+        let mut url = format!("/api/v1/work-items/{}/context?format={}", id, format);
+        if let Some(n) = max_chars {
+            url.push_str(&format!("&max_chars={}", n));
+        }
+        url
+    }
+
+    /// Get a work item's context pack as JSON
+    pub async fn work_item_context(
+        &self,
+        id: &str,
+        max_chars: Option<usize>,
+    ) -> CliClientResult<Value> {
+        let url = Self::work_item_context_url(id, "json", max_chars);
+        let req = self.request(Method::GET, &url);
+        self.execute(req).await
+    }
+
+    /// Get a work item's context pack rendered as Markdown by the server
+    pub async fn work_item_context_markdown(
+        &self,
+        id: &str,
+        max_chars: Option<usize>,
+    ) -> CliClientResult<String> {
+        let url = Self::work_item_context_url(id, "markdown", max_chars);
+        let req = self.request(Method::GET, &url);
+
+        let response = req.send().await?;
+        if response.status().is_success() {
+            return Ok(response.text().await?);
+        }
+
+        // Errors are still JSON
+        let body: Value = response.json().await?;
+        Err(Self::api_error(body.get("error").unwrap_or(&Value::Null)))
+    }
+
     /// List work items in a project
     #[allow(clippy::too_many_arguments)]
     pub async fn list_work_items(
@@ -269,8 +387,10 @@ impl Client {
         descendants_of: Option<&str>,
         ancestors_of: Option<&str>,
         include_done: bool,
+        custom_fields: &[(String, Value)],
+        options: &ListOptions,
     ) -> CliClientResult<Value> {
-        let mut url = format!("/api/v1/projects/{}/work-items", project_id);
+        let path = format!("/api/v1/projects/{}/work-items", project_id);
 
         // Build query string
         let mut params = vec![];
@@ -295,12 +415,18 @@ impl Client {
         if include_done {
             params.push("include_done=true".to_string());
         }
-        if !params.is_empty() {
-            url.push_str(&format!("?{}", params.join("&")));
+        if !custom_fields.is_empty() {
+            let filters: Vec<String> = custom_fields
+                .iter()
+                .map(|(name, value)| match value {
+                    Value::String(s) => format!("{}:{}", name, s),
+                    other => format!("{}:{}", name, other),
+                })
+                .collect();
+            params.push(format!("custom_field={}", filters.join(",")));
         }
 
-        let req = self.request(Method::GET, &url);
-        self.execute(req).await
+        self.list(&path, params, "work_items", options).await
     }
 
     /// Create a new work item
@@ -314,6 +440,8 @@ impl Client {
         parent_id: Option<&str>,
         status: Option<&str>,
         priority: Option<&str>,
+        custom_fields: &[(String, Value)],
+        original_estimate_seconds: Option<i32>,
     ) -> CliClientResult<Value> {
         #[derive(Serialize)]
         struct CreateRequest<'a> {
@@ -328,6 +456,10 @@ impl Client {
             status: Option<&'a str>,
             #[serde(skip_serializing_if = "Option::is_none")]
             priority: Option<&'a str>,
+            #[serde(skip_serializing_if = "Map::is_empty")]
+            custom_fields: Map<String, Value>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            original_estimate_seconds: Option<i32>,
         }
 
         let body = CreateRequest {
@@ -338,6 +470,8 @@ impl Client {
             parent_id,
             status,
             priority,
+            custom_fields: custom_fields.iter().cloned().collect(),
+            original_estimate_seconds,
         };
 
         let req = self.request(Method::POST, "/api/v1/work-items").json(&body);
@@ -359,6 +493,10 @@ impl Client {
         parent_id: Option<&str>,
         update_parent: bool,
         position: Option<i32>,
+        custom_fields: &[(String, Value)],
+        original_estimate_seconds: Option<i32>,
+        remaining_estimate_seconds: Option<i32>,
+        clear_estimate: bool,
         expected_version: i32,
     ) -> CliClientResult<Value> {
         #[derive(Serialize)]
@@ -382,6 +520,13 @@ impl Client {
             update_parent: bool,
             #[serde(skip_serializing_if = "Option::is_none")]
             position: Option<i32>,
+            #[serde(skip_serializing_if = "Map::is_empty")]
+            custom_fields: Map<String, Value>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            original_estimate_seconds: Option<i32>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            remaining_estimate_seconds: Option<i32>,
+            clear_estimate: bool,
             expected_version: i32,
         }
 
@@ -396,6 +541,10 @@ impl Client {
             parent_id,
             update_parent,
             position,
+            custom_fields: custom_fields.iter().cloned().collect(),
+            original_estimate_seconds,
+            remaining_estimate_seconds,
+            clear_estimate,
             expected_version,
         };
 
@@ -405,6 +554,65 @@ impl Client {
         self.execute(req).await
     }
 
+    /// Move a work item within its project's backlog, directly below
+    /// `before_id` and/or directly above `after_id`
+    pub async fn move_work_item(
+        &self,
+        id: &str,
+        before_id: Option<&str>,
+        after_id: Option<&str>,
+        expected_version: i32,
+    ) -> CliClientResult<Value> {
+        #[derive(Serialize)]
+        struct MoveRequest<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            before_id: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            after_id: Option<&'a str>,
+            expected_version: i32,
+        }
+
+        let body = MoveRequest {
+            before_id,
+            after_id,
+            expected_version,
+        };
+        let req = self
+            .request(Method::POST, &format!("/api/v1/work-items/{}/move", id))
+            .json(&body);
+        self.execute(req).await
+    }
+
+    /// Move a work item and its descendants to another project
+    pub async fn move_work_item_to_project(
+        &self,
+        id: &str,
+        project_id: &str,
+        parent_id: Option<&str>,
+        expected_version: i32,
+    ) -> CliClientResult<Value> {
+        #[derive(Serialize)]
+        struct MoveRequest<'a> {
+            project_id: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            parent_id: Option<&'a str>,
+            expected_version: i32,
+        }
+
+        let body = MoveRequest {
+            project_id,
+            parent_id,
+            expected_version,
+        };
+        let req = self
+            .request(
+                Method::POST,
+                &format!("/api/v1/work-items/{}/move-to-project", id),
+            )
+            .json(&body);
+        self.execute(req).await
+    }
+
     /// Delete a work item
     pub async fn delete_work_item(&self, id: &str) -> CliClientResult<Value> {
         let req = self.request(Method::DELETE, &format!("/api/v1/work-items/{}", id));
@@ -412,18 +620,125 @@ impl Client {
     }
 
     // =========================================================================
-    // Comment Operations
+    // Custom Field Operations
     // =========================================================================
 
-    /// List comments on a work item
-    pub async fn list_comments(&self, work_item_id: &str) -> CliClientResult<Value> {
+    /// List custom field definitions in a project
+    pub async fn list_custom_fields(&self, project_id: &str) -> CliClientResult<Value> {
         let req = self.request(
             Method::GET,
-            &format!("/api/v1/work-items/{}/comments", work_item_id),
+            &format!("/api/v1/projects/{}/custom-fields", project_id),
         );
         self.execute(req).await
     }
 
+    /// Create a custom field definition
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_custom_field(
+        &self,
+        project_id: &str,
+        name: &str,
+        field_type: &str,
+        label: Option<&str>,
+        options: &[String],
+        required: bool,
+        default_value: Option<Value>,
+    ) -> CliClientResult<Value> {
+        #[derive(Serialize)]
+        struct CreateRequest<'a> {
+            name: &'a str,
+            field_type: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            label: Option<&'a str>,
+            options: &'a [String],
+            required: bool,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            default_value: Option<Value>,
+        }
+
+        let body = CreateRequest {
+            name,
+            field_type,
+            label,
+            options,
+            required,
+            default_value,
+        };
+
+        let req = self
+            .request(
+                Method::POST,
+                &format!("/api/v1/projects/{}/custom-fields", project_id),
+            )
+            .json(&body);
+        self.execute(req).await
+    }
+
+    /// Update a custom field definition
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_custom_field(
+        &self,
+        id: &str,
+        label: Option<&str>,
+        options: Option<&[String]>,
+        required: Option<bool>,
+        default_value: Option<Value>,
+        clear_default: bool,
+        position: Option<i32>,
+        expected_version: i32,
+    ) -> CliClientResult<Value> {
+        #[derive(Serialize)]
+        struct UpdateRequest<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            label: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            options: Option<&'a [String]>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            required: Option<bool>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            default_value: Option<Value>,
+            clear_default: bool,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            position: Option<i32>,
+            expected_version: i32,
+        }
+
+        let body = UpdateRequest {
+            label,
+            options,
+            required,
+            default_value,
+            clear_default,
+            position,
+            expected_version,
+        };
+
+        let req = self
+            .request(Method::PUT, &format!("/api/v1/custom-fields/{}", id))
+            .json(&body);
+        self.execute(req).await
+    }
+
+    /// Delete a custom field definition
+    pub async fn delete_custom_field(&self, id: &str) -> CliClientResult<Value> {
+        let req = self.request(Method::DELETE, &format!("/api/v1/custom-fields/{}", id));
+        self.execute(req).await
+    }
+
+    // =========================================================================
+    // Comment Operations
+    // =========================================================================
+
+    /// List comments on a work item
+    pub async fn list_comments(
+        &self,
+        work_item_id: &str,
+        options: &ListOptions,
+    ) -> CliClientResult<Value> {
+        let path = format!("/api/v1/work-items/{}/comments", work_item_id);
+        self.list(&path, Vec::new(), "comments", options).await
+    }
+
     /// Create a comment on a work item
     pub async fn create_comment(
         &self,
@@ -566,6 +881,7 @@ impl Client {
         id: &str,
         stop: Option<bool>,
         description: Option<&str>,
+        remaining_estimate_seconds: Option<i32>,
     ) -> CliClientResult<Value> {
         #[derive(Serialize)]
         struct UpdateRequest<'a> {
@@ -573,9 +889,15 @@ impl Client {
             stop: Option<bool>,
             #[serde(skip_serializing_if = "Option::is_none")]
             description: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            remaining_estimate_seconds: Option<i32>,
         }
 
-        let body = UpdateRequest { stop, description };
+        let body = UpdateRequest {
+            stop,
+            description,
+            remaining_estimate_seconds,
+        };
         let req = self
             .request(Method::PUT, &format!("/api/v1/time-entries/{}", id))
             .json(&body);
@@ -587,6 +909,336 @@ impl Client {
         self.execute(req).await
     }
 
+    // =========================================================================
+    // LLM Context Operations
+    // =========================================================================
+
+    /// List LLM context entries, highest priority first. With `project_id`,
+    /// the project's entries are included alongside the global ones.
+    pub async fn list_llm_context(
+        &self,
+        project_id: Option<&str>,
+        category: Option<&str>,
+        context_type: Option<&str>,
+        min_priority: Option<i32>,
+    ) -> CliClientResult<Value> {
+        let mut params = Vec::new();
+        if let Some(p) = project_id {
+            params.push(format!("project_id={}", p));
+        }
+        if let Some(c) = category {
+            params.push(format!("category={}", c));
+        }
+        if let Some(t) = context_type {
+            params.push(format!("context_type={}", t));
+        }
+        if let Some(p) = min_priority {
+            params.push(format!("min_priority={}", p));
+        }
+        let url = if params.is_empty() {
+            "/api/v1/llm-context".to_string()
+        } else {
+            format!("/api/v1/llm-context?{}", params.join("&"))
+        };
+        let req = self.request(Method::GET, &url);
+        self.execute(req).await
+    }
+
+    /// Get an LLM context entry by ID
+    pub async fn get_llm_context(&self, id: &str) -> CliClientResult<Value> {
+        let req = self.request(Method::GET, &format!("/api/v1/llm-context/{}", id));
+        self.execute(req).await
+    }
+
+    /// Create an LLM context entry (global when `project_id` is None)
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_llm_context(
+        &self,
+        project_id: Option<&str>,
+        context_type: &str,
+        category: &str,
+        title: &str,
+        content: &str,
+        example_sql: Option<&str>,
+        example_description: Option<&str>,
+        priority: i32,
+    ) -> CliClientResult<Value> {
+        #[derive(Serialize)]
+        struct CreateRequest<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            project_id: Option<&'a str>,
+            context_type: &'a str,
+            category: &'a str,
+            title: &'a str,
+            content: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            example_sql: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            example_description: Option<&'a str>,
+            priority: i32,
+        }
+
+        let body = CreateRequest {
+            project_id,
+            context_type,
+            category,
+            title,
+            content,
+            example_sql,
+            example_description,
+            priority,
+        };
+
+        let req = self
+            .request(Method::POST, "/api/v1/llm-context")
+            .json(&body);
+        self.execute(req).await
+    }
+
+    /// Update an LLM context entry. Only the given fields change.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_llm_context(
+        &self,
+        id: &str,
+        context_type: Option<&str>,
+        category: Option<&str>,
+        title: Option<&str>,
+        content: Option<&str>,
+        example_sql: Option<&str>,
+        example_description: Option<&str>,
+        priority: Option<i32>,
+    ) -> CliClientResult<Value> {
+        #[derive(Serialize)]
+        struct UpdateRequest<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            context_type: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            category: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            title: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            content: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            example_sql: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            example_description: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            priority: Option<i32>,
+        }
+
+        let body = UpdateRequest {
+            context_type,
+            category,
+            title,
+            content,
+            example_sql,
+            example_description,
+            priority,
+        };
+
+        let req = self
+            .request(Method::PUT, &format!("/api/v1/llm-context/{}", id))
+            .json(&body);
+        self.execute(req).await
+    }
+
+    /// Delete an LLM context entry
+    pub async fn delete_llm_context(&self, id: &str) -> CliClientResult<Value> {
+        let req = self.request(Method::DELETE, &format!("/api/v1/llm-context/{}", id));
+        self.execute(req).await
+    }
+
+    /// Prepare every example query against the live schema
+    pub async fn verify_llm_context(&self) -> CliClientResult<Value> {
+        let req = self.request(Method::GET, "/api/v1/llm-context/verify");
+        self.execute(req).await
+    }
+
+    // =========================================================================
+    // Query Operations
+    // =========================================================================
+
+    /// Run a read-only SQL query
+    pub async fn run_query(&self, sql: &str, max_rows: Option<usize>) -> CliClientResult<Value> {
+        #[derive(Serialize)]
+        struct QueryRequest<'a> {
+            sql: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            max_rows: Option<usize>,
+        }
+
+        let req = self
+            .request(Method::POST, "/api/v1/query")
+            .json(&QueryRequest { sql, max_rows });
+        self.execute(req).await
+    }
+
+    // =========================================================================
+    // Graph Operations
+    // =========================================================================
+
+    /// Render the work item graph as Mermaid or DOT text
+    pub async fn graph(
+        &self,
+        project_id: Option<&str>,
+        root: Option<&str>,
+        depth: Option<usize>,
+        dependency_type: &str,
+        format: &str,
+    ) -> CliClientResult<String> {
+        let mut params = vec![
+            format!("dependency_type={}", dependency_type),
+            format!("format={}", format),
+        ];
+        if let Some(pid) = project_id {
+            params.push(format!("project_id={}", pid));
+        }
+        if let Some(r) = root {
+            params.push(format!("root={}", r));
+        }
+        if let Some(d) = depth {
+            params.push(format!("depth={}", d));
+        }
+        let req = self.request(Method::GET, &format!("/api/v1/graph?{}", params.join("&")));
+
+        let response = req.send().await?;
+        if response.status().is_success() {
+            return Ok(response.text().await?);
+        }
+
+        // Errors are still JSON
+        let body: Value = response.json().await?;
+        Err(Self::api_error(body.get("error").unwrap_or(&Value::Null)))
+    }
+
+    // =========================================================================
+    // Schedule Operations
+    // =========================================================================
+
+    /// Get the critical-path schedule for a project, epic or sprint
+    pub async fn critical_path(
+        &self,
+        project_id: Option<&str>,
+        epic_id: Option<&str>,
+        sprint_id: Option<&str>,
+        weight: &str,
+        include_done: bool,
+    ) -> CliClientResult<Value> {
+        let mut params = vec![format!("weight={}", weight)];
+        if let Some(pid) = project_id {
+            params.push(format!("project_id={}", pid));
+        }
+        if let Some(eid) = epic_id {
+            params.push(format!("epic_id={}", eid));
+        }
+        if let Some(sid) = sprint_id {
+            params.push(format!("sprint_id={}", sid));
+        }
+        if include_done {
+            params.push("include_done=true".to_string());
+        }
+        let url = format!("/api/v1/schedule?{}", params.join("&"));
+        let req = self.request(Method::GET, &url);
+        self.execute(req).await
+    }
+
+    // =========================================================================
+    // Time Report Operations
+    // =========================================================================
+
+    /// Build the time report URL with query parameters
+    fn time_report_url(
+        from: &str,
+        to: &str,
+        group_by: &str,
+        period: Option<&str>,
+        project_id: Option<&str>,
+        user_id: Option<&str>,
+    ) -> String {
+        let mut params = vec![
+            format!("from={}", from),
+            format!("to={}", to),
+            format!("group_by={}", group_by),
+        ];
+        if let Some(p) = period {
+            params.push(format!("period={}", p));
+        }
+        if let Some(pid) = project_id {
+            params.push(format!("project_id={}", pid));
+        }
+        if let Some(uid) = user_id {
+            params.push(format!("user_id={}", uid));
+        }
+        format!("/api/v1/time-reports?{}", params.join("&"))
+    }
+
+    /// Get an aggregated time report as JSON
+    pub async fn time_report(
+        &self,
+        from: &str,
+        to: &str,
+        group_by: &str,
+        period: Option<&str>,
+        project_id: Option<&str>,
+        user_id: Option<&str>,
+    ) -> CliClientResult<Value> {
+        let url = Self::time_report_url(from, to, group_by, period, project_id, user_id);
+        let req = self.request(Method::GET, &url);
+        self.execute(req).await
+    }
+
+    /// Get an aggregated time report rendered as CSV by the server
+    pub async fn time_report_csv(
+        &self,
+        from: &str,
+        to: &str,
+        group_by: &str,
+        period: Option<&str>,
+        project_id: Option<&str>,
+        user_id: Option<&str>,
+    ) -> CliClientResult<String> {
+        let url = Self::time_report_url(from, to, group_by, period, project_id, user_id);
+        let req = self.request(Method::GET, &format!("{}&format=csv", url));
+
+        let response = req.send().await?;
+        if response.status().is_success() {
+            return Ok(response.text().await?);
+        }
+
+        // Errors are still JSON
+        let body: Value = response.json().await?;
+        Err(Self::api_error(body.get("error").unwrap_or(&Value::Null)))
+    }
+
+    // =========================================================================
+    // Backup Operations
+    // =========================================================================
+
+    /// Take an online backup of the database
+    pub async fn create_backup(&self) -> CliClientResult<Value> {
+        let req = self.request(Method::POST, "/admin/backup");
+        self.execute(req).await
+    }
+
+    /// List database backups, newest first
+    pub async fn list_backups(&self) -> CliClientResult<Value> {
+        let req = self.request(Method::GET, "/admin/backups");
+        self.execute(req).await
+    }
+
+    /// Stage a backup for restore; the server shuts down to apply it
+    pub async fn restore_backup(&self, name: &str) -> CliClientResult<Value> {
+        #[derive(Serialize)]
+        struct RestoreRequest<'a> {
+            name: &'a str,
+        }
+
+        let req = self
+            .request(Method::POST, "/admin/backup/restore")
+            .json(&RestoreRequest { name });
+        self.execute(req).await
+    }
+
     // =========================================================================
     // Import / Export Operations
     // =========================================================================
@@ -637,8 +1289,9 @@ impl Client {
         Ok(result)
     }
 
-    /// Import data from JSON file
-    pub async fn import_data(&self, file_path: &str) -> CliClientResult<Value> {
+    /// Import data from JSON file; a dry run rolls back and only reports
+    /// the diff
+    pub async fn import_data(&self, file_path: &str, dry_run: bool) -> CliClientResult<Value> {
         // Read file
         let json_str = std::fs::read_to_string(file_path)?;
 
@@ -646,9 +1299,93 @@ impl Client {
         let data: Value = serde_json::from_str(&json_str)?;
 
         // Send to server
+        let url = match dry_run {
+            true => "/api/v1/sync/import?dry_run=true",
+            false => "/api/v1/sync/import",
+        };
+        let req = self.request(Method::POST, url).json(&data);
+        self.execute(req).await
+    }
+
+    /// Pull from or push to another server (`direction` is "pull" or "push")
+    pub async fn sync_peer(
+        &self,
+        direction: &str,
+        remote: &str,
+        on_conflict: &str,
+        token: Option<&str>,
+    ) -> CliClientResult<Value> {
+        #[derive(Serialize)]
+        struct PeerSyncRequest<'a> {
+            remote: &'a str,
+            on_conflict: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            token: Option<&'a str>,
+        }
+
         let req = self
-            .request(Method::POST, "/api/v1/sync/import")
-            .json(&data);
+            .request(Method::POST, &format!("/api/v1/sync/{}", direction))
+            .json(&PeerSyncRequest {
+                remote,
+                on_conflict,
+                token,
+            });
+        self.execute(req).await
+    }
+
+    pub async fn list_sync_peers(&self) -> CliClientResult<Value> {
+        let req = self.request(Method::GET, "/api/v1/sync/peers");
+        self.execute(req).await
+    }
+
+    // =========================================================================
+    // Template Operations
+    // =========================================================================
+
+    /// List project templates
+    pub async fn list_templates(&self) -> CliClientResult<Value> {
+        let req = self.request(Method::GET, "/api/v1/templates");
+        self.execute(req).await
+    }
+
+    /// Get a template by ID or name
+    pub async fn get_template(&self, id: &str) -> CliClientResult<Value> {
+        let req = self.request(Method::GET, &format!("/api/v1/templates/{}", id));
+        self.execute(req).await
+    }
+
+    /// Save a template from a project, or from a structure
+    pub async fn create_template(
+        &self,
+        name: &str,
+        description: Option<&str>,
+        project_id: Option<&str>,
+        structure: Option<&Value>,
+    ) -> CliClientResult<Value> {
+        #[derive(Serialize)]
+        struct CreateRequest<'a> {
+            name: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            description: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            project_id: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            structure: Option<&'a Value>,
+        }
+
+        let body = CreateRequest {
+            name,
+            description,
+            project_id,
+            structure,
+        };
+        let req = self.request(Method::POST, "/api/v1/templates").json(&body);
+        self.execute(req).await
+    }
+
+    /// Delete a template
+    pub async fn delete_template(&self, id: &str) -> CliClientResult<Value> {
+        let req = self.request(Method::DELETE, &format!("/api/v1/templates/{}", id));
         self.execute(req).await
     }
 }
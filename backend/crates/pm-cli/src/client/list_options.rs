@@ -0,0 +1,33 @@
+/// Paging, sorting and field selection for list requests
+#[derive(Debug, Clone, Default)]
+pub struct ListOptions {
+    /// Items per page; the server default applies when None
+    pub limit: Option<usize>,
+    /// Start after this cursor (a previous page's `next_cursor`)
+    pub cursor: Option<String>,
+    /// Comma-separated sort fields, `-` prefix for descending
+    pub sort: Option<String>,
+    /// Comma-separated fields to return
+    pub fields: Option<String>,
+    /// Follow `next_cursor` until the last page and merge the results
+    pub all: bool,
+}
+
+impl ListOptions {
+    pub fn query_params(&self) -> Vec<String> {
+        let mut params = Vec::new();
+        if let Some(limit) = self.limit {
+            params.push(format!("limit={}", limit));
+        }
+        if let Some(cursor) = &self.cursor {
+            params.push(format!("cursor={}", cursor));
+        }
+        if let Some(sort) = &self.sort {
+            params.push(format!("sort={}", sort));
+        }
+        if let Some(fields) = &self.fields {
+            params.push(format!("fields={}", fields));
+        }
+        params
+    }
+}
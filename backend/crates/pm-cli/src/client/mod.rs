@@ -3,6 +3,8 @@
 #[allow(clippy::module_inception)]
 pub(crate) mod client;
 pub(crate) mod error;
+pub(crate) mod list_options;
 
 pub use client::Client;
 pub use error::{ClientError, Result as CliClientResult};
+pub use list_options::ListOptions;
@@ -1,11 +1,14 @@
 use crate::{
-    comment_commands::CommentCommands, dependency_commands::DependencyCommands,
-    project_commands::ProjectCommands, sprint_commands::SprintCommands,
+    backup_commands::BackupCommands, comment_commands::CommentCommands,
+    custom_field_commands::CustomFieldCommands, dependency_commands::DependencyCommands,
+    llm_context_commands::LlmContextCommands, project_commands::ProjectCommands,
+    schedule_commands::ScheduleCommands, sprint_commands::SprintCommands,
     swim_lane_commands::SwimLaneCommands, sync_commands::SyncCommands,
+    template_commands::TemplateCommands, time_commands::TimeCommands,
     time_entry_commands::TimeEntryCommands, work_item_commands::WorkItemCommands,
 };
 
-use clap::Subcommand;
+use clap::{ArgGroup, Subcommand};
 
 #[derive(Subcommand)]
 pub(crate) enum Commands {
@@ -33,6 +36,12 @@ pub(crate) enum Commands {
         action: CommentCommands,
     },
 
+    /// Custom field definition operations
+    CustomField {
+        #[command(subcommand)]
+        action: CustomFieldCommands,
+    },
+
     /// Dependency operations
     Dependency {
         #[command(subcommand)]
@@ -51,12 +60,92 @@ pub(crate) enum Commands {
         action: TimeEntryCommands,
     },
 
+    /// Time-tracking reports
+    Time {
+        #[command(subcommand)]
+        action: TimeCommands,
+    },
+
+    /// Render the work item hierarchy and dependencies as Mermaid or DOT
+    #[command(group(
+        ArgGroup::new("scope")
+            .args(["project_id", "root"])
+            .required(true)
+    ))]
+    Graph {
+        /// Render every item in a project (UUID or project key like "PONE")
+        #[arg(long)]
+        project_id: Option<String>,
+
+        /// Render the subtree under this item (UUID or display key like "PONE-12")
+        #[arg(long)]
+        root: Option<String>,
+
+        /// Levels of children to include (unlimited by default)
+        #[arg(long)]
+        depth: Option<usize>,
+
+        /// Dependency edges to draw
+        #[arg(long, default_value = "all", value_parser = ["all", "blocks", "relates_to", "none"])]
+        dependency_type: String,
+
+        /// Output format
+        #[arg(long, default_value = "mermaid", value_parser = ["mermaid", "dot"])]
+        format: String,
+    },
+
+    /// Dependency schedule analysis
+    Schedule {
+        #[command(subcommand)]
+        action: ScheduleCommands,
+    },
+
+    /// Run a read-only SQL query against projects you are a member of
+    #[command(group(
+        ArgGroup::new("source")
+            .args(["sql", "file"])
+            .required(true)
+    ))]
+    Query {
+        /// A single SELECT statement
+        sql: Option<String>,
+
+        /// Read the statement from a file
+        #[arg(long)]
+        file: Option<String>,
+
+        /// Return at most this many rows (capped by the server limit)
+        #[arg(long)]
+        max_rows: Option<usize>,
+    },
+
+    /// LLM context entries that teach agents the schema and conventions
+    LlmContext {
+        #[command(subcommand)]
+        action: LlmContextCommands,
+    },
+
     /// Launch the desktop app for this repository
     Desktop,
 
+    /// Serve the Model Context Protocol over stdio for LLM agents
+    Mcp,
+
+    /// Online database backups (create/list/restore)
+    Backup {
+        #[command(subcommand)]
+        action: BackupCommands,
+    },
+
     /// Bulk data sync operations (export/import)
     Sync {
         #[command(subcommand)]
         action: SyncCommands,
     },
+
+    /// Project templates: structure without history, for new projects
+    Template {
+        #[command(subcommand)]
+        action: TemplateCommands,
+    },
 }
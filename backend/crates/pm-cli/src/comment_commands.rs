@@ -1,3 +1,5 @@
+use crate::list_args::ListArgs;
+
 use clap::Subcommand;
 
 #[derive(Subcommand)]
@@ -6,6 +8,9 @@ pub enum CommentCommands {
     List {
         /// Work item ID (UUID or display key like "PONE-123")
         work_item_id: String,
+
+        #[command(flatten)]
+        page: ListArgs,
     },
 
     /// Create a comment on a work item
@@ -0,0 +1,81 @@
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+pub enum CustomFieldCommands {
+    /// List custom field definitions in a project
+    List {
+        /// Project ID (UUID or project key like "PONE")
+        project_id: String,
+    },
+
+    /// Create a custom field definition
+    Create {
+        /// Project ID (UUID or project key like "PONE")
+        #[arg(long)]
+        project_id: String,
+
+        /// Field name used as the value key (lowercase letters, digits, underscores)
+        #[arg(long)]
+        name: String,
+
+        /// Field type
+        #[arg(long, value_parser = ["text", "number", "date", "single_select", "multi_select", "user"])]
+        r#type: String,
+
+        /// Display label (default: the field name)
+        #[arg(long)]
+        label: Option<String>,
+
+        /// Allowed option for select fields (repeatable)
+        #[arg(long = "option")]
+        options: Vec<String>,
+
+        /// Require a value on every work item
+        #[arg(long)]
+        required: bool,
+
+        /// Default value applied on create (JSON, or plain text for strings)
+        #[arg(long)]
+        default: Option<String>,
+    },
+
+    /// Update a custom field definition
+    Update {
+        /// Custom field ID (UUID)
+        id: String,
+
+        /// New display label
+        #[arg(long)]
+        label: Option<String>,
+
+        /// Replace the allowed options for select fields (repeatable)
+        #[arg(long = "option")]
+        options: Vec<String>,
+
+        /// Whether a value is required
+        #[arg(long)]
+        required: Option<bool>,
+
+        /// New default value (JSON, or plain text for strings)
+        #[arg(long, conflicts_with = "clear_default")]
+        default: Option<String>,
+
+        /// Remove the default value
+        #[arg(long)]
+        clear_default: bool,
+
+        /// Position for ordering
+        #[arg(long)]
+        position: Option<i32>,
+
+        /// Expected version for optimistic locking (required)
+        #[arg(long)]
+        version: i32,
+    },
+
+    /// Delete a custom field definition
+    Delete {
+        /// Custom field ID (UUID)
+        id: String,
+    },
+}
@@ -2,19 +2,28 @@
 //!
 //! This module exports the HTTP client for use in tests and other crates.
 
+pub(crate) mod backup_commands;
 pub(crate) mod cli;
 pub(crate) mod client;
 pub(crate) mod commands;
 pub(crate) mod comment_commands;
+pub(crate) mod custom_field_commands;
 pub(crate) mod dependency_commands;
+pub(crate) mod list_args;
+pub(crate) mod llm_context_commands;
+pub(crate) mod mcp;
 pub(crate) mod project_commands;
+pub(crate) mod schedule_commands;
 pub(crate) mod sprint_commands;
 pub(crate) mod swim_lane_commands;
 pub(crate) mod sync_commands;
+pub(crate) mod template_commands;
+pub(crate) mod time_commands;
 pub(crate) mod time_entry_commands;
 pub(crate) mod work_item_commands;
 
 #[cfg(test)]
 mod tests;
 
-pub use client::{CliClientResult, Client, ClientError};
+pub use client::{CliClientResult, Client, ClientError, ListOptions};
+pub use mcp::McpServer;
@@ -0,0 +1,39 @@
+use crate::ListOptions;
+
+use clap::Args;
+
+/// Paging flags shared by the `list` subcommands
+#[derive(Args, Debug, Clone, Default)]
+pub struct ListArgs {
+    /// Items per page (server default when omitted)
+    #[arg(long)]
+    pub limit: Option<usize>,
+
+    /// Continue from a previous response's `next_cursor`
+    #[arg(long)]
+    pub cursor: Option<String>,
+
+    /// Sort by comma-separated fields; prefix with '-' for descending (e.g. "-updated_at")
+    #[arg(long)]
+    pub sort: Option<String>,
+
+    /// Return only these comma-separated fields (e.g. "display_key,title,status")
+    #[arg(long = "fields", value_name = "FIELDS")]
+    pub select: Option<String>,
+
+    /// Fetch every page by following cursors
+    #[arg(long)]
+    pub all: bool,
+}
+
+impl From<&ListArgs> for ListOptions {
+    fn from(args: &ListArgs) -> Self {
+        Self {
+            limit: args.limit,
+            cursor: args.cursor.clone(),
+            sort: args.sort.clone(),
+            fields: args.select.clone(),
+            all: args.all,
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use clap::{ArgGroup, Subcommand};
+
+const CONTEXT_TYPES: [&str; 5] = [
+    "schema_doc",
+    "query_pattern",
+    "business_rule",
+    "example",
+    "instruction",
+];
+
+#[derive(Subcommand)]
+pub enum LlmContextCommands {
+    /// List LLM context entries, highest priority first
+    List {
+        /// Include this project's entries alongside the global ones (UUID or project key)
+        #[arg(long)]
+        project_id: Option<String>,
+
+        /// Filter by category
+        #[arg(long)]
+        category: Option<String>,
+
+        /// Filter by context type
+        #[arg(long, value_parser = CONTEXT_TYPES)]
+        r#type: Option<String>,
+
+        /// Only entries with at least this priority
+        #[arg(long)]
+        min_priority: Option<i32>,
+    },
+
+    /// Get an LLM context entry
+    Get {
+        /// Entry ID (UUID)
+        id: String,
+    },
+
+    /// Create an LLM context entry (global unless --project-id is given)
+    #[command(group(
+        ArgGroup::new("body")
+            .args(["content", "content_file"])
+            .required(true)
+    ))]
+    Create {
+        /// Project the entry applies to (UUID or project key like "PONE")
+        #[arg(long)]
+        project_id: Option<String>,
+
+        /// Context type
+        #[arg(long, value_parser = CONTEXT_TYPES)]
+        r#type: String,
+
+        /// Category, e.g. "work_items" or "conventions"
+        #[arg(long)]
+        category: String,
+
+        /// Entry title
+        #[arg(long)]
+        title: String,
+
+        /// Entry content (markdown)
+        #[arg(long)]
+        content: Option<String>,
+
+        /// Read the content from a file
+        #[arg(long)]
+        content_file: Option<String>,
+
+        /// Example SQL query
+        #[arg(long)]
+        example_sql: Option<String>,
+
+        /// What the example query demonstrates
+        #[arg(long)]
+        example_description: Option<String>,
+
+        /// Priority from 0 to 100 (higher is read first)
+        #[arg(long, default_value_t = 0)]
+        priority: i32,
+    },
+
+    /// Update an LLM context entry (only the given fields change)
+    Update {
+        /// Entry ID (UUID)
+        id: String,
+
+        /// New context type
+        #[arg(long, value_parser = CONTEXT_TYPES)]
+        r#type: Option<String>,
+
+        /// New category
+        #[arg(long)]
+        category: Option<String>,
+
+        /// New title
+        #[arg(long)]
+        title: Option<String>,
+
+        /// New content (markdown)
+        #[arg(long, conflicts_with = "content_file")]
+        content: Option<String>,
+
+        /// Read the new content from a file
+        #[arg(long)]
+        content_file: Option<String>,
+
+        /// New example SQL ("" to remove)
+        #[arg(long)]
+        example_sql: Option<String>,
+
+        /// New example description ("" to remove)
+        #[arg(long)]
+        example_description: Option<String>,
+
+        /// New priority (0-100)
+        #[arg(long)]
+        priority: Option<i32>,
+    },
+
+    /// Delete an LLM context entry
+    Delete {
+        /// Entry ID (UUID)
+        id: String,
+    },
+
+    /// Check every example query against the current schema (exits non-zero when any are broken)
+    Verify,
+}
@@ -15,35 +15,50 @@
 //! pm work-item update <id> --status done --version 1
 //! ```
 
+mod backup_commands;
 mod cli;
 mod client;
 mod commands;
 mod comment_commands;
+mod custom_field_commands;
 mod dependency_commands;
+mod list_args;
+mod llm_context_commands;
 mod project_commands;
+mod schedule_commands;
 mod sprint_commands;
 mod swim_lane_commands;
 mod sync_commands;
+mod template_commands;
+mod template_toml;
+mod time_commands;
 mod time_entry_commands;
 mod work_item_commands;
 mod work_item_toml;
 
 use crate::{
+    backup_commands::BackupCommands,
     cli::Cli,
     client::{CliClientResult, error::ClientError},
     commands::Commands,
     comment_commands::CommentCommands,
+    custom_field_commands::CustomFieldCommands,
     dependency_commands::DependencyCommands,
+    llm_context_commands::LlmContextCommands,
     project_commands::ProjectCommands,
+    schedule_commands::ScheduleCommands,
     sprint_commands::SprintCommands,
     swim_lane_commands::SwimLaneCommands,
     sync_commands::SyncCommands,
+    template_commands::TemplateCommands,
+    template_toml::TemplateToml,
+    time_commands::TimeCommands,
     time_entry_commands::TimeEntryCommands,
-    work_item_commands::WorkItemCommands,
+    work_item_commands::{WorkItemCommands, parse_cli_value},
     work_item_toml::WorkItemToml,
 };
 
-use pm_cli::Client;
+use pm_cli::{Client, ListOptions, McpServer};
 
 use std::path::PathBuf;
 use std::process::ExitCode;
@@ -67,18 +82,32 @@ async fn main() -> ExitCode {
 
     let client = Client::new(&server_url, cli.user_id.as_deref());
 
+    // MCP owns stdin/stdout until the client disconnects
+    if matches!(cli.command, Commands::Mcp) {
+        return run_mcp(client).await;
+    }
+
+    // Verification reports are printed like any result but fail the exit code
+    let verifying = matches!(
+        cli.command,
+        Commands::LlmContext {
+            action: LlmContextCommands::Verify
+        }
+    );
+
     let result = match cli.command {
         // Project commands
         Commands::Project { action } => match action {
-            ProjectCommands::List => client.list_projects().await,
+            ProjectCommands::List { page } => client.list_projects(&ListOptions::from(&page)).await,
             ProjectCommands::Get { id } => client.get_project(&id).await,
             ProjectCommands::Create {
                 title,
                 key,
                 description,
+                template,
             } => {
                 client
-                    .create_project(&title, &key, description.as_deref())
+                    .create_project(&title, &key, description.as_deref(), template.as_deref())
                     .await
             }
             ProjectCommands::Update {
@@ -98,13 +127,23 @@ async fn main() -> ExitCode {
                     )
                     .await
             }
+            ProjectCommands::RenameKey {
+                id,
+                key,
+                expected_version,
+            } => client.rename_project_key(&id, &key, expected_version).await,
             ProjectCommands::Delete { id } => client.delete_project(&id).await,
         },
 
         // Sprint commands
         Commands::Sprint { action } => match action {
-            SprintCommands::List { project_id } => client.list_sprints(&project_id).await,
+            SprintCommands::List { project_id, page } => {
+                client
+                    .list_sprints(&project_id, &ListOptions::from(&page))
+                    .await
+            }
             SprintCommands::Get { id } => client.get_sprint(&id).await,
+            SprintCommands::Summary { id } => client.get_sprint_summary(&id).await,
             SprintCommands::Create {
                 project_id,
                 name,
@@ -150,6 +189,8 @@ async fn main() -> ExitCode {
                 parent_id,
                 status,
                 priority,
+                fields,
+                estimate,
                 from_toml,
             } => {
                 // Load TOML base if --from-toml is provided
@@ -176,14 +217,14 @@ async fn main() -> ExitCode {
 
                 // Validate item_type when present — TOML bypasses clap's value_parser.
                 // Runs before required-field check so item_type is still Option here.
-                if let Some(ref t) = item_type {
-                    if !["epic", "story", "task"].contains(&t.as_str()) {
-                        eprintln!(
-                            "Error: --type must be 'epic', 'story', or 'task'. Got: '{}'",
-                            t
-                        );
-                        return ExitCode::FAILURE;
-                    }
+                if let Some(ref t) = item_type
+                    && !["epic", "story", "task"].contains(&t.as_str())
+                {
+                    eprintln!(
+                        "Error: --type must be 'epic', 'story', or 'task'. Got: '{}'",
+                        t
+                    );
+                    return ExitCode::FAILURE;
                 }
 
                 // Validate required fields — report only what's actually missing
@@ -218,6 +259,8 @@ async fn main() -> ExitCode {
                         parent_id.as_deref(),
                         status.as_deref(),
                         priority.as_deref(),
+                        &fields,
+                        estimate,
                     )
                     .await
             }
@@ -231,6 +274,8 @@ async fn main() -> ExitCode {
                 descendants_of,
                 ancestors_of,
                 include_done,
+                fields,
+                page,
             } => {
                 client
                     .list_work_items(
@@ -242,6 +287,8 @@ async fn main() -> ExitCode {
                         descendants_of.as_deref(),
                         ancestors_of.as_deref(),
                         include_done,
+                        &fields,
+                        &ListOptions::from(&page),
                     )
                     .await
             }
@@ -257,6 +304,10 @@ async fn main() -> ExitCode {
                 parent_id,
                 update_parent,
                 position,
+                fields,
+                estimate,
+                remaining,
+                clear_estimate,
                 from_toml,
                 version,
             } => {
@@ -289,11 +340,11 @@ async fn main() -> ExitCode {
                 let parent_id = parent_id.or(toml_parent_id.clone());
 
                 // Validate story_points range — TOML bypasses any CLI-level validator.
-                if let Some(sp) = story_points {
-                    if !(0..=100).contains(&sp) {
-                        eprintln!("Error: story_points must be between 0 and 100, got {}", sp);
-                        return ExitCode::FAILURE;
-                    }
+                if let Some(sp) = story_points
+                    && !(0..=100).contains(&sp)
+                {
+                    eprintln!("Error: story_points must be between 0 and 100, got {}", sp);
+                    return ExitCode::FAILURE;
                 }
 
                 // Warn when parent_id came from TOML but --update-parent is not set.
@@ -320,16 +371,119 @@ async fn main() -> ExitCode {
                         parent_id.as_deref(),
                         update_parent,
                         position,
+                        &fields,
+                        estimate,
+                        remaining,
+                        clear_estimate,
                         version,
                     )
                     .await
             }
+            WorkItemCommands::Context {
+                id,
+                format,
+                max_chars,
+            } => {
+                // Markdown is rendered by the server and printed verbatim
+                if format == "markdown" {
+                    let text = client.work_item_context_markdown(&id, max_chars).await;
+                    return match text {
+                        Ok(text) => {
+                            print!("{}", text);
+                            ExitCode::SUCCESS
+                        }
+                        Err(e) => {
+                            eprintln!("Error: {}", e);
+                            ExitCode::FAILURE
+                        }
+                    };
+                }
+
+                client.work_item_context(&id, max_chars).await
+            }
+            WorkItemCommands::Reorder {
+                id,
+                below,
+                above,
+                version,
+            } => {
+                client
+                    .move_work_item(&id, below.as_deref(), above.as_deref(), version)
+                    .await
+            }
+            WorkItemCommands::Move {
+                id,
+                project,
+                parent_id,
+                version,
+            } => {
+                client
+                    .move_work_item_to_project(&id, &project, parent_id.as_deref(), version)
+                    .await
+            }
             WorkItemCommands::Delete { id } => client.delete_work_item(&id).await,
         },
 
+        // Custom field commands
+        Commands::CustomField { action } => match action {
+            CustomFieldCommands::List { project_id } => {
+                client.list_custom_fields(&project_id).await
+            }
+            CustomFieldCommands::Create {
+                project_id,
+                name,
+                r#type,
+                label,
+                options,
+                required,
+                default,
+            } => {
+                client
+                    .create_custom_field(
+                        &project_id,
+                        &name,
+                        &r#type,
+                        label.as_deref(),
+                        &options,
+                        required,
+                        default.as_deref().map(parse_cli_value),
+                    )
+                    .await
+            }
+            CustomFieldCommands::Update {
+                id,
+                label,
+                options,
+                required,
+                default,
+                clear_default,
+                position,
+                version,
+            } => {
+                let options = (!options.is_empty()).then_some(options.as_slice());
+                client
+                    .update_custom_field(
+                        &id,
+                        label.as_deref(),
+                        options,
+                        required,
+                        default.as_deref().map(parse_cli_value),
+                        clear_default,
+                        position,
+                        version,
+                    )
+                    .await
+            }
+            CustomFieldCommands::Delete { id } => client.delete_custom_field(&id).await,
+        },
+
         // Comment commands
         Commands::Comment { action } => match action {
-            CommentCommands::List { work_item_id } => client.list_comments(&work_item_id).await,
+            CommentCommands::List { work_item_id, page } => {
+                client
+                    .list_comments(&work_item_id, &ListOptions::from(&page))
+                    .await
+            }
             CommentCommands::Create {
                 work_item_id,
                 content,
@@ -372,20 +526,224 @@ async fn main() -> ExitCode {
             TimeEntryCommands::Update {
                 id,
                 stop,
+                remaining,
                 description,
             } => {
                 let stop_flag = if stop { Some(true) } else { None };
                 client
-                    .update_time_entry(&id, stop_flag, description.as_deref())
+                    .update_time_entry(&id, stop_flag, description.as_deref(), remaining)
                     .await
             }
             TimeEntryCommands::Delete { id } => client.delete_time_entry(&id).await,
         },
 
-        // Desktop is handled above before server discovery
-        Commands::Desktop => unreachable!(),
+        // Time report commands
+        Commands::Time { action } => match action {
+            TimeCommands::Report {
+                from,
+                to,
+                group_by,
+                period,
+                project_id,
+                user,
+                format,
+            } => {
+                // CSV is rendered by the server and printed verbatim
+                if format == "csv" {
+                    let csv = client
+                        .time_report_csv(
+                            &from,
+                            &to,
+                            &group_by,
+                            period.as_deref(),
+                            project_id.as_deref(),
+                            user.as_deref(),
+                        )
+                        .await;
+                    return match csv {
+                        Ok(text) => {
+                            print!("{}", text);
+                            ExitCode::SUCCESS
+                        }
+                        Err(e) => {
+                            eprintln!("Error: {}", e);
+                            ExitCode::FAILURE
+                        }
+                    };
+                }
+
+                client
+                    .time_report(
+                        &from,
+                        &to,
+                        &group_by,
+                        period.as_deref(),
+                        project_id.as_deref(),
+                        user.as_deref(),
+                    )
+                    .await
+            }
+        },
+
+        // Graph rendering (text output, printed verbatim)
+        Commands::Graph {
+            project_id,
+            root,
+            depth,
+            dependency_type,
+            format,
+        } => {
+            let text = client
+                .graph(
+                    project_id.as_deref(),
+                    root.as_deref(),
+                    depth,
+                    &dependency_type,
+                    &format,
+                )
+                .await;
+            return match text {
+                Ok(text) => {
+                    print!("{}", text);
+                    ExitCode::SUCCESS
+                }
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    ExitCode::FAILURE
+                }
+            };
+        }
+
+        // Schedule analysis commands
+        Commands::Schedule { action } => match action {
+            ScheduleCommands::CriticalPath {
+                project_id,
+                epic_id,
+                sprint_id,
+                weight,
+                include_done,
+            } => {
+                client
+                    .critical_path(
+                        project_id.as_deref(),
+                        epic_id.as_deref(),
+                        sprint_id.as_deref(),
+                        &weight,
+                        include_done,
+                    )
+                    .await
+            }
+        },
+
+        // LLM context commands
+        Commands::Query {
+            sql,
+            file,
+            max_rows,
+        } => {
+            let sql = match read_content(sql, file).await {
+                Ok(sql) => sql.unwrap_or_default(),
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            client.run_query(&sql, max_rows).await
+        }
+
+        Commands::LlmContext { action } => match action {
+            LlmContextCommands::List {
+                project_id,
+                category,
+                r#type,
+                min_priority,
+            } => {
+                client
+                    .list_llm_context(
+                        project_id.as_deref(),
+                        category.as_deref(),
+                        r#type.as_deref(),
+                        min_priority,
+                    )
+                    .await
+            }
+            LlmContextCommands::Get { id } => client.get_llm_context(&id).await,
+            LlmContextCommands::Create {
+                project_id,
+                r#type,
+                category,
+                title,
+                content,
+                content_file,
+                example_sql,
+                example_description,
+                priority,
+            } => {
+                let content = match read_content(content, content_file).await {
+                    Ok(c) => c,
+                    Err(e) => {
+                        eprintln!("Error: {}", e);
+                        return ExitCode::FAILURE;
+                    }
+                };
+                client
+                    .create_llm_context(
+                        project_id.as_deref(),
+                        &r#type,
+                        &category,
+                        &title,
+                        content.as_deref().unwrap_or_default(),
+                        example_sql.as_deref(),
+                        example_description.as_deref(),
+                        priority,
+                    )
+                    .await
+            }
+            LlmContextCommands::Update {
+                id,
+                r#type,
+                category,
+                title,
+                content,
+                content_file,
+                example_sql,
+                example_description,
+                priority,
+            } => {
+                let content = match read_content(content, content_file).await {
+                    Ok(c) => c,
+                    Err(e) => {
+                        eprintln!("Error: {}", e);
+                        return ExitCode::FAILURE;
+                    }
+                };
+                client
+                    .update_llm_context(
+                        &id,
+                        r#type.as_deref(),
+                        category.as_deref(),
+                        title.as_deref(),
+                        content.as_deref(),
+                        example_sql.as_deref(),
+                        example_description.as_deref(),
+                        priority,
+                    )
+                    .await
+            }
+            LlmContextCommands::Delete { id } => client.delete_llm_context(&id).await,
+            LlmContextCommands::Verify => client.verify_llm_context().await,
+        },
+
+        // Desktop and MCP are handled above
+        Commands::Desktop | Commands::Mcp => unreachable!(),
 
         // Sync commands (bulk export/import)
+        Commands::Backup { action } => match action {
+            BackupCommands::Create => client.create_backup().await,
+            BackupCommands::List => client.list_backups().await,
+            BackupCommands::Restore { name } => client.restore_backup(&name).await,
+        },
+
         Commands::Sync { action } => match action {
             SyncCommands::Export { output, scope } => match scope {
                 Some(sync_commands::ExportScope::WorkItem {
@@ -414,7 +772,71 @@ async fn main() -> ExitCode {
                         .await
                 }
             },
-            SyncCommands::Import { file } => client.import_data(&file).await,
+            SyncCommands::Import { file, dry_run } => client.import_data(&file, dry_run).await,
+            SyncCommands::Pull {
+                remote,
+                on_conflict,
+                token,
+            } => {
+                client
+                    .sync_peer("pull", &remote, &on_conflict, token.as_deref())
+                    .await
+            }
+            SyncCommands::Push {
+                remote,
+                on_conflict,
+                token,
+            } => {
+                client
+                    .sync_peer("push", &remote, &on_conflict, token.as_deref())
+                    .await
+            }
+            SyncCommands::Peers => client.list_sync_peers().await,
+        },
+
+        // Template commands
+        Commands::Template { action } => match action {
+            TemplateCommands::List => client.list_templates().await,
+            TemplateCommands::Get { id } => client.get_template(&id).await,
+            TemplateCommands::Create {
+                name,
+                description,
+                project,
+                from_toml,
+            } => {
+                let base = if let Some(ref path) = from_toml {
+                    match load_template_toml(path).await {
+                        Ok(t) => Some(t),
+                        Err(e) => {
+                            eprintln!("Error: {}", e);
+                            return ExitCode::FAILURE;
+                        }
+                    }
+                } else {
+                    None
+                };
+
+                // CLI flags take precedence over TOML values
+                let name = name.or(base.as_ref().and_then(|t| t.name.clone()));
+                let description = description.or(base.as_ref().and_then(|t| t.description.clone()));
+                let Some(name) = name else {
+                    eprintln!(
+                        "Error: --name required. Provide via CLI flag or include in the --from-toml file."
+                    );
+                    return ExitCode::FAILURE;
+                };
+                let structure = base.map(TemplateToml::into_structure);
+
+                client
+                    .create_template(
+                        &name,
+                        description.as_deref(),
+                        project.as_deref(),
+                        structure.as_ref(),
+                    )
+                    .await
+            }
+            TemplateCommands::Delete { id } => client.delete_template(&id).await,
         },
     };
 
@@ -427,6 +849,10 @@ async fn main() -> ExitCode {
         }
     };
 
+    let has_problems = value["problems"]
+        .as_array()
+        .is_some_and(|problems| !problems.is_empty());
+
     // Handle output phase — JSON always printed first, TOML file write is advisory.
     // All errors here are crate::client::error::ClientError, so ? works cleanly.
     let output_result: CliClientResult<()> = async {
@@ -454,6 +880,7 @@ async fn main() -> ExitCode {
     .await;
 
     match output_result {
+        Ok(()) if verifying && has_problems => ExitCode::FAILURE,
         Ok(()) => ExitCode::SUCCESS,
         Err(e) => {
             eprintln!("Error: {}", e);
@@ -505,6 +932,24 @@ fn discover_server_url() -> String {
     }
 }
 
+/// Serve MCP over stdio against the discovered server.
+async fn run_mcp(client: Client) -> ExitCode {
+    let server = McpServer::new(client);
+    match server
+        .serve(
+            tokio::io::BufReader::new(tokio::io::stdin()),
+            tokio::io::stdout(),
+        )
+        .await
+    {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: MCP transport failed: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
 /// Launch the Tauri desktop app for the current repository.
 fn launch_desktop() -> ExitCode {
     let repo_root = match pm_config::Config::config_dir() {
@@ -716,6 +1161,20 @@ fn homogenize_arrays(value: serde_json::Value) -> serde_json::Value {
     }
 }
 
+/// Text given inline, or read from a file (`--content-file`, `--file`)
+async fn read_content(
+    content: Option<String>,
+    content_file: Option<String>,
+) -> CliClientResult<Option<String>> {
+    match content_file {
+        Some(path) => tokio::fs::read_to_string(path)
+            .await
+            .map(Some)
+            .map_err(ClientError::from_io),
+        None => Ok(content),
+    }
+}
+
 /// Load a WorkItemToml from a TOML file at the given path.
 ///
 /// Returns a populated struct with all Option fields. Fields absent from the file
@@ -738,10 +1197,10 @@ async fn load_work_item_toml(path: &str) -> CliClientResult<WorkItemToml> {
     // Hand-written flat TOML (no `work_item` key) passes through unchanged.
     let mut value: toml::Value = toml::from_str(&content).map_err(ClientError::from_toml)?;
 
-    if let toml::Value::Table(ref mut outer) = value {
-        if let Some(inner @ toml::Value::Table(_)) = outer.remove("work_item") {
-            value = inner;
-        }
+    if let toml::Value::Table(ref mut outer) = value
+        && let Some(inner @ toml::Value::Table(_)) = outer.remove("work_item")
+    {
+        value = inner;
     }
 
     // Re-serialise to string then parse into WorkItemToml.
@@ -749,3 +1208,23 @@ async fn load_work_item_toml(path: &str) -> CliClientResult<WorkItemToml> {
     let adjusted = toml::to_string(&value).map_err(ClientError::from_toml_ser)?;
     toml::from_str(&adjusted).map_err(ClientError::from_toml)
 }
+
+/// Load a TemplateToml from a TOML file at the given path.
+///
+/// Accepts a hand-written file or the `--output-toml` output of
+/// `template get`, whose fields are nested under a `[template]` section.
+async fn load_template_toml(path: &str) -> CliClientResult<TemplateToml> {
+    let content = tokio::fs::read_to_string(path)
+        .await
+        .map_err(ClientError::from_io)?;
+
+    let mut value: toml::Value = toml::from_str(&content).map_err(ClientError::from_toml)?;
+
+    if let toml::Value::Table(ref mut outer) = value
+        && let Some(inner @ toml::Value::Table(_)) = outer.remove("template")
+    {
+        value = inner;
+    }
+
+    value.try_into().map_err(ClientError::from_toml)
+}
@@ -0,0 +1,73 @@
+//! Typed access to `tools/call` arguments
+
+use crate::mcp::protocol::RpcError;
+
+use serde_json::{Map, Value};
+
+/// Tool arguments with type-checked accessors. Missing required values and
+/// values of the wrong type are reported as invalid params.
+pub struct Arguments<'a> {
+    map: &'a Map<String, Value>,
+}
+
+impl<'a> Arguments<'a> {
+    pub fn new(map: &'a Map<String, Value>) -> Self {
+        Self { map }
+    }
+
+    /// True when the key was passed at all, even as null or ""
+    pub fn contains(&self, key: &str) -> bool {
+        self.map.contains_key(key)
+    }
+
+    pub fn str(&self, key: &str) -> Result<&'a str, RpcError> {
+        self.opt_str(key)?
+            .ok_or_else(|| RpcError::invalid_params(format!("Missing required argument '{}'", key)))
+    }
+
+    pub fn opt_str(&self, key: &str) -> Result<Option<&'a str>, RpcError> {
+        match self.map.get(key) {
+            None | Some(Value::Null) => Ok(None),
+            Some(Value::String(s)) => Ok(Some(s.as_str())),
+            Some(_) => Err(type_error(key, "a string")),
+        }
+    }
+
+    pub fn i64(&self, key: &str) -> Result<i64, RpcError> {
+        self.opt_i64(key)?
+            .ok_or_else(|| RpcError::invalid_params(format!("Missing required argument '{}'", key)))
+    }
+
+    pub fn opt_i64(&self, key: &str) -> Result<Option<i64>, RpcError> {
+        match self.map.get(key) {
+            None | Some(Value::Null) => Ok(None),
+            Some(v) => v
+                .as_i64()
+                .map(Some)
+                .ok_or_else(|| type_error(key, "an integer")),
+        }
+    }
+
+    pub fn i32(&self, key: &str) -> Result<i32, RpcError> {
+        self.opt_i32(key)?
+            .ok_or_else(|| RpcError::invalid_params(format!("Missing required argument '{}'", key)))
+    }
+
+    pub fn opt_i32(&self, key: &str) -> Result<Option<i32>, RpcError> {
+        self.opt_i64(key)?
+            .map(|v| i32::try_from(v).map_err(|_| type_error(key, "a 32-bit integer")))
+            .transpose()
+    }
+
+    pub fn bool(&self, key: &str) -> Result<bool, RpcError> {
+        match self.map.get(key) {
+            None | Some(Value::Null) => Ok(false),
+            Some(Value::Bool(b)) => Ok(*b),
+            Some(_) => Err(type_error(key, "a boolean")),
+        }
+    }
+}
+
+fn type_error(key: &str, expected: &str) -> RpcError {
+    RpcError::invalid_params(format!("Argument '{}' must be {}", key, expected))
+}
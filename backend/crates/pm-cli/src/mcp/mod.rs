@@ -0,0 +1,7 @@
+pub(crate) mod arguments;
+pub(crate) mod protocol;
+pub(crate) mod resources;
+pub(crate) mod server;
+pub(crate) mod tools;
+
+pub use server::McpServer;
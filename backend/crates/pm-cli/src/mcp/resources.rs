@@ -0,0 +1,89 @@
+//! MCP resources: one per `pm_llm_context` entry
+
+use crate::{
+    Client, ClientError,
+    mcp::protocol::{INTERNAL_ERROR, RESOURCE_NOT_FOUND, RpcError},
+};
+
+use serde_json::{Value, json};
+
+const LLM_CONTEXT_URI_PREFIX: &str = "pm://llm-context/";
+const MIME_TYPE: &str = "text/markdown";
+
+async fn fetch_entries(client: &Client) -> Result<Vec<Value>, RpcError> {
+    let body = client
+        .list_llm_context(None, None, None, None)
+        .await
+        .map_err(|e: ClientError| RpcError::new(INTERNAL_ERROR, e.to_string()))?;
+    Ok(body
+        .get("entries")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default())
+}
+
+fn field<'a>(entry: &'a Value, key: &str) -> &'a str {
+    entry.get(key).and_then(Value::as_str).unwrap_or_default()
+}
+
+/// Render an entry as markdown: title, content, then the example query
+fn render(entry: &Value) -> String {
+    let mut text = format!(
+        "# {}\n\n{}\n",
+        field(entry, "title"),
+        field(entry, "content")
+    );
+    let example_sql = field(entry, "example_sql");
+    if !example_sql.is_empty() {
+        text.push_str(&format!("\n```sql\n{}\n```\n", example_sql));
+    }
+    let example_description = field(entry, "example_description");
+    if !example_description.is_empty() {
+        text.push_str(&format!("\n{}\n", example_description));
+    }
+    text
+}
+
+/// `resources/list` result
+pub async fn list_resources(client: &Client) -> Result<Value, RpcError> {
+    let resources: Vec<Value> = fetch_entries(client)
+        .await?
+        .iter()
+        .map(|entry| {
+            json!({
+                "uri": format!("{}{}", LLM_CONTEXT_URI_PREFIX, field(entry, "id")),
+                "name": field(entry, "title"),
+                "description": format!(
+                    "{} ({})",
+                    field(entry, "category"),
+                    field(entry, "context_type")
+                ),
+                "mimeType": MIME_TYPE,
+            })
+        })
+        .collect();
+
+    Ok(json!({ "resources": resources }))
+}
+
+/// `resources/read` result for a `pm://llm-context/<id>` URI
+pub async fn read_resource(client: &Client, uri: &str) -> Result<Value, RpcError> {
+    let not_found = || RpcError::new(RESOURCE_NOT_FOUND, format!("Resource not found: {}", uri));
+    let id = uri
+        .strip_prefix(LLM_CONTEXT_URI_PREFIX)
+        .ok_or_else(not_found)?;
+
+    let entries = fetch_entries(client).await?;
+    let entry = entries
+        .iter()
+        .find(|e| field(e, "id") == id)
+        .ok_or_else(not_found)?;
+
+    Ok(json!({
+        "contents": [{
+            "uri": uri,
+            "mimeType": MIME_TYPE,
+            "text": render(entry),
+        }],
+    }))
+}
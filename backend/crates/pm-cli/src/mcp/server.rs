@@ -0,0 +1,169 @@
+//! MCP server over newline-delimited JSON-RPC
+//!
+//! Reads one JSON-RPC message per line and writes one response per line.
+//! Nothing else may be written to the output stream, so diagnostics go to
+//! stderr.
+
+use crate::{
+    Client, ClientError,
+    mcp::{
+        arguments::Arguments,
+        protocol::{
+            INVALID_REQUEST, METHOD_NOT_FOUND, PARSE_ERROR, RpcError, error_response,
+            negotiate_protocol_version, success_response,
+        },
+        resources::{list_resources, read_resource},
+        tools::{call_tool, tool_specs},
+    },
+};
+
+use serde_json::{Map, Value, json};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt};
+
+const SERVER_NAME: &str = "pm";
+
+/// MCP server exposing the pm REST API as tools and LLM context as resources
+pub struct McpServer {
+    client: Client,
+}
+
+impl McpServer {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Serve until the input stream closes
+    pub async fn serve<R, W>(&self, reader: R, mut writer: W) -> std::io::Result<()>
+    where
+        R: AsyncBufRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        let mut lines = reader.lines();
+        while let Some(line) = lines.next_line().await? {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let response = match serde_json::from_str::<Value>(&line) {
+                Ok(message) => self.handle_message(message).await,
+                Err(e) => Some(error_response(
+                    Value::Null,
+                    RpcError::new(PARSE_ERROR, format!("Parse error: {}", e)),
+                )),
+            };
+
+            if let Some(response) = response {
+                let mut bytes = serde_json::to_vec(&response)?;
+                bytes.push(b'\n');
+                writer.write_all(&bytes).await?;
+                writer.flush().await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Handle one message. Notifications (no `id`) get no response.
+    pub async fn handle_message(&self, message: Value) -> Option<Value> {
+        let Some(object) = message.as_object() else {
+            return Some(error_response(
+                Value::Null,
+                RpcError::new(INVALID_REQUEST, "Expected a JSON-RPC object"),
+            ));
+        };
+        let id = object.get("id").cloned();
+        let method = object.get("method").and_then(Value::as_str);
+
+        let (Some(method), Some("2.0")) = (method, object.get("jsonrpc").and_then(Value::as_str))
+        else {
+            return id.map(|id| {
+                error_response(
+                    id,
+                    RpcError::new(INVALID_REQUEST, "Expected jsonrpc \"2.0\" and a method"),
+                )
+            });
+        };
+
+        let empty = Map::new();
+        let params = object
+            .get("params")
+            .and_then(Value::as_object)
+            .unwrap_or(&empty);
+
+        // Notifications: initialized, cancelled, ... need no reply
+        let id = id?;
+
+        Some(match self.dispatch(method, params).await {
+            Ok(result) => success_response(id, result),
+            Err(error) => error_response(id, error),
+        })
+    }
+
+    async fn dispatch(&self, method: &str, params: &Map<String, Value>) -> Result<Value, RpcError> {
+        match method {
+            "initialize" => Ok(json!({
+                "protocolVersion": negotiate_protocol_version(
+                    params.get("protocolVersion").and_then(Value::as_str)
+                ),
+                "capabilities": {
+                    "tools": { "listChanged": false },
+                    "resources": { "listChanged": false, "subscribe": false },
+                },
+                "serverInfo": {
+                    "name": SERVER_NAME,
+                    "version": env!("CARGO_PKG_VERSION"),
+                },
+                "instructions": "Tools manage projects, work items, sprints, comments, \
+                    dependencies and time entries. Read the pm://llm-context resources for \
+                    schema documentation and business rules.",
+            })),
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(json!({
+                "tools": tool_specs().iter().map(|t| t.to_json()).collect::<Vec<_>>(),
+            })),
+            "tools/call" => self.call_tool(params).await,
+            "resources/list" => list_resources(&self.client).await,
+            "resources/templates/list" => Ok(json!({ "resourceTemplates": [] })),
+            "resources/read" => {
+                let uri = Arguments::new(params).str("uri")?;
+                read_resource(&self.client, uri).await
+            }
+            other => Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("Method not found: {}", other),
+            )),
+        }
+    }
+
+    /// API failures are tool results with `isError`, so the model can react
+    async fn call_tool(&self, params: &Map<String, Value>) -> Result<Value, RpcError> {
+        let empty = Map::new();
+        let name = Arguments::new(params).str("name")?;
+        let arguments = params
+            .get("arguments")
+            .and_then(Value::as_object)
+            .unwrap_or(&empty);
+
+        match call_tool(&self.client, name, &Arguments::new(arguments)).await? {
+            Ok(value) => Ok(json!({
+                "content": [{
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&value).unwrap_or_default(),
+                }],
+                "structuredContent": value,
+                "isError": false,
+            })),
+            Err(e) => Ok(json!({
+                "content": [{ "type": "text", "text": error_text(&e) }],
+                "isError": true,
+            })),
+        }
+    }
+}
+
+/// API errors without the source location noise
+fn error_text(error: &ClientError) -> String {
+    match error {
+        ClientError::Api { code, message, .. } => format!("{}: {}", code, message),
+        other => other.to_string(),
+    }
+}
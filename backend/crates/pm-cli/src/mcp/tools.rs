@@ -0,0 +1,487 @@
+//! MCP tools backed by the REST client
+//!
+//! Each tool maps onto one `Client` method. Input schemas are typed so
+//! agents can fill arguments without reading CLI help text.
+
+use crate::{
+    CliClientResult, Client, ListOptions,
+    mcp::{arguments::Arguments, protocol::RpcError},
+};
+
+use serde_json::{Value, json};
+
+const ITEM_TYPES: [&str; 3] = ["epic", "story", "task"];
+const STATUSES: [&str; 6] = [
+    "backlog",
+    "todo",
+    "in_progress",
+    "review",
+    "done",
+    "blocked",
+];
+const PRIORITIES: [&str; 4] = ["low", "medium", "high", "critical"];
+const SPRINT_STATUSES: [&str; 3] = ["planned", "active", "completed"];
+const DEPENDENCY_TYPES: [&str; 2] = ["blocks", "relates_to"];
+
+/// Name, description and JSON Schema of one tool
+pub struct ToolSpec {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub input_schema: Value,
+}
+
+impl ToolSpec {
+    fn new(
+        name: &'static str,
+        description: &'static str,
+        properties: Value,
+        required: &[&str],
+    ) -> Self {
+        Self {
+            name,
+            description,
+            input_schema: json!({
+                "type": "object",
+                "properties": properties,
+                "required": required,
+                "additionalProperties": false,
+            }),
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "name": self.name,
+            "description": self.description,
+            "inputSchema": self.input_schema,
+        })
+    }
+}
+
+fn string(description: &str) -> Value {
+    json!({ "type": "string", "description": description })
+}
+
+fn integer(description: &str) -> Value {
+    json!({ "type": "integer", "description": description })
+}
+
+fn boolean(description: &str) -> Value {
+    json!({ "type": "boolean", "description": description })
+}
+
+fn one_of(values: &[&str], description: &str) -> Value {
+    json!({ "type": "string", "enum": values, "description": description })
+}
+
+fn id(description: &str) -> Value {
+    json!({ "id": string(description) })
+}
+
+/// Add the paging properties shared by list tools
+fn paged(mut properties: Value) -> Value {
+    if let Some(map) = properties.as_object_mut() {
+        map.insert("limit".into(), integer("Items per page"));
+        map.insert(
+            "cursor".into(),
+            string("next_cursor from the previous page, to fetch the next one"),
+        );
+        map.insert(
+            "sort".into(),
+            string("Comma-separated sort fields; prefix with '-' for descending"),
+        );
+        map.insert(
+            "fields".into(),
+            string("Comma-separated fields to return, to keep responses small"),
+        );
+    }
+    properties
+}
+
+fn list_options(args: &Arguments) -> Result<ListOptions, RpcError> {
+    Ok(ListOptions {
+        limit: args.opt_i64("limit")?.map(|n| n.max(0) as usize),
+        cursor: args.opt_str("cursor")?.map(str::to_string),
+        sort: args.opt_str("sort")?.map(str::to_string),
+        fields: args.opt_str("fields")?.map(str::to_string),
+        all: false,
+    })
+}
+
+/// Every tool the server offers, in `tools/list` order
+pub fn tool_specs() -> Vec<ToolSpec> {
+    vec![
+        // Projects
+        ToolSpec::new(
+            "list_projects",
+            "List projects, one page at a time",
+            paged(json!({})),
+            &[],
+        ),
+        ToolSpec::new(
+            "get_project",
+            "Get a project by ID or key",
+            id("Project UUID or key like \"PONE\""),
+            &["id"],
+        ),
+        ToolSpec::new(
+            "create_project",
+            "Create a project",
+            json!({
+                "title": string("Project title"),
+                "key": string("Short uppercase key used in display keys, e.g. \"PONE\""),
+                "description": string("Project description"),
+                "template_id": string("Template UUID or name to start the project from"),
+            }),
+            &["title", "key"],
+        ),
+        // Work items
+        ToolSpec::new(
+            "list_work_items",
+            "List work items in a project, one page at a time. Done items are excluded \
+             unless include_done is set.",
+            paged(json!({
+                "project_id": string("Project UUID or key"),
+                "item_type": one_of(&ITEM_TYPES, "Filter by type"),
+                "status": one_of(&STATUSES, "Filter by status"),
+                "parent_id": string("Filter by parent (UUID or display key)"),
+                "include_done": boolean("Include items with status 'done'"),
+            })),
+            &["project_id"],
+        ),
+        ToolSpec::new(
+            "get_work_item",
+            "Get a work item by ID or display key",
+            id("Work item UUID or display key like \"PONE-12\""),
+            &["id"],
+        ),
+        ToolSpec::new(
+            "get_work_item_context",
+            "Brief yourself on a work item in one call: the item, its ancestors, siblings, \
+             blockers, recent comments, activity summary and the project's rules",
+            json!({
+                "id": string("Work item UUID or display key like \"PONE-12\""),
+                "max_chars": integer("Size budget; lower-value sections are dropped to fit"),
+            }),
+            &["id"],
+        ),
+        ToolSpec::new(
+            "create_work_item",
+            "Create an epic, story or task",
+            json!({
+                "project_id": string("Project UUID"),
+                "item_type": one_of(&ITEM_TYPES, "Work item type"),
+                "title": string("Title"),
+                "description": string("Markdown description"),
+                "parent_id": string("Parent work item UUID"),
+                "status": one_of(&STATUSES, "Initial status (default: backlog)"),
+                "priority": one_of(&PRIORITIES, "Priority (default: medium)"),
+                "original_estimate_seconds": integer("Original estimate in seconds"),
+            }),
+            &["project_id", "item_type", "title"],
+        ),
+        ToolSpec::new(
+            "update_work_item",
+            "Update a work item. Only the given fields change. Pass parent_id \"\" to clear the parent.",
+            json!({
+                "id": string("Work item UUID or display key"),
+                "expected_version": integer("Current version, for optimistic locking"),
+                "title": string("New title"),
+                "description": string("New description"),
+                "status": one_of(&STATUSES, "New status"),
+                "priority": one_of(&PRIORITIES, "New priority"),
+                "assignee_id": string("Assignee user UUID, or \"\" to unassign"),
+                "sprint_id": string("Sprint UUID, or \"\" to remove from the sprint"),
+                "story_points": integer("Story points (0-100)"),
+                "parent_id": string("New parent (UUID or display key), or \"\" for none"),
+                "original_estimate_seconds": integer("New original estimate in seconds"),
+                "remaining_estimate_seconds": integer("New remaining estimate in seconds"),
+            }),
+            &["id", "expected_version"],
+        ),
+        ToolSpec::new(
+            "delete_work_item",
+            "Delete a work item",
+            id("Work item UUID or display key"),
+            &["id"],
+        ),
+        // Sprints
+        ToolSpec::new(
+            "list_sprints",
+            "List sprints in a project",
+            paged(json!({ "project_id": string("Project UUID or key") })),
+            &["project_id"],
+        ),
+        ToolSpec::new(
+            "get_sprint",
+            "Get a sprint by ID",
+            id("Sprint UUID"),
+            &["id"],
+        ),
+        ToolSpec::new(
+            "create_sprint",
+            "Create a sprint",
+            json!({
+                "project_id": string("Project UUID or key"),
+                "name": string("Sprint name"),
+                "start_date": integer("Start (Unix timestamp, seconds)"),
+                "end_date": integer("End (Unix timestamp, seconds)"),
+                "goal": string("Sprint goal"),
+            }),
+            &["project_id", "name", "start_date", "end_date"],
+        ),
+        ToolSpec::new(
+            "update_sprint",
+            "Update a sprint. Only the given fields change.",
+            json!({
+                "id": string("Sprint UUID"),
+                "expected_version": integer("Current version, for optimistic locking"),
+                "name": string("New name"),
+                "goal": string("New goal"),
+                "start_date": integer("New start (Unix timestamp, seconds)"),
+                "end_date": integer("New end (Unix timestamp, seconds)"),
+                "status": one_of(&SPRINT_STATUSES, "New status"),
+            }),
+            &["id", "expected_version"],
+        ),
+        // Comments
+        ToolSpec::new(
+            "list_comments",
+            "List comments on a work item",
+            paged(json!({ "work_item_id": string("Work item UUID or display key") })),
+            &["work_item_id"],
+        ),
+        ToolSpec::new(
+            "create_comment",
+            "Add a comment to a work item",
+            json!({
+                "work_item_id": string("Work item UUID or display key"),
+                "content": string("Comment text (markdown)"),
+            }),
+            &["work_item_id", "content"],
+        ),
+        // Dependencies
+        ToolSpec::new(
+            "list_dependencies",
+            "List dependencies of a work item (both blocking and blocked)",
+            json!({ "work_item_id": string("Work item UUID or display key") }),
+            &["work_item_id"],
+        ),
+        ToolSpec::new(
+            "create_dependency",
+            "Link two work items",
+            json!({
+                "blocking_item_id": string("Work item that blocks (UUID)"),
+                "blocked_item_id": string("Work item that is blocked (UUID)"),
+                "dependency_type": one_of(&DEPENDENCY_TYPES, "Kind of link"),
+            }),
+            &["blocking_item_id", "blocked_item_id", "dependency_type"],
+        ),
+        ToolSpec::new(
+            "delete_dependency",
+            "Remove a dependency",
+            id("Dependency UUID"),
+            &["id"],
+        ),
+        // Time entries
+        ToolSpec::new(
+            "list_time_entries",
+            "List time entries on a work item",
+            json!({ "work_item_id": string("Work item UUID or display key") }),
+            &["work_item_id"],
+        ),
+        ToolSpec::new(
+            "start_timer",
+            "Start a timer on a work item (stops any other running timer)",
+            json!({
+                "work_item_id": string("Work item UUID or display key"),
+                "description": string("What is being worked on"),
+            }),
+            &["work_item_id"],
+        ),
+        ToolSpec::new(
+            "stop_timer",
+            "Stop a running timer. The work item's remaining estimate is reduced by the \
+             logged time unless remaining_estimate_seconds is given.",
+            json!({
+                "id": string("Time entry UUID"),
+                "remaining_estimate_seconds": integer("Remaining estimate to set instead"),
+            }),
+            &["id"],
+        ),
+        // Read-only SQL
+        ToolSpec::new(
+            "run_query",
+            "Run a single read-only SELECT against the projects you are a member of. \
+             The pm://llm-context resources document the schema and example queries.",
+            json!({
+                "sql": string("A single SELECT (or WITH ... SELECT) statement"),
+                "max_rows": integer("Return at most this many rows"),
+            }),
+            &["sql"],
+        ),
+    ]
+}
+
+/// Run a tool. The outer error means the call itself was malformed (unknown
+/// tool, bad arguments); the inner result is the API outcome.
+pub async fn call_tool(
+    client: &Client,
+    name: &str,
+    args: &Arguments<'_>,
+) -> Result<CliClientResult<Value>, RpcError> {
+    let result = match name {
+        "list_projects" => client.list_projects(&list_options(args)?).await,
+        "get_project" => client.get_project(args.str("id")?).await,
+        "create_project" => {
+            client
+                .create_project(
+                    args.str("title")?,
+                    args.str("key")?,
+                    args.opt_str("description")?,
+                    args.opt_str("template_id")?,
+                )
+                .await
+        }
+
+        "list_work_items" => {
+            client
+                .list_work_items(
+                    args.str("project_id")?,
+                    args.opt_str("item_type")?,
+                    args.opt_str("status")?,
+                    args.opt_str("parent_id")?,
+                    false,
+                    None,
+                    None,
+                    args.bool("include_done")?,
+                    &[],
+                    &list_options(args)?,
+                )
+                .await
+        }
+        "get_work_item" => client.get_work_item(args.str("id")?).await,
+        "get_work_item_context" => {
+            let max_chars = args.opt_i64("max_chars")?.map(|n| n.max(0) as usize);
+            client.work_item_context(args.str("id")?, max_chars).await
+        }
+        "create_work_item" => {
+            client
+                .create_work_item(
+                    args.str("project_id")?,
+                    args.str("item_type")?,
+                    args.str("title")?,
+                    args.opt_str("description")?,
+                    args.opt_str("parent_id")?,
+                    args.opt_str("status")?,
+                    args.opt_str("priority")?,
+                    &[],
+                    args.opt_i32("original_estimate_seconds")?,
+                )
+                .await
+        }
+        "update_work_item" => {
+            client
+                .update_work_item(
+                    args.str("id")?,
+                    args.opt_str("title")?,
+                    args.opt_str("description")?,
+                    args.opt_str("status")?,
+                    args.opt_str("priority")?,
+                    args.opt_str("assignee_id")?,
+                    args.opt_str("sprint_id")?,
+                    args.opt_i32("story_points")?,
+                    args.opt_str("parent_id")?,
+                    args.contains("parent_id"),
+                    None,
+                    &[],
+                    args.opt_i32("original_estimate_seconds")?,
+                    args.opt_i32("remaining_estimate_seconds")?,
+                    false,
+                    args.i32("expected_version")?,
+                )
+                .await
+        }
+        "delete_work_item" => client.delete_work_item(args.str("id")?).await,
+
+        "list_sprints" => {
+            client
+                .list_sprints(args.str("project_id")?, &list_options(args)?)
+                .await
+        }
+        "get_sprint" => client.get_sprint(args.str("id")?).await,
+        "create_sprint" => {
+            client
+                .create_sprint(
+                    args.str("project_id")?,
+                    args.str("name")?,
+                    args.i64("start_date")?,
+                    args.i64("end_date")?,
+                    args.opt_str("goal")?,
+                )
+                .await
+        }
+        "update_sprint" => {
+            client
+                .update_sprint(
+                    args.str("id")?,
+                    args.opt_str("name")?,
+                    args.opt_str("goal")?,
+                    args.opt_i64("start_date")?,
+                    args.opt_i64("end_date")?,
+                    args.opt_str("status")?,
+                    args.i32("expected_version")?,
+                )
+                .await
+        }
+
+        "list_comments" => {
+            client
+                .list_comments(args.str("work_item_id")?, &list_options(args)?)
+                .await
+        }
+        "create_comment" => {
+            client
+                .create_comment(args.str("work_item_id")?, args.str("content")?)
+                .await
+        }
+
+        "list_dependencies" => client.list_dependencies(args.str("work_item_id")?).await,
+        "create_dependency" => {
+            client
+                .create_dependency(
+                    args.str("blocking_item_id")?,
+                    args.str("blocked_item_id")?,
+                    args.str("dependency_type")?,
+                )
+                .await
+        }
+        "delete_dependency" => client.delete_dependency(args.str("id")?).await,
+
+        "list_time_entries" => client.list_time_entries(args.str("work_item_id")?).await,
+        "start_timer" => {
+            client
+                .create_time_entry(args.str("work_item_id")?, args.opt_str("description")?)
+                .await
+        }
+        "stop_timer" => {
+            client
+                .update_time_entry(
+                    args.str("id")?,
+                    Some(true),
+                    None,
+                    args.opt_i32("remaining_estimate_seconds")?,
+                )
+                .await
+        }
+
+        "run_query" => {
+            let max_rows = args.opt_i64("max_rows")?.map(|n| n.max(0) as usize);
+            client.run_query(args.str("sql")?, max_rows).await
+        }
+
+        other => return Err(RpcError::invalid_params(format!("Unknown tool: {}", other))),
+    };
+
+    Ok(result)
+}
@@ -1,9 +1,14 @@
+use crate::list_args::ListArgs;
+
 use clap::Subcommand;
 
 #[derive(Subcommand)]
 pub enum ProjectCommands {
-    /// List all projects
-    List,
+    /// List projects
+    List {
+        #[command(flatten)]
+        page: ListArgs,
+    },
 
     /// Get a project by ID
     Get {
@@ -24,6 +29,11 @@ pub enum ProjectCommands {
         /// Optional description
         #[arg(long)]
         description: Option<String>,
+
+        /// Template to create custom fields, work items and LLM context from
+        /// (UUID or name)
+        #[arg(long)]
+        template: Option<String>,
     },
 
     /// Update a project
@@ -46,6 +56,21 @@ pub enum ProjectCommands {
         expected_version: i32,
     },
 
+    /// Change a project's key (admins only). The old key and display keys
+    /// built on it keep resolving.
+    RenameKey {
+        /// Project ID (UUID or project key like "PONE")
+        id: String,
+
+        /// New project key: 1-10 letters
+        #[arg(long)]
+        key: String,
+
+        /// Expected version (required for optimistic locking)
+        #[arg(long)]
+        expected_version: i32,
+    },
+
     /// Delete a project
     Delete {
         /// Project ID (UUID or project key like "PONE")
@@ -0,0 +1,32 @@
+use clap::{ArgGroup, Subcommand};
+
+#[derive(Subcommand)]
+pub enum ScheduleCommands {
+    /// Critical path, slack and Gantt offsets over `blocks` dependencies
+    #[command(group(
+        ArgGroup::new("scope")
+            .args(["project_id", "epic_id", "sprint_id"])
+            .required(true)
+    ))]
+    CriticalPath {
+        /// Schedule every item in a project (UUID or project key like "PONE")
+        #[arg(long)]
+        project_id: Option<String>,
+
+        /// Schedule the descendants of an epic (UUID or display key like "PONE-1")
+        #[arg(long)]
+        epic_id: Option<String>,
+
+        /// Schedule the items in a sprint (UUID)
+        #[arg(long)]
+        sprint_id: Option<String>,
+
+        /// Use remaining estimates or story points as item durations
+        #[arg(long, default_value = "estimate", value_parser = ["estimate", "story_points"])]
+        weight: String,
+
+        /// Include work items with status 'done' (excluded by default)
+        #[arg(long)]
+        include_done: bool,
+    },
+}
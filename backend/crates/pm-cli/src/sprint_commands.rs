@@ -1,3 +1,5 @@
+use crate::list_args::ListArgs;
+
 use clap::Subcommand;
 
 #[derive(Subcommand)]
@@ -6,6 +8,9 @@ pub enum SprintCommands {
     List {
         /// Project ID (UUID or project key like "PONE")
         project_id: String,
+
+        #[command(flatten)]
+        page: ListArgs,
     },
 
     /// Get a sprint by ID
@@ -14,6 +19,12 @@ pub enum SprintCommands {
         id: String,
     },
 
+    /// Show estimate totals for a sprint (original, remaining, logged)
+    Summary {
+        /// Sprint ID (UUID)
+        id: String,
+    },
+
     /// Create a new sprint
     Create {
         /// Project ID (UUID or project key like "PONE")
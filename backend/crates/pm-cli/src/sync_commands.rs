@@ -18,7 +18,44 @@ pub enum SyncCommands {
         /// Input JSON file path
         #[arg(short, long)]
         file: String,
+
+        /// Report what would change without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Pull changes from another pm-server since the last pull
+    Pull {
+        /// Base URL of the other server, e.g. http://10.0.0.5:8000
+        #[arg(long)]
+        remote: String,
+
+        /// Records changed on both sides: list them, keep ours, or take theirs
+        #[arg(long, default_value = "report", value_parser = ["report", "ours", "theirs"])]
+        on_conflict: String,
+
+        /// Bearer token for the other server, when it has auth enabled
+        #[arg(long)]
+        token: Option<String>,
     },
+
+    /// Push local changes to another pm-server since the last push
+    Push {
+        /// Base URL of the other server, e.g. http://10.0.0.5:8000
+        #[arg(long)]
+        remote: String,
+
+        /// Records changed on both sides: list them, keep ours, or take theirs
+        #[arg(long, default_value = "report", value_parser = ["report", "ours", "theirs"])]
+        on_conflict: String,
+
+        /// Bearer token for the other server, when it has auth enabled
+        #[arg(long)]
+        token: Option<String>,
+    },
+
+    /// List servers synced with and how far each direction has got
+    Peers,
 }
 
 #[derive(Subcommand)]
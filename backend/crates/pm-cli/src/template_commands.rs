@@ -0,0 +1,45 @@
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+pub enum TemplateCommands {
+    /// List project templates
+    List,
+
+    /// Get a template by ID
+    Get {
+        /// Template ID (UUID or name)
+        id: String,
+    },
+
+    /// Save a template from a project or from a TOML file
+    Create {
+        /// Unique template name (can instead be in the TOML file)
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Optional description
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Project to copy custom fields, work items and LLM context from
+        /// (UUID or project key like "PONE")
+        #[arg(
+            long,
+            conflicts_with = "from_toml",
+            required_unless_present = "from_toml"
+        )]
+        project: Option<String>,
+
+        /// Read the structure from a TOML file with [[custom_fields]],
+        /// [[work_items]] and [[llm_context]] tables, or from the output of
+        /// `template get --output-toml`
+        #[arg(long, value_name = "PATH")]
+        from_toml: Option<String>,
+    },
+
+    /// Delete a template. Projects created from it are not affected.
+    Delete {
+        /// Template ID (UUID or name)
+        id: String,
+    },
+}
@@ -0,0 +1,55 @@
+use serde_json::{Value, json};
+
+/// A project template loaded from a TOML file for `template create`.
+///
+/// Hand-written files put the structure at the top level:
+///
+/// ```toml
+/// name = "scrum"
+/// description = "Starter backlog"
+///
+/// [[custom_fields]]
+/// name = "severity"
+/// field_type = "single_select"
+/// options = ["low", "high"]
+///
+/// [[work_items]]
+/// number = 1
+/// item_type = "epic"
+/// title = "Onboarding"
+///
+/// [[work_items]]
+/// number = 2
+/// parent = 1
+/// item_type = "story"
+/// title = "Sign-up flow"
+/// ```
+///
+/// Files written by `template get --output-toml` nest it under `structure`
+/// instead; server-only fields (`id`, `created_at`, …) are ignored.
+#[derive(serde::Deserialize, Default, Debug)]
+pub struct TemplateToml {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub structure: Option<Value>,
+
+    #[serde(default)]
+    pub custom_fields: Vec<Value>,
+    #[serde(default)]
+    pub work_items: Vec<Value>,
+    #[serde(default)]
+    pub llm_context: Vec<Value>,
+}
+
+impl TemplateToml {
+    /// The structure to send to the server
+    pub fn into_structure(self) -> Value {
+        self.structure.unwrap_or_else(|| {
+            json!({
+                "custom_fields": self.custom_fields,
+                "work_items": self.work_items,
+                "llm_context": self.llm_context,
+            })
+        })
+    }
+}
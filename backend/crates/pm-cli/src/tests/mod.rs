@@ -1 +1,2 @@
 mod client;
+mod work_item_commands;
@@ -0,0 +1,24 @@
+use crate::work_item_commands::parse_duration_arg;
+
+#[test]
+fn test_parse_duration_units() {
+    assert_eq!(parse_duration_arg("2h30m"), Ok(9000));
+    assert_eq!(parse_duration_arg("90m"), Ok(5400));
+    assert_eq!(parse_duration_arg("45s"), Ok(45));
+    assert_eq!(parse_duration_arg("1h1m1s"), Ok(3661));
+}
+
+#[test]
+fn test_parse_duration_bare_seconds() {
+    assert_eq!(parse_duration_arg("3600"), Ok(3600));
+    assert_eq!(parse_duration_arg("0"), Ok(0));
+}
+
+#[test]
+fn test_parse_duration_rejects_invalid() {
+    assert!(parse_duration_arg("").is_err());
+    assert!(parse_duration_arg("-5").is_err());
+    assert!(parse_duration_arg("2d").is_err());
+    assert!(parse_duration_arg("h").is_err());
+    assert!(parse_duration_arg("1h30").is_err());
+}
@@ -0,0 +1,35 @@
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+pub enum TimeCommands {
+    /// Aggregate logged time over a date range
+    Report {
+        /// First day of the range (YYYY-MM-DD, inclusive, UTC)
+        #[arg(long)]
+        from: String,
+
+        /// Last day of the range (YYYY-MM-DD, inclusive, UTC)
+        #[arg(long)]
+        to: String,
+
+        /// Group totals by user, work item (rolled up to ancestors), or sprint
+        #[arg(long, default_value = "user", value_parser = ["user", "item", "sprint"])]
+        group_by: String,
+
+        /// Split totals into day or week buckets
+        #[arg(long, value_parser = ["day", "week"])]
+        period: Option<String>,
+
+        /// Restrict to a project (UUID or project key like "PONE")
+        #[arg(long)]
+        project_id: Option<String>,
+
+        /// Restrict to a single user's entries (UUID)
+        #[arg(long = "user")]
+        user: Option<String>,
+
+        /// Output format
+        #[arg(long, default_value = "json", value_parser = ["json", "csv"])]
+        format: String,
+    },
+}
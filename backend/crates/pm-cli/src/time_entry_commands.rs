@@ -1,3 +1,5 @@
+use crate::work_item_commands::parse_duration_arg;
+
 use clap::Subcommand;
 
 #[derive(Subcommand)]
@@ -31,6 +33,10 @@ pub enum TimeEntryCommands {
         /// Stop the running timer
         #[arg(long)]
         stop: bool,
+        /// Remaining estimate to set on the work item when stopping, instead
+        /// of subtracting the logged time (e.g. "1h30m" or seconds)
+        #[arg(long, value_parser = parse_duration_arg, requires = "stop")]
+        remaining: Option<i32>,
         /// Update description
         #[arg(long)]
         description: Option<String>,
@@ -1,4 +1,69 @@
+use crate::list_args::ListArgs;
+
 use clap::Subcommand;
+use serde_json::Value;
+
+/// Parse a `--field name=value` argument. See [`parse_cli_value`] for how the
+/// value is interpreted.
+pub fn parse_custom_field_arg(arg: &str) -> Result<(String, Value), String> {
+    let (name, raw) = arg
+        .split_once('=')
+        .ok_or_else(|| format!("expected name=value, got '{}'", arg))?;
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(format!("missing field name in '{}'", arg));
+    }
+    Ok((name.to_string(), parse_cli_value(raw)))
+}
+
+/// Read a CLI value as JSON when it parses, otherwise as a plain string
+pub fn parse_cli_value(raw: &str) -> Value {
+    serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.to_string()))
+}
+
+/// Parse a duration argument such as `2h30m`, `90m`, `45s`, or a bare number
+/// of seconds into whole seconds.
+pub fn parse_duration_arg(arg: &str) -> Result<i32, String> {
+    let arg = arg.trim();
+    if arg.is_empty() {
+        return Err("empty duration".to_string());
+    }
+    if let Ok(seconds) = arg.parse::<i32>() {
+        return if seconds < 0 {
+            Err(format!("duration must not be negative, got '{}'", arg))
+        } else {
+            Ok(seconds)
+        };
+    }
+
+    let mut total: i64 = 0;
+    let mut digits = String::new();
+    for c in arg.chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        let unit = match c {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return Err(format!("invalid duration '{}': unknown unit '{}'", arg, c)),
+        };
+        let amount: i64 = digits
+            .parse()
+            .map_err(|_| format!("invalid duration '{}': missing number before '{}'", arg, c))?;
+        total += amount * unit;
+        digits.clear();
+    }
+    if !digits.is_empty() {
+        return Err(format!(
+            "invalid duration '{}': trailing number needs a unit (h, m, s)",
+            arg
+        ));
+    }
+
+    i32::try_from(total).map_err(|_| format!("duration '{}' is too large", arg))
+}
 
 #[derive(Subcommand)]
 pub enum WorkItemCommands {
@@ -32,6 +97,14 @@ pub enum WorkItemCommands {
         #[arg(long)]
         priority: Option<String>,
 
+        /// Custom field value as name=value (repeatable)
+        #[arg(long = "field", value_name = "NAME=VALUE", value_parser = parse_custom_field_arg)]
+        fields: Vec<(String, Value)>,
+
+        /// Original estimate, e.g. "2h30m", "90m", or seconds
+        #[arg(long, value_parser = parse_duration_arg)]
+        estimate: Option<i32>,
+
         /// Load fields from a TOML file. CLI flags override file values.
         /// Required fields (project_id, type, title) can be in the file instead of CLI.
         #[arg(long, value_name = "PATH")]
@@ -76,6 +149,13 @@ pub enum WorkItemCommands {
         /// Include work items with status 'done' (excluded by default)
         #[arg(long)]
         include_done: bool,
+
+        /// Filter by custom field value as name=value (repeatable, all must match)
+        #[arg(long = "field", value_name = "NAME=VALUE", value_parser = parse_custom_field_arg)]
+        fields: Vec<(String, Value)>,
+
+        #[command(flatten)]
+        page: ListArgs,
     },
 
     /// Update a work item
@@ -123,6 +203,22 @@ pub enum WorkItemCommands {
         #[arg(long)]
         position: Option<i32>,
 
+        /// Custom field value as name=value (repeatable; name=null clears the field)
+        #[arg(long = "field", value_name = "NAME=VALUE", value_parser = parse_custom_field_arg)]
+        fields: Vec<(String, Value)>,
+
+        /// New original estimate, e.g. "2h30m", "90m", or seconds
+        #[arg(long, value_parser = parse_duration_arg, conflicts_with = "clear_estimate")]
+        estimate: Option<i32>,
+
+        /// New remaining estimate, e.g. "1h", or seconds
+        #[arg(long, value_parser = parse_duration_arg, conflicts_with = "clear_estimate")]
+        remaining: Option<i32>,
+
+        /// Clear both the original and remaining estimates
+        #[arg(long)]
+        clear_estimate: bool,
+
         /// Load fields from a TOML file. CLI flags override file values.
         /// Note: --version cannot come from the TOML file (must be on CLI).
         #[arg(long, value_name = "PATH")]
@@ -133,6 +229,59 @@ pub enum WorkItemCommands {
         version: i32,
     },
 
+    /// Assemble the item, its hierarchy, blockers, recent comments, activity
+    /// and project rules into one document for an LLM prompt
+    Context {
+        /// Work item ID (UUID or display key like "PONE-123")
+        id: String,
+
+        /// Output format
+        #[arg(long, default_value = "markdown", value_parser = ["markdown", "json"])]
+        format: String,
+
+        /// Size budget in characters; lower-value sections are dropped to fit
+        #[arg(long)]
+        max_chars: Option<usize>,
+    },
+
+    /// Reorder a work item within its project's backlog. Only the item itself
+    /// changes; give at least one of `--below` and `--above`.
+    Reorder {
+        /// Work item ID (UUID or display key like "PONE-123")
+        id: String,
+
+        /// Place directly below this item (UUID or display key)
+        #[arg(long)]
+        below: Option<String>,
+
+        /// Place directly above this item (UUID or display key)
+        #[arg(long)]
+        above: Option<String>,
+
+        /// Expected version for optimistic locking (required)
+        #[arg(long)]
+        version: i32,
+    },
+
+    /// Move a work item and its descendants to another project. Comments,
+    /// time entries and dependencies come along; old display keys still resolve.
+    Move {
+        /// Work item ID (UUID or display key like "PONE-123")
+        id: String,
+
+        /// Target project (UUID or project key like "PTWO")
+        #[arg(long)]
+        project: String,
+
+        /// New parent in the target project (UUID or display key); omit for top level
+        #[arg(long)]
+        parent_id: Option<String>,
+
+        /// Expected version for optimistic locking (required)
+        #[arg(long)]
+        version: i32,
+    },
+
     /// Delete a work item
     Delete {
         /// Work item ID (UUID or display key like "PONE-123")
@@ -1,11 +1,11 @@
 //! Integration tests for the CLI client using wiremock mock server
 
-use pm_cli::Client;
+use pm_cli::{Client, ListOptions};
 
 use serde_json::json;
 use wiremock::{
     Mock, MockServer, ResponseTemplate,
-    matchers::{body_string_contains, header, method, path, query_param},
+    matchers::{body_json, body_string_contains, header, method, path, query_param},
 };
 
 #[tokio::test]
@@ -30,7 +30,7 @@ async fn test_list_projects_success() {
         .await;
 
     let client = Client::new(&mock_server.uri(), None);
-    let result = client.list_projects().await.unwrap();
+    let result = client.list_projects(&ListOptions::default()).await.unwrap();
 
     assert!(result["projects"].is_array());
     let projects = result["projects"].as_array().unwrap();
@@ -109,6 +109,8 @@ async fn test_create_work_item_success() {
             None,
             None,
             None,
+            &[],
+            None,
         )
         .await
         .unwrap();
@@ -149,6 +151,10 @@ async fn test_update_work_item_conflict() {
             None,
             false,
             None,
+            &[],
+            None,
+            None,
+            false,
             999,
         )
         .await;
@@ -185,6 +191,8 @@ async fn test_list_work_items_with_filters() {
             None,
             None,
             false,
+            &[],
+            &ListOptions::default(),
         )
         .await
         .unwrap();
@@ -204,7 +212,7 @@ async fn test_user_id_header_sent() {
         .await;
 
     let client = Client::new(&mock_server.uri(), Some("user-123"));
-    let result = client.list_projects().await;
+    let result = client.list_projects(&ListOptions::default()).await;
 
     assert!(result.is_ok());
 }
@@ -263,3 +271,669 @@ async fn test_create_comment() {
 
     assert_eq!(result["comment"]["content"], "Test comment");
 }
+
+#[tokio::test]
+async fn test_list_work_items_with_custom_field_filter() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/api/v1/projects/00000000-0000-0000-0000-000000000001/work-items",
+        ))
+        .and(query_param("custom_field", "severity:high,estimate:3"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "work_items": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new(&mock_server.uri(), None);
+    let result = client
+        .list_work_items(
+            "00000000-0000-0000-0000-000000000001",
+            None,
+            None,
+            None,
+            false,
+            None,
+            None,
+            false,
+            &[
+                ("severity".to_string(), json!("high")),
+                ("estimate".to_string(), json!(3)),
+            ],
+            &ListOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    assert!(result["work_items"].is_array());
+}
+
+#[tokio::test]
+async fn test_list_all_follows_cursors() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/projects"))
+        .and(query_param("cursor", "page-2"))
+        .and(query_param("limit", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "projects": [{ "key": "BETA" }],
+            "total": 2,
+            "next_cursor": null
+        })))
+        .with_priority(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/projects"))
+        .and(query_param("limit", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "projects": [{ "key": "ALPHA" }],
+            "total": 2,
+            "next_cursor": "page-2"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new(&mock_server.uri(), None);
+    let options = ListOptions {
+        limit: Some(1),
+        all: true,
+        ..ListOptions::default()
+    };
+    let result = client.list_projects(&options).await.unwrap();
+
+    let keys: Vec<&str> = result["projects"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["key"].as_str().unwrap())
+        .collect();
+    assert_eq!(keys, vec!["ALPHA", "BETA"]);
+    assert_eq!(result["total"], 2);
+    assert!(result["next_cursor"].is_null());
+}
+
+#[tokio::test]
+async fn test_create_custom_field() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/api/v1/projects/00000000-0000-0000-0000-000000000001/custom-fields",
+        ))
+        .and(body_string_contains("\"field_type\":\"single_select\""))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "custom_field": {
+                "id": "00000000-0000-0000-0000-000000000002",
+                "name": "severity",
+                "field_type": "single_select",
+                "options": ["low", "high"]
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new(&mock_server.uri(), None);
+    let result = client
+        .create_custom_field(
+            "00000000-0000-0000-0000-000000000001",
+            "severity",
+            "single_select",
+            None,
+            &["low".to_string(), "high".to_string()],
+            false,
+            Some(json!("low")),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result["custom_field"]["name"], "severity");
+}
+
+#[tokio::test]
+async fn test_time_report_csv() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/time-reports"))
+        .and(query_param("from", "2026-10-01"))
+        .and(query_param("to", "2026-10-07"))
+        .and(query_param("group_by", "sprint"))
+        .and(query_param("period", "week"))
+        .and(query_param("format", "csv"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "text/csv; charset=utf-8")
+                .set_body_string(
+                    "key,label,period_start,seconds,hours,rollup_seconds,rollup_hours\n",
+                ),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new(&mock_server.uri(), None);
+    let csv = client
+        .time_report_csv(
+            "2026-10-01",
+            "2026-10-07",
+            "sprint",
+            Some("week"),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert!(csv.starts_with("key,label,period_start"));
+}
+
+#[tokio::test]
+async fn test_time_report_validation_error() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/time-reports"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "error": {
+                "code": "VALIDATION_ERROR",
+                "message": "'from' must not be after 'to'",
+                "field": "from"
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new(&mock_server.uri(), None);
+    let result = client
+        .time_report("2026-10-07", "2026-10-01", "user", None, None, None)
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_stop_time_entry_with_remaining_estimate() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path(
+            "/api/v1/time-entries/00000000-0000-0000-0000-000000000002",
+        ))
+        .and(body_string_contains("\"remaining_estimate_seconds\":5400"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "time_entry": {
+                "id": "00000000-0000-0000-0000-000000000002",
+                "ended_at": 1700000600
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new(&mock_server.uri(), None);
+    let result = client
+        .update_time_entry(
+            "00000000-0000-0000-0000-000000000002",
+            Some(true),
+            None,
+            Some(5400),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result["time_entry"]["ended_at"], 1700000600);
+}
+
+#[tokio::test]
+async fn test_get_sprint_summary() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/api/v1/sprints/00000000-0000-0000-0000-000000000003/summary",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "summary": {
+                "sprint_id": "00000000-0000-0000-0000-000000000003",
+                "item_count": 2,
+                "estimated_item_count": 2,
+                "over_estimate_item_count": 1,
+                "original_estimate_seconds": 7200,
+                "remaining_estimate_seconds": 3600,
+                "logged_seconds": 5400,
+                "over_estimate": true
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new(&mock_server.uri(), None);
+    let result = client
+        .get_sprint_summary("00000000-0000-0000-0000-000000000003")
+        .await
+        .unwrap();
+
+    assert_eq!(result["summary"]["over_estimate_item_count"], 1);
+    assert_eq!(result["summary"]["over_estimate"], true);
+}
+
+#[tokio::test]
+async fn test_critical_path_for_epic() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/schedule"))
+        .and(query_param("epic_id", "PONE-1"))
+        .and(query_param("weight", "story_points"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "scope": "epic",
+            "scope_id": "00000000-0000-0000-0000-000000000004",
+            "schedule": {
+                "weight": "story_points",
+                "unit": "points",
+                "total_duration": 8,
+                "unweighted_item_count": 0,
+                "critical_path": ["00000000-0000-0000-0000-000000000005"],
+                "items": []
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new(&mock_server.uri(), None);
+    let result = client
+        .critical_path(None, Some("PONE-1"), None, "story_points", false)
+        .await
+        .unwrap();
+
+    assert_eq!(result["scope"], "epic");
+    assert_eq!(result["schedule"]["total_duration"], 8);
+}
+
+#[tokio::test]
+async fn test_graph_returns_text() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/graph"))
+        .and(query_param("root", "PONE-1"))
+        .and(query_param("depth", "2"))
+        .and(query_param("format", "dot"))
+        .and(query_param("dependency_type", "blocks"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "text/vnd.graphviz; charset=utf-8")
+                .set_body_string("digraph work_items {\n}\n"),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new(&mock_server.uri(), None);
+    let text = client
+        .graph(None, Some("PONE-1"), Some(2), "blocks", "dot")
+        .await
+        .unwrap();
+
+    assert!(text.starts_with("digraph work_items"));
+}
+
+#[tokio::test]
+async fn test_work_item_context_markdown() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/work-items/PONE-12/context"))
+        .and(query_param("format", "markdown"))
+        .and(query_param("max_chars", "8000"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "text/markdown; charset=utf-8")
+                .set_body_string("# PONE-12: Add login\n"),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new(&mock_server.uri(), None);
+    let text = client
+        .work_item_context_markdown("PONE-12", Some(8000))
+        .await
+        .unwrap();
+
+    assert_eq!(text, "# PONE-12: Add login\n");
+}
+
+#[tokio::test]
+async fn test_list_llm_context_for_project() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/llm-context"))
+        .and(query_param("project_id", "PONE"))
+        .and(query_param("context_type", "business_rule"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "entries": [] })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new(&mock_server.uri(), None);
+    let result = client
+        .list_llm_context(Some("PONE"), None, Some("business_rule"), None)
+        .await
+        .unwrap();
+
+    assert_eq!(result["entries"], json!([]));
+}
+
+#[tokio::test]
+async fn test_create_llm_context() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/llm-context"))
+        .and(body_string_contains("\"project_id\":\"PONE\""))
+        .and(body_string_contains("\"context_type\":\"instruction\""))
+        .and(body_string_contains("\"priority\":50"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "entry": {
+                "id": "00000000-0000-0000-0000-000000000002",
+                "context_type": "instruction",
+                "title": "Run the linter"
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new(&mock_server.uri(), None);
+    let result = client
+        .create_llm_context(
+            Some("PONE"),
+            "instruction",
+            "conventions",
+            "Run the linter",
+            "Run cargo clippy before committing.",
+            None,
+            None,
+            50,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result["entry"]["title"], "Run the linter");
+}
+
+#[tokio::test]
+async fn test_update_llm_context_sends_only_given_fields() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path(
+            "/api/v1/llm-context/00000000-0000-0000-0000-000000000002",
+        ))
+        .and(body_json(json!({ "example_sql": "", "priority": 10 })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "entry": { "id": "00000000-0000-0000-0000-000000000002", "priority": 10 }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new(&mock_server.uri(), None);
+    let result = client
+        .update_llm_context(
+            "00000000-0000-0000-0000-000000000002",
+            None,
+            None,
+            None,
+            None,
+            Some(""),
+            None,
+            Some(10),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result["entry"]["priority"], 10);
+}
+
+#[tokio::test]
+async fn test_verify_llm_context() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/llm-context/verify"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "checked": 8, "problems": [] })),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new(&mock_server.uri(), None);
+    let result = client.verify_llm_context().await.unwrap();
+
+    assert_eq!(result["checked"], 8);
+}
+
+#[tokio::test]
+async fn test_restore_backup_sends_name() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/admin/backup/restore"))
+        .and(body_json(json!({ "name": "pm-20260118T093000.000Z.db" })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "status": "restarting",
+            "message": "Restore staged; it is applied when the server next starts",
+            "backup": { "name": "pm-20260118T093000.000Z.db", "size_bytes": 4096, "created_at": 1768728600 }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new(&mock_server.uri(), None);
+    let result = client
+        .restore_backup("pm-20260118T093000.000Z.db")
+        .await
+        .unwrap();
+
+    assert_eq!(result["status"], "restarting");
+    assert_eq!(result["backup"]["size_bytes"], 4096);
+}
+
+#[tokio::test]
+async fn test_import_dry_run_sets_query_param() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/sync/import"))
+        .and(query_param("dry_run", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "dry_run": true,
+            "projects": { "created": 0, "updated": 0, "skipped": 0 }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let file = std::env::temp_dir().join(format!("pm-import-{}.json", std::process::id()));
+    std::fs::write(&file, r#"{"schema_version": 1, "projects": []}"#).unwrap();
+
+    let client = Client::new(&mock_server.uri(), None);
+    let result = client
+        .import_data(file.to_str().unwrap(), true)
+        .await
+        .unwrap();
+    let _ = std::fs::remove_file(&file);
+
+    assert_eq!(result["dry_run"], true);
+}
+
+#[tokio::test]
+async fn test_sync_pull_posts_remote_and_conflict_strategy() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/sync/pull"))
+        .and(body_json(json!({
+            "remote": "http://10.0.0.5:8000",
+            "on_conflict": "theirs"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "peer": { "url": "http://10.0.0.5:8000" },
+            "result": { "projects": { "created": 1, "conflicts": 0 } }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new(&mock_server.uri(), None);
+    let result = client
+        .sync_peer("pull", "http://10.0.0.5:8000", "theirs", None)
+        .await
+        .unwrap();
+
+    assert_eq!(result["peer"]["url"], "http://10.0.0.5:8000");
+}
+
+#[tokio::test]
+async fn test_create_project_from_template_sends_template_id() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/projects"))
+        .and(body_json(json!({
+            "title": "New Project",
+            "key": "NEW",
+            "template_id": "scrum"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "project": { "key": "NEW" }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new(&mock_server.uri(), None);
+    let result = client
+        .create_project("New Project", "NEW", None, Some("scrum"))
+        .await
+        .unwrap();
+
+    assert_eq!(result["project"]["key"], "NEW");
+}
+
+#[tokio::test]
+async fn test_create_template_from_structure() {
+    let mock_server = MockServer::start().await;
+    let structure = json!({
+        "custom_fields": [],
+        "work_items": [{ "number": 1, "item_type": "epic", "title": "Onboarding" }],
+        "llm_context": []
+    });
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/templates"))
+        .and(body_json(json!({
+            "name": "scrum",
+            "structure": structure.clone()
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "template": { "name": "scrum", "structure": structure.clone() }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new(&mock_server.uri(), None);
+    let result = client
+        .create_template("scrum", None, None, Some(&structure))
+        .await
+        .unwrap();
+
+    assert_eq!(result["template"]["name"], "scrum");
+}
+
+#[tokio::test]
+async fn test_delete_template_by_name() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v1/templates/scrum"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "deleted_id": "00000000-0000-0000-0000-000000000009"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new(&mock_server.uri(), None);
+    let result = client.delete_template("scrum").await.unwrap();
+
+    assert_eq!(result["deleted_id"], "00000000-0000-0000-0000-000000000009");
+}
+
+#[tokio::test]
+async fn test_move_work_item_to_project_sends_target_and_version() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/work-items/PONE-12/move-to-project"))
+        .and(body_json(json!({
+            "project_id": "PTWO",
+            "expected_version": 3
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "work_item": { "display_key": "PTWO-1" },
+            "moved": [{ "from": "PONE-12", "to": "PTWO-1" }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new(&mock_server.uri(), None);
+    let result = client
+        .move_work_item_to_project("PONE-12", "PTWO", None, 3)
+        .await
+        .unwrap();
+
+    assert_eq!(result["moved"][0]["to"], "PTWO-1");
+}
+
+#[tokio::test]
+async fn test_move_work_item_sends_neighbour_and_version() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/work-items/PONE-12/move"))
+        .and(body_json(json!({
+            "after_id": "PONE-3",
+            "expected_version": 4
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "work_item": { "display_key": "PONE-12", "rank": "8", "version": 5 }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new(&mock_server.uri(), None);
+    let result = client
+        .move_work_item("PONE-12", None, Some("PONE-3"), 4)
+        .await
+        .unwrap();
+
+    assert_eq!(result["work_item"]["version"], 5);
+}
+
+#[tokio::test]
+async fn test_rename_project_key_sends_key_and_version() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/projects/PONE/key"))
+        .and(body_json(json!({
+            "key": "ALPHA",
+            "expected_version": 2
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "project": { "key": "ALPHA", "version": 3 }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new(&mock_server.uri(), None);
+    let result = client.rename_project_key("PONE", "ALPHA", 2).await.unwrap();
+
+    assert_eq!(result["project"]["key"], "ALPHA");
+}
@@ -0,0 +1,368 @@
+//! Integration tests for `pm mcp` against an in-process pm-server
+
+use pm_auth::RateLimiterFactory;
+use pm_cli::{Client, McpServer};
+use pm_config::ApiConfig;
+use pm_server::routes::build_router;
+use pm_ws::{
+    AppState, CircuitBreaker, CircuitBreakerConfig, ConnectionConfig, ConnectionLimits,
+    ConnectionRegistry, Metrics, ShutdownCoordinator,
+};
+
+use std::sync::Arc;
+
+use serde_json::{Value, json};
+use sqlx::SqlitePool;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+const USER_ID: &str = "00000000-0000-0000-0000-000000000001";
+
+/// Start pm-server on an ephemeral port backed by an in-memory database
+async fn start_server() -> String {
+    let pool = SqlitePool::connect(":memory:")
+        .await
+        .expect("Failed to create test database");
+    sqlx::migrate!("../pm-db/migrations")
+        .run(&pool)
+        .await
+        .expect("Failed to run migrations");
+    sqlx::query("INSERT INTO users (id, email, created_at) VALUES (?, ?, ?)")
+        .bind(USER_ID)
+        .bind("mcp@test.local")
+        .bind(chrono::Utc::now().timestamp())
+        .execute(&pool)
+        .await
+        .expect("Failed to create test user");
+
+    let state = AppState {
+        pool,
+        circuit_breaker: Arc::new(CircuitBreaker::new(CircuitBreakerConfig::default())),
+        jwt_validator: None,
+        desktop_user_id: "test-user".to_string(),
+        rate_limiter_factory: RateLimiterFactory::default(),
+        registry: ConnectionRegistry::new(ConnectionLimits::default()),
+        metrics: Metrics::new(),
+        shutdown: ShutdownCoordinator::new(),
+        config: ConnectionConfig::default(),
+        api_config: ApiConfig::default(),
+        validation: pm_config::ValidationConfig::default(),
+        backups: None,
+        tenants: None,
+    };
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, build_router(state)).await.unwrap();
+    });
+
+    format!("http://{}", addr)
+}
+
+async fn mcp_server() -> McpServer {
+    let url = start_server().await;
+    McpServer::new(Client::new(&url, Some(USER_ID)))
+}
+
+async fn request(server: &McpServer, id: i64, method: &str, params: Value) -> Value {
+    server
+        .handle_message(json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method,
+            "params": params,
+        }))
+        .await
+        .expect("requests always get a response")
+}
+
+async fn call_tool(server: &McpServer, name: &str, arguments: Value) -> Value {
+    let response = request(
+        server,
+        1,
+        "tools/call",
+        json!({ "name": name, "arguments": arguments }),
+    )
+    .await;
+    response["result"].clone()
+}
+
+#[tokio::test]
+async fn test_mcp_initialize_and_list_tools() {
+    let server = mcp_server().await;
+
+    let response = request(
+        &server,
+        1,
+        "initialize",
+        json!({
+            "protocolVersion": "2025-03-26",
+            "capabilities": {},
+            "clientInfo": { "name": "test", "version": "0" },
+        }),
+    )
+    .await;
+    assert_eq!(response["id"], 1);
+    assert_eq!(response["result"]["protocolVersion"], "2025-03-26");
+    assert_eq!(response["result"]["serverInfo"]["name"], "pm");
+    assert!(response["result"]["capabilities"]["tools"].is_object());
+    assert!(response["result"]["capabilities"]["resources"].is_object());
+
+    let notification = server
+        .handle_message(json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }))
+        .await;
+    assert!(notification.is_none());
+
+    let response = request(&server, 2, "tools/list", json!({})).await;
+    let tools = response["result"]["tools"].as_array().unwrap();
+    let names: Vec<&str> = tools.iter().map(|t| t["name"].as_str().unwrap()).collect();
+    for expected in [
+        "list_projects",
+        "create_work_item",
+        "update_work_item",
+        "list_sprints",
+        "create_comment",
+        "create_dependency",
+        "start_timer",
+        "stop_timer",
+    ] {
+        assert!(names.contains(&expected), "missing tool {}", expected);
+    }
+    let create = tools
+        .iter()
+        .find(|t| t["name"] == "create_work_item")
+        .unwrap();
+    assert_eq!(
+        create["inputSchema"]["properties"]["item_type"]["enum"],
+        json!(["epic", "story", "task"])
+    );
+    assert_eq!(
+        create["inputSchema"]["required"],
+        json!(["project_id", "item_type", "title"])
+    );
+}
+
+#[tokio::test]
+async fn test_mcp_tools_round_trip() {
+    let server = mcp_server().await;
+
+    let result = call_tool(
+        &server,
+        "create_project",
+        json!({ "title": "MCP", "key": "MCP" }),
+    )
+    .await;
+    assert_eq!(result["isError"], false, "{}", result);
+    let project_id = result["structuredContent"]["project"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let result = call_tool(
+        &server,
+        "create_work_item",
+        json!({
+            "project_id": project_id,
+            "item_type": "task",
+            "title": "Written by an agent",
+            "original_estimate_seconds": 3600,
+        }),
+    )
+    .await;
+    assert_eq!(result["isError"], false, "{}", result);
+    assert_eq!(result["content"][0]["type"], "text");
+
+    let result = call_tool(&server, "get_work_item", json!({ "id": "MCP-1" })).await;
+    assert_eq!(result["isError"], false, "{}", result);
+    let item = &result["structuredContent"]["work_item"];
+    assert_eq!(item["title"], "Written by an agent");
+    assert_eq!(item["remaining_estimate_seconds"], 3600);
+
+    let result = call_tool(
+        &server,
+        "create_comment",
+        json!({ "work_item_id": item["id"], "content": "Looks good" }),
+    )
+    .await;
+    assert_eq!(result["isError"], false, "{}", result);
+
+    let result = call_tool(
+        &server,
+        "list_comments",
+        json!({ "work_item_id": item["id"] }),
+    )
+    .await;
+    assert_eq!(
+        result["structuredContent"]["comments"][0]["content"],
+        "Looks good"
+    );
+
+    let result = call_tool(
+        &server,
+        "get_work_item_context",
+        json!({ "id": "MCP-1", "max_chars": 4000 }),
+    )
+    .await;
+    assert_eq!(result["isError"], false, "{}", result);
+    let context = &result["structuredContent"]["context"];
+    assert_eq!(context["item"]["key"], "MCP-1");
+    assert_eq!(context["comments"][0]["content"], "Looks good");
+}
+
+#[tokio::test]
+async fn test_mcp_run_query() {
+    let server = mcp_server().await;
+    let result = call_tool(
+        &server,
+        "create_project",
+        json!({ "title": "Query", "key": "QRY" }),
+    )
+    .await;
+    assert_eq!(result["isError"], false, "{}", result);
+
+    let result = call_tool(
+        &server,
+        "run_query",
+        json!({ "sql": "SELECT key, title FROM pm_projects", "max_rows": 5 }),
+    )
+    .await;
+    assert_eq!(result["isError"], false, "{}", result);
+    assert_eq!(
+        result["structuredContent"]["rows"],
+        json!([["QRY", "Query"]])
+    );
+
+    let result = call_tool(
+        &server,
+        "run_query",
+        json!({ "sql": "UPDATE pm_projects SET title = 'x'" }),
+    )
+    .await;
+    assert_eq!(result["isError"], true);
+}
+
+#[tokio::test]
+async fn test_mcp_tool_errors() {
+    let server = mcp_server().await;
+
+    // API failures are tool results flagged with isError
+    let result = call_tool(&server, "get_work_item", json!({ "id": "NOPE-1" })).await;
+    assert_eq!(result["isError"], true);
+    assert!(
+        result["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("NOT_FOUND")
+    );
+
+    // Malformed calls are JSON-RPC errors
+    let response = request(
+        &server,
+        7,
+        "tools/call",
+        json!({ "name": "get_work_item", "arguments": {} }),
+    )
+    .await;
+    assert_eq!(response["error"]["code"], -32602);
+    assert!(
+        response["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("'id'")
+    );
+
+    let response = request(
+        &server,
+        8,
+        "tools/call",
+        json!({ "name": "get_work_item", "arguments": { "id": 5 } }),
+    )
+    .await;
+    assert_eq!(response["error"]["code"], -32602);
+
+    let response = request(&server, 9, "tools/call", json!({ "name": "drop_tables" })).await;
+    assert_eq!(response["error"]["code"], -32602);
+
+    let response = request(&server, 10, "sampling/createMessage", json!({})).await;
+    assert_eq!(response["error"]["code"], -32601);
+}
+
+#[tokio::test]
+async fn test_mcp_llm_context_resources() {
+    let server = mcp_server().await;
+
+    let response = request(&server, 1, "resources/list", json!({})).await;
+    let resources = response["result"]["resources"].as_array().unwrap();
+    assert!(!resources.is_empty(), "seeded LLM context should be listed");
+    let first = &resources[0];
+    let uri = first["uri"].as_str().unwrap();
+    assert!(uri.starts_with("pm://llm-context/"));
+    assert_eq!(first["mimeType"], "text/markdown");
+
+    let response = request(&server, 2, "resources/read", json!({ "uri": uri })).await;
+    let contents = &response["result"]["contents"][0];
+    assert_eq!(contents["uri"], uri);
+    let expected_heading = format!("# {}\n", first["name"].as_str().unwrap());
+    assert!(
+        contents["text"]
+            .as_str()
+            .unwrap()
+            .starts_with(&expected_heading)
+    );
+
+    let response = request(
+        &server,
+        3,
+        "resources/read",
+        json!({ "uri": "pm://llm-context/missing" }),
+    )
+    .await;
+    assert_eq!(response["error"]["code"], -32002);
+}
+
+#[tokio::test]
+async fn test_mcp_serve_over_stream() {
+    let server = mcp_server().await;
+    let (client_side, server_side) = tokio::io::duplex(64 * 1024);
+    let (server_read, server_write) = tokio::io::split(server_side);
+    let serve = tokio::spawn(async move {
+        server
+            .serve(BufReader::new(server_read), server_write)
+            .await
+            .unwrap();
+    });
+
+    let (client_read, mut client_write) = tokio::io::split(client_side);
+    client_write
+        .write_all(
+            concat!(
+                "{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"ping\"}\n",
+                "{\"jsonrpc\":\"2.0\",\"method\":\"notifications/initialized\"}\n",
+                "\n",
+                "not json\n",
+                "{\"jsonrpc\":\"2.0\",\"id\":2,\"method\":\"tools/call\",",
+                "\"params\":{\"name\":\"list_projects\"}}\n",
+            )
+            .as_bytes(),
+        )
+        .await
+        .unwrap();
+    client_write.shutdown().await.unwrap();
+
+    let mut lines = BufReader::new(client_read).lines();
+    let mut responses = Vec::new();
+    while let Some(line) = lines.next_line().await.unwrap() {
+        responses.push(serde_json::from_str::<Value>(&line).unwrap());
+    }
+    serve.await.unwrap();
+
+    assert_eq!(responses.len(), 3);
+    assert_eq!(
+        responses[0],
+        json!({ "jsonrpc": "2.0", "id": 1, "result": {} })
+    );
+    assert_eq!(responses[1]["error"]["code"], -32700);
+    assert_eq!(responses[2]["id"], 2);
+    assert_eq!(responses[2]["result"]["isError"], false);
+}
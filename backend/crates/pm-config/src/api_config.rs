@@ -1,3 +1,5 @@
+use crate::{ConfigError, ConfigErrorResult};
+
 use serde::Deserialize;
 use uuid::Uuid;
 
@@ -6,6 +8,32 @@ pub const DEFAULT_LLM_USER_ID: &str = "00000000-0000-0000-0000-000000000001";
 /// Default display name for the LLM user
 pub const DEFAULT_LLM_USER_NAME: &str = "LLM Assistant";
 
+// Read-only query limits
+pub const MIN_QUERY_MAX_ROWS: usize = 1;
+pub const MAX_QUERY_MAX_ROWS: usize = 100_000;
+pub const DEFAULT_QUERY_MAX_ROWS: usize = 1000;
+pub const MIN_QUERY_TIMEOUT_MS: u64 = 100;
+pub const MAX_QUERY_TIMEOUT_MS: u64 = 60_000;
+pub const DEFAULT_QUERY_TIMEOUT_MS: u64 = 5000;
+
+// List endpoint page sizes
+pub const MIN_PAGE_SIZE: usize = 1;
+pub const MAX_PAGE_SIZE: usize = 10_000;
+pub const DEFAULT_PAGE_SIZE: usize = 100;
+pub const DEFAULT_MAX_PAGE_SIZE: usize = 1000;
+
+// Request limits
+pub const MIN_BODY_LIMIT_BYTES: usize = 1024;
+pub const MAX_BODY_LIMIT_BYTES: usize = 1024 * 1024 * 1024;
+pub const DEFAULT_MAX_BODY_BYTES: usize = 2 * 1024 * 1024;
+pub const DEFAULT_MAX_IMPORT_BYTES: usize = 64 * 1024 * 1024;
+pub const MIN_REQUEST_TIMEOUT_SECS: u64 = 1;
+pub const MAX_REQUEST_TIMEOUT_SECS: u64 = 3600;
+pub const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 60;
+pub const MIN_CONCURRENT_REQUESTS: usize = 1;
+pub const MAX_CONCURRENT_REQUESTS: usize = 10_000;
+pub const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 256;
+
 /// Configuration for the REST API layer
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
@@ -16,6 +44,22 @@ pub struct ApiConfig {
     pub llm_user_id: String,
     /// Display name for the LLM user
     pub llm_user_name: String,
+    /// Maximum rows returned by the read-only query endpoint
+    pub query_max_rows: usize,
+    /// Wall-clock budget for a single read-only query, in milliseconds
+    pub query_timeout_ms: u64,
+    /// Items per page when a list request gives no `limit`
+    pub default_page_size: usize,
+    /// Largest `limit` a list request may ask for
+    pub max_page_size: usize,
+    /// Largest request body accepted by most routes, in bytes
+    pub max_body_bytes: usize,
+    /// Largest request body accepted by the sync import route, in bytes
+    pub max_import_bytes: usize,
+    /// Time budget for a single request, in seconds
+    pub request_timeout_secs: u64,
+    /// Requests handled at once per route; further requests wait
+    pub max_concurrent_requests: usize,
 }
 
 impl Default for ApiConfig {
@@ -24,6 +68,14 @@ impl Default for ApiConfig {
             enabled: true,
             llm_user_id: DEFAULT_LLM_USER_ID.to_string(),
             llm_user_name: DEFAULT_LLM_USER_NAME.to_string(),
+            query_max_rows: DEFAULT_QUERY_MAX_ROWS,
+            query_timeout_ms: DEFAULT_QUERY_TIMEOUT_MS,
+            default_page_size: DEFAULT_PAGE_SIZE,
+            max_page_size: DEFAULT_MAX_PAGE_SIZE,
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+            max_import_bytes: DEFAULT_MAX_IMPORT_BYTES,
+            request_timeout_secs: DEFAULT_REQUEST_TIMEOUT_SECS,
+            max_concurrent_requests: DEFAULT_MAX_CONCURRENT_REQUESTS,
         }
     }
 }
@@ -36,4 +88,73 @@ impl ApiConfig {
             Uuid::parse_str(DEFAULT_LLM_USER_ID).expect("Default LLM user ID is valid")
         })
     }
+
+    pub fn validate(&self) -> ConfigErrorResult<()> {
+        if self.query_max_rows < MIN_QUERY_MAX_ROWS || self.query_max_rows > MAX_QUERY_MAX_ROWS {
+            return Err(ConfigError::config(format!(
+                "api.query_max_rows must be {}-{}, got {}",
+                MIN_QUERY_MAX_ROWS, MAX_QUERY_MAX_ROWS, self.query_max_rows
+            )));
+        }
+
+        if self.query_timeout_ms < MIN_QUERY_TIMEOUT_MS
+            || self.query_timeout_ms > MAX_QUERY_TIMEOUT_MS
+        {
+            return Err(ConfigError::config(format!(
+                "api.query_timeout_ms must be {}-{}, got {}",
+                MIN_QUERY_TIMEOUT_MS, MAX_QUERY_TIMEOUT_MS, self.query_timeout_ms
+            )));
+        }
+
+        if self.max_page_size < MIN_PAGE_SIZE || self.max_page_size > MAX_PAGE_SIZE {
+            return Err(ConfigError::config(format!(
+                "api.max_page_size must be {}-{}, got {}",
+                MIN_PAGE_SIZE, MAX_PAGE_SIZE, self.max_page_size
+            )));
+        }
+
+        if self.default_page_size < MIN_PAGE_SIZE || self.default_page_size > self.max_page_size {
+            return Err(ConfigError::config(format!(
+                "api.default_page_size must be {}-{} (api.max_page_size), got {}",
+                MIN_PAGE_SIZE, self.max_page_size, self.default_page_size
+            )));
+        }
+
+        if self.max_body_bytes < MIN_BODY_LIMIT_BYTES || self.max_body_bytes > MAX_BODY_LIMIT_BYTES
+        {
+            return Err(ConfigError::config(format!(
+                "api.max_body_bytes must be {}-{}, got {}",
+                MIN_BODY_LIMIT_BYTES, MAX_BODY_LIMIT_BYTES, self.max_body_bytes
+            )));
+        }
+
+        if self.max_import_bytes < self.max_body_bytes
+            || self.max_import_bytes > MAX_BODY_LIMIT_BYTES
+        {
+            return Err(ConfigError::config(format!(
+                "api.max_import_bytes must be {} (api.max_body_bytes)-{}, got {}",
+                self.max_body_bytes, MAX_BODY_LIMIT_BYTES, self.max_import_bytes
+            )));
+        }
+
+        if self.request_timeout_secs < MIN_REQUEST_TIMEOUT_SECS
+            || self.request_timeout_secs > MAX_REQUEST_TIMEOUT_SECS
+        {
+            return Err(ConfigError::config(format!(
+                "api.request_timeout_secs must be {}-{}, got {}",
+                MIN_REQUEST_TIMEOUT_SECS, MAX_REQUEST_TIMEOUT_SECS, self.request_timeout_secs
+            )));
+        }
+
+        if self.max_concurrent_requests < MIN_CONCURRENT_REQUESTS
+            || self.max_concurrent_requests > MAX_CONCURRENT_REQUESTS
+        {
+            return Err(ConfigError::config(format!(
+                "api.max_concurrent_requests must be {}-{}, got {}",
+                MIN_CONCURRENT_REQUESTS, MAX_CONCURRENT_REQUESTS, self.max_concurrent_requests
+            )));
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,65 @@
+use crate::{ConfigError, ConfigErrorResult};
+
+use serde::Deserialize;
+
+pub const DEFAULT_BACKUP_DIRECTORY: &str = "backups";
+pub const MAX_BACKUP_INTERVAL_HOURS: u64 = 24 * 365;
+pub const DEFAULT_BACKUP_INTERVAL_HOURS: u64 = 24;
+pub const MIN_BACKUP_RETENTION: usize = 1;
+pub const MAX_BACKUP_RETENTION: usize = 1000;
+pub const DEFAULT_BACKUP_RETENTION: usize = 7;
+
+/// Online database backups
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct BackupConfig {
+    /// Backup directory, relative to the config directory (`.pm/`)
+    pub dir: String,
+    /// Hours between scheduled backups (0 = disabled)
+    pub interval_hours: u64,
+    /// Backups to keep; the oldest are deleted after each new backup
+    pub retention_count: usize,
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        Self {
+            dir: DEFAULT_BACKUP_DIRECTORY.to_string(),
+            interval_hours: DEFAULT_BACKUP_INTERVAL_HOURS,
+            retention_count: DEFAULT_BACKUP_RETENTION,
+        }
+    }
+}
+
+impl BackupConfig {
+    pub fn validate(&self) -> ConfigErrorResult<()> {
+        let dir = std::path::Path::new(&self.dir);
+        if self.dir.is_empty()
+            || dir.is_absolute()
+            || self.dir.starts_with('/')
+            || self.dir.contains("..")
+        {
+            return Err(ConfigError::config(
+                "backup.dir must be a relative path and cannot contain '..'",
+            ));
+        }
+
+        if self.interval_hours > MAX_BACKUP_INTERVAL_HOURS {
+            return Err(ConfigError::config(format!(
+                "backup.interval_hours must be 0-{}, got {}",
+                MAX_BACKUP_INTERVAL_HOURS, self.interval_hours
+            )));
+        }
+
+        if self.retention_count < MIN_BACKUP_RETENTION
+            || self.retention_count > MAX_BACKUP_RETENTION
+        {
+            return Err(ConfigError::config(format!(
+                "backup.retention_count must be {}-{}, got {}",
+                MIN_BACKUP_RETENTION, MAX_BACKUP_RETENTION, self.retention_count
+            )));
+        }
+
+        Ok(())
+    }
+}
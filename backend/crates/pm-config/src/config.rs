@@ -1,7 +1,7 @@
 use crate::{
-    ActivityLogConfig, ApiConfig, AuthConfig, CircuitBreakerConfig, ConfigError, ConfigErrorResult,
-    DatabaseConfig, HandlerConfig, LoggingConfig, RateLimitConfig, RetryConfig, ServerConfig,
-    ValidationConfig, WebSocketConfig,
+    ActivityLogConfig, ApiConfig, AuthConfig, BackupConfig, CircuitBreakerConfig, ConfigError,
+    ConfigErrorResult, DatabaseConfig, HandlerConfig, LoggingConfig, RateLimitConfig, RetryConfig,
+    ServerConfig, TenantConfig, ValidationConfig, WebSocketConfig,
 };
 
 use std::path::PathBuf;
@@ -27,6 +27,12 @@ pub struct Config {
     /// REST API configuration
     #[serde(default)]
     pub api: ApiConfig,
+    /// Database backup configuration
+    #[serde(default)]
+    pub backup: BackupConfig,
+    /// Per-tenant database routing
+    #[serde(default)]
+    pub tenant: TenantConfig,
 }
 
 impl Config {
@@ -153,6 +159,9 @@ impl Config {
         self.retry.validate()?;
         self.handler.validate()?;
         self.validation.validate()?;
+        self.api.validate()?;
+        self.backup.validate()?;
+        self.tenant.validate(self.auth.enabled)?;
 
         // Validate database path doesn't escape config dir
         let db_path = std::path::Path::new(&self.database.path);
@@ -176,6 +185,18 @@ impl Config {
         Ok(config_dir.join(&self.database.path))
     }
 
+    /// Get absolute path to the backup directory.
+    pub fn backup_dir(&self) -> Result<PathBuf, ConfigError> {
+        let config_dir = Self::config_dir()?;
+        Ok(config_dir.join(&self.backup.dir))
+    }
+
+    /// Get absolute path to the tenant database root.
+    pub fn tenant_dir(&self) -> Result<PathBuf, ConfigError> {
+        let config_dir = Self::config_dir()?;
+        Ok(config_dir.join(&self.tenant.dir))
+    }
+
     /// Get bind address as string.
     pub fn bind_addr(&self) -> String {
         format!("{}:{}", self.server.host, self.server.port)
@@ -248,6 +269,21 @@ impl Config {
 
         info!("  handler: timeout={}s", self.handler.timeout_secs);
 
+        info!(
+            "  backup: every {}h, keep {} ({})",
+            self.backup.interval_hours, self.backup.retention_count, self.backup.dir
+        );
+
+        if self.tenant.enabled {
+            info!(
+                "  tenant: {:?} -> {}/<tenant>/main.db (max {} open, {} conns each)",
+                self.tenant.source,
+                self.tenant.dir,
+                self.tenant.max_open_pools,
+                self.tenant.max_connections_per_tenant
+            );
+        }
+
         info!(
             "  validation: title={}, desc={}, comment={}, sprint_name={}, points={}",
             self.validation.max_title_length,
@@ -318,6 +354,14 @@ impl Config {
             "PM_RATE_LIMIT_WINDOW_SECS",
             &mut self.rate_limit.window_secs,
         )?;
+        Self::apply_env_parse(
+            "PM_RATE_LIMIT_API_REQUESTS_PER_USER",
+            &mut self.rate_limit.api_requests_per_user,
+        )?;
+        Self::apply_env_parse(
+            "PM_RATE_LIMIT_API_REQUESTS_PER_IP",
+            &mut self.rate_limit.api_requests_per_ip,
+        )?;
 
         // NEW OVERRIDES BELOW
 
@@ -381,6 +425,41 @@ impl Config {
             &mut self.validation.max_sprint_name_length,
         )?;
 
+        // API
+        Self::apply_env_parse("PM_API_QUERY_MAX_ROWS", &mut self.api.query_max_rows)?;
+        Self::apply_env_parse("PM_API_QUERY_TIMEOUT_MS", &mut self.api.query_timeout_ms)?;
+        Self::apply_env_parse("PM_API_DEFAULT_PAGE_SIZE", &mut self.api.default_page_size)?;
+        Self::apply_env_parse("PM_API_MAX_PAGE_SIZE", &mut self.api.max_page_size)?;
+        Self::apply_env_parse("PM_API_MAX_BODY_BYTES", &mut self.api.max_body_bytes)?;
+        Self::apply_env_parse("PM_API_MAX_IMPORT_BYTES", &mut self.api.max_import_bytes)?;
+        Self::apply_env_parse(
+            "PM_API_REQUEST_TIMEOUT_SECS",
+            &mut self.api.request_timeout_secs,
+        )?;
+        Self::apply_env_parse(
+            "PM_API_MAX_CONCURRENT_REQUESTS",
+            &mut self.api.max_concurrent_requests,
+        )?;
+
+        // Backup
+        Self::apply_env_string("PM_BACKUP_DIR", &mut self.backup.dir);
+        Self::apply_env_parse("PM_BACKUP_INTERVAL_HOURS", &mut self.backup.interval_hours)?;
+        Self::apply_env_parse(
+            "PM_BACKUP_RETENTION_COUNT",
+            &mut self.backup.retention_count,
+        )?;
+
+        // Tenants
+        Self::apply_env_bool("PM_TENANT_ENABLED", &mut self.tenant.enabled);
+        Self::apply_env_parse("PM_TENANT_SOURCE", &mut self.tenant.source)?;
+        Self::apply_env_string("PM_TENANT_DIR", &mut self.tenant.dir);
+        Self::apply_env_bool("PM_TENANT_CREATE_MISSING", &mut self.tenant.create_missing);
+        Self::apply_env_parse("PM_TENANT_MAX_OPEN_POOLS", &mut self.tenant.max_open_pools)?;
+        Self::apply_env_parse(
+            "PM_TENANT_IDLE_TIMEOUT_SECS",
+            &mut self.tenant.idle_timeout_secs,
+        )?;
+
         Ok(())
     }
 
@@ -1,6 +1,7 @@
 mod activity_log_config;
 mod api_config;
 mod auth_config;
+mod backup_config;
 mod circuit_breaker_config;
 mod config;
 mod database_config;
@@ -12,6 +13,7 @@ mod port_file;
 mod rate_limit_config;
 mod retry_config;
 mod server_config;
+mod tenant_config;
 mod validation_config;
 mod websocket_config;
 
@@ -19,8 +21,16 @@ mod websocket_config;
 mod tests;
 
 pub use activity_log_config::ActivityLogConfig;
-pub use api_config::{ApiConfig, DEFAULT_LLM_USER_ID, DEFAULT_LLM_USER_NAME};
+pub use api_config::{
+    ApiConfig, DEFAULT_LLM_USER_ID, DEFAULT_LLM_USER_NAME, DEFAULT_MAX_BODY_BYTES,
+    DEFAULT_MAX_CONCURRENT_REQUESTS, DEFAULT_MAX_IMPORT_BYTES, DEFAULT_MAX_PAGE_SIZE,
+    DEFAULT_PAGE_SIZE, DEFAULT_QUERY_MAX_ROWS, DEFAULT_QUERY_TIMEOUT_MS,
+    DEFAULT_REQUEST_TIMEOUT_SECS,
+};
 pub use auth_config::AuthConfig;
+pub use backup_config::{
+    BackupConfig, DEFAULT_BACKUP_DIRECTORY, DEFAULT_BACKUP_INTERVAL_HOURS, DEFAULT_BACKUP_RETENTION,
+};
 pub use circuit_breaker_config::CircuitBreakerConfig;
 pub use config::Config;
 pub use database_config::DatabaseConfig;
@@ -29,9 +39,15 @@ pub use handler_config::HandlerConfig;
 pub use log_level::LogLevel;
 pub use logging_config::LoggingConfig;
 pub use port_file::{is_process_running, port_file_info::PortFileInfo};
-pub use rate_limit_config::RateLimitConfig;
+pub use rate_limit_config::{
+    DEFAULT_API_REQUESTS_PER_IP, DEFAULT_API_REQUESTS_PER_USER, RateLimitConfig,
+};
 pub use retry_config::RetryConfig;
 pub use server_config::ServerConfig;
+pub use tenant_config::{
+    DEFAULT_MAX_OPEN_TENANT_POOLS, DEFAULT_TENANT_DIRECTORY, DEFAULT_TENANT_IDLE_TIMEOUT_SECS,
+    DEFAULT_TENANT_POOL_CONNECTIONS, DEFAULT_TENANT_WS_CONNECTIONS, TenantConfig, TenantSource,
+};
 pub use validation_config::{
     DEFAULT_TIME_ENTRIES_LIMIT, MAX_BLOCKED_DEPENDENCIES_PER_ITEM,
     MAX_BLOCKING_DEPENDENCIES_PER_ITEM, MAX_FUTURE_TIMESTAMP_TOLERANCE_SECONDS,
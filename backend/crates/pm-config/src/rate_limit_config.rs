@@ -11,8 +11,12 @@ pub const MIN_RATE_LIMIT_WINDOW_SECS: u64 = 1;
 pub const MAX_RATE_LIMIT_WINDOW_SECS: u64 = 3600;
 pub const DEFAULT_RATE_LIMIT_WINDOW_SECS: u64 = 60;
 
+pub const DEFAULT_API_REQUESTS_PER_USER: u32 = 600;
+pub const DEFAULT_API_REQUESTS_PER_IP: u32 = 1200;
+
 /// Rate limiting settings.
-/// Applied per-connection to prevent abuse.
+/// Applied per WebSocket connection, and per user and client IP on the REST API,
+/// to prevent abuse.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
 pub struct RateLimitConfig {
@@ -20,6 +24,10 @@ pub struct RateLimitConfig {
     pub max_requests: u32,
     /// Window duration in seconds
     pub window_secs: u64,
+    /// REST requests per window for each user with a valid bearer token
+    pub api_requests_per_user: u32,
+    /// REST requests per window from each client IP
+    pub api_requests_per_ip: u32,
 }
 
 impl Default for RateLimitConfig {
@@ -27,6 +35,8 @@ impl Default for RateLimitConfig {
         Self {
             max_requests: DEFAULT_RATE_LIMIT_REQUESTS,
             window_secs: DEFAULT_RATE_LIMIT_WINDOW_SECS,
+            api_requests_per_user: DEFAULT_API_REQUESTS_PER_USER,
+            api_requests_per_ip: DEFAULT_API_REQUESTS_PER_IP,
         }
     }
 }
@@ -51,6 +61,18 @@ impl RateLimitConfig {
             )));
         }
 
+        for (name, value) in [
+            ("api_requests_per_user", self.api_requests_per_user),
+            ("api_requests_per_ip", self.api_requests_per_ip),
+        ] {
+            if !(MIN_RATE_LIMIT_REQUESTS..=MAX_RATE_LIMIT_REQUESTS).contains(&value) {
+                return Err(ConfigError::config(format!(
+                    "rate_limit.{} must be {}-{}, got {}",
+                    name, MIN_RATE_LIMIT_REQUESTS, MAX_RATE_LIMIT_REQUESTS, value
+                )));
+            }
+        }
+
         Ok(())
     }
 }
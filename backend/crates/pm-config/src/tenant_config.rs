@@ -0,0 +1,134 @@
+use crate::{ConfigError, ConfigErrorResult};
+
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+pub const DEFAULT_TENANT_DIRECTORY: &str = "tenants";
+pub const MIN_OPEN_TENANT_POOLS: usize = 1;
+pub const MAX_OPEN_TENANT_POOLS: usize = 10_000;
+pub const DEFAULT_MAX_OPEN_TENANT_POOLS: usize = 100;
+pub const DEFAULT_TENANT_IDLE_TIMEOUT_SECS: u64 = 600;
+pub const MIN_TENANT_POOL_CONNECTIONS: u32 = 1;
+pub const MAX_TENANT_POOL_CONNECTIONS: u32 = 100;
+pub const DEFAULT_TENANT_POOL_CONNECTIONS: u32 = 5;
+pub const MIN_TENANT_WS_CONNECTIONS: usize = 1;
+pub const DEFAULT_TENANT_WS_CONNECTIONS: usize = 500;
+
+/// Where the tenant of a request comes from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TenantSource {
+    /// The `tenant_id` claim of the JWT (requires auth)
+    #[default]
+    Claim,
+    /// The first label of the Host header (`acme.board.example.com` → `acme`)
+    Host,
+}
+
+impl FromStr for TenantSource {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "claim" => Ok(TenantSource::Claim),
+            "host" => Ok(TenantSource::Host),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Per-tenant SQLite databases (ADR-0002) for multi-tenant hosting.
+///
+/// Disabled by default: desktop mode serves the single database in
+/// `database.path` (ADR-0006).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct TenantConfig {
+    /// Route each request to its tenant's database
+    pub enabled: bool,
+    /// How the tenant is identified
+    pub source: TenantSource,
+    /// Tenant root, relative to the config directory; each tenant's database
+    /// is `<dir>/<tenant_id>/main.db`
+    pub dir: String,
+    /// Create and migrate databases for unknown tenants instead of rejecting them
+    pub create_missing: bool,
+    /// Tenant pools kept open; the least recently used is closed beyond this
+    pub max_open_pools: usize,
+    /// Seconds a tenant pool may sit unused before it is closed (0 = never)
+    pub idle_timeout_secs: u64,
+    /// SQLite connections per tenant pool
+    pub max_connections_per_tenant: u32,
+    /// WebSocket connections per tenant
+    pub max_ws_connections_per_tenant: usize,
+}
+
+impl Default for TenantConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            source: TenantSource::default(),
+            dir: DEFAULT_TENANT_DIRECTORY.to_string(),
+            create_missing: false,
+            max_open_pools: DEFAULT_MAX_OPEN_TENANT_POOLS,
+            idle_timeout_secs: DEFAULT_TENANT_IDLE_TIMEOUT_SECS,
+            max_connections_per_tenant: DEFAULT_TENANT_POOL_CONNECTIONS,
+            max_ws_connections_per_tenant: DEFAULT_TENANT_WS_CONNECTIONS,
+        }
+    }
+}
+
+impl TenantConfig {
+    pub fn validate(&self, auth_enabled: bool) -> ConfigErrorResult<()> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.source == TenantSource::Claim && !auth_enabled {
+            return Err(ConfigError::config(
+                "tenant.source = \"claim\" requires auth.enabled = true",
+            ));
+        }
+
+        let dir = std::path::Path::new(&self.dir);
+        if self.dir.is_empty()
+            || dir.is_absolute()
+            || self.dir.starts_with('/')
+            || self.dir.contains("..")
+        {
+            return Err(ConfigError::config(
+                "tenant.dir must be a relative path and cannot contain '..'",
+            ));
+        }
+
+        if self.max_open_pools < MIN_OPEN_TENANT_POOLS
+            || self.max_open_pools > MAX_OPEN_TENANT_POOLS
+        {
+            return Err(ConfigError::config(format!(
+                "tenant.max_open_pools must be {}-{}, got {}",
+                MIN_OPEN_TENANT_POOLS, MAX_OPEN_TENANT_POOLS, self.max_open_pools
+            )));
+        }
+
+        if self.max_connections_per_tenant < MIN_TENANT_POOL_CONNECTIONS
+            || self.max_connections_per_tenant > MAX_TENANT_POOL_CONNECTIONS
+        {
+            return Err(ConfigError::config(format!(
+                "tenant.max_connections_per_tenant must be {}-{}, got {}",
+                MIN_TENANT_POOL_CONNECTIONS,
+                MAX_TENANT_POOL_CONNECTIONS,
+                self.max_connections_per_tenant
+            )));
+        }
+
+        if self.max_ws_connections_per_tenant < MIN_TENANT_WS_CONNECTIONS {
+            return Err(ConfigError::config(format!(
+                "tenant.max_ws_connections_per_tenant must be at least {}, got {}",
+                MIN_TENANT_WS_CONNECTIONS, self.max_ws_connections_per_tenant
+            )));
+        }
+
+        Ok(())
+    }
+}
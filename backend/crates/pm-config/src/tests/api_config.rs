@@ -1,4 +1,8 @@
-use crate::{ApiConfig, DEFAULT_LLM_USER_ID, DEFAULT_LLM_USER_NAME};
+use crate::{
+    ApiConfig, DEFAULT_API_REQUESTS_PER_IP, DEFAULT_API_REQUESTS_PER_USER, DEFAULT_LLM_USER_ID,
+    DEFAULT_LLM_USER_NAME, DEFAULT_MAX_BODY_BYTES, DEFAULT_MAX_IMPORT_BYTES, DEFAULT_MAX_PAGE_SIZE,
+    DEFAULT_PAGE_SIZE, DEFAULT_QUERY_MAX_ROWS, DEFAULT_QUERY_TIMEOUT_MS, RateLimitConfig,
+};
 
 #[test]
 fn test_default_api_config() {
@@ -24,3 +28,87 @@ fn test_llm_user_uuid_invalid_falls_back() {
     let uuid = config.llm_user_uuid();
     assert_eq!(uuid.to_string(), DEFAULT_LLM_USER_ID);
 }
+
+#[test]
+fn test_default_query_limits_are_valid() {
+    let config = ApiConfig::default();
+    assert_eq!(config.query_max_rows, DEFAULT_QUERY_MAX_ROWS);
+    assert_eq!(config.query_timeout_ms, DEFAULT_QUERY_TIMEOUT_MS);
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_query_limits_out_of_range_rejected() {
+    let config = ApiConfig {
+        query_max_rows: 0,
+        ..Default::default()
+    };
+    assert!(config.validate().is_err());
+
+    let config = ApiConfig {
+        query_timeout_ms: 10,
+        ..Default::default()
+    };
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_page_sizes_validated() {
+    let config = ApiConfig::default();
+    assert_eq!(config.default_page_size, DEFAULT_PAGE_SIZE);
+    assert_eq!(config.max_page_size, DEFAULT_MAX_PAGE_SIZE);
+    assert!(config.validate().is_ok());
+
+    let config = ApiConfig {
+        default_page_size: 2000,
+        ..Default::default()
+    };
+    assert!(config.validate().is_err());
+
+    let config = ApiConfig {
+        max_page_size: 0,
+        ..Default::default()
+    };
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_request_limits_validated() {
+    let config = ApiConfig::default();
+    assert_eq!(config.max_body_bytes, DEFAULT_MAX_BODY_BYTES);
+    assert_eq!(config.max_import_bytes, DEFAULT_MAX_IMPORT_BYTES);
+    assert!(config.validate().is_ok());
+
+    // The import limit may not be tighter than the general one
+    let config = ApiConfig {
+        max_import_bytes: DEFAULT_MAX_BODY_BYTES - 1,
+        ..Default::default()
+    };
+    assert!(config.validate().is_err());
+
+    let config = ApiConfig {
+        request_timeout_secs: 0,
+        ..Default::default()
+    };
+    assert!(config.validate().is_err());
+
+    let config = ApiConfig {
+        max_concurrent_requests: 0,
+        ..Default::default()
+    };
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_api_rate_limits_validated() {
+    let config = RateLimitConfig::default();
+    assert_eq!(config.api_requests_per_user, DEFAULT_API_REQUESTS_PER_USER);
+    assert_eq!(config.api_requests_per_ip, DEFAULT_API_REQUESTS_PER_IP);
+    assert!(config.validate().is_ok());
+
+    let config = RateLimitConfig {
+        api_requests_per_ip: 0,
+        ..Default::default()
+    };
+    assert!(config.validate().is_err());
+}
@@ -0,0 +1,72 @@
+use crate::tests::{EnvGuard, setup_config_dir};
+use crate::{BackupConfig, Config, DEFAULT_BACKUP_INTERVAL_HOURS, DEFAULT_BACKUP_RETENTION};
+
+use googletest::assert_that;
+use googletest::prelude::{anything, err, ok};
+use serial_test::serial;
+
+// =========================================================================
+// Validation Tests - Backup
+// =========================================================================
+
+#[test]
+fn given_defaults_when_validate_then_ok() {
+    // Given
+    let config = BackupConfig::default();
+
+    // When
+    let result = config.validate();
+
+    // Then
+    assert_that!(result, ok(anything()));
+    assert_eq!(config.interval_hours, DEFAULT_BACKUP_INTERVAL_HOURS);
+    assert_eq!(config.retention_count, DEFAULT_BACKUP_RETENTION);
+}
+
+#[test]
+#[serial]
+fn given_zero_retention_when_validate_then_error() {
+    // Given
+    let _temp = setup_config_dir();
+    let _retention = EnvGuard::set("PM_BACKUP_RETENTION_COUNT", "0");
+
+    // When
+    let config = Config::load().unwrap();
+    let result = config.validate();
+
+    // Then
+    assert_that!(result, err(anything()));
+}
+
+#[test]
+#[serial]
+fn given_zero_interval_when_validate_then_ok() {
+    // Given
+    let _temp = setup_config_dir();
+    let _interval = EnvGuard::set("PM_BACKUP_INTERVAL_HOURS", "0");
+
+    // When
+    let config = Config::load().unwrap();
+    let result = config.validate();
+
+    // Then
+    assert_eq!(config.backup.interval_hours, 0);
+    assert_that!(result, ok(anything()));
+}
+
+#[test]
+fn given_dir_escaping_config_dir_when_validate_then_error() {
+    for dir in ["../backups", "/tmp/backups", ""] {
+        // Given
+        let config = BackupConfig {
+            dir: dir.to_string(),
+            ..Default::default()
+        };
+
+        // When
+        let result = config.validate();
+
+        // Then
+        assert_that!(result, err(anything()));
+    }
+}
@@ -1,5 +1,6 @@
 mod api_config;
 mod auth;
+mod backup;
 mod circuit_breaker;
 mod config;
 mod desktop_id;
@@ -8,6 +9,7 @@ mod handler;
 mod port_file;
 mod retry;
 mod server;
+mod tenant;
 mod validation;
 mod web_socket;
 
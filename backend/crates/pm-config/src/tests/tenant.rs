@@ -0,0 +1,104 @@
+use crate::tests::{EnvGuard, setup_config_dir};
+use crate::{Config, TenantConfig, TenantSource};
+
+use googletest::assert_that;
+use googletest::prelude::{anything, err, ok};
+use serial_test::serial;
+
+// =========================================================================
+// Validation Tests - Tenant
+// =========================================================================
+
+#[test]
+fn given_disabled_when_validate_then_ok_without_auth() {
+    // Given
+    let config = TenantConfig::default();
+
+    // When
+    let result = config.validate(false);
+
+    // Then
+    assert_that!(result, ok(anything()));
+}
+
+#[test]
+fn given_claim_source_without_auth_when_validate_then_error() {
+    // Given
+    let config = TenantConfig {
+        enabled: true,
+        source: TenantSource::Claim,
+        ..Default::default()
+    };
+
+    // When
+    let result = config.validate(false);
+
+    // Then
+    assert_that!(result, err(anything()));
+    assert_that!(config.validate(true), ok(anything()));
+}
+
+#[test]
+fn given_host_source_without_auth_when_validate_then_ok() {
+    // Given
+    let config = TenantConfig {
+        enabled: true,
+        source: TenantSource::Host,
+        ..Default::default()
+    };
+
+    // When
+    let result = config.validate(false);
+
+    // Then
+    assert_that!(result, ok(anything()));
+}
+
+#[test]
+fn given_dir_escaping_config_dir_when_validate_then_error() {
+    for dir in ["../tenants", "/data/tenants", ""] {
+        // Given
+        let config = TenantConfig {
+            enabled: true,
+            source: TenantSource::Host,
+            dir: dir.to_string(),
+            ..Default::default()
+        };
+
+        // When
+        let result = config.validate(false);
+
+        // Then
+        assert_that!(result, err(anything()));
+    }
+}
+
+#[test]
+#[serial]
+fn given_tenant_env_vars_when_load_then_overrides_applied() {
+    // Given
+    let _temp = setup_config_dir();
+    let _enabled = EnvGuard::set("PM_TENANT_ENABLED", "true");
+    let _source = EnvGuard::set("PM_TENANT_SOURCE", "host");
+
+    // When
+    let config = Config::load().unwrap();
+
+    // Then
+    assert!(config.tenant.enabled);
+    assert_eq!(config.tenant.source, TenantSource::Host);
+}
+
+#[test]
+#[serial]
+fn given_unknown_tenant_source_when_load_then_error() {
+    // Given
+    let _temp = setup_config_dir();
+    let _source = EnvGuard::set("PM_TENANT_SOURCE", "cookie");
+
+    // When
+    let result = Config::load();
+
+    // Then
+    assert_that!(result, err(anything()));
+}
@@ -42,6 +42,42 @@ pub enum CoreError {
         value: String,
         location: ErrorLocation,
     },
+
+    #[error("Invalid custom field type: {value} {location}")]
+    InvalidCustomFieldType {
+        value: String,
+        location: ErrorLocation,
+    },
+
+    #[error("Invalid time report grouping: {value} {location}")]
+    InvalidTimeReportGroupBy {
+        value: String,
+        location: ErrorLocation,
+    },
+
+    #[error("Invalid time report period: {value} {location}")]
+    InvalidTimeReportPeriod {
+        value: String,
+        location: ErrorLocation,
+    },
+
+    #[error("Invalid graph format: {value} {location}")]
+    InvalidGraphFormat {
+        value: String,
+        location: ErrorLocation,
+    },
+
+    #[error("Invalid context format: {value} {location}")]
+    InvalidContextFormat {
+        value: String,
+        location: ErrorLocation,
+    },
+
+    #[error("Invalid schedule weight: {value} {location}")]
+    InvalidScheduleWeight {
+        value: String,
+        location: ErrorLocation,
+    },
 }
 
 pub type Result<T> = StdResult<T, CoreError>;
@@ -8,31 +8,76 @@ mod tests;
 pub use error::{CoreError, Result as CoreResult};
 pub use models::{
     activity_log::ActivityLog,
+    activity_log_dto::ActivityLogDto,
     comment::Comment,
     comment_dto::CommentDto,
+    context_format::ContextFormat,
+    custom_field_definition::{
+        CustomFieldDefinition, MAX_CUSTOM_FIELD_NAME_LENGTH, MAX_CUSTOM_FIELD_OPTIONS,
+    },
+    custom_field_definition_dto::CustomFieldDefinitionDto,
+    custom_field_type::CustomFieldType,
+    custom_field_values::{
+        CustomFieldValues, apply_custom_field_values, display_custom_field_value,
+    },
     dependency::Dependency,
     dependency_dto::DependencyDto,
     dependency_type::DependencyType,
-    llm_context::LlmContext,
+    estimate_rollup::{EstimateRollup, is_over_estimate},
+    example_sql_check::{ExampleSqlIssue, ExampleSqlProblem, ExampleSqlReport},
+    graph_format::GraphFormat,
+    llm_context::{
+        LlmContext, MAX_LLM_CONTEXT_CATEGORY_LENGTH, MAX_LLM_CONTEXT_CONTENT_LENGTH,
+        MAX_LLM_CONTEXT_PRIORITY, MAX_LLM_CONTEXT_TITLE_LENGTH,
+    },
+    llm_context_dto::LlmContextDto,
     llm_context_type::LlmContextType,
-    project::Project,
+    project::{MAX_PROJECT_KEY_LENGTH, Project},
     project_dto::ProjectDto,
     project_member::{Permission, ProjectMember},
+    project_member_dto::ProjectMemberDto,
     project_status::ProjectStatus,
+    project_template::{MAX_TEMPLATE_NAME_LENGTH, ProjectTemplate},
+    project_template_dto::ProjectTemplateDto,
+    rank::{MAX_RANK_LENGTH, rank_between, rank_needs_rebalance, spread_ranks},
+    schedule::{Schedule, ScheduleItem},
+    schedule_weight::ScheduleWeight,
     sprint::Sprint,
     sprint_dto::SprintDto,
+    sprint_estimate_summary::SprintEstimateSummary,
     sprint_status::SprintStatus,
     swim_lane::SwimLane,
     swim_lane_dto::SwimLaneDto,
+    template_custom_field::TemplateCustomField,
+    template_instance::TemplateInstance,
+    template_llm_context::TemplateLlmContext,
+    template_structure::TemplateStructure,
+    template_work_item::TemplateWorkItem,
     time_entry::TimeEntry,
     time_entry_dto::TimeEntryDto,
+    time_report::{TimeReport, TimeReportRow},
+    time_report_group_by::TimeReportGroupBy,
+    time_report_period::TimeReportPeriod,
     work_item::WorkItem,
+    work_item_context::{
+        ContextActivity, ContextActivityEntry, ContextComment, ContextItem, ContextOmitted,
+        ContextRule, WorkItemContext,
+    },
     work_item_dto::WorkItemDto,
+    work_item_graph::{GraphEdge, GraphNode, WorkItemGraph},
     work_item_type::WorkItemType,
 };
 pub use sync::{
+    conflict_strategy::ConflictStrategy,
     entity_import_counts::EntityImportCounts,
-    export_data::ExportData,
+    entity_import_diff::{ConflictEntry, EntityImportDiff, SkippedEntry, UpdatedEntry},
+    export_data::{CURRENT_EXPORT_SCHEMA_VERSION, ExportData},
+    export_upgrade::upgrade_export_data,
+    field_change::FieldChange,
+    import_diff::ImportDiff,
+    import_options::{ImportAction, ImportOptions},
+    import_problem::ImportProblem,
     import_result::ImportResult,
     sync_handlers::{parse_timestamp, parse_uuid},
+    sync_peer::SyncPeer,
 };
@@ -1,3 +1,5 @@
+use crate::{ActivityLogDto, CoreError, CoreResult, parse_timestamp, parse_uuid};
+
 use pm_proto::FieldChange;
 
 use chrono::{DateTime, Utc};
@@ -95,3 +97,22 @@ impl ActivityLog {
         }
     }
 }
+
+impl TryFrom<ActivityLogDto> for ActivityLog {
+    type Error = CoreError;
+
+    fn try_from(dto: ActivityLogDto) -> CoreResult<Self> {
+        Ok(ActivityLog {
+            id: parse_uuid(&dto.id, "activity_log.id")?,
+            entity_type: dto.entity_type,
+            entity_id: parse_uuid(&dto.entity_id, "activity_log.entity_id")?,
+            action: dto.action,
+            field_name: dto.field_name,
+            old_value: dto.old_value,
+            new_value: dto.new_value,
+            user_id: parse_uuid(&dto.user_id, "activity_log.user_id")?,
+            timestamp: parse_timestamp(dto.timestamp, "activity_log.timestamp")?,
+            comment: dto.comment,
+        })
+    }
+}
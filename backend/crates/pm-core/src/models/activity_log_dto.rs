@@ -0,0 +1,35 @@
+use crate::ActivityLog;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ActivityLogDto {
+    pub id: String,
+    pub entity_type: String,
+    pub entity_id: String,
+    pub action: String,
+    pub field_name: Option<String>,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub user_id: String,
+    pub timestamp: i64,
+    pub comment: Option<String>,
+}
+
+impl From<ActivityLog> for ActivityLogDto {
+    fn from(a: ActivityLog) -> Self {
+        Self {
+            id: a.id.to_string(),
+            entity_type: a.entity_type,
+            entity_id: a.entity_id.to_string(),
+            action: a.action,
+            field_name: a.field_name,
+            old_value: a.old_value,
+            new_value: a.new_value,
+            user_id: a.user_id.to_string(),
+            timestamp: a.timestamp.timestamp(),
+            comment: a.comment,
+        }
+    }
+}
@@ -1,8 +1,9 @@
 use crate::Comment;
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CommentDto {
     pub id: String,
     pub work_item_id: String,
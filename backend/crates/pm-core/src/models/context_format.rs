@@ -0,0 +1,40 @@
+use crate::{CoreError, CoreResult};
+
+use std::panic::Location;
+use std::str::FromStr;
+
+use error_location::ErrorLocation;
+use serde::{Deserialize, Serialize};
+
+/// Output format of a work item context pack
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ContextFormat {
+    Markdown,
+    Json,
+}
+
+impl ContextFormat {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Markdown => "markdown",
+            Self::Json => "json",
+        }
+    }
+}
+
+impl FromStr for ContextFormat {
+    type Err = CoreError;
+
+    #[track_caller]
+    fn from_str(s: &str) -> CoreResult<Self> {
+        match s {
+            "markdown" => Ok(Self::Markdown),
+            "json" => Ok(Self::Json),
+            _ => Err(CoreError::InvalidContextFormat {
+                value: s.to_string(),
+                location: ErrorLocation::from(Location::caller()),
+            }),
+        }
+    }
+}
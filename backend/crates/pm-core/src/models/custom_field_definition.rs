@@ -0,0 +1,262 @@
+use crate::{
+    CoreError, CoreResult, CustomFieldDefinitionDto, CustomFieldType, parse_timestamp, parse_uuid,
+};
+
+use std::panic::Location;
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use error_location::ErrorLocation;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+/// Maximum length of a custom field name (the key used in work item values)
+pub const MAX_CUSTOM_FIELD_NAME_LENGTH: usize = 64;
+
+/// Maximum number of options a select field may define
+pub const MAX_CUSTOM_FIELD_OPTIONS: usize = 100;
+
+/// A typed, per-project custom field.
+///
+/// Work items store their values in a JSON map keyed by `name`, so the name
+/// is immutable once the field exists. The `label` is what UIs display.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomFieldDefinition {
+    pub id: Uuid,
+    pub project_id: Uuid,
+
+    /// Stable key (lowercase snake_case), e.g. "severity"
+    pub name: String,
+    /// Human-readable label, e.g. "Severity"
+    pub label: String,
+    pub field_type: CustomFieldType,
+
+    /// Allowed values for single_select / multi_select fields
+    pub options: Vec<String>,
+    pub required: bool,
+    /// Value applied to new work items when none is supplied
+    pub default_value: Option<Value>,
+    pub position: i32,
+
+    pub version: i32,
+
+    // Audit
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub created_by: Uuid,
+    pub updated_by: Uuid,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl CustomFieldDefinition {
+    pub fn new(
+        project_id: Uuid,
+        name: String,
+        label: String,
+        field_type: CustomFieldType,
+        created_by: Uuid,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            project_id,
+            name,
+            label,
+            field_type,
+            options: Vec::new(),
+            required: false,
+            default_value: None,
+            position: 0,
+            version: 1,
+            created_at: now,
+            updated_at: now,
+            created_by,
+            updated_by: created_by,
+            deleted_at: None,
+        }
+    }
+
+    /// Validate a field name: starts with a lowercase letter, then lowercase
+    /// letters, digits or underscores.
+    #[track_caller]
+    pub fn validate_name(name: &str) -> CoreResult<()> {
+        let mut chars = name.chars();
+        let valid = name.len() <= MAX_CUSTOM_FIELD_NAME_LENGTH
+            && chars.next().is_some_and(|c| c.is_ascii_lowercase())
+            && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+
+        if !valid {
+            return Err(CoreError::Validation {
+                message: format!(
+                    "Invalid custom field name '{}': must start with a lowercase letter and contain \
+                     only lowercase letters, digits and underscores (max {} characters)",
+                    name, MAX_CUSTOM_FIELD_NAME_LENGTH
+                ),
+                field: Some("name".into()),
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+        Ok(())
+    }
+
+    /// Validate the definition itself (name, label, options and default value).
+    #[track_caller]
+    pub fn validate(&self) -> CoreResult<()> {
+        Self::validate_name(&self.name)?;
+
+        if self.label.trim().is_empty() {
+            return Err(CoreError::Validation {
+                message: "Custom field label cannot be empty".into(),
+                field: Some("label".into()),
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+
+        if self.field_type.is_select() {
+            if self.options.is_empty() || self.options.len() > MAX_CUSTOM_FIELD_OPTIONS {
+                return Err(CoreError::Validation {
+                    message: format!(
+                        "{} fields require between 1 and {} options",
+                        self.field_type.as_str(),
+                        MAX_CUSTOM_FIELD_OPTIONS
+                    ),
+                    field: Some("options".into()),
+                    location: ErrorLocation::from(Location::caller()),
+                });
+            }
+            for (i, option) in self.options.iter().enumerate() {
+                if option.trim().is_empty() || self.options[..i].contains(option) {
+                    return Err(CoreError::Validation {
+                        message: format!("Options must be non-empty and unique: '{}'", option),
+                        field: Some("options".into()),
+                        location: ErrorLocation::from(Location::caller()),
+                    });
+                }
+            }
+        } else if !self.options.is_empty() {
+            return Err(CoreError::Validation {
+                message: format!("{} fields do not take options", self.field_type.as_str()),
+                field: Some("options".into()),
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+
+        if let Some(ref default) = self.default_value {
+            self.normalize_value(default)
+                .map_err(|e| CoreError::Validation {
+                    message: format!("Invalid default value: {}", e),
+                    field: Some("default_value".into()),
+                    location: ErrorLocation::from(Location::caller()),
+                })?;
+        }
+
+        Ok(())
+    }
+
+    /// Validate a value against this field's type and return its canonical form.
+    ///
+    /// Canonical forms: text is trimmed, dates are `YYYY-MM-DD`, users are
+    /// hyphenated UUIDs, and multi-select values are de-duplicated. Whether a
+    /// user belongs to the project needs the database, so callers check it.
+    #[track_caller]
+    pub fn normalize_value(&self, value: &Value) -> CoreResult<Value> {
+        let invalid = |expected: &str| CoreError::Validation {
+            message: format!(
+                "Invalid value for custom field '{}': expected {}, got {}",
+                self.name, expected, value
+            ),
+            field: Some(format!("custom_fields.{}", self.name)),
+            location: ErrorLocation::from(Location::caller()),
+        };
+
+        match self.field_type {
+            CustomFieldType::Text => value
+                .as_str()
+                .map(|s| Value::String(s.trim().to_string()))
+                .ok_or_else(|| invalid("a string")),
+            CustomFieldType::Number => match value {
+                Value::Number(_) => Ok(value.clone()),
+                _ => Err(invalid("a number")),
+            },
+            CustomFieldType::Date => value
+                .as_str()
+                .and_then(|s| NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d").ok())
+                .map(|d| Value::String(d.format("%Y-%m-%d").to_string()))
+                .ok_or_else(|| invalid("a date (YYYY-MM-DD)")),
+            CustomFieldType::SingleSelect => value
+                .as_str()
+                .filter(|s| self.options.iter().any(|o| o == s))
+                .map(|s| Value::String(s.to_string()))
+                .ok_or_else(|| invalid(&format!("one of [{}]", self.options.join(", ")))),
+            CustomFieldType::MultiSelect => {
+                let expected = format!("a list drawn from [{}]", self.options.join(", "));
+                let items = value.as_array().ok_or_else(|| invalid(&expected))?;
+                let mut selected: Vec<Value> = Vec::with_capacity(items.len());
+                for item in items {
+                    let s = item
+                        .as_str()
+                        .filter(|s| self.options.iter().any(|o| o == s))
+                        .ok_or_else(|| invalid(&expected))?;
+                    if !selected.iter().any(|v| v == s) {
+                        selected.push(Value::String(s.to_string()));
+                    }
+                }
+                Ok(Value::Array(selected))
+            }
+            CustomFieldType::User => value
+                .as_str()
+                .and_then(|s| Uuid::parse_str(s.trim()).ok())
+                .map(|id| Value::String(id.to_string()))
+                .ok_or_else(|| invalid("a user ID (UUID)")),
+        }
+    }
+
+    /// Check whether a stored value matches a filter expression.
+    ///
+    /// Multi-select values match when any selected option equals the filter;
+    /// numbers compare numerically; everything else compares as text.
+    pub fn matches_filter(&self, value: &Value, expected: &str) -> bool {
+        match (self.field_type, value) {
+            (CustomFieldType::MultiSelect, Value::Array(items)) => {
+                items.iter().any(|v| v.as_str() == Some(expected))
+            }
+            (CustomFieldType::Number, Value::Number(n)) => expected
+                .parse::<f64>()
+                .is_ok_and(|e| n.as_f64().is_some_and(|v| v == e)),
+            (CustomFieldType::User, Value::String(s)) => s.eq_ignore_ascii_case(expected),
+            (_, Value::String(s)) => s == expected,
+            _ => false,
+        }
+    }
+}
+
+impl TryFrom<CustomFieldDefinitionDto> for CustomFieldDefinition {
+    type Error = CoreError;
+
+    fn try_from(dto: CustomFieldDefinitionDto) -> CoreResult<Self> {
+        Ok(CustomFieldDefinition {
+            id: parse_uuid(&dto.id, "custom_field.id")?,
+            project_id: parse_uuid(&dto.project_id, "custom_field.project_id")?,
+            name: dto.name,
+            label: dto.label,
+            field_type: CustomFieldType::from_str(&dto.field_type).map_err(|_| {
+                CoreError::Validation {
+                    message: format!("Invalid custom field type: {}", dto.field_type),
+                    field: Some("field_type".into()),
+                    location: ErrorLocation::from(Location::caller()),
+                }
+            })?,
+            options: dto.options,
+            required: dto.required,
+            default_value: dto.default_value,
+            position: dto.position,
+            version: dto.version,
+            created_at: parse_timestamp(dto.created_at, "custom_field.created_at")?,
+            updated_at: parse_timestamp(dto.updated_at, "custom_field.updated_at")?,
+            created_by: parse_uuid(&dto.created_by, "custom_field.created_by")?,
+            updated_by: parse_uuid(&dto.updated_by, "custom_field.updated_by")?,
+            deleted_at: None,
+        })
+    }
+}
@@ -0,0 +1,48 @@
+use crate::CustomFieldDefinition;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+
+/// Custom field definition DTO for JSON serialization and deserialization
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CustomFieldDefinitionDto {
+    pub id: String,
+    pub project_id: String,
+    pub name: String,
+    pub label: String,
+    pub field_type: String,
+    #[serde(default)]
+    pub options: Vec<String>,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default)]
+    pub default_value: Option<Value>,
+    pub position: i32,
+    pub version: i32,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub created_by: String,
+    pub updated_by: String,
+}
+
+impl From<CustomFieldDefinition> for CustomFieldDefinitionDto {
+    fn from(f: CustomFieldDefinition) -> Self {
+        Self {
+            id: f.id.to_string(),
+            project_id: f.project_id.to_string(),
+            name: f.name,
+            label: f.label,
+            field_type: f.field_type.as_str().to_string(),
+            options: f.options,
+            required: f.required,
+            default_value: f.default_value,
+            position: f.position,
+            version: f.version,
+            created_at: f.created_at.timestamp(),
+            updated_at: f.updated_at.timestamp(),
+            created_by: f.created_by.to_string(),
+            updated_by: f.updated_by.to_string(),
+        }
+    }
+}
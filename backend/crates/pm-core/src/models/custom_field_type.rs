@@ -0,0 +1,56 @@
+use crate::{CoreError, CoreResult};
+
+use std::panic::Location;
+use std::str::FromStr;
+
+use error_location::ErrorLocation;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomFieldType {
+    Text,
+    Number,
+    Date,
+    SingleSelect,
+    MultiSelect,
+    User,
+}
+
+impl CustomFieldType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Text => "text",
+            Self::Number => "number",
+            Self::Date => "date",
+            Self::SingleSelect => "single_select",
+            Self::MultiSelect => "multi_select",
+            Self::User => "user",
+        }
+    }
+
+    /// Select types require a non-empty list of options
+    pub fn is_select(&self) -> bool {
+        matches!(self, Self::SingleSelect | Self::MultiSelect)
+    }
+}
+
+impl FromStr for CustomFieldType {
+    type Err = CoreError;
+
+    #[track_caller]
+    fn from_str(s: &str) -> CoreResult<Self> {
+        match s {
+            "text" => Ok(Self::Text),
+            "number" => Ok(Self::Number),
+            "date" => Ok(Self::Date),
+            "single_select" => Ok(Self::SingleSelect),
+            "multi_select" => Ok(Self::MultiSelect),
+            "user" => Ok(Self::User),
+            _ => Err(CoreError::InvalidCustomFieldType {
+                value: s.to_string(),
+                location: ErrorLocation::from(Location::caller()),
+            }),
+        }
+    }
+}
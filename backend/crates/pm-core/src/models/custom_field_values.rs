@@ -0,0 +1,84 @@
+use crate::{CoreError, CoreResult, CustomFieldDefinition};
+
+use std::collections::BTreeMap;
+use std::panic::Location;
+
+use error_location::ErrorLocation;
+use serde_json::Value;
+
+/// Custom field values stored on a work item, keyed by field name
+pub type CustomFieldValues = BTreeMap<String, Value>;
+
+/// Merge requested custom field values into a work item's current values.
+///
+/// * Every key in `updates` must name a field in `definitions`; values are
+///   validated and normalized. A JSON `null` clears the value.
+/// * When `is_new` is set, defaults are applied to fields that were not supplied.
+/// * Required fields must have a value on new items and cannot be cleared.
+///   Existing items created before a field became required are left alone
+///   until the field is edited.
+/// * Values for fields whose definition no longer exists are dropped.
+#[track_caller]
+pub fn apply_custom_field_values(
+    definitions: &[CustomFieldDefinition],
+    current: &CustomFieldValues,
+    updates: &CustomFieldValues,
+    is_new: bool,
+) -> CoreResult<CustomFieldValues> {
+    let mut result: CustomFieldValues = current
+        .iter()
+        .filter(|(name, _)| definitions.iter().any(|d| &d.name == *name))
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect();
+
+    for (name, value) in updates {
+        let definition = definitions
+            .iter()
+            .find(|d| &d.name == name)
+            .ok_or_else(|| CoreError::Validation {
+                message: format!("Unknown custom field: {}", name),
+                field: Some(format!("custom_fields.{}", name)),
+                location: ErrorLocation::from(Location::caller()),
+            })?;
+
+        if value.is_null() {
+            result.remove(name);
+        } else {
+            result.insert(name.clone(), definition.normalize_value(value)?);
+        }
+    }
+
+    for definition in definitions {
+        if result.contains_key(&definition.name) {
+            continue;
+        }
+        let explicitly_cleared = updates.contains_key(&definition.name);
+
+        if is_new
+            && !explicitly_cleared
+            && let Some(ref default) = definition.default_value
+        {
+            result.insert(
+                definition.name.clone(),
+                definition.normalize_value(default)?,
+            );
+        } else if definition.required && (is_new || explicitly_cleared) {
+            return Err(CoreError::Validation {
+                message: format!("Custom field '{}' is required", definition.name),
+                field: Some(format!("custom_fields.{}", definition.name)),
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+    }
+
+    Ok(result)
+}
+
+/// Render a custom field value for display and activity log entries.
+/// Strings are shown as-is; other values use their compact JSON form.
+pub fn display_custom_field_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
@@ -1,8 +1,9 @@
 use crate::Dependency;
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct DependencyDto {
     pub id: String,
     pub blocking_item_id: String,
@@ -0,0 +1,75 @@
+use crate::WorkItem;
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Estimates and logged time for a work item and all of its descendants
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct EstimateRollup {
+    pub original_estimate_seconds: i64,
+    pub remaining_estimate_seconds: i64,
+    /// Completed time entries logged against the subtree
+    pub logged_seconds: i64,
+    /// True when logged plus remaining time exceeds the original estimate
+    pub over_estimate: bool,
+}
+
+impl EstimateRollup {
+    /// Roll estimates up the hierarchy for every item in `items`.
+    ///
+    /// `logged_seconds` maps work item IDs to the time logged directly against
+    /// them. Each item's totals include its own values plus those of every
+    /// descendant present in `items`.
+    pub fn compute(
+        items: &[WorkItem],
+        logged_seconds: &HashMap<Uuid, i64>,
+    ) -> HashMap<Uuid, EstimateRollup> {
+        let parents: HashMap<Uuid, Option<Uuid>> =
+            items.iter().map(|i| (i.id, i.parent_id)).collect();
+        let mut rollups: HashMap<Uuid, EstimateRollup> = items
+            .iter()
+            .map(|i| (i.id, EstimateRollup::default()))
+            .collect();
+
+        for item in items {
+            let original = item.original_estimate_seconds.unwrap_or(0) as i64;
+            let remaining = item.remaining_estimate_seconds.unwrap_or(0) as i64;
+            let logged = logged_seconds.get(&item.id).copied().unwrap_or(0);
+
+            // Credit the item and each ancestor (cycle-safe)
+            let mut visited = HashSet::new();
+            let mut current = Some(item.id);
+            while let Some(id) = current {
+                if !visited.insert(id) {
+                    break;
+                }
+                let Some(rollup) = rollups.get_mut(&id) else {
+                    break;
+                };
+                rollup.original_estimate_seconds += original;
+                rollup.remaining_estimate_seconds += remaining;
+                rollup.logged_seconds += logged;
+                current = parents.get(&id).copied().flatten();
+            }
+        }
+
+        for rollup in rollups.values_mut() {
+            rollup.over_estimate = is_over_estimate(
+                rollup.original_estimate_seconds,
+                rollup.remaining_estimate_seconds,
+                rollup.logged_seconds,
+            );
+        }
+
+        rollups
+    }
+}
+
+/// An estimate is exceeded when the time already spent plus the time still
+/// expected is more than originally planned. Unestimated work is never over.
+pub fn is_over_estimate(original: i64, remaining: i64, logged: i64) -> bool {
+    original > 0 && logged + remaining > original
+}
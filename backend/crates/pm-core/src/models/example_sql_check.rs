@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Result of preparing every stored `example_sql` against the live schema
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct ExampleSqlReport {
+    /// Entries that have example SQL
+    pub checked: usize,
+    /// Entries whose example no longer prepares, in priority order
+    pub problems: Vec<ExampleSqlProblem>,
+}
+
+impl ExampleSqlReport {
+    pub fn is_ok(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+/// Why an example query failed to prepare
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExampleSqlIssue {
+    /// References a table that no longer exists
+    MissingTable,
+    /// References a column that no longer exists
+    MissingColumn,
+    /// Any other SQLite error (syntax, ambiguous names, ...)
+    Invalid,
+}
+
+/// One LLM context entry whose example SQL is broken
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct ExampleSqlProblem {
+    pub entry_id: String,
+    pub title: String,
+    pub issue: ExampleSqlIssue,
+    /// The missing table or column, as SQLite reported it
+    pub missing: Option<String>,
+    /// SQLite's error message
+    pub error: String,
+}
+
+impl ExampleSqlProblem {
+    /// Classify a SQLite prepare error for an entry
+    pub fn from_sqlite_error(entry_id: String, title: String, error: &str) -> Self {
+        let (issue, missing) = if let Some(name) = error.strip_prefix("no such table: ") {
+            (ExampleSqlIssue::MissingTable, Some(name.trim().to_string()))
+        } else if let Some(name) = error.strip_prefix("no such column: ") {
+            (
+                ExampleSqlIssue::MissingColumn,
+                Some(name.trim().to_string()),
+            )
+        } else {
+            (ExampleSqlIssue::Invalid, None)
+        };
+
+        Self {
+            entry_id,
+            title,
+            issue,
+            missing,
+            error: error.to_string(),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{CoreError, CoreResult};
+
+use std::panic::Location;
+use std::str::FromStr;
+
+use error_location::ErrorLocation;
+use serde::{Deserialize, Serialize};
+
+/// Text format a work item graph is rendered in
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum GraphFormat {
+    Mermaid,
+    Dot,
+}
+
+impl GraphFormat {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Mermaid => "mermaid",
+            Self::Dot => "dot",
+        }
+    }
+}
+
+impl FromStr for GraphFormat {
+    type Err = CoreError;
+
+    #[track_caller]
+    fn from_str(s: &str) -> CoreResult<Self> {
+        match s {
+            "mermaid" => Ok(Self::Mermaid),
+            "dot" => Ok(Self::Dot),
+            _ => Err(CoreError::InvalidGraphFormat {
+                value: s.to_string(),
+                location: ErrorLocation::from(Location::caller()),
+            }),
+        }
+    }
+}
@@ -1,12 +1,33 @@
-use crate::LlmContextType;
+use crate::{CoreError, CoreResult, LlmContextDto, LlmContextType, parse_timestamp, parse_uuid};
+
+use std::panic::Location;
+use std::str::FromStr;
 
 use chrono::{DateTime, Utc};
+use error_location::ErrorLocation;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// Maximum length of an LLM context title
+pub const MAX_LLM_CONTEXT_TITLE_LENGTH: usize = 200;
+
+/// Maximum length of an LLM context category
+pub const MAX_LLM_CONTEXT_CATEGORY_LENGTH: usize = 50;
+
+/// Maximum length of an LLM context body or example query
+pub const MAX_LLM_CONTEXT_CONTENT_LENGTH: usize = 20_000;
+
+/// Priorities range from 0 (background) to 100 (always include)
+pub const MAX_LLM_CONTEXT_PRIORITY: i32 = 100;
+
+/// An entry teaching agents about the schema, query patterns or rules.
+///
+/// Entries without a `project_id` are global; project entries apply only
+/// when an agent works in that project.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LlmContext {
     pub id: Uuid,
+    pub project_id: Option<Uuid>,
     pub context_type: LlmContextType,
 
     pub category: String,
@@ -34,6 +55,7 @@ impl LlmContext {
         let now = Utc::now();
         Self {
             id: Uuid::new_v4(),
+            project_id: None,
             context_type,
             category,
             title,
@@ -46,4 +68,96 @@ impl LlmContext {
             deleted_at: None,
         }
     }
+
+    /// Validate text fields and the priority range
+    #[track_caller]
+    pub fn validate(&self) -> CoreResult<()> {
+        let checks = [
+            ("category", &self.category, MAX_LLM_CONTEXT_CATEGORY_LENGTH),
+            ("title", &self.title, MAX_LLM_CONTEXT_TITLE_LENGTH),
+        ];
+        for (field, value, max) in checks {
+            if value.trim().is_empty() || value.chars().count() > max {
+                return Err(CoreError::Validation {
+                    message: format!("LLM context {} must be 1-{} characters", field, max),
+                    field: Some(field.into()),
+                    location: ErrorLocation::from(Location::caller()),
+                });
+            }
+        }
+
+        if self.content.trim().is_empty()
+            || self.content.chars().count() > MAX_LLM_CONTEXT_CONTENT_LENGTH
+        {
+            return Err(CoreError::Validation {
+                message: format!(
+                    "LLM context content must be 1-{} characters",
+                    MAX_LLM_CONTEXT_CONTENT_LENGTH
+                ),
+                field: Some("content".into()),
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+
+        if self
+            .example_sql
+            .as_ref()
+            .is_some_and(|sql| sql.chars().count() > MAX_LLM_CONTEXT_CONTENT_LENGTH)
+        {
+            return Err(CoreError::Validation {
+                message: format!(
+                    "LLM context example_sql must be at most {} characters",
+                    MAX_LLM_CONTEXT_CONTENT_LENGTH
+                ),
+                field: Some("example_sql".into()),
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+
+        if !(0..=MAX_LLM_CONTEXT_PRIORITY).contains(&self.priority) {
+            return Err(CoreError::Validation {
+                message: format!(
+                    "LLM context priority must be between 0 and {}",
+                    MAX_LLM_CONTEXT_PRIORITY
+                ),
+                field: Some("priority".into()),
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<LlmContextDto> for LlmContext {
+    type Error = CoreError;
+
+    fn try_from(dto: LlmContextDto) -> CoreResult<Self> {
+        let entry = LlmContext {
+            id: parse_uuid(&dto.id, "llm_context.id")?,
+            project_id: dto
+                .project_id
+                .as_deref()
+                .map(|id| parse_uuid(id, "llm_context.project_id"))
+                .transpose()?,
+            context_type: LlmContextType::from_str(&dto.context_type).map_err(|_| {
+                CoreError::Validation {
+                    message: format!("Invalid LLM context type: {}", dto.context_type),
+                    field: Some("context_type".into()),
+                    location: ErrorLocation::from(Location::caller()),
+                }
+            })?,
+            category: dto.category,
+            title: dto.title,
+            content: dto.content,
+            example_sql: dto.example_sql,
+            example_description: dto.example_description,
+            priority: dto.priority,
+            created_at: parse_timestamp(dto.created_at, "llm_context.created_at")?,
+            updated_at: parse_timestamp(dto.updated_at, "llm_context.updated_at")?,
+            deleted_at: None,
+        };
+        entry.validate()?;
+        Ok(entry)
+    }
 }
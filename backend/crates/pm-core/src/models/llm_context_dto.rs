@@ -0,0 +1,38 @@
+use crate::LlmContext;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LlmContextDto {
+    pub id: String,
+    /// None for global entries
+    pub project_id: Option<String>,
+    pub context_type: String,
+    pub category: String,
+    pub title: String,
+    pub content: String,
+    pub example_sql: Option<String>,
+    pub example_description: Option<String>,
+    pub priority: i32,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl From<LlmContext> for LlmContextDto {
+    fn from(c: LlmContext) -> Self {
+        Self {
+            id: c.id.to_string(),
+            project_id: c.project_id.map(|id| id.to_string()),
+            context_type: c.context_type.as_str().to_string(),
+            category: c.category,
+            title: c.title,
+            content: c.content,
+            example_sql: c.example_sql,
+            example_description: c.example_description,
+            priority: c.priority,
+            created_at: c.created_at.timestamp(),
+            updated_at: c.updated_at.timestamp(),
+        }
+    }
+}
@@ -40,8 +40,12 @@ impl FromStr for LlmContextType {
             "example" => Ok(Self::Example),
             "instruction" => Ok(Self::Instruction),
             _ => Err(CoreError::Validation {
-                message: format!("Invalid context type: {}", s),
-                field: Some("instruction".into()),
+                message: format!(
+                    "Invalid context_type: {}. Valid values: schema_doc, query_pattern, \
+                     business_rule, example, instruction",
+                    s
+                ),
+                field: Some("context_type".into()),
                 location: ErrorLocation::from(Location::caller()),
             }),
         }
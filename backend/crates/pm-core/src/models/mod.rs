@@ -1,22 +1,49 @@
 pub mod activity_log;
+pub mod activity_log_dto;
 pub mod comment;
 pub mod comment_dto;
+pub mod context_format;
+pub mod custom_field_definition;
+pub mod custom_field_definition_dto;
+pub mod custom_field_type;
+pub mod custom_field_values;
 pub mod dependency;
 pub mod dependency_dto;
 pub mod dependency_type;
+pub mod estimate_rollup;
+pub mod example_sql_check;
+pub mod graph_format;
 pub mod llm_context;
+pub mod llm_context_dto;
 pub mod llm_context_type;
 pub mod project;
 pub mod project_dto;
 pub mod project_member;
+pub mod project_member_dto;
 pub mod project_status;
+pub mod project_template;
+pub mod project_template_dto;
+pub mod rank;
+pub mod schedule;
+pub mod schedule_weight;
 pub mod sprint;
 pub mod sprint_dto;
+pub mod sprint_estimate_summary;
 pub mod sprint_status;
 pub mod swim_lane;
 pub mod swim_lane_dto;
+pub mod template_custom_field;
+pub mod template_instance;
+pub mod template_llm_context;
+pub mod template_structure;
+pub mod template_work_item;
 pub mod time_entry;
 pub mod time_entry_dto;
+pub mod time_report;
+pub mod time_report_group_by;
+pub mod time_report_period;
 pub mod work_item;
+pub mod work_item_context;
 pub mod work_item_dto;
+pub mod work_item_graph;
 pub mod work_item_type;
@@ -10,6 +10,9 @@ use error_location::ErrorLocation;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// Longest project key a display key (`KEY-123`) can carry
+pub const MAX_PROJECT_KEY_LENGTH: usize = 10;
+
 /// A project is a top-level organizational container.
 /// Unlike work items, projects have a unique key and status (active/archived).
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -60,6 +63,26 @@ impl Project {
     pub fn is_archived(&self) -> bool {
         self.status == ProjectStatus::Archived
     }
+
+    /// Check a key can appear in display keys: 1 to
+    /// [`MAX_PROJECT_KEY_LENGTH`] uppercase ASCII letters
+    #[track_caller]
+    pub fn validate_key(key: &str) -> CoreResult<()> {
+        if key.is_empty()
+            || key.len() > MAX_PROJECT_KEY_LENGTH
+            || !key.chars().all(|c| c.is_ascii_uppercase())
+        {
+            return Err(CoreError::Validation {
+                message: format!(
+                    "Invalid project key '{}': must be 1-{} uppercase letters (A-Z)",
+                    key, MAX_PROJECT_KEY_LENGTH
+                ),
+                field: Some("key".into()),
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+        Ok(())
+    }
 }
 
 impl TryFrom<ProjectDto> for Project {
@@ -1,9 +1,10 @@
 use crate::Project;
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// Project DTO for JSON serialization
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ProjectDto {
     pub id: String,
     pub key: String,
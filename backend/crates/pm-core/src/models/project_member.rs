@@ -1,4 +1,9 @@
+use crate::{CoreError, CoreResult, ProjectMemberDto, parse_timestamp, parse_uuid};
+
+use std::panic::Location;
+
 use chrono::{DateTime, Utc};
+use error_location::ErrorLocation;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -32,6 +37,28 @@ impl ProjectMember {
     }
 }
 
+impl TryFrom<ProjectMemberDto> for ProjectMember {
+    type Error = CoreError;
+
+    fn try_from(dto: ProjectMemberDto) -> CoreResult<Self> {
+        if !matches!(dto.role.as_str(), "viewer" | "editor" | "admin") {
+            return Err(CoreError::Validation {
+                message: format!("Invalid project member role: {}", dto.role),
+                field: Some("role".into()),
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+
+        Ok(ProjectMember {
+            id: parse_uuid(&dto.id, "project_member.id")?,
+            project_id: parse_uuid(&dto.project_id, "project_member.project_id")?,
+            user_id: parse_uuid(&dto.user_id, "project_member.user_id")?,
+            role: dto.role,
+            created_at: parse_timestamp(dto.created_at, "project_member.created_at")?,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Permission {
     View,
@@ -0,0 +1,26 @@
+use crate::ProjectMember;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProjectMemberDto {
+    pub id: String,
+    pub project_id: String,
+    pub user_id: String,
+    /// "viewer", "editor" or "admin"
+    pub role: String,
+    pub created_at: i64,
+}
+
+impl From<ProjectMember> for ProjectMemberDto {
+    fn from(m: ProjectMember) -> Self {
+        Self {
+            id: m.id.to_string(),
+            project_id: m.project_id.to_string(),
+            user_id: m.user_id.to_string(),
+            role: m.role,
+            created_at: m.created_at.timestamp(),
+        }
+    }
+}
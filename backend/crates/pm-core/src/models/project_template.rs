@@ -0,0 +1,71 @@
+use crate::{CoreError, CoreResult, TemplateStructure};
+
+use std::panic::Location;
+
+use chrono::{DateTime, Utc};
+use error_location::ErrorLocation;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Maximum length of a template name
+pub const MAX_TEMPLATE_NAME_LENGTH: usize = 64;
+
+/// A saved project structure that new projects can be created from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectTemplate {
+    pub id: Uuid,
+    /// Unique name, e.g. "client-engagement"
+    pub name: String,
+    pub description: Option<String>,
+    pub structure: TemplateStructure,
+
+    // Audit
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub created_by: Uuid,
+}
+
+impl ProjectTemplate {
+    pub fn new(
+        name: String,
+        description: Option<String>,
+        structure: TemplateStructure,
+        created_by: Uuid,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            description,
+            structure,
+            created_at: now,
+            updated_at: now,
+            created_by,
+        }
+    }
+
+    /// Validate a template name: non-empty, at most
+    /// [`MAX_TEMPLATE_NAME_LENGTH`] characters, and not a UUID (names and
+    /// IDs share one lookup)
+    #[track_caller]
+    pub fn validate_name(name: &str) -> CoreResult<()> {
+        let message = if name.trim().is_empty() {
+            "Template name cannot be empty".to_string()
+        } else if name.chars().count() > MAX_TEMPLATE_NAME_LENGTH {
+            format!(
+                "Template name exceeds {} characters",
+                MAX_TEMPLATE_NAME_LENGTH
+            )
+        } else if Uuid::parse_str(name).is_ok() {
+            "Template name cannot be a UUID".to_string()
+        } else {
+            return Ok(());
+        };
+
+        Err(CoreError::Validation {
+            message,
+            field: Some("name".into()),
+            location: ErrorLocation::from(Location::caller()),
+        })
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{ProjectTemplate, TemplateStructure};
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Project template DTO for JSON serialization
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProjectTemplateDto {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub structure: TemplateStructure,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub created_by: String,
+}
+
+impl From<ProjectTemplate> for ProjectTemplateDto {
+    fn from(t: ProjectTemplate) -> Self {
+        Self {
+            id: t.id.to_string(),
+            name: t.name,
+            description: t.description,
+            structure: t.structure,
+            created_at: t.created_at.timestamp(),
+            updated_at: t.updated_at.timestamp(),
+            created_by: t.created_by.to_string(),
+        }
+    }
+}
@@ -0,0 +1,156 @@
+//! Fractional ranks for backlog ordering
+//!
+//! A rank is a string of base-36 digits (`0-9`, then `a-z`) read as a
+//! fraction: `"i"` is 0.5, `"i8"` is a little more. Ranks compare byte by
+//! byte, so a database `ORDER BY rank` sorts them, and a new rank always
+//! fits between two neighbours. Placing an item therefore writes only its
+//! own row. Ranks never end in `0`, which keeps that gap open.
+//!
+//! Repeated placements in the same gap make ranks longer; once one passes
+//! [`MAX_RANK_LENGTH`] the project is rebalanced with [`spread_ranks`].
+
+use crate::{CoreError, CoreResult};
+
+use std::panic::Location;
+
+use error_location::ErrorLocation;
+
+/// Rank digits in ascending byte order
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// Ranks longer than this trigger a rebalance of their project
+pub const MAX_RANK_LENGTH: usize = 12;
+
+/// Headroom left per gap when spreading ranks, so items can be placed
+/// between any two neighbours a few times before ranks grow
+const SPREAD_GAP: u128 = 16;
+
+/// A rank between `before` and `after`; `None` is the start or end of the
+/// backlog. Fails if either rank is malformed or they are out of order.
+#[track_caller]
+pub fn rank_between(before: Option<&str>, after: Option<&str>) -> CoreResult<String> {
+    for rank in [before, after].into_iter().flatten() {
+        validate_rank(rank)?;
+    }
+    if let (Some(before), Some(after)) = (before, after)
+        && before >= after
+    {
+        return Err(CoreError::Validation {
+            message: format!(
+                "Rank '{}' must sort before '{}' to place an item between them",
+                before, after
+            ),
+            field: Some("rank".into()),
+            location: ErrorLocation::from(Location::caller()),
+        });
+    }
+
+    let rank = match (before, after) {
+        // Appending is the common case: step the last digit rather than
+        // halving the gap to the end, so ranks grow one digit per 35 appends
+        (Some(before), None) => increment(before.as_bytes()),
+        _ => midpoint(before.unwrap_or("").as_bytes(), after.map(str::as_bytes)),
+    };
+    Ok(String::from_utf8(rank).expect("rank digits are ASCII"))
+}
+
+/// `count` short, evenly spaced ranks in ascending order
+pub fn spread_ranks(count: usize) -> Vec<String> {
+    let slots = (count as u128 + 1) * SPREAD_GAP;
+    let mut width = 1;
+    let mut space: u128 = 36;
+    while space < slots {
+        width += 1;
+        space *= 36;
+    }
+    let step = space / (count as u128 + 1);
+
+    (1..=count as u128)
+        .map(|i| {
+            let mut value = i * step;
+            let mut digits = vec![b'0'; width];
+            for digit in digits.iter_mut().rev() {
+                *digit = DIGITS[(value % 36) as usize];
+                value /= 36;
+            }
+            while digits.last() == Some(&b'0') {
+                digits.pop();
+            }
+            String::from_utf8(digits).expect("rank digits are ASCII")
+        })
+        .collect()
+}
+
+/// Whether `rank` is long enough that its project should be rebalanced
+pub fn rank_needs_rebalance(rank: &str) -> bool {
+    rank.len() > MAX_RANK_LENGTH
+}
+
+#[track_caller]
+fn validate_rank(rank: &str) -> CoreResult<()> {
+    if rank.is_empty() || rank.ends_with('0') || !rank.bytes().all(|b| DIGITS.contains(&b)) {
+        return Err(CoreError::Validation {
+            message: format!(
+                "Invalid rank '{}': must be digits 0-9 and a-z, not ending in 0",
+                rank
+            ),
+            field: Some("rank".into()),
+            location: ErrorLocation::from(Location::caller()),
+        });
+    }
+    Ok(())
+}
+
+fn value(digit: u8) -> usize {
+    DIGITS
+        .iter()
+        .position(|d| *d == digit)
+        .expect("validated rank digit")
+}
+
+/// The next rank after `rank` with nothing in between taken: its last digit
+/// plus one, or a `1` appended after a `z`
+fn increment(rank: &[u8]) -> Vec<u8> {
+    let mut next = rank.to_vec();
+    match next.last_mut() {
+        Some(last) if *last != b'z' => *last = DIGITS[value(*last) + 1],
+        _ => next.push(b'1'),
+    }
+    next
+}
+
+/// The shortest digits strictly between `a` and `b` (`None` = 1.0), where
+/// `a < b` and neither ends in `0`
+fn midpoint(a: &[u8], b: Option<&[u8]>) -> Vec<u8> {
+    // Keep the prefix the two share, reading a missing digit of `a` as 0
+    if let Some(b) = b {
+        let shared = b
+            .iter()
+            .enumerate()
+            .take_while(|(i, digit)| a.get(*i).copied().unwrap_or(b'0') == **digit)
+            .count();
+        if shared > 0 {
+            let mut rank = b[..shared].to_vec();
+            rank.extend(midpoint(
+                a.get(shared..).unwrap_or_default(),
+                Some(&b[shared..]),
+            ));
+            return rank;
+        }
+    }
+
+    let digit_a = a.first().map_or(0, |d| value(*d));
+    let digit_b = b.map_or(DIGITS.len(), |b| value(b[0]));
+    if digit_b - digit_a > 1 {
+        return vec![DIGITS[(digit_a + digit_b).div_ceil(2)]];
+    }
+    match b {
+        // `b`'s first digit alone sorts between the two
+        Some(b) if b.len() > 1 => vec![b[0]],
+        _ => {
+            let mut rank = vec![DIGITS[digit_a]];
+            rank.extend(midpoint(a.get(1..).unwrap_or_default(), None));
+            rank
+        }
+    }
+}
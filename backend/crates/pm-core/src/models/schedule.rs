@@ -0,0 +1,196 @@
+use crate::{CoreError, CoreResult, Dependency, DependencyType, ScheduleWeight, WorkItem};
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::panic::Location;
+
+use error_location::ErrorLocation;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Critical-path schedule over the `blocks` dependencies of a set of work items
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct Schedule {
+    pub weight: String,
+    /// Unit of every duration and offset: "seconds" or "points"
+    pub unit: String,
+    /// Length of the critical path (earliest finish of the whole set)
+    pub total_duration: i64,
+    /// Items with no estimate or story points, scheduled with zero duration
+    pub unweighted_item_count: usize,
+    /// Work item IDs along the critical path, first to last
+    pub critical_path: Vec<String>,
+    /// Items in topological order (every blocker before the items it blocks)
+    pub items: Vec<ScheduleItem>,
+}
+
+/// One work item's position in a schedule. The earliest start/end offsets
+/// double as Gantt bars; the latest ones show how far the item can slip.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ScheduleItem {
+    pub work_item_id: String,
+    pub display_key: String,
+    pub title: String,
+    pub item_type: String,
+    pub status: String,
+    pub duration: i64,
+    pub start_offset: i64,
+    pub end_offset: i64,
+    pub latest_start: i64,
+    pub latest_end: i64,
+    /// How long the item can be delayed without delaying the whole set
+    pub slack: i64,
+    pub critical: bool,
+    /// IDs of in-scope items that block this one
+    pub blocked_by: Vec<String>,
+}
+
+impl Schedule {
+    /// Compute the schedule for `items` using the `blocks` edges among them.
+    ///
+    /// Dependencies touching items outside the set, and `relates_to` links,
+    /// are ignored. Ties in the topological order are broken by item number
+    /// so the output is stable. Fails if the edges contain a cycle.
+    pub fn compute(
+        items: &[WorkItem],
+        dependencies: &[Dependency],
+        weight: ScheduleWeight,
+        project_key: &str,
+    ) -> CoreResult<Schedule> {
+        let mut items: Vec<&WorkItem> = items.iter().collect();
+        items.sort_by_key(|i| (i.item_number, i.id));
+        let index: HashMap<Uuid, usize> =
+            items.iter().enumerate().map(|(n, i)| (i.id, n)).collect();
+
+        // 1. Build the graph
+        let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); items.len()];
+        let mut successors: Vec<Vec<usize>> = vec![Vec::new(); items.len()];
+        let mut seen = HashSet::new();
+        for dep in dependencies {
+            if dep.dependency_type != DependencyType::Blocks || dep.deleted_at.is_some() {
+                continue;
+            }
+            let (Some(&from), Some(&to)) = (
+                index.get(&dep.blocking_item_id),
+                index.get(&dep.blocked_item_id),
+            ) else {
+                continue;
+            };
+            if seen.insert((from, to)) {
+                successors[from].push(to);
+                predecessors[to].push(from);
+            }
+        }
+
+        // 2. Topological order (Kahn), lowest item number first among ready items
+        let mut in_degree: Vec<usize> = predecessors.iter().map(Vec::len).collect();
+        let mut ready: BinaryHeap<Reverse<usize>> = in_degree
+            .iter()
+            .enumerate()
+            .filter(|(_, d)| **d == 0)
+            .map(|(n, _)| Reverse(n))
+            .collect();
+        let mut order = Vec::with_capacity(items.len());
+        while let Some(Reverse(n)) = ready.pop() {
+            order.push(n);
+            for &succ in &successors[n] {
+                in_degree[succ] -= 1;
+                if in_degree[succ] == 0 {
+                    ready.push(Reverse(succ));
+                }
+            }
+        }
+        if order.len() < items.len() {
+            let mut cyclic: Vec<String> = (0..items.len())
+                .filter(|n| in_degree[*n] > 0)
+                .map(|n| items[n].display_key(project_key))
+                .collect();
+            cyclic.sort();
+            return Err(CoreError::Validation {
+                message: format!("Dependency cycle among: {}", cyclic.join(", ")),
+                field: None,
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+
+        // 3. Forward pass: earliest start/finish
+        let durations: Vec<Option<i64>> = items.iter().map(|i| weight.duration_of(i)).collect();
+        let duration = |n: usize| durations[n].unwrap_or(0);
+        let mut earliest_start = vec![0i64; items.len()];
+        for &n in &order {
+            earliest_start[n] = predecessors[n]
+                .iter()
+                .map(|&p| earliest_start[p] + duration(p))
+                .max()
+                .unwrap_or(0);
+        }
+        let total_duration = (0..items.len())
+            .map(|n| earliest_start[n] + duration(n))
+            .max()
+            .unwrap_or(0);
+
+        // 4. Backward pass: latest finish
+        let mut latest_end = vec![total_duration; items.len()];
+        for &n in order.iter().rev() {
+            latest_end[n] = successors[n]
+                .iter()
+                .map(|&s| latest_end[s] - duration(s))
+                .min()
+                .unwrap_or(total_duration);
+        }
+        let slack = |n: usize| latest_end[n] - duration(n) - earliest_start[n];
+
+        // 5. Walk back from the critical item that finishes last
+        let mut critical_path = Vec::new();
+        let mut current = order
+            .iter()
+            .rev()
+            .copied()
+            .find(|&n| slack(n) == 0 && earliest_start[n] + duration(n) == total_duration);
+        while let Some(n) = current {
+            critical_path.push(items[n].id.to_string());
+            current = predecessors[n]
+                .iter()
+                .copied()
+                .find(|&p| slack(p) == 0 && earliest_start[p] + duration(p) == earliest_start[n]);
+        }
+        critical_path.reverse();
+
+        let schedule_items = order
+            .iter()
+            .map(|&n| {
+                let item = items[n];
+                let mut blocked_by: Vec<usize> = predecessors[n].clone();
+                blocked_by.sort();
+                ScheduleItem {
+                    work_item_id: item.id.to_string(),
+                    display_key: item.display_key(project_key),
+                    title: item.title.clone(),
+                    item_type: item.item_type.as_str().to_string(),
+                    status: item.status.clone(),
+                    duration: duration(n),
+                    start_offset: earliest_start[n],
+                    end_offset: earliest_start[n] + duration(n),
+                    latest_start: latest_end[n] - duration(n),
+                    latest_end: latest_end[n],
+                    slack: slack(n),
+                    critical: slack(n) == 0,
+                    blocked_by: blocked_by
+                        .into_iter()
+                        .map(|p| items[p].id.to_string())
+                        .collect(),
+                }
+            })
+            .collect();
+
+        Ok(Schedule {
+            weight: weight.as_str().to_string(),
+            unit: weight.unit().to_string(),
+            total_duration,
+            unweighted_item_count: durations.iter().filter(|d| d.is_none()).count(),
+            critical_path,
+            items: schedule_items,
+        })
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{CoreError, CoreResult, WorkItem};
+
+use std::panic::Location;
+use std::str::FromStr;
+
+use error_location::ErrorLocation;
+use serde::{Deserialize, Serialize};
+
+/// What a schedule uses as the duration of each work item
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduleWeight {
+    /// Remaining estimate in seconds, falling back to the original estimate
+    Estimate,
+    /// Story points
+    StoryPoints,
+}
+
+impl ScheduleWeight {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Estimate => "estimate",
+            Self::StoryPoints => "story_points",
+        }
+    }
+
+    /// Unit the durations and offsets of a schedule are expressed in
+    pub fn unit(&self) -> &str {
+        match self {
+            Self::Estimate => "seconds",
+            Self::StoryPoints => "points",
+        }
+    }
+
+    /// Duration of an item under this weight, or None when it has no value
+    pub fn duration_of(&self, item: &WorkItem) -> Option<i64> {
+        match self {
+            Self::Estimate => item
+                .remaining_estimate_seconds
+                .or(item.original_estimate_seconds)
+                .map(i64::from),
+            Self::StoryPoints => item.story_points.map(i64::from),
+        }
+    }
+}
+
+impl FromStr for ScheduleWeight {
+    type Err = CoreError;
+
+    #[track_caller]
+    fn from_str(s: &str) -> CoreResult<Self> {
+        match s {
+            "estimate" => Ok(Self::Estimate),
+            "story_points" => Ok(Self::StoryPoints),
+            _ => Err(CoreError::InvalidScheduleWeight {
+                value: s.to_string(),
+                location: ErrorLocation::from(Location::caller()),
+            }),
+        }
+    }
+}
@@ -1,9 +1,10 @@
 use crate::Sprint;
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// Sprint DTO for JSON serialization and deserialization
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct SprintDto {
     pub id: String,
     pub project_id: String,
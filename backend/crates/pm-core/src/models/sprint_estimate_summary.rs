@@ -0,0 +1,71 @@
+use crate::{EstimateRollup, WorkItem, is_over_estimate};
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Sprint-level totals of estimates against logged time
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SprintEstimateSummary {
+    pub sprint_id: String,
+    pub item_count: usize,
+    /// Items with an original estimate
+    pub estimated_item_count: usize,
+    /// Items whose own rolled-up estimate is exceeded
+    pub over_estimate_item_count: usize,
+    pub original_estimate_seconds: i64,
+    pub remaining_estimate_seconds: i64,
+    pub logged_seconds: i64,
+    pub over_estimate: bool,
+}
+
+impl SprintEstimateSummary {
+    /// Summarize the items assigned to `sprint_id`.
+    ///
+    /// Totals use each item's own values so a parent and child in the same
+    /// sprint are not counted twice. `all_items` must contain every item in
+    /// the project so per-item roll-ups see the full hierarchy.
+    pub fn compute(
+        sprint_id: Uuid,
+        all_items: &[WorkItem],
+        logged_seconds: &HashMap<Uuid, i64>,
+    ) -> Self {
+        let rollups = EstimateRollup::compute(all_items, logged_seconds);
+        let sprint_items: Vec<&WorkItem> = all_items
+            .iter()
+            .filter(|i| i.sprint_id == Some(sprint_id))
+            .collect();
+
+        let original: i64 = sprint_items
+            .iter()
+            .map(|i| i.original_estimate_seconds.unwrap_or(0) as i64)
+            .sum();
+        let remaining: i64 = sprint_items
+            .iter()
+            .map(|i| i.remaining_estimate_seconds.unwrap_or(0) as i64)
+            .sum();
+        let logged: i64 = sprint_items
+            .iter()
+            .map(|i| logged_seconds.get(&i.id).copied().unwrap_or(0))
+            .sum();
+
+        Self {
+            sprint_id: sprint_id.to_string(),
+            item_count: sprint_items.len(),
+            estimated_item_count: sprint_items
+                .iter()
+                .filter(|i| i.original_estimate_seconds.is_some())
+                .count(),
+            over_estimate_item_count: sprint_items
+                .iter()
+                .filter(|i| rollups.get(&i.id).is_some_and(|r| r.over_estimate))
+                .count(),
+            original_estimate_seconds: original,
+            remaining_estimate_seconds: remaining,
+            logged_seconds: logged,
+            over_estimate: is_over_estimate(original, remaining, logged),
+        }
+    }
+}
@@ -1,9 +1,10 @@
 use crate::SwimLane;
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// Swim Lane DTO for JSON serialization
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct SwimLaneDto {
     pub id: String,
     pub project_id: String,
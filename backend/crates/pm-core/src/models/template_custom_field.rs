@@ -0,0 +1,36 @@
+use crate::{CustomFieldDefinition, CustomFieldType};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+
+/// A custom field definition in a project template
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TemplateCustomField {
+    pub name: String,
+    pub label: String,
+    #[schema(value_type = String)]
+    pub field_type: CustomFieldType,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<String>,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<Value>,
+    #[serde(default)]
+    pub position: i32,
+}
+
+impl From<&CustomFieldDefinition> for TemplateCustomField {
+    fn from(f: &CustomFieldDefinition) -> Self {
+        Self {
+            name: f.name.clone(),
+            label: f.label.clone(),
+            field_type: f.field_type,
+            options: f.options.clone(),
+            required: f.required,
+            default_value: f.default_value.clone(),
+            position: f.position,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use crate::{CustomFieldDefinition, LlmContext, WorkItem};
+
+/// The records a template creates in a new project, ready to insert.
+/// Work items are ordered parents first.
+#[derive(Debug, Clone)]
+pub struct TemplateInstance {
+    pub custom_fields: Vec<CustomFieldDefinition>,
+    pub work_items: Vec<WorkItem>,
+    pub llm_context: Vec<LlmContext>,
+    /// The project's work item counter after these items
+    pub next_work_item_number: i32,
+}
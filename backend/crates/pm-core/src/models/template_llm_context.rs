@@ -0,0 +1,34 @@
+use crate::{LlmContext, LlmContextType};
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// A project-specific LLM context entry in a project template
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TemplateLlmContext {
+    #[schema(value_type = String)]
+    pub context_type: LlmContextType,
+    pub category: String,
+    pub title: String,
+    pub content: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub example_sql: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub example_description: Option<String>,
+    #[serde(default)]
+    pub priority: i32,
+}
+
+impl From<&LlmContext> for TemplateLlmContext {
+    fn from(c: &LlmContext) -> Self {
+        Self {
+            context_type: c.context_type.clone(),
+            category: c.category.clone(),
+            title: c.title.clone(),
+            content: c.content.clone(),
+            example_sql: c.example_sql.clone(),
+            example_description: c.example_description.clone(),
+            priority: c.priority,
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use crate::{
+    CoreError, CoreResult, CustomFieldDefinition, CustomFieldValues, LlmContext,
+    TemplateCustomField, TemplateInstance, TemplateLlmContext, TemplateWorkItem, WorkItem,
+    apply_custom_field_values, spread_ranks,
+};
+
+use std::collections::{HashMap, HashSet};
+use std::panic::Location;
+
+use error_location::ErrorLocation;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// What a project template creates: custom fields, starter work items and
+/// project LLM context. Nothing with history (sprints, comments, time
+/// entries, activity) is kept.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TemplateStructure {
+    #[serde(default)]
+    pub custom_fields: Vec<TemplateCustomField>,
+    #[serde(default)]
+    pub work_items: Vec<TemplateWorkItem>,
+    #[serde(default)]
+    pub llm_context: Vec<TemplateLlmContext>,
+}
+
+impl TemplateStructure {
+    /// Capture a project's structure. Items are numbered 1.. in their
+    /// current item number order and positioned in backlog order; parents
+    /// outside `work_items` are dropped.
+    pub fn from_project(
+        work_items: &[WorkItem],
+        custom_fields: &[CustomFieldDefinition],
+        llm_context: &[LlmContext],
+    ) -> Self {
+        let mut items: Vec<&WorkItem> = work_items.iter().collect();
+        items.sort_by_key(|w| w.item_number);
+        let numbers: HashMap<Uuid, i32> = items
+            .iter()
+            .enumerate()
+            .map(|(i, w)| (w.id, i as i32 + 1))
+            .collect();
+
+        let mut backlog: Vec<&WorkItem> = work_items.iter().collect();
+        backlog.sort_by(|a, b| (&a.rank, a.item_number).cmp(&(&b.rank, b.item_number)));
+        let positions: HashMap<Uuid, i32> = backlog
+            .iter()
+            .enumerate()
+            .map(|(i, w)| (w.id, i as i32))
+            .collect();
+
+        let mut fields: Vec<&CustomFieldDefinition> = custom_fields.iter().collect();
+        fields.sort_by_key(|f| f.position);
+
+        Self {
+            custom_fields: fields.into_iter().map(TemplateCustomField::from).collect(),
+            work_items: items
+                .into_iter()
+                .map(|w| TemplateWorkItem {
+                    number: numbers[&w.id],
+                    parent: w.parent_id.and_then(|id| numbers.get(&id).copied()),
+                    item_type: w.item_type.clone(),
+                    title: w.title.clone(),
+                    description: w.description.clone(),
+                    priority: w.priority.clone(),
+                    story_points: w.story_points,
+                    original_estimate_seconds: w.original_estimate_seconds,
+                    position: positions[&w.id],
+                    custom_fields: w.custom_fields.clone(),
+                })
+                .collect(),
+            llm_context: llm_context.iter().map(TemplateLlmContext::from).collect(),
+        }
+    }
+
+    /// Check the template can be instantiated
+    #[track_caller]
+    pub fn validate(&self) -> CoreResult<()> {
+        self.instantiate(Uuid::nil(), Uuid::nil()).map(|_| ())
+    }
+
+    /// Build the records for a new project: fresh IDs throughout, work
+    /// items numbered 1.. with parents before their children, and ranked
+    /// by position (ties keep template order).
+    #[track_caller]
+    pub fn instantiate(&self, project_id: Uuid, created_by: Uuid) -> CoreResult<TemplateInstance> {
+        let mut custom_fields: Vec<CustomFieldDefinition> = Vec::new();
+        for template in &self.custom_fields {
+            if custom_fields.iter().any(|f| f.name == template.name) {
+                return Err(invalid(
+                    format!("Duplicate custom field '{}'", template.name),
+                    "custom_fields",
+                ));
+            }
+            let mut field = CustomFieldDefinition::new(
+                project_id,
+                template.name.clone(),
+                template.label.clone(),
+                template.field_type,
+                created_by,
+            );
+            field.options = template.options.clone();
+            field.required = template.required;
+            field.position = template.position;
+            field.validate()?;
+            field.default_value = template
+                .default_value
+                .as_ref()
+                .map(|v| field.normalize_value(v))
+                .transpose()?;
+            custom_fields.push(field);
+        }
+
+        let ordered = self.parents_first()?;
+        let mut backlog: Vec<&TemplateWorkItem> = self.work_items.iter().collect();
+        backlog.sort_by_key(|w| w.position);
+        let ranks: HashMap<i32, String> = backlog
+            .iter()
+            .map(|w| w.number)
+            .zip(spread_ranks(backlog.len()))
+            .collect();
+
+        let mut ids: HashMap<i32, Uuid> = HashMap::new();
+        let mut work_items = Vec::with_capacity(ordered.len());
+        for (index, template) in ordered.into_iter().enumerate() {
+            let mut item = WorkItem::new(
+                template.item_type.clone(),
+                template.title.clone(),
+                template.description.clone(),
+                template.parent.map(|number| ids[&number]),
+                project_id,
+                created_by,
+            );
+            item.item_number = index as i32 + 1;
+            item.version = 1;
+            item.priority = template.priority.clone();
+            item.story_points = template.story_points;
+            item.original_estimate_seconds = template.original_estimate_seconds;
+            item.remaining_estimate_seconds = template.original_estimate_seconds;
+            item.position = template.position;
+            item.rank = ranks[&template.number].clone();
+            item.custom_fields = apply_custom_field_values(
+                &custom_fields,
+                &CustomFieldValues::new(),
+                &template.custom_fields,
+                true,
+            )?;
+            ids.insert(template.number, item.id);
+            work_items.push(item);
+        }
+
+        let mut llm_context = Vec::with_capacity(self.llm_context.len());
+        for template in &self.llm_context {
+            let mut entry = LlmContext::new(
+                template.context_type.clone(),
+                template.category.clone(),
+                template.title.clone(),
+                template.content.clone(),
+            );
+            entry.project_id = Some(project_id);
+            entry.example_sql = template.example_sql.clone();
+            entry.example_description = template.example_description.clone();
+            entry.priority = template.priority;
+            entry.validate()?;
+            llm_context.push(entry);
+        }
+
+        Ok(TemplateInstance {
+            next_work_item_number: work_items.len() as i32 + 1,
+            custom_fields,
+            work_items,
+            llm_context,
+        })
+    }
+
+    /// Work items reordered so every parent comes before its children,
+    /// otherwise keeping template order
+    #[track_caller]
+    fn parents_first(&self) -> CoreResult<Vec<&TemplateWorkItem>> {
+        let mut numbers = HashSet::new();
+        for item in &self.work_items {
+            if !numbers.insert(item.number) {
+                return Err(invalid(
+                    format!("Duplicate work item number {}", item.number),
+                    "work_items",
+                ));
+            }
+        }
+        for item in &self.work_items {
+            if let Some(parent) = item.parent
+                && !numbers.contains(&parent)
+            {
+                return Err(invalid(
+                    format!("Work item {} has unknown parent {}", item.number, parent),
+                    "work_items",
+                ));
+            }
+        }
+
+        let mut placed: HashSet<i32> = HashSet::new();
+        let mut ordered = Vec::with_capacity(self.work_items.len());
+        while ordered.len() < self.work_items.len() {
+            let before = ordered.len();
+            for item in &self.work_items {
+                if !placed.contains(&item.number) && item.parent.is_none_or(|p| placed.contains(&p))
+                {
+                    placed.insert(item.number);
+                    ordered.push(item);
+                }
+            }
+            if ordered.len() == before {
+                return Err(invalid("Work item parents form a cycle", "work_items"));
+            }
+        }
+        Ok(ordered)
+    }
+}
+
+#[track_caller]
+fn invalid(message: impl Into<String>, field: &str) -> CoreError {
+    CoreError::Validation {
+        message: message.into(),
+        field: Some(field.into()),
+        location: ErrorLocation::from(Location::caller()),
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{CustomFieldValues, WorkItemType};
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// A starter work item in a project template.
+///
+/// Items refer to each other by `number`, which is local to the template;
+/// instantiating it assigns fresh IDs and project item numbers. Status,
+/// assignee, sprint and logged time are not part of a template.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TemplateWorkItem {
+    /// Unique within the template
+    pub number: i32,
+    /// `number` of the parent item in the same template
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<i32>,
+    #[schema(value_type = String)]
+    pub item_type: WorkItemType,
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default = "default_priority")]
+    pub priority: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub story_points: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_estimate_seconds: Option<i32>,
+    /// Backlog order within the template; ties keep template order
+    #[serde(default)]
+    pub position: i32,
+    #[serde(default, skip_serializing_if = "CustomFieldValues::is_empty")]
+    #[schema(value_type = Object)]
+    pub custom_fields: CustomFieldValues,
+}
+
+fn default_priority() -> String {
+    "medium".to_string()
+}
@@ -1,6 +1,6 @@
-use crate::{CoreError, CoreResult, TimeEntryDto, parse_timestamp, parse_uuid};
+use crate::{CoreError, CoreResult, TimeEntryDto, TimeReportPeriod, parse_timestamp, parse_uuid};
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -49,6 +49,39 @@ impl TimeEntry {
     pub fn is_running(&self) -> bool {
         self.ended_at.is_none()
     }
+
+    /// Seconds of this entry falling within `[from, to)`, optionally split
+    /// into period buckets. Running timers count up to `now`.
+    pub fn seconds_in_range(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        now: DateTime<Utc>,
+        period: Option<TimeReportPeriod>,
+    ) -> Vec<(Option<NaiveDate>, i64)> {
+        let end = self.ended_at.unwrap_or(now).min(to);
+        let mut start = self.started_at.max(from);
+        if end <= start {
+            return vec![];
+        }
+
+        let Some(period) = period else {
+            return vec![(None, (end - start).num_seconds())];
+        };
+
+        let mut slices = Vec::new();
+        while start < end {
+            let bucket = period.bucket_start(start.date_naive());
+            let boundary = period
+                .next_bucket(bucket)
+                .and_time(NaiveTime::MIN)
+                .and_utc();
+            let slice_end = boundary.min(end);
+            slices.push((Some(bucket), (slice_end - start).num_seconds()));
+            start = slice_end;
+        }
+        slices
+    }
 }
 
 impl TryFrom<TimeEntryDto> for TimeEntry {
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Aggregated time-tracking report over a date range
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TimeReport {
+    /// First day of the range (YYYY-MM-DD, inclusive)
+    pub from: String,
+    /// Last day of the range (YYYY-MM-DD, inclusive)
+    pub to: String,
+    pub group_by: String,
+    /// Bucket size when rows are split by day or week
+    pub period: Option<String>,
+    pub generated_at: i64,
+    pub total_seconds: i64,
+    pub rows: Vec<TimeReportRow>,
+}
+
+/// One aggregated row: a user, work item or sprint, optionally within a period
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TimeReportRow {
+    /// User ID, work item ID or sprint ID (empty for time outside any sprint)
+    pub key: String,
+    /// Human-readable label (display key and title for items, sprint name, ...)
+    pub label: String,
+    /// First day of the bucket (YYYY-MM-DD) when the report has a period
+    pub period_start: Option<String>,
+    /// Time logged directly against this row
+    pub seconds: i64,
+    /// Item reports only: `seconds` plus time logged on all descendants
+    pub rollup_seconds: Option<i64>,
+}
+
+impl TimeReport {
+    /// Render the rows as CSV with a header line. Hours are rounded to two decimals.
+    pub fn to_csv(&self) -> String {
+        let mut out =
+            String::from("key,label,period_start,seconds,hours,rollup_seconds,rollup_hours\n");
+        for row in &self.rows {
+            let fields = [
+                csv_escape(&row.key),
+                csv_escape(&row.label),
+                row.period_start.clone().unwrap_or_default(),
+                row.seconds.to_string(),
+                format_hours(row.seconds),
+                row.rollup_seconds
+                    .map(|s| s.to_string())
+                    .unwrap_or_default(),
+                row.rollup_seconds.map(format_hours).unwrap_or_default(),
+            ];
+            out.push_str(&fields.join(","));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+fn format_hours(seconds: i64) -> String {
+    format!("{:.2}", seconds as f64 / 3600.0)
+}
+
+fn csv_escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
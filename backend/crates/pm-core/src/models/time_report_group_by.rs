@@ -0,0 +1,43 @@
+use crate::{CoreError, CoreResult};
+
+use std::panic::Location;
+use std::str::FromStr;
+
+use error_location::ErrorLocation;
+use serde::{Deserialize, Serialize};
+
+/// Dimension a time report is aggregated by
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeReportGroupBy {
+    User,
+    Item,
+    Sprint,
+}
+
+impl TimeReportGroupBy {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::User => "user",
+            Self::Item => "item",
+            Self::Sprint => "sprint",
+        }
+    }
+}
+
+impl FromStr for TimeReportGroupBy {
+    type Err = CoreError;
+
+    #[track_caller]
+    fn from_str(s: &str) -> CoreResult<Self> {
+        match s {
+            "user" => Ok(Self::User),
+            "item" => Ok(Self::Item),
+            "sprint" => Ok(Self::Sprint),
+            _ => Err(CoreError::InvalidTimeReportGroupBy {
+                value: s.to_string(),
+                location: ErrorLocation::from(Location::caller()),
+            }),
+        }
+    }
+}
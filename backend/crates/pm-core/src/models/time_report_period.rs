@@ -0,0 +1,57 @@
+use crate::{CoreError, CoreResult};
+
+use std::panic::Location;
+use std::str::FromStr;
+
+use chrono::{Datelike, Days, NaiveDate};
+use error_location::ErrorLocation;
+use serde::{Deserialize, Serialize};
+
+/// Calendar bucket for time reports. Buckets are UTC days, and weeks start on Monday.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeReportPeriod {
+    Day,
+    Week,
+}
+
+impl TimeReportPeriod {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Day => "day",
+            Self::Week => "week",
+        }
+    }
+
+    /// First day of the bucket containing `date`
+    pub fn bucket_start(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            Self::Day => date,
+            Self::Week => date - Days::new(date.weekday().num_days_from_monday() as u64),
+        }
+    }
+
+    /// First day of the bucket after the one starting at `start`
+    pub fn next_bucket(&self, start: NaiveDate) -> NaiveDate {
+        match self {
+            Self::Day => start + Days::new(1),
+            Self::Week => start + Days::new(7),
+        }
+    }
+}
+
+impl FromStr for TimeReportPeriod {
+    type Err = CoreError;
+
+    #[track_caller]
+    fn from_str(s: &str) -> CoreResult<Self> {
+        match s {
+            "day" => Ok(Self::Day),
+            "week" => Ok(Self::Week),
+            _ => Err(CoreError::InvalidTimeReportPeriod {
+                value: s.to_string(),
+                location: ErrorLocation::from(Location::caller()),
+            }),
+        }
+    }
+}
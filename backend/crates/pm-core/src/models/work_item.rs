@@ -1,4 +1,7 @@
-use crate::{CoreError, CoreResult, WorkItemDto, WorkItemType, parse_timestamp, parse_uuid};
+use crate::{
+    CoreError, CoreResult, CustomFieldValues, WorkItemDto, WorkItemType, parse_timestamp,
+    parse_uuid,
+};
 
 use std::panic::Location;
 use std::str::FromStr;
@@ -16,7 +19,12 @@ pub struct WorkItem {
     // Hierarchy
     pub parent_id: Option<Uuid>,
     pub project_id: Uuid,
+    /// Legacy integer order, kept for older clients; backlogs are ordered
+    /// by `rank`
     pub position: i32,
+    /// Fractional rank ordering the item within its project's backlog
+    /// (see [`rank_between`](crate::rank_between))
+    pub rank: String,
 
     // Core fields
     pub title: String,
@@ -32,9 +40,19 @@ pub struct WorkItem {
     // Agile
     pub story_points: Option<i32>,
 
+    // Estimates
+    /// Hour-based estimate set when the work was planned, in seconds
+    pub original_estimate_seconds: Option<i32>,
+    /// Time still expected to be needed, in seconds. Decremented as timers stop.
+    pub remaining_estimate_seconds: Option<i32>,
+
     // Sprint
     pub sprint_id: Option<Uuid>,
 
+    // Custom fields
+    /// Values for the project's custom fields, keyed by field name
+    pub custom_fields: CustomFieldValues,
+
     // JIRA-style ID
     /// Sequential number within project (e.g., 1, 2, 3...)
     /// Combined with project key to form display ID: "PROJ-123"
@@ -67,13 +85,17 @@ impl WorkItem {
             parent_id,
             project_id,
             position: 0,
+            rank: String::new(), // Assigned when the item is placed in a project
             title,
             description,
             status: "backlog".to_string(),
             priority: "medium".to_string(),
             assignee_id: None,
             story_points: None,
+            original_estimate_seconds: None,
+            remaining_estimate_seconds: None,
             sprint_id: None,
+            custom_fields: CustomFieldValues::new(),
             item_number: 0, // Will be set during DB insert
             version: 0,
             created_at: now,
@@ -88,6 +110,31 @@ impl WorkItem {
     pub fn display_key(&self, project_key: &str) -> String {
         format!("{}-{}", project_key, self.item_number)
     }
+
+    /// Apply time logged against this item to its remaining estimate.
+    ///
+    /// An explicit `override_seconds` replaces the remaining estimate;
+    /// otherwise it is decremented by `logged_seconds`, never below zero.
+    /// Items without a remaining estimate are left alone unless overridden.
+    /// Returns the `(old, new)` values when the estimate changed.
+    pub fn apply_logged_time(
+        &mut self,
+        logged_seconds: i32,
+        override_seconds: Option<i32>,
+    ) -> Option<(Option<i32>, i32)> {
+        let old = self.remaining_estimate_seconds;
+        let new = match (override_seconds, old) {
+            (Some(value), _) => value.max(0),
+            (None, Some(remaining)) => remaining.saturating_sub(logged_seconds.max(0)).max(0),
+            (None, None) => return None,
+        };
+
+        if old == Some(new) {
+            return None;
+        }
+        self.remaining_estimate_seconds = Some(new);
+        Some((old, new))
+    }
 }
 
 impl TryFrom<WorkItemDto> for WorkItem {
@@ -124,7 +171,11 @@ impl TryFrom<WorkItemDto> for WorkItem {
             status: dto.status,
             priority: dto.priority,
             position: dto.position,
+            rank: dto.rank,
             story_points: dto.story_points,
+            original_estimate_seconds: dto.original_estimate_seconds,
+            remaining_estimate_seconds: dto.remaining_estimate_seconds,
+            custom_fields: dto.custom_fields,
             item_number: dto.item_number,
             version: dto.version,
             created_at: parse_timestamp(dto.created_at, "work_item.created_at")?,
@@ -0,0 +1,360 @@
+use crate::{ActivityLog, Comment, ContextFormat, LlmContext, WorkItem};
+
+use std::collections::BTreeMap;
+
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Appended to a description cut short to fit the size budget
+const TRUNCATION_MARKER: &str = "… [truncated]";
+
+/// Everything an agent needs to brief itself on one work item, assembled
+/// into a single document and trimmed to a character budget
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WorkItemContext {
+    pub item: ContextItem,
+    /// Root first, direct parent last
+    pub ancestors: Vec<ContextItem>,
+    /// Other children of the same parent (or top-level items of the project)
+    pub siblings: Vec<ContextItem>,
+    /// Items this one waits on
+    pub blocked_by: Vec<ContextItem>,
+    /// Items waiting on this one
+    pub blocks: Vec<ContextItem>,
+    /// Newest first
+    pub comments: Vec<ContextComment>,
+    pub activity: ContextActivity,
+    /// Business rules and instructions for the project, highest priority first
+    pub rules: Vec<ContextRule>,
+    /// What was dropped to fit the budget
+    pub omitted: ContextOmitted,
+}
+
+/// A work item reduced to the fields worth putting in a prompt
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ContextItem {
+    pub key: String,
+    pub title: String,
+    pub item_type: String,
+    pub status: String,
+    pub priority: String,
+    pub assignee_id: Option<Uuid>,
+    pub story_points: Option<i32>,
+    /// Only set for the item the pack is about
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ContextComment {
+    pub author_id: Uuid,
+    pub created_at: i64,
+    pub content: String,
+}
+
+/// Summary of the item's activity log with the latest changes spelled out
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct ContextActivity {
+    pub total: usize,
+    /// Entry count per action (created, updated, ...)
+    pub by_action: BTreeMap<String, usize>,
+    pub last_changed_at: Option<i64>,
+    /// Newest first
+    pub recent: Vec<ContextActivityEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ContextActivityEntry {
+    pub timestamp: i64,
+    pub action: String,
+    pub field_name: Option<String>,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ContextRule {
+    pub title: String,
+    pub context_type: String,
+    pub category: String,
+    pub priority: i32,
+    pub content: String,
+}
+
+/// Counts of entries removed by [`WorkItemContext::fit_to_budget`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ContextOmitted {
+    pub activity: usize,
+    pub rules: usize,
+    pub siblings: usize,
+    pub comments: usize,
+    pub description_truncated: bool,
+}
+
+impl ContextOmitted {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl ContextItem {
+    pub fn new(item: &WorkItem, project_key: &str) -> Self {
+        Self {
+            key: item.display_key(project_key),
+            title: item.title.clone(),
+            item_type: item.item_type.as_str().to_string(),
+            status: item.status.clone(),
+            priority: item.priority.clone(),
+            assignee_id: item.assignee_id,
+            story_points: item.story_points,
+            description: None,
+        }
+    }
+
+    fn summary_line(&self) -> String {
+        format!(
+            "- {} ({}, {}): {}\n",
+            self.key,
+            self.item_type,
+            self.status,
+            single_line(&self.title)
+        )
+    }
+}
+
+impl From<&Comment> for ContextComment {
+    fn from(comment: &Comment) -> Self {
+        Self {
+            author_id: comment.created_by,
+            created_at: comment.created_at.timestamp(),
+            content: comment.content.clone(),
+        }
+    }
+}
+
+impl From<&ActivityLog> for ContextActivityEntry {
+    fn from(log: &ActivityLog) -> Self {
+        Self {
+            timestamp: log.timestamp.timestamp(),
+            action: log.action.clone(),
+            field_name: log.field_name.clone(),
+            old_value: log.old_value.clone(),
+            new_value: log.new_value.clone(),
+        }
+    }
+}
+
+impl From<&LlmContext> for ContextRule {
+    fn from(entry: &LlmContext) -> Self {
+        Self {
+            title: entry.title.clone(),
+            context_type: entry.context_type.as_str().to_string(),
+            category: entry.category.clone(),
+            priority: entry.priority,
+            content: entry.content.clone(),
+        }
+    }
+}
+
+impl ContextActivity {
+    /// Summarise a newest-first activity log, keeping at most `recent` entries
+    pub fn summarize(logs: &[ActivityLog], recent: usize) -> Self {
+        let mut by_action = BTreeMap::new();
+        for log in logs {
+            *by_action.entry(log.action.clone()).or_insert(0) += 1;
+        }
+        Self {
+            total: logs.len(),
+            by_action,
+            last_changed_at: logs.iter().map(|l| l.timestamp.timestamp()).max(),
+            recent: logs.iter().take(recent).map(Into::into).collect(),
+        }
+    }
+}
+
+impl WorkItemContext {
+    pub fn render(&self, format: ContextFormat) -> String {
+        match format {
+            ContextFormat::Markdown => self.to_markdown(),
+            ContextFormat::Json => serde_json::to_string_pretty(self).unwrap_or_default(),
+        }
+    }
+
+    /// Drop the least useful content until the rendered document fits in
+    /// `max_chars` characters: oldest activity first, then the lowest
+    /// priority rules, siblings, the oldest comments, and finally the tail of
+    /// the description. Ancestors and blockers are always kept.
+    pub fn fit_to_budget(&mut self, max_chars: usize, format: ContextFormat) {
+        loop {
+            let len = self.render(format).chars().count();
+            if len <= max_chars {
+                return;
+            }
+            if self.activity.recent.pop().is_some() {
+                self.omitted.activity += 1;
+            } else if self.rules.pop().is_some() {
+                self.omitted.rules += 1;
+            } else if self.siblings.pop().is_some() {
+                self.omitted.siblings += 1;
+            } else if self.comments.pop().is_some() {
+                self.omitted.comments += 1;
+            } else if !self.truncate_description(len - max_chars) {
+                return;
+            }
+        }
+    }
+
+    /// Shorten the description by at least `excess` characters. Returns
+    /// false when there is nothing left to cut.
+    fn truncate_description(&mut self, excess: usize) -> bool {
+        let Some(description) = self.item.description.as_mut() else {
+            return false;
+        };
+        // A repeat pass replaces the existing marker rather than adding another
+        let body = match description.strip_suffix(TRUNCATION_MARKER) {
+            Some(body) if self.omitted.description_truncated => body,
+            _ => description.as_str(),
+        };
+        let length = body.chars().count();
+        if length == 0 {
+            return false;
+        }
+        let keep = length.saturating_sub(excess + TRUNCATION_MARKER.chars().count());
+        let mut shortened: String = body.chars().take(keep).collect();
+        shortened.push_str(TRUNCATION_MARKER);
+        *description = shortened;
+        self.omitted.description_truncated = true;
+        true
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let item = &self.item;
+        let mut out = format!("# {}: {}\n\n", item.key, single_line(&item.title));
+        out.push_str(&format!("- **Type:** {}\n", item.item_type));
+        out.push_str(&format!("- **Status:** {}\n", item.status));
+        out.push_str(&format!("- **Priority:** {}\n", item.priority));
+        if let Some(assignee) = item.assignee_id {
+            out.push_str(&format!("- **Assignee:** {}\n", assignee));
+        }
+        if let Some(points) = item.story_points {
+            out.push_str(&format!("- **Story points:** {}\n", points));
+        }
+        if let Some(description) = item.description.as_deref().filter(|d| !d.is_empty()) {
+            out.push_str(&format!("\n## Description\n\n{}\n", description.trim_end()));
+        }
+
+        for (heading, items) in [
+            ("Ancestors", &self.ancestors),
+            ("Blocked by", &self.blocked_by),
+            ("Blocks", &self.blocks),
+            ("Siblings", &self.siblings),
+        ] {
+            if items.is_empty() {
+                continue;
+            }
+            out.push_str(&format!("\n## {}\n\n", heading));
+            for item in items {
+                out.push_str(&item.summary_line());
+            }
+        }
+
+        if !self.comments.is_empty() {
+            out.push_str("\n## Recent comments\n");
+            for comment in &self.comments {
+                out.push_str(&format!(
+                    "\n### {} by {}\n\n{}\n",
+                    format_timestamp(comment.created_at),
+                    comment.author_id,
+                    comment.content.trim_end()
+                ));
+            }
+        }
+
+        let activity = &self.activity;
+        if activity.total > 0 {
+            let counts: Vec<String> = activity
+                .by_action
+                .iter()
+                .map(|(action, count)| format!("{} {}", count, action))
+                .collect();
+            out.push_str(&format!(
+                "\n## Activity\n\n{} {} ({})",
+                activity.total,
+                if activity.total == 1 {
+                    "entry"
+                } else {
+                    "entries"
+                },
+                counts.join(", ")
+            ));
+            if let Some(last) = activity.last_changed_at {
+                out.push_str(&format!(", last change {}", format_timestamp(last)));
+            }
+            out.push_str("\n\n");
+            for entry in &activity.recent {
+                out.push_str(&format!(
+                    "- {} {}",
+                    format_timestamp(entry.timestamp),
+                    entry.action
+                ));
+                if let Some(field) = &entry.field_name {
+                    out.push_str(&format!(
+                        " {}: {} → {}",
+                        field,
+                        single_line(entry.old_value.as_deref().unwrap_or("∅")),
+                        single_line(entry.new_value.as_deref().unwrap_or("∅"))
+                    ));
+                }
+                out.push('\n');
+            }
+        }
+
+        if !self.rules.is_empty() {
+            out.push_str("\n## Rules\n");
+            for rule in &self.rules {
+                out.push_str(&format!(
+                    "\n### {} ({}, priority {})\n\n{}\n",
+                    single_line(&rule.title),
+                    rule.context_type,
+                    rule.priority,
+                    rule.content.trim_end()
+                ));
+            }
+        }
+
+        let omitted = &self.omitted;
+        if !omitted.is_empty() {
+            let mut parts = Vec::new();
+            for (count, what) in [
+                (omitted.activity, "activity entries"),
+                (omitted.rules, "rules"),
+                (omitted.siblings, "siblings"),
+                (omitted.comments, "comments"),
+            ] {
+                if count > 0 {
+                    parts.push(format!("{} {}", count, what));
+                }
+            }
+            if omitted.description_truncated {
+                parts.push("part of the description".to_string());
+            }
+            out.push_str(&format!(
+                "\n_Trimmed to fit the size budget: omitted {}._\n",
+                parts.join(", ")
+            ));
+        }
+
+        out
+    }
+}
+
+fn single_line(value: &str) -> String {
+    value.replace(['\r', '\n'], " ")
+}
+
+fn format_timestamp(timestamp: i64) -> String {
+    DateTime::from_timestamp(timestamp, 0)
+        .map(|t| t.format("%Y-%m-%d %H:%M UTC").to_string())
+        .unwrap_or_else(|| timestamp.to_string())
+}
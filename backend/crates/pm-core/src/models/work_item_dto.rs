@@ -1,9 +1,10 @@
-use crate::WorkItem;
+use crate::{CustomFieldValues, EstimateRollup, WorkItem};
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// Work item DTO for JSON serialization
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct WorkItemDto {
     pub id: String,
     pub display_key: String,
@@ -17,8 +18,22 @@ pub struct WorkItemDto {
     pub assignee_id: Option<String>,
     pub sprint_id: Option<String>,
     pub story_points: Option<i32>,
+    #[serde(default)]
+    pub original_estimate_seconds: Option<i32>,
+    #[serde(default)]
+    pub remaining_estimate_seconds: Option<i32>,
+    /// Estimates and logged time rolled up over the item's subtree.
+    /// Derived on read; ignored on import.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<EstimateRollup>,
+    #[serde(default)]
+    #[schema(value_type = Object)]
+    pub custom_fields: CustomFieldValues,
     pub item_number: i32,
     pub position: i32,
+    /// Backlog order within the project; compare as bytes
+    #[serde(default)]
+    pub rank: String,
     pub version: i32,
     pub created_at: i64,
     pub updated_at: i64,
@@ -42,8 +57,13 @@ impl WorkItemDto {
             assignee_id: w.assignee_id.map(|id| id.to_string()),
             sprint_id: w.sprint_id.map(|id| id.to_string()),
             story_points: w.story_points,
+            original_estimate_seconds: w.original_estimate_seconds,
+            remaining_estimate_seconds: w.remaining_estimate_seconds,
+            estimate: None,
+            custom_fields: w.custom_fields,
             item_number: w.item_number,
             position: w.position,
+            rank: w.rank,
             version: w.version,
             created_at: w.created_at.timestamp(),
             updated_at: w.updated_at.timestamp(),
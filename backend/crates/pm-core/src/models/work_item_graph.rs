@@ -0,0 +1,138 @@
+use crate::{DependencyType, GraphFormat, WorkItem};
+
+use serde::{Deserialize, Serialize};
+
+/// Work item hierarchy and dependency edges, ready to render as text
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorkItemGraph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+/// A work item in a graph, identified by its display key
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphNode {
+    pub key: String,
+    pub title: String,
+    pub item_type: String,
+    pub status: String,
+    /// Outside the selected subtree; only present as a dependency endpoint
+    pub external: bool,
+}
+
+/// Parent → child edge, or a dependency edge from blocking to blocked item
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphEdge {
+    pub from: String,
+    pub to: String,
+    /// None for hierarchy edges
+    pub dependency_type: Option<DependencyType>,
+}
+
+impl GraphNode {
+    pub fn new(item: &WorkItem, project_key: &str, external: bool) -> Self {
+        Self {
+            key: item.display_key(project_key),
+            title: item.title.clone(),
+            item_type: item.item_type.as_str().to_string(),
+            status: item.status.clone(),
+            external,
+        }
+    }
+}
+
+impl WorkItemGraph {
+    pub fn render(&self, format: GraphFormat) -> String {
+        match format {
+            GraphFormat::Mermaid => self.to_mermaid(),
+            GraphFormat::Dot => self.to_dot(),
+        }
+    }
+
+    /// Mermaid flowchart. Hierarchy edges are solid, `blocks` edges dotted
+    /// arrows and `relates_to` edges dotted lines; external nodes are dashed.
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::from("graph TD\n");
+        for node in &self.nodes {
+            out.push_str(&format!(
+                "    {}[\"{}: {}<br/>{} · {}\"]\n",
+                mermaid_id(&node.key),
+                node.key,
+                mermaid_escape(&node.title),
+                node.item_type,
+                node.status
+            ));
+        }
+        for edge in &self.edges {
+            let (from, to) = (mermaid_id(&edge.from), mermaid_id(&edge.to));
+            let line = match edge.dependency_type {
+                None => format!("    {} --> {}\n", from, to),
+                Some(DependencyType::Blocks) => format!("    {} -. blocks .-> {}\n", from, to),
+                Some(DependencyType::RelatesTo) => {
+                    format!("    {} -. relates_to .- {}\n", from, to)
+                }
+            };
+            out.push_str(&line);
+        }
+        for node in self.nodes.iter().filter(|n| n.external) {
+            out.push_str(&format!(
+                "    style {} stroke-dasharray: 5 5\n",
+                mermaid_id(&node.key)
+            ));
+        }
+        out
+    }
+
+    /// Graphviz digraph with the same conventions as [`Self::to_mermaid`]
+    pub fn to_dot(&self) -> String {
+        let mut out =
+            String::from("digraph work_items {\n    rankdir=TB;\n    node [shape=box];\n");
+        for node in &self.nodes {
+            let style = if node.external { ", style=dashed" } else { "" };
+            out.push_str(&format!(
+                "    \"{}\" [label=\"{}: {}\\n{} · {}\"{}];\n",
+                node.key,
+                node.key,
+                dot_escape(&node.title),
+                node.item_type,
+                node.status,
+                style
+            ));
+        }
+        for edge in &self.edges {
+            let attrs = match edge.dependency_type {
+                None => "",
+                Some(DependencyType::Blocks) => " [style=dashed, color=red, label=\"blocks\"]",
+                Some(DependencyType::RelatesTo) => {
+                    " [style=dotted, dir=none, label=\"relates_to\"]"
+                }
+            };
+            out.push_str(&format!(
+                "    \"{}\" -> \"{}\"{};\n",
+                edge.from, edge.to, attrs
+            ));
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// Mermaid node IDs cannot contain '-'
+fn mermaid_id(key: &str) -> String {
+    key.replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+}
+
+fn mermaid_escape(value: &str) -> String {
+    value
+        .replace('"', "#quot;")
+        .replace('\n', " ")
+        .replace('\r', "")
+}
+
+fn dot_escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', " ")
+        .replace('\r', "")
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// What an import does with a record changed on both sides since the last
+/// sync
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictStrategy {
+    /// Leave the local record and list the conflict
+    #[default]
+    Report,
+    /// Overwrite the local record with the imported one
+    Theirs,
+    /// Keep the local record without listing it as a conflict
+    Ours,
+}
+
+impl ConflictStrategy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Report => "report",
+            Self::Theirs => "theirs",
+            Self::Ours => "ours",
+        }
+    }
+
+    /// The same choice made from the other side of a sync, e.g. keeping
+    /// this server's records when it pushes means the receiver takes theirs
+    pub fn reversed(&self) -> Self {
+        match self {
+            Self::Report => Self::Report,
+            Self::Theirs => Self::Ours,
+            Self::Ours => Self::Theirs,
+        }
+    }
+}
@@ -1,8 +1,24 @@
-use serde::Serialize;
+use crate::sync::entity_import_diff::EntityImportDiff;
 
-#[derive(Debug, Default, Serialize)]
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
 pub struct EntityImportCounts {
     pub created: usize,
     pub updated: usize,
     pub skipped: usize,
+    #[serde(default)]
+    pub conflicts: usize,
+}
+
+impl From<&EntityImportDiff> for EntityImportCounts {
+    fn from(diff: &EntityImportDiff) -> Self {
+        Self {
+            created: diff.created.len(),
+            updated: diff.updated.len(),
+            skipped: diff.skipped.len(),
+            conflicts: diff.conflicts.len(),
+        }
+    }
 }
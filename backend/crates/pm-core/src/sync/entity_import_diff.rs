@@ -0,0 +1,77 @@
+use crate::sync::field_change::FieldChange;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// An existing record the import overwrites
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UpdatedEntry {
+    pub id: String,
+    pub changes: Vec<FieldChange>,
+}
+
+/// An imported record that was left alone
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SkippedEntry {
+    pub id: String,
+    /// Why, e.g. "local is newer"
+    pub reason: String,
+}
+
+/// A record changed both locally and in the import since the last sync,
+/// left as it is
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ConflictEntry {
+    pub id: String,
+    pub local_updated_at: DateTime<Utc>,
+    pub imported_updated_at: DateTime<Utc>,
+    /// What the import would change, from local to imported values
+    pub changes: Vec<FieldChange>,
+}
+
+/// What an import does to one entity type
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct EntityImportDiff {
+    /// IDs of records that don't exist locally
+    pub created: Vec<String>,
+    pub updated: Vec<UpdatedEntry>,
+    pub skipped: Vec<SkippedEntry>,
+    #[serde(default)]
+    pub conflicts: Vec<ConflictEntry>,
+}
+
+impl EntityImportDiff {
+    pub fn create(&mut self, id: impl ToString) {
+        self.created.push(id.to_string());
+    }
+
+    pub fn update(&mut self, id: impl ToString, changes: Vec<FieldChange>) {
+        self.updated.push(UpdatedEntry {
+            id: id.to_string(),
+            changes,
+        });
+    }
+
+    pub fn skip(&mut self, id: impl ToString, reason: impl Into<String>) {
+        self.skipped.push(SkippedEntry {
+            id: id.to_string(),
+            reason: reason.into(),
+        });
+    }
+
+    pub fn conflict(
+        &mut self,
+        id: impl ToString,
+        local_updated_at: DateTime<Utc>,
+        imported_updated_at: DateTime<Utc>,
+        changes: Vec<FieldChange>,
+    ) {
+        self.conflicts.push(ConflictEntry {
+            id: id.to_string(),
+            local_updated_at,
+            imported_updated_at,
+            changes,
+        });
+    }
+}
@@ -1,12 +1,18 @@
 use crate::{
-    CommentDto, DependencyDto, ProjectDto, SprintDto, SwimLaneDto, TimeEntryDto, WorkItemDto,
+    ActivityLogDto, CommentDto, CustomFieldDefinitionDto, DependencyDto, LlmContextDto, ProjectDto,
+    ProjectMemberDto, SprintDto, SwimLaneDto, TimeEntryDto, WorkItemDto,
 };
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Schema version written by this build. Older payloads are upgraded on
+/// import by [`upgrade_export_data`](crate::upgrade_export_data).
+pub const CURRENT_EXPORT_SCHEMA_VERSION: u32 = 3;
 
 /// Complete export/import payload containing all entity types
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ExportData {
-    /// Schema version for compatibility checks (current: 1)
+    /// Schema version for compatibility checks (current: 3)
     pub schema_version: u32,
 
     /// RFC3339 timestamp when data was exported
@@ -35,4 +41,16 @@ pub struct ExportData {
 
     /// All time entries
     pub time_entries: Vec<TimeEntryDto>,
+
+    /// Custom field definitions
+    pub custom_fields: Vec<CustomFieldDefinitionDto>,
+
+    /// Project memberships and roles (since version 2)
+    pub project_members: Vec<ProjectMemberDto>,
+
+    /// LLM context entries, global and per project (since version 2)
+    pub llm_context: Vec<LlmContextDto>,
+
+    /// Activity history (since version 2)
+    pub activity_log: Vec<ActivityLogDto>,
 }
@@ -0,0 +1,112 @@
+//! Upgrades for older export payloads
+//!
+//! Each historical schema version has one upgrade turning it into the next
+//! version, applied in order to the raw JSON until it reaches
+//! [`CURRENT_EXPORT_SCHEMA_VERSION`]. A new version adds its upgrade to
+//! [`UPGRADES`] and a fixture under `tests/sync/fixtures`; upgrades never
+//! change once released.
+//!
+//! Version history:
+//! - 1: projects, sprints, swim lanes, work items, comments, dependencies,
+//!   time entries; custom field definitions were added later and may be absent
+//! - 2: adds project members, LLM context and the activity log
+//! - 3: adds a fractional `rank` to work items, which orders the backlog
+
+use crate::sync::export_data::{CURRENT_EXPORT_SCHEMA_VERSION, ExportData};
+use crate::{CoreError, CoreResult, spread_ranks};
+
+use std::collections::BTreeMap;
+use std::panic::Location;
+
+use error_location::ErrorLocation;
+use serde_json::{Map, Value};
+
+/// Upgrade from version `n` is `UPGRADES[n - 1]`
+const UPGRADES: [fn(&mut Map<String, Value>); CURRENT_EXPORT_SCHEMA_VERSION as usize - 1] =
+    [upgrade_v1_to_v2, upgrade_v2_to_v3];
+
+/// Parse an export of any supported schema version, upgrading it to the
+/// current one
+#[track_caller]
+pub fn upgrade_export_data(value: Value) -> CoreResult<ExportData> {
+    let Value::Object(mut data) = value else {
+        return Err(invalid("Export data must be a JSON object", None));
+    };
+
+    let version = data
+        .get("schema_version")
+        .and_then(Value::as_u64)
+        .ok_or_else(|| invalid("Missing or invalid schema_version", Some("schema_version")))?;
+    if version == 0 || version > u64::from(CURRENT_EXPORT_SCHEMA_VERSION) {
+        return Err(invalid(
+            &format!(
+                "Unsupported schema version: {} (supported: 1-{})",
+                version, CURRENT_EXPORT_SCHEMA_VERSION
+            ),
+            Some("schema_version"),
+        ));
+    }
+
+    for upgrade in &UPGRADES[version as usize - 1..] {
+        upgrade(&mut data);
+    }
+    data.insert(
+        "schema_version".into(),
+        Value::from(CURRENT_EXPORT_SCHEMA_VERSION),
+    );
+
+    serde_json::from_value(Value::Object(data))
+        .map_err(|e| invalid(&format!("Invalid export data: {}", e), None))
+}
+
+/// Version 2 adds members, LLM context and activity; version 1 files from
+/// before custom fields have none
+fn upgrade_v1_to_v2(data: &mut Map<String, Value>) {
+    for field in [
+        "custom_fields",
+        "project_members",
+        "llm_context",
+        "activity_log",
+    ] {
+        data.entry(field)
+            .or_insert_with(|| Value::Array(Vec::new()));
+    }
+}
+
+/// Version 3 orders backlogs by rank; rank each project's items in their
+/// version 2 position order
+fn upgrade_v2_to_v3(data: &mut Map<String, Value>) {
+    let Some(Value::Array(work_items)) = data.get_mut("work_items") else {
+        return;
+    };
+
+    let mut projects: BTreeMap<String, Vec<&mut Map<String, Value>>> = BTreeMap::new();
+    for item in work_items.iter_mut().filter_map(Value::as_object_mut) {
+        let project_id = item
+            .get("project_id")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        projects.entry(project_id).or_default().push(item);
+    }
+
+    for items in projects.values_mut() {
+        items.sort_by_key(|item| {
+            let number = |field: &str| item.get(field).and_then(Value::as_i64).unwrap_or(0);
+            (number("position"), number("item_number"))
+        });
+        let ranks = spread_ranks(items.len());
+        for (item, rank) in items.iter_mut().zip(ranks) {
+            item.insert("rank".into(), Value::String(rank));
+        }
+    }
+}
+
+#[track_caller]
+fn invalid(message: &str, field: Option<&str>) -> CoreError {
+    CoreError::Validation {
+        message: message.to_string(),
+        field: field.map(str::to_string),
+        location: ErrorLocation::from(Location::caller()),
+    }
+}
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+
+/// One field an import changes on an existing record
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FieldChange {
+    pub field: String,
+    /// Local value before the import
+    pub old: Value,
+    /// Imported value
+    pub new: Value,
+}
+
+impl FieldChange {
+    /// Fields whose serialized values differ between `old` and `new`, in
+    /// field name order. Values that don't serialize to objects are
+    /// compared as a whole under the field name `value`.
+    pub fn between<T: Serialize>(old: &T, new: &T) -> Vec<FieldChange> {
+        let old = serde_json::to_value(old).unwrap_or(Value::Null);
+        let new = serde_json::to_value(new).unwrap_or(Value::Null);
+
+        match (old, new) {
+            (Value::Object(old), Value::Object(mut new)) => {
+                let mut changes: Vec<FieldChange> = old
+                    .into_iter()
+                    .filter_map(|(field, old_value)| {
+                        let new_value = new.remove(&field).unwrap_or(Value::Null);
+                        (old_value != new_value).then_some(FieldChange {
+                            field,
+                            old: old_value,
+                            new: new_value,
+                        })
+                    })
+                    .collect();
+                changes.extend(new.into_iter().filter(|(_, v)| !v.is_null()).map(
+                    |(field, new_value)| FieldChange {
+                        field,
+                        old: Value::Null,
+                        new: new_value,
+                    },
+                ));
+                changes.sort_by(|a, b| a.field.cmp(&b.field));
+                changes
+            }
+            (old, new) if old != new => vec![FieldChange {
+                field: "value".to_string(),
+                old,
+                new,
+            }],
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::sync::entity_import_diff::EntityImportDiff;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Per-entity record of what an import created, updated, skipped and
+/// reported as conflicting
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct ImportDiff {
+    pub projects: EntityImportDiff,
+    pub custom_fields: EntityImportDiff,
+    pub sprints: EntityImportDiff,
+    pub swim_lanes: EntityImportDiff,
+    pub work_items: EntityImportDiff,
+    pub comments: EntityImportDiff,
+    pub dependencies: EntityImportDiff,
+    pub time_entries: EntityImportDiff,
+    #[serde(default)]
+    pub project_members: EntityImportDiff,
+    #[serde(default)]
+    pub llm_context: EntityImportDiff,
+    #[serde(default)]
+    pub activity_log: EntityImportDiff,
+}
@@ -0,0 +1,54 @@
+use crate::sync::{conflict_strategy::ConflictStrategy, field_change::FieldChange};
+
+use chrono::{DateTime, Utc};
+
+/// How an import treats records that already exist locally
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ImportOptions {
+    /// When the two sides last synced, in the importer's clock. Local
+    /// records changed at or after it that differ from the import are
+    /// conflicts. Without it the later `updated_at` wins.
+    pub base: Option<DateTime<Utc>>,
+    pub on_conflict: ConflictStrategy,
+}
+
+/// What to do with an imported record that has a local copy
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportAction {
+    Update,
+    Skip(&'static str),
+    Conflict,
+}
+
+impl ImportOptions {
+    /// Decide between the local copy and the imported record, given the
+    /// `changes` the import would make
+    pub fn resolve(
+        &self,
+        local_updated_at: DateTime<Utc>,
+        imported_updated_at: DateTime<Utc>,
+        changes: &[FieldChange],
+    ) -> ImportAction {
+        if changes.is_empty() {
+            return ImportAction::Skip("local is up to date");
+        }
+
+        if let Some(base) = self.base
+            && local_updated_at >= base
+        {
+            return match self.on_conflict {
+                ConflictStrategy::Report => ImportAction::Conflict,
+                ConflictStrategy::Theirs => ImportAction::Update,
+                ConflictStrategy::Ours => ImportAction::Skip("kept local change"),
+            };
+        }
+
+        if imported_updated_at > local_updated_at {
+            ImportAction::Update
+        } else if local_updated_at > imported_updated_at {
+            ImportAction::Skip("local is newer")
+        } else {
+            ImportAction::Skip("local is up to date")
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// A record in an import payload that stops the import from being applied
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct ImportProblem {
+    /// Entity type, e.g. "work_item"
+    pub entity: String,
+    /// ID of the record as given in the payload
+    pub id: String,
+    /// Field at fault, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    pub message: String,
+}
+
+impl ImportProblem {
+    pub fn new(
+        entity: &str,
+        id: impl ToString,
+        field: Option<&str>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            entity: entity.to_string(),
+            id: id.to_string(),
+            field: field.map(str::to_string),
+            message: message.into(),
+        }
+    }
+}
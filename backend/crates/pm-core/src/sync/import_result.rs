@@ -1,14 +1,66 @@
-use crate::sync::entity_import_counts::EntityImportCounts;
+use crate::sync::{entity_import_counts::EntityImportCounts, import_diff::ImportDiff};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Default, Serialize)]
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
 pub struct ImportResult {
+    /// True when the import was rolled back instead of committed
+    pub dry_run: bool,
     pub projects: EntityImportCounts,
+    pub custom_fields: EntityImportCounts,
     pub sprints: EntityImportCounts,
     pub swim_lanes: EntityImportCounts,
     pub work_items: EntityImportCounts,
     pub comments: EntityImportCounts,
     pub dependencies: EntityImportCounts,
     pub time_entries: EntityImportCounts,
+    #[serde(default)]
+    pub project_members: EntityImportCounts,
+    #[serde(default)]
+    pub llm_context: EntityImportCounts,
+    #[serde(default)]
+    pub activity_log: EntityImportCounts,
+    /// The records behind the counts, with field-level changes for updates
+    pub diff: ImportDiff,
+}
+
+impl ImportResult {
+    pub fn new(diff: ImportDiff, dry_run: bool) -> Self {
+        Self {
+            dry_run,
+            projects: (&diff.projects).into(),
+            custom_fields: (&diff.custom_fields).into(),
+            sprints: (&diff.sprints).into(),
+            swim_lanes: (&diff.swim_lanes).into(),
+            work_items: (&diff.work_items).into(),
+            comments: (&diff.comments).into(),
+            dependencies: (&diff.dependencies).into(),
+            time_entries: (&diff.time_entries).into(),
+            project_members: (&diff.project_members).into(),
+            llm_context: (&diff.llm_context).into(),
+            activity_log: (&diff.activity_log).into(),
+            diff,
+        }
+    }
+
+    /// Records left alone because both sides changed them
+    pub fn conflicts(&self) -> usize {
+        [
+            &self.projects,
+            &self.custom_fields,
+            &self.sprints,
+            &self.swim_lanes,
+            &self.work_items,
+            &self.comments,
+            &self.dependencies,
+            &self.time_entries,
+            &self.project_members,
+            &self.llm_context,
+            &self.activity_log,
+        ]
+        .iter()
+        .map(|counts| counts.conflicts)
+        .sum()
+    }
 }
@@ -1,4 +1,12 @@
+pub(crate) mod conflict_strategy;
 pub(crate) mod entity_import_counts;
+pub(crate) mod entity_import_diff;
 pub(crate) mod export_data;
+pub(crate) mod export_upgrade;
+pub(crate) mod field_change;
+pub(crate) mod import_diff;
+pub(crate) mod import_options;
+pub(crate) mod import_problem;
 pub(crate) mod import_result;
 pub(crate) mod sync_handlers;
+pub(crate) mod sync_peer;
@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Another pm-server this one pulls from and pushes to, with how far each
+/// direction has got
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct SyncPeer {
+    /// Base URL of the peer, e.g. "http://10.0.0.5:8000"
+    pub url: String,
+    /// The peer's `exported_at` for the last complete pull (peer clock)
+    pub pulled_until: Option<DateTime<Utc>>,
+    /// When the last complete push started (local clock)
+    pub pushed_until: Option<DateTime<Utc>>,
+    /// When the last complete pull or push started (local clock). Local
+    /// records changed since then conflict with differing incoming ones.
+    pub synced_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SyncPeer {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            pulled_until: None,
+            pushed_until: None,
+            synced_at: None,
+            updated_at: Utc::now(),
+        }
+    }
+}
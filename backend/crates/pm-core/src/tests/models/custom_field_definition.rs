@@ -0,0 +1,184 @@
+use crate::{CustomFieldDefinition, CustomFieldType, CustomFieldValues, apply_custom_field_values};
+
+use std::str::FromStr;
+
+use serde_json::json;
+use uuid::Uuid;
+
+fn field(name: &str, field_type: CustomFieldType) -> CustomFieldDefinition {
+    CustomFieldDefinition::new(
+        Uuid::new_v4(),
+        name.to_string(),
+        name.to_string(),
+        field_type,
+        Uuid::new_v4(),
+    )
+}
+
+fn select(name: &str, field_type: CustomFieldType, options: &[&str]) -> CustomFieldDefinition {
+    let mut f = field(name, field_type);
+    f.options = options.iter().map(|o| o.to_string()).collect();
+    f
+}
+
+#[test]
+fn test_custom_field_type_round_trip() {
+    for t in [
+        CustomFieldType::Text,
+        CustomFieldType::Number,
+        CustomFieldType::Date,
+        CustomFieldType::SingleSelect,
+        CustomFieldType::MultiSelect,
+        CustomFieldType::User,
+    ] {
+        assert_eq!(CustomFieldType::from_str(t.as_str()).unwrap(), t);
+    }
+    assert!(CustomFieldType::from_str("checkbox").is_err());
+}
+
+#[test]
+fn test_validate_name() {
+    assert!(CustomFieldDefinition::validate_name("severity").is_ok());
+    assert!(CustomFieldDefinition::validate_name("due_date_2").is_ok());
+    assert!(CustomFieldDefinition::validate_name("").is_err());
+    assert!(CustomFieldDefinition::validate_name("2fast").is_err());
+    assert!(CustomFieldDefinition::validate_name("Severity").is_err());
+    assert!(CustomFieldDefinition::validate_name("has-dash").is_err());
+    assert!(CustomFieldDefinition::validate_name(&"a".repeat(65)).is_err());
+}
+
+#[test]
+fn test_validate_select_requires_unique_options() {
+    assert!(
+        select("sev", CustomFieldType::SingleSelect, &[])
+            .validate()
+            .is_err()
+    );
+    assert!(
+        select("sev", CustomFieldType::SingleSelect, &["low", "low"])
+            .validate()
+            .is_err()
+    );
+    assert!(
+        select("sev", CustomFieldType::SingleSelect, &["low", "high"])
+            .validate()
+            .is_ok()
+    );
+    assert!(
+        select("notes", CustomFieldType::Text, &["x"])
+            .validate()
+            .is_err()
+    );
+}
+
+#[test]
+fn test_validate_rejects_invalid_default() {
+    let mut f = select("sev", CustomFieldType::SingleSelect, &["low", "high"]);
+    f.default_value = Some(json!("medium"));
+    assert!(f.validate().is_err());
+
+    f.default_value = Some(json!("low"));
+    assert!(f.validate().is_ok());
+}
+
+#[test]
+fn test_normalize_value_by_type() {
+    let text = field("notes", CustomFieldType::Text);
+    assert_eq!(text.normalize_value(&json!("  hi ")).unwrap(), json!("hi"));
+    assert!(text.normalize_value(&json!(5)).is_err());
+
+    let number = field("cost", CustomFieldType::Number);
+    assert_eq!(number.normalize_value(&json!(2.5)).unwrap(), json!(2.5));
+    assert!(number.normalize_value(&json!("2.5")).is_err());
+
+    let date = field("due", CustomFieldType::Date);
+    assert_eq!(
+        date.normalize_value(&json!("2026-02-10")).unwrap(),
+        json!("2026-02-10")
+    );
+    assert!(date.normalize_value(&json!("2026-02-30")).is_err());
+
+    let multi = select("tags", CustomFieldType::MultiSelect, &["ui", "api"]);
+    assert_eq!(
+        multi.normalize_value(&json!(["api", "ui", "api"])).unwrap(),
+        json!(["api", "ui"])
+    );
+    assert!(multi.normalize_value(&json!(["db"])).is_err());
+    assert!(multi.normalize_value(&json!("ui")).is_err());
+
+    let user = field("reviewer", CustomFieldType::User);
+    let id = Uuid::new_v4();
+    assert_eq!(
+        user.normalize_value(&json!(id.to_string().to_uppercase()))
+            .unwrap(),
+        json!(id.to_string())
+    );
+    assert!(user.normalize_value(&json!("bob")).is_err());
+}
+
+#[test]
+fn test_matches_filter() {
+    let multi = select("tags", CustomFieldType::MultiSelect, &["ui", "api"]);
+    assert!(multi.matches_filter(&json!(["ui", "api"]), "api"));
+    assert!(!multi.matches_filter(&json!(["ui"]), "api"));
+
+    let number = field("cost", CustomFieldType::Number);
+    assert!(number.matches_filter(&json!(3), "3.0"));
+    assert!(!number.matches_filter(&json!(3), "4"));
+
+    let text = field("notes", CustomFieldType::Text);
+    assert!(text.matches_filter(&json!("x"), "x"));
+}
+
+#[test]
+fn test_apply_values_on_create_uses_defaults_and_enforces_required() {
+    let mut sev = select("sev", CustomFieldType::SingleSelect, &["low", "high"]);
+    sev.default_value = Some(json!("low"));
+    let mut team = field("team", CustomFieldType::Text);
+    team.required = true;
+    let defs = vec![sev, team];
+
+    let err = apply_custom_field_values(
+        &defs,
+        &CustomFieldValues::new(),
+        &CustomFieldValues::new(),
+        true,
+    );
+    assert!(err.is_err());
+
+    let updates = CustomFieldValues::from([("team".to_string(), json!("core"))]);
+    let values =
+        apply_custom_field_values(&defs, &CustomFieldValues::new(), &updates, true).unwrap();
+    assert_eq!(values["sev"], json!("low"));
+    assert_eq!(values["team"], json!("core"));
+}
+
+#[test]
+fn test_apply_values_on_update() {
+    let mut team = field("team", CustomFieldType::Text);
+    team.required = true;
+    let notes = field("notes", CustomFieldType::Text);
+    let defs = vec![team, notes];
+
+    let current = CustomFieldValues::from([
+        ("team".to_string(), json!("core")),
+        ("notes".to_string(), json!("n")),
+        ("removed_field".to_string(), json!("stale")),
+    ]);
+
+    // Clearing an optional field removes it; stale values are dropped
+    let updates = CustomFieldValues::from([("notes".to_string(), serde_json::Value::Null)]);
+    let values = apply_custom_field_values(&defs, &current, &updates, false).unwrap();
+    assert_eq!(
+        values,
+        CustomFieldValues::from([("team".to_string(), json!("core"))])
+    );
+
+    // Clearing a required field is rejected
+    let updates = CustomFieldValues::from([("team".to_string(), serde_json::Value::Null)]);
+    assert!(apply_custom_field_values(&defs, &current, &updates, false).is_err());
+
+    // Unknown fields are rejected
+    let updates = CustomFieldValues::from([("nope".to_string(), json!("x"))]);
+    assert!(apply_custom_field_values(&defs, &current, &updates, false).is_err());
+}
@@ -0,0 +1,108 @@
+use crate::{EstimateRollup, SprintEstimateSummary, WorkItem, WorkItemType, is_over_estimate};
+
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+fn item(parent_id: Option<Uuid>, original: Option<i32>, remaining: Option<i32>) -> WorkItem {
+    let mut item = WorkItem::new(
+        WorkItemType::Task,
+        "Item".to_string(),
+        None,
+        parent_id,
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+    );
+    item.original_estimate_seconds = original;
+    item.remaining_estimate_seconds = remaining;
+    item
+}
+
+#[test]
+fn test_apply_logged_time_decrements_remaining() {
+    let mut wi = item(None, Some(7200), Some(7200));
+
+    let change = wi.apply_logged_time(1800, None);
+
+    assert_eq!(change, Some((Some(7200), 5400)));
+    assert_eq!(wi.remaining_estimate_seconds, Some(5400));
+}
+
+#[test]
+fn test_apply_logged_time_never_goes_negative() {
+    let mut wi = item(None, Some(3600), Some(600));
+
+    wi.apply_logged_time(1800, None);
+
+    assert_eq!(wi.remaining_estimate_seconds, Some(0));
+    assert_eq!(wi.apply_logged_time(60, None), None);
+}
+
+#[test]
+fn test_apply_logged_time_override_wins() {
+    let mut wi = item(None, Some(3600), Some(3600));
+
+    let change = wi.apply_logged_time(1800, Some(3000));
+
+    assert_eq!(change, Some((Some(3600), 3000)));
+}
+
+#[test]
+fn test_apply_logged_time_without_estimate_is_noop() {
+    let mut wi = item(None, None, None);
+
+    assert_eq!(wi.apply_logged_time(1800, None), None);
+    assert_eq!(wi.remaining_estimate_seconds, None);
+}
+
+#[test]
+fn test_rollup_sums_descendants() {
+    let epic = item(None, Some(3600), Some(0));
+    let story = item(Some(epic.id), Some(7200), Some(3600));
+    let task = item(Some(story.id), None, Some(1800));
+    let logged = HashMap::from([(story.id, 5400), (task.id, 600)]);
+
+    let rollups = EstimateRollup::compute(&[epic.clone(), story.clone(), task.clone()], &logged);
+
+    let epic_rollup = &rollups[&epic.id];
+    assert_eq!(epic_rollup.original_estimate_seconds, 10800);
+    assert_eq!(epic_rollup.remaining_estimate_seconds, 5400);
+    assert_eq!(epic_rollup.logged_seconds, 6000);
+    assert!(epic_rollup.over_estimate);
+
+    let story_rollup = &rollups[&story.id];
+    assert_eq!(story_rollup.original_estimate_seconds, 7200);
+    assert_eq!(story_rollup.logged_seconds, 6000);
+    assert!(story_rollup.over_estimate);
+
+    let task_rollup = &rollups[&task.id];
+    assert!(!task_rollup.over_estimate, "unestimated work is never over");
+}
+
+#[test]
+fn test_over_estimate_boundary() {
+    assert!(!is_over_estimate(3600, 1800, 1800));
+    assert!(is_over_estimate(3600, 1800, 1801));
+    assert!(!is_over_estimate(0, 100, 100));
+}
+
+#[test]
+fn test_sprint_summary_counts_own_values_only() {
+    let sprint_id = Uuid::new_v4();
+    let mut parent = item(None, Some(3600), Some(3600));
+    parent.sprint_id = Some(sprint_id);
+    let mut child = item(Some(parent.id), Some(1800), Some(0));
+    child.sprint_id = Some(sprint_id);
+    let other = item(None, Some(99999), Some(99999));
+    let logged = HashMap::from([(child.id, 3600)]);
+
+    let summary = SprintEstimateSummary::compute(sprint_id, &[parent, child, other], &logged);
+
+    assert_eq!(summary.item_count, 2);
+    assert_eq!(summary.estimated_item_count, 2);
+    assert_eq!(summary.original_estimate_seconds, 5400);
+    assert_eq!(summary.remaining_estimate_seconds, 3600);
+    assert_eq!(summary.logged_seconds, 3600);
+    assert!(summary.over_estimate);
+    assert_eq!(summary.over_estimate_item_count, 2);
+}
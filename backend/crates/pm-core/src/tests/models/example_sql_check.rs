@@ -0,0 +1,30 @@
+use crate::{ExampleSqlIssue, ExampleSqlProblem};
+
+fn problem(error: &str) -> ExampleSqlProblem {
+    ExampleSqlProblem::from_sqlite_error("id".to_string(), "Title".to_string(), error)
+}
+
+#[test]
+fn test_missing_table_is_classified() {
+    let p = problem("no such table: pm_tasks");
+
+    assert_eq!(p.issue, ExampleSqlIssue::MissingTable);
+    assert_eq!(p.missing.as_deref(), Some("pm_tasks"));
+    assert_eq!(p.error, "no such table: pm_tasks");
+}
+
+#[test]
+fn test_missing_column_is_classified() {
+    let p = problem("no such column: wi.display_key");
+
+    assert_eq!(p.issue, ExampleSqlIssue::MissingColumn);
+    assert_eq!(p.missing.as_deref(), Some("wi.display_key"));
+}
+
+#[test]
+fn test_other_errors_are_invalid() {
+    let p = problem("near \"SELEC\": syntax error");
+
+    assert_eq!(p.issue, ExampleSqlIssue::Invalid);
+    assert_eq!(p.missing, None);
+}
@@ -0,0 +1,73 @@
+use crate::{DependencyType, GraphEdge, GraphFormat, GraphNode, WorkItemGraph};
+
+use std::str::FromStr;
+
+fn node(key: &str, title: &str, external: bool) -> GraphNode {
+    GraphNode {
+        key: key.to_string(),
+        title: title.to_string(),
+        item_type: "story".to_string(),
+        status: "todo".to_string(),
+        external,
+    }
+}
+
+fn edge(from: &str, to: &str, dependency_type: Option<DependencyType>) -> GraphEdge {
+    GraphEdge {
+        from: from.to_string(),
+        to: to.to_string(),
+        dependency_type,
+    }
+}
+
+fn sample() -> WorkItemGraph {
+    WorkItemGraph {
+        nodes: vec![
+            node("PONE-1", "Epic", false),
+            node("PONE-2", "Say \"hi\"", false),
+            node("OTHER-7", "Elsewhere", true),
+        ],
+        edges: vec![
+            edge("PONE-1", "PONE-2", None),
+            edge("OTHER-7", "PONE-2", Some(DependencyType::Blocks)),
+            edge("PONE-1", "OTHER-7", Some(DependencyType::RelatesTo)),
+        ],
+    }
+}
+
+#[test]
+fn test_graph_renders_mermaid() {
+    let text = sample().render(GraphFormat::Mermaid);
+
+    assert!(text.starts_with("graph TD\n"));
+    assert!(text.contains("    PONE_2[\"PONE-2: Say #quot;hi#quot;<br/>story · todo\"]\n"));
+    assert!(text.contains("    PONE_1 --> PONE_2\n"));
+    assert!(text.contains("    OTHER_7 -. blocks .-> PONE_2\n"));
+    assert!(text.contains("    PONE_1 -. relates_to .- OTHER_7\n"));
+    assert!(text.contains("    style OTHER_7 stroke-dasharray: 5 5\n"));
+    assert!(!text.contains("style PONE_1"));
+}
+
+#[test]
+fn test_graph_renders_dot() {
+    let text = sample().render(GraphFormat::Dot);
+
+    assert!(text.starts_with("digraph work_items {\n"));
+    assert!(text.contains("    \"PONE-2\" [label=\"PONE-2: Say \\\"hi\\\"\\nstory · todo\"];\n"));
+    assert!(text.contains(
+        "    \"OTHER-7\" [label=\"OTHER-7: Elsewhere\\nstory · todo\", style=dashed];\n"
+    ));
+    assert!(text.contains("    \"PONE-1\" -> \"PONE-2\";\n"));
+    assert!(
+        text.contains(
+            "    \"OTHER-7\" -> \"PONE-2\" [style=dashed, color=red, label=\"blocks\"];\n"
+        )
+    );
+    assert!(text.ends_with("}\n"));
+}
+
+#[test]
+fn test_graph_format_from_str() {
+    assert_eq!(GraphFormat::from_str("dot").unwrap(), GraphFormat::Dot);
+    assert!(GraphFormat::from_str("svg").is_err());
+}
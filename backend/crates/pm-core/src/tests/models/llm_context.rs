@@ -0,0 +1,64 @@
+use crate::{LlmContext, LlmContextType, MAX_LLM_CONTEXT_PRIORITY, MAX_LLM_CONTEXT_TITLE_LENGTH};
+
+use std::str::FromStr;
+
+fn entry() -> LlmContext {
+    LlmContext::new(
+        LlmContextType::BusinessRule,
+        "work_items".to_string(),
+        "Done means deployed".to_string(),
+        "Only move items to done once they are deployed.".to_string(),
+    )
+}
+
+#[test]
+fn test_llm_context_type_round_trip() {
+    for t in [
+        LlmContextType::SchemaDoc,
+        LlmContextType::QueryPattern,
+        LlmContextType::BusinessRule,
+        LlmContextType::Example,
+        LlmContextType::Instruction,
+    ] {
+        assert_eq!(LlmContextType::from_str(t.as_str()).unwrap(), t);
+    }
+    let err = LlmContextType::from_str("tip").unwrap_err();
+    assert!(err.to_string().contains("business_rule"));
+}
+
+#[test]
+fn test_validate_accepts_new_entry() {
+    let e = entry();
+    assert!(e.project_id.is_none());
+    assert!(e.validate().is_ok());
+}
+
+#[test]
+fn test_validate_rejects_blank_fields() {
+    let mut e = entry();
+    e.title = "  ".to_string();
+    assert!(e.validate().is_err());
+
+    let mut e = entry();
+    e.category = String::new();
+    assert!(e.validate().is_err());
+
+    let mut e = entry();
+    e.content = "\n".to_string();
+    assert!(e.validate().is_err());
+
+    let mut e = entry();
+    e.title = "x".repeat(MAX_LLM_CONTEXT_TITLE_LENGTH + 1);
+    assert!(e.validate().is_err());
+}
+
+#[test]
+fn test_validate_priority_range() {
+    let mut e = entry();
+    e.priority = MAX_LLM_CONTEXT_PRIORITY;
+    assert!(e.validate().is_ok());
+    e.priority = MAX_LLM_CONTEXT_PRIORITY + 1;
+    assert!(e.validate().is_err());
+    e.priority = -1;
+    assert!(e.validate().is_err());
+}
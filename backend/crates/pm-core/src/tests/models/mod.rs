@@ -1,2 +1,12 @@
+mod custom_field_definition;
+mod estimate;
+mod example_sql_check;
+mod graph;
+mod llm_context;
 mod project;
 mod project_status;
+mod project_template;
+mod rank;
+mod schedule;
+mod time_report;
+mod work_item_context;
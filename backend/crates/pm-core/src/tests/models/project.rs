@@ -39,3 +39,15 @@ fn test_project_is_archived() {
     project.status = ProjectStatus::Archived;
     assert!(project.is_archived());
 }
+
+#[test]
+fn test_project_validate_key() {
+    assert!(Project::validate_key("PONE").is_ok());
+    assert!(Project::validate_key("ABCDEFGHIJ").is_ok());
+
+    assert!(Project::validate_key("").is_err());
+    assert!(Project::validate_key("ABCDEFGHIJK").is_err());
+    assert!(Project::validate_key("pone").is_err());
+    assert!(Project::validate_key("P1").is_err());
+    assert!(Project::validate_key("P-ONE").is_err());
+}
@@ -0,0 +1,182 @@
+use crate::{
+    CustomFieldDefinition, CustomFieldType, LlmContext, LlmContextType, ProjectTemplate,
+    TemplateStructure, TemplateWorkItem, WorkItem, WorkItemType,
+};
+
+use serde_json::json;
+use uuid::Uuid;
+
+fn template_item(number: i32, parent: Option<i32>, item_type: WorkItemType) -> TemplateWorkItem {
+    TemplateWorkItem {
+        number,
+        parent,
+        item_type,
+        title: format!("Item {}", number),
+        description: None,
+        priority: "medium".to_string(),
+        story_points: None,
+        original_estimate_seconds: None,
+        position: 0,
+        custom_fields: Default::default(),
+    }
+}
+
+#[test]
+fn test_from_project_renumbers_items_and_keeps_parents() {
+    let project_id = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let mut epic = WorkItem::new(
+        WorkItemType::Epic,
+        "Onboarding".into(),
+        None,
+        None,
+        project_id,
+        user_id,
+    );
+    epic.item_number = 7;
+    epic.status = "done".into();
+    let mut story = WorkItem::new(
+        WorkItemType::Story,
+        "Kickoff".into(),
+        None,
+        Some(epic.id),
+        project_id,
+        user_id,
+    );
+    story.item_number = 12;
+    story.assignee_id = Some(user_id);
+
+    let structure = TemplateStructure::from_project(&[story, epic], &[], &[]);
+
+    assert_eq!(structure.work_items.len(), 2);
+    assert_eq!(structure.work_items[0].number, 1);
+    assert_eq!(structure.work_items[0].title, "Onboarding");
+    assert_eq!(structure.work_items[1].number, 2);
+    assert_eq!(structure.work_items[1].parent, Some(1));
+}
+
+#[test]
+fn test_instantiate_creates_fresh_records_parents_first() {
+    let structure = TemplateStructure {
+        work_items: vec![
+            template_item(5, Some(9), WorkItemType::Story),
+            template_item(9, None, WorkItemType::Epic),
+        ],
+        ..Default::default()
+    };
+    let project_id = Uuid::new_v4();
+
+    let instance = structure.instantiate(project_id, Uuid::new_v4()).unwrap();
+
+    let items = &instance.work_items;
+    assert_eq!(items[0].item_type, WorkItemType::Epic);
+    assert_eq!(items[0].item_number, 1);
+    assert_eq!(items[1].item_number, 2);
+    assert_eq!(items[1].parent_id, Some(items[0].id));
+    assert!(items.iter().all(|w| w.project_id == project_id));
+    assert!(items.iter().all(|w| w.status == "backlog"));
+    assert_eq!(instance.next_work_item_number, 3);
+
+    let again = structure.instantiate(project_id, Uuid::new_v4()).unwrap();
+    assert_ne!(again.work_items[0].id, items[0].id);
+}
+
+#[test]
+fn test_instantiate_ranks_items_by_position() {
+    let mut epic = template_item(1, None, WorkItemType::Epic);
+    epic.position = 2;
+    let mut story = template_item(2, Some(1), WorkItemType::Story);
+    story.position = 1;
+    let structure = TemplateStructure {
+        work_items: vec![epic, story],
+        ..Default::default()
+    };
+
+    let instance = structure
+        .instantiate(Uuid::new_v4(), Uuid::new_v4())
+        .unwrap();
+
+    let items = &instance.work_items;
+    assert_eq!(items[0].item_type, WorkItemType::Epic);
+    assert!(!items[1].rank.is_empty());
+    assert!(items[1].rank < items[0].rank);
+}
+
+#[test]
+fn test_instantiate_applies_custom_field_defaults() {
+    let mut field = CustomFieldDefinition::new(
+        Uuid::new_v4(),
+        "team".into(),
+        "Team".into(),
+        CustomFieldType::SingleSelect,
+        Uuid::new_v4(),
+    );
+    field.options = vec!["platform".into(), "web".into()];
+    field.default_value = Some(json!("web"));
+    let structure = TemplateStructure {
+        custom_fields: vec![(&field).into()],
+        work_items: vec![template_item(1, None, WorkItemType::Epic)],
+        ..Default::default()
+    };
+
+    let instance = structure
+        .instantiate(Uuid::new_v4(), Uuid::new_v4())
+        .unwrap();
+
+    assert_eq!(instance.custom_fields[0].name, "team");
+    assert_ne!(instance.custom_fields[0].id, field.id);
+    assert_eq!(instance.work_items[0].custom_fields["team"], json!("web"));
+}
+
+#[test]
+fn test_instantiate_scopes_llm_context_to_the_project() {
+    let mut entry = LlmContext::new(
+        LlmContextType::BusinessRule,
+        "process".into(),
+        "Weekly report".into(),
+        "Send a status report every Friday".into(),
+    );
+    entry.project_id = Some(Uuid::new_v4());
+    let structure = TemplateStructure::from_project(&[], &[], &[entry]);
+    let project_id = Uuid::new_v4();
+
+    let instance = structure.instantiate(project_id, Uuid::new_v4()).unwrap();
+
+    assert_eq!(instance.llm_context[0].project_id, Some(project_id));
+    assert_eq!(instance.llm_context[0].title, "Weekly report");
+}
+
+#[test]
+fn test_validate_rejects_unknown_parent_and_cycles() {
+    let unknown = TemplateStructure {
+        work_items: vec![template_item(1, Some(2), WorkItemType::Story)],
+        ..Default::default()
+    };
+    assert!(unknown.validate().is_err());
+
+    let cycle = TemplateStructure {
+        work_items: vec![
+            template_item(1, Some(2), WorkItemType::Story),
+            template_item(2, Some(1), WorkItemType::Story),
+        ],
+        ..Default::default()
+    };
+    assert!(cycle.validate().is_err());
+
+    let duplicate = TemplateStructure {
+        work_items: vec![
+            template_item(1, None, WorkItemType::Epic),
+            template_item(1, None, WorkItemType::Epic),
+        ],
+        ..Default::default()
+    };
+    assert!(duplicate.validate().is_err());
+}
+
+#[test]
+fn test_validate_name() {
+    assert!(ProjectTemplate::validate_name("client-engagement").is_ok());
+    assert!(ProjectTemplate::validate_name("  ").is_err());
+    assert!(ProjectTemplate::validate_name(&"x".repeat(65)).is_err());
+    assert!(ProjectTemplate::validate_name(&Uuid::new_v4().to_string()).is_err());
+}
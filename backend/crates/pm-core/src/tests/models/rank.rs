@@ -0,0 +1,71 @@
+use crate::{MAX_RANK_LENGTH, rank_between, rank_needs_rebalance, spread_ranks};
+
+#[test]
+fn test_rank_between_sorts_between_neighbours() {
+    let rank = rank_between(Some("a"), Some("b")).unwrap();
+
+    assert!("a" < rank.as_str() && rank.as_str() < "b");
+    assert!(!rank.ends_with('0'));
+}
+
+#[test]
+fn test_rank_between_appends_and_prepends() {
+    let first = rank_between(None, None).unwrap();
+    let last = rank_between(Some(&first), None).unwrap();
+    let before = rank_between(None, Some(&first)).unwrap();
+
+    assert!(before < first);
+    assert!(first < last);
+}
+
+#[test]
+fn test_rank_between_handles_adjacent_digits() {
+    let rank = rank_between(Some("a1"), Some("a2")).unwrap();
+
+    assert!("a1" < rank.as_str() && rank.as_str() < "a2");
+}
+
+#[test]
+fn test_repeated_inserts_keep_order() {
+    let mut ranks = vec![rank_between(None, None).unwrap()];
+    for i in 0..200 {
+        // Alternate between inserting at the front, after the first item and
+        // at the end
+        let rank = match i % 3 {
+            0 => rank_between(None, Some(&ranks[0])).unwrap(),
+            1 => rank_between(Some(&ranks[0]), Some(&ranks[1])).unwrap(),
+            _ => rank_between(ranks.last().map(String::as_str), None).unwrap(),
+        };
+        ranks.push(rank);
+        ranks.sort();
+    }
+
+    assert!(ranks.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn test_rank_between_rejects_bad_input() {
+    assert!(rank_between(Some("b"), Some("a")).is_err());
+    assert!(rank_between(Some("a"), Some("a")).is_err());
+    assert!(rank_between(Some("a0"), None).is_err());
+    assert!(rank_between(Some("A"), None).is_err());
+    assert!(rank_between(None, Some("")).is_err());
+}
+
+#[test]
+fn test_spread_ranks_are_sorted_and_short() {
+    for count in [0, 1, 10, 500, 5000] {
+        let ranks = spread_ranks(count);
+
+        assert_eq!(ranks.len(), count);
+        assert!(ranks.windows(2).all(|w| w[0] < w[1]));
+        assert!(ranks.iter().all(|r| !rank_needs_rebalance(r)));
+        assert!(ranks.iter().all(|r| rank_between(Some(r), None).is_ok()));
+    }
+}
+
+#[test]
+fn test_rank_needs_rebalance() {
+    assert!(!rank_needs_rebalance(&"i".repeat(MAX_RANK_LENGTH)));
+    assert!(rank_needs_rebalance(&"i".repeat(MAX_RANK_LENGTH + 1)));
+}
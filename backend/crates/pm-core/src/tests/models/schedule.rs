@@ -0,0 +1,160 @@
+use crate::{Dependency, DependencyType, Schedule, ScheduleWeight, WorkItem, WorkItemType};
+
+use std::str::FromStr;
+
+use uuid::Uuid;
+
+fn item(number: i32, estimate: Option<i32>) -> WorkItem {
+    let mut item = WorkItem::new(
+        WorkItemType::Task,
+        format!("Item {}", number),
+        None,
+        None,
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+    );
+    item.item_number = number;
+    item.original_estimate_seconds = estimate;
+    item.remaining_estimate_seconds = estimate;
+    item
+}
+
+fn blocks(blocking: &WorkItem, blocked: &WorkItem) -> Dependency {
+    Dependency::new(
+        blocking.id,
+        blocked.id,
+        DependencyType::Blocks,
+        Uuid::new_v4(),
+    )
+}
+
+fn find<'a>(schedule: &'a Schedule, key: &str) -> &'a crate::ScheduleItem {
+    schedule
+        .items
+        .iter()
+        .find(|i| i.display_key == key)
+        .unwrap()
+}
+
+/// 1 → 2 → 4 and 1 → 3 → 4, where the path through 2 is longer
+fn diamond() -> (Vec<WorkItem>, Vec<Dependency>) {
+    let a = item(1, Some(100));
+    let b = item(2, Some(300));
+    let c = item(3, Some(100));
+    let d = item(4, Some(50));
+    let deps = vec![
+        blocks(&a, &b),
+        blocks(&a, &c),
+        blocks(&b, &d),
+        blocks(&c, &d),
+    ];
+    (vec![d, c, b, a], deps)
+}
+
+#[test]
+fn test_schedule_topological_order_and_critical_path() {
+    let (items, deps) = diamond();
+
+    let schedule = Schedule::compute(&items, &deps, ScheduleWeight::Estimate, "P").unwrap();
+
+    let order: Vec<&str> = schedule
+        .items
+        .iter()
+        .map(|i| i.display_key.as_str())
+        .collect();
+    assert_eq!(order, ["P-1", "P-2", "P-3", "P-4"]);
+    assert_eq!(schedule.total_duration, 450);
+
+    let path: Vec<String> = schedule
+        .critical_path
+        .iter()
+        .map(|id| {
+            let item = schedule
+                .items
+                .iter()
+                .find(|i| &i.work_item_id == id)
+                .unwrap();
+            item.display_key.clone()
+        })
+        .collect();
+    assert_eq!(path, ["P-1", "P-2", "P-4"]);
+}
+
+#[test]
+fn test_schedule_offsets_and_slack() {
+    let (items, deps) = diamond();
+
+    let schedule = Schedule::compute(&items, &deps, ScheduleWeight::Estimate, "P").unwrap();
+
+    let c = find(&schedule, "P-3");
+    assert_eq!((c.start_offset, c.end_offset), (100, 200));
+    assert_eq!((c.latest_start, c.latest_end), (300, 400));
+    assert_eq!(c.slack, 200);
+    assert!(!c.critical);
+
+    let d = find(&schedule, "P-4");
+    assert_eq!((d.start_offset, d.end_offset), (400, 450));
+    assert_eq!(d.slack, 0);
+    assert!(d.critical);
+    assert_eq!(d.blocked_by.len(), 2);
+}
+
+#[test]
+fn test_schedule_ignores_relates_to_and_out_of_scope_edges() {
+    let a = item(1, Some(10));
+    let b = item(2, Some(20));
+    let outside = item(3, Some(1000));
+    let deps = vec![
+        Dependency::new(a.id, b.id, DependencyType::RelatesTo, Uuid::new_v4()),
+        blocks(&outside, &a),
+    ];
+
+    let schedule = Schedule::compute(&[a, b], &deps, ScheduleWeight::Estimate, "P").unwrap();
+
+    assert_eq!(schedule.total_duration, 20);
+    assert!(schedule.items.iter().all(|i| i.start_offset == 0));
+    assert_eq!(find(&schedule, "P-1").slack, 10);
+}
+
+#[test]
+fn test_schedule_story_points_and_unweighted_items() {
+    let mut a = item(1, None);
+    a.story_points = Some(5);
+    let b = item(2, None);
+    let deps = vec![blocks(&a, &b)];
+
+    let by_points = Schedule::compute(
+        &[a.clone(), b.clone()],
+        &deps,
+        ScheduleWeight::StoryPoints,
+        "P",
+    )
+    .unwrap();
+    let by_estimate = Schedule::compute(&[a, b], &deps, ScheduleWeight::Estimate, "P").unwrap();
+
+    assert_eq!(by_points.unit, "points");
+    assert_eq!(by_points.total_duration, 5);
+    assert_eq!(by_points.unweighted_item_count, 1);
+    assert_eq!(by_estimate.total_duration, 0);
+    assert_eq!(by_estimate.unweighted_item_count, 2);
+}
+
+#[test]
+fn test_schedule_rejects_cycles() {
+    let a = item(1, Some(10));
+    let b = item(2, Some(10));
+    let deps = vec![blocks(&a, &b), blocks(&b, &a)];
+
+    let err = Schedule::compute(&[a, b], &deps, ScheduleWeight::Estimate, "P").unwrap_err();
+
+    assert!(err.to_string().contains("P-1, P-2"));
+}
+
+#[test]
+fn test_schedule_weight_from_str() {
+    assert_eq!(
+        ScheduleWeight::from_str("story_points").unwrap(),
+        ScheduleWeight::StoryPoints
+    );
+    assert!(ScheduleWeight::from_str("hours").is_err());
+}
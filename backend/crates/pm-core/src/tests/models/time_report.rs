@@ -0,0 +1,139 @@
+use crate::{TimeEntry, TimeReport, TimeReportGroupBy, TimeReportPeriod, TimeReportRow};
+
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use uuid::Uuid;
+
+fn at(y: i32, m: u32, d: u32, h: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap()
+}
+
+fn entry(started_at: DateTime<Utc>, ended_at: Option<DateTime<Utc>>) -> TimeEntry {
+    let mut entry = TimeEntry::new(Uuid::new_v4(), Uuid::new_v4(), None);
+    entry.started_at = started_at;
+    entry.ended_at = ended_at;
+    entry
+}
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn test_group_by_and_period_round_trip() {
+    for g in [
+        TimeReportGroupBy::User,
+        TimeReportGroupBy::Item,
+        TimeReportGroupBy::Sprint,
+    ] {
+        assert_eq!(TimeReportGroupBy::from_str(g.as_str()).unwrap(), g);
+    }
+    for p in [TimeReportPeriod::Day, TimeReportPeriod::Week] {
+        assert_eq!(TimeReportPeriod::from_str(p.as_str()).unwrap(), p);
+    }
+    assert!(TimeReportGroupBy::from_str("epic").is_err());
+    assert!(TimeReportPeriod::from_str("month").is_err());
+}
+
+#[test]
+fn test_week_bucket_starts_on_monday() {
+    // 2026-10-18 is a Sunday
+    assert_eq!(
+        TimeReportPeriod::Week.bucket_start(date(2026, 10, 18)),
+        date(2026, 10, 12)
+    );
+    assert_eq!(
+        TimeReportPeriod::Week.bucket_start(date(2026, 10, 12)),
+        date(2026, 10, 12)
+    );
+}
+
+#[test]
+fn test_seconds_in_range_clips_to_range() {
+    let e = entry(at(2026, 10, 1, 22), Some(at(2026, 10, 2, 4)));
+
+    let slices = e.seconds_in_range(
+        at(2026, 10, 2, 0),
+        at(2026, 10, 3, 0),
+        at(2026, 10, 5, 0),
+        None,
+    );
+
+    assert_eq!(slices, vec![(None, 4 * 3600)]);
+}
+
+#[test]
+fn test_seconds_in_range_splits_across_days() {
+    let e = entry(at(2026, 10, 1, 22), Some(at(2026, 10, 2, 4)));
+
+    let slices = e.seconds_in_range(
+        at(2026, 10, 1, 0),
+        at(2026, 10, 3, 0),
+        at(2026, 10, 5, 0),
+        Some(TimeReportPeriod::Day),
+    );
+
+    assert_eq!(
+        slices,
+        vec![
+            (Some(date(2026, 10, 1)), 2 * 3600),
+            (Some(date(2026, 10, 2)), 4 * 3600),
+        ]
+    );
+}
+
+#[test]
+fn test_seconds_in_range_counts_running_timer_until_now() {
+    let e = entry(at(2026, 10, 1, 9), None);
+
+    let slices = e.seconds_in_range(
+        at(2026, 10, 1, 0),
+        at(2026, 10, 2, 0),
+        at(2026, 10, 1, 12),
+        None,
+    );
+
+    assert_eq!(slices, vec![(None, 3 * 3600)]);
+}
+
+#[test]
+fn test_seconds_in_range_outside_range_is_empty() {
+    let e = entry(at(2026, 9, 1, 9), Some(at(2026, 9, 1, 10)));
+
+    let slices = e.seconds_in_range(
+        at(2026, 10, 1, 0),
+        at(2026, 10, 2, 0),
+        at(2026, 10, 5, 0),
+        None,
+    );
+
+    assert!(slices.is_empty());
+}
+
+#[test]
+fn test_to_csv_escapes_labels() {
+    let report = TimeReport {
+        from: "2026-10-01".into(),
+        to: "2026-10-07".into(),
+        group_by: "item".into(),
+        period: None,
+        generated_at: 0,
+        total_seconds: 5400,
+        rows: vec![TimeReportRow {
+            key: "id-1".into(),
+            label: "PONE-1 Fix \"login\", again".into(),
+            period_start: None,
+            seconds: 5400,
+            rollup_seconds: Some(9000),
+        }],
+    };
+
+    let csv = report.to_csv();
+
+    assert_eq!(
+        csv,
+        "key,label,period_start,seconds,hours,rollup_seconds,rollup_hours\n\
+         id-1,\"PONE-1 Fix \"\"login\"\", again\",,5400,1.50,9000,2.50\n"
+    );
+}
@@ -0,0 +1,139 @@
+use crate::{
+    ContextActivity, ContextActivityEntry, ContextComment, ContextFormat, ContextItem,
+    ContextOmitted, ContextRule, WorkItemContext,
+};
+
+use std::str::FromStr;
+
+use uuid::Uuid;
+
+fn item(key: &str, title: &str) -> ContextItem {
+    ContextItem {
+        key: key.to_string(),
+        title: title.to_string(),
+        item_type: "story".to_string(),
+        status: "todo".to_string(),
+        priority: "medium".to_string(),
+        assignee_id: None,
+        story_points: None,
+        description: None,
+    }
+}
+
+fn rule(title: &str, priority: i32) -> ContextRule {
+    ContextRule {
+        title: title.to_string(),
+        context_type: "business_rule".to_string(),
+        category: "workflow".to_string(),
+        priority,
+        content: "Follow the rule.".to_string(),
+    }
+}
+
+fn sample() -> WorkItemContext {
+    let mut main = item("PONE-12", "Add login");
+    main.story_points = Some(3);
+    main.description = Some("Users sign in with email.".to_string());
+
+    WorkItemContext {
+        item: main,
+        ancestors: vec![item("PONE-1", "Auth epic")],
+        siblings: vec![item("PONE-13", "Add logout"), item("PONE-14", "Reset")],
+        blocked_by: vec![item("PONE-9", "Session store")],
+        blocks: vec![],
+        comments: vec![ContextComment {
+            author_id: Uuid::nil(),
+            created_at: 1_767_225_600,
+            content: "Use the existing session table.".to_string(),
+        }],
+        activity: ContextActivity {
+            total: 2,
+            by_action: [("created".to_string(), 1), ("updated".to_string(), 1)]
+                .into_iter()
+                .collect(),
+            last_changed_at: Some(1_767_225_600),
+            recent: vec![ContextActivityEntry {
+                timestamp: 1_767_225_600,
+                action: "updated".to_string(),
+                field_name: Some("status".to_string()),
+                old_value: Some("backlog".to_string()),
+                new_value: Some("todo".to_string()),
+            }],
+        },
+        rules: vec![rule("Definition of done", 90), rule("Naming", 40)],
+        omitted: ContextOmitted::default(),
+    }
+}
+
+#[test]
+fn test_context_renders_markdown_sections() {
+    let text = sample().render(ContextFormat::Markdown);
+
+    assert!(text.starts_with("# PONE-12: Add login\n"));
+    assert!(text.contains("- **Story points:** 3\n"));
+    assert!(text.contains("## Description\n\nUsers sign in with email.\n"));
+    assert!(text.contains("## Ancestors\n\n- PONE-1 (story, todo): Auth epic\n"));
+    assert!(text.contains("## Blocked by\n\n- PONE-9 (story, todo): Session store\n"));
+    assert!(!text.contains("## Blocks\n"));
+    assert!(text.contains("### 2026-01-01 00:00 UTC by 00000000-0000-0000-0000-000000000000"));
+    assert!(text.contains("2 entries (1 created, 1 updated), last change 2026-01-01 00:00 UTC"));
+    assert!(text.contains("- 2026-01-01 00:00 UTC updated status: backlog → todo\n"));
+    assert!(text.contains("### Definition of done (business_rule, priority 90)"));
+    assert!(!text.contains("Trimmed"));
+}
+
+#[test]
+fn test_context_within_budget_is_unchanged() {
+    let mut context = sample();
+    context.fit_to_budget(100_000, ContextFormat::Markdown);
+
+    assert!(context.omitted.is_empty());
+    assert_eq!(context.rules.len(), 2);
+}
+
+#[test]
+fn test_context_budget_drops_low_value_sections_first() {
+    let full = sample().render(ContextFormat::Markdown).chars().count();
+
+    let mut context = sample();
+    context.fit_to_budget(full - 80, ContextFormat::Markdown);
+    let text = context.render(ContextFormat::Markdown);
+
+    assert!(text.chars().count() <= full - 80);
+    assert_eq!(context.omitted.activity, 1);
+    assert!(context.omitted.rules >= 1);
+    // Lowest priority rule goes first
+    assert!(!text.contains("### Naming"));
+    assert_eq!(context.ancestors.len(), 1);
+    assert_eq!(context.blocked_by.len(), 1);
+    assert!(text.contains("_Trimmed to fit the size budget: omitted 1 activity entries"));
+}
+
+#[test]
+fn test_context_budget_truncates_description_last() {
+    let mut context = sample();
+    context.item.description = Some("x".repeat(2_000));
+    context.fit_to_budget(2_000, ContextFormat::Json);
+
+    assert!(context.render(ContextFormat::Json).chars().count() <= 2_000);
+    assert!(context.rules.is_empty());
+    assert!(context.siblings.is_empty());
+    assert!(context.comments.is_empty());
+    assert!(context.omitted.description_truncated);
+    let description = context.item.description.unwrap();
+    assert!(description.ends_with("… [truncated]"));
+    assert!(description.starts_with("xxx"));
+}
+
+#[test]
+fn test_context_format_from_str() {
+    assert_eq!(
+        ContextFormat::from_str("markdown").unwrap(),
+        ContextFormat::Markdown
+    );
+    assert_eq!(
+        ContextFormat::from_str("json").unwrap(),
+        ContextFormat::Json
+    );
+    assert!(ContextFormat::from_str("html").is_err());
+}
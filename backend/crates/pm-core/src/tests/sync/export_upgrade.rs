@@ -0,0 +1,154 @@
+use crate::{
+    ActivityLog, CURRENT_EXPORT_SCHEMA_VERSION, Comment, CustomFieldDefinition, Dependency,
+    LlmContext, Project, ProjectMember, Sprint, TimeEntry, WorkItem, upgrade_export_data,
+};
+
+use serde_json::{Value, json};
+
+/// One fixture per schema version ever released, oldest first
+const FIXTURES: [(u32, &str); 3] = [
+    (1, include_str!("fixtures/export_v1.json")),
+    (2, include_str!("fixtures/export_v2.json")),
+    (3, include_str!("fixtures/export_v3.json")),
+];
+
+fn fixture(version: u32) -> Value {
+    let (_, json) = FIXTURES
+        .iter()
+        .find(|(v, _)| *v == version)
+        .expect("fixture for version");
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn test_every_schema_version_has_a_fixture() {
+    let versions: Vec<u32> = FIXTURES.iter().map(|(v, _)| *v).collect();
+    let expected: Vec<u32> = (1..=CURRENT_EXPORT_SCHEMA_VERSION).collect();
+
+    assert_eq!(versions, expected);
+    for (version, json) in FIXTURES {
+        let value: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(value["schema_version"], json!(version));
+    }
+}
+
+#[test]
+fn test_every_fixture_upgrades_to_the_current_version_and_round_trips() {
+    for (version, json) in FIXTURES {
+        let data = upgrade_export_data(serde_json::from_str(json).unwrap())
+            .unwrap_or_else(|e| panic!("version {} fixture: {}", version, e));
+        assert_eq!(data.schema_version, CURRENT_EXPORT_SCHEMA_VERSION);
+
+        let written = serde_json::to_value(&data).unwrap();
+        let reread = upgrade_export_data(written.clone()).unwrap();
+        assert_eq!(
+            serde_json::to_value(&reread).unwrap(),
+            written,
+            "version {} fixture",
+            version
+        );
+    }
+}
+
+#[test]
+fn test_every_fixture_converts_to_models() {
+    for (_, json) in FIXTURES {
+        let data = upgrade_export_data(serde_json::from_str(json).unwrap()).unwrap();
+
+        for dto in data.projects {
+            Project::try_from(dto).unwrap();
+        }
+        for dto in data.sprints {
+            Sprint::try_from(dto).unwrap();
+        }
+        for dto in data.work_items {
+            WorkItem::try_from(dto).unwrap();
+        }
+        for dto in data.comments {
+            Comment::try_from(dto).unwrap();
+        }
+        for dto in data.dependencies {
+            Dependency::try_from(dto).unwrap();
+        }
+        for dto in data.time_entries {
+            TimeEntry::try_from(dto).unwrap();
+        }
+        for dto in data.custom_fields {
+            CustomFieldDefinition::try_from(dto).unwrap();
+        }
+        for dto in data.project_members {
+            ProjectMember::try_from(dto).unwrap();
+        }
+        for dto in data.llm_context {
+            LlmContext::try_from(dto).unwrap();
+        }
+        for dto in data.activity_log {
+            ActivityLog::try_from(dto).unwrap();
+        }
+    }
+}
+
+#[test]
+fn test_v1_upgrade_keeps_records_and_adds_empty_collections() {
+    let data = upgrade_export_data(fixture(1)).unwrap();
+
+    assert_eq!(data.projects.len(), 1);
+    assert_eq!(data.work_items.len(), 2);
+    assert_eq!(data.time_entries.len(), 1);
+    assert!(data.custom_fields.is_empty());
+    assert!(data.project_members.is_empty());
+    assert!(data.llm_context.is_empty());
+    assert!(data.activity_log.is_empty());
+}
+
+#[test]
+fn test_v2_fixture_carries_members_context_and_activity() {
+    let data = upgrade_export_data(fixture(2)).unwrap();
+
+    assert_eq!(data.project_members[0].role, "editor");
+    assert_eq!(data.llm_context[0].title, "Fixture rule");
+    assert_eq!(
+        data.activity_log[0].new_value.as_deref(),
+        Some("in_progress")
+    );
+}
+
+#[test]
+fn test_v2_upgrade_ranks_work_items_in_position_order() {
+    let mut value = fixture(2);
+    value["work_items"][0]["position"] = json!(5);
+
+    let data = upgrade_export_data(value).unwrap();
+
+    let first = &data.work_items[0];
+    let second = &data.work_items[1];
+    assert_eq!(first.position, 5);
+    assert!(!first.rank.is_empty());
+    assert!(second.rank < first.rank);
+}
+
+#[test]
+fn test_v3_fixture_keeps_its_ranks() {
+    let data = upgrade_export_data(fixture(3)).unwrap();
+
+    let ranks: Vec<&str> = data.work_items.iter().map(|w| w.rank.as_str()).collect();
+    assert_eq!(ranks, ["c", "r"]);
+}
+
+#[test]
+fn test_newer_schema_version_is_rejected() {
+    let mut value = fixture(CURRENT_EXPORT_SCHEMA_VERSION);
+    value["schema_version"] = json!(CURRENT_EXPORT_SCHEMA_VERSION + 1);
+
+    let err = upgrade_export_data(value).unwrap_err();
+
+    assert!(err.to_string().contains("Unsupported schema version"));
+}
+
+#[test]
+fn test_missing_schema_version_is_rejected() {
+    let mut value = fixture(1);
+    value.as_object_mut().unwrap().remove("schema_version");
+
+    assert!(upgrade_export_data(value).is_err());
+}
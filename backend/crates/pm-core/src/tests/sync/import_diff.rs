@@ -0,0 +1,55 @@
+use crate::{FieldChange, ImportDiff, ImportResult, Project};
+
+use serde_json::json;
+use uuid::Uuid;
+
+#[test]
+fn test_field_change_between_lists_only_changed_fields() {
+    let user_id = Uuid::new_v4();
+    let old = Project::new("Old title".to_string(), "PROJ".to_string(), user_id);
+    let mut new = old.clone();
+    new.title = "New title".to_string();
+    new.description = Some("Now described".to_string());
+
+    let changes = FieldChange::between(&old, &new);
+
+    assert_eq!(
+        changes,
+        vec![
+            FieldChange {
+                field: "description".to_string(),
+                old: json!(null),
+                new: json!("Now described"),
+            },
+            FieldChange {
+                field: "title".to_string(),
+                old: json!("Old title"),
+                new: json!("New title"),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_field_change_between_identical_values_is_empty() {
+    let project = Project::new("Same".to_string(), "SAME".to_string(), Uuid::new_v4());
+
+    assert!(FieldChange::between(&project, &project.clone()).is_empty());
+}
+
+#[test]
+fn test_import_result_counts_match_diff() {
+    let mut diff = ImportDiff::default();
+    diff.projects.create(Uuid::new_v4());
+    diff.work_items.update(Uuid::new_v4(), Vec::new());
+    diff.work_items.skip(Uuid::new_v4(), "local is newer");
+    diff.work_items.skip(Uuid::new_v4(), "local is up to date");
+
+    let result = ImportResult::new(diff, true);
+
+    assert!(result.dry_run);
+    assert_eq!(result.projects.created, 1);
+    assert_eq!(result.work_items.updated, 1);
+    assert_eq!(result.work_items.skipped, 2);
+    assert_eq!(result.diff.work_items.skipped[0].reason, "local is newer");
+}
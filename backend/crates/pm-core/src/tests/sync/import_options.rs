@@ -0,0 +1,99 @@
+use crate::{ConflictStrategy, FieldChange, ImportAction, ImportOptions};
+
+use chrono::{Duration, Utc};
+use serde_json::json;
+
+fn changes() -> Vec<FieldChange> {
+    vec![FieldChange {
+        field: "title".to_string(),
+        old: json!("Local"),
+        new: json!("Imported"),
+    }]
+}
+
+#[test]
+fn test_resolve_without_base_takes_the_later_record() {
+    let options = ImportOptions::default();
+    let now = Utc::now();
+
+    assert_eq!(
+        options.resolve(now, now + Duration::seconds(1), &changes()),
+        ImportAction::Update
+    );
+    assert_eq!(
+        options.resolve(now + Duration::seconds(1), now, &changes()),
+        ImportAction::Skip("local is newer")
+    );
+}
+
+#[test]
+fn test_resolve_identical_record_is_skipped_even_when_changed_since_base() {
+    let now = Utc::now();
+    let options = ImportOptions {
+        base: Some(now - Duration::hours(1)),
+        on_conflict: ConflictStrategy::Report,
+    };
+
+    assert_eq!(
+        options.resolve(now, now, &[]),
+        ImportAction::Skip("local is up to date")
+    );
+}
+
+#[test]
+fn test_resolve_local_change_since_base_is_a_conflict_even_when_older() {
+    let now = Utc::now();
+    let options = ImportOptions {
+        base: Some(now - Duration::hours(1)),
+        on_conflict: ConflictStrategy::Report,
+    };
+
+    assert_eq!(
+        options.resolve(now - Duration::minutes(30), now, &changes()),
+        ImportAction::Conflict
+    );
+}
+
+#[test]
+fn test_resolve_local_unchanged_since_base_takes_the_import() {
+    let now = Utc::now();
+    let options = ImportOptions {
+        base: Some(now - Duration::hours(1)),
+        on_conflict: ConflictStrategy::Report,
+    };
+
+    assert_eq!(
+        options.resolve(now - Duration::hours(2), now, &changes()),
+        ImportAction::Update
+    );
+}
+
+#[test]
+fn test_resolve_conflict_follows_strategy() {
+    let now = Utc::now();
+    let base = Some(now - Duration::hours(1));
+    let theirs = ImportOptions {
+        base,
+        on_conflict: ConflictStrategy::Theirs,
+    };
+    let ours = ImportOptions {
+        base,
+        on_conflict: ConflictStrategy::Ours,
+    };
+
+    assert_eq!(theirs.resolve(now, now, &changes()), ImportAction::Update);
+    assert_eq!(
+        ours.resolve(now, now, &changes()),
+        ImportAction::Skip("kept local change")
+    );
+}
+
+#[test]
+fn test_conflict_strategy_reversed_swaps_sides() {
+    assert_eq!(ConflictStrategy::Theirs.reversed(), ConflictStrategy::Ours);
+    assert_eq!(ConflictStrategy::Ours.reversed(), ConflictStrategy::Theirs);
+    assert_eq!(
+        ConflictStrategy::Report.reversed(),
+        ConflictStrategy::Report
+    );
+}
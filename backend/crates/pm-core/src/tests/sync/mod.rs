@@ -0,0 +1,3 @@
+mod export_upgrade;
+mod import_diff;
+mod import_options;
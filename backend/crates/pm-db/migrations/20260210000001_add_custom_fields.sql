@@ -0,0 +1,40 @@
+-- ============================================================
+-- Migration: Add typed custom fields per project
+-- Adds: pm_custom_field_definitions
+-- Adds: pm_work_items.custom_fields (JSON object keyed by field name)
+--
+-- Field names are immutable once created because work item values
+-- are keyed by name. Labels, options, defaults and the required
+-- flag may change; values are re-validated when an item is edited.
+--
+-- SQLx runs each migration inside a transaction, so no explicit
+-- BEGIN/COMMIT is needed here.
+-- ============================================================
+
+CREATE TABLE pm_custom_field_definitions (
+    id TEXT PRIMARY KEY,
+    project_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    label TEXT NOT NULL,
+    field_type TEXT NOT NULL CHECK(field_type IN ('text', 'number', 'date', 'single_select', 'multi_select', 'user')),
+    options TEXT NOT NULL DEFAULT '[]',      -- JSON array of allowed values (select types)
+    required BOOLEAN NOT NULL DEFAULT 0,
+    default_value TEXT,                       -- JSON-encoded default, NULL when none
+    position INTEGER NOT NULL DEFAULT 0,
+    version INTEGER NOT NULL DEFAULT 1,
+    created_at INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL,
+    created_by TEXT NOT NULL,
+    updated_by TEXT NOT NULL,
+    deleted_at INTEGER,
+    FOREIGN KEY (project_id) REFERENCES pm_projects(id) ON DELETE CASCADE
+);
+
+-- Names are unique among live definitions in a project
+CREATE UNIQUE INDEX idx_pm_custom_field_definitions_name
+    ON pm_custom_field_definitions(project_id, name) WHERE deleted_at IS NULL;
+
+CREATE INDEX idx_pm_custom_field_definitions_project
+    ON pm_custom_field_definitions(project_id) WHERE deleted_at IS NULL;
+
+ALTER TABLE pm_work_items ADD COLUMN custom_fields TEXT NOT NULL DEFAULT '{}';
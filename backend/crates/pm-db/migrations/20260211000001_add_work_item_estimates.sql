@@ -0,0 +1,18 @@
+-- ============================================================
+-- Migration: Add hour-based estimates to work items
+-- Adds: pm_work_items.original_estimate_seconds
+-- Adds: pm_work_items.remaining_estimate_seconds
+--
+-- Both are optional. Remaining time is decremented by the duration
+-- of each stopped timer unless the user overrides it explicitly;
+-- logged time itself is always derived from pm_time_entries.
+--
+-- SQLx runs each migration inside a transaction, so no explicit
+-- BEGIN/COMMIT is needed here.
+-- ============================================================
+
+ALTER TABLE pm_work_items ADD COLUMN original_estimate_seconds INTEGER
+    CHECK(original_estimate_seconds IS NULL OR original_estimate_seconds >= 0);
+
+ALTER TABLE pm_work_items ADD COLUMN remaining_estimate_seconds INTEGER
+    CHECK(remaining_estimate_seconds IS NULL OR remaining_estimate_seconds >= 0);
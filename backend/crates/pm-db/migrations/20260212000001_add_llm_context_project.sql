@@ -0,0 +1,15 @@
+-- ============================================================
+-- Migration: Project-specific LLM context entries
+-- Adds: pm_llm_context.project_id (NULL for global entries)
+--
+-- Global entries document the schema and apply everywhere; project
+-- entries teach agents the conventions of one repository.
+--
+-- SQLx runs each migration inside a transaction, so no explicit
+-- BEGIN/COMMIT is needed here.
+-- ============================================================
+
+ALTER TABLE pm_llm_context ADD COLUMN project_id TEXT REFERENCES pm_projects(id) ON DELETE CASCADE;
+
+CREATE INDEX idx_pm_llm_context_project
+    ON pm_llm_context(project_id) WHERE deleted_at IS NULL;
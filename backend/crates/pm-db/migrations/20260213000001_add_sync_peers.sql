@@ -0,0 +1,21 @@
+-- ============================================================
+-- Migration: Add peer sync watermarks
+-- Adds: pm_sync_peers
+--
+-- One row per remote pm-server this database pulls from or
+-- pushes to. pulled_until is in the peer's clock (its export
+-- timestamp); pushed_until and synced_at are in the local clock.
+-- Each only advances after an exchange with no conflicts, so
+-- conflicting records are offered again on the next sync.
+--
+-- SQLx runs each migration inside a transaction, so no explicit
+-- BEGIN/COMMIT is needed here.
+-- ============================================================
+
+CREATE TABLE pm_sync_peers (
+    url TEXT PRIMARY KEY,
+    pulled_until INTEGER,
+    pushed_until INTEGER,
+    synced_at INTEGER,
+    updated_at INTEGER NOT NULL
+);
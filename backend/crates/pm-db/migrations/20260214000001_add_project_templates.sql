@@ -0,0 +1,22 @@
+-- ============================================================
+-- Migration: Add project templates
+-- Adds: pm_project_templates
+--
+-- A template is a project's structure without its history:
+-- custom fields, starter work items and project LLM context,
+-- stored as one JSON document in structure. Templates are
+-- looked up by id or by their unique name and deleted outright.
+--
+-- SQLx runs each migration inside a transaction, so no explicit
+-- BEGIN/COMMIT is needed here.
+-- ============================================================
+
+CREATE TABLE pm_project_templates (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE,
+    description TEXT,
+    structure TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL,
+    created_by TEXT NOT NULL
+);
@@ -0,0 +1,25 @@
+-- ============================================================
+-- Migration: Add work item display key aliases
+-- Adds: pm_work_item_aliases
+--
+-- Moving a work item to another project gives it a new number
+-- there. The number it had before is kept here, keyed by the
+-- old project's id (not its key, so a later key change still
+-- finds it), so display keys like PONE-12 keep resolving.
+-- Numbers are never reused: next_work_item_number only grows.
+--
+-- SQLx runs each migration inside a transaction, so no explicit
+-- BEGIN/COMMIT is needed here.
+-- ============================================================
+
+CREATE TABLE pm_work_item_aliases (
+    project_id TEXT NOT NULL,
+    item_number INTEGER NOT NULL,
+    work_item_id TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    PRIMARY KEY (project_id, item_number),
+    FOREIGN KEY (project_id) REFERENCES pm_projects(id) ON DELETE CASCADE,
+    FOREIGN KEY (work_item_id) REFERENCES pm_work_items(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_pm_work_item_aliases_work_item ON pm_work_item_aliases(work_item_id);
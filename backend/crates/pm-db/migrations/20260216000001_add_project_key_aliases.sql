@@ -0,0 +1,22 @@
+-- ============================================================
+-- Migration: Add project key aliases
+-- Adds: pm_project_key_aliases
+--
+-- Renaming a project's key keeps the old key here so project
+-- lookups and display keys like PONE-12 keep resolving after
+-- the rename. A key belongs to one project at a time: live
+-- keys and aliases are checked against each other before a
+-- project is created or renamed.
+--
+-- SQLx runs each migration inside a transaction, so no explicit
+-- BEGIN/COMMIT is needed here.
+-- ============================================================
+
+CREATE TABLE pm_project_key_aliases (
+    key TEXT PRIMARY KEY,
+    project_id TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES pm_projects(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_pm_project_key_aliases_project ON pm_project_key_aliases(project_id);
@@ -0,0 +1,33 @@
+-- ============================================================
+-- Migration: Add fractional ranks for backlog ordering
+-- Adds: pm_work_items.rank
+--
+-- Work items are ordered within a project by rank, a string
+-- compared byte by byte (digits 0-9 then a-z). A new rank can
+-- always be made between two neighbours, so moving an item
+-- rewrites only that item's row instead of renumbering the
+-- integer positions of everything after it.
+--
+-- Existing items keep their current order: each project's items
+-- are ranked by (position, item_number) as fixed-width numbers
+-- with a non-zero last digit, e.g. 000001i, 000002i, ...
+--
+-- SQLx runs each migration inside a transaction, so no explicit
+-- BEGIN/COMMIT is needed here.
+-- ============================================================
+
+ALTER TABLE pm_work_items ADD COLUMN rank TEXT NOT NULL DEFAULT '';
+
+UPDATE pm_work_items
+SET rank = (
+    SELECT printf('%06di', ranked.n)
+    FROM (
+        SELECT id, ROW_NUMBER() OVER (
+            PARTITION BY project_id ORDER BY position, item_number
+        ) AS n
+        FROM pm_work_items
+    ) AS ranked
+    WHERE ranked.id = pm_work_items.id
+);
+
+CREATE INDEX idx_pm_work_items_project_rank ON pm_work_items(project_id, rank);
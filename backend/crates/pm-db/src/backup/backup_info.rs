@@ -0,0 +1,14 @@
+use std::path::PathBuf;
+
+/// A backup file in the backup directory
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackupInfo {
+    /// File name, e.g. `pm-20260118T093000.123Z.db`
+    pub name: String,
+    /// Absolute path to the file
+    pub path: PathBuf,
+    /// File size in bytes
+    pub size_bytes: u64,
+    /// Unix timestamp (seconds) the backup was taken, parsed from the name
+    pub created_at: i64,
+}
@@ -0,0 +1,255 @@
+//! Online database backups
+//!
+//! Backups are written with `VACUUM INTO`, which produces a consistent,
+//! compacted copy while the server keeps serving requests. Every backup is
+//! checked with `PRAGMA integrity_check` before it is kept.
+//!
+//! Restoring cannot swap the database under a live pool, so it happens in
+//! two steps: [`BackupStore::stage_restore`] copies a verified backup next
+//! to the database, and [`BackupStore::apply_staged_restore`] swaps it in
+//! at the next startup, before migrations run.
+
+use crate::{BackupInfo, DbError, Result as DbErrorResult};
+
+use std::ffi::OsString;
+use std::panic::Location;
+use std::path::{Path, PathBuf};
+
+use chrono::{NaiveDateTime, Utc};
+use error_location::ErrorLocation;
+use sqlx::sqlite::SqliteConnectOptions;
+use sqlx::{ConnectOptions, Connection, SqlitePool};
+
+const BACKUP_PREFIX: &str = "pm-";
+const BACKUP_EXTENSION: &str = ".db";
+/// UTC timestamp in backup names; sorts lexically in time order
+const TIMESTAMP_FORMAT: &str = "%Y%m%dT%H%M%S%.3fZ";
+/// Suffix of the staged restore file, next to the database
+const STAGED_RESTORE_SUFFIX: &str = ".restore";
+/// Suffix the replaced database is kept under after a restore
+const PRE_RESTORE_SUFFIX: &str = ".pre-restore";
+/// SQLite side files that belong to a database in WAL mode
+const SIDE_FILE_SUFFIXES: [&str; 2] = ["-wal", "-shm"];
+
+#[derive(Debug, Clone)]
+pub struct BackupStore {
+    /// Directory backups are written to
+    pub dir: PathBuf,
+    /// The live database file
+    pub database_path: PathBuf,
+    /// Backups to keep; older ones are deleted after each new backup
+    pub retention_count: usize,
+}
+
+impl BackupStore {
+    pub fn new(dir: PathBuf, database_path: PathBuf, retention_count: usize) -> Self {
+        Self {
+            dir,
+            database_path,
+            retention_count,
+        }
+    }
+
+    /// Write a verified backup of `pool`'s database, then prune old backups.
+    /// Fails for an in-memory database, which has no file to back up.
+    pub async fn create(&self, pool: &SqlitePool) -> DbErrorResult<BackupInfo> {
+        let file: String =
+            sqlx::query_scalar("SELECT file FROM pragma_database_list WHERE name = 'main'")
+                .fetch_one(pool)
+                .await?;
+        if file.is_empty() {
+            return Err(Self::backup_error(
+                "the database is in memory; only file-backed databases can be backed up"
+                    .to_string(),
+            ));
+        }
+
+        std::fs::create_dir_all(&self.dir)
+            .map_err(|e| Self::backup_error(format!("create {}: {}", self.dir.display(), e)))?;
+
+        let name = format!(
+            "{}{}{}",
+            BACKUP_PREFIX,
+            Utc::now().format(TIMESTAMP_FORMAT),
+            BACKUP_EXTENSION
+        );
+        let path = self.dir.join(&name);
+        if path.exists() {
+            return Err(Self::backup_error(format!("{} already exists", name)));
+        }
+
+        sqlx::query("VACUUM INTO ?")
+            .bind(path.to_string_lossy().into_owned())
+            .execute(pool)
+            .await?;
+
+        if let Err(e) = Self::verify(&path).await {
+            let _ = std::fs::remove_file(&path);
+            return Err(e);
+        }
+
+        let info = Self::info(path)
+            .ok_or_else(|| Self::backup_error(format!("{} was not written", name)))?;
+        self.prune()?;
+
+        Ok(info)
+    }
+
+    /// Backups in the backup directory, newest first.
+    pub fn list(&self) -> DbErrorResult<Vec<BackupInfo>> {
+        let entries = match std::fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => {
+                return Err(Self::backup_error(format!(
+                    "read {}: {}",
+                    self.dir.display(),
+                    e
+                )));
+            }
+        };
+
+        let mut backups: Vec<BackupInfo> = entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| Self::info(entry.path()))
+            .collect();
+        backups.sort_by(|a, b| b.name.cmp(&a.name));
+
+        Ok(backups)
+    }
+
+    /// Delete backups beyond the retention count, returning their names.
+    pub fn prune(&self) -> DbErrorResult<Vec<String>> {
+        let mut removed = Vec::new();
+        for backup in self.list()?.into_iter().skip(self.retention_count) {
+            std::fs::remove_file(&backup.path).map_err(|e| {
+                Self::backup_error(format!("remove {}: {}", backup.path.display(), e))
+            })?;
+            removed.push(backup.name);
+        }
+        Ok(removed)
+    }
+
+    /// Fail unless `PRAGMA integrity_check` reports `ok` for the file.
+    pub async fn verify(path: &Path) -> DbErrorResult<()> {
+        let name = path.display();
+        let mut conn = SqliteConnectOptions::new()
+            .filename(path)
+            .read_only(true)
+            .connect()
+            .await
+            .map_err(|e| Self::backup_error(format!("open {}: {}", name, e)))?;
+
+        let result = sqlx::query_scalar::<_, String>("PRAGMA integrity_check")
+            .fetch_all(&mut conn)
+            .await;
+        let _ = conn.close().await;
+
+        match result {
+            Ok(rows) if rows == ["ok"] => Ok(()),
+            Ok(rows) => Err(Self::backup_error(format!(
+                "{} failed integrity check: {}",
+                name,
+                rows.join("; ")
+            ))),
+            Err(e) => Err(Self::backup_error(format!(
+                "{} failed integrity check: {}",
+                name, e
+            ))),
+        }
+    }
+
+    /// Verify the named backup and stage it to replace the database at the
+    /// next startup.
+    pub async fn stage_restore(&self, name: &str) -> DbErrorResult<BackupInfo> {
+        // Only names from the listing are accepted, so a name can't point
+        // outside the backup directory
+        let backup = self
+            .list()?
+            .into_iter()
+            .find(|backup| backup.name == name)
+            .ok_or_else(|| Self::backup_error(format!("no backup named {}", name)))?;
+
+        Self::verify(&backup.path).await?;
+
+        let staged = Self::staged_restore_path(&self.database_path);
+        std::fs::copy(&backup.path, &staged)
+            .map_err(|e| Self::backup_error(format!("stage {}: {}", staged.display(), e)))?;
+
+        Ok(backup)
+    }
+
+    /// Swap a staged restore into place. Call before opening the database.
+    ///
+    /// The replaced database and its WAL files are kept with a
+    /// `.pre-restore` suffix. Returns `false` when nothing was staged.
+    pub fn apply_staged_restore(database_path: &Path) -> DbErrorResult<bool> {
+        let staged = Self::staged_restore_path(database_path);
+        if !staged.exists() {
+            return Ok(false);
+        }
+
+        let previous = with_suffix(database_path, PRE_RESTORE_SUFFIX);
+        let moves = std::iter::once((database_path.to_path_buf(), previous.clone())).chain(
+            SIDE_FILE_SUFFIXES.iter().map(|suffix| {
+                (
+                    with_suffix(database_path, suffix),
+                    with_suffix(&previous, suffix),
+                )
+            }),
+        );
+        for (from, to) in moves {
+            // Clear side files left by an earlier restore so they can't pair
+            // with the wrong database
+            let _ = std::fs::remove_file(&to);
+            if from.exists() {
+                std::fs::rename(&from, &to)
+                    .map_err(|e| Self::backup_error(format!("move {}: {}", from.display(), e)))?;
+            }
+        }
+
+        std::fs::rename(&staged, database_path)
+            .map_err(|e| Self::backup_error(format!("restore {}: {}", staged.display(), e)))?;
+
+        Ok(true)
+    }
+
+    /// Where [`Self::stage_restore`] puts the backup for `database_path`
+    pub fn staged_restore_path(database_path: &Path) -> PathBuf {
+        with_suffix(database_path, STAGED_RESTORE_SUFFIX)
+    }
+
+    /// Describe `path` if it's named like a backup
+    fn info(path: PathBuf) -> Option<BackupInfo> {
+        let name = path.file_name()?.to_str()?.to_string();
+        let timestamp = name
+            .strip_prefix(BACKUP_PREFIX)?
+            .strip_suffix(BACKUP_EXTENSION)?;
+        let created_at = NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT)
+            .ok()?
+            .and_utc()
+            .timestamp();
+        let metadata = std::fs::metadata(&path).ok().filter(|m| m.is_file())?;
+
+        Some(BackupInfo {
+            name,
+            path,
+            size_bytes: metadata.len(),
+            created_at,
+        })
+    }
+
+    #[track_caller]
+    fn backup_error(message: String) -> DbError {
+        DbError::Backup {
+            message,
+            location: ErrorLocation::from(Location::caller()),
+        }
+    }
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(suffix);
+    PathBuf::from(name)
+}
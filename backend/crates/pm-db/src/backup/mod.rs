@@ -0,0 +1,2 @@
+pub mod backup_info;
+pub mod backup_store;
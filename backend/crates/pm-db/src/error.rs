@@ -23,6 +23,24 @@ pub enum DbError {
         location: ErrorLocation,
     },
 
+    #[error("Query rejected: {message} {location}")]
+    QueryRejected {
+        message: String,
+        location: ErrorLocation,
+    },
+
+    #[error("Query exceeded the {timeout_ms}ms time limit {location}")]
+    QueryTimeout {
+        timeout_ms: u64,
+        location: ErrorLocation,
+    },
+
+    #[error("Backup failed: {message} {location}")]
+    Backup {
+        message: String,
+        location: ErrorLocation,
+    },
+
     #[error("Tenant database not found: {tenant_id} {location}")]
     TenantNotFound {
         tenant_id: String,
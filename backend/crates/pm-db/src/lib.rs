@@ -1,15 +1,22 @@
+pub mod backup;
 pub mod error;
 pub mod repositories;
+pub mod sandbox;
+pub mod tenant;
 
+pub use backup::{backup_info::BackupInfo, backup_store::BackupStore};
 pub use error::{DbError, Result};
 pub use repositories::{
     activity_log_repository::ActivityLogRepository, comment_repository::CommentRepository,
-    dependency_repository::DependencyRepository, idempotency_repository::IdempotencyRepository,
-    llm_context_repository::LlmContextRepository,
+    custom_field_repository::CustomFieldRepository, dependency_repository::DependencyRepository,
+    idempotency_repository::IdempotencyRepository, llm_context_repository::LlmContextRepository,
     project_member_repository::ProjectMemberRepository, project_repository::ProjectRepository,
-    sprint_repository::SprintRepository, swim_lane_repository::SwimLaneRepository,
+    project_template_repository::ProjectTemplateRepository, sprint_repository::SprintRepository,
+    swim_lane_repository::SwimLaneRepository, sync_peer_repository::SyncPeerRepository,
     time_entry_repository::TimeEntryRepository, work_item_repository::WorkItemRepository,
 };
+pub use sandbox::{query_result::QueryResult, sql_sandbox::SqlSandbox};
+pub use tenant::{tenant_pool_options::TenantPoolOptions, tenant_pools::TenantPools};
 
 #[cfg(test)]
 mod tests;
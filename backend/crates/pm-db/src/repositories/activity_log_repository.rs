@@ -10,6 +10,20 @@ use uuid::Uuid;
 
 pub struct ActivityLogRepository;
 
+/// Raw row shape for the lookups that share `row_to_activity_log`
+struct ActivityLogRow {
+    id: Option<String>,
+    entity_type: String,
+    entity_id: String,
+    action: String,
+    field_name: Option<String>,
+    old_value: Option<String>,
+    new_value: Option<String>,
+    user_id: String,
+    timestamp: i64,
+    comment: Option<String>,
+}
+
 impl ActivityLogRepository {
     pub async fn create<'e, E>(executor: E, log: &ActivityLog) -> DbErrorResult<()>
     where
@@ -277,4 +291,81 @@ impl ActivityLogRepository {
 
         Ok(result.rows_affected())
     }
+
+    pub async fn find_by_id<'e, E>(executor: E, id: Uuid) -> DbErrorResult<Option<ActivityLog>>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let id_str = id.to_string();
+
+        let row = sqlx::query_as!(
+            ActivityLogRow,
+            r#"
+                SELECT id, entity_type, entity_id, action,
+                       field_name, old_value, new_value,
+                       user_id, timestamp, comment
+                FROM pm_activity_log
+                WHERE id = ?
+                "#,
+            id_str
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        row.map(row_to_activity_log).transpose()
+    }
+
+    /// The whole log, oldest first, for export
+    pub async fn find_all<'e, E>(executor: E) -> DbErrorResult<Vec<ActivityLog>>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let rows = sqlx::query_as!(
+            ActivityLogRow,
+            r#"
+                SELECT id, entity_type, entity_id, action,
+                       field_name, old_value, new_value,
+                       user_id, timestamp, comment
+                FROM pm_activity_log
+                ORDER BY timestamp ASC, id ASC
+                "#
+        )
+        .fetch_all(executor)
+        .await?;
+
+        rows.into_iter().map(row_to_activity_log).collect()
+    }
+}
+
+fn row_to_activity_log(r: ActivityLogRow) -> DbErrorResult<ActivityLog> {
+    Ok(ActivityLog {
+        id: Uuid::parse_str(r.id.as_ref().ok_or_else(|| DbError::Initialization {
+            message: "activity_log.id is NULL".to_string(),
+            location: ErrorLocation::from(Location::caller()),
+        })?)
+        .map_err(|e| DbError::Initialization {
+            message: format!("Invalid UUID in activity_log.id: {}", e),
+            location: ErrorLocation::from(Location::caller()),
+        })?,
+        entity_type: r.entity_type,
+        entity_id: Uuid::parse_str(&r.entity_id).map_err(|e| DbError::Initialization {
+            message: format!("Invalid UUID in activity_log.entity_id: {}", e),
+            location: ErrorLocation::from(Location::caller()),
+        })?,
+        action: r.action,
+        field_name: r.field_name,
+        old_value: r.old_value,
+        new_value: r.new_value,
+        user_id: Uuid::parse_str(&r.user_id).map_err(|e| DbError::Initialization {
+            message: format!("Invalid UUID in activity_log.user_id: {}", e),
+            location: ErrorLocation::from(Location::caller()),
+        })?,
+        timestamp: DateTime::from_timestamp(r.timestamp, 0).ok_or_else(|| {
+            DbError::Initialization {
+                message: "Invalid timestamp in activity_log.timestamp".to_string(),
+                location: ErrorLocation::from(Location::caller()),
+            }
+        })?,
+        comment: r.comment,
+    })
 }
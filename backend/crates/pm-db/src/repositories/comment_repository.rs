@@ -19,6 +19,13 @@ impl CommentRepository {
     }
 
     pub async fn create(&self, comment: &Comment) -> DbErrorResult<()> {
+        Self::create_with(&self.pool, comment).await
+    }
+
+    pub async fn create_with<'e, E>(executor: E, comment: &Comment) -> DbErrorResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
         let id = comment.id.to_string();
         let work_item_id = comment.work_item_id.to_string();
         let created_at = comment.created_at.timestamp();
@@ -43,13 +50,20 @@ impl CommentRepository {
             updated_by,
             deleted_at,
         )
-        .execute(&self.pool)
+        .execute(executor)
         .await?;
 
         Ok(())
     }
 
     pub async fn find_by_id(&self, id: Uuid) -> DbErrorResult<Option<Comment>> {
+        Self::find_by_id_with(&self.pool, id).await
+    }
+
+    pub async fn find_by_id_with<'e, E>(executor: E, id: Uuid) -> DbErrorResult<Option<Comment>>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
         let id_str = id.to_string();
 
         let row = sqlx::query!(
@@ -61,7 +75,7 @@ impl CommentRepository {
                 "#,
             id_str
         )
-        .fetch_optional(&self.pool)
+        .fetch_optional(executor)
         .await?;
 
         row.map(|r| -> DbErrorResult<Comment> {
@@ -176,6 +190,13 @@ impl CommentRepository {
     }
 
     pub async fn update(&self, comment: &Comment) -> DbErrorResult<()> {
+        Self::update_with(&self.pool, comment).await
+    }
+
+    pub async fn update_with<'e, E>(executor: E, comment: &Comment) -> DbErrorResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
         let id = comment.id.to_string();
         let updated_at = comment.updated_at.timestamp();
         let updated_by = comment.updated_by.to_string();
@@ -191,7 +212,7 @@ impl CommentRepository {
             updated_by,
             id,
         )
-        .execute(&self.pool)
+        .execute(executor)
         .await?;
 
         Ok(())
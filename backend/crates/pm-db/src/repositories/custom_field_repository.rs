@@ -0,0 +1,316 @@
+use crate::{DbError, Result as DbErrorResult};
+
+use pm_core::{CustomFieldDefinition, CustomFieldType};
+
+use std::panic::Location;
+use std::str::FromStr;
+
+use chrono::DateTime;
+use error_location::ErrorLocation;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+pub struct CustomFieldRepository {
+    pool: SqlitePool,
+}
+
+/// Raw row shape shared by every SELECT in this repository
+struct CustomFieldRow {
+    id: Option<String>,
+    project_id: String,
+    name: String,
+    label: String,
+    field_type: String,
+    options: String,
+    required: bool,
+    default_value: Option<String>,
+    position: i64,
+    version: i64,
+    created_at: i64,
+    updated_at: i64,
+    created_by: String,
+    updated_by: String,
+    deleted_at: Option<i64>,
+}
+
+impl CustomFieldRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create(&self, field: &CustomFieldDefinition) -> DbErrorResult<()> {
+        Self::create_with(&self.pool, field).await
+    }
+
+    pub async fn create_with<'e, E>(executor: E, field: &CustomFieldDefinition) -> DbErrorResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let id = field.id.to_string();
+        let project_id = field.project_id.to_string();
+        let field_type = field.field_type.as_str();
+        let options = serde_json::Value::from(field.options.clone()).to_string();
+        let default_value = field.default_value.as_ref().map(|v| v.to_string());
+        let created_at = field.created_at.timestamp();
+        let updated_at = field.updated_at.timestamp();
+        let created_by = field.created_by.to_string();
+        let updated_by = field.updated_by.to_string();
+        let deleted_at = field.deleted_at.map(|dt| dt.timestamp());
+
+        sqlx::query!(
+            r#"
+              INSERT INTO pm_custom_field_definitions (
+                  id, project_id, name, label, field_type, options, required,
+                  default_value, position, version,
+                  created_at, updated_at, created_by, updated_by, deleted_at
+              ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+              "#,
+            id,
+            project_id,
+            field.name,
+            field.label,
+            field_type,
+            options,
+            field.required,
+            default_value,
+            field.position,
+            field.version,
+            created_at,
+            updated_at,
+            created_by,
+            updated_by,
+            deleted_at,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn find_by_id(&self, id: Uuid) -> DbErrorResult<Option<CustomFieldDefinition>> {
+        Self::find_by_id_with(&self.pool, id).await
+    }
+
+    pub async fn find_by_id_with<'e, E>(
+        executor: E,
+        id: Uuid,
+    ) -> DbErrorResult<Option<CustomFieldDefinition>>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let id_str = id.to_string();
+
+        let row = sqlx::query_as!(
+            CustomFieldRow,
+            r#"
+              SELECT id, project_id, name, label, field_type, options,
+                     required as "required: bool", default_value, position, version,
+                     created_at, updated_at, created_by, updated_by, deleted_at
+              FROM pm_custom_field_definitions
+              WHERE id = ? AND deleted_at IS NULL
+              "#,
+            id_str
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        row.map(row_to_definition).transpose()
+    }
+
+    /// List the live definitions for a project, ordered by position then name
+    pub async fn find_by_project(
+        &self,
+        project_id: Uuid,
+    ) -> DbErrorResult<Vec<CustomFieldDefinition>> {
+        let project_id_str = project_id.to_string();
+
+        let rows = sqlx::query_as!(
+            CustomFieldRow,
+            r#"
+              SELECT id, project_id, name, label, field_type, options,
+                     required as "required: bool", default_value, position, version,
+                     created_at, updated_at, created_by, updated_by, deleted_at
+              FROM pm_custom_field_definitions
+              WHERE project_id = ? AND deleted_at IS NULL
+              ORDER BY position ASC, name ASC
+              "#,
+            project_id_str
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(row_to_definition).collect()
+    }
+
+    pub async fn find_by_project_and_name(
+        &self,
+        project_id: Uuid,
+        name: &str,
+    ) -> DbErrorResult<Option<CustomFieldDefinition>> {
+        Self::find_by_project_and_name_with(&self.pool, project_id, name).await
+    }
+
+    pub async fn find_by_project_and_name_with<'e, E>(
+        executor: E,
+        project_id: Uuid,
+        name: &str,
+    ) -> DbErrorResult<Option<CustomFieldDefinition>>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let project_id_str = project_id.to_string();
+
+        let row = sqlx::query_as!(
+            CustomFieldRow,
+            r#"
+              SELECT id, project_id, name, label, field_type, options,
+                     required as "required: bool", default_value, position, version,
+                     created_at, updated_at, created_by, updated_by, deleted_at
+              FROM pm_custom_field_definitions
+              WHERE project_id = ? AND name = ? AND deleted_at IS NULL
+              "#,
+            project_id_str,
+            name
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        row.map(row_to_definition).transpose()
+    }
+
+    /// Update mutable attributes. `name`, `field_type` and `project_id` are fixed.
+    pub async fn update(&self, field: &CustomFieldDefinition) -> DbErrorResult<()> {
+        Self::update_with(&self.pool, field).await
+    }
+
+    pub async fn update_with<'e, E>(executor: E, field: &CustomFieldDefinition) -> DbErrorResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let id = field.id.to_string();
+        let options = serde_json::Value::from(field.options.clone()).to_string();
+        let default_value = field.default_value.as_ref().map(|v| v.to_string());
+        let updated_at = field.updated_at.timestamp();
+        let updated_by = field.updated_by.to_string();
+
+        sqlx::query!(
+            r#"
+                UPDATE pm_custom_field_definitions
+                SET label = ?, options = ?, required = ?, default_value = ?,
+                    position = ?, version = ?, updated_at = ?, updated_by = ?
+                WHERE id = ? AND deleted_at IS NULL
+            "#,
+            field.label,
+            options,
+            field.required,
+            default_value,
+            field.position,
+            field.version,
+            updated_at,
+            updated_by,
+            id,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(&self, id: Uuid, deleted_at: i64) -> DbErrorResult<()> {
+        let id_str = id.to_string();
+
+        sqlx::query!(
+            r#"
+              UPDATE pm_custom_field_definitions
+              SET deleted_at = ?
+              WHERE id = ? AND deleted_at IS NULL
+              "#,
+            deleted_at,
+            id_str
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn find_all(&self) -> DbErrorResult<Vec<CustomFieldDefinition>> {
+        let rows = sqlx::query_as!(
+            CustomFieldRow,
+            r#"
+              SELECT id, project_id, name, label, field_type, options,
+                     required as "required: bool", default_value, position, version,
+                     created_at, updated_at, created_by, updated_by, deleted_at
+              FROM pm_custom_field_definitions
+              WHERE deleted_at IS NULL
+              ORDER BY project_id, position ASC, name ASC
+              "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(row_to_definition).collect()
+    }
+}
+
+fn row_to_definition(r: CustomFieldRow) -> DbErrorResult<CustomFieldDefinition> {
+    Ok(CustomFieldDefinition {
+        id: Uuid::parse_str(r.id.as_ref().ok_or_else(|| DbError::Initialization {
+            message: "custom_field.id is NULL".to_string(),
+            location: ErrorLocation::from(Location::caller()),
+        })?)
+        .map_err(|e| DbError::Initialization {
+            message: format!("Invalid UUID in custom_field.id: {}", e),
+            location: ErrorLocation::from(Location::caller()),
+        })?,
+        project_id: Uuid::parse_str(&r.project_id).map_err(|e| DbError::Initialization {
+            message: format!("Invalid UUID in custom_field.project_id: {}", e),
+            location: ErrorLocation::from(Location::caller()),
+        })?,
+        name: r.name,
+        label: r.label,
+        field_type: CustomFieldType::from_str(&r.field_type).map_err(|e| {
+            DbError::Initialization {
+                message: format!("Invalid CustomFieldType in custom_field.field_type: {}", e),
+                location: ErrorLocation::from(Location::caller()),
+            }
+        })?,
+        options: serde_json::from_str(&r.options).map_err(|e| DbError::Initialization {
+            message: format!("Invalid JSON in custom_field.options: {}", e),
+            location: ErrorLocation::from(Location::caller()),
+        })?,
+        required: r.required,
+        default_value: r
+            .default_value
+            .as_deref()
+            .map(serde_json::from_str)
+            .transpose()
+            .map_err(|e| DbError::Initialization {
+                message: format!("Invalid JSON in custom_field.default_value: {}", e),
+                location: ErrorLocation::from(Location::caller()),
+            })?,
+        position: r.position as i32,
+        version: r.version as i32,
+        created_at: DateTime::from_timestamp(r.created_at, 0).ok_or_else(|| {
+            DbError::Initialization {
+                message: "Invalid timestamp in custom_field.created_at".to_string(),
+                location: ErrorLocation::from(Location::caller()),
+            }
+        })?,
+        updated_at: DateTime::from_timestamp(r.updated_at, 0).ok_or_else(|| {
+            DbError::Initialization {
+                message: "Invalid timestamp in custom_field.updated_at".to_string(),
+                location: ErrorLocation::from(Location::caller()),
+            }
+        })?,
+        created_by: Uuid::parse_str(&r.created_by).map_err(|e| DbError::Initialization {
+            message: format!("Invalid UUID in custom_field.created_by: {}", e),
+            location: ErrorLocation::from(Location::caller()),
+        })?,
+        updated_by: Uuid::parse_str(&r.updated_by).map_err(|e| DbError::Initialization {
+            message: format!("Invalid UUID in custom_field.updated_by: {}", e),
+            location: ErrorLocation::from(Location::caller()),
+        })?,
+        deleted_at: r.deleted_at.and_then(|ts| DateTime::from_timestamp(ts, 0)),
+    })
+}
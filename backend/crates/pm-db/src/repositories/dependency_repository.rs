@@ -20,6 +20,13 @@ impl DependencyRepository {
     }
 
     pub async fn create(&self, dependency: &Dependency) -> DbErrorResult<()> {
+        Self::create_with(&self.pool, dependency).await
+    }
+
+    pub async fn create_with<'e, E>(executor: E, dependency: &Dependency) -> DbErrorResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
         let id = dependency.id.to_string();
         let blocking_item_id = dependency.blocking_item_id.to_string();
         let blocked_item_id = dependency.blocked_item_id.to_string();
@@ -43,13 +50,20 @@ impl DependencyRepository {
             created_by,
             deleted_at,
         )
-        .execute(&self.pool)
+        .execute(executor)
         .await?;
 
         Ok(())
     }
 
     pub async fn find_by_id(&self, id: Uuid) -> DbErrorResult<Option<Dependency>> {
+        Self::find_by_id_with(&self.pool, id).await
+    }
+
+    pub async fn find_by_id_with<'e, E>(executor: E, id: Uuid) -> DbErrorResult<Option<Dependency>>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
         let id_str = id.to_string();
 
         let row = sqlx::query!(
@@ -61,7 +75,7 @@ impl DependencyRepository {
                 "#,
             id_str
         )
-        .fetch_optional(&self.pool)
+        .fetch_optional(executor)
         .await?;
 
         row.map(|r| -> DbErrorResult<Dependency> {
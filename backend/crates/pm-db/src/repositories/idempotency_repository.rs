@@ -52,6 +52,59 @@ impl IdempotencyRepository {
         Ok(())
     }
 
+    /// Insert `result_json` under `message_id` unless the key is already
+    /// taken. Returns whether this call took it; the unique key makes this
+    /// safe against concurrent callers.
+    pub async fn reserve(
+        &self,
+        message_id: &str,
+        operation: &str,
+        result_json: &str,
+    ) -> DbErrorResult<bool> {
+        let created_at = Utc::now().timestamp();
+
+        let result = sqlx::query!(
+            r#"
+              INSERT INTO pm_idempotency_keys (message_id, operation, result_json, created_at)
+              VALUES (?, ?, ?, ?)
+              ON CONFLICT(message_id) DO NOTHING
+              "#,
+            message_id,
+            operation,
+            result_json,
+            created_at
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    /// Replace the stored result of a reserved key
+    pub async fn complete(&self, message_id: &str, result_json: &str) -> DbErrorResult<()> {
+        sqlx::query!(
+            "UPDATE pm_idempotency_keys SET result_json = ? WHERE message_id = ?",
+            result_json,
+            message_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Release a key so the request can be sent again
+    pub async fn delete(&self, message_id: &str) -> DbErrorResult<()> {
+        sqlx::query!(
+            "DELETE FROM pm_idempotency_keys WHERE message_id = ?",
+            message_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
     pub async fn cleanup_old_entries(&self, max_age_seconds: i64) -> DbErrorResult<u64> {
         let cutoff = Utc::now().timestamp() - max_age_seconds;
 
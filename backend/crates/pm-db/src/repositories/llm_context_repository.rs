@@ -1,24 +1,159 @@
 use crate::{DbError, Result as DbErrorResult};
 
-use pm_core::{LlmContext, LlmContextType};
+use pm_core::{ExampleSqlProblem, ExampleSqlReport, LlmContext, LlmContextType};
 
 use std::panic::Location;
 use std::str::FromStr;
 
 use chrono::{DateTime, Utc};
 use error_location::ErrorLocation;
+use sqlx::{Executor, SqlitePool};
 use uuid::Uuid;
 
 pub struct LlmContextRepository;
 
+/// Raw row shape shared by every SELECT in this repository
+struct LlmContextRow {
+    id: Option<String>,
+    project_id: Option<String>,
+    context_type: String,
+    category: String,
+    title: String,
+    content: String,
+    example_sql: Option<String>,
+    example_description: Option<String>,
+    priority: i64,
+    created_at: i64,
+    updated_at: i64,
+    deleted_at: Option<i64>,
+}
+
 impl LlmContextRepository {
+    pub async fn create<'e, E>(executor: E, entry: &LlmContext) -> DbErrorResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let id = entry.id.to_string();
+        let project_id = entry.project_id.map(|id| id.to_string());
+        let context_type = entry.context_type.as_str();
+        let created_at = entry.created_at.timestamp();
+        let updated_at = entry.updated_at.timestamp();
+        let deleted_at = entry.deleted_at.map(|dt| dt.timestamp());
+
+        sqlx::query!(
+            r#"
+            INSERT INTO pm_llm_context (
+                id, project_id, context_type, category, title, content,
+                example_sql, example_description, priority,
+                created_at, updated_at, deleted_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+            id,
+            project_id,
+            context_type,
+            entry.category,
+            entry.title,
+            entry.content,
+            entry.example_sql,
+            entry.example_description,
+            entry.priority,
+            created_at,
+            updated_at,
+            deleted_at,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Update the editable fields. An entry cannot move between projects.
+    pub async fn update<'e, E>(executor: E, entry: &LlmContext) -> DbErrorResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let id = entry.id.to_string();
+        let context_type = entry.context_type.as_str();
+        let updated_at = entry.updated_at.timestamp();
+
+        sqlx::query!(
+            r#"
+            UPDATE pm_llm_context
+            SET context_type = ?, category = ?, title = ?, content = ?,
+                example_sql = ?, example_description = ?, priority = ?,
+                updated_at = ?
+            WHERE id = ? AND deleted_at IS NULL
+            "#,
+            context_type,
+            entry.category,
+            entry.title,
+            entry.content,
+            entry.example_sql,
+            entry.example_description,
+            entry.priority,
+            updated_at,
+            id,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn soft_delete<'e, E>(executor: E, id: Uuid, deleted_at: i64) -> DbErrorResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let id_str = id.to_string();
+
+        sqlx::query!(
+            r#"
+            UPDATE pm_llm_context
+            SET deleted_at = ?, updated_at = ?
+            WHERE id = ? AND deleted_at IS NULL
+            "#,
+            deleted_at,
+            deleted_at,
+            id_str,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn find_by_id<'e, E>(executor: E, id: Uuid) -> DbErrorResult<Option<LlmContext>>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let id_str = id.to_string();
+
+        let row = sqlx::query_as!(
+            LlmContextRow,
+            r#"
+            SELECT id, project_id, context_type, category, title, content,
+                   example_sql, example_description, priority,
+                   created_at, updated_at, deleted_at
+            FROM pm_llm_context
+            WHERE id = ? AND deleted_at IS NULL
+            "#,
+            id_str
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        row.map(row_to_llm_context).transpose()
+    }
+
+    /// Every live entry, global and project-specific
     pub async fn list_all<'e, E>(executor: E) -> DbErrorResult<Vec<LlmContext>>
     where
         E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
     {
-        let rows = sqlx::query!(
+        let rows = sqlx::query_as!(
+            LlmContextRow,
             r#"
-            SELECT id, context_type, category, title, content,
+            SELECT id, project_id, context_type, category, title, content,
                    example_sql, example_description, priority,
                    created_at, updated_at, deleted_at
             FROM pm_llm_context
@@ -29,52 +164,48 @@ impl LlmContextRepository {
         .fetch_all(executor)
         .await?;
 
-        rows.into_iter()
-            .map(|r| {
-                let id = r.id.ok_or_else(|| DbError::Initialization {
-                    message: "llm_context.id is NULL".to_string(),
-                    location: ErrorLocation::from(Location::caller()),
-                })?;
-
-                Ok(LlmContext {
-                    id: Uuid::parse_str(&id).map_err(|e| DbError::Initialization {
-                        message: format!("Invalid UUID in llm_context.id: {}", e),
-                        location: ErrorLocation::from(Location::caller()),
-                    })?,
-                    context_type: LlmContextType::from_str(&r.context_type).map_err(|_| {
-                        DbError::Initialization {
-                            message: format!("Invalid context_type: {}", r.context_type),
-                            location: ErrorLocation::from(Location::caller()),
-                        }
-                    })?,
-                    category: r.category,
-                    title: r.title,
-                    content: r.content,
-                    example_sql: r.example_sql,
-                    example_description: r.example_description,
-                    priority: r.priority as i32,
-                    created_at: DateTime::<Utc>::from_timestamp(r.created_at, 0).ok_or_else(
-                        || DbError::Initialization {
-                            message: "Invalid created_at timestamp".to_string(),
-                            location: ErrorLocation::from(Location::caller()),
-                        },
-                    )?,
-                    updated_at: DateTime::<Utc>::from_timestamp(r.updated_at, 0).ok_or_else(
-                        || DbError::Initialization {
-                            message: "Invalid updated_at timestamp".to_string(),
-                            location: ErrorLocation::from(Location::caller()),
-                        },
-                    )?,
-                    deleted_at: r
-                        .deleted_at
-                        .and_then(|ts| DateTime::<Utc>::from_timestamp(ts, 0)),
-                })
-            })
-            .collect()
+        rows.into_iter().map(row_to_llm_context).collect()
     }
 
+    /// Prepare every entry's `example_sql` as `EXPLAIN ...` against the
+    /// current schema. Nothing is executed; parameters like `?1` stay
+    /// unbound. Entries that fail are reported with SQLite's error.
+    pub async fn verify_example_sql(pool: &SqlitePool) -> DbErrorResult<ExampleSqlReport> {
+        let entries = Self::list_all(pool).await?;
+
+        let mut checked = 0;
+        let mut problems = Vec::new();
+        for entry in entries {
+            let Some(sql) = entry
+                .example_sql
+                .as_deref()
+                .filter(|s| !s.trim().is_empty())
+            else {
+                continue;
+            };
+            checked += 1;
+
+            let explain = format!("EXPLAIN {}", sql);
+            match pool.prepare(explain.as_str()).await {
+                Ok(_) => {}
+                Err(sqlx::Error::Database(e)) => {
+                    problems.push(ExampleSqlProblem::from_sqlite_error(
+                        entry.id.to_string(),
+                        entry.title,
+                        e.message(),
+                    ));
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        Ok(ExampleSqlReport { checked, problems })
+    }
+
+    /// Global entries, plus the entries of `project_id` when given
     pub async fn list_filtered<'e, E>(
         executor: E,
+        project_id: Option<Uuid>,
         category: Option<&str>,
         context_type: Option<&str>,
         min_priority: Option<i32>,
@@ -82,18 +213,23 @@ impl LlmContextRepository {
     where
         E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
     {
-        let rows = sqlx::query!(
+        let project_id = project_id.map(|id| id.to_string());
+
+        let rows = sqlx::query_as!(
+            LlmContextRow,
             r#"
-            SELECT id, context_type, category, title, content,
+            SELECT id, project_id, context_type, category, title, content,
                    example_sql, example_description, priority,
                    created_at, updated_at, deleted_at
             FROM pm_llm_context
             WHERE deleted_at IS NULL
-              AND (?1 IS NULL OR category = ?1)
-              AND (?2 IS NULL OR context_type = ?2)
-              AND (?3 IS NULL OR priority >= ?3)
+              AND (project_id IS NULL OR project_id = ?1)
+              AND (?2 IS NULL OR category = ?2)
+              AND (?3 IS NULL OR context_type = ?3)
+              AND (?4 IS NULL OR priority >= ?4)
             ORDER BY priority DESC, title ASC
             "#,
+            project_id,
             category,
             context_type,
             min_priority
@@ -101,47 +237,55 @@ impl LlmContextRepository {
         .fetch_all(executor)
         .await?;
 
-        rows.into_iter()
-            .map(|r| {
-                let id = r.id.ok_or_else(|| DbError::Initialization {
-                    message: "llm_context.id is NULL".to_string(),
-                    location: ErrorLocation::from(Location::caller()),
-                })?;
-
-                Ok(LlmContext {
-                    id: Uuid::parse_str(&id).map_err(|e| DbError::Initialization {
-                        message: format!("Invalid UUID in llm_context.id: {}", e),
-                        location: ErrorLocation::from(Location::caller()),
-                    })?,
-                    context_type: LlmContextType::from_str(&r.context_type).map_err(|_| {
-                        DbError::Initialization {
-                            message: format!("Invalid context_type: {}", r.context_type),
-                            location: ErrorLocation::from(Location::caller()),
-                        }
-                    })?,
-                    category: r.category,
-                    title: r.title,
-                    content: r.content,
-                    example_sql: r.example_sql,
-                    example_description: r.example_description,
-                    priority: r.priority as i32,
-                    created_at: DateTime::<Utc>::from_timestamp(r.created_at, 0).ok_or_else(
-                        || DbError::Initialization {
-                            message: "Invalid created_at timestamp".to_string(),
-                            location: ErrorLocation::from(Location::caller()),
-                        },
-                    )?,
-                    updated_at: DateTime::<Utc>::from_timestamp(r.updated_at, 0).ok_or_else(
-                        || DbError::Initialization {
-                            message: "Invalid updated_at timestamp".to_string(),
-                            location: ErrorLocation::from(Location::caller()),
-                        },
-                    )?,
-                    deleted_at: r
-                        .deleted_at
-                        .and_then(|ts| DateTime::<Utc>::from_timestamp(ts, 0)),
-                })
-            })
-            .collect()
+        rows.into_iter().map(row_to_llm_context).collect()
     }
 }
+
+fn row_to_llm_context(r: LlmContextRow) -> DbErrorResult<LlmContext> {
+    let id = r.id.ok_or_else(|| DbError::Initialization {
+        message: "llm_context.id is NULL".to_string(),
+        location: ErrorLocation::from(Location::caller()),
+    })?;
+
+    Ok(LlmContext {
+        id: Uuid::parse_str(&id).map_err(|e| DbError::Initialization {
+            message: format!("Invalid UUID in llm_context.id: {}", e),
+            location: ErrorLocation::from(Location::caller()),
+        })?,
+        project_id: r
+            .project_id
+            .map(|p| Uuid::parse_str(&p))
+            .transpose()
+            .map_err(|e| DbError::Initialization {
+                message: format!("Invalid UUID in llm_context.project_id: {}", e),
+                location: ErrorLocation::from(Location::caller()),
+            })?,
+        context_type: LlmContextType::from_str(&r.context_type).map_err(|_| {
+            DbError::Initialization {
+                message: format!("Invalid context_type: {}", r.context_type),
+                location: ErrorLocation::from(Location::caller()),
+            }
+        })?,
+        category: r.category,
+        title: r.title,
+        content: r.content,
+        example_sql: r.example_sql,
+        example_description: r.example_description,
+        priority: r.priority as i32,
+        created_at: DateTime::<Utc>::from_timestamp(r.created_at, 0).ok_or_else(|| {
+            DbError::Initialization {
+                message: "Invalid created_at timestamp".to_string(),
+                location: ErrorLocation::from(Location::caller()),
+            }
+        })?,
+        updated_at: DateTime::<Utc>::from_timestamp(r.updated_at, 0).ok_or_else(|| {
+            DbError::Initialization {
+                message: "Invalid updated_at timestamp".to_string(),
+                location: ErrorLocation::from(Location::caller()),
+            }
+        })?,
+        deleted_at: r
+            .deleted_at
+            .and_then(|ts| DateTime::<Utc>::from_timestamp(ts, 0)),
+    })
+}
@@ -1,11 +1,14 @@
 pub mod activity_log_repository;
 pub mod comment_repository;
+pub mod custom_field_repository;
 pub mod dependency_repository;
 pub mod idempotency_repository;
 pub mod llm_context_repository;
 pub mod project_member_repository;
 pub mod project_repository;
+pub mod project_template_repository;
 pub mod sprint_repository;
 pub mod swim_lane_repository;
+pub mod sync_peer_repository;
 pub mod time_entry_repository;
 pub mod work_item_repository;
@@ -22,6 +22,17 @@ impl ProjectMemberRepository {
         user_id: Uuid,
         project_id: Uuid,
     ) -> DbErrorResult<Option<ProjectMember>> {
+        Self::find_by_user_and_project_with(&self.pool, user_id, project_id).await
+    }
+
+    pub async fn find_by_user_and_project_with<'e, E>(
+        executor: E,
+        user_id: Uuid,
+        project_id: Uuid,
+    ) -> DbErrorResult<Option<ProjectMember>>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
         let user_id_str = user_id.to_string();
         let project_id_str = project_id.to_string();
 
@@ -34,7 +45,7 @@ impl ProjectMemberRepository {
             user_id_str,
             project_id_str
         )
-        .fetch_optional(&self.pool)
+        .fetch_optional(executor)
         .await?;
 
         row.map(|r| -> DbErrorResult<ProjectMember> {
@@ -116,7 +127,66 @@ impl ProjectMemberRepository {
             .collect::<DbErrorResult<Vec<_>>>()
     }
 
+    /// Every membership, for export
+    pub async fn find_all(&self) -> DbErrorResult<Vec<ProjectMember>> {
+        Self::find_all_with(&self.pool).await
+    }
+
+    pub async fn find_all_with<'e, E>(executor: E) -> DbErrorResult<Vec<ProjectMember>>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let rows = sqlx::query!(
+            r#"
+                SELECT id, project_id, user_id, role, created_at
+                FROM pm_project_members
+                ORDER BY project_id, created_at
+                "#
+        )
+        .fetch_all(executor)
+        .await?;
+
+        rows.into_iter()
+            .map(|r| -> DbErrorResult<ProjectMember> {
+                Ok(ProjectMember {
+                    id: Uuid::parse_str(r.id.as_ref().ok_or_else(|| DbError::Initialization {
+                        message: "project_member.id is NULL".to_string(),
+                        location: ErrorLocation::from(Location::caller()),
+                    })?)
+                    .map_err(|e| DbError::Initialization {
+                        message: format!("Invalid UUID in project_member.id: {}", e),
+                        location: ErrorLocation::from(Location::caller()),
+                    })?,
+                    project_id: Uuid::parse_str(&r.project_id).map_err(|e| {
+                        DbError::Initialization {
+                            message: format!("Invalid UUID in project_member.project_id: {}", e),
+                            location: ErrorLocation::from(Location::caller()),
+                        }
+                    })?,
+                    user_id: Uuid::parse_str(&r.user_id).map_err(|e| DbError::Initialization {
+                        message: format!("Invalid UUID in project_member.user_id: {}", e),
+                        location: ErrorLocation::from(Location::caller()),
+                    })?,
+                    role: r.role,
+                    created_at: DateTime::from_timestamp(r.created_at, 0).ok_or_else(|| {
+                        DbError::Initialization {
+                            message: "Invalid timestamp in project_member.created_at".to_string(),
+                            location: ErrorLocation::from(Location::caller()),
+                        }
+                    })?,
+                })
+            })
+            .collect::<DbErrorResult<Vec<_>>>()
+    }
+
     pub async fn create(&self, member: &ProjectMember) -> DbErrorResult<()> {
+        Self::create_with(&self.pool, member).await
+    }
+
+    pub async fn create_with<'e, E>(executor: E, member: &ProjectMember) -> DbErrorResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
         let id_str = member.id.to_string();
         let project_id_str = member.project_id.to_string();
         let user_id_str = member.user_id.to_string();
@@ -133,7 +203,7 @@ impl ProjectMemberRepository {
             member.role,
             created_at
         )
-        .execute(&self.pool)
+        .execute(executor)
         .await?;
 
         Ok(())
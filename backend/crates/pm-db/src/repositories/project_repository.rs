@@ -28,7 +28,7 @@ use pm_core::{Project, ProjectStatus};
 use std::panic::Location;
 use std::str::FromStr;
 
-use chrono::DateTime;
+use chrono::{DateTime, Utc};
 use error_location::ErrorLocation;
 use sqlx::SqlitePool;
 use uuid::Uuid;
@@ -43,6 +43,13 @@ impl ProjectRepository {
     }
 
     pub async fn create(&self, project: &Project) -> DbErrorResult<()> {
+        Self::create_with(&self.pool, project).await
+    }
+
+    pub async fn create_with<'e, E>(executor: E, project: &Project) -> DbErrorResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
         let id = project.id.to_string();
         let status = project.status.as_str();
         let created_at = project.created_at.timestamp();
@@ -72,13 +79,20 @@ impl ProjectRepository {
             deleted_at,
             project.next_work_item_number,
         )
-        .execute(&self.pool)
+        .execute(executor)
         .await?;
 
         Ok(())
     }
 
     pub async fn find_by_id(&self, id: Uuid) -> DbErrorResult<Option<Project>> {
+        Self::find_by_id_with(&self.pool, id).await
+    }
+
+    pub async fn find_by_id_with<'e, E>(executor: E, id: Uuid) -> DbErrorResult<Option<Project>>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
         let id_str = id.to_string();
 
         let row = sqlx::query!(
@@ -91,7 +105,7 @@ impl ProjectRepository {
                 "#,
             id_str
         )
-        .fetch_optional(&self.pool)
+        .fetch_optional(executor)
         .await?;
 
         row.map(|r| -> DbErrorResult<Project> {
@@ -343,6 +357,13 @@ impl ProjectRepository {
     }
 
     pub async fn update(&self, project: &Project) -> DbErrorResult<()> {
+        Self::update_with(&self.pool, project).await
+    }
+
+    pub async fn update_with<'e, E>(executor: E, project: &Project) -> DbErrorResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
         let id = project.id.to_string();
         let status = project.status.as_str();
         let updated_at = project.updated_at.timestamp();
@@ -364,7 +385,7 @@ impl ProjectRepository {
             updated_by,
             id,
         )
-        .execute(&self.pool)
+        .execute(executor)
         .await?;
 
         Ok(())
@@ -388,6 +409,64 @@ impl ProjectRepository {
         Ok(())
     }
 
+    /// Keep `key` as an old key of `project_id` after a rename
+    pub async fn create_key_alias_with<'e, E>(
+        executor: E,
+        key: &str,
+        project_id: Uuid,
+    ) -> DbErrorResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let project_id_str = project_id.to_string();
+        let created_at = Utc::now().timestamp();
+
+        sqlx::query!(
+            r#"
+            INSERT INTO pm_project_key_aliases (key, project_id, created_at)
+            VALUES (?, ?, ?)
+            "#,
+            key,
+            project_id_str,
+            created_at,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Drop an old key, e.g. when a project takes its old key back
+    pub async fn delete_key_alias_with<'e, E>(executor: E, key: &str) -> DbErrorResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        sqlx::query!("DELETE FROM pm_project_key_aliases WHERE key = ?", key)
+            .execute(executor)
+            .await?;
+
+        Ok(())
+    }
+
+    /// The project an old key now refers to
+    pub async fn find_id_by_key_alias(&self, key: &str) -> DbErrorResult<Option<Uuid>> {
+        let project_id = sqlx::query_scalar!(
+            "SELECT project_id FROM pm_project_key_aliases WHERE key = ?",
+            key
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        project_id
+            .map(|id| {
+                Uuid::parse_str(&id).map_err(|e| DbError::Initialization {
+                    message: format!("Invalid UUID in project_key_alias.project_id: {}", e),
+                    location: ErrorLocation::from(Location::caller()),
+                })
+            })
+            .transpose()
+    }
+
     /// Atomically get and increment the work item number for a project.
     /// Returns the number to assign to the new work item.
     ///
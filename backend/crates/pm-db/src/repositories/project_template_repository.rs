@@ -0,0 +1,157 @@
+use crate::{DbError, Result as DbErrorResult};
+
+use pm_core::ProjectTemplate;
+
+use std::panic::Location;
+
+use chrono::DateTime;
+use error_location::ErrorLocation;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+pub struct ProjectTemplateRepository {
+    pool: SqlitePool,
+}
+
+/// Raw row shape shared by every SELECT in this repository
+struct ProjectTemplateRow {
+    id: Option<String>,
+    name: String,
+    description: Option<String>,
+    structure: String,
+    created_at: i64,
+    updated_at: i64,
+    created_by: String,
+}
+
+impl ProjectTemplateRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create(&self, template: &ProjectTemplate) -> DbErrorResult<()> {
+        let id = template.id.to_string();
+        let structure =
+            serde_json::to_string(&template.structure).map_err(|e| DbError::Initialization {
+                message: format!("Failed to serialize project_template.structure: {}", e),
+                location: ErrorLocation::from(Location::caller()),
+            })?;
+        let created_at = template.created_at.timestamp();
+        let updated_at = template.updated_at.timestamp();
+        let created_by = template.created_by.to_string();
+
+        sqlx::query!(
+            r#"
+            INSERT INTO pm_project_templates (
+                id, name, description, structure, created_at, updated_at, created_by
+            ) VALUES (?, ?, ?, ?, ?, ?, ?)
+            "#,
+            id,
+            template.name,
+            template.description,
+            structure,
+            created_at,
+            updated_at,
+            created_by,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn find_by_id(&self, id: Uuid) -> DbErrorResult<Option<ProjectTemplate>> {
+        let id_str = id.to_string();
+
+        let row = sqlx::query_as!(
+            ProjectTemplateRow,
+            r#"
+            SELECT id, name, description, structure, created_at, updated_at, created_by
+            FROM pm_project_templates
+            WHERE id = ?
+            "#,
+            id_str
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(row_to_template).transpose()
+    }
+
+    pub async fn find_by_name(&self, name: &str) -> DbErrorResult<Option<ProjectTemplate>> {
+        let row = sqlx::query_as!(
+            ProjectTemplateRow,
+            r#"
+            SELECT id, name, description, structure, created_at, updated_at, created_by
+            FROM pm_project_templates
+            WHERE name = ?
+            "#,
+            name
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(row_to_template).transpose()
+    }
+
+    pub async fn find_all(&self) -> DbErrorResult<Vec<ProjectTemplate>> {
+        let rows = sqlx::query_as!(
+            ProjectTemplateRow,
+            r#"
+            SELECT id, name, description, structure, created_at, updated_at, created_by
+            FROM pm_project_templates
+            ORDER BY name
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(row_to_template).collect()
+    }
+
+    /// Delete a template. Projects created from it are not affected.
+    pub async fn delete(&self, id: Uuid) -> DbErrorResult<bool> {
+        let id_str = id.to_string();
+
+        let result = sqlx::query!("DELETE FROM pm_project_templates WHERE id = ?", id_str)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+fn row_to_template(r: ProjectTemplateRow) -> DbErrorResult<ProjectTemplate> {
+    Ok(ProjectTemplate {
+        id: Uuid::parse_str(r.id.as_ref().ok_or_else(|| DbError::Initialization {
+            message: "project_template.id is NULL".to_string(),
+            location: ErrorLocation::from(Location::caller()),
+        })?)
+        .map_err(|e| DbError::Initialization {
+            message: format!("Invalid UUID in project_template.id: {}", e),
+            location: ErrorLocation::from(Location::caller()),
+        })?,
+        name: r.name,
+        description: r.description,
+        structure: serde_json::from_str(&r.structure).map_err(|e| DbError::Initialization {
+            message: format!("Invalid JSON in project_template.structure: {}", e),
+            location: ErrorLocation::from(Location::caller()),
+        })?,
+        created_at: DateTime::from_timestamp(r.created_at, 0).ok_or_else(|| {
+            DbError::Initialization {
+                message: "Invalid timestamp in project_template.created_at".to_string(),
+                location: ErrorLocation::from(Location::caller()),
+            }
+        })?,
+        updated_at: DateTime::from_timestamp(r.updated_at, 0).ok_or_else(|| {
+            DbError::Initialization {
+                message: "Invalid timestamp in project_template.updated_at".to_string(),
+                location: ErrorLocation::from(Location::caller()),
+            }
+        })?,
+        created_by: Uuid::parse_str(&r.created_by).map_err(|e| DbError::Initialization {
+            message: format!("Invalid UUID in project_template.created_by: {}", e),
+            location: ErrorLocation::from(Location::caller()),
+        })?,
+    })
+}
@@ -20,6 +20,13 @@ impl SprintRepository {
     }
 
     pub async fn create(&self, sprint: &Sprint) -> DbErrorResult<()> {
+        Self::create_with(&self.pool, sprint).await
+    }
+
+    pub async fn create_with<'e, E>(executor: E, sprint: &Sprint) -> DbErrorResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
         let id = sprint.id.to_string();
         let project_id = sprint.project_id.to_string();
         let status = sprint.status.as_str();
@@ -53,13 +60,20 @@ impl SprintRepository {
             updated_by,
             deleted_at,
         )
-        .execute(&self.pool)
+        .execute(executor)
         .await?;
 
         Ok(())
     }
 
     pub async fn find_by_id(&self, id: Uuid) -> DbErrorResult<Option<Sprint>> {
+        Self::find_by_id_with(&self.pool, id).await
+    }
+
+    pub async fn find_by_id_with<'e, E>(executor: E, id: Uuid) -> DbErrorResult<Option<Sprint>>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
         let id_str = id.to_string();
 
         let row = sqlx::query!(
@@ -71,7 +85,7 @@ impl SprintRepository {
             "#,
             id_str
         )
-        .fetch_optional(&self.pool)
+        .fetch_optional(executor)
         .await?;
 
         row.map(|r| -> DbErrorResult<Sprint> {
@@ -306,6 +320,13 @@ impl SprintRepository {
     }
 
     pub async fn update(&self, sprint: &Sprint) -> DbErrorResult<()> {
+        Self::update_with(&self.pool, sprint).await
+    }
+
+    pub async fn update_with<'e, E>(executor: E, sprint: &Sprint) -> DbErrorResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
         let id = sprint.id.to_string();
         let project_id = sprint.project_id.to_string();
         let status = sprint.status.as_str();
@@ -333,7 +354,7 @@ impl SprintRepository {
             updated_by,
             id,
         )
-        .execute(&self.pool)
+        .execute(executor)
         .await?;
 
         Ok(())
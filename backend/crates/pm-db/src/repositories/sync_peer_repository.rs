@@ -0,0 +1,115 @@
+use crate::{DbError, Result as DbErrorResult};
+
+use pm_core::SyncPeer;
+
+use std::panic::Location;
+
+use chrono::{DateTime, Utc};
+use error_location::ErrorLocation;
+use sqlx::SqlitePool;
+
+pub struct SyncPeerRepository {
+    pool: SqlitePool,
+}
+
+/// Raw row shape shared by every SELECT in this repository
+struct SyncPeerRow {
+    url: Option<String>,
+    pulled_until: Option<i64>,
+    pushed_until: Option<i64>,
+    synced_at: Option<i64>,
+    updated_at: i64,
+}
+
+impl SyncPeerRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn find_by_url(&self, url: &str) -> DbErrorResult<Option<SyncPeer>> {
+        let row = sqlx::query_as!(
+            SyncPeerRow,
+            r#"
+            SELECT url, pulled_until, pushed_until, synced_at, updated_at
+            FROM pm_sync_peers
+            WHERE url = ?
+            "#,
+            url
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(row_to_sync_peer).transpose()
+    }
+
+    pub async fn find_all(&self) -> DbErrorResult<Vec<SyncPeer>> {
+        let rows = sqlx::query_as!(
+            SyncPeerRow,
+            r#"
+            SELECT url, pulled_until, pushed_until, synced_at, updated_at
+            FROM pm_sync_peers
+            ORDER BY url
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(row_to_sync_peer).collect()
+    }
+
+    /// Insert the peer or replace its watermarks
+    pub async fn upsert(&self, peer: &SyncPeer) -> DbErrorResult<()> {
+        let pulled_until = peer.pulled_until.map(|t| t.timestamp());
+        let pushed_until = peer.pushed_until.map(|t| t.timestamp());
+        let synced_at = peer.synced_at.map(|t| t.timestamp());
+        let updated_at = peer.updated_at.timestamp();
+
+        sqlx::query!(
+            r#"
+            INSERT INTO pm_sync_peers (url, pulled_until, pushed_until, synced_at, updated_at)
+            VALUES (?, ?, ?, ?, ?)
+            ON CONFLICT(url) DO UPDATE SET
+                pulled_until = excluded.pulled_until,
+                pushed_until = excluded.pushed_until,
+                synced_at = excluded.synced_at,
+                updated_at = excluded.updated_at
+            "#,
+            peer.url,
+            pulled_until,
+            pushed_until,
+            synced_at,
+            updated_at,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+fn row_to_sync_peer(r: SyncPeerRow) -> DbErrorResult<SyncPeer> {
+    let url = r.url.ok_or_else(|| DbError::Initialization {
+        message: "sync_peer.url is NULL".to_string(),
+        location: ErrorLocation::from(Location::caller()),
+    })?;
+    let timestamp = |secs: i64, column: &str| {
+        DateTime::<Utc>::from_timestamp(secs, 0).ok_or_else(|| DbError::Initialization {
+            message: format!("Invalid timestamp in sync_peer.{}: {}", column, secs),
+            location: ErrorLocation::from(Location::caller()),
+        })
+    };
+
+    Ok(SyncPeer {
+        url,
+        pulled_until: r
+            .pulled_until
+            .map(|t| timestamp(t, "pulled_until"))
+            .transpose()?,
+        pushed_until: r
+            .pushed_until
+            .map(|t| timestamp(t, "pushed_until"))
+            .transpose()?,
+        synced_at: r.synced_at.map(|t| timestamp(t, "synced_at")).transpose()?,
+        updated_at: timestamp(r.updated_at, "updated_at")?,
+    })
+}
@@ -2,9 +2,9 @@ use crate::{DbError, Result as DbErrorResult};
 
 use pm_core::TimeEntry;
 
-use std::panic::Location;
+use std::{collections::HashMap, panic::Location};
 
-use chrono::DateTime;
+use chrono::{DateTime, Utc};
 use error_location::ErrorLocation;
 use sqlx::SqlitePool;
 use uuid::Uuid;
@@ -19,6 +19,13 @@ impl TimeEntryRepository {
     }
 
     pub async fn create(&self, entry: &TimeEntry) -> DbErrorResult<()> {
+        Self::create_with(&self.pool, entry).await
+    }
+
+    pub async fn create_with<'e, E>(executor: E, entry: &TimeEntry) -> DbErrorResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
         let id = entry.id.to_string();
         let work_item_id = entry.work_item_id.to_string();
         let user_id = entry.user_id.to_string();
@@ -47,13 +54,20 @@ impl TimeEntryRepository {
             updated_at,
             deleted_at,
         )
-        .execute(&self.pool)
+        .execute(executor)
         .await?;
 
         Ok(())
     }
 
     pub async fn find_by_id(&self, id: Uuid) -> DbErrorResult<Option<TimeEntry>> {
+        Self::find_by_id_with(&self.pool, id).await
+    }
+
+    pub async fn find_by_id_with<'e, E>(executor: E, id: Uuid) -> DbErrorResult<Option<TimeEntry>>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
         let id_str = id.to_string();
 
         let row = sqlx::query!(
@@ -66,7 +80,7 @@ impl TimeEntryRepository {
                 "#,
             id_str
         )
-        .fetch_optional(&self.pool)
+        .fetch_optional(executor)
         .await?;
 
         row.map(|r| -> DbErrorResult<TimeEntry> {
@@ -340,6 +354,13 @@ impl TimeEntryRepository {
     }
 
     pub async fn update(&self, entry: &TimeEntry) -> DbErrorResult<()> {
+        Self::update_with(&self.pool, entry).await
+    }
+
+    pub async fn update_with<'e, E>(executor: E, entry: &TimeEntry) -> DbErrorResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
         let id = entry.id.to_string();
         let ended_at = entry.ended_at.map(|dt| dt.timestamp());
         let updated_at = entry.updated_at.timestamp();
@@ -356,7 +377,7 @@ impl TimeEntryRepository {
             updated_at,
             id,
         )
-        .execute(&self.pool)
+        .execute(executor)
         .await?;
 
         Ok(())
@@ -449,4 +470,123 @@ impl TimeEntryRepository {
             })
             .collect::<DbErrorResult<Vec<_>>>()
     }
+
+    /// Find entries overlapping `[from, to)`, including running timers that
+    /// started before `to`. Deleted entries are excluded.
+    pub async fn find_in_range(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> DbErrorResult<Vec<TimeEntry>> {
+        let from_ts = from.timestamp();
+        let to_ts = to.timestamp();
+
+        let rows = sqlx::query!(
+            r#"
+              SELECT id, work_item_id, user_id, started_at, ended_at,
+                     duration_seconds, description,
+                     created_at, updated_at, deleted_at
+              FROM pm_time_entries
+              WHERE deleted_at IS NULL
+                AND started_at < ?
+                AND (ended_at IS NULL OR ended_at > ?)
+              ORDER BY started_at ASC
+          "#,
+            to_ts,
+            from_ts
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|r| -> DbErrorResult<TimeEntry> {
+                Ok(TimeEntry {
+                    id: Uuid::parse_str(r.id.as_ref().ok_or_else(|| DbError::Initialization {
+                        message: "time_entry.id is NULL".to_string(),
+                        location: ErrorLocation::from(Location::caller()),
+                    })?)
+                    .map_err(|e| DbError::Initialization {
+                        message: format!("Invalid UUID in time_entry.id: {}", e),
+                        location: ErrorLocation::from(Location::caller()),
+                    })?,
+                    work_item_id: Uuid::parse_str(&r.work_item_id).map_err(|e| {
+                        DbError::Initialization {
+                            message: format!("Invalid UUID in time_entry.work_item_id: {}", e),
+                            location: ErrorLocation::from(Location::caller()),
+                        }
+                    })?,
+                    user_id: Uuid::parse_str(&r.user_id).map_err(|e| DbError::Initialization {
+                        message: format!("Invalid UUID in time_entry.user_id: {}", e),
+                        location: ErrorLocation::from(Location::caller()),
+                    })?,
+                    started_at: DateTime::from_timestamp(r.started_at, 0).ok_or_else(|| {
+                        DbError::Initialization {
+                            message: "Invalid timestamp in time_entry.started_at".to_string(),
+                            location: ErrorLocation::from(Location::caller()),
+                        }
+                    })?,
+                    ended_at: r
+                        .ended_at
+                        .map(|ts| {
+                            DateTime::from_timestamp(ts, 0).ok_or_else(|| DbError::Initialization {
+                                message: "Invalid timestamp in time_entry.ended_at".to_string(),
+                                location: ErrorLocation::from(Location::caller()),
+                            })
+                        })
+                        .transpose()?,
+                    duration_seconds: r.duration_seconds.map(|d| d as i32),
+                    description: r.description,
+                    created_at: DateTime::from_timestamp(r.created_at, 0).ok_or_else(|| {
+                        DbError::Initialization {
+                            message: "Invalid timestamp in time_entry.created_at".to_string(),
+                            location: ErrorLocation::from(Location::caller()),
+                        }
+                    })?,
+                    updated_at: DateTime::from_timestamp(r.updated_at, 0).ok_or_else(|| {
+                        DbError::Initialization {
+                            message: "Invalid timestamp in time_entry.updated_at".to_string(),
+                            location: ErrorLocation::from(Location::caller()),
+                        }
+                    })?,
+                    deleted_at: r.deleted_at.and_then(|ts| DateTime::from_timestamp(ts, 0)),
+                })
+            })
+            .collect::<DbErrorResult<Vec<_>>>()
+    }
+
+    /// Total completed time logged per work item in a project.
+    /// Running timers and deleted entries are excluded.
+    pub async fn logged_seconds_by_project(
+        &self,
+        project_id: Uuid,
+    ) -> DbErrorResult<HashMap<Uuid, i64>> {
+        let project_id_str = project_id.to_string();
+
+        let rows = sqlx::query!(
+            r#"
+              SELECT te.work_item_id,
+                     SUM(te.duration_seconds) as "logged_seconds!: i64"
+              FROM pm_time_entries te
+              JOIN pm_work_items wi ON wi.id = te.work_item_id
+              WHERE wi.project_id = ?
+                AND te.deleted_at IS NULL
+                AND te.ended_at IS NOT NULL
+              GROUP BY te.work_item_id
+          "#,
+            project_id_str
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|r| -> DbErrorResult<(Uuid, i64)> {
+                let work_item_id =
+                    Uuid::parse_str(&r.work_item_id).map_err(|e| DbError::Initialization {
+                        message: format!("Invalid UUID in time_entry.work_item_id: {}", e),
+                        location: ErrorLocation::from(Location::caller()),
+                    })?;
+                Ok((work_item_id, r.logged_seconds))
+            })
+            .collect()
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{DbError, error::Result as DbErrorResult};
 
-use pm_core::{WorkItem, WorkItemType};
+use pm_core::{CustomFieldValues, WorkItem, WorkItemType};
 
 use std::panic::Location;
 use std::str::FromStr;
@@ -27,28 +27,34 @@ impl WorkItemRepository {
         let created_by = work_item.created_by.to_string();
         let updated_by = work_item.updated_by.to_string();
         let deleted_at = work_item.deleted_at.map(|dt| dt.timestamp());
+        let custom_fields = serialize_custom_fields(&work_item.custom_fields)?;
 
         sqlx::query!(
             r#"
               INSERT INTO pm_work_items (
-                  id, item_type, parent_id, project_id, position,
+                  id, item_type, parent_id, project_id, position, rank,
                   title, description, status, priority, assignee_id,
-                  story_points, sprint_id, item_number, version,
+                  story_points, original_estimate_seconds, remaining_estimate_seconds,
+                  sprint_id, custom_fields, item_number, version,
                   created_at, updated_at, created_by, updated_by, deleted_at
-              ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+              ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
               "#,
             id,
             item_type,
             parent_id,
             project_id,
             work_item.position,
+            work_item.rank,
             work_item.title,
             work_item.description,
             work_item.status,
             work_item.priority,
             assignee_id,
             work_item.story_points,
+            work_item.original_estimate_seconds,
+            work_item.remaining_estimate_seconds,
             sprint_id,
+            custom_fields,
             work_item.item_number,
             work_item.version,
             created_at,
@@ -72,9 +78,10 @@ impl WorkItemRepository {
         let row = sqlx::query!(
             r#"
               SELECT
-                  id, item_type, parent_id, project_id, position,
+                  id, item_type, parent_id, project_id, position, rank,
                   title, description, status, priority, assignee_id,
-                  story_points, sprint_id, item_number, version,
+                  story_points, original_estimate_seconds, remaining_estimate_seconds,
+                  sprint_id, custom_fields, item_number, version,
                   created_at, updated_at, created_by, updated_by, deleted_at
               FROM pm_work_items
               WHERE id = ? AND deleted_at IS NULL
@@ -108,13 +115,17 @@ impl WorkItemRepository {
                     }
                 })?,
                 position: r.position as i32,
+                rank: r.rank,
                 title: r.title,
                 description: r.description,
                 status: r.status,
                 priority: r.priority,
                 assignee_id: r.assignee_id.as_ref().and_then(|s| Uuid::parse_str(s).ok()),
                 story_points: r.story_points.map(|sp| sp as i32),
+                original_estimate_seconds: r.original_estimate_seconds.map(|s| s as i32),
+                remaining_estimate_seconds: r.remaining_estimate_seconds.map(|s| s as i32),
                 sprint_id: r.sprint_id.as_ref().and_then(|s| Uuid::parse_str(s).ok()),
+                custom_fields: parse_custom_fields(&r.custom_fields)?,
                 item_number: r.item_number as i32,
                 version: r.version as i32,
                 created_at: DateTime::from_timestamp(r.created_at, 0).ok_or_else(|| {
@@ -161,14 +172,15 @@ impl WorkItemRepository {
         let rows = sqlx::query!(
             r#"
                 SELECT
-                    id, item_type, parent_id, project_id, position,
+                    id, item_type, parent_id, project_id, position, rank,
                     title, description, status, priority, assignee_id,
-                    story_points, sprint_id, item_number, version,
+                    story_points, original_estimate_seconds, remaining_estimate_seconds,
+                  sprint_id, custom_fields, item_number, version,
                     created_at, updated_at, created_by, updated_by, deleted_at
                 FROM pm_work_items
                 WHERE project_id = ? AND deleted_at IS NULL
                   AND (? = 1 OR status != 'done')
-                ORDER BY position
+                ORDER BY rank, item_number
             "#,
             project_id_str,
             include_done_param
@@ -201,13 +213,17 @@ impl WorkItemRepository {
                         }
                     })?,
                     position: r.position as i32,
+                    rank: r.rank,
                     title: r.title,
                     description: r.description,
                     status: r.status,
                     priority: r.priority,
                     assignee_id: r.assignee_id.as_ref().and_then(|s| Uuid::parse_str(s).ok()),
                     story_points: r.story_points.map(|sp| sp as i32),
+                    original_estimate_seconds: r.original_estimate_seconds.map(|s| s as i32),
+                    remaining_estimate_seconds: r.remaining_estimate_seconds.map(|s| s as i32),
                     sprint_id: r.sprint_id.as_ref().and_then(|s| Uuid::parse_str(s).ok()),
+                    custom_fields: parse_custom_fields(&r.custom_fields)?,
                     item_number: r.item_number as i32,
                     version: r.version as i32,
                     created_at: DateTime::from_timestamp(r.created_at, 0).ok_or_else(|| {
@@ -240,6 +256,8 @@ impl WorkItemRepository {
             .collect::<DbErrorResult<Vec<_>>>()
     }
 
+    /// Write an edit. `rank` is left alone so an edit never undoes a
+    /// concurrent move or rebalance; see `update_rank`.
     pub async fn update<'e, E>(executor: E, work_item: &WorkItem) -> DbErrorResult<()>
     where
         E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
@@ -252,13 +270,15 @@ impl WorkItemRepository {
         let sprint_id = work_item.sprint_id.map(|id| id.to_string());
         let updated_at = work_item.updated_at.timestamp();
         let updated_by = work_item.updated_by.to_string();
+        let custom_fields = serialize_custom_fields(&work_item.custom_fields)?;
 
         sqlx::query!(
             r#"
             UPDATE pm_work_items
             SET item_type = ?, parent_id = ?, project_id = ?, position = ?,
                 title = ?, description = ?, status = ?, priority = ?, assignee_id = ?,
-                story_points = ?, sprint_id = ?, version = ?,
+                story_points = ?, original_estimate_seconds = ?, remaining_estimate_seconds = ?,
+                sprint_id = ?, custom_fields = ?, version = ?,
                 updated_at = ?, updated_by = ?
             WHERE id = ? AND deleted_at IS NULL
             "#,
@@ -272,7 +292,10 @@ impl WorkItemRepository {
             work_item.priority,
             assignee_id,
             work_item.story_points,
+            work_item.original_estimate_seconds,
+            work_item.remaining_estimate_seconds,
             sprint_id,
+            custom_fields,
             work_item.version,
             updated_at,
             updated_by,
@@ -284,6 +307,109 @@ impl WorkItemRepository {
         Ok(())
     }
 
+    /// Re-home a work item: writes its project, item number, parent,
+    /// position, rank, sprint and custom fields along with the version bump.
+    /// `update` leaves `item_number` alone, so moves go through here.
+    pub async fn move_to_project<'e, E>(executor: E, work_item: &WorkItem) -> DbErrorResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let id = work_item.id.to_string();
+        let project_id = work_item.project_id.to_string();
+        let parent_id = work_item.parent_id.map(|id| id.to_string());
+        let sprint_id = work_item.sprint_id.map(|id| id.to_string());
+        let updated_at = work_item.updated_at.timestamp();
+        let updated_by = work_item.updated_by.to_string();
+        let custom_fields = serialize_custom_fields(&work_item.custom_fields)?;
+
+        sqlx::query!(
+            r#"
+            UPDATE pm_work_items
+            SET project_id = ?, item_number = ?, parent_id = ?, position = ?, rank = ?,
+                sprint_id = ?, custom_fields = ?, version = ?,
+                updated_at = ?, updated_by = ?
+            WHERE id = ? AND deleted_at IS NULL
+            "#,
+            project_id,
+            work_item.item_number,
+            parent_id,
+            work_item.position,
+            work_item.rank,
+            sprint_id,
+            custom_fields,
+            work_item.version,
+            updated_at,
+            updated_by,
+            id,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Keep an old display key (project + number) pointing at a moved item
+    pub async fn create_alias<'e, E>(
+        executor: E,
+        project_id: Uuid,
+        item_number: i32,
+        work_item_id: Uuid,
+    ) -> DbErrorResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let project_id_str = project_id.to_string();
+        let work_item_id_str = work_item_id.to_string();
+        let created_at = Utc::now().timestamp();
+
+        sqlx::query!(
+            r#"
+            INSERT INTO pm_work_item_aliases (project_id, item_number, work_item_id, created_at)
+            VALUES (?, ?, ?, ?)
+            "#,
+            project_id_str,
+            item_number,
+            work_item_id_str,
+            created_at,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// The work item an old display key (project + number) now refers to
+    pub async fn find_id_by_alias<'e, E>(
+        executor: E,
+        project_id: Uuid,
+        item_number: i32,
+    ) -> DbErrorResult<Option<Uuid>>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let project_id_str = project_id.to_string();
+
+        let work_item_id = sqlx::query_scalar!(
+            r#"
+            SELECT work_item_id FROM pm_work_item_aliases
+            WHERE project_id = ? AND item_number = ?
+            "#,
+            project_id_str,
+            item_number
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        work_item_id
+            .map(|id| {
+                Uuid::parse_str(&id).map_err(|e| DbError::Initialization {
+                    message: format!("Invalid UUID in work_item_alias.work_item_id: {}", e),
+                    location: ErrorLocation::from(Location::caller()),
+                })
+            })
+            .transpose()
+    }
+
     pub async fn soft_delete<'e, E>(executor: E, id: Uuid, deleted_by: Uuid) -> DbErrorResult<()>
     where
         E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
@@ -318,9 +444,10 @@ impl WorkItemRepository {
         let rows = sqlx::query!(
             r#"
               SELECT
-                  id, item_type, parent_id, project_id, position,
+                  id, item_type, parent_id, project_id, position, rank,
                   title, description, status, priority, assignee_id,
-                  story_points, sprint_id, item_number, version,
+                  story_points, original_estimate_seconds, remaining_estimate_seconds,
+                  sprint_id, custom_fields, item_number, version,
                   created_at, updated_at, created_by, updated_by, deleted_at
               FROM pm_work_items
               WHERE parent_id = ? AND deleted_at IS NULL
@@ -355,13 +482,17 @@ impl WorkItemRepository {
                         }
                     })?,
                     position: r.position as i32,
+                    rank: r.rank,
                     title: r.title,
                     description: r.description,
                     status: r.status,
                     priority: r.priority,
                     assignee_id: r.assignee_id.as_ref().and_then(|s| Uuid::parse_str(s).ok()),
                     story_points: r.story_points.map(|sp| sp as i32),
+                    original_estimate_seconds: r.original_estimate_seconds.map(|s| s as i32),
+                    remaining_estimate_seconds: r.remaining_estimate_seconds.map(|s| s as i32),
                     sprint_id: r.sprint_id.as_ref().and_then(|s| Uuid::parse_str(s).ok()),
+                    custom_fields: parse_custom_fields(&r.custom_fields)?,
                     item_number: r.item_number as i32,
                     version: r.version as i32,
                     created_at: DateTime::from_timestamp(r.created_at, 0).ok_or_else(|| {
@@ -420,6 +551,132 @@ impl WorkItemRepository {
         Ok(result as i64)
     }
 
+    /// Move a work item within its backlog: writes its rank along with the
+    /// version bump and nothing else
+    pub async fn update_rank<'e, E>(executor: E, work_item: &WorkItem) -> DbErrorResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let id = work_item.id.to_string();
+        let updated_at = work_item.updated_at.timestamp();
+        let updated_by = work_item.updated_by.to_string();
+
+        sqlx::query!(
+            r#"
+            UPDATE pm_work_items
+            SET rank = ?, version = ?, updated_at = ?, updated_by = ?
+            WHERE id = ? AND deleted_at IS NULL
+            "#,
+            work_item.rank,
+            work_item.version,
+            updated_at,
+            updated_by,
+            id,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Overwrite a rank without bumping the version, for imports; the
+    /// backlog order it encodes is already agreed with the exporting peer
+    pub async fn set_rank<'e, E>(executor: E, id: Uuid, rank: &str) -> DbErrorResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let id_str = id.to_string();
+
+        sqlx::query!(
+            "UPDATE pm_work_items SET rank = ? WHERE id = ?",
+            rank,
+            id_str
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// The highest rank in a project's backlog, if it has any items
+    pub async fn find_last_rank<'e, E>(
+        executor: E,
+        project_id: Uuid,
+    ) -> DbErrorResult<Option<String>>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let project_id_str = project_id.to_string();
+
+        let rank = sqlx::query_scalar!(
+            r#"
+            SELECT MAX(rank) as "rank: String" FROM pm_work_items
+            WHERE project_id = ? AND deleted_at IS NULL
+            "#,
+            project_id_str
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(rank)
+    }
+
+    /// The next rank above `rank` in a project's backlog, ignoring `exclude_id`
+    pub async fn find_rank_after<'e, E>(
+        executor: E,
+        project_id: Uuid,
+        rank: &str,
+        exclude_id: Uuid,
+    ) -> DbErrorResult<Option<String>>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let project_id_str = project_id.to_string();
+        let exclude_id_str = exclude_id.to_string();
+
+        let rank = sqlx::query_scalar!(
+            r#"
+            SELECT MIN(rank) as "rank: String" FROM pm_work_items
+            WHERE project_id = ? AND rank > ? AND id != ? AND deleted_at IS NULL
+            "#,
+            project_id_str,
+            rank,
+            exclude_id_str
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(rank)
+    }
+
+    /// The next rank below `rank` in a project's backlog, ignoring `exclude_id`
+    pub async fn find_rank_before<'e, E>(
+        executor: E,
+        project_id: Uuid,
+        rank: &str,
+        exclude_id: Uuid,
+    ) -> DbErrorResult<Option<String>>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let project_id_str = project_id.to_string();
+        let exclude_id_str = exclude_id.to_string();
+
+        let rank = sqlx::query_scalar!(
+            r#"
+            SELECT MAX(rank) as "rank: String" FROM pm_work_items
+            WHERE project_id = ? AND rank < ? AND id != ? AND deleted_at IS NULL
+            "#,
+            project_id_str,
+            rank,
+            exclude_id_str
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(rank)
+    }
+
     pub async fn find_by_project_since<'e, E>(
         executor: E,
         project_id: Uuid,
@@ -434,13 +691,14 @@ impl WorkItemRepository {
         let rows = sqlx::query!(
             r#"
               SELECT
-                  id, item_type, parent_id, project_id, position,
+                  id, item_type, parent_id, project_id, position, rank,
                   title, description, status, priority, assignee_id,
-                  story_points, sprint_id, item_number, version,
+                  story_points, original_estimate_seconds, remaining_estimate_seconds,
+                  sprint_id, custom_fields, item_number, version,
                   created_at, updated_at, created_by, updated_by, deleted_at
               FROM pm_work_items
               WHERE project_id = ? AND updated_at > ?
-              ORDER BY position
+              ORDER BY rank, item_number
           "#,
             project_id_str,
             since_timestamp
@@ -473,13 +731,17 @@ impl WorkItemRepository {
                         }
                     })?,
                     position: r.position as i32,
+                    rank: r.rank,
                     title: r.title,
                     description: r.description,
                     status: r.status,
                     priority: r.priority,
                     assignee_id: r.assignee_id.as_ref().and_then(|s| Uuid::parse_str(s).ok()),
                     story_points: r.story_points.map(|sp| sp as i32),
+                    original_estimate_seconds: r.original_estimate_seconds.map(|s| s as i32),
+                    remaining_estimate_seconds: r.remaining_estimate_seconds.map(|s| s as i32),
                     sprint_id: r.sprint_id.as_ref().and_then(|s| Uuid::parse_str(s).ok()),
+                    custom_fields: parse_custom_fields(&r.custom_fields)?,
                     item_number: r.item_number as i32,
                     version: r.version as i32,
                     created_at: DateTime::from_timestamp(r.created_at, 0).ok_or_else(|| {
@@ -526,9 +788,10 @@ impl WorkItemRepository {
         let row = sqlx::query!(
             r#"
               SELECT
-                  id, item_type, parent_id, project_id, position,
+                  id, item_type, parent_id, project_id, position, rank,
                   title, description, status, priority, assignee_id,
-                  story_points, sprint_id, item_number, version,
+                  story_points, original_estimate_seconds, remaining_estimate_seconds,
+                  sprint_id, custom_fields, item_number, version,
                   created_at, updated_at, created_by, updated_by, deleted_at
               FROM pm_work_items
               WHERE project_id = ? AND item_number = ? AND deleted_at IS NULL
@@ -563,13 +826,17 @@ impl WorkItemRepository {
                     }
                 })?,
                 position: r.position as i32,
+                rank: r.rank,
                 title: r.title,
                 description: r.description,
                 status: r.status,
                 priority: r.priority,
                 assignee_id: r.assignee_id.as_ref().and_then(|s| Uuid::parse_str(s).ok()),
                 story_points: r.story_points.map(|sp| sp as i32),
+                original_estimate_seconds: r.original_estimate_seconds.map(|s| s as i32),
+                remaining_estimate_seconds: r.remaining_estimate_seconds.map(|s| s as i32),
                 sprint_id: r.sprint_id.as_ref().and_then(|s| Uuid::parse_str(s).ok()),
+                custom_fields: parse_custom_fields(&r.custom_fields)?,
                 item_number: r.item_number as i32,
                 version: r.version as i32,
                 created_at: DateTime::from_timestamp(r.created_at, 0).ok_or_else(|| {
@@ -610,14 +877,15 @@ impl WorkItemRepository {
         let rows = sqlx::query!(
             r#"
               SELECT
-                  id, item_type, parent_id, project_id, position,
+                  id, item_type, parent_id, project_id, position, rank,
                   title, description, status, priority, assignee_id,
-                  story_points, sprint_id, item_number, version,
+                  story_points, original_estimate_seconds, remaining_estimate_seconds,
+                  sprint_id, custom_fields, item_number, version,
                   created_at, updated_at, created_by, updated_by, deleted_at
               FROM pm_work_items
               WHERE deleted_at IS NULL
                 AND (? = 1 OR status != 'done')
-              ORDER BY position
+              ORDER BY rank, item_number
           "#,
             include_done_param
         )
@@ -649,13 +917,17 @@ impl WorkItemRepository {
                         }
                     })?,
                     position: r.position as i32,
+                    rank: r.rank,
                     title: r.title,
                     description: r.description,
                     status: r.status,
                     priority: r.priority,
                     assignee_id: r.assignee_id.as_ref().and_then(|s| Uuid::parse_str(s).ok()),
                     story_points: r.story_points.map(|sp| sp as i32),
+                    original_estimate_seconds: r.original_estimate_seconds.map(|s| s as i32),
+                    remaining_estimate_seconds: r.remaining_estimate_seconds.map(|s| s as i32),
                     sprint_id: r.sprint_id.as_ref().and_then(|s| Uuid::parse_str(s).ok()),
+                    custom_fields: parse_custom_fields(&r.custom_fields)?,
                     item_number: r.item_number as i32,
                     version: r.version as i32,
                     created_at: DateTime::from_timestamp(r.created_at, 0).ok_or_else(|| {
@@ -688,3 +960,19 @@ impl WorkItemRepository {
             .collect::<DbErrorResult<Vec<_>>>()
     }
 }
+
+/// Serialize custom field values for the `custom_fields` JSON column
+fn serialize_custom_fields(values: &CustomFieldValues) -> DbErrorResult<String> {
+    serde_json::to_string(values).map_err(|e| DbError::Initialization {
+        message: format!("Failed to serialize work_item.custom_fields: {}", e),
+        location: ErrorLocation::from(Location::caller()),
+    })
+}
+
+/// Parse the `custom_fields` JSON column into a value map
+fn parse_custom_fields(json: &str) -> DbErrorResult<CustomFieldValues> {
+    serde_json::from_str(json).map_err(|e| DbError::Initialization {
+        message: format!("Invalid JSON in work_item.custom_fields: {}", e),
+        location: ErrorLocation::from(Location::caller()),
+    })
+}
@@ -0,0 +1,86 @@
+//! SQLite authorizer for sandboxed queries
+//!
+//! SQLite consults the authorizer while preparing a statement, once per
+//! table column read, function called and so on. The sandbox only allows
+//! reads, and only reads of the main database that go through one of the
+//! project-scoped temp views (see [`super::project_scope`]). Writes,
+//! PRAGMAs, ATTACH, schema changes and transactions are all denied.
+
+use libsqlite3_sys::{
+    SQLITE_DENY, SQLITE_FUNCTION, SQLITE_OK, SQLITE_READ, SQLITE_RECURSIVE, SQLITE_SELECT,
+    sqlite3_set_authorizer,
+};
+use sqlx::sqlite::LockedSqliteHandle;
+
+use std::ffi::{CStr, c_char, c_int, c_void};
+use std::ptr;
+
+/// Functions that reach outside the database
+const DENIED_FUNCTIONS: &[&str] = &["load_extension"];
+
+/// Decide whether a sandboxed statement may perform `action`.
+///
+/// `name` is the function name for `SQLITE_FUNCTION`; `database` and
+/// `view` are the schema being read (none for CTEs) and the inner-most view
+/// responsible for the read, if any.
+pub fn authorize(
+    action: c_int,
+    name: Option<&str>,
+    database: Option<&str>,
+    view: Option<&str>,
+) -> c_int {
+    match action {
+        SQLITE_SELECT | SQLITE_RECURSIVE => SQLITE_OK,
+        SQLITE_FUNCTION => match name {
+            Some(name)
+                if DENIED_FUNCTIONS
+                    .iter()
+                    .any(|f| f.eq_ignore_ascii_case(name)) =>
+            {
+                SQLITE_DENY
+            }
+            _ => SQLITE_OK,
+        },
+        // CTEs and subqueries have no schema
+        SQLITE_READ => match (database, view) {
+            (None, _) | (Some("temp"), _) | (Some("main"), Some(_)) => SQLITE_OK,
+            _ => SQLITE_DENY,
+        },
+        _ => SQLITE_DENY,
+    }
+}
+
+unsafe extern "C" fn authorizer_callback(
+    _user_data: *mut c_void,
+    action: c_int,
+    _arg1: *const c_char,
+    arg2: *const c_char,
+    database: *const c_char,
+    view: *const c_char,
+) -> c_int {
+    // SAFETY: SQLite passes either NULL or a NUL-terminated string that
+    // stays valid for the duration of the callback.
+    let text = |value: *const c_char| {
+        if value.is_null() {
+            None
+        } else {
+            unsafe { CStr::from_ptr(value) }.to_str().ok()
+        }
+    };
+
+    authorize(action, text(arg2), text(database), text(view))
+}
+
+/// Install the sandbox authorizer on a connection. It stays in place until
+/// the connection is closed.
+pub fn install(handle: &mut LockedSqliteHandle<'_>) {
+    // SAFETY: the handle is locked for the duration of the call and the
+    // callback carries no user data.
+    unsafe {
+        sqlite3_set_authorizer(
+            handle.as_raw_handle().as_ptr(),
+            Some(authorizer_callback),
+            ptr::null_mut(),
+        );
+    }
+}
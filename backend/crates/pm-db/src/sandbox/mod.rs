@@ -0,0 +1,5 @@
+pub mod authorizer;
+pub mod project_scope;
+pub mod query_result;
+pub mod sql_sandbox;
+pub mod statement;
@@ -0,0 +1,87 @@
+//! Temp views that limit sandboxed queries to the caller's projects
+//!
+//! Each queryable table gets a temp view of the same name. SQLite searches
+//! the temp schema first, so `SELECT * FROM pm_work_items` reads the view
+//! and sees only rows from projects the caller is a member of. Tables not
+//! listed here (idempotency keys, migrations, ...) are not queryable: the
+//! authorizer rejects reads of the main schema that do not go through a
+//! view.
+
+use uuid::Uuid;
+
+/// Placeholder for the caller's project ID list in [`SCOPED_TABLES`]
+const PROJECTS: &str = "{projects}";
+/// Placeholder for the caller's user ID in [`SCOPED_TABLES`]
+const USER: &str = "{user}";
+
+/// Queryable tables and the filter applied to each. Views may refer to
+/// views earlier in the list.
+const SCOPED_TABLES: &[(&str, &str)] = &[
+    ("pm_projects", "id IN ({projects})"),
+    ("pm_project_members", "project_id IN ({projects})"),
+    ("pm_work_items", "project_id IN ({projects})"),
+    ("pm_sprints", "project_id IN ({projects})"),
+    ("pm_swim_lanes", "project_id IN ({projects})"),
+    ("pm_custom_field_definitions", "project_id IN ({projects})"),
+    (
+        "pm_comments",
+        "work_item_id IN (SELECT id FROM temp.pm_work_items)",
+    ),
+    (
+        "pm_time_entries",
+        "work_item_id IN (SELECT id FROM temp.pm_work_items)",
+    ),
+    (
+        "pm_dependencies",
+        "blocking_item_id IN (SELECT id FROM temp.pm_work_items) \
+         AND blocked_item_id IN (SELECT id FROM temp.pm_work_items)",
+    ),
+    (
+        "pm_llm_context",
+        "project_id IS NULL OR project_id IN ({projects})",
+    ),
+    (
+        "pm_activity_log",
+        "entity_id IN (\
+            SELECT id FROM temp.pm_projects \
+            UNION ALL SELECT id FROM temp.pm_work_items \
+            UNION ALL SELECT id FROM temp.pm_sprints \
+            UNION ALL SELECT id FROM temp.pm_comments \
+            UNION ALL SELECT id FROM temp.pm_time_entries \
+            UNION ALL SELECT id FROM temp.pm_dependencies \
+            UNION ALL SELECT id FROM temp.pm_custom_field_definitions)",
+    ),
+    (
+        "users",
+        "id = '{user}' OR id IN (SELECT user_id FROM temp.pm_project_members)",
+    ),
+];
+
+/// Names of the tables a sandboxed query can read
+pub fn queryable_tables() -> impl Iterator<Item = &'static str> {
+    SCOPED_TABLES.iter().map(|(table, _)| *table)
+}
+
+/// `CREATE TEMP VIEW` statements scoping every queryable table to
+/// `project_ids`. IDs are formatted from parsed UUIDs, so they are safe to
+/// inline.
+pub fn scoped_view_statements(user_id: Uuid, project_ids: &[Uuid]) -> Vec<String> {
+    let projects = project_ids
+        .iter()
+        .map(|id| format!("'{}'", id))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let user = user_id.to_string();
+
+    SCOPED_TABLES
+        .iter()
+        .map(|(table, filter)| {
+            let filter = filter.replace(PROJECTS, &projects).replace(USER, &user);
+            format!(
+                "CREATE TEMP VIEW {table} AS SELECT * FROM main.{table} WHERE {filter}",
+                table = table,
+                filter = filter
+            )
+        })
+        .collect()
+}
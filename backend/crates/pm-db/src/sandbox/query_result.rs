@@ -0,0 +1,12 @@
+use serde_json::Value;
+
+/// Rows returned by a sandboxed read-only query
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryResult {
+    /// Column names in select-list order
+    pub columns: Vec<String>,
+    /// One JSON value per column; blobs are hex-encoded strings
+    pub rows: Vec<Vec<Value>>,
+    /// True when the query matched more rows than the row limit allowed
+    pub truncated: bool,
+}
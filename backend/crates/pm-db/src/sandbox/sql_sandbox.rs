@@ -0,0 +1,153 @@
+//! Read-only SQL for LLM agents
+//!
+//! Each query runs on its own read-only connection with `query_only` set,
+//! the sandbox authorizer installed and a progress handler enforcing the
+//! time limit. Project-scoped temp views restrict what the caller can see.
+
+use crate::{
+    DbError, QueryResult, Result as DbErrorResult,
+    sandbox::{authorizer, project_scope::scoped_view_statements, statement::single_select},
+};
+
+use std::panic::Location;
+use std::time::{Duration, Instant};
+
+use error_location::ErrorLocation;
+use futures::TryStreamExt;
+use serde_json::Value;
+use sqlx::sqlite::{SqliteConnection, SqliteRow};
+use sqlx::{Column, ConnectOptions, Connection, Executor, Row, SqlitePool, Statement, ValueRef};
+use uuid::Uuid;
+
+/// SQLite virtual machine instructions between time-limit checks
+const PROGRESS_HANDLER_OPS: i32 = 1000;
+
+pub struct SqlSandbox;
+
+impl SqlSandbox {
+    /// Run a single SELECT as `user_id`, returning at most `max_rows` rows.
+    ///
+    /// Statements that are not a single SELECT, or that try to write, run
+    /// PRAGMAs, ATTACH databases or read tables outside the caller's
+    /// projects fail with [`DbError::QueryRejected`]. Queries still running
+    /// after `timeout` are interrupted with [`DbError::QueryTimeout`].
+    pub async fn query(
+        pool: &SqlitePool,
+        user_id: Uuid,
+        sql: &str,
+        max_rows: usize,
+        timeout: Duration,
+    ) -> DbErrorResult<QueryResult> {
+        let statement = single_select(sql)?;
+
+        let user_id_str = user_id.to_string();
+        let project_ids: Vec<Uuid> = sqlx::query_scalar!(
+            "SELECT project_id FROM pm_project_members WHERE user_id = ?",
+            user_id_str
+        )
+        .fetch_all(pool)
+        .await?
+        .iter()
+        .filter_map(|id| Uuid::parse_str(id).ok())
+        .collect();
+
+        let mut conn = (*pool.connect_options())
+            .clone()
+            .read_only(true)
+            .connect()
+            .await?;
+
+        for view in scoped_view_statements(user_id, &project_ids) {
+            conn.execute(view.as_str()).await?;
+        }
+        conn.execute("PRAGMA query_only = ON").await?;
+
+        let deadline = Instant::now() + timeout;
+        {
+            let mut handle = conn.lock_handle().await?;
+            authorizer::install(&mut handle);
+            handle.set_progress_handler(PROGRESS_HANDLER_OPS, move || Instant::now() < deadline);
+        }
+
+        let result = Self::fetch(&mut conn, statement, max_rows)
+            .await
+            .map_err(|e| Self::query_error(e, deadline, timeout));
+
+        // The connection is discarded either way; a failed close only
+        // means SQLite releases it when the handle drops
+        let _ = conn.close().await;
+
+        result
+    }
+
+    async fn fetch(
+        conn: &mut SqliteConnection,
+        statement: &str,
+        max_rows: usize,
+    ) -> Result<QueryResult, sqlx::Error> {
+        let prepared = conn.prepare(statement).await?;
+        let columns = prepared
+            .columns()
+            .iter()
+            .map(|c| c.name().to_string())
+            .collect();
+
+        let mut rows = Vec::new();
+        let mut truncated = false;
+        let mut stream = prepared.query().fetch(&mut *conn);
+        while let Some(row) = stream.try_next().await? {
+            if rows.len() == max_rows {
+                truncated = true;
+                break;
+            }
+            rows.push(row_values(&row));
+        }
+
+        Ok(QueryResult {
+            columns,
+            rows,
+            truncated,
+        })
+    }
+
+    /// SQLite reports its own errors (syntax, unknown tables, authorizer
+    /// denials, interrupts) as database errors; those are the caller's to fix.
+    #[track_caller]
+    fn query_error(error: sqlx::Error, deadline: Instant, timeout: Duration) -> DbError {
+        match error {
+            sqlx::Error::Database(_) if Instant::now() >= deadline => DbError::QueryTimeout {
+                timeout_ms: timeout.as_millis() as u64,
+                location: ErrorLocation::from(Location::caller()),
+            },
+            sqlx::Error::Database(e) => DbError::QueryRejected {
+                message: e.message().to_string(),
+                location: ErrorLocation::from(Location::caller()),
+            },
+            other => DbError::from(other),
+        }
+    }
+}
+
+/// Convert a row to JSON using each value's storage class
+fn row_values(row: &SqliteRow) -> Vec<Value> {
+    (0..row.len()).map(|i| column_value(row, i)).collect()
+}
+
+fn column_value(row: &SqliteRow, index: usize) -> Value {
+    if row.try_get_raw(index).map_or(true, |v| v.is_null()) {
+        return Value::Null;
+    }
+    if let Ok(v) = row.try_get::<i64, _>(index) {
+        return Value::from(v);
+    }
+    if let Ok(v) = row.try_get::<f64, _>(index) {
+        return serde_json::Number::from_f64(v).map_or(Value::Null, Value::Number);
+    }
+    if let Ok(v) = row.try_get::<String, _>(index) {
+        return Value::String(v);
+    }
+    match row.try_get::<Vec<u8>, _>(index) {
+        Ok(bytes) => Value::String(bytes.iter().map(|b| format!("{:02x}", b)).collect()),
+        Err(_) => Value::Null,
+    }
+}
@@ -0,0 +1,106 @@
+//! Lexical checks applied to sandboxed SQL before it reaches SQLite
+
+use crate::{DbError, Result as DbErrorResult};
+
+use std::panic::Location;
+
+use error_location::ErrorLocation;
+
+/// Where the scanner is within the SQL text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scan {
+    Code,
+    /// Inside a quoted string or identifier closed by the given byte
+    Quoted(u8),
+    LineComment,
+    BlockComment,
+}
+
+#[track_caller]
+fn rejected(message: &str) -> DbError {
+    DbError::QueryRejected {
+        message: message.to_string(),
+        location: ErrorLocation::from(Location::caller()),
+    }
+}
+
+/// Return the single SELECT statement in `sql`, without leading comments
+/// or trailing semicolons.
+///
+/// Semicolons inside string literals, quoted identifiers and comments are
+/// ignored. Anything after the first statement other than whitespace,
+/// comments or further semicolons is rejected, as is any statement that
+/// does not start with `SELECT` or `WITH`. The authorizer still checks
+/// what the statement actually does; this only gives callers a clear error
+/// for the common mistakes.
+#[track_caller]
+pub fn single_select(sql: &str) -> DbErrorResult<&str> {
+    let bytes = sql.as_bytes();
+    let mut state = Scan::Code;
+    let mut start = None;
+    let mut end = None;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let byte = bytes[i];
+        let next = bytes.get(i + 1).copied();
+
+        match state {
+            Scan::Code => match byte {
+                b'-' if next == Some(b'-') => {
+                    state = Scan::LineComment;
+                    i += 1;
+                }
+                b'/' if next == Some(b'*') => {
+                    state = Scan::BlockComment;
+                    i += 1;
+                }
+                b';' => {
+                    end.get_or_insert(i);
+                }
+                _ if byte.is_ascii_whitespace() => {}
+                _ => {
+                    if end.is_some() {
+                        return Err(rejected("Only a single statement is allowed"));
+                    }
+                    start.get_or_insert(i);
+                    match byte {
+                        b'\'' | b'"' | b'`' => state = Scan::Quoted(byte),
+                        b'[' => state = Scan::Quoted(b']'),
+                        _ => {}
+                    }
+                }
+            },
+            Scan::Quoted(close) => {
+                if byte == close {
+                    state = Scan::Code;
+                }
+            }
+            Scan::LineComment => {
+                if byte == b'\n' {
+                    state = Scan::Code;
+                }
+            }
+            Scan::BlockComment => {
+                if byte == b'*' && next == Some(b'/') {
+                    state = Scan::Code;
+                    i += 1;
+                }
+            }
+        }
+        i += 1;
+    }
+
+    let start = start.ok_or_else(|| rejected("Query is empty"))?;
+    let statement = sql[start..end.unwrap_or(sql.len())].trim_end();
+
+    let keyword = statement
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .next()
+        .unwrap_or_default();
+    if !keyword.eq_ignore_ascii_case("select") && !keyword.eq_ignore_ascii_case("with") {
+        return Err(rejected("Only SELECT queries are allowed"));
+    }
+
+    Ok(statement)
+}
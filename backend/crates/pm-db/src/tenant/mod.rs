@@ -0,0 +1,2 @@
+pub mod tenant_pool_options;
+pub mod tenant_pools;
@@ -0,0 +1,20 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// How [`crate::TenantPools`] finds, opens and retires tenant databases
+#[derive(Debug, Clone)]
+pub struct TenantPoolOptions {
+    /// Tenant root; each tenant's database is `<root>/<tenant_id>/main.db`
+    pub root: PathBuf,
+    /// Create databases for unknown tenants instead of rejecting them
+    pub create_missing: bool,
+    /// Pools kept open; the least recently used is dropped beyond this
+    pub max_open_pools: usize,
+    /// Pools unused for this long are dropped by `evict_idle` (None = never)
+    pub idle_timeout: Option<Duration>,
+    /// SQLite connections per tenant pool
+    pub max_connections: u32,
+    /// `(id, name)` of a user inserted into every tenant database after
+    /// migrating, so requests that fall back to it satisfy foreign keys
+    pub system_user: Option<(String, String)>,
+}
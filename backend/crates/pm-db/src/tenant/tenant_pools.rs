@@ -0,0 +1,187 @@
+//! Per-tenant SQLite pools (ADR-0002)
+//!
+//! Each tenant has its own database file, opened on first use, migrated to
+//! the current schema and cached. The cache is bounded: past
+//! `max_open_pools` the least recently used pool is dropped, and
+//! [`TenantPools::evict_idle`] drops pools unused for `idle_timeout`.
+//! Dropping a pool only removes it from the cache; requests and WebSocket
+//! connections still holding a clone keep using it until they finish.
+
+use crate::{DbError, Result as DbErrorResult, TenantPoolOptions};
+
+use std::collections::HashMap;
+use std::panic::Location;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use error_location::ErrorLocation;
+use sqlx::SqlitePool;
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, SqliteSynchronous};
+use tokio::sync::Mutex;
+
+const TENANT_DATABASE_FILENAME: &str = "main.db";
+const MAX_TENANT_ID_LENGTH: usize = 64;
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+struct OpenPool {
+    pool: SqlitePool,
+    last_used: Instant,
+}
+
+/// Lazily opened, migrated pools keyed by tenant ID
+#[derive(Clone)]
+pub struct TenantPools {
+    options: Arc<TenantPoolOptions>,
+    open: Arc<Mutex<HashMap<String, OpenPool>>>,
+}
+
+impl TenantPools {
+    pub fn new(options: TenantPoolOptions) -> Self {
+        Self {
+            options: Arc::new(options),
+            open: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// The pool for `tenant_id`, opening and migrating its database on
+    /// first use.
+    ///
+    /// Fails with [`DbError::TenantNotFound`] when the ID isn't a valid
+    /// tenant ID or, unless `create_missing` is set, has no database.
+    pub async fn pool(&self, tenant_id: &str) -> DbErrorResult<SqlitePool> {
+        if !Self::is_valid_tenant_id(tenant_id) {
+            return Err(Self::not_found(tenant_id));
+        }
+
+        // Held across the open so two requests can't race to open the
+        // same tenant
+        let mut open = self.open.lock().await;
+        if let Some(entry) = open.get_mut(tenant_id) {
+            entry.last_used = Instant::now();
+            return Ok(entry.pool.clone());
+        }
+
+        let pool = self.open_pool(tenant_id).await?;
+
+        while open.len() >= self.options.max_open_pools {
+            let Some(oldest) = open
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(id, _)| id.clone())
+            else {
+                break;
+            };
+            open.remove(&oldest);
+        }
+
+        open.insert(
+            tenant_id.to_string(),
+            OpenPool {
+                pool: pool.clone(),
+                last_used: Instant::now(),
+            },
+        );
+
+        Ok(pool)
+    }
+
+    /// Drop pools unused for longer than the idle timeout, returning their
+    /// tenant IDs.
+    pub async fn evict_idle(&self) -> Vec<String> {
+        let Some(idle_timeout) = self.options.idle_timeout else {
+            return Vec::new();
+        };
+
+        let mut open = self.open.lock().await;
+        let idle: Vec<String> = open
+            .iter()
+            .filter(|(_, entry)| entry.last_used.elapsed() >= idle_timeout)
+            .map(|(id, _)| id.clone())
+            .collect();
+        for tenant_id in &idle {
+            open.remove(tenant_id);
+        }
+
+        idle
+    }
+
+    /// Tenant IDs with an open pool
+    pub async fn open_tenants(&self) -> Vec<String> {
+        let mut tenants: Vec<String> = self.open.lock().await.keys().cloned().collect();
+        tenants.sort();
+        tenants
+    }
+
+    /// Where `tenant_id`'s database lives
+    pub fn database_path(&self, tenant_id: &str) -> PathBuf {
+        self.options
+            .root
+            .join(tenant_id)
+            .join(TENANT_DATABASE_FILENAME)
+    }
+
+    /// Tenant IDs are used as directory names, so only ASCII letters,
+    /// digits, `-` and `_` are accepted
+    pub fn is_valid_tenant_id(tenant_id: &str) -> bool {
+        !tenant_id.is_empty()
+            && tenant_id.len() <= MAX_TENANT_ID_LENGTH
+            && tenant_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    }
+
+    async fn open_pool(&self, tenant_id: &str) -> DbErrorResult<SqlitePool> {
+        let path = self.database_path(tenant_id);
+        if !path.exists() {
+            if !self.options.create_missing {
+                return Err(Self::not_found(tenant_id));
+            }
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir).map_err(|e| DbError::Initialization {
+                    message: format!("create {}: {}", dir.display(), e),
+                    location: ErrorLocation::from(Location::caller()),
+                })?;
+            }
+        }
+
+        let pool = SqlitePoolOptions::new()
+            .max_connections(self.options.max_connections)
+            .connect_with(
+                SqliteConnectOptions::new()
+                    .filename(&path)
+                    .create_if_missing(self.options.create_missing)
+                    .journal_mode(SqliteJournalMode::Wal)
+                    .synchronous(SqliteSynchronous::Normal)
+                    .busy_timeout(BUSY_TIMEOUT),
+            )
+            .await?;
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .map_err(|e| DbError::Migration {
+                message: format!("tenant {}: {}", tenant_id, e),
+                location: ErrorLocation::from(Location::caller()),
+            })?;
+
+        if let Some((user_id, user_name)) = &self.options.system_user {
+            sqlx::query("INSERT OR IGNORE INTO users (id, email, name) VALUES (?, ?, ?)")
+                .bind(user_id)
+                .bind(format!("{}@system.local", user_id))
+                .bind(user_name)
+                .execute(&pool)
+                .await?;
+        }
+
+        Ok(pool)
+    }
+
+    #[track_caller]
+    fn not_found(tenant_id: &str) -> DbError {
+        DbError::TenantNotFound {
+            tenant_id: tenant_id.to_string(),
+            location: ErrorLocation::from(Location::caller()),
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{BackupStore, DbError};
+
+use std::path::Path;
+
+use sqlx::sqlite::SqliteConnectOptions;
+use sqlx::{SqlitePool, migrate};
+use tempfile::TempDir;
+
+struct Fixture {
+    _temp: TempDir,
+    pool: SqlitePool,
+    store: BackupStore,
+}
+
+async fn setup(retention_count: usize) -> Fixture {
+    let temp = TempDir::new().expect("Failed to create temp dir");
+    let database_path = temp.path().join("data.db");
+    let pool = open(&database_path).await;
+    migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("Failed to run migrations");
+
+    let store = BackupStore::new(temp.path().join("backups"), database_path, retention_count);
+    Fixture {
+        _temp: temp,
+        pool,
+        store,
+    }
+}
+
+async fn open(path: &Path) -> SqlitePool {
+    SqlitePool::connect_with(
+        SqliteConnectOptions::new()
+            .filename(path)
+            .create_if_missing(true),
+    )
+    .await
+    .expect("Failed to open database")
+}
+
+async fn user_count(pool: &SqlitePool) -> i64 {
+    sqlx::query_scalar("SELECT COUNT(*) FROM users")
+        .fetch_one(pool)
+        .await
+        .expect("Failed to count users")
+}
+
+async fn add_user(pool: &SqlitePool, id: &str) {
+    sqlx::query("INSERT INTO users (id, email, created_at) VALUES (?, ?, 0)")
+        .bind(id)
+        .bind(format!("{}@test.local", id))
+        .execute(pool)
+        .await
+        .expect("Failed to create user");
+}
+
+#[tokio::test]
+async fn when_creating_backup_then_copy_is_verified_and_listed() {
+    let fixture = setup(3).await;
+    add_user(&fixture.pool, "alice").await;
+
+    let backup = fixture.store.create(&fixture.pool).await.unwrap();
+
+    assert!(backup.name.starts_with("pm-") && backup.name.ends_with(".db"));
+    assert!(backup.size_bytes > 0);
+    BackupStore::verify(&backup.path).await.unwrap();
+    assert_eq!(fixture.store.list().unwrap(), vec![backup.clone()]);
+
+    let copy = open(&backup.path).await;
+    assert_eq!(user_count(&copy).await, 1);
+}
+
+#[tokio::test]
+async fn when_more_backups_than_retention_then_oldest_are_pruned() {
+    let fixture = setup(2).await;
+
+    let mut names = Vec::new();
+    for _ in 0..4 {
+        names.push(fixture.store.create(&fixture.pool).await.unwrap().name);
+        // Names carry millisecond timestamps
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    }
+
+    let kept: Vec<String> = fixture
+        .store
+        .list()
+        .unwrap()
+        .into_iter()
+        .map(|b| b.name)
+        .collect();
+    assert_eq!(kept, vec![names[3].clone(), names[2].clone()]);
+}
+
+#[tokio::test]
+async fn when_file_is_not_a_database_then_verify_fails() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("pm-20260101T000000.000Z.db");
+    std::fs::write(
+        &path,
+        b"definitely not sqlite, just some bytes to fill a page",
+    )
+    .unwrap();
+
+    let result = BackupStore::verify(&path).await;
+
+    assert!(matches!(result, Err(DbError::Backup { .. })));
+}
+
+#[tokio::test]
+async fn when_database_is_in_memory_then_create_fails() {
+    let temp = TempDir::new().unwrap();
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    let store = BackupStore::new(temp.path().join("backups"), temp.path().join("data.db"), 3);
+
+    let result = store.create(&pool).await;
+
+    assert!(
+        matches!(result, Err(DbError::Backup { ref message, .. }) if message.contains("in memory"))
+    );
+    assert_eq!(store.list().unwrap().len(), 0);
+}
+
+#[tokio::test]
+async fn when_restoring_unknown_name_then_rejected() {
+    let fixture = setup(3).await;
+    fixture.store.create(&fixture.pool).await.unwrap();
+
+    for name in ["pm-missing.db", "../data.db"] {
+        let result = fixture.store.stage_restore(name).await;
+        assert!(matches!(result, Err(DbError::Backup { .. })));
+    }
+    assert!(!BackupStore::staged_restore_path(&fixture.store.database_path).exists());
+}
+
+#[tokio::test]
+async fn when_staged_restore_applied_then_database_is_replaced() {
+    let fixture = setup(3).await;
+    add_user(&fixture.pool, "alice").await;
+    let backup = fixture.store.create(&fixture.pool).await.unwrap();
+    add_user(&fixture.pool, "bob").await;
+
+    fixture.store.stage_restore(&backup.name).await.unwrap();
+    fixture.pool.close().await;
+    let applied = BackupStore::apply_staged_restore(&fixture.store.database_path).unwrap();
+
+    assert!(applied);
+    let restored = open(&fixture.store.database_path).await;
+    assert_eq!(user_count(&restored).await, 1);
+
+    let previous = fixture.store.database_path.with_extension("db.pre-restore");
+    let replaced = open(&previous).await;
+    assert_eq!(user_count(&replaced).await, 2);
+}
+
+#[test]
+fn when_nothing_staged_then_apply_is_a_no_op() {
+    let temp = TempDir::new().unwrap();
+
+    let applied = BackupStore::apply_staged_restore(&temp.path().join("data.db")).unwrap();
+
+    assert!(!applied);
+}
@@ -0,0 +1 @@
+mod backup_store_tests;
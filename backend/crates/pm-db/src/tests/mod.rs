@@ -1 +1,4 @@
+mod backup;
 mod repositories;
+mod sandbox;
+mod tenant;
@@ -1,6 +1,9 @@
 use crate::LlmContextRepository;
 
+use pm_core::{ExampleSqlIssue, LlmContext, LlmContextType};
+
 use sqlx::{SqlitePool, migrate};
+use uuid::Uuid;
 
 async fn setup_db() -> SqlitePool {
     let pool = SqlitePool::connect(":memory:")
@@ -15,6 +18,37 @@ async fn setup_db() -> SqlitePool {
     pool
 }
 
+async fn create_project(pool: &SqlitePool, key: &str) -> Uuid {
+    let project_id = Uuid::new_v4();
+    let user_id = Uuid::new_v4().to_string();
+    sqlx::query(
+        r#"
+        INSERT INTO pm_projects (id, key, title, status, created_at, updated_at, created_by, updated_by, version)
+        VALUES (?, ?, ?, 'active', 0, 0, ?, ?, 1)
+        "#,
+    )
+    .bind(project_id.to_string())
+    .bind(key)
+    .bind(key)
+    .bind(&user_id)
+    .bind(&user_id)
+    .execute(pool)
+    .await
+    .expect("Failed to create project");
+    project_id
+}
+
+fn project_rule(project_id: Uuid, title: &str) -> LlmContext {
+    let mut entry = LlmContext::new(
+        LlmContextType::BusinessRule,
+        "conventions".to_string(),
+        title.to_string(),
+        "Follow the repository conventions.".to_string(),
+    );
+    entry.project_id = Some(project_id);
+    entry
+}
+
 #[tokio::test]
 async fn when_list_all_then_returns_seeded_context() {
     let pool = setup_db().await;
@@ -28,9 +62,127 @@ async fn when_list_all_then_returns_seeded_context() {
 async fn when_filtered_by_category_then_only_matches_returned() {
     let pool = setup_db().await;
 
-    let entries = LlmContextRepository::list_filtered(&pool, Some("work_items"), None, None)
+    let entries = LlmContextRepository::list_filtered(&pool, None, Some("work_items"), None, None)
         .await
         .unwrap();
 
     assert!(entries.iter().all(|e| e.category == "work_items"));
 }
+
+#[tokio::test]
+async fn when_filtered_by_project_then_includes_global_and_own_entries_only() {
+    let pool = setup_db().await;
+    let alpha = create_project(&pool, "ALPHA").await;
+    let beta = create_project(&pool, "BETA").await;
+    LlmContextRepository::create(&pool, &project_rule(alpha, "Alpha rule"))
+        .await
+        .unwrap();
+    LlmContextRepository::create(&pool, &project_rule(beta, "Beta rule"))
+        .await
+        .unwrap();
+
+    let global = LlmContextRepository::list_filtered(&pool, None, None, None, None)
+        .await
+        .unwrap();
+    let for_alpha = LlmContextRepository::list_filtered(&pool, Some(alpha), None, None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(global.len(), 28);
+    assert!(global.iter().all(|e| e.project_id.is_none()));
+    assert_eq!(for_alpha.len(), 29);
+    assert!(for_alpha.iter().any(|e| e.title == "Alpha rule"));
+    assert!(!for_alpha.iter().any(|e| e.title == "Beta rule"));
+}
+
+#[tokio::test]
+async fn when_updated_then_find_returns_new_values() {
+    let pool = setup_db().await;
+    let project_id = create_project(&pool, "ALPHA").await;
+    let mut entry = project_rule(project_id, "Alpha rule");
+    LlmContextRepository::create(&pool, &entry).await.unwrap();
+
+    entry.context_type = LlmContextType::Instruction;
+    entry.content = "Run the linter before committing.".to_string();
+    entry.priority = 80;
+    LlmContextRepository::update(&pool, &entry).await.unwrap();
+
+    let found = LlmContextRepository::find_by_id(&pool, entry.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.context_type, LlmContextType::Instruction);
+    assert_eq!(found.content, "Run the linter before committing.");
+    assert_eq!(found.priority, 80);
+    assert_eq!(found.project_id, Some(project_id));
+}
+
+#[tokio::test]
+async fn when_soft_deleted_then_not_found() {
+    let pool = setup_db().await;
+    let project_id = create_project(&pool, "ALPHA").await;
+    let entry = project_rule(project_id, "Alpha rule");
+    LlmContextRepository::create(&pool, &entry).await.unwrap();
+
+    LlmContextRepository::soft_delete(&pool, entry.id, chrono::Utc::now().timestamp())
+        .await
+        .unwrap();
+
+    assert!(
+        LlmContextRepository::find_by_id(&pool, entry.id)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn when_seeded_examples_verified_then_all_prepare() {
+    let pool = setup_db().await;
+
+    let report = LlmContextRepository::verify_example_sql(&pool)
+        .await
+        .unwrap();
+
+    assert_eq!(report.checked, 8);
+    assert!(report.is_ok(), "{:?}", report.problems);
+}
+
+#[tokio::test]
+async fn when_example_references_dropped_column_then_reported() {
+    let pool = setup_db().await;
+    let mut entry = LlmContext::new(
+        LlmContextType::QueryPattern,
+        "work_items".to_string(),
+        "Stale query".to_string(),
+        "Uses a column that was renamed.".to_string(),
+    );
+    entry.example_sql = Some("SELECT display_key FROM pm_work_items".to_string());
+    LlmContextRepository::create(&pool, &entry).await.unwrap();
+    let mut dropped = entry.clone();
+    dropped.id = Uuid::new_v4();
+    dropped.title = "Gone table".to_string();
+    dropped.example_sql = Some("SELECT * FROM pm_tasks".to_string());
+    LlmContextRepository::create(&pool, &dropped).await.unwrap();
+
+    let report = LlmContextRepository::verify_example_sql(&pool)
+        .await
+        .unwrap();
+
+    assert_eq!(report.checked, 10);
+    assert_eq!(report.problems.len(), 2);
+    let stale = report
+        .problems
+        .iter()
+        .find(|p| p.title == "Stale query")
+        .unwrap();
+    assert_eq!(stale.issue, ExampleSqlIssue::MissingColumn);
+    assert_eq!(stale.missing.as_deref(), Some("display_key"));
+    let gone = report
+        .problems
+        .iter()
+        .find(|p| p.title == "Gone table")
+        .unwrap();
+    assert_eq!(gone.issue, ExampleSqlIssue::MissingTable);
+    assert_eq!(gone.entry_id, dropped.id.to_string());
+}
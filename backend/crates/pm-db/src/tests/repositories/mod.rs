@@ -1,2 +1,4 @@
 mod activity_log_repository_tests;
 mod llm_context_repository_tests;
+mod project_template_repository_tests;
+mod sync_peer_repository_tests;
@@ -0,0 +1,99 @@
+use crate::ProjectTemplateRepository;
+
+use pm_core::{ProjectTemplate, TemplateStructure, TemplateWorkItem, WorkItemType};
+
+use sqlx::{SqlitePool, migrate};
+use uuid::Uuid;
+
+async fn setup_db() -> SqlitePool {
+    let pool = SqlitePool::connect(":memory:")
+        .await
+        .expect("Failed to create test database");
+
+    migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("Failed to run migrations");
+
+    pool
+}
+
+fn template(name: &str) -> ProjectTemplate {
+    let structure = TemplateStructure {
+        work_items: vec![TemplateWorkItem {
+            number: 1,
+            parent: None,
+            item_type: WorkItemType::Epic,
+            title: "Discovery".to_string(),
+            description: None,
+            priority: "high".to_string(),
+            story_points: None,
+            original_estimate_seconds: Some(3600),
+            position: 0,
+            custom_fields: Default::default(),
+        }],
+        ..Default::default()
+    };
+    ProjectTemplate::new(
+        name.to_string(),
+        Some("Starter epics".to_string()),
+        structure,
+        Uuid::new_v4(),
+    )
+}
+
+#[tokio::test]
+async fn given_saved_template_when_found_by_id_or_name_then_structure_round_trips() {
+    let repo = ProjectTemplateRepository::new(setup_db().await);
+    let saved = template("client-engagement");
+
+    repo.create(&saved).await.unwrap();
+
+    let by_id = repo.find_by_id(saved.id).await.unwrap().unwrap();
+    let by_name = repo
+        .find_by_name("client-engagement")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(by_id.structure, saved.structure);
+    assert_eq!(by_name.id, saved.id);
+    assert_eq!(by_name.description.as_deref(), Some("Starter epics"));
+}
+
+#[tokio::test]
+async fn given_taken_name_when_create_then_fails() {
+    let repo = ProjectTemplateRepository::new(setup_db().await);
+    repo.create(&template("starter")).await.unwrap();
+
+    let result = repo.create(&template("starter")).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn given_templates_when_find_all_then_ordered_by_name() {
+    let repo = ProjectTemplateRepository::new(setup_db().await);
+    repo.create(&template("zeta")).await.unwrap();
+    repo.create(&template("alpha")).await.unwrap();
+
+    let names: Vec<String> = repo
+        .find_all()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|t| t.name)
+        .collect();
+
+    assert_eq!(names, vec!["alpha", "zeta"]);
+}
+
+#[tokio::test]
+async fn given_template_when_deleted_then_gone() {
+    let repo = ProjectTemplateRepository::new(setup_db().await);
+    let saved = template("starter");
+    repo.create(&saved).await.unwrap();
+
+    assert!(repo.delete(saved.id).await.unwrap());
+    assert!(repo.find_by_id(saved.id).await.unwrap().is_none());
+    assert!(!repo.delete(saved.id).await.unwrap());
+}
@@ -0,0 +1,61 @@
+use crate::SyncPeerRepository;
+
+use pm_core::SyncPeer;
+
+use chrono::{DateTime, Utc};
+use sqlx::{SqlitePool, migrate};
+
+async fn setup_db() -> SqlitePool {
+    let pool = SqlitePool::connect(":memory:")
+        .await
+        .expect("Failed to create test database");
+
+    migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("Failed to run migrations");
+
+    pool
+}
+
+fn at(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap()
+}
+
+#[tokio::test]
+async fn given_unknown_url_when_find_then_returns_none() {
+    let repo = SyncPeerRepository::new(setup_db().await);
+
+    let peer = repo.find_by_url("http://peer.test").await.unwrap();
+
+    assert!(peer.is_none());
+}
+
+#[tokio::test]
+async fn given_new_peer_when_upsert_then_watermarks_round_trip() {
+    let repo = SyncPeerRepository::new(setup_db().await);
+    let mut peer = SyncPeer::new("http://peer.test");
+    peer.pulled_until = Some(at(1_700_000_000));
+    peer.synced_at = Some(at(1_700_000_100));
+    peer.updated_at = at(1_700_000_100);
+
+    repo.upsert(&peer).await.unwrap();
+
+    let found = repo.find_by_url("http://peer.test").await.unwrap();
+    assert_eq!(found, Some(peer));
+}
+
+#[tokio::test]
+async fn given_existing_peer_when_upsert_then_watermarks_are_replaced() {
+    let repo = SyncPeerRepository::new(setup_db().await);
+    let mut peer = SyncPeer::new("http://peer.test");
+    peer.updated_at = at(1_700_000_000);
+    repo.upsert(&peer).await.unwrap();
+
+    peer.pushed_until = Some(at(1_700_000_500));
+    peer.updated_at = at(1_700_000_500);
+    repo.upsert(&peer).await.unwrap();
+
+    let all = repo.find_all().await.unwrap();
+    assert_eq!(all, vec![peer]);
+}
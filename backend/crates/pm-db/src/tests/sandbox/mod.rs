@@ -0,0 +1,2 @@
+mod sql_sandbox_tests;
+mod statement_tests;
@@ -0,0 +1,248 @@
+use crate::{DbError, SqlSandbox};
+
+use std::time::Duration;
+
+use serde_json::json;
+use sqlx::{SqlitePool, migrate};
+use uuid::Uuid;
+
+const MAX_ROWS: usize = 100;
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+struct Fixture {
+    pool: SqlitePool,
+    user_id: Uuid,
+    member_project: Uuid,
+    other_project: Uuid,
+}
+
+async fn setup() -> Fixture {
+    let pool = SqlitePool::connect(":memory:")
+        .await
+        .expect("Failed to create test database");
+    migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("Failed to run migrations");
+
+    let user_id = Uuid::new_v4();
+    sqlx::query("INSERT INTO users (id, email, name, created_at) VALUES (?, 'a@b.c', 'Agent', 0)")
+        .bind(user_id.to_string())
+        .execute(&pool)
+        .await
+        .expect("Failed to create user");
+
+    let member_project = create_project(&pool, user_id, "MINE").await;
+    let other_project = create_project(&pool, user_id, "OTHER").await;
+    sqlx::query(
+        "INSERT INTO pm_project_members (id, project_id, user_id, role, created_at) VALUES (?, ?, ?, 'viewer', 0)",
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(member_project.to_string())
+    .bind(user_id.to_string())
+    .execute(&pool)
+    .await
+    .expect("Failed to add member");
+
+    for (project_id, title) in [
+        (member_project, "Visible one"),
+        (member_project, "Visible two"),
+        (other_project, "Hidden"),
+    ] {
+        create_work_item(&pool, user_id, project_id, title).await;
+    }
+
+    Fixture {
+        pool,
+        user_id,
+        member_project,
+        other_project,
+    }
+}
+
+async fn create_project(pool: &SqlitePool, user_id: Uuid, key: &str) -> Uuid {
+    let project_id = Uuid::new_v4();
+    sqlx::query(
+        r#"
+        INSERT INTO pm_projects (id, key, title, status, created_at, updated_at, created_by, updated_by, version)
+        VALUES (?, ?, ?, 'active', 0, 0, ?, ?, 1)
+        "#,
+    )
+    .bind(project_id.to_string())
+    .bind(key)
+    .bind(key)
+    .bind(user_id.to_string())
+    .bind(user_id.to_string())
+    .execute(pool)
+    .await
+    .expect("Failed to create project");
+    project_id
+}
+
+async fn create_work_item(pool: &SqlitePool, user_id: Uuid, project_id: Uuid, title: &str) {
+    sqlx::query(
+        r#"
+        INSERT INTO pm_work_items (id, item_type, project_id, title, item_number, created_at, updated_at, created_by, updated_by)
+        VALUES (?, 'task', ?, ?, (SELECT COUNT(*) + 1 FROM pm_work_items WHERE project_id = ?), 0, 0, ?, ?)
+        "#,
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(project_id.to_string())
+    .bind(title)
+    .bind(project_id.to_string())
+    .bind(user_id.to_string())
+    .bind(user_id.to_string())
+    .execute(pool)
+    .await
+    .expect("Failed to create work item");
+}
+
+async fn query(fixture: &Fixture, sql: &str) -> crate::Result<crate::QueryResult> {
+    SqlSandbox::query(&fixture.pool, fixture.user_id, sql, MAX_ROWS, TIMEOUT).await
+}
+
+#[tokio::test]
+async fn when_querying_then_only_member_projects_are_visible() {
+    let fixture = setup().await;
+
+    let result = query(
+        &fixture,
+        "SELECT title, item_number FROM pm_work_items ORDER BY title",
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.columns, vec!["title", "item_number"]);
+    assert_eq!(
+        result.rows,
+        vec![
+            vec![json!("Visible one"), json!(1)],
+            vec![json!("Visible two"), json!(2)],
+        ]
+    );
+    assert!(!result.truncated);
+
+    let projects = query(&fixture, "SELECT id FROM pm_projects").await.unwrap();
+    assert_eq!(
+        projects.rows,
+        vec![vec![json!(fixture.member_project.to_string())]]
+    );
+
+    let hidden = query(
+        &fixture,
+        &format!(
+            "SELECT COUNT(*) FROM pm_work_items WHERE project_id = '{}'",
+            fixture.other_project
+        ),
+    )
+    .await
+    .unwrap();
+    assert_eq!(hidden.rows, vec![vec![json!(0)]]);
+}
+
+#[tokio::test]
+async fn when_dependency_crosses_projects_then_hidden() {
+    let fixture = setup().await;
+    let item_id = |title: &'static str| {
+        sqlx::query_scalar::<_, String>("SELECT id FROM pm_work_items WHERE title = ?")
+            .bind(title)
+            .fetch_one(&fixture.pool)
+    };
+    let visible_one = item_id("Visible one").await.unwrap();
+    let visible_two = item_id("Visible two").await.unwrap();
+    let hidden = item_id("Hidden").await.unwrap();
+    for (blocking, blocked) in [
+        (&visible_one, &visible_two),
+        (&visible_one, &hidden),
+        (&hidden, &visible_two),
+    ] {
+        sqlx::query(
+            "INSERT INTO pm_dependencies (id, blocking_item_id, blocked_item_id, created_at, created_by) VALUES (?, ?, ?, 0, ?)",
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(blocking)
+        .bind(blocked)
+        .bind(fixture.user_id.to_string())
+        .execute(&fixture.pool)
+        .await
+        .expect("Failed to create dependency");
+    }
+
+    let result = query(
+        &fixture,
+        "SELECT blocking_item_id, blocked_item_id FROM pm_dependencies",
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        result.rows,
+        vec![vec![json!(visible_one), json!(visible_two)]]
+    );
+}
+
+#[tokio::test]
+async fn when_query_bypasses_views_then_rejected() {
+    let fixture = setup().await;
+
+    for sql in [
+        "SELECT * FROM main.pm_work_items",
+        "SELECT * FROM pm_idempotency_keys",
+        "SELECT name FROM sqlite_master",
+        "SELECT load_extension('evil')",
+        "WITH gone AS (SELECT 1) DELETE FROM pm_work_items",
+    ] {
+        let err = query(&fixture, sql).await.unwrap_err();
+        assert!(matches!(err, DbError::QueryRejected { .. }), "{sql}: {err}");
+    }
+
+    let count = query(&fixture, "SELECT COUNT(*) FROM pm_work_items")
+        .await
+        .unwrap();
+    assert_eq!(count.rows, vec![vec![json!(2)]]);
+}
+
+#[tokio::test]
+async fn when_more_rows_than_limit_then_truncated() {
+    let fixture = setup().await;
+
+    let result = SqlSandbox::query(
+        &fixture.pool,
+        fixture.user_id,
+        "WITH RECURSIVE n(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM n WHERE x < 50) SELECT x, x * 0.5 AS half FROM n",
+        10,
+        TIMEOUT,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.rows.len(), 10);
+    assert_eq!(result.rows[9], vec![json!(10), json!(5.0)]);
+    assert!(result.truncated);
+}
+
+#[tokio::test]
+async fn when_query_runs_too_long_then_times_out() {
+    let fixture = setup().await;
+
+    let err = SqlSandbox::query(
+        &fixture.pool,
+        fixture.user_id,
+        "WITH RECURSIVE n(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM n) SELECT COUNT(*) FROM n",
+        MAX_ROWS,
+        Duration::from_millis(100),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(
+        matches!(
+            err,
+            DbError::QueryTimeout {
+                timeout_ms: 100,
+                ..
+            }
+        ),
+        "{err}"
+    );
+}
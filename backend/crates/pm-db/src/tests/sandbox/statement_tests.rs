@@ -0,0 +1,44 @@
+use crate::{DbError, sandbox::statement::single_select};
+
+#[test]
+fn when_select_has_trailing_semicolons_and_comments_then_they_are_stripped() {
+    let sql = "-- open items\n  SELECT * FROM pm_work_items;; -- done\n";
+
+    assert_eq!(single_select(sql).unwrap(), "SELECT * FROM pm_work_items");
+}
+
+#[test]
+fn when_semicolon_is_quoted_then_statement_is_kept_whole() {
+    let sql = "SELECT ';' AS a, \"x;y\" FROM [t;1] /* ; */ WHERE b = 'it''s'";
+
+    assert_eq!(single_select(sql).unwrap(), sql);
+}
+
+#[test]
+fn when_with_clause_then_accepted() {
+    assert!(single_select("with x AS (SELECT 1) SELECT * FROM x").is_ok());
+}
+
+#[test]
+fn when_second_statement_then_rejected() {
+    let err = single_select("SELECT 1; DELETE FROM pm_work_items").unwrap_err();
+
+    assert!(
+        matches!(err, DbError::QueryRejected { ref message, .. } if message.contains("single"))
+    );
+}
+
+#[test]
+fn when_not_a_select_then_rejected() {
+    for sql in [
+        "PRAGMA table_info(pm_work_items)",
+        "ATTACH DATABASE 'other.db' AS other",
+        "DELETE FROM pm_work_items",
+        "  -- only a comment",
+    ] {
+        assert!(
+            matches!(single_select(sql), Err(DbError::QueryRejected { .. })),
+            "{sql}"
+        );
+    }
+}
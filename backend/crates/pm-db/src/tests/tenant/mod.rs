@@ -0,0 +1 @@
+mod tenant_pools_tests;
@@ -0,0 +1,110 @@
+use crate::{DbError, TenantPoolOptions, TenantPools};
+
+use std::time::Duration;
+
+use sqlx::SqlitePool;
+use tempfile::TempDir;
+
+const SYSTEM_USER_ID: &str = "00000000-0000-0000-0000-000000000001";
+
+fn pools(temp: &TempDir, create_missing: bool, max_open_pools: usize) -> TenantPools {
+    TenantPools::new(TenantPoolOptions {
+        root: temp.path().to_path_buf(),
+        create_missing,
+        max_open_pools,
+        idle_timeout: Some(Duration::ZERO),
+        max_connections: 2,
+        system_user: Some((SYSTEM_USER_ID.to_string(), "LLM Assistant".to_string())),
+    })
+}
+
+async fn project_keys(pool: &SqlitePool) -> Vec<String> {
+    sqlx::query_scalar("SELECT key FROM pm_projects ORDER BY key")
+        .fetch_all(pool)
+        .await
+        .expect("Failed to list projects")
+}
+
+async fn create_project(pool: &SqlitePool, key: &str) {
+    sqlx::query(
+        r#"
+        INSERT INTO pm_projects (id, key, title, status, created_at, updated_at, created_by, updated_by, version)
+        VALUES (?, ?, ?, 'active', 0, 0, ?, ?, 1)
+        "#,
+    )
+    .bind(uuid::Uuid::new_v4().to_string())
+    .bind(key)
+    .bind(key)
+    .bind(SYSTEM_USER_ID)
+    .bind(SYSTEM_USER_ID)
+    .execute(pool)
+    .await
+    .expect("Failed to create project");
+}
+
+#[tokio::test]
+async fn when_tenants_open_pools_then_data_is_isolated() {
+    let temp = TempDir::new().unwrap();
+    let pools = pools(&temp, true, 10);
+
+    let acme = pools.pool("acme").await.unwrap();
+    let globex = pools.pool("globex").await.unwrap();
+    create_project(&acme, "ACME").await;
+    create_project(&globex, "GLOBEX").await;
+
+    assert_eq!(project_keys(&acme).await, vec!["ACME"]);
+    assert_eq!(project_keys(&globex).await, vec!["GLOBEX"]);
+    assert!(pools.database_path("acme").exists());
+    assert!(pools.database_path("globex").exists());
+}
+
+#[tokio::test]
+async fn when_tenant_database_missing_then_not_found_unless_created() {
+    let temp = TempDir::new().unwrap();
+
+    let result = pools(&temp, false, 10).pool("acme").await;
+
+    assert!(matches!(result, Err(DbError::TenantNotFound { .. })));
+    assert!(!temp.path().join("acme").exists());
+}
+
+#[tokio::test]
+async fn when_tenant_id_is_a_path_then_rejected() {
+    let temp = TempDir::new().unwrap();
+    let pools = pools(&temp, true, 10);
+
+    for tenant_id in ["", "../acme", "acme/main", "a.b"] {
+        let result = pools.pool(tenant_id).await;
+        assert!(
+            matches!(result, Err(DbError::TenantNotFound { .. })),
+            "{tenant_id:?} was accepted"
+        );
+    }
+}
+
+#[tokio::test]
+async fn when_more_tenants_than_max_open_then_least_recently_used_is_evicted() {
+    let temp = TempDir::new().unwrap();
+    let pools = pools(&temp, true, 2);
+
+    pools.pool("acme").await.unwrap();
+    pools.pool("globex").await.unwrap();
+    pools.pool("acme").await.unwrap();
+    pools.pool("initech").await.unwrap();
+
+    assert_eq!(pools.open_tenants().await, vec!["acme", "initech"]);
+}
+
+#[tokio::test]
+async fn when_reopened_after_eviction_then_data_persists() {
+    let temp = TempDir::new().unwrap();
+    let pools = pools(&temp, true, 10);
+    let acme = pools.pool("acme").await.unwrap();
+    create_project(&acme, "ACME").await;
+
+    let evicted = pools.evict_idle().await;
+    let reopened = pools.pool("acme").await.unwrap();
+
+    assert_eq!(evicted, vec!["acme"]);
+    assert_eq!(project_keys(&reopened).await, vec!["ACME"]);
+}
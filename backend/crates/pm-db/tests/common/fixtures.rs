@@ -2,8 +2,8 @@
 
 use chrono::Utc;
 use pm_core::{
-    ActivityLog, Comment, Dependency, DependencyType, Project, ProjectStatus, Sprint, SprintStatus,
-    SwimLane, TimeEntry, WorkItem, WorkItemType,
+    ActivityLog, Comment, CustomFieldDefinition, CustomFieldType, Dependency, DependencyType,
+    Project, ProjectStatus, Sprint, SprintStatus, SwimLane, TimeEntry, WorkItem, WorkItemType,
 };
 use uuid::Uuid;
 
@@ -35,13 +35,17 @@ pub fn create_test_work_item(project_id: Uuid, user_id: Uuid, item_number: i32)
         parent_id: None,
         project_id,
         position: 0,
+        rank: "i".to_string(),
         title: "Test Work Item".to_string(),
         description: Some("Test description".to_string()),
         status: "backlog".to_string(),
         priority: "medium".to_string(),
         assignee_id: None,
         story_points: None,
+        original_estimate_seconds: None,
+        remaining_estimate_seconds: None,
         sprint_id: None,
+        custom_fields: Default::default(),
         item_number,
         version: 0,
         created_at: Utc::now(),
@@ -259,3 +263,20 @@ pub fn create_default_swim_lane(project_id: Uuid) -> SwimLane {
         deleted_at: None,
     }
 }
+
+/// Creates a test single-select CustomFieldDefinition
+pub fn create_test_custom_field(
+    project_id: Uuid,
+    user_id: Uuid,
+    name: &str,
+) -> CustomFieldDefinition {
+    let mut field = CustomFieldDefinition::new(
+        project_id,
+        name.to_string(),
+        format!("Label {}", name),
+        CustomFieldType::SingleSelect,
+        user_id,
+    );
+    field.options = vec!["low".to_string(), "high".to_string()];
+    field
+}
@@ -0,0 +1,163 @@
+mod common;
+
+use common::{
+    create_test_custom_field, create_test_pool, create_test_project, create_test_user,
+    create_test_work_item,
+};
+
+use pm_db::{CustomFieldRepository, ProjectRepository, WorkItemRepository};
+
+use chrono::Utc;
+use googletest::prelude::*;
+use serde_json::json;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn given_valid_custom_field_when_created_then_can_be_found_by_id() {
+    // Given: A project
+    let pool = create_test_pool().await;
+    let user_id = Uuid::new_v4();
+    create_test_user(&pool, user_id).await;
+    let project = create_test_project(user_id);
+    ProjectRepository::new(pool.clone())
+        .create(&project)
+        .await
+        .unwrap();
+
+    let repo = CustomFieldRepository::new(pool.clone());
+    let mut field = create_test_custom_field(project.id, user_id, "severity");
+    field.required = true;
+    field.default_value = Some(json!("low"));
+
+    // When: Creating the field
+    repo.create(&field).await.unwrap();
+
+    // Then: All typed attributes round-trip
+    let found = repo.find_by_id(field.id).await.unwrap().unwrap();
+    assert_that!(found.name, eq("severity"));
+    assert_that!(found.field_type, eq(field.field_type));
+    assert_that!(found.options, elements_are![eq("low"), eq("high")]);
+    assert_that!(found.required, eq(true));
+    assert_that!(found.default_value, some(eq(&json!("low"))));
+}
+
+#[tokio::test]
+async fn given_fields_when_finding_by_project_then_ordered_by_position() {
+    // Given: Two fields created out of order
+    let pool = create_test_pool().await;
+    let user_id = Uuid::new_v4();
+    create_test_user(&pool, user_id).await;
+    let project = create_test_project(user_id);
+    ProjectRepository::new(pool.clone())
+        .create(&project)
+        .await
+        .unwrap();
+
+    let repo = CustomFieldRepository::new(pool.clone());
+    let mut second = create_test_custom_field(project.id, user_id, "second");
+    second.position = 1;
+    let first = create_test_custom_field(project.id, user_id, "first");
+    repo.create(&second).await.unwrap();
+    repo.create(&first).await.unwrap();
+
+    // When: Listing the project's fields
+    let fields = repo.find_by_project(project.id).await.unwrap();
+
+    // Then: Position order wins over insertion order
+    let names: Vec<&str> = fields.iter().map(|f| f.name.as_str()).collect();
+    assert_that!(names, elements_are![eq(&"first"), eq(&"second")]);
+}
+
+#[tokio::test]
+async fn given_deleted_field_when_recreated_with_same_name_then_succeeds() {
+    // Given: A field that has been soft-deleted
+    let pool = create_test_pool().await;
+    let user_id = Uuid::new_v4();
+    create_test_user(&pool, user_id).await;
+    let project = create_test_project(user_id);
+    ProjectRepository::new(pool.clone())
+        .create(&project)
+        .await
+        .unwrap();
+
+    let repo = CustomFieldRepository::new(pool.clone());
+    let field = create_test_custom_field(project.id, user_id, "severity");
+    repo.create(&field).await.unwrap();
+    repo.delete(field.id, Utc::now().timestamp()).await.unwrap();
+
+    // When: Creating a new field with the same name
+    let replacement = create_test_custom_field(project.id, user_id, "severity");
+    repo.create(&replacement).await.unwrap();
+
+    // Then: Only the live definition is visible
+    assert_that!(repo.find_by_id(field.id).await.unwrap(), none());
+    let found = repo
+        .find_by_project_and_name(project.id, "severity")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_that!(found.id, eq(replacement.id));
+}
+
+#[tokio::test]
+async fn given_live_field_when_duplicate_name_created_then_fails() {
+    // Given: A live field
+    let pool = create_test_pool().await;
+    let user_id = Uuid::new_v4();
+    create_test_user(&pool, user_id).await;
+    let project = create_test_project(user_id);
+    ProjectRepository::new(pool.clone())
+        .create(&project)
+        .await
+        .unwrap();
+
+    let repo = CustomFieldRepository::new(pool.clone());
+    repo.create(&create_test_custom_field(project.id, user_id, "severity"))
+        .await
+        .unwrap();
+
+    // When: Creating another field with the same name
+    let result = repo
+        .create(&create_test_custom_field(project.id, user_id, "severity"))
+        .await;
+
+    // Then: The unique index rejects it
+    assert_that!(result, err(anything()));
+}
+
+#[tokio::test]
+async fn given_work_item_with_custom_values_when_saved_then_values_round_trip() {
+    // Given: A work item carrying custom field values
+    let pool = create_test_pool().await;
+    let user_id = Uuid::new_v4();
+    create_test_user(&pool, user_id).await;
+    let project = create_test_project(user_id);
+    ProjectRepository::new(pool.clone())
+        .create(&project)
+        .await
+        .unwrap();
+
+    let mut work_item = create_test_work_item(project.id, user_id, 1);
+    work_item
+        .custom_fields
+        .insert("severity".to_string(), json!("high"));
+    work_item
+        .custom_fields
+        .insert("labels".to_string(), json!(["a", "b"]));
+
+    // When: Creating and then updating the item
+    WorkItemRepository::create(&pool, &work_item).await.unwrap();
+    work_item.custom_fields.remove("labels");
+    WorkItemRepository::update(&pool, &work_item).await.unwrap();
+
+    // Then: The stored values reflect the update
+    let found = WorkItemRepository::find_by_id(&pool, work_item.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_that!(found.custom_fields.len(), eq(1));
+    assert_that!(
+        found.custom_fields.get("severity"),
+        some(eq(&json!("high")))
+    );
+}
@@ -214,3 +214,46 @@ async fn given_empty_database_when_finding_all_then_returns_empty_vec() {
     // Then: Returns empty vector
     assert_that!(projects, is_empty());
 }
+
+#[tokio::test]
+async fn given_renamed_project_when_finding_by_old_key_alias_then_returns_project_id() {
+    // Given: A project whose key changed from its old key to NEWKEY
+    let pool = create_test_pool().await;
+    let user_id = Uuid::new_v4();
+    create_test_user(&pool, user_id).await;
+
+    let repo = ProjectRepository::new(pool.clone());
+    let mut project = create_test_project(user_id);
+    repo.create(&project).await.unwrap();
+    let old_key = project.key.clone();
+
+    project.key = "NEWKEY".to_string();
+    project.version = 2;
+    project.updated_at = Utc::now();
+    let mut tx = pool.begin().await.unwrap();
+    ProjectRepository::update_with(&mut *tx, &project)
+        .await
+        .unwrap();
+    ProjectRepository::create_key_alias_with(&mut *tx, &old_key, project.id)
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    // When: Looking up the old key and an unknown key
+    let found = repo.find_id_by_key_alias(&old_key).await.unwrap();
+    let unknown = repo.find_id_by_key_alias("NOPE").await.unwrap();
+
+    // Then: The old key points at the project; the live key moved on
+    assert_that!(found, some(eq(project.id)));
+    assert_that!(unknown, none());
+    assert_that!(repo.find_by_key(&old_key).await.unwrap(), none());
+    assert_that!(repo.find_by_key("NEWKEY").await.unwrap(), some(anything()));
+
+    // When: The alias is dropped
+    ProjectRepository::delete_key_alias_with(&pool, &old_key)
+        .await
+        .unwrap();
+
+    // Then: The old key no longer resolves
+    assert_that!(repo.find_id_by_key_alias(&old_key).await.unwrap(), none());
+}
@@ -266,3 +266,64 @@ async fn given_time_entries_with_one_deleted_when_finding_by_work_item_then_excl
     assert_that!(entries, len(eq(1)));
     assert_that!(entries[0].id, eq(entry2.id));
 }
+
+#[tokio::test]
+async fn given_entries_around_range_when_finding_in_range_then_returns_overlapping_only() {
+    // Given: Entries before, inside, and overlapping a one-day window, plus a
+    // running timer and a deleted entry
+    let pool = create_test_pool().await;
+    let user_id = Uuid::new_v4();
+    create_test_user(&pool, user_id).await;
+
+    let project = create_test_project(user_id);
+    ProjectRepository::new(pool.clone())
+        .create(&project)
+        .await
+        .unwrap();
+
+    let work_item = create_test_work_item(project.id, user_id, 1);
+    WorkItemRepository::create(&pool, &work_item).await.unwrap();
+
+    let repo = TimeEntryRepository::new(pool.clone());
+    let day_start = Utc::now() - chrono::Duration::days(3);
+    let day_end = day_start + chrono::Duration::days(1);
+
+    let mut before = create_test_time_entry(work_item.id, user_id);
+    before.started_at = day_start - chrono::Duration::hours(5);
+    before.ended_at = Some(day_start - chrono::Duration::hours(4));
+
+    let mut overlapping = create_test_time_entry(work_item.id, user_id);
+    overlapping.started_at = day_start - chrono::Duration::hours(1);
+    overlapping.ended_at = Some(day_start + chrono::Duration::hours(1));
+
+    let mut inside = create_test_time_entry(work_item.id, user_id);
+    inside.started_at = day_start + chrono::Duration::hours(2);
+    inside.ended_at = Some(day_start + chrono::Duration::hours(3));
+
+    let mut running = create_running_time_entry(work_item.id, user_id);
+    running.started_at = day_start + chrono::Duration::hours(4);
+
+    let deleted = {
+        let mut e = create_test_time_entry(work_item.id, user_id);
+        e.started_at = day_start + chrono::Duration::hours(5);
+        e.ended_at = Some(day_start + chrono::Duration::hours(6));
+        e
+    };
+
+    for entry in [&before, &overlapping, &inside, &running, &deleted] {
+        repo.create(entry).await.unwrap();
+    }
+    repo.delete(deleted.id, Utc::now().timestamp())
+        .await
+        .unwrap();
+
+    // When: Finding entries in the window
+    let entries = repo.find_in_range(day_start, day_end).await.unwrap();
+
+    // Then: Only live entries overlapping the window are returned
+    let ids: Vec<Uuid> = entries.iter().map(|e| e.id).collect();
+    assert_that!(ids, len(eq(3)));
+    assert_that!(ids, contains(eq(&overlapping.id)));
+    assert_that!(ids, contains(eq(&inside.id)));
+    assert_that!(ids, contains(eq(&running.id)));
+}
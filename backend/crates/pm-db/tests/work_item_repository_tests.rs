@@ -356,3 +356,132 @@ async fn given_project_with_work_items_when_deleted_then_all_work_items_cascade_
     assert_that!(result1, none());
     assert_that!(result2, none());
 }
+
+#[tokio::test]
+async fn given_work_item_moved_to_another_project_when_old_number_looked_up_then_alias_finds_it() {
+    // Given: A work item in one project and a second project
+    let pool = create_test_pool().await;
+    let user_id = Uuid::new_v4();
+    create_test_user(&pool, user_id).await;
+
+    let source = create_test_project(user_id);
+    let mut target = create_test_project(user_id);
+    target.key = "TARGET".to_string();
+    let projects = ProjectRepository::new(pool.clone());
+    projects.create(&source).await.unwrap();
+    projects.create(&target).await.unwrap();
+
+    let mut item = create_test_work_item(source.id, user_id, 12);
+    WorkItemRepository::create(&pool, &item).await.unwrap();
+
+    // When: Moving it and recording its old number
+    item.project_id = target.id;
+    item.item_number = 1;
+    item.version += 1;
+    WorkItemRepository::move_to_project(&pool, &item)
+        .await
+        .unwrap();
+    WorkItemRepository::create_alias(&pool, source.id, 12, item.id)
+        .await
+        .unwrap();
+
+    // Then: The new number finds it, and the old number only via the alias
+    let moved = WorkItemRepository::find_by_project_and_number(&pool, target.id, 1)
+        .await
+        .unwrap();
+    assert_that!(moved.map(|w| w.id), some(eq(item.id)));
+    let old = WorkItemRepository::find_by_project_and_number(&pool, source.id, 12)
+        .await
+        .unwrap();
+    assert_that!(old, none());
+    let alias = WorkItemRepository::find_id_by_alias(&pool, source.id, 12)
+        .await
+        .unwrap();
+    assert_that!(alias, some(eq(item.id)));
+    let unknown = WorkItemRepository::find_id_by_alias(&pool, source.id, 13)
+        .await
+        .unwrap();
+    assert_that!(unknown, none());
+}
+
+#[tokio::test]
+async fn given_ranked_work_items_when_one_is_reranked_then_backlog_order_follows_rank() {
+    // Given: Three work items ranked in item number order
+    let pool = create_test_pool().await;
+    let user_id = Uuid::new_v4();
+    create_test_user(&pool, user_id).await;
+
+    let project = create_test_project(user_id);
+    ProjectRepository::new(pool.clone())
+        .create(&project)
+        .await
+        .unwrap();
+
+    let mut items = Vec::new();
+    for (number, rank) in [(1, "c"), (2, "i"), (3, "r")] {
+        let mut item = create_test_work_item(project.id, user_id, number);
+        item.rank = rank.to_string();
+        WorkItemRepository::create(&pool, &item).await.unwrap();
+        items.push(item);
+    }
+
+    // When: Moving the last item to the front
+    let mut moved = items[2].clone();
+    moved.rank = "5".to_string();
+    moved.version += 1;
+    WorkItemRepository::update_rank(&pool, &moved)
+        .await
+        .unwrap();
+
+    // Then: Reads come back in rank order and only the moved item changed
+    let backlog = WorkItemRepository::find_by_project(&pool, project.id, true)
+        .await
+        .unwrap();
+    let numbers: Vec<i32> = backlog.iter().map(|w| w.item_number).collect();
+    assert_that!(numbers, elements_are![eq(&3), eq(&1), eq(&2)]);
+    assert_that!(backlog[0].version, eq(moved.version));
+    assert_that!(backlog[1].version, eq(items[0].version));
+
+    // And: Neighbour lookups skip the excluded item
+    let last = WorkItemRepository::find_last_rank(&pool, project.id)
+        .await
+        .unwrap();
+    assert_that!(last.as_deref(), some(eq("i")));
+    let after = WorkItemRepository::find_rank_after(&pool, project.id, "c", items[1].id)
+        .await
+        .unwrap();
+    assert_that!(after, none());
+    let before = WorkItemRepository::find_rank_before(&pool, project.id, "c", items[0].id)
+        .await
+        .unwrap();
+    assert_that!(before.as_deref(), some(eq("5")));
+}
+
+#[tokio::test]
+async fn given_work_item_when_rank_set_then_version_is_unchanged() {
+    // Given: A work item
+    let pool = create_test_pool().await;
+    let user_id = Uuid::new_v4();
+    create_test_user(&pool, user_id).await;
+
+    let project = create_test_project(user_id);
+    ProjectRepository::new(pool.clone())
+        .create(&project)
+        .await
+        .unwrap();
+    let item = create_test_work_item(project.id, user_id, 1);
+    WorkItemRepository::create(&pool, &item).await.unwrap();
+
+    // When: Setting its rank as an import would
+    WorkItemRepository::set_rank(&pool, item.id, "8")
+        .await
+        .unwrap();
+
+    // Then: The rank changes but the version does not
+    let found = WorkItemRepository::find_by_id(&pool, item.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_that!(found.rank, eq("8"));
+    assert_that!(found.version, eq(item.version));
+}
@@ -1,10 +1,12 @@
 use crate::{
-    ConnectionConfig, ConnectionRegistry, Metrics, ShutdownCoordinator, WebSocketConnection,
-    WebSocketConnectionParams, circuit_breaker::CircuitBreaker,
+    ConnectionConfig, ConnectionRegistry, Metrics, Result as WsErrorResult, ShutdownCoordinator,
+    TenantResolver, WebSocketConnection, WebSocketConnectionParams, WsError,
+    circuit_breaker::CircuitBreaker,
 };
 
 use pm_auth::{JwtValidator, RateLimiterFactory};
 use pm_config::{ApiConfig, ValidationConfig};
+use pm_db::BackupStore;
 
 use std::sync::Arc;
 
@@ -34,6 +36,30 @@ pub struct AppState {
     pub config: ConnectionConfig,
     pub api_config: ApiConfig,
     pub validation: ValidationConfig,
+    /// Online backups; `None` disables the backup endpoints
+    pub backups: Option<BackupStore>,
+    /// Per-tenant databases; `None` serves every request from `pool`
+    pub tenants: Option<TenantResolver>,
+}
+
+impl AppState {
+    /// This state scoped to the request's tenant: `pool` is the tenant's
+    /// database and `registry` only reaches the tenant's connections.
+    /// Without tenant routing the state is returned as is.
+    pub async fn for_request(&self, headers: &HeaderMap) -> WsErrorResult<AppState> {
+        let Some(tenants) = &self.tenants else {
+            return Ok(self.clone());
+        };
+
+        let tenant_id = tenants.tenant_id(headers, self.jwt_validator.as_deref())?;
+        let pool = tenants.pool(&tenant_id).await?;
+
+        Ok(AppState {
+            pool,
+            registry: self.registry.for_tenant(&tenant_id),
+            ..self.clone()
+        })
+    }
 }
 
 /// WebSocket upgrade handler
@@ -52,11 +78,27 @@ pub async fn handler(
     )?;
     debug!("WebSocket upgrade request from user {}", user_id);
 
+    // Route to the tenant's database and connections
+    let state = state.for_request(&headers).await.map_err(|e| {
+        warn!("WebSocket tenant resolution failed: {}", e);
+        match e {
+            WsError::NotFound { .. } => StatusCode::NOT_FOUND,
+            WsError::Unauthorized { .. } => StatusCode::UNAUTHORIZED,
+            WsError::Forbidden { .. } => StatusCode::FORBIDDEN,
+            _ => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    })?;
+
     if state.registry.is_at_total_limit().await {
         warn!("WebSocket connection rejected: total limit reached");
         return Err(StatusCode::SERVICE_UNAVAILABLE);
     }
 
+    if state.registry.is_at_tenant_limit().await {
+        warn!("WebSocket connection rejected: tenant limit reached");
+        return Err(StatusCode::SERVICE_UNAVAILABLE);
+    }
+
     // Create rate limiter for this connection
     let rate_limiter = state.rate_limiter_factory.create();
 
@@ -93,11 +135,18 @@ async fn handle_socket(
     let (tx, rx) = mpsc::channel::<Message>(state.config.send_buffer_size);
 
     // Register connection (enforces connection limits)
-    let connection_id = state
+    let connection_id = match state
         .registry
         .register(user_id.to_string(), tx.clone())
         .await
-        .unwrap();
+    {
+        Ok(connection_id) => connection_id,
+        Err(e) => {
+            // Limits were checked before the upgrade; another connection won the race
+            warn!("WebSocket connection rejected: {e}");
+            return;
+        }
+    };
 
     let connection = WebSocketConnection::new(WebSocketConnectionParams {
         connection_id,
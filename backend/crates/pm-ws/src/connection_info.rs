@@ -9,6 +9,8 @@ use tokio::sync::mpsc;
 pub struct ConnectionInfo {
     pub connection_id: ConnectionId,
     pub user_id: String,
+    /// Tenant the connection belongs to; `None` in single-tenant mode
+    pub tenant_id: Option<String>,
     pub connected_at: DateTime<chrono::Utc>,
     pub sender: mpsc::Sender<Message>,
     pub subscriptions: ClientSubscriptions,
@@ -3,10 +3,15 @@
 pub struct ConnectionLimits {
     /// Maximum total connections
     pub max_total: usize,
+    /// Maximum connections per tenant (multi-tenant hosting only)
+    pub max_per_tenant: usize,
 }
 
 impl Default for ConnectionLimits {
     fn default() -> Self {
-        Self { max_total: 10000 }
+        Self {
+            max_total: 10000,
+            max_per_tenant: 10000,
+        }
     }
 }
@@ -13,9 +13,14 @@ use log::{debug, info, warn};
 use tokio::sync::{RwLock, mpsc};
 
 /// Registry for tracking active WebSocket connections                                                                                                                           
+///
+/// A registry scoped with [`ConnectionRegistry::for_tenant`] shares the
+/// connection table but registers connections under that tenant and only
+/// broadcasts to them, so events never cross tenants.
 pub struct ConnectionRegistry {
     inner: Arc<RwLock<RegistryInner>>,
     limits: ConnectionLimits,
+    tenant_id: Option<String>,
 }
 
 struct RegistryInner {
@@ -30,9 +35,24 @@ impl ConnectionRegistry {
                 connections: HashMap::new(),
             })),
             limits,
+            tenant_id: None,
         }
     }
 
+    /// A view of this registry for one tenant's connections
+    pub fn for_tenant(&self, tenant_id: &str) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+            limits: self.limits.clone(),
+            tenant_id: Some(tenant_id.to_string()),
+        }
+    }
+
+    /// The tenant this registry is scoped to, if any
+    pub fn tenant_id(&self) -> Option<&str> {
+        self.tenant_id.as_deref()
+    }
+
     /// Register a new connection, returns ConnectionId if successful
     pub async fn register(
         &self,
@@ -55,11 +75,28 @@ impl ConnectionRegistry {
             });
         }
 
+        // Check per-tenant connection limit
+        if let Some(tenant_id) = &self.tenant_id {
+            let tenant_count = Self::tenant_count(&inner, tenant_id);
+            if tenant_count >= self.limits.max_per_tenant {
+                warn!(
+                    "Tenant {tenant_id} connection limit reached: {tenant_count}/{}",
+                    self.limits.max_per_tenant
+                );
+                return Err(WsError::ConnectionLimitExceeded {
+                    current: tenant_count,
+                    max: self.limits.max_per_tenant,
+                    location: ErrorLocation::from(Location::caller()),
+                });
+            }
+        }
+
         // Create new connection
         let connection_id = ConnectionId::new();
         let info = ConnectionInfo {
             connection_id,
             user_id,
+            tenant_id: self.tenant_id.clone(),
             connected_at: chrono::Utc::now(),
             sender,
             subscriptions: ClientSubscriptions::new(),
@@ -104,6 +141,16 @@ impl ConnectionRegistry {
         inner.connections.len() >= self.limits.max_total
     }
 
+    /// True if this registry's tenant reached max_per_tenant (never for
+    /// an unscoped registry)
+    pub async fn is_at_tenant_limit(&self) -> bool {
+        let Some(tenant_id) = &self.tenant_id else {
+            return false;
+        };
+        let inner = self.inner.read().await;
+        Self::tenant_count(&inner, tenant_id) >= self.limits.max_per_tenant
+    }
+
     /// Subscribe a connection to project/sprint updates
     pub async fn subscribe(
         &self,
@@ -171,6 +218,7 @@ impl ConnectionRegistry {
         let connections: Vec<(ClientSubscriptions, mpsc::Sender<Message>)> = inner
             .connections
             .values()
+            .filter(|info| info.tenant_id == self.tenant_id)
             .map(|info| (info.subscriptions.clone(), info.sender.clone()))
             .collect();
         drop(inner);
@@ -215,6 +263,7 @@ impl ConnectionRegistry {
         let connections: Vec<(ClientSubscriptions, mpsc::Sender<Message>)> = inner
             .connections
             .values()
+            .filter(|info| info.tenant_id == self.tenant_id)
             .map(|info| (info.subscriptions.clone(), info.sender.clone()))
             .collect();
         drop(inner);
@@ -230,6 +279,14 @@ impl ConnectionRegistry {
 
         Ok(delivered)
     }
+
+    fn tenant_count(inner: &RegistryInner, tenant_id: &str) -> usize {
+        inner
+            .connections
+            .values()
+            .filter(|info| info.tenant_id.as_deref() == Some(tenant_id))
+            .count()
+    }
 }
 
 impl Clone for ConnectionRegistry {
@@ -237,6 +294,7 @@ impl Clone for ConnectionRegistry {
         Self {
             inner: Arc::clone(&self.inner),
             limits: self.limits.clone(),
+            tenant_id: self.tenant_id.clone(),
         }
     }
 }
@@ -92,6 +92,12 @@ pub enum WsError {
         location: ErrorLocation,
     },
 
+    #[error("Forbidden: {message}")]
+    Forbidden {
+        message: String,
+        location: ErrorLocation,
+    },
+
     // Database error with details
     #[error("Database error: {message}")]
     Database {
@@ -143,6 +149,7 @@ impl WsError {
             Self::ConflictError { .. } => "CONFLICT",
             Self::DeleteBlocked { .. } => "DELETE_BLOCKED",
             Self::Unauthorized { .. } => "UNAUTHORIZED",
+            Self::Forbidden { .. } => "FORBIDDEN",
             Self::Database { .. } => "DATABASE_ERROR",
             Self::ServiceUnavailable { .. } => "SERVICE_UNAVAILABLE",
             Self::Timeout { .. } => "TIMEOUT",
@@ -188,6 +195,24 @@ impl From<pm_db::DbError> for WsError {
     }
 }
 
+impl From<pm_core::CoreError> for WsError {
+    #[track_caller]
+    fn from(err: pm_core::CoreError) -> Self {
+        match err {
+            pm_core::CoreError::Validation { message, field, .. } => Self::ValidationError {
+                message,
+                field,
+                location: ErrorLocation::from(Location::caller()),
+            },
+            other => Self::ValidationError {
+                message: other.to_string(),
+                field: None,
+                location: ErrorLocation::from(Location::caller()),
+            },
+        }
+    }
+}
+
 impl From<sqlx::Error> for WsError {
     #[track_caller]
     fn from(err: sqlx::Error) -> Self {
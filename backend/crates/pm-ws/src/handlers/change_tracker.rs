@@ -1,4 +1,4 @@
-use pm_core::WorkItem;
+use pm_core::{CustomFieldValues, WorkItem, display_custom_field_value};
 use pm_proto::{FieldChange, UpdateWorkItemRequest};
 
 /// Track which fields changed between current state and update request.
@@ -87,5 +87,78 @@ pub fn track_changes(current: &WorkItem, request: &UpdateWorkItemRequest) -> Vec
         }
     }
 
+    let (original, remaining) = requested_estimates(current, request);
+    changes.extend(track_estimate_changes(current, original, remaining));
+
     changes
 }
+
+/// Resolve the estimates an update will leave on the item.
+/// Mirrors `apply_updates`: `clear_estimate` wins, and setting an original
+/// estimate on unestimated work also initializes the remaining estimate.
+fn requested_estimates(
+    current: &WorkItem,
+    request: &UpdateWorkItemRequest,
+) -> (Option<i32>, Option<i32>) {
+    if request.clear_estimate {
+        (None, None)
+    } else {
+        let original = request
+            .original_estimate_seconds
+            .or(current.original_estimate_seconds);
+        let remaining = request
+            .remaining_estimate_seconds
+            .or(current.remaining_estimate_seconds)
+            .or(request.original_estimate_seconds);
+        (original, remaining)
+    }
+}
+
+/// Track changes between the item's current estimates and the new values.
+pub fn track_estimate_changes(
+    current: &WorkItem,
+    original: Option<i32>,
+    remaining: Option<i32>,
+) -> Vec<FieldChange> {
+    [
+        (
+            "original_estimate_seconds",
+            current.original_estimate_seconds,
+            original,
+        ),
+        (
+            "remaining_estimate_seconds",
+            current.remaining_estimate_seconds,
+            remaining,
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, old, new)| old != new)
+    .map(|(field_name, old, new)| FieldChange {
+        field_name: field_name.to_string(),
+        old_value: old.map(|v| v.to_string()),
+        new_value: new.map(|v| v.to_string()),
+    })
+    .collect()
+}
+
+/// Track custom field changes between the stored and resolved value maps.
+/// Each changed field is reported as `custom_fields.<name>`.
+pub fn track_custom_field_changes(
+    current: &CustomFieldValues,
+    updated: &CustomFieldValues,
+) -> Vec<FieldChange> {
+    let mut names: Vec<&String> = current.keys().chain(updated.keys()).collect();
+    names.sort();
+    names.dedup();
+
+    names
+        .into_iter()
+        .filter(|name| current.get(*name) != updated.get(*name))
+        .map(|name| FieldChange {
+            field_name: format!("custom_fields.{}", name),
+            old_value: current.get(name).map(display_custom_field_value),
+            new_value: updated.get(name).map(display_custom_field_value),
+        })
+        .collect()
+}
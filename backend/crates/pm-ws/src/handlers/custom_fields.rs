@@ -0,0 +1,112 @@
+//! Custom field value helpers shared by the WebSocket and REST work item handlers.
+//!
+//! Values travel over protobuf as JSON-encoded strings keyed by field name;
+//! the domain model holds them as `serde_json::Value`s.
+
+use crate::{Result as WsErrorResult, WsError};
+
+use pm_core::{
+    CustomFieldDefinition, CustomFieldType, CustomFieldValues, apply_custom_field_values,
+};
+use pm_db::{CustomFieldRepository, ProjectMemberRepository};
+
+use std::collections::HashMap;
+use std::panic::Location;
+
+use error_location::ErrorLocation;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+/// Decode JSON-encoded proto values into domain values.
+/// The string "null" decodes to `Value::Null`, which clears a field on update.
+pub fn decode_custom_field_values(
+    raw: &HashMap<String, String>,
+) -> WsErrorResult<CustomFieldValues> {
+    raw.iter()
+        .map(|(name, json)| {
+            serde_json::from_str(json)
+                .map(|value| (name.clone(), value))
+                .map_err(|e| WsError::ValidationError {
+                    message: format!("Custom field '{}' is not valid JSON: {}", name, e),
+                    field: Some(format!("custom_fields.{}", name)),
+                    location: ErrorLocation::from(Location::caller()),
+                })
+        })
+        .collect()
+}
+
+/// Encode domain values as JSON strings for the proto map
+pub fn encode_custom_field_values(values: &CustomFieldValues) -> HashMap<String, String> {
+    values
+        .iter()
+        .map(|(name, value)| (name.clone(), value.to_string()))
+        .collect()
+}
+
+/// Load the project's field definitions and merge `updates` into `current`.
+/// User fields must name a member of the project.
+///
+/// Skips the definition lookup when there is nothing to validate: an update
+/// that does not mention custom fields leaves stored values untouched.
+pub async fn resolve_custom_field_values(
+    pool: &SqlitePool,
+    project_id: Uuid,
+    current: &CustomFieldValues,
+    updates: &CustomFieldValues,
+    is_new: bool,
+) -> WsErrorResult<CustomFieldValues> {
+    if !is_new && updates.is_empty() {
+        return Ok(current.clone());
+    }
+
+    let definitions = CustomFieldRepository::new(pool.clone())
+        .find_by_project(project_id)
+        .await?;
+
+    let values = apply_custom_field_values(&definitions, current, updates, is_new)?;
+    check_user_values(pool, project_id, &definitions, current, &values).await?;
+    Ok(values)
+}
+
+/// Reject user-field values that name someone outside the project. Only
+/// values that are new or changed are checked, so a member leaving does not
+/// block unrelated edits.
+async fn check_user_values(
+    pool: &SqlitePool,
+    project_id: Uuid,
+    definitions: &[CustomFieldDefinition],
+    current: &CustomFieldValues,
+    values: &CustomFieldValues,
+) -> WsErrorResult<()> {
+    let members = ProjectMemberRepository::new(pool.clone());
+    for definition in definitions
+        .iter()
+        .filter(|d| d.field_type == CustomFieldType::User)
+    {
+        let Some(value) = values.get(&definition.name) else {
+            continue;
+        };
+        if current.get(&definition.name) == Some(value) {
+            continue;
+        }
+        // normalize_value has already checked this is a UUID
+        let Some(user_id) = value.as_str().and_then(|s| Uuid::parse_str(s).ok()) else {
+            continue;
+        };
+        if members
+            .find_by_user_and_project(user_id, project_id)
+            .await?
+            .is_none()
+        {
+            return Err(WsError::ValidationError {
+                message: format!(
+                    "Invalid value for custom field '{}': user {} is not a member of this project",
+                    definition.name, user_id
+                ),
+                field: Some(format!("custom_fields.{}", definition.name)),
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+    }
+    Ok(())
+}
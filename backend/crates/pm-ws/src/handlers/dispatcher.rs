@@ -4,9 +4,9 @@ use crate::{
     handle_create_time_entry, handle_delete, handle_delete_comment, handle_delete_dependency,
     handle_delete_project, handle_delete_sprint, handle_delete_time_entry, handle_get_comments,
     handle_get_dependencies, handle_get_running_timer, handle_get_sprints, handle_get_time_entries,
-    handle_get_work_items, handle_list, handle_start_timer, handle_stop_timer, handle_subscribe,
-    handle_unsubscribe, handle_update, handle_update_comment, handle_update_project,
-    handle_update_sprint, handle_update_time_entry, log_handler_entry,
+    handle_get_work_items, handle_list, handle_move, handle_start_timer, handle_stop_timer,
+    handle_subscribe, handle_unsubscribe, handle_update, handle_update_comment,
+    handle_update_project, handle_update_sprint, handle_update_time_entry, log_handler_entry,
 };
 
 use pm_proto::{Pong, WebSocketMessage, web_socket_message::Payload};
@@ -14,7 +14,10 @@ use pm_proto::{Pong, WebSocketMessage, web_socket_message::Payload};
 use std::panic::Location;
 
 use crate::handlers::activity_log::handle_get_activity_log;
-use crate::handlers::llm_context::handle_get_llm_context;
+use crate::handlers::llm_context::{
+    handle_create_llm_context, handle_delete_llm_context, handle_get_llm_context,
+    handle_update_llm_context,
+};
 use error_location::ErrorLocation;
 use log::{error, info, warn};
 
@@ -76,6 +79,7 @@ async fn dispatch_inner(msg: WebSocketMessage, ctx: HandlerContext) -> WebSocket
         Some(Payload::CreateWorkItemRequest(req)) => handle_create(req, ctx).await,
         Some(Payload::UpdateWorkItemRequest(req)) => handle_update(req, ctx).await,
         Some(Payload::DeleteWorkItemRequest(req)) => handle_delete(req, ctx).await,
+        Some(Payload::MoveWorkItemRequest(req)) => handle_move(req, ctx).await,
         Some(Payload::GetWorkItemsRequest(req)) => handle_get_work_items(req, ctx).await,
 
         // Project handlers
@@ -115,6 +119,9 @@ async fn dispatch_inner(msg: WebSocketMessage, ctx: HandlerContext) -> WebSocket
 
         // LLM Context handlers
         Some(Payload::GetLlmContextRequest(req)) => handle_get_llm_context(req, ctx).await,
+        Some(Payload::CreateLlmContextRequest(req)) => handle_create_llm_context(req, ctx).await,
+        Some(Payload::UpdateLlmContextRequest(req)) => handle_update_llm_context(req, ctx).await,
+        Some(Payload::DeleteLlmContextRequest(req)) => handle_delete_llm_context(req, ctx).await,
 
         // Ping/Pong
         Some(Payload::Ping(ping)) => {
@@ -160,6 +167,7 @@ fn payload_to_handler_name(payload: &Option<Payload>) -> &'static str {
         Some(Payload::CreateWorkItemRequest(_)) => "CreateWorkItem",
         Some(Payload::UpdateWorkItemRequest(_)) => "UpdateWorkItem",
         Some(Payload::DeleteWorkItemRequest(_)) => "DeleteWorkItem",
+        Some(Payload::MoveWorkItemRequest(_)) => "MoveWorkItem",
         Some(Payload::GetWorkItemsRequest(_)) => "GetWorkItems",
 
         // Projects
@@ -192,6 +200,9 @@ fn payload_to_handler_name(payload: &Option<Payload>) -> &'static str {
 
         // LLM Context
         Some(Payload::GetLlmContextRequest(_)) => "GetLlmContext",
+        Some(Payload::CreateLlmContextRequest(_)) => "CreateLlmContext",
+        Some(Payload::UpdateLlmContextRequest(_)) => "UpdateLlmContext",
+        Some(Payload::DeleteLlmContextRequest(_)) => "DeleteLlmContext",
 
         _ => "Unknown",
     }
@@ -0,0 +1,65 @@
+//! Estimate roll-ups for work item responses.
+//!
+//! Logged time lives in pm_time_entries, so populating the proto
+//! `estimate` field takes one aggregate query per project on top of
+//! the item set already loaded for hierarchy computation.
+
+use pm_core::{EstimateRollup, TimeEntry, WorkItem};
+use pm_db::TimeEntryRepository;
+use pm_proto::FieldChange;
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use log::warn;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+/// Load completed logged time per work item for a project.
+///
+/// Graceful degradation: on failure an empty map is returned so the
+/// calling operation still succeeds with zero logged time.
+pub async fn load_logged_seconds(pool: &SqlitePool, project_id: Uuid) -> HashMap<Uuid, i64> {
+    TimeEntryRepository::new(pool.clone())
+        .logged_seconds_by_project(project_id)
+        .await
+        .unwrap_or_else(|e| {
+            warn!("Failed to load logged time for estimate roll-up: {}", e);
+            HashMap::new()
+        })
+}
+
+/// Compute the estimate roll-up for a SINGLE item given all project items.
+pub fn compute_estimate_for_item(
+    items: &[WorkItem],
+    logged_seconds: &HashMap<Uuid, i64>,
+    item_id: Uuid,
+) -> Option<EstimateRollup> {
+    EstimateRollup::compute(items, logged_seconds).remove(&item_id)
+}
+
+/// Apply a stopped timer to its work item's remaining estimate.
+///
+/// The remaining estimate is decremented by the entry's duration, or set to
+/// `override_seconds` when the user supplied one. When the estimate changes
+/// the item's version and audit fields are bumped and the change is returned
+/// so the caller can persist the item and record it in the activity log.
+pub fn apply_stopped_timer(
+    work_item: &mut WorkItem,
+    entry: &TimeEntry,
+    override_seconds: Option<i32>,
+    user_id: Uuid,
+) -> Option<FieldChange> {
+    let logged = entry.duration_seconds.unwrap_or(0);
+    let (old, new) = work_item.apply_logged_time(logged, override_seconds)?;
+
+    work_item.updated_at = Utc::now();
+    work_item.updated_by = user_id;
+    work_item.version += 1;
+
+    Some(FieldChange {
+        field_name: "remaining_estimate_seconds".to_string(),
+        old_value: old.map(|v| v.to_string()),
+        new_value: Some(new.to_string()),
+    })
+}
@@ -62,12 +62,7 @@ pub async fn validate_hierarchy(
             location: ErrorLocation::from(Location::caller()),
         })?;
 
-    let valid = matches!(
-        (parent.item_type.clone(), child_type.clone()),
-        (WorkItemType::Epic, WorkItemType::Story) | (WorkItemType::Story, WorkItemType::Task)
-    );
-
-    if !valid {
+    if !is_valid_parent(&parent.item_type, &child_type) {
         return Err(WsError::ValidationError {
             message: format!(
                 "Invalid hierarchy: {child_type:?} cannot be a child of {:?}",
@@ -80,3 +75,11 @@ pub async fn validate_hierarchy(
 
     Ok(())
 }
+
+/// Whether a `child` may sit directly under a `parent` (Epic → Story → Task)
+pub fn is_valid_parent(parent: &WorkItemType, child: &WorkItemType) -> bool {
+    matches!(
+        (parent, child),
+        (WorkItemType::Epic, WorkItemType::Story) | (WorkItemType::Story, WorkItemType::Task)
+    )
+}
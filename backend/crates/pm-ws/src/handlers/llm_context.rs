@@ -1,25 +1,311 @@
-use crate::{HandlerContext, Result as WsErrorResult, build_llm_context_list_response, db_read};
+use crate::{
+    HandlerContext, Result as WsErrorResult, WsError, build_activity_log_created_event,
+    build_llm_context_created_response, build_llm_context_deleted_response,
+    build_llm_context_list_response, build_llm_context_updated_response, check_idempotency,
+    check_permission, db_read, db_write, decode_cached_response, sanitize_string,
+    store_idempotency_non_fatal,
+};
 
-use pm_db::LlmContextRepository;
-use pm_proto::{GetLlmContextRequest, WebSocketMessage};
+use pm_core::{ActivityLog, LlmContext, LlmContextType, Permission};
+use pm_db::{ActivityLogRepository, LlmContextRepository};
+use pm_proto::{
+    CreateLlmContextRequest, DeleteLlmContextRequest, GetLlmContextRequest,
+    UpdateLlmContextRequest, WebSocketMessage,
+};
+
+use std::panic::Location;
+use std::str::FromStr;
+
+use axum::extract::ws::Message;
+use chrono::Utc;
+use error_location::ErrorLocation;
+use log::{debug, info, warn};
+use prost::Message as ProstMessage;
+use uuid::Uuid;
+
+fn parse_uuid(s: &str, field: &str) -> WsErrorResult<Uuid> {
+    Uuid::parse_str(s).map_err(|_| WsError::ValidationError {
+        message: format!("Invalid UUID format for {}", field),
+        field: Some(field.to_string()),
+        location: ErrorLocation::from(Location::caller()),
+    })
+}
+
+/// Trimmed optional text; blank means "none"
+fn optional_text(value: Option<&str>) -> Option<String> {
+    value.map(sanitize_string).filter(|v| !v.is_empty())
+}
+
+/// Project entries need Edit on their project; global entries are shared
+/// by every user.
+async fn check_entry_permission(
+    ctx: &HandlerContext,
+    project_id: Option<Uuid>,
+    required: Permission,
+) -> WsErrorResult<()> {
+    match project_id {
+        Some(project_id) => {
+            db_read(ctx, "check_permission", || async {
+                check_permission(ctx, project_id, required).await
+            })
+            .await
+        }
+        None => Ok(()),
+    }
+}
+
+/// Record the change and notify project subscribers (global entries have no
+/// project channel, so only the caller sees the response).
+async fn broadcast_change(
+    ctx: &HandlerContext,
+    entry: &LlmContext,
+    activity: &ActivityLog,
+    event: WebSocketMessage,
+) -> WsErrorResult<()> {
+    let Some(project_id) = entry.project_id else {
+        return Ok(());
+    };
+    let project_id_str = project_id.to_string();
+
+    let activity_event = build_activity_log_created_event(activity);
+    ctx.registry
+        .broadcast_activity_log_created(
+            &project_id_str,
+            None,
+            None,
+            Message::Binary(activity_event.encode_to_vec().into()),
+        )
+        .await?;
+
+    if let Err(e) = ctx
+        .registry
+        .broadcast_to_project(
+            &project_id_str,
+            Message::Binary(event.encode_to_vec().into()),
+        )
+        .await
+    {
+        warn!(
+            "{} Failed to broadcast LLM context change: {}",
+            ctx.log_prefix(),
+            e
+        );
+    }
+
+    Ok(())
+}
+
+async fn find_entry(ctx: &HandlerContext, id: Uuid) -> WsErrorResult<LlmContext> {
+    db_read(ctx, "find_llm_context", || async {
+        LlmContextRepository::find_by_id(&ctx.pool, id)
+            .await
+            .map_err(WsError::from)
+    })
+    .await?
+    .ok_or_else(|| WsError::NotFound {
+        message: format!("LLM context entry {} not found", id),
+        location: ErrorLocation::from(Location::caller()),
+    })
+}
 
 pub async fn handle_get_llm_context(
     req: GetLlmContextRequest,
     ctx: HandlerContext,
 ) -> WsErrorResult<WebSocketMessage> {
-    log::debug!("{} GetLlmContext starting", ctx.log_prefix());
+    debug!("{} GetLlmContext starting", ctx.log_prefix());
+
+    let project_id = req
+        .project_id
+        .as_deref()
+        .map(|id| parse_uuid(id, "project_id"))
+        .transpose()?;
+    if let Some(project_id) = project_id {
+        db_read(&ctx, "check_permission", || async {
+            check_permission(&ctx, project_id, Permission::View).await
+        })
+        .await?;
+    }
 
     let entries = db_read(&ctx, "list_llm_context", || async {
         LlmContextRepository::list_filtered(
             &ctx.pool,
+            project_id,
             req.category.as_deref(),
             req.context_type.as_deref(),
             req.min_priority,
         )
         .await
-        .map_err(crate::WsError::from)
+        .map_err(WsError::from)
     })
     .await?;
 
     Ok(build_llm_context_list_response(&ctx.message_id, entries))
 }
+
+/// Create a global or project-specific LLM context entry
+pub async fn handle_create_llm_context(
+    req: CreateLlmContextRequest,
+    ctx: HandlerContext,
+) -> WsErrorResult<WebSocketMessage> {
+    debug!("{} CreateLlmContext starting", ctx.log_prefix());
+
+    // 1. Check idempotency
+    let cached = db_read(&ctx, "check_idempotency", || async {
+        check_idempotency(&ctx.pool, &ctx.message_id).await
+    })
+    .await?;
+    if let Some(cached_response) = cached {
+        info!("{} Returning cached idempotent response", ctx.log_prefix());
+        return decode_cached_response(&cached_response);
+    }
+
+    // 2. Build and validate the entry
+    let project_id = req
+        .project_id
+        .as_deref()
+        .map(|id| parse_uuid(id, "project_id"))
+        .transpose()?;
+    let mut entry = LlmContext::new(
+        LlmContextType::from_str(&req.context_type)?,
+        sanitize_string(&req.category),
+        sanitize_string(&req.title),
+        sanitize_string(&req.content),
+    );
+    entry.project_id = project_id;
+    entry.example_sql = optional_text(req.example_sql.as_deref());
+    entry.example_description = optional_text(req.example_description.as_deref());
+    entry.priority = req.priority;
+    entry.validate()?;
+
+    // 3. Authorization
+    check_entry_permission(&ctx, project_id, Permission::Edit).await?;
+
+    // 4. Persist with activity log
+    let activity = ActivityLog::created("llm_context", entry.id, ctx.user_id);
+    db_write(&ctx, "create_llm_context_tx", || async {
+        let mut tx = ctx.pool.begin().await?;
+        LlmContextRepository::create(&mut *tx, &entry).await?;
+        ActivityLogRepository::create(&mut *tx, &activity).await?;
+        tx.commit().await?;
+        Ok::<_, WsError>(())
+    })
+    .await?;
+
+    // 5. Broadcast and respond
+    let event =
+        build_llm_context_created_response(&Uuid::new_v4().to_string(), &entry, ctx.user_id);
+    broadcast_change(&ctx, &entry, &activity, event).await?;
+
+    let response = build_llm_context_created_response(&ctx.message_id, &entry, ctx.user_id);
+    store_idempotency_non_fatal(&ctx.pool, &ctx.message_id, "create_llm_context", &response).await;
+
+    info!(
+        "{} Created LLM context entry {} ({})",
+        ctx.log_prefix(),
+        entry.id,
+        entry.context_type.as_str()
+    );
+
+    Ok(response)
+}
+
+/// Update an LLM context entry. Only the given fields change.
+pub async fn handle_update_llm_context(
+    req: UpdateLlmContextRequest,
+    ctx: HandlerContext,
+) -> WsErrorResult<WebSocketMessage> {
+    debug!("{} UpdateLlmContext starting", ctx.log_prefix());
+
+    let id = parse_uuid(&req.id, "id")?;
+    let mut entry = find_entry(&ctx, id).await?;
+    check_entry_permission(&ctx, entry.project_id, Permission::Edit).await?;
+
+    // Apply the given fields
+    if let Some(context_type) = &req.context_type {
+        entry.context_type = LlmContextType::from_str(context_type)?;
+    }
+    if let Some(category) = &req.category {
+        entry.category = sanitize_string(category);
+    }
+    if let Some(title) = &req.title {
+        entry.title = sanitize_string(title);
+    }
+    if let Some(content) = &req.content {
+        entry.content = sanitize_string(content);
+    }
+    if req.example_sql.is_some() {
+        entry.example_sql = optional_text(req.example_sql.as_deref());
+    }
+    if req.example_description.is_some() {
+        entry.example_description = optional_text(req.example_description.as_deref());
+    }
+    if let Some(priority) = req.priority {
+        entry.priority = priority;
+    }
+    entry.updated_at = Utc::now();
+    entry.validate()?;
+
+    let activity = ActivityLog::updated("llm_context", entry.id, ctx.user_id, &[]);
+    db_write(&ctx, "update_llm_context_tx", || async {
+        let mut tx = ctx.pool.begin().await?;
+        LlmContextRepository::update(&mut *tx, &entry).await?;
+        ActivityLogRepository::create(&mut *tx, &activity).await?;
+        tx.commit().await?;
+        Ok::<_, WsError>(())
+    })
+    .await?;
+
+    let event =
+        build_llm_context_updated_response(&Uuid::new_v4().to_string(), &entry, ctx.user_id);
+    broadcast_change(&ctx, &entry, &activity, event).await?;
+
+    info!(
+        "{} Updated LLM context entry {}",
+        ctx.log_prefix(),
+        entry.id
+    );
+
+    Ok(build_llm_context_updated_response(
+        &ctx.message_id,
+        &entry,
+        ctx.user_id,
+    ))
+}
+
+/// Soft-delete an LLM context entry
+pub async fn handle_delete_llm_context(
+    req: DeleteLlmContextRequest,
+    ctx: HandlerContext,
+) -> WsErrorResult<WebSocketMessage> {
+    debug!("{} DeleteLlmContext starting", ctx.log_prefix());
+
+    let id = parse_uuid(&req.id, "id")?;
+    let entry = find_entry(&ctx, id).await?;
+    check_entry_permission(&ctx, entry.project_id, Permission::Edit).await?;
+
+    let activity = ActivityLog::deleted("llm_context", entry.id, ctx.user_id);
+    db_write(&ctx, "delete_llm_context_tx", || async {
+        let mut tx = ctx.pool.begin().await?;
+        LlmContextRepository::soft_delete(&mut *tx, entry.id, Utc::now().timestamp()).await?;
+        ActivityLogRepository::create(&mut *tx, &activity).await?;
+        tx.commit().await?;
+        Ok::<_, WsError>(())
+    })
+    .await?;
+
+    let event =
+        build_llm_context_deleted_response(&Uuid::new_v4().to_string(), &entry, ctx.user_id);
+    broadcast_change(&ctx, &entry, &activity, event).await?;
+
+    info!(
+        "{} Deleted LLM context entry {}",
+        ctx.log_prefix(),
+        entry.id
+    );
+
+    Ok(build_llm_context_deleted_response(
+        &ctx.message_id,
+        &entry,
+        ctx.user_id,
+    ))
+}
@@ -4,11 +4,13 @@ pub(crate) mod change_tracker;
 pub(crate) mod comment;
 pub(crate) mod connection;
 pub(crate) mod context;
+pub(crate) mod custom_fields;
 pub(crate) mod db_ops;
 pub(crate) mod dependency;
 pub(crate) mod dispatcher;
 pub(crate) mod error_boundary;
 pub(crate) mod error_codes;
+pub(crate) mod estimates;
 pub(crate) mod field_change_builder;
 pub(crate) mod hierarchy;
 pub(crate) mod hierarchy_validator;
@@ -16,8 +18,10 @@ pub(crate) mod idempotency;
 pub(crate) mod llm_context;
 pub(crate) mod project;
 pub(crate) mod query;
+pub(crate) mod ranking;
 pub(crate) mod response_builder;
 pub(crate) mod sprint;
 pub(crate) mod subscription;
 pub(crate) mod time_entry;
+pub(crate) mod version_check;
 pub(crate) mod work_item;
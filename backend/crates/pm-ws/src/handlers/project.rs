@@ -3,7 +3,8 @@
 use crate::{
     HandlerContext, MessageValidator, Result as WsResult, WsError, build_project_created_response,
     build_project_deleted_response, build_project_list_response, build_project_updated_response,
-    check_idempotency, db_read, db_write, log_handler_entry, sanitize_string, store_idempotency,
+    check_idempotency, check_version, db_read, db_write, log_handler_entry, sanitize_string,
+    store_idempotency,
 };
 
 use pm_core::{ActivityLog, Project, ProjectMember, ProjectStatus};
@@ -66,7 +67,15 @@ pub async fn handle_create(
     })
     .await?;
 
-    if existing.is_some() {
+    // A renamed project's old key still resolves to it
+    let alias_owner = db_read(&ctx, "check_key_alias", || async {
+        repo.find_id_by_key_alias(&key_upper)
+            .await
+            .map_err(WsError::from)
+    })
+    .await?;
+
+    if existing.is_some() || alias_owner.is_some() {
         return Err(WsError::ValidationError {
             message: format!("Project key '{}' already exists", key_upper),
             field: Some("key".to_string()),
@@ -178,12 +187,7 @@ pub async fn handle_update(
     })?;
 
     // 2. Optimistic locking
-    if project.version != req.expected_version {
-        return Err(WsError::ConflictError {
-            current_version: project.version,
-            location: ErrorLocation::from(Location::caller()),
-        });
-    }
+    check_version(project.version, req.expected_version)?;
 
     // 3. Validate updates
     if let Some(ref title) = req.title {
@@ -333,12 +337,7 @@ pub async fn handle_delete(
     })?;
 
     // 3. Optimistic locking
-    if project.version != req.expected_version {
-        return Err(WsError::ConflictError {
-            current_version: project.version,
-            location: ErrorLocation::from(Location::caller()),
-        });
-    }
+    check_version(project.version, req.expected_version)?;
 
     // 4. Check no work items exist
     let pool_clone = ctx.pool.clone();
@@ -5,7 +5,7 @@ use crate::{
 
 use error_location::ErrorLocation;
 use pm_core::Permission;
-use pm_db::WorkItemRepository;
+use pm_db::{TimeEntryRepository, WorkItemRepository};
 use pm_proto::{GetWorkItemsRequest, WebSocketMessage};
 
 use std::panic::Location;
@@ -42,6 +42,15 @@ pub async fn handle_get_work_items(
     })
     .await?;
 
+    // 3b. Fetch logged time for estimate roll-ups
+    let logged_seconds = db_read(&ctx, "logged_seconds_by_project", || async {
+        TimeEntryRepository::new(ctx.pool.clone())
+            .logged_seconds_by_project(project_id)
+            .await
+            .map_err(WsError::from)
+    })
+    .await?;
+
     info!(
         count = work_items.len(),
         project_id = %project_id,
@@ -57,6 +66,7 @@ pub async fn handle_get_work_items(
         &ctx.message_id,
         &work_items,
         &work_items,
+        &logged_seconds,
         chrono::Utc::now().timestamp(),
     ))
 }
@@ -0,0 +1,287 @@
+//! Backlog rank helpers shared by the WebSocket and REST work item handlers.
+//!
+//! Creating or moving a work item writes only that item's rank. Ranks that
+//! grow too long are respread across their project in the background; tied
+//! or unranked neighbours, which leave no gap to place an item in, are
+//! respread inline before retrying. A respread bumps the version of every
+//! row it rewrites and broadcasts them, so clients re-sort and ETags stay
+//! current.
+
+use crate::{
+    ConnectionRegistry, Result as WsErrorResult, WsError, build_work_item_updated_response,
+    compute_estimate_for_item, compute_hierarchy_for_item, load_logged_seconds,
+};
+
+use pm_core::{WorkItem, rank_between, rank_needs_rebalance, spread_ranks};
+use pm_db::WorkItemRepository;
+use pm_proto::FieldChange;
+
+use std::panic::Location;
+
+use axum::extract::ws::Message;
+use chrono::Utc;
+use error_location::ErrorLocation;
+use log::{info, warn};
+use prost::Message as ProstMessage;
+use sqlx::{SqliteConnection, SqlitePool};
+use uuid::Uuid;
+
+/// Work items a respread rewrote, each with its rank change. Broadcast them
+/// with [`broadcast_rebalanced`] once the transaction commits.
+pub type Rebalanced = Vec<(WorkItem, FieldChange)>;
+
+/// Rank for a new work item at the end of a project's backlog. Run it in
+/// the transaction that inserts the item, after that transaction has taken
+/// the write lock, so concurrent creates cannot read the same last rank.
+pub async fn next_rank(
+    conn: &mut SqliteConnection,
+    project_id: Uuid,
+    user_id: Uuid,
+) -> WsErrorResult<(String, Rebalanced)> {
+    let last = WorkItemRepository::find_last_rank(&mut *conn, project_id).await?;
+    if let Ok(rank) = rank_between(last.as_deref(), None) {
+        return Ok((rank, Vec::new()));
+    }
+
+    let rebalanced = rebalance_ranks(&mut *conn, project_id, user_id).await?;
+    let last = WorkItemRepository::find_last_rank(&mut *conn, project_id).await?;
+    Ok((rank_between(last.as_deref(), None)?, rebalanced))
+}
+
+/// `work_item` placed directly below `before_id` and/or directly above
+/// `after_id`. With one neighbour given, the other side is whatever is next
+/// to it in the backlog. The copy returned carries the new rank, and the
+/// current version if a respread rewrote the item on the way.
+pub async fn rank_for_move(
+    pool: &SqlitePool,
+    registry: &ConnectionRegistry,
+    work_item: &WorkItem,
+    before_id: Option<Uuid>,
+    after_id: Option<Uuid>,
+    user_id: Uuid,
+) -> WsErrorResult<WorkItem> {
+    if before_id.is_none() && after_id.is_none() {
+        return Err(WsError::ValidationError {
+            message: "Either before_id or after_id is required".to_string(),
+            field: Some("before_id".to_string()),
+            location: ErrorLocation::from(Location::caller()),
+        });
+    }
+
+    let out_of_order = || WsError::ValidationError {
+        message: "before_id must come before after_id in the backlog".to_string(),
+        field: Some("before_id".to_string()),
+        location: ErrorLocation::from(Location::caller()),
+    };
+
+    let mut placed = work_item.clone();
+    let (before, after) = neighbour_ranks(pool, work_item, before_id, after_id).await?;
+    if let Ok(rank) = rank_between(before.as_deref(), after.as_deref()) {
+        placed.rank = rank;
+        return Ok(placed);
+    }
+    // Respreading fixes ties and malformed ranks, not neighbours given the
+    // wrong way round; reject those before rewriting the whole backlog
+    if (before_id.is_some() && before_id == after_id)
+        || matches!((&before, &after), (Some(before), Some(after)) if before > after)
+    {
+        return Err(out_of_order());
+    }
+
+    let rebalanced = rebalance_project(pool, registry, work_item.project_id, user_id).await?;
+    if let Some((current, _)) = rebalanced.iter().find(|(item, _)| item.id == work_item.id) {
+        placed = current.clone();
+    }
+    let (before, after) = neighbour_ranks(pool, &placed, before_id, after_id).await?;
+    placed.rank = rank_between(before.as_deref(), after.as_deref()).map_err(|_| out_of_order())?;
+    Ok(placed)
+}
+
+/// Respread a project's ranks evenly inside the caller's transaction,
+/// keeping its order. Rewritten rows get a new version, `updated_at` and
+/// `updated_by`.
+pub async fn rebalance_ranks(
+    conn: &mut SqliteConnection,
+    project_id: Uuid,
+    user_id: Uuid,
+) -> WsErrorResult<Rebalanced> {
+    let mut items = WorkItemRepository::find_by_project(&mut *conn, project_id, true).await?;
+    items.sort_by(|a, b| (&a.rank, a.item_number).cmp(&(&b.rank, b.item_number)));
+
+    let ranks = spread_ranks(items.len());
+    let now = Utc::now();
+    let mut rebalanced = Vec::new();
+    for (mut item, rank) in items.into_iter().zip(ranks) {
+        if item.rank == rank {
+            continue;
+        }
+        let change = FieldChange {
+            field_name: "rank".to_string(),
+            old_value: Some(item.rank.clone()),
+            new_value: Some(rank.clone()),
+        };
+        item.rank = rank;
+        item.updated_at = now;
+        item.updated_by = user_id;
+        item.version += 1;
+        WorkItemRepository::update_rank(&mut *conn, &item).await?;
+        rebalanced.push((item, change));
+    }
+    Ok(rebalanced)
+}
+
+/// Respread a project's ranks in its own transaction and broadcast the
+/// rewritten rows
+pub async fn rebalance_project(
+    pool: &SqlitePool,
+    registry: &ConnectionRegistry,
+    project_id: Uuid,
+    user_id: Uuid,
+) -> WsErrorResult<Rebalanced> {
+    let mut tx = pool.begin().await?;
+    let rebalanced = rebalance_ranks(&mut tx, project_id, user_id).await?;
+    tx.commit().await?;
+
+    info!(
+        "Rebalanced ranks of {} work items in project {}",
+        rebalanced.len(),
+        project_id
+    );
+    broadcast_rebalanced(pool, registry, project_id, &rebalanced, user_id).await;
+    Ok(rebalanced)
+}
+
+/// Broadcast WorkItemUpdated for each row a committed respread rewrote
+pub async fn broadcast_rebalanced(
+    pool: &SqlitePool,
+    registry: &ConnectionRegistry,
+    project_id: Uuid,
+    rebalanced: &Rebalanced,
+    user_id: Uuid,
+) {
+    if rebalanced.is_empty() {
+        return;
+    }
+
+    let all_items = WorkItemRepository::find_by_project(pool, project_id, true)
+        .await
+        .unwrap_or_else(|e| {
+            warn!("Failed to fetch items for hierarchy computation: {}", e);
+            vec![]
+        });
+    let logged_seconds = load_logged_seconds(pool, project_id).await;
+    let project_id_str = project_id.to_string();
+
+    for (item, change) in rebalanced {
+        let hierarchy = compute_hierarchy_for_item(&all_items, item.id);
+        let estimate = compute_estimate_for_item(&all_items, &logged_seconds, item.id);
+        let broadcast = build_work_item_updated_response(
+            &Uuid::new_v4().to_string(),
+            item,
+            std::slice::from_ref(change),
+            user_id,
+            hierarchy.ancestor_ids,
+            hierarchy.descendant_ids,
+            estimate,
+        );
+        if let Err(e) = registry
+            .broadcast_to_project(
+                &project_id_str,
+                Message::Binary(broadcast.encode_to_vec().into()),
+            )
+            .await
+        {
+            warn!("Failed to broadcast rebalanced WorkItemUpdated: {}", e);
+        }
+    }
+}
+
+/// Rebalance a project in the background once `rank` has grown too long
+pub fn schedule_rebalance(
+    pool: &SqlitePool,
+    registry: &ConnectionRegistry,
+    project_id: Uuid,
+    user_id: Uuid,
+    rank: &str,
+) {
+    if !rank_needs_rebalance(rank) {
+        return;
+    }
+
+    let pool = pool.clone();
+    let registry = registry.clone();
+    tokio::spawn(async move {
+        if let Err(e) = rebalance_project(&pool, &registry, project_id, user_id).await {
+            warn!("Failed to rebalance ranks in project {}: {}", project_id, e);
+        }
+    });
+}
+
+async fn neighbour_ranks(
+    pool: &SqlitePool,
+    work_item: &WorkItem,
+    before_id: Option<Uuid>,
+    after_id: Option<Uuid>,
+) -> WsErrorResult<(Option<String>, Option<String>)> {
+    let before = match before_id {
+        Some(id) => Some(neighbour(pool, work_item, id, "before_id").await?.rank),
+        None => None,
+    };
+    let after = match after_id {
+        Some(id) => Some(neighbour(pool, work_item, id, "after_id").await?.rank),
+        None => None,
+    };
+
+    Ok(match (before, after) {
+        (Some(before), None) => {
+            let after = WorkItemRepository::find_rank_after(
+                pool,
+                work_item.project_id,
+                &before,
+                work_item.id,
+            )
+            .await?;
+            (Some(before), after)
+        }
+        (None, Some(after)) => {
+            let before = WorkItemRepository::find_rank_before(
+                pool,
+                work_item.project_id,
+                &after,
+                work_item.id,
+            )
+            .await?;
+            (before, Some(after))
+        }
+        both => both,
+    })
+}
+
+async fn neighbour(
+    pool: &SqlitePool,
+    work_item: &WorkItem,
+    id: Uuid,
+    field: &str,
+) -> WsErrorResult<WorkItem> {
+    let invalid = |message: String| WsError::ValidationError {
+        message,
+        field: Some(field.to_string()),
+        location: ErrorLocation::from(Location::caller()),
+    };
+
+    if id == work_item.id {
+        return Err(invalid(
+            "A work item cannot be placed next to itself".to_string(),
+        ));
+    }
+    let neighbour = WorkItemRepository::find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| invalid(format!("Work item {} not found", id)))?;
+    if neighbour.project_id != work_item.project_id {
+        return Err(invalid(format!(
+            "Work item {} is in a different project",
+            id
+        )));
+    }
+    Ok(neighbour)
+}
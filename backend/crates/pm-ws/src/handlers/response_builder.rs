@@ -1,40 +1,44 @@
-use crate::compute_hierarchy_maps;
+use crate::{compute_hierarchy_maps, encode_custom_field_values};
 
 use pm_core::{
-    ActivityLog, Comment, Dependency, DependencyType, LlmContext, Project, ProjectStatus, Sprint,
-    SprintStatus, TimeEntry, WorkItem,
+    ActivityLog, Comment, Dependency, DependencyType, EstimateRollup, LlmContext, Project,
+    ProjectStatus, Sprint, SprintStatus, TimeEntry, WorkItem,
 };
 use pm_proto::{
     ActivityLogCreated, ActivityLogEntry as ProtoActivityLogEntry, ActivityLogList,
     Comment as ProtoComment, CommentCreated, CommentDeleted, CommentUpdated, CommentsList,
     DependenciesList, Dependency as ProtoDependency, DependencyCreated, DependencyDeleted,
-    DependencyType as ProtoDependencyType, Error as PmProtoError, FieldChange,
-    LlmContextEntry as ProtoLlmContextEntry, LlmContextList, Project as ProtoProject,
-    ProjectCreated, ProjectDeleted, ProjectList, ProjectStatus as ProtoProjectStatus,
-    ProjectUpdated, RunningTimerResponse, Sprint as ProtoSprint, SprintCreated, SprintDeleted,
-    SprintStatus as ProtoSprintStatus, SprintUpdated, SprintsList, TimeEntriesList,
-    TimeEntry as ProtoTimeEntry, TimeEntryCreated, TimeEntryDeleted, TimeEntryUpdated,
-    TimerStarted, TimerStopped, WebSocketMessage, WorkItem as PmProtoWorkItem, WorkItemCreated,
-    WorkItemDeleted, WorkItemUpdated, WorkItemsList,
+    DependencyType as ProtoDependencyType, Error as PmProtoError,
+    EstimateRollup as ProtoEstimateRollup, FieldChange, LlmContextCreated, LlmContextDeleted,
+    LlmContextEntry as ProtoLlmContextEntry, LlmContextList, LlmContextUpdated,
+    Project as ProtoProject, ProjectCreated, ProjectDeleted, ProjectList,
+    ProjectStatus as ProtoProjectStatus, ProjectUpdated, RunningTimerResponse,
+    Sprint as ProtoSprint, SprintCreated, SprintDeleted, SprintStatus as ProtoSprintStatus,
+    SprintUpdated, SprintsList, TimeEntriesList, TimeEntry as ProtoTimeEntry, TimeEntryCreated,
+    TimeEntryDeleted, TimeEntryUpdated, TimerStarted, TimerStopped, WebSocketMessage,
+    WorkItem as PmProtoWorkItem, WorkItemCreated, WorkItemDeleted, WorkItemUpdated, WorkItemsList,
     web_socket_message::Payload::{
         ActivityLogCreated as ProtoActivityLogCreated, ActivityLogList as ProtoActivityLogList,
         CommentCreated as ProtoCommentCreated, CommentDeleted as ProtoCommentDeleted,
         CommentUpdated as ProtoCommentUpdated, CommentsList as ProtoCommentsList,
         DependenciesList as ProtoDependenciesList, DependencyCreated as ProtoDependencyCreated,
         DependencyDeleted as ProtoDependencyDeleted, Error as ProtoError,
-        LlmContextList as ProtoLlmContextList, ProjectCreated as ProtoProjectCreated,
-        ProjectDeleted as ProtoProjectDeleted, ProjectList as ProtoProjectList,
-        ProjectUpdated as ProtoProjectUpdated, RunningTimerResponse as ProtoRunningTimerResponse,
-        SprintCreated as ProtoSprintCreated, SprintDeleted as ProtoSprintDeleted,
-        SprintUpdated as ProtoSprintUpdated, SprintsList as ProtoSprintsList,
-        TimeEntriesList as ProtoTimeEntriesList, TimeEntryCreated as ProtoTimeEntryCreated,
-        TimeEntryDeleted as ProtoTimeEntryDeleted, TimeEntryUpdated as ProtoTimeEntryUpdated,
-        TimerStarted as ProtoTimerStarted, TimerStopped as ProtoTimerStopped,
-        WorkItemCreated as ProtoWorkItemCreated, WorkItemDeleted as ProtoWorkItemDeleted,
-        WorkItemUpdated as ProtoWorkItemUpdated, WorkItemsList as ProtoWorkItemsList,
+        LlmContextCreated as ProtoLlmContextCreated, LlmContextDeleted as ProtoLlmContextDeleted,
+        LlmContextList as ProtoLlmContextList, LlmContextUpdated as ProtoLlmContextUpdated,
+        ProjectCreated as ProtoProjectCreated, ProjectDeleted as ProtoProjectDeleted,
+        ProjectList as ProtoProjectList, ProjectUpdated as ProtoProjectUpdated,
+        RunningTimerResponse as ProtoRunningTimerResponse, SprintCreated as ProtoSprintCreated,
+        SprintDeleted as ProtoSprintDeleted, SprintUpdated as ProtoSprintUpdated,
+        SprintsList as ProtoSprintsList, TimeEntriesList as ProtoTimeEntriesList,
+        TimeEntryCreated as ProtoTimeEntryCreated, TimeEntryDeleted as ProtoTimeEntryDeleted,
+        TimeEntryUpdated as ProtoTimeEntryUpdated, TimerStarted as ProtoTimerStarted,
+        TimerStopped as ProtoTimerStopped, WorkItemCreated as ProtoWorkItemCreated,
+        WorkItemDeleted as ProtoWorkItemDeleted, WorkItemUpdated as ProtoWorkItemUpdated,
+        WorkItemsList as ProtoWorkItemsList,
     },
 };
 
+use std::collections::HashMap;
 #[cfg(debug_assertions)]
 use std::collections::HashSet;
 
@@ -48,12 +52,18 @@ pub fn build_work_item_created_response(
     actor_id: Uuid,
     ancestor_ids: Vec<String>,
     descendant_ids: Vec<String>,
+    estimate: Option<EstimateRollup>,
 ) -> WebSocketMessage {
     WebSocketMessage {
         message_id: message_id.to_string(),
         timestamp: Utc::now().timestamp(),
         payload: Some(ProtoWorkItemCreated(WorkItemCreated {
-            work_item: Some(work_item_to_proto(work_item, ancestor_ids, descendant_ids)),
+            work_item: Some(work_item_to_proto(
+                work_item,
+                ancestor_ids,
+                descendant_ids,
+                estimate,
+            )),
             user_id: actor_id.to_string(),
         })),
     }
@@ -67,12 +77,18 @@ pub fn build_work_item_updated_response(
     actor_id: Uuid,
     ancestor_ids: Vec<String>,
     descendant_ids: Vec<String>,
+    estimate: Option<EstimateRollup>,
 ) -> WebSocketMessage {
     WebSocketMessage {
         message_id: message_id.to_string(),
         timestamp: Utc::now().timestamp(),
         payload: Some(ProtoWorkItemUpdated(WorkItemUpdated {
-            work_item: Some(work_item_to_proto(work_item, ancestor_ids, descendant_ids)),
+            work_item: Some(work_item_to_proto(
+                work_item,
+                ancestor_ids,
+                descendant_ids,
+                estimate,
+            )),
             changes: changes.to_vec(),
             user_id: actor_id.to_string(),
         })),
@@ -100,7 +116,8 @@ pub fn build_work_item_deleted_response(
 /// **Contract:** `all_project_items` MUST be the full unfiltered item set
 /// from `find_by_project(pool, project_id, true)`. Hierarchy is computed
 /// from this full set. `work_items` controls which items appear in the
-/// response — they may be a filtered subset.
+/// response — they may be a filtered subset. `logged_seconds` holds the
+/// project's logged time per item for the estimate roll-up.
 ///
 /// A debug assertion validates that every item in `work_items` exists in
 /// `all_project_items`, catching contract violations during development.
@@ -108,6 +125,7 @@ pub fn build_work_items_list_response(
     message_id: &str,
     work_items: &[WorkItem],
     all_project_items: &[WorkItem],
+    logged_seconds: &HashMap<Uuid, i64>,
     as_of_timestamp: i64,
 ) -> WebSocketMessage {
     // Validate subset contract in debug builds
@@ -122,6 +140,7 @@ pub fn build_work_items_list_response(
 
     // Compute hierarchy from the FULL project set — O(N)
     let hierarchy = compute_hierarchy_maps(all_project_items);
+    let mut estimates = EstimateRollup::compute(all_project_items, logged_seconds);
 
     let proto_items = work_items
         .iter()
@@ -130,7 +149,7 @@ pub fn build_work_items_list_response(
                 .get(&item.id)
                 .map(|h| (h.ancestor_ids.clone(), h.descendant_ids.clone()))
                 .unwrap_or_default();
-            work_item_to_proto(item, ancestors, descendants)
+            work_item_to_proto(item, ancestors, descendants, estimates.remove(&item.id))
         })
         .collect();
 
@@ -153,11 +172,12 @@ pub fn build_error_response(message_id: &str, error: PmProtoError) -> WebSocketM
     }
 }
 
-/// Convert domain WorkItem to proto WorkItem with hierarchy data.
+/// Convert domain WorkItem to proto WorkItem with hierarchy and estimate data.
 fn work_item_to_proto(
     item: &WorkItem,
     ancestor_ids: Vec<String>,
     descendant_ids: Vec<String>,
+    estimate: Option<EstimateRollup>,
 ) -> PmProtoWorkItem {
     PmProtoWorkItem {
         id: item.id.to_string(),
@@ -171,6 +191,7 @@ fn work_item_to_proto(
         assignee_id: item.assignee_id.map(|id| id.to_string()),
         story_points: item.story_points,
         position: item.position,
+        rank: item.rank.clone(),
         sprint_id: item.sprint_id.map(|id| id.to_string()),
         version: item.version,
         created_at: item.created_at.timestamp(),
@@ -181,6 +202,15 @@ fn work_item_to_proto(
         item_number: item.item_number,
         ancestor_ids,
         descendant_ids,
+        custom_fields: encode_custom_field_values(&item.custom_fields),
+        original_estimate_seconds: item.original_estimate_seconds,
+        remaining_estimate_seconds: item.remaining_estimate_seconds,
+        estimate: estimate.map(|e| ProtoEstimateRollup {
+            original_estimate_seconds: e.original_estimate_seconds,
+            remaining_estimate_seconds: e.remaining_estimate_seconds,
+            logged_seconds: e.logged_seconds,
+            over_estimate: e.over_estimate,
+        }),
     }
 }
 
@@ -685,6 +715,7 @@ fn llm_context_to_proto(entry: &LlmContext) -> ProtoLlmContextEntry {
         example_sql: entry.example_sql.clone(),
         example_description: entry.example_description.clone(),
         priority: entry.priority,
+        project_id: entry.project_id.map(|id| id.to_string()),
     }
 }
 
@@ -700,3 +731,49 @@ pub fn build_llm_context_list_response(
         })),
     }
 }
+
+pub fn build_llm_context_created_response(
+    message_id: &str,
+    entry: &LlmContext,
+    actor_id: Uuid,
+) -> WebSocketMessage {
+    WebSocketMessage {
+        message_id: message_id.to_string(),
+        timestamp: Utc::now().timestamp(),
+        payload: Some(ProtoLlmContextCreated(LlmContextCreated {
+            entry: Some(llm_context_to_proto(entry)),
+            user_id: actor_id.to_string(),
+        })),
+    }
+}
+
+pub fn build_llm_context_updated_response(
+    message_id: &str,
+    entry: &LlmContext,
+    actor_id: Uuid,
+) -> WebSocketMessage {
+    WebSocketMessage {
+        message_id: message_id.to_string(),
+        timestamp: Utc::now().timestamp(),
+        payload: Some(ProtoLlmContextUpdated(LlmContextUpdated {
+            entry: Some(llm_context_to_proto(entry)),
+            user_id: actor_id.to_string(),
+        })),
+    }
+}
+
+pub fn build_llm_context_deleted_response(
+    message_id: &str,
+    entry: &LlmContext,
+    actor_id: Uuid,
+) -> WebSocketMessage {
+    WebSocketMessage {
+        message_id: message_id.to_string(),
+        timestamp: Utc::now().timestamp(),
+        payload: Some(ProtoLlmContextDeleted(LlmContextDeleted {
+            id: entry.id.to_string(),
+            project_id: entry.project_id.map(|id| id.to_string()),
+            user_id: actor_id.to_string(),
+        })),
+    }
+}
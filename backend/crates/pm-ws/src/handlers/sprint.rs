@@ -3,8 +3,8 @@
 use crate::{
     FieldChangeBuilder, HandlerContext, build_activity_log_created_event,
     build_sprint_created_response, build_sprint_deleted_response, build_sprint_updated_response,
-    build_sprints_list_response, check_idempotency, check_permission, db_read, db_write,
-    sanitize_string, store_idempotency,
+    build_sprints_list_response, check_idempotency, check_permission, check_version, db_read,
+    db_write, sanitize_string, store_idempotency,
 };
 use crate::{MessageValidator, Result as WsErrorResult, WsError};
 
@@ -214,12 +214,7 @@ pub async fn handle_update_sprint(
     .await?;
 
     // 4. Optimistic locking - version must match
-    if sprint.version != req.expected_version {
-        return Err(WsError::ConflictError {
-            current_version: sprint.version,
-            location: ErrorLocation::from(Location::caller()),
-        });
-    }
+    check_version(sprint.version, req.expected_version)?;
 
     // 5. Cannot modify completed sprints
     if sprint.status == SprintStatus::Completed {
@@ -1,12 +1,13 @@
 #![allow(dead_code)]
 
 use crate::{
-    HandlerContext, MessageValidator, Result as WsErrorResult, WsError,
+    HandlerContext, MessageValidator, Result as WsErrorResult, WsError, apply_stopped_timer,
     build_activity_log_created_event, build_running_timer_response,
     build_time_entries_list_response, build_time_entry_created_response,
     build_time_entry_deleted_response, build_time_entry_updated_response,
-    build_timer_started_response, build_timer_stopped_response, check_idempotency,
-    check_permission, db_read, db_write, decode_cached_response, sanitize_string,
+    build_timer_started_response, build_timer_stopped_response, build_work_item_updated_response,
+    check_idempotency, check_permission, compute_estimate_for_item, compute_hierarchy_for_item,
+    db_read, db_write, decode_cached_response, load_logged_seconds, sanitize_string,
     store_idempotency_non_fatal,
 };
 
@@ -24,7 +25,7 @@ use std::panic::Location;
 use axum::extract::ws::Message;
 use chrono::{DateTime, Utc};
 use error_location::ErrorLocation;
-use log::{debug, info};
+use log::{debug, info, warn};
 use prost::Message as ProstMessage;
 use uuid::Uuid;
 
@@ -177,8 +178,11 @@ pub async fn handle_stop_timer(
 ) -> WsErrorResult<WebSocketMessage> {
     debug!("{} StopTimer starting", ctx.log_prefix());
 
-    // 1. Parse time_entry_id
+    // 1. Parse time_entry_id and validate the estimate override
     let time_entry_id = parse_uuid(&req.time_entry_id, "time_entry_id")?;
+    if let Some(remaining) = req.remaining_estimate_seconds {
+        MessageValidator::validate_estimate_seconds(remaining, "remaining_estimate_seconds")?;
+    }
 
     // 2. Check idempotency
     let cached = db_read(&ctx, "check_idempotency", || async {
@@ -238,15 +242,9 @@ pub async fn handle_stop_timer(
         }],
     );
     let activity_clone = activity.clone();
-    db_write(&ctx, "stop_timer", || async {
-        let repo = TimeEntryRepository::new(ctx.pool.clone());
-        repo.update(&entry).await?;
-        ActivityLogRepository::create(&ctx.pool, &activity_clone).await?;
-        Ok::<_, WsError>(())
-    })
-    .await?;
 
-    let work_item = db_read(&ctx, "find_work_item_for_time_entry", || async {
+    // 6b. Apply the logged time to the work item's remaining estimate
+    let mut work_item = db_read(&ctx, "find_work_item_for_time_entry", || async {
         WorkItemRepository::find_by_id(&ctx.pool, entry.work_item_id)
             .await
             .map_err(WsError::from)
@@ -256,15 +254,86 @@ pub async fn handle_stop_timer(
         message: format!("Work item {} not found", entry.work_item_id),
         location: ErrorLocation::from(Location::caller()),
     })?;
+    let estimate_change = apply_stopped_timer(
+        &mut work_item,
+        &entry,
+        req.remaining_estimate_seconds,
+        ctx.user_id,
+    );
+    let estimate_activity = estimate_change.as_ref().map(|change| {
+        ActivityLog::updated(
+            "work_item",
+            work_item.id,
+            ctx.user_id,
+            std::slice::from_ref(change),
+        )
+    });
+
+    let work_item_clone = work_item.clone();
+    let estimate_activity_clone = estimate_activity.clone();
+    db_write(&ctx, "stop_timer", || async {
+        let repo = TimeEntryRepository::new(ctx.pool.clone());
+        repo.update(&entry).await?;
+        ActivityLogRepository::create(&ctx.pool, &activity_clone).await?;
+        if let Some(ref estimate_activity) = estimate_activity_clone {
+            let mut tx = ctx.pool.begin().await?;
+            WorkItemRepository::update(&mut *tx, &work_item_clone).await?;
+            ActivityLogRepository::create(&mut *tx, estimate_activity).await?;
+            tx.commit().await?;
+        }
+        Ok::<_, WsError>(())
+    })
+    .await?;
 
-    let event = build_activity_log_created_event(&activity);
-    let bytes = event.encode_to_vec();
-    let message = Message::Binary(bytes.into());
     let project_id_str = work_item.project_id.to_string();
     let work_item_id_str = work_item.id.to_string();
-    ctx.registry
-        .broadcast_activity_log_created(&project_id_str, Some(&work_item_id_str), None, message)
-        .await?;
+    for activity in std::iter::once(&activity).chain(estimate_activity.as_ref()) {
+        let event = build_activity_log_created_event(activity);
+        let bytes = event.encode_to_vec();
+        let message = Message::Binary(bytes.into());
+        ctx.registry
+            .broadcast_activity_log_created(&project_id_str, Some(&work_item_id_str), None, message)
+            .await?;
+    }
+
+    // 6c. Broadcast the new remaining estimate to project subscribers
+    if let Some(change) = estimate_change {
+        let all_items = WorkItemRepository::find_by_project(&ctx.pool, work_item.project_id, true)
+            .await
+            .unwrap_or_else(|e| {
+                warn!(
+                    "{} Failed to fetch items for hierarchy computation: {}",
+                    ctx.log_prefix(),
+                    e
+                );
+                vec![]
+            });
+        let hierarchy = compute_hierarchy_for_item(&all_items, work_item.id);
+        let logged_seconds = load_logged_seconds(&ctx.pool, work_item.project_id).await;
+        let broadcast = build_work_item_updated_response(
+            &Uuid::new_v4().to_string(),
+            &work_item,
+            &[change],
+            ctx.user_id,
+            hierarchy.ancestor_ids,
+            hierarchy.descendant_ids,
+            compute_estimate_for_item(&all_items, &logged_seconds, work_item.id),
+        );
+        if let Err(e) = ctx
+            .registry
+            .broadcast_to_project(
+                &project_id_str,
+                Message::Binary(broadcast.encode_to_vec().into()),
+            )
+            .await
+        {
+            warn!(
+                "{} Failed to broadcast WorkItemUpdated: {}",
+                ctx.log_prefix(),
+                e
+            );
+        }
+    }
 
     // 7. Build response
     let response = build_timer_stopped_response(&ctx.message_id, &entry, ctx.user_id);
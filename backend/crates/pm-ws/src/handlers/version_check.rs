@@ -0,0 +1,19 @@
+use crate::{Result as WsErrorResult, WsError};
+
+use std::panic::Location;
+
+use error_location::ErrorLocation;
+
+/// Optimistic locking check shared by the WebSocket handlers and the REST API.
+///
+/// Returns Ok(()) if the client saw the current version, Err(WsError::ConflictError) otherwise.
+#[track_caller]
+pub fn check_version(current_version: i32, expected_version: i32) -> WsErrorResult<()> {
+    if current_version != expected_version {
+        return Err(WsError::ConflictError {
+            current_version,
+            location: ErrorLocation::from(Location::caller()),
+        });
+    }
+    Ok(())
+}
@@ -1,20 +1,22 @@
 use crate::{
-    HandlerContext, MessageValidator, Result as WsErrorResult, WsError,
+    HandlerContext, MessageValidator, Result as WsErrorResult, WsError, broadcast_rebalanced,
     build_activity_log_created_event, build_work_item_created_response,
     build_work_item_deleted_response, build_work_item_updated_response, check_idempotency,
-    check_permission, compute_hierarchy_for_item, db_read, db_write, store_idempotency,
-    track_changes, validate_hierarchy,
+    check_permission, check_version, compute_estimate_for_item, compute_hierarchy_for_item,
+    db_read, db_write, decode_custom_field_values, load_logged_seconds, next_rank, rank_for_move,
+    resolve_custom_field_values, schedule_rebalance, store_idempotency, track_changes,
+    track_custom_field_changes, validate_hierarchy,
 };
 
 use pm_config::ValidationConfig;
-use pm_core::{ActivityLog, Permission, WorkItem, WorkItemType};
+use pm_core::{ActivityLog, CustomFieldValues, Permission, WorkItem, WorkItemType};
 use pm_db::{ActivityLogRepository, ProjectRepository, WorkItemRepository};
 use pm_proto::{
-    CreateWorkItemRequest, DeleteWorkItemRequest, UpdateWorkItemRequest, WebSocketMessage,
-    WorkItemType as ProtoWorkItemType,
+    CreateWorkItemRequest, DeleteWorkItemRequest, FieldChange, MoveWorkItemRequest,
+    UpdateWorkItemRequest, WebSocketMessage, WorkItemType as ProtoWorkItemType,
 };
 
-use std::panic::Location;
+use std::{collections::HashMap, panic::Location};
 
 use axum::extract::ws::Message;
 use base64::Engine;
@@ -55,6 +57,9 @@ pub async fn handle_create(
         item_type.as_str(),
         &ctx.validation,
     )?;
+    if let Some(estimate) = req.original_estimate_seconds {
+        MessageValidator::validate_estimate_seconds(estimate, "original_estimate_seconds")?;
+    }
 
     // 3. Check idempotency BEFORE any mutations (with circuit breaker)
     let cached = db_read(&ctx, "check_idempotency", || async {
@@ -100,6 +105,20 @@ pub async fn handle_create(
         .await?;
     }
 
+    // 6b. Validate custom field values (defaults fill in omitted fields)
+    let requested_custom_fields = decode_custom_field_values(&req.custom_fields)?;
+    let custom_fields = db_read(&ctx, "resolve_custom_fields", || async {
+        resolve_custom_field_values(
+            &ctx.pool,
+            project_id,
+            &CustomFieldValues::new(),
+            &requested_custom_fields,
+            true,
+        )
+        .await
+    })
+    .await?;
+
     // 7. Get next position
     let max_position = db_read(&ctx, "find_max_position", || async {
         WorkItemRepository::find_max_position(&ctx.pool, project_id, parent_id)
@@ -116,13 +135,17 @@ pub async fn handle_create(
         parent_id,
         project_id,
         position: (max_position + 1) as i32,
+        rank: String::new(), // Set by transaction
         title: sanitize_string(&req.title),
         description: req.description.as_ref().map(|d| sanitize_string(d)),
         status: req.status.clone().unwrap_or_else(|| "backlog".to_string()),
         priority: req.priority.clone().unwrap_or_else(|| "medium".to_string()),
         assignee_id: None,
         story_points: None,
+        original_estimate_seconds: req.original_estimate_seconds,
+        remaining_estimate_seconds: req.original_estimate_seconds,
         sprint_id: None,
+        custom_fields,
         item_number: 0,
         version: 1,
         created_at: now,
@@ -132,13 +155,13 @@ pub async fn handle_create(
         deleted_at: None,
     };
 
-    // 9. Execute transaction with atomic item_number assignment
+    // 9. Execute transaction with atomic item_number and rank assignment
     let activity = ActivityLog::created("work_item", work_item.id, ctx.user_id);
     let activity_clone = activity.clone();
 
     let work_item_for_tx = work_item.clone();
 
-    let item_number = db_write(&ctx, "create_work_item_tx", || async {
+    let (item_number, rank, rebalanced) = db_write(&ctx, "create_work_item_tx", || async {
         let mut tx = ctx.pool.begin().await?;
 
         // Atomically get and increment the work item number
@@ -146,21 +169,34 @@ pub async fn handle_create(
             .await
             .map_err(WsError::from)?;
 
-        // Create work item with assigned number
+        // Rank it at the end of the backlog, now the write lock is held
+        let (rank, rebalanced) = next_rank(&mut tx, project_id, ctx.user_id).await?;
+
+        // Create work item with assigned number and rank
         let mut wi = work_item_for_tx.clone();
         wi.item_number = item_num;
+        wi.rank = rank.clone();
         WorkItemRepository::create(&mut *tx, &wi).await?;
 
         // Create activity log
         ActivityLogRepository::create(&mut *tx, &activity_clone).await?;
 
         tx.commit().await?;
-        Ok::<_, WsError>(item_num)
+        Ok::<_, WsError>((item_num, rank, rebalanced))
     })
     .await?;
 
-    // Update our local copy with the assigned number
+    // Update our local copy with the assigned number and rank
     work_item.item_number = item_number;
+    work_item.rank = rank;
+    broadcast_rebalanced(
+        &ctx.pool,
+        &ctx.registry,
+        project_id,
+        &rebalanced,
+        ctx.user_id,
+    )
+    .await;
 
     // Compute hierarchy for the newly created item.
     // Graceful degradation: if fetch fails, send empty hierarchy
@@ -176,6 +212,8 @@ pub async fn handle_create(
             vec![]
         });
     let hierarchy = compute_hierarchy_for_item(&all_items, work_item.id);
+    // A new item has no logged time or children yet
+    let estimate = compute_estimate_for_item(&all_items, &HashMap::new(), work_item.id);
 
     // 10. Broadcast ActivityLogCreated
 
@@ -196,6 +234,7 @@ pub async fn handle_create(
         ctx.user_id,
         hierarchy.ancestor_ids.clone(),
         hierarchy.descendant_ids.clone(),
+        estimate.clone(),
     );
     let broadcast_bytes = broadcast.encode_to_vec();
     if let Err(e) = ctx
@@ -217,6 +256,7 @@ pub async fn handle_create(
         ctx.user_id,
         hierarchy.ancestor_ids,
         hierarchy.descendant_ids,
+        estimate,
     );
 
     // 12. Store idempotency (after commit, failure here is non-fatal)
@@ -279,12 +319,7 @@ pub async fn handle_update(
     .await?;
 
     // 4. Optimistic locking
-    if work_item.version != req.expected_version {
-        return Err(WsError::ConflictError {
-            current_version: work_item.version,
-            location: ErrorLocation::from(Location::caller()),
-        });
-    }
+    check_version(work_item.version, req.expected_version)?;
 
     // 4b. Validate new parent if changing (uses update_parent flag)
     #[allow(clippy::collapsible_if)]
@@ -317,8 +352,26 @@ pub async fn handle_update(
         }
     }
 
+    // 4c. Validate custom field values against the project's definitions
+    let requested_custom_fields = decode_custom_field_values(&req.custom_fields)?;
+    let custom_fields = db_read(&ctx, "resolve_custom_fields", || async {
+        resolve_custom_field_values(
+            &ctx.pool,
+            work_item.project_id,
+            &work_item.custom_fields,
+            &requested_custom_fields,
+            false,
+        )
+        .await
+    })
+    .await?;
+
     // 5. Track changes
-    let changes = track_changes(&work_item, &req);
+    let mut changes = track_changes(&work_item, &req);
+    changes.extend(track_custom_field_changes(
+        &work_item.custom_fields,
+        &custom_fields,
+    ));
 
     if changes.is_empty() {
         // No changes — current DB state IS the correct state
@@ -333,6 +386,8 @@ pub async fn handle_update(
                 vec![]
             });
         let hierarchy = compute_hierarchy_for_item(&all_items, work_item.id);
+        let logged_seconds = load_logged_seconds(&ctx.pool, work_item.project_id).await;
+        let estimate = compute_estimate_for_item(&all_items, &logged_seconds, work_item.id);
 
         return Ok(build_work_item_updated_response(
             &ctx.message_id,
@@ -341,11 +396,13 @@ pub async fn handle_update(
             ctx.user_id,
             hierarchy.ancestor_ids,
             hierarchy.descendant_ids,
+            estimate,
         ));
     }
 
     // 6. Apply updates with validation
     apply_updates(&mut work_item, &req, &ctx.validation)?;
+    work_item.custom_fields = custom_fields;
 
     // 7. Update metadata
     let now = Utc::now();
@@ -381,6 +438,8 @@ pub async fn handle_update(
             vec![]
         });
     let hierarchy = compute_hierarchy_for_item(&all_items, work_item.id);
+    let logged_seconds = load_logged_seconds(&ctx.pool, work_item.project_id).await;
+    let estimate = compute_estimate_for_item(&all_items, &logged_seconds, work_item.id);
 
     // 9. Broadcast ActivityLogCreated
     let event = build_activity_log_created_event(&activity);
@@ -400,6 +459,7 @@ pub async fn handle_update(
         ctx.user_id,
         hierarchy.ancestor_ids.clone(),
         hierarchy.descendant_ids.clone(),
+        estimate.clone(),
     );
     let broadcast_bytes = broadcast.encode_to_vec();
     if let Err(e) = ctx
@@ -428,6 +488,160 @@ pub async fn handle_update(
         ctx.user_id,
         hierarchy.ancestor_ids,
         hierarchy.descendant_ids,
+        estimate,
+    ))
+}
+
+/// Handle MoveWorkItemRequest: re-rank one item between its new neighbours
+pub async fn handle_move(
+    req: MoveWorkItemRequest,
+    ctx: HandlerContext,
+) -> WsErrorResult<WebSocketMessage> {
+    debug!("{} MoveWorkItem starting", ctx.log_prefix());
+
+    // 1. Parse IDs
+    let work_item_id = parse_uuid(&req.work_item_id, "work_item_id")?;
+    let before_id = req
+        .before_id
+        .as_deref()
+        .map(|id| parse_uuid(id, "before_id"))
+        .transpose()?;
+    let after_id = req
+        .after_id
+        .as_deref()
+        .map(|id| parse_uuid(id, "after_id"))
+        .transpose()?;
+
+    // 2. Fetch existing
+    let mut work_item = db_read(&ctx, "find_work_item", || async {
+        WorkItemRepository::find_by_id(&ctx.pool, work_item_id)
+            .await
+            .map_err(WsError::from)
+    })
+    .await?
+    .ok_or_else(|| WsError::NotFound {
+        message: format!("Work item {} not found", work_item_id),
+        location: ErrorLocation::from(Location::caller()),
+    })?;
+
+    // 3. Authorization
+    db_read(&ctx, "check_permission", || async {
+        check_permission(&ctx, work_item.project_id, Permission::Edit).await
+    })
+    .await?;
+
+    // 4. Optimistic locking
+    check_version(work_item.version, req.expected_version)?;
+
+    // 5. Rank between the new neighbours (may rebalance ties first)
+    let placed = db_write(&ctx, "rank_for_move", || async {
+        rank_for_move(
+            &ctx.pool,
+            &ctx.registry,
+            &work_item,
+            before_id,
+            after_id,
+            ctx.user_id,
+        )
+        .await
+    })
+    .await?;
+
+    let changes = vec![FieldChange {
+        field_name: "rank".to_string(),
+        old_value: Some(work_item.rank.clone()),
+        new_value: Some(placed.rank.clone()),
+    }];
+    work_item = placed;
+    work_item.updated_at = Utc::now();
+    work_item.updated_by = ctx.user_id;
+    work_item.version += 1;
+
+    // 6. Transaction: only this item's row changes
+    let activity = ActivityLog::updated("work_item", work_item.id, ctx.user_id, &changes);
+    let work_item_clone = work_item.clone();
+    let activity_clone = activity.clone();
+    db_write(&ctx, "move_work_item_tx", || async {
+        let mut tx = ctx.pool.begin().await?;
+
+        WorkItemRepository::update_rank(&mut *tx, &work_item_clone).await?;
+        ActivityLogRepository::create(&mut *tx, &activity_clone).await?;
+
+        tx.commit().await?;
+        Ok::<_, WsError>(())
+    })
+    .await?;
+
+    schedule_rebalance(
+        &ctx.pool,
+        &ctx.registry,
+        work_item.project_id,
+        ctx.user_id,
+        &work_item.rank,
+    );
+
+    let all_items = WorkItemRepository::find_by_project(&ctx.pool, work_item.project_id, true)
+        .await
+        .unwrap_or_else(|e| {
+            warn!(
+                "{} Failed to fetch items for hierarchy computation: {}",
+                ctx.log_prefix(),
+                e
+            );
+            vec![]
+        });
+    let hierarchy = compute_hierarchy_for_item(&all_items, work_item.id);
+    let logged_seconds = load_logged_seconds(&ctx.pool, work_item.project_id).await;
+    let estimate = compute_estimate_for_item(&all_items, &logged_seconds, work_item.id);
+
+    // 7. Broadcast ActivityLogCreated and WorkItemUpdated
+    let event = build_activity_log_created_event(&activity);
+    let bytes = event.encode_to_vec();
+    let message = Message::Binary(bytes.into());
+    let project_id_str = work_item.project_id.to_string();
+    let work_item_id_str = work_item.id.to_string();
+    ctx.registry
+        .broadcast_activity_log_created(&project_id_str, Some(&work_item_id_str), None, message)
+        .await?;
+
+    let broadcast = build_work_item_updated_response(
+        &Uuid::new_v4().to_string(),
+        &work_item,
+        &changes,
+        ctx.user_id,
+        hierarchy.ancestor_ids.clone(),
+        hierarchy.descendant_ids.clone(),
+        estimate.clone(),
+    );
+    let broadcast_bytes = broadcast.encode_to_vec();
+    if let Err(e) = ctx
+        .registry
+        .broadcast_to_project(&project_id_str, Message::Binary(broadcast_bytes.into()))
+        .await
+    {
+        warn!(
+            "{} Failed to broadcast WorkItemUpdated: {}",
+            ctx.log_prefix(),
+            e
+        );
+    }
+
+    info!(
+        "{} Moved work item {} to rank {} (version {})",
+        ctx.log_prefix(),
+        work_item.id,
+        work_item.rank,
+        work_item.version
+    );
+
+    Ok(build_work_item_updated_response(
+        &ctx.message_id,
+        &work_item,
+        &changes,
+        ctx.user_id,
+        hierarchy.ancestor_ids,
+        hierarchy.descendant_ids,
+        estimate,
     ))
 }
 
@@ -600,6 +814,23 @@ fn apply_updates(
         }
         work_item.story_points = Some(story_points);
     }
+    if req.clear_estimate {
+        work_item.original_estimate_seconds = None;
+        work_item.remaining_estimate_seconds = None;
+    } else {
+        if let Some(original) = req.original_estimate_seconds {
+            MessageValidator::validate_estimate_seconds(original, "original_estimate_seconds")?;
+            // Setting an estimate on unestimated work starts the remaining time at it
+            if work_item.remaining_estimate_seconds.is_none() {
+                work_item.remaining_estimate_seconds = Some(original);
+            }
+            work_item.original_estimate_seconds = Some(original);
+        }
+        if let Some(remaining) = req.remaining_estimate_seconds {
+            MessageValidator::validate_estimate_seconds(remaining, "remaining_estimate_seconds")?;
+            work_item.remaining_estimate_seconds = Some(remaining);
+        }
+    }
     // Handle parent_id change (uses update_parent flag)
     if req.update_parent {
         work_item.parent_id = if let Some(parent_id) = req.parent_id.as_ref() {
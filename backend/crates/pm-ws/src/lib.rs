@@ -17,6 +17,7 @@ mod retry;
 mod shutdown_coordinator;
 mod shutdown_guard;
 mod subscription_filter;
+mod tenant_resolver;
 mod web_socket_connection;
 
 pub use app_state::{AppState, handler};
@@ -30,12 +31,15 @@ pub use connection_registry::ConnectionRegistry;
 pub use error::{Result, WsError};
 pub use handlers::{
     authorization::check_permission,
-    change_tracker::track_changes,
+    change_tracker::{track_changes, track_custom_field_changes, track_estimate_changes},
     comment::{
         handle_create_comment, handle_delete_comment, handle_get_comments, handle_update_comment,
     },
     connection::extract_user_id,
     context::HandlerContext,
+    custom_fields::{
+        decode_custom_field_values, encode_custom_field_values, resolve_custom_field_values,
+    },
     db_ops::{db_read, db_transaction, db_write},
     dependency::{handle_create_dependency, handle_delete_dependency, handle_get_dependencies},
     dispatcher::dispatch,
@@ -44,9 +48,10 @@ pub use handlers::{
         CONFLICT, DELETE_BLOCKED, INTERNAL_ERROR, INVALID_MESSAGE, NOT_FOUND, RATE_LIMITED,
         UNAUTHORIZED, VALIDATION_ERROR,
     },
+    estimates::{apply_stopped_timer, compute_estimate_for_item, load_logged_seconds},
     field_change_builder::FieldChangeBuilder,
     hierarchy::{HierarchyData, compute_hierarchy_for_item, compute_hierarchy_maps},
-    hierarchy_validator::validate_hierarchy,
+    hierarchy_validator::{is_valid_parent, validate_hierarchy},
     idempotency::{
         check_idempotency, decode_cached_response, store_idempotency, store_idempotency_non_fatal,
     },
@@ -55,12 +60,18 @@ pub use handlers::{
         handle_list, handle_update as handle_update_project,
     },
     query::handle_get_work_items,
+    ranking::{
+        Rebalanced, broadcast_rebalanced, next_rank, rank_for_move, rebalance_project,
+        rebalance_ranks, schedule_rebalance,
+    },
     response_builder::{
         build_activity_log_created_event, build_activity_log_list_response,
         build_comment_created_response, build_comment_deleted_response,
         build_comment_updated_response, build_comments_list_response,
         build_dependencies_list_response, build_dependency_created_response,
-        build_dependency_deleted_response, build_error_response, build_llm_context_list_response,
+        build_dependency_deleted_response, build_error_response,
+        build_llm_context_created_response, build_llm_context_deleted_response,
+        build_llm_context_list_response, build_llm_context_updated_response,
         build_project_created_response, build_project_deleted_response,
         build_project_list_response, build_project_updated_response, build_running_timer_response,
         build_sprint_created_response, build_sprint_deleted_response,
@@ -79,9 +90,10 @@ pub use handlers::{
         handle_create_time_entry, handle_delete_time_entry, handle_get_running_timer,
         handle_get_time_entries, handle_start_timer, handle_stop_timer, handle_update_time_entry,
     },
+    version_check::check_version,
     work_item::{
-        handle_create, handle_delete, handle_update, sanitize_string, validate_priority,
-        validate_status,
+        handle_create, handle_delete, handle_move, handle_update, sanitize_string,
+        validate_priority, validate_status,
     },
 };
 pub use message_validator::MessageValidator;
@@ -93,6 +105,7 @@ pub use retry::{IsRetryable, RetryConfig, with_retry};
 pub use shutdown_coordinator::ShutdownCoordinator;
 pub use shutdown_guard::ShutdownGuard;
 pub use subscription_filter::SubscriptionFilter;
+pub use tenant_resolver::TenantResolver;
 pub use web_socket_connection::{MAX_VIOLATIONS, WebSocketConnection, WebSocketConnectionParams};
 
 #[cfg(test)]
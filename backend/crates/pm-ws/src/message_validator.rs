@@ -241,6 +241,19 @@ impl MessageValidator {
         Ok(())
     }
 
+    /// Validate an hour-based estimate (seconds, must not be negative)
+    #[track_caller]
+    pub fn validate_estimate_seconds(value: i32, field_name: &str) -> WsErrorResult<()> {
+        if value < 0 {
+            return Err(WsError::ValidationError {
+                message: format!("{} must not be negative", field_name),
+                field: Some(field_name.into()),
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+        Ok(())
+    }
+
     /// Validate time entry description (optional, max 1000 chars)
     #[track_caller]
     pub fn validate_time_entry_description(description: Option<&str>) -> WsErrorResult<()> {
@@ -0,0 +1,103 @@
+use crate::{Result as WsErrorResult, WsError};
+
+use pm_auth::{Claims, JwtValidator};
+use pm_config::TenantSource;
+use pm_db::{DbError, TenantPools};
+
+use std::panic::Location;
+
+use axum::http::{HeaderMap, header};
+use error_location::ErrorLocation;
+use sqlx::SqlitePool;
+
+/// Picks the tenant of a request and opens its database (ADR-0002)
+#[derive(Clone)]
+pub struct TenantResolver {
+    pub source: TenantSource,
+    pub pools: TenantPools,
+}
+
+impl TenantResolver {
+    pub fn new(source: TenantSource, pools: TenantPools) -> Self {
+        Self { source, pools }
+    }
+
+    /// The tenant named by the request: the JWT `tenant_id` claim or the
+    /// first label of the Host header, depending on the source. With
+    /// authentication enabled, a Host tenant must also match the token's
+    /// claim, so a token issued for one tenant cannot reach another.
+    pub fn tenant_id(
+        &self,
+        headers: &HeaderMap,
+        validator: Option<&JwtValidator>,
+    ) -> WsErrorResult<String> {
+        let tenant_id = match self.source {
+            TenantSource::Claim => {
+                let validator = validator.ok_or_else(|| WsError::Unauthorized {
+                    message: "Tenant claim requires authentication".into(),
+                    location: ErrorLocation::from(Location::caller()),
+                })?;
+                Self::claims(headers, validator)?.tenant_id
+            }
+            TenantSource::Host => {
+                let tenant_id = headers
+                    .get(header::HOST)
+                    .and_then(|h| h.to_str().ok())
+                    .and_then(Self::tenant_from_host);
+                if let (Some(host_tenant), Some(validator)) = (&tenant_id, validator) {
+                    let claimed = Self::claims(headers, validator)?.tenant_id;
+                    if !claimed.is_some_and(|claimed| claimed.eq_ignore_ascii_case(host_tenant)) {
+                        return Err(WsError::Forbidden {
+                            message: format!("Token was not issued for tenant {}", host_tenant),
+                            location: ErrorLocation::from(Location::caller()),
+                        });
+                    }
+                }
+                tenant_id
+            }
+        };
+
+        tenant_id.ok_or_else(|| WsError::Unauthorized {
+            message: format!("No tenant in request ({:?} source)", self.source),
+            location: ErrorLocation::from(Location::caller()),
+        })
+    }
+
+    /// The pool for `tenant_id`; unknown tenants are [`WsError::NotFound`]
+    pub async fn pool(&self, tenant_id: &str) -> WsErrorResult<SqlitePool> {
+        self.pools.pool(tenant_id).await.map_err(|e| match e {
+            DbError::TenantNotFound { tenant_id, .. } => WsError::NotFound {
+                message: format!("Tenant {} not found", tenant_id),
+                location: ErrorLocation::from(Location::caller()),
+            },
+            other => other.into(),
+        })
+    }
+
+    /// Claims of the request's bearer token
+    fn claims(headers: &HeaderMap, validator: &JwtValidator) -> WsErrorResult<Claims> {
+        let token = headers
+            .get(header::AUTHORIZATION)
+            .and_then(|h| h.to_str().ok())
+            .and_then(|h| h.strip_prefix("Bearer "))
+            .ok_or_else(|| WsError::Unauthorized {
+                message: "Missing bearer token".into(),
+                location: ErrorLocation::from(Location::caller()),
+            })?;
+        validator
+            .validate(token)
+            .map_err(|e| WsError::Unauthorized {
+                message: e.to_string(),
+                location: ErrorLocation::from(Location::caller()),
+            })
+    }
+
+    /// `acme.board.example.com:8000` → `acme`. A bare host or IP address
+    /// names no tenant.
+    pub fn tenant_from_host(host: &str) -> Option<String> {
+        let host = host.split(':').next()?;
+        let (label, rest) = host.split_once('.')?;
+        let is_ip = host.chars().all(|c| c.is_ascii_digit() || c == '.');
+        (!label.is_empty() && !rest.is_empty() && !is_ip).then(|| label.to_lowercase())
+    }
+}
@@ -68,6 +68,7 @@ fn given_auth_enabled_and_valid_jwt_when_extract_then_returns_uuid() {
         exp: 9999999999,
         iat: 1234567890,
         roles: vec![],
+        tenant_id: None,
     };
 
     let params = HashMap::new();
@@ -99,6 +100,7 @@ fn given_auth_enabled_and_invalid_uuid_in_jwt_when_extract_then_unauthorized() {
         exp: 9999999999,
         iat: 1234567890,
         roles: vec![],
+        tenant_id: None,
     };
 
     let params = HashMap::new();
@@ -20,13 +20,17 @@ fn test_item(id: Uuid, parent_id: Option<Uuid>) -> WorkItem {
         item_type: pm_core::WorkItemType::Task,
         project_id: Uuid::new_v4(),
         position: 0,
+        rank: String::new(),
         title: String::new(),
         description: None,
         status: "backlog".into(),
         priority: "medium".into(),
         assignee_id: None,
         story_points: None,
+        original_estimate_seconds: None,
+        remaining_estimate_seconds: None,
         sprint_id: None,
+        custom_fields: Default::default(),
         item_number: 0,
         version: 1,
         created_at: chrono::Utc::now(),
@@ -171,3 +171,10 @@ fn given_cjk_description_over_char_limit_when_validated_then_fails() {
     let result = MessageValidator::validate_work_item_create("title", Some(&desc), "task", &config);
     assert!(result.is_err());
 }
+
+#[test]
+fn given_negative_estimate_when_validated_then_fails() {
+    let result = MessageValidator::validate_estimate_seconds(-1, "original_estimate_seconds");
+    assert!(result.is_err());
+    assert!(MessageValidator::validate_estimate_seconds(0, "original_estimate_seconds").is_ok());
+}
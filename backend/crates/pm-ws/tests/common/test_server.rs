@@ -121,12 +121,14 @@ async fn create_app(config: TestServerConfig) -> (Router, AppState) {
     let rate_limit_config = RateLimitConfig {
         max_requests: config.rate_limit_max_requests,
         window_secs: config.rate_limit_window_secs,
+        ..Default::default()
     };
     let rate_limiter_factory = RateLimiterFactory::new(rate_limit_config);
 
     // Create connection registry with limits
     let limits = ConnectionLimits {
         max_total: config.max_connections_total,
+        ..Default::default()
     };
     let registry = ConnectionRegistry::new(limits);
 
@@ -152,6 +154,8 @@ async fn create_app(config: TestServerConfig) -> (Router, AppState) {
         config: connection_config,
         api_config: ApiConfig::default(),
         validation: pm_config::ValidationConfig::default(),
+        backups: None,
+        tenants: None,
     };
 
     let router = Router::new()
@@ -230,6 +230,8 @@ async fn given_empty_title_when_creating_work_item_then_returns_validation_error
             parent_id: None,
             status: None,
             priority: None,
+            custom_fields: Default::default(),
+            original_estimate_seconds: None,
         })),
     };
 
@@ -1,4 +1,7 @@
-use pm_proto::{GetLlmContextRequest, WebSocketMessage, web_socket_message::Payload};
+use pm_proto::{
+    CreateLlmContextRequest, DeleteLlmContextRequest, GetLlmContextRequest,
+    UpdateLlmContextRequest, WebSocketMessage, web_socket_message::Payload,
+};
 use pm_ws::{
     CircuitBreaker, CircuitBreakerConfig, ConnectionLimits, ConnectionRegistry, HandlerContext,
     dispatch,
@@ -14,6 +17,7 @@ struct TestFixture {
     pool: SqlitePool,
     circuit_breaker: Arc<CircuitBreaker>,
     user_id: Uuid,
+    project_id: Uuid,
 }
 
 impl TestFixture {
@@ -27,13 +31,57 @@ impl TestFixture {
             .await
             .expect("Failed to run migrations");
 
+        let user_id = Uuid::new_v4();
+        let project_id = Uuid::new_v4();
+        let now = Utc::now().timestamp();
+
+        sqlx::query("INSERT INTO users (id, email, name, created_at) VALUES (?, 'test@example.com', 'Test User', ?)")
+            .bind(user_id.to_string())
+            .bind(now)
+            .execute(&pool)
+            .await
+            .expect("Failed to create test user");
+
+        sqlx::query(
+            r#"
+              INSERT INTO pm_projects (id, title, key, status, version, created_at, updated_at, created_by, updated_by)
+              VALUES (?, 'Test Project', 'TEST', 'active', 1, ?, ?, ?, ?)
+              "#,
+        )
+        .bind(project_id.to_string())
+        .bind(now)
+        .bind(now)
+        .bind(user_id.to_string())
+        .bind(user_id.to_string())
+        .execute(&pool)
+        .await
+        .expect("Failed to create test project");
+
         Self {
             pool,
             circuit_breaker: Arc::new(CircuitBreaker::new(CircuitBreakerConfig::default())),
-            user_id: Uuid::new_v4(),
+            user_id,
+            project_id,
         }
     }
 
+    async fn add_member(&self, role: &str) {
+        sqlx::query(
+            r#"
+              INSERT INTO pm_project_members (id, project_id, user_id, role, created_at)
+              VALUES (?, ?, ?, ?, ?)
+              "#,
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(self.project_id.to_string())
+        .bind(self.user_id.to_string())
+        .bind(role)
+        .bind(Utc::now().timestamp())
+        .execute(&self.pool)
+        .await
+        .expect("Failed to add project member");
+    }
+
     fn ctx(&self, msg_id: &str) -> HandlerContext {
         let registry = ConnectionRegistry::new(ConnectionLimits::default());
         HandlerContext::new(
@@ -48,6 +96,28 @@ impl TestFixture {
     }
 }
 
+fn create_request(project_id: Option<Uuid>, context_type: &str) -> CreateLlmContextRequest {
+    CreateLlmContextRequest {
+        project_id: project_id.map(|id| id.to_string()),
+        context_type: context_type.to_string(),
+        category: "conventions".to_string(),
+        title: "  Use conventional commits  ".to_string(),
+        content: "Prefix commit subjects with the ticket ID.".to_string(),
+        example_sql: None,
+        example_description: Some(String::new()),
+        priority: 70,
+    }
+}
+
+fn list_request(project_id: Option<Uuid>) -> GetLlmContextRequest {
+    GetLlmContextRequest {
+        category: None,
+        context_type: None,
+        min_priority: None,
+        project_id: project_id.map(|id| id.to_string()),
+    }
+}
+
 #[tokio::test]
 async fn given_get_llm_context_when_dispatched_then_returns_seeded_entries() {
     let fixture = TestFixture::new().await;
@@ -59,6 +129,7 @@ async fn given_get_llm_context_when_dispatched_then_returns_seeded_entries() {
             category: None,
             context_type: None,
             min_priority: None,
+            project_id: None,
         })),
     };
 
@@ -71,3 +142,201 @@ async fn given_get_llm_context_when_dispatched_then_returns_seeded_entries() {
         _ => panic!("Expected LlmContextList response"),
     }
 }
+
+#[tokio::test]
+async fn given_project_member_when_create_project_entry_then_created() {
+    // Given
+    let fixture = TestFixture::new().await;
+    fixture.add_member("editor").await;
+
+    // When
+    let msg = WebSocketMessage {
+        message_id: "msg-001".to_string(),
+        timestamp: Utc::now().timestamp(),
+        payload: Some(Payload::CreateLlmContextRequest(create_request(
+            Some(fixture.project_id),
+            "business_rule",
+        ))),
+    };
+
+    let response = dispatch(msg, fixture.ctx("msg-001")).await;
+
+    // Then
+    let entry = match response.payload {
+        Some(Payload::LlmContextCreated(created)) => created.entry.unwrap(),
+        other => panic!("Expected LlmContextCreated, got {:?}", other),
+    };
+    assert_eq!(entry.title, "Use conventional commits");
+    assert_eq!(entry.project_id, Some(fixture.project_id.to_string()));
+    assert_eq!(entry.example_description, None);
+}
+
+#[tokio::test]
+async fn given_project_entry_when_listed_then_only_returned_for_its_project() {
+    // Given
+    let fixture = TestFixture::new().await;
+    fixture.add_member("editor").await;
+    let msg = WebSocketMessage {
+        message_id: "msg-001".to_string(),
+        timestamp: Utc::now().timestamp(),
+        payload: Some(Payload::CreateLlmContextRequest(create_request(
+            Some(fixture.project_id),
+            "business_rule",
+        ))),
+    };
+    let entry = match dispatch(msg, fixture.ctx("msg-001")).await.payload {
+        Some(Payload::LlmContextCreated(created)) => created.entry.unwrap(),
+        other => panic!("Expected LlmContextCreated, got {:?}", other),
+    };
+
+    // When
+    let global = WebSocketMessage {
+        message_id: "msg-002".to_string(),
+        timestamp: Utc::now().timestamp(),
+        payload: Some(Payload::GetLlmContextRequest(list_request(None))),
+    };
+    let scoped = WebSocketMessage {
+        message_id: "msg-003".to_string(),
+        timestamp: Utc::now().timestamp(),
+        payload: Some(Payload::GetLlmContextRequest(list_request(Some(
+            fixture.project_id,
+        )))),
+    };
+
+    let global = dispatch(global, fixture.ctx("msg-002")).await;
+    let scoped = dispatch(scoped, fixture.ctx("msg-003")).await;
+
+    // Then
+    match global.payload {
+        Some(Payload::LlmContextList(list)) => {
+            assert!(!list.entries.iter().any(|e| e.id == entry.id));
+        }
+        _ => panic!("Expected LlmContextList response"),
+    }
+    match scoped.payload {
+        Some(Payload::LlmContextList(list)) => {
+            assert!(list.entries.iter().any(|e| e.id == entry.id));
+        }
+        _ => panic!("Expected LlmContextList response"),
+    }
+}
+
+#[tokio::test]
+async fn given_non_member_when_create_project_entry_then_unauthorized() {
+    let fixture = TestFixture::new().await;
+
+    let msg = WebSocketMessage {
+        message_id: "msg-001".to_string(),
+        timestamp: Utc::now().timestamp(),
+        payload: Some(Payload::CreateLlmContextRequest(create_request(
+            Some(fixture.project_id),
+            "business_rule",
+        ))),
+    };
+
+    let response = dispatch(msg, fixture.ctx("msg-001")).await;
+
+    match response.payload {
+        Some(Payload::Error(err)) => assert_eq!(err.code, "UNAUTHORIZED"),
+        other => panic!("Expected error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn given_invalid_context_type_when_create_then_validation_error() {
+    let fixture = TestFixture::new().await;
+
+    let msg = WebSocketMessage {
+        message_id: "msg-001".to_string(),
+        timestamp: Utc::now().timestamp(),
+        payload: Some(Payload::CreateLlmContextRequest(create_request(
+            None, "tip",
+        ))),
+    };
+
+    let response = dispatch(msg, fixture.ctx("msg-001")).await;
+
+    match response.payload {
+        Some(Payload::Error(err)) => {
+            assert_eq!(err.code, "VALIDATION_ERROR");
+            assert_eq!(err.field.as_deref(), Some("context_type"));
+        }
+        other => panic!("Expected error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn given_global_entry_when_updated_and_deleted_then_changes_apply() {
+    // Given
+    let fixture = TestFixture::new().await;
+    let msg = WebSocketMessage {
+        message_id: "msg-001".to_string(),
+        timestamp: Utc::now().timestamp(),
+        payload: Some(Payload::CreateLlmContextRequest(create_request(
+            None,
+            "instruction",
+        ))),
+    };
+    let entry = match dispatch(msg, fixture.ctx("msg-001")).await.payload {
+        Some(Payload::LlmContextCreated(created)) => created.entry.unwrap(),
+        other => panic!("Expected LlmContextCreated, got {:?}", other),
+    };
+
+    // When: update only the content and example
+    let msg = WebSocketMessage {
+        message_id: "msg-002".to_string(),
+        timestamp: Utc::now().timestamp(),
+        payload: Some(Payload::UpdateLlmContextRequest(UpdateLlmContextRequest {
+            id: entry.id.clone(),
+            context_type: None,
+            category: None,
+            title: None,
+            content: Some("Reference the work item key.".to_string()),
+            example_sql: Some("SELECT 1".to_string()),
+            example_description: None,
+            priority: None,
+        })),
+    };
+
+    let response = dispatch(msg, fixture.ctx("msg-002")).await;
+
+    // Then
+    match response.payload {
+        Some(Payload::LlmContextUpdated(updated)) => {
+            let updated = updated.entry.unwrap();
+            assert_eq!(updated.content, "Reference the work item key.");
+            assert_eq!(updated.example_sql.as_deref(), Some("SELECT 1"));
+            assert_eq!(updated.title, entry.title);
+            assert_eq!(updated.priority, 70);
+        }
+        other => panic!("Expected LlmContextUpdated, got {:?}", other),
+    }
+
+    // When: delete
+    let msg = WebSocketMessage {
+        message_id: "msg-003".to_string(),
+        timestamp: Utc::now().timestamp(),
+        payload: Some(Payload::DeleteLlmContextRequest(DeleteLlmContextRequest {
+            id: entry.id.clone(),
+        })),
+    };
+    let response = dispatch(msg, fixture.ctx("msg-003")).await;
+    assert!(matches!(
+        response.payload,
+        Some(Payload::LlmContextDeleted(_))
+    ));
+
+    // Then: gone
+    let msg = WebSocketMessage {
+        message_id: "msg-004".to_string(),
+        timestamp: Utc::now().timestamp(),
+        payload: Some(Payload::DeleteLlmContextRequest(DeleteLlmContextRequest {
+            id: entry.id,
+        })),
+    };
+    let response = dispatch(msg, fixture.ctx("msg-004")).await;
+    match response.payload {
+        Some(Payload::Error(err)) => assert_eq!(err.code, "NOT_FOUND"),
+        other => panic!("Expected error, got {:?}", other),
+    }
+}
@@ -0,0 +1,76 @@
+use pm_ws::{ConnectionLimits, ConnectionRegistry, TenantResolver};
+
+use axum::extract::ws::Message;
+use tokio::sync::mpsc;
+
+#[tokio::test]
+async fn broadcast_only_reaches_connections_of_the_same_tenant() {
+    let registry = ConnectionRegistry::new(ConnectionLimits::default());
+    let acme = registry.for_tenant("acme");
+    let globex = registry.for_tenant("globex");
+
+    let (tx_a, mut rx_a) = mpsc::channel::<Message>(8);
+    let (tx_b, mut rx_b) = mpsc::channel::<Message>(8);
+
+    let conn_a = acme.register("user-a".to_string(), tx_a).await.unwrap();
+    let conn_b = globex.register("user-b".to_string(), tx_b).await.unwrap();
+
+    // Both subscribe to the same project ID
+    acme.subscribe(&conn_a.to_string(), &["p1".into()], &[])
+        .await
+        .unwrap();
+    globex
+        .subscribe(&conn_b.to_string(), &["p1".into()], &[])
+        .await
+        .unwrap();
+
+    let delivered = acme
+        .broadcast_to_project("p1", Message::Binary(vec![1].into()))
+        .await
+        .unwrap();
+
+    assert_eq!(delivered, 1);
+    assert!(rx_a.try_recv().is_ok());
+    assert!(rx_b.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn tenant_limit_does_not_affect_other_tenants() {
+    let registry = ConnectionRegistry::new(ConnectionLimits {
+        max_total: 10,
+        max_per_tenant: 1,
+    });
+    let acme = registry.for_tenant("acme");
+    let globex = registry.for_tenant("globex");
+
+    let (tx_1, _rx_1) = mpsc::channel::<Message>(8);
+    let (tx_2, _rx_2) = mpsc::channel::<Message>(8);
+    let (tx_3, _rx_3) = mpsc::channel::<Message>(8);
+
+    acme.register("user-1".to_string(), tx_1).await.unwrap();
+    assert!(acme.is_at_tenant_limit().await);
+    assert!(acme.register("user-2".to_string(), tx_2).await.is_err());
+
+    assert!(!globex.is_at_tenant_limit().await);
+    globex.register("user-3".to_string(), tx_3).await.unwrap();
+    assert_eq!(registry.total_count().await, 2);
+}
+
+#[test]
+fn tenant_from_host_takes_first_label() {
+    assert_eq!(
+        TenantResolver::tenant_from_host("acme.board.example.com:8000"),
+        Some("acme".to_string())
+    );
+    assert_eq!(
+        TenantResolver::tenant_from_host("ACME.board.example.com"),
+        Some("acme".to_string())
+    );
+}
+
+#[test]
+fn tenant_from_host_rejects_bare_hosts_and_ips() {
+    assert_eq!(TenantResolver::tenant_from_host("localhost:8000"), None);
+    assert_eq!(TenantResolver::tenant_from_host("127.0.0.1:8000"), None);
+    assert_eq!(TenantResolver::tenant_from_host(".example.com"), None);
+}
@@ -346,6 +346,7 @@ async fn given_running_timer_when_stop_timer_then_calculates_duration() {
         timestamp: Utc::now().timestamp(),
         payload: Some(Payload::StopTimerRequest(StopTimerRequest {
             time_entry_id: entry_id.clone(),
+            remaining_estimate_seconds: None,
         })),
     };
     let response = dispatch(stop_msg, ctx2).await;
@@ -369,6 +370,102 @@ async fn given_running_timer_when_stop_timer_then_calculates_duration() {
     }
 }
 
+impl TestFixture {
+    /// Give the fixture work item an hour-based estimate
+    async fn set_estimate(&self, original: i32, remaining: i32) {
+        sqlx::query(
+            "UPDATE pm_work_items SET original_estimate_seconds = ?, remaining_estimate_seconds = ? WHERE id = ?",
+        )
+        .bind(original)
+        .bind(remaining)
+        .bind(self.work_item_id.to_string())
+        .execute(&self.pool)
+        .await
+        .expect("Failed to set estimate");
+    }
+
+    /// Start a timer and backdate it so stopping it logs `seconds`
+    async fn start_backdated_timer(&self, seconds: i64) -> String {
+        let ctx = self.create_context("msg-start");
+        let start_msg = WebSocketMessage {
+            message_id: "msg-start".to_string(),
+            timestamp: Utc::now().timestamp(),
+            payload: Some(Payload::StartTimerRequest(StartTimerRequest {
+                work_item_id: self.work_item_id.to_string(),
+                description: None,
+            })),
+        };
+        let entry_id = match dispatch(start_msg, ctx).await.payload {
+            Some(Payload::TimerStarted(s)) => s.time_entry.unwrap().id,
+            _ => panic!("Expected TimerStarted"),
+        };
+        sqlx::query("UPDATE pm_time_entries SET started_at = ? WHERE id = ?")
+            .bind(Utc::now().timestamp() - seconds)
+            .bind(&entry_id)
+            .execute(&self.pool)
+            .await
+            .expect("Failed to backdate timer");
+        entry_id
+    }
+
+    async fn remaining_estimate(&self) -> Option<i64> {
+        sqlx::query_scalar("SELECT remaining_estimate_seconds FROM pm_work_items WHERE id = ?")
+            .bind(self.work_item_id.to_string())
+            .fetch_one(&self.pool)
+            .await
+            .expect("Failed to read estimate")
+    }
+}
+
+#[tokio::test]
+async fn given_estimated_item_when_stop_timer_then_decrements_remaining_estimate() {
+    // Given
+    let fixture = TestFixture::new().await;
+    fixture.set_estimate(7200, 3600).await;
+    let entry_id = fixture.start_backdated_timer(1800).await;
+
+    // When
+    let ctx = fixture.create_context("msg-stop");
+    let stop_msg = WebSocketMessage {
+        message_id: "msg-stop".to_string(),
+        timestamp: Utc::now().timestamp(),
+        payload: Some(Payload::StopTimerRequest(StopTimerRequest {
+            time_entry_id: entry_id,
+            remaining_estimate_seconds: None,
+        })),
+    };
+    let response = dispatch(stop_msg, ctx).await;
+
+    // Then
+    assert!(matches!(response.payload, Some(Payload::TimerStopped(_))));
+    let remaining = fixture.remaining_estimate().await.unwrap();
+    assert!((1795..=1800).contains(&remaining), "got {}", remaining);
+}
+
+#[tokio::test]
+async fn given_override_when_stop_timer_then_sets_remaining_estimate() {
+    // Given
+    let fixture = TestFixture::new().await;
+    fixture.set_estimate(7200, 3600).await;
+    let entry_id = fixture.start_backdated_timer(1800).await;
+
+    // When
+    let ctx = fixture.create_context("msg-stop");
+    let stop_msg = WebSocketMessage {
+        message_id: "msg-stop".to_string(),
+        timestamp: Utc::now().timestamp(),
+        payload: Some(Payload::StopTimerRequest(StopTimerRequest {
+            time_entry_id: entry_id,
+            remaining_estimate_seconds: Some(5400),
+        })),
+    };
+    let response = dispatch(stop_msg, ctx).await;
+
+    // Then
+    assert!(matches!(response.payload, Some(Payload::TimerStopped(_))));
+    assert_eq!(fixture.remaining_estimate().await, Some(5400));
+}
+
 #[tokio::test]
 async fn given_other_users_timer_when_stop_timer_then_permission_error() {
     // Given
@@ -397,6 +494,7 @@ async fn given_other_users_timer_when_stop_timer_then_permission_error() {
         timestamp: Utc::now().timestamp(),
         payload: Some(Payload::StopTimerRequest(StopTimerRequest {
             time_entry_id: entry_id,
+            remaining_estimate_seconds: None,
         })),
     };
     let response = dispatch(stop_msg, ctx2).await;
@@ -437,6 +535,7 @@ async fn given_already_stopped_timer_when_stop_timer_then_error() {
         timestamp: Utc::now().timestamp(),
         payload: Some(Payload::StopTimerRequest(StopTimerRequest {
             time_entry_id: entry_id.clone(),
+            remaining_estimate_seconds: None,
         })),
     };
     dispatch(stop_msg, ctx2).await;
@@ -448,6 +547,7 @@ async fn given_already_stopped_timer_when_stop_timer_then_error() {
         timestamp: Utc::now().timestamp(),
         payload: Some(Payload::StopTimerRequest(StopTimerRequest {
             time_entry_id: entry_id,
+            remaining_estimate_seconds: None,
         })),
     };
     let response = dispatch(stop_msg2, ctx3).await;
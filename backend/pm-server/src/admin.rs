@@ -1,20 +1,75 @@
 //! Administrative endpoints for server management.
 
+use crate::{ApiError, ApiResult};
+
+use pm_db::{BackupInfo, BackupStore};
+use pm_ws::AppState;
+
+use std::panic::Location;
+
 use axum::{Json, extract::State, http::StatusCode};
+use error_location::ErrorLocation;
 use log::info;
-use pm_ws::AppState;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct CheckpointResponse {
     pub status: String,
     pub message: String,
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BackupResponse {
+    /// File name in the backup directory; pass it to restore
+    pub name: String,
+    pub size_bytes: u64,
+    /// Unix timestamp (seconds) the backup was taken
+    pub created_at: i64,
+}
+
+impl From<BackupInfo> for BackupResponse {
+    fn from(info: BackupInfo) -> Self {
+        Self {
+            name: info.name,
+            size_bytes: info.size_bytes,
+            created_at: info.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BackupListResponse {
+    /// Newest first
+    pub backups: Vec<BackupResponse>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RestoreBackupRequest {
+    /// Backup file name, as listed by `GET /admin/backups`
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RestoreBackupResponse {
+    pub status: String,
+    pub message: String,
+    pub backup: BackupResponse,
+}
+
 /// Checkpoint WAL to main database file.
 ///
 /// This forces SQLite to flush the Write-Ahead Log to the main database file,
 /// ensuring durability before shutdown.
+#[utoipa::path(
+    post,
+    path = "/admin/checkpoint",
+    tag = "admin",
+    responses(
+        (status = 200, description = "WAL checkpoint completed", body = CheckpointResponse),
+        (status = 500, description = "Checkpoint failed", body = String),
+    )
+)]
 pub async fn checkpoint_handler(
     State(state): State<AppState>,
 ) -> Result<Json<CheckpointResponse>, (StatusCode, String)> {
@@ -36,9 +91,117 @@ pub async fn checkpoint_handler(
     }))
 }
 
+/// Take an online backup of the database.
+///
+/// Writes a consistent copy with `VACUUM INTO`, verifies it with
+/// `PRAGMA integrity_check` and prunes backups beyond the retention count.
+#[utoipa::path(
+    post,
+    path = "/admin/backup",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Backup written and verified", body = BackupResponse),
+        (status = 400, description = "Backups are not configured", body = crate::ApiErrorResponse),
+        (status = 500, description = "Backup failed", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn backup_handler(State(state): State<AppState>) -> ApiResult<Json<BackupResponse>> {
+    info!("Manual backup requested");
+
+    let backup = backup_store(&state)?.create(&state.pool).await?;
+
+    info!(
+        "Database backup written: {} ({} bytes)",
+        backup.name, backup.size_bytes
+    );
+
+    Ok(Json(backup.into()))
+}
+
+/// List database backups, newest first.
+#[utoipa::path(
+    get,
+    path = "/admin/backups",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Backups in the backup directory", body = BackupListResponse),
+        (status = 400, description = "Backups are not configured", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn list_backups_handler(
+    State(state): State<AppState>,
+) -> ApiResult<Json<BackupListResponse>> {
+    let backups = backup_store(&state)?.list()?;
+
+    Ok(Json(BackupListResponse {
+        backups: backups.into_iter().map(BackupResponse::from).collect(),
+    }))
+}
+
+/// Restore the database from a backup.
+///
+/// Verifies the backup, stages it next to the database and shuts the
+/// server down. The next start swaps the files and re-runs migrations.
+#[utoipa::path(
+    post,
+    path = "/admin/backup/restore",
+    tag = "admin",
+    request_body = RestoreBackupRequest,
+    responses(
+        (status = 202, description = "Restore staged; server shutting down", body = RestoreBackupResponse),
+        (status = 404, description = "No backup with that name", body = crate::ApiErrorResponse),
+        (status = 500, description = "Backup failed verification", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn restore_backup_handler(
+    State(state): State<AppState>,
+    Json(req): Json<RestoreBackupRequest>,
+) -> ApiResult<(StatusCode, Json<RestoreBackupResponse>)> {
+    let store = backup_store(&state)?;
+    if !store.list()?.iter().any(|backup| backup.name == req.name) {
+        return Err(ApiError::NotFound {
+            message: format!("Backup {} not found", req.name),
+            location: ErrorLocation::from(Location::caller()),
+        });
+    }
+
+    let backup = store.stage_restore(&req.name).await?;
+    info!(
+        "Restore from {} staged, shutting down to apply it",
+        backup.name
+    );
+
+    state.shutdown.shutdown();
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(RestoreBackupResponse {
+            status: "restarting".to_string(),
+            message: "Restore staged; it is applied when the server next starts".to_string(),
+            backup: backup.into(),
+        }),
+    ))
+}
+
+#[track_caller]
+fn backup_store(state: &AppState) -> ApiResult<&BackupStore> {
+    state.backups.as_ref().ok_or_else(|| ApiError::BadRequest {
+        message: "Backups are not configured on this server".to_string(),
+        location: ErrorLocation::from(Location::caller()),
+    })
+}
+
 /// Graceful shutdown endpoint.
 ///
 /// Triggers immediate graceful shutdown of the server.
+#[utoipa::path(
+    post,
+    path = "/admin/shutdown",
+    tag = "admin",
+    responses(
+        (status = 202, description = "Graceful shutdown started"),
+    )
+)]
 pub async fn shutdown_handler(
     State(state): State<AppState>,
 ) -> Result<StatusCode, (StatusCode, String)> {
@@ -1,8 +1,13 @@
-use pm_core::CommentDto;
-
 use serde::Serialize;
+use serde_json::Value;
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct CommentListResponse {
-    pub comments: Vec<CommentDto>,
+    /// Comment DTOs, reduced to the requested `fields`
+    pub comments: Vec<Value>,
+    /// Matching items across all pages
+    pub total: usize,
+    /// Cursor for the next page; None on the last page
+    pub next_cursor: Option<String>,
 }
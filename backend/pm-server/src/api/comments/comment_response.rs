@@ -1,8 +1,9 @@
 use pm_core::CommentDto;
 
 use serde::Serialize;
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct CommentResponse {
     pub comment: CommentDto,
 }
@@ -2,13 +2,14 @@
 
 use crate::{
     ApiError, ApiResult, CommentListResponse, CommentResponse, CreateCommentRequest,
-    DeleteResponse, UpdateCommentRequest, UserId, api::resolve::resolve_work_item,
+    DeleteResponse, PageQuery, TenantState, UpdateCommentRequest, UserId,
+    api::resolve::resolve_work_item,
 };
 
 use pm_core::{ActivityLog, Comment, CommentDto};
 use pm_db::{ActivityLogRepository, CommentRepository, WorkItemRepository};
 use pm_ws::{
-    AppState, MessageValidator, build_activity_log_created_event, build_comment_created_response,
+    MessageValidator, build_activity_log_created_event, build_comment_created_response,
     build_comment_deleted_response, build_comment_updated_response, sanitize_string,
 };
 
@@ -16,17 +17,32 @@ use std::panic::Location;
 
 use axum::{
     Json,
-    extract::{Path, State, ws::Message},
+    extract::{Path, Query, ws::Message},
 };
 use chrono::Utc;
 use error_location::ErrorLocation;
 use prost::Message as ProstMessage;
 use uuid::Uuid;
 
+/// Fields comment lists can be sorted on
+const COMMENT_SORT_FIELDS: [&str; 2] = ["created_at", "updated_at"];
+
 /// GET /api/v1/work-items/:work_item_id/comments
+#[utoipa::path(
+    get,
+    path = "/api/v1/work-items/{work_item_id}/comments",
+    tag = "comments",
+    params(("work_item_id" = String, Path, description = "Work item UUID or display key"), PageQuery),
+    responses(
+        (status = 200, description = "One page of comments", body = CommentListResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
 pub async fn list_comments(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     Path(work_item_id): Path<String>,
+    Query(page): Query<PageQuery>,
 ) -> ApiResult<Json<CommentListResponse>> {
     let work_item = resolve_work_item(&state.pool, &work_item_id).await?;
     let work_item_uuid = work_item.id;
@@ -34,14 +50,35 @@ pub async fn list_comments(
     let repo = CommentRepository::new(state.pool.clone());
     let comments = repo.find_by_work_item(work_item_uuid).await?;
 
+    let comments: Vec<CommentDto> = comments.into_iter().map(CommentDto::from).collect();
+    let page = page.paginate(
+        &comments,
+        &state.api_config,
+        &COMMENT_SORT_FIELDS,
+        "created_at",
+    )?;
     Ok(Json(CommentListResponse {
-        comments: comments.into_iter().map(CommentDto::from).collect(),
+        comments: page.items,
+        total: page.total,
+        next_cursor: page.next_cursor,
     }))
 }
 
 /// POST /api/v1/work-items/:work_item_id/comments
+#[utoipa::path(
+    post,
+    path = "/api/v1/work-items/{work_item_id}/comments",
+    tag = "comments",
+    params(("work_item_id" = String, Path, description = "Work item UUID or display key")),
+    request_body = CreateCommentRequest,
+    responses(
+        (status = 200, description = "The created comment", body = CommentResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
 pub async fn create_comment(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     UserId(user_id): UserId,
     Path(work_item_id): Path<String>,
     Json(req): Json<CreateCommentRequest>,
@@ -117,8 +154,20 @@ pub async fn create_comment(
 }
 
 /// PUT /api/v1/comments/:id
+#[utoipa::path(
+    put,
+    path = "/api/v1/comments/{id}",
+    tag = "comments",
+    params(("id" = String, Path, description = "Comment UUID")),
+    request_body = UpdateCommentRequest,
+    responses(
+        (status = 200, description = "The updated comment", body = CommentResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
 pub async fn update_comment(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     UserId(user_id): UserId,
     Path(comment_id): Path<String>,
     Json(req): Json<UpdateCommentRequest>,
@@ -205,8 +254,19 @@ pub async fn update_comment(
 }
 
 /// DELETE /api/v1/comments/:id
+#[utoipa::path(
+    delete,
+    path = "/api/v1/comments/{id}",
+    tag = "comments",
+    params(("id" = String, Path, description = "Comment UUID")),
+    responses(
+        (status = 200, description = "The comment was deleted", body = DeleteResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
 pub async fn delete_comment(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     UserId(user_id): UserId,
     Path(comment_id): Path<String>,
 ) -> ApiResult<Json<DeleteResponse>> {
@@ -0,0 +1,27 @@
+//! Entity tags derived from an entity's optimistic locking version
+
+use axum::{
+    Json,
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+
+/// Strong entity tag for a version, e.g. `"3"`
+pub fn entity_tag(version: i32) -> String {
+    format!("\"{}\"", version)
+}
+
+/// JSON response carrying the entity's `ETag`
+pub fn tagged_json<T: Serialize>(version: i32, body: T) -> Response {
+    ([(header::ETAG, entity_tag(version))], Json(body)).into_response()
+}
+
+/// 304 Not Modified for an entity the client already holds
+pub fn not_modified(version: i32) -> Response {
+    (
+        StatusCode::NOT_MODIFIED,
+        [(header::ETAG, entity_tag(version))],
+    )
+        .into_response()
+}
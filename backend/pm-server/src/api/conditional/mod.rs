@@ -0,0 +1,2 @@
+pub(crate) mod entity_tag;
+pub(crate) mod preconditions;
@@ -0,0 +1,127 @@
+//! Conditional request headers for REST reads and writes
+
+use crate::{ApiError, ApiResult, entity_tag};
+
+use std::future::Future;
+use std::panic::Location;
+
+use axum::{
+    extract::FromRequestParts,
+    http::{HeaderMap, HeaderName, header, request::Parts},
+};
+use error_location::ErrorLocation;
+use serde::Serialize;
+use utoipa::IntoParams;
+
+/// Extracts `If-Match` and `If-None-Match`
+///
+/// Entity tags come from [`entity_tag`], so a tag names an entity version.
+/// Version checks share [`pm_ws::check_version`] with the WebSocket handlers.
+#[derive(Debug, Default, IntoParams)]
+#[into_params(parameter_in = Header)]
+pub struct Preconditions {
+    /// Entity tag the write expects, or `*`
+    #[param(rename = "If-Match")]
+    if_match: Option<String>,
+    /// Entity tags the client already holds
+    #[param(rename = "If-None-Match")]
+    if_none_match: Option<String>,
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for Preconditions {
+    type Rejection = ApiError;
+
+    #[allow(clippy::manual_async_fn)]
+    fn from_request_parts(
+        parts: &mut Parts,
+        _state: &S,
+    ) -> impl Future<Output = Result<Self, Self::Rejection>> + Send {
+        async move {
+            Ok(Preconditions {
+                if_match: header_value(&parts.headers, header::IF_MATCH)?,
+                if_none_match: header_value(&parts.headers, header::IF_NONE_MATCH)?,
+            })
+        }
+    }
+}
+
+impl Preconditions {
+    /// True when `If-None-Match` names the current version, so a GET can
+    /// answer 304 Not Modified
+    pub fn is_not_modified(&self, version: i32) -> bool {
+        self.if_none_match
+            .as_deref()
+            .is_some_and(|tags| tags_match(tags, version, true))
+    }
+
+    /// True when a write must be refused with [`precondition_failed`]:
+    /// `If-Match` is present and names another version
+    pub fn if_match_fails(&self, version: i32) -> bool {
+        self.if_match
+            .as_deref()
+            .is_some_and(|tags| !tags_match(tags, version, false))
+    }
+
+    /// Check the body's `expected_version` against the current version.
+    /// Updates need either this or `If-Match`; a mismatch is the same 409
+    /// the WebSocket API returns.
+    #[track_caller]
+    pub fn check_expected_version(
+        &self,
+        version: i32,
+        expected_version: Option<i32>,
+    ) -> ApiResult<()> {
+        match expected_version {
+            Some(expected) => pm_ws::check_version(version, expected).map_err(ApiError::from),
+            None if self.if_match.is_some() => Ok(()),
+            None => Err(ApiError::Validation {
+                message: "Send an If-Match header or expected_version for optimistic locking"
+                    .to_string(),
+                field: Some("expected_version".into()),
+                location: ErrorLocation::from(Location::caller()),
+            }),
+        }
+    }
+}
+
+/// 412 Precondition Failed carrying `current`, the entity as a GET would
+/// return it, so the client can retry without another round trip
+#[track_caller]
+pub fn precondition_failed<T: Serialize>(version: i32, current: &T) -> ApiError {
+    ApiError::PreconditionFailed {
+        current: serde_json::to_value(current).unwrap_or_default(),
+        current_version: version,
+        location: ErrorLocation::from(Location::caller()),
+    }
+}
+
+fn header_value(headers: &HeaderMap, name: HeaderName) -> ApiResult<Option<String>> {
+    headers
+        .get(&name)
+        .map(|value| {
+            value
+                .to_str()
+                .map(str::to_string)
+                .map_err(|_| ApiError::BadRequest {
+                    message: format!("{} header is not valid text", name),
+                    location: ErrorLocation::from(Location::caller()),
+                })
+        })
+        .transpose()
+}
+
+/// Match a comma-separated tag list (or `*`) against a version. `If-Match`
+/// uses strong comparison, so weak tags never match it; `If-None-Match`
+/// uses weak comparison.
+fn tags_match(tags: &str, version: i32, weak: bool) -> bool {
+    let current = entity_tag(version);
+    tags.split(',').map(str::trim).any(|tag| {
+        if tag == "*" {
+            return true;
+        }
+        match tag.strip_prefix("W/") {
+            Some(opaque) => weak && opaque == current,
+            None => tag == current,
+        }
+    })
+}
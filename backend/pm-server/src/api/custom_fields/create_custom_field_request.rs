@@ -0,0 +1,24 @@
+use serde::Deserialize;
+use serde_json::Value;
+use utoipa::ToSchema;
+
+/// Request body for creating a custom field definition
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateCustomFieldRequest {
+    /// Stable key used in work item values (lowercase snake_case)
+    pub name: String,
+    /// Display label (defaults to the name)
+    #[serde(default)]
+    pub label: Option<String>,
+    /// One of: text, number, date, single_select, multi_select, user
+    pub field_type: String,
+    /// Allowed values for select fields
+    #[serde(default)]
+    pub options: Vec<String>,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default)]
+    pub default_value: Option<Value>,
+    #[serde(default)]
+    pub position: Option<i32>,
+}
@@ -0,0 +1,10 @@
+use pm_core::CustomFieldDefinitionDto;
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// List of custom field definitions response
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CustomFieldListResponse {
+    pub custom_fields: Vec<CustomFieldDefinitionDto>,
+}
@@ -0,0 +1,10 @@
+use pm_core::CustomFieldDefinitionDto;
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Single custom field definition response
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CustomFieldResponse {
+    pub custom_field: CustomFieldDefinitionDto,
+}
@@ -0,0 +1,319 @@
+//! Custom Field REST API handlers
+//!
+//! Custom fields are typed, per-project attributes whose values are stored
+//! on work items. These handlers manage the field definitions.
+
+use crate::{
+    ApiError, ApiResult, CreateCustomFieldRequest, CustomFieldListResponse, CustomFieldResponse,
+    DeleteResponse, TenantState, UpdateCustomFieldRequest, UserId, api::resolve::resolve_project,
+};
+
+use pm_core::{ActivityLog, CustomFieldDefinition, CustomFieldDefinitionDto, CustomFieldType};
+use pm_db::{ActivityLogRepository, CustomFieldRepository};
+use pm_ws::{AppState, build_activity_log_created_event, sanitize_string};
+
+use std::{panic::Location, str::FromStr};
+
+use axum::{
+    Json,
+    extract::{Path, ws::Message},
+};
+use chrono::Utc;
+use error_location::ErrorLocation;
+use prost::Message as ProstMessage;
+use uuid::Uuid;
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+/// Load a live custom field definition by ID
+async fn find_custom_field(state: &AppState, id: &str) -> ApiResult<CustomFieldDefinition> {
+    let field_id = Uuid::parse_str(id)?;
+
+    CustomFieldRepository::new(state.pool.clone())
+        .find_by_id(field_id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound {
+            message: format!("Custom field {} not found", id),
+            location: ErrorLocation::from(Location::caller()),
+        })
+}
+
+/// Persist an activity log entry and broadcast it to project subscribers
+async fn record_activity(
+    state: &AppState,
+    project_id: Uuid,
+    activity: ActivityLog,
+) -> ApiResult<()> {
+    let mut tx = state.pool.begin().await?;
+    ActivityLogRepository::create(&mut *tx, &activity).await?;
+    tx.commit().await?;
+
+    let event = build_activity_log_created_event(&activity);
+    let bytes = event.encode_to_vec();
+    if let Err(e) = state
+        .registry
+        .broadcast_activity_log_created(
+            &project_id.to_string(),
+            None,
+            None,
+            Message::Binary(bytes.into()),
+        )
+        .await
+    {
+        log::warn!(
+            "Failed to broadcast custom field activity to WebSocket clients: {}",
+            e
+        );
+    }
+
+    Ok(())
+}
+
+// =============================================================================
+// Handlers
+// =============================================================================
+
+/// GET /api/v1/projects/:project_id/custom-fields
+///
+/// List custom field definitions for a project (ordered by position)
+#[utoipa::path(
+    get,
+    path = "/api/v1/projects/{project_id}/custom-fields",
+    tag = "custom-fields",
+    params(("project_id" = String, Path, description = "Project UUID or key")),
+    responses(
+        (status = 200, description = "Custom field definitions in position order", body = CustomFieldListResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn list_custom_fields(
+    TenantState(state): TenantState,
+    Path(project_id): Path<String>,
+) -> ApiResult<Json<CustomFieldListResponse>> {
+    let project = resolve_project(&state.pool, &project_id).await?;
+
+    let fields = CustomFieldRepository::new(state.pool.clone())
+        .find_by_project(project.id)
+        .await?;
+
+    Ok(Json(CustomFieldListResponse {
+        custom_fields: fields
+            .into_iter()
+            .map(CustomFieldDefinitionDto::from)
+            .collect(),
+    }))
+}
+
+/// POST /api/v1/projects/:project_id/custom-fields
+///
+/// Create a custom field definition. Names must be unique within the project.
+#[utoipa::path(
+    post,
+    path = "/api/v1/projects/{project_id}/custom-fields",
+    tag = "custom-fields",
+    params(("project_id" = String, Path, description = "Project UUID or key")),
+    request_body = CreateCustomFieldRequest,
+    responses(
+        (status = 200, description = "The created definition", body = CustomFieldResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+        (status = 409, description = "Version conflict or duplicate", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn create_custom_field(
+    TenantState(state): TenantState,
+    UserId(user_id): UserId,
+    Path(project_id): Path<String>,
+    Json(req): Json<CreateCustomFieldRequest>,
+) -> ApiResult<Json<CustomFieldResponse>> {
+    let project = resolve_project(&state.pool, &project_id).await?;
+
+    // 1. Parse field type
+    let field_type =
+        CustomFieldType::from_str(&req.field_type).map_err(|_| ApiError::Validation {
+            message: format!(
+                "Invalid field_type: {}. Valid values: text, number, date, single_select, \
+                 multi_select, user",
+                req.field_type
+            ),
+            field: Some("field_type".into()),
+            location: ErrorLocation::from(Location::caller()),
+        })?;
+
+    // 2. Build and validate the definition
+    let name = req.name.trim().to_string();
+    let label = req
+        .label
+        .as_deref()
+        .map(sanitize_string)
+        .unwrap_or_else(|| name.clone());
+
+    let repo = CustomFieldRepository::new(state.pool.clone());
+    let existing = repo.find_by_project(project.id).await?;
+
+    let mut field = CustomFieldDefinition::new(project.id, name, label, field_type, user_id);
+    field.options = req.options.iter().map(|o| sanitize_string(o)).collect();
+    field.required = req.required;
+    field.default_value = req
+        .default_value
+        .as_ref()
+        .map(|v| field.normalize_value(v))
+        .transpose()?;
+    field.position = req
+        .position
+        .unwrap_or_else(|| existing.iter().map(|f| f.position + 1).max().unwrap_or(0));
+    field.validate()?;
+
+    // 3. Enforce unique name within the project
+    if existing.iter().any(|f| f.name == field.name) {
+        return Err(ApiError::Validation {
+            message: format!(
+                "Custom field '{}' already exists in project {}",
+                field.name, project.key
+            ),
+            field: Some("name".into()),
+            location: ErrorLocation::from(Location::caller()),
+        });
+    }
+
+    // 4. Persist and record activity
+    repo.create(&field).await?;
+    record_activity(
+        &state,
+        project.id,
+        ActivityLog::created("custom_field", field.id, user_id),
+    )
+    .await?;
+
+    log::info!(
+        "Created custom field {} ({}) in project {} via REST API",
+        field.name,
+        field.field_type.as_str(),
+        project.key
+    );
+
+    Ok(Json(CustomFieldResponse {
+        custom_field: field.into(),
+    }))
+}
+
+/// PUT /api/v1/custom-fields/:id
+///
+/// Update a custom field definition. Uses optimistic locking via expected_version.
+#[utoipa::path(
+    put,
+    path = "/api/v1/custom-fields/{id}",
+    tag = "custom-fields",
+    params(("id" = String, Path, description = "Custom field UUID")),
+    request_body = UpdateCustomFieldRequest,
+    responses(
+        (status = 200, description = "The updated definition", body = CustomFieldResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+        (status = 409, description = "Version conflict or duplicate", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn update_custom_field(
+    TenantState(state): TenantState,
+    UserId(user_id): UserId,
+    Path(id): Path<String>,
+    Json(req): Json<UpdateCustomFieldRequest>,
+) -> ApiResult<Json<CustomFieldResponse>> {
+    let mut field = find_custom_field(&state, &id).await?;
+
+    if field.version != req.expected_version {
+        return Err(ApiError::Conflict {
+            message: format!(
+                "Version mismatch: expected {}, found {}",
+                req.expected_version, field.version
+            ),
+            current_version: field.version,
+            location: ErrorLocation::from(Location::caller()),
+        });
+    }
+
+    if let Some(ref label) = req.label {
+        field.label = sanitize_string(label);
+    }
+    if let Some(ref options) = req.options {
+        field.options = options.iter().map(|o| sanitize_string(o)).collect();
+    }
+    if let Some(required) = req.required {
+        field.required = required;
+    }
+    if req.clear_default {
+        field.default_value = None;
+    } else if let Some(ref default) = req.default_value {
+        field.default_value = Some(field.normalize_value(default)?);
+    }
+    if let Some(position) = req.position {
+        field.position = position;
+    }
+    field.validate()?;
+
+    field.version += 1;
+    field.updated_at = Utc::now();
+    field.updated_by = user_id;
+
+    CustomFieldRepository::new(state.pool.clone())
+        .update(&field)
+        .await?;
+    record_activity(
+        &state,
+        field.project_id,
+        ActivityLog::updated("custom_field", field.id, user_id, &[]),
+    )
+    .await?;
+
+    log::info!(
+        "Updated custom field {} to version {} via REST API",
+        field.name,
+        field.version
+    );
+
+    Ok(Json(CustomFieldResponse {
+        custom_field: field.into(),
+    }))
+}
+
+/// DELETE /api/v1/custom-fields/:id
+///
+/// Soft-delete a custom field definition. Values already stored on work
+/// items are ignored from then on and dropped the next time the item's
+/// custom fields are edited.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/custom-fields/{id}",
+    tag = "custom-fields",
+    params(("id" = String, Path, description = "Custom field UUID")),
+    responses(
+        (status = 200, description = "The definition was deleted", body = DeleteResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn delete_custom_field(
+    TenantState(state): TenantState,
+    UserId(user_id): UserId,
+    Path(id): Path<String>,
+) -> ApiResult<Json<DeleteResponse>> {
+    let field = find_custom_field(&state, &id).await?;
+
+    CustomFieldRepository::new(state.pool.clone())
+        .delete(field.id, Utc::now().timestamp())
+        .await?;
+    record_activity(
+        &state,
+        field.project_id,
+        ActivityLog::deleted("custom_field", field.id, user_id),
+    )
+    .await?;
+
+    log::info!("Deleted custom field {} via REST API", field.name);
+
+    Ok(Json(DeleteResponse {
+        deleted_id: field.id.to_string(),
+    }))
+}
@@ -0,0 +1,6 @@
+pub(crate) mod create_custom_field_request;
+pub(crate) mod custom_field_list_response;
+pub(crate) mod custom_field_response;
+#[allow(clippy::module_inception)]
+pub(crate) mod custom_fields;
+pub(crate) mod update_custom_field_request;
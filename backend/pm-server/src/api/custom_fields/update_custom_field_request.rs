@@ -0,0 +1,24 @@
+use serde::Deserialize;
+use serde_json::Value;
+use utoipa::ToSchema;
+
+/// Request body for updating a custom field definition.
+/// `name` and `field_type` cannot change once the field exists.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateCustomFieldRequest {
+    #[serde(default)]
+    pub label: Option<String>,
+    #[serde(default)]
+    pub options: Option<Vec<String>>,
+    #[serde(default)]
+    pub required: Option<bool>,
+    #[serde(default)]
+    pub default_value: Option<Value>,
+    /// Set to true to remove the default value
+    #[serde(default)]
+    pub clear_default: bool,
+    #[serde(default)]
+    pub position: Option<i32>,
+    /// Required: current version for optimistic locking
+    pub expected_version: i32,
+}
@@ -4,14 +4,14 @@
 //! via WebSocket so connected clients see updates in real-time.
 
 use crate::{
-    ApiError, ApiResult, CreateDependencyRequest, DeleteResponse, DependencyListResponse, UserId,
-    api::resolve::resolve_work_item,
+    ApiError, ApiResult, CreateDependencyRequest, DeleteResponse, DependencyListResponse,
+    TenantState, UserId, api::resolve::resolve_work_item,
 };
 
 use pm_core::{ActivityLog, Dependency, DependencyDto, DependencyType};
 use pm_db::{ActivityLogRepository, DependencyRepository, WorkItemRepository};
 use pm_ws::{
-    AppState, build_activity_log_created_event, build_dependency_created_response,
+    build_activity_log_created_event, build_dependency_created_response,
     build_dependency_deleted_response,
 };
 
@@ -19,7 +19,7 @@ use std::{panic::Location, str::FromStr};
 
 use axum::{
     Json,
-    extract::{Path, State, ws::Message},
+    extract::{Path, ws::Message},
 };
 use chrono::Utc;
 use error_location::ErrorLocation;
@@ -33,8 +33,18 @@ use uuid::Uuid;
 /// GET /api/v1/work-items/:id/dependencies
 ///
 /// List all dependencies for a work item (both blocking and blocked)
+#[utoipa::path(
+    get,
+    path = "/api/v1/work-items/{id}/dependencies",
+    tag = "dependencies",
+    params(("id" = String, Path, description = "Work item UUID or display key")),
+    responses(
+        (status = 200, description = "Dependencies where the item is blocking or blocked", body = DependencyListResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
 pub async fn list_dependencies(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     Path(id): Path<String>,
 ) -> ApiResult<Json<DependencyListResponse>> {
     let work_item = resolve_work_item(&state.pool, &id).await?;
@@ -62,8 +72,20 @@ pub async fn list_dependencies(
 /// POST /api/v1/dependencies
 ///
 /// Create a dependency link. Broadcasts activity to WebSocket clients.
+#[utoipa::path(
+    post,
+    path = "/api/v1/dependencies",
+    tag = "dependencies",
+    request_body = CreateDependencyRequest,
+    responses(
+        (status = 200, description = "The created dependency", body = DependencyDto),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+        (status = 409, description = "Version conflict or duplicate", body = crate::ApiErrorResponse),
+    )
+)]
 pub async fn create_dependency(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     UserId(user_id): UserId,
     Json(req): Json<CreateDependencyRequest>,
 ) -> ApiResult<Json<DependencyDto>> {
@@ -200,8 +222,19 @@ pub async fn create_dependency(
 /// DELETE /api/v1/dependencies/:id
 ///
 /// Delete a dependency
+#[utoipa::path(
+    delete,
+    path = "/api/v1/dependencies/{id}",
+    tag = "dependencies",
+    params(("id" = String, Path, description = "Dependency UUID")),
+    responses(
+        (status = 200, description = "The dependency was deleted", body = DeleteResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
 pub async fn delete_dependency(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     UserId(user_id): UserId,
     Path(id): Path<String>,
 ) -> ApiResult<Json<DeleteResponse>> {
@@ -1,8 +1,9 @@
 use pm_core::DependencyDto;
 
 use serde::Serialize;
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct DependencyListResponse {
     pub dependencies: Vec<DependencyDto>,
 }
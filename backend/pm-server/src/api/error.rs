@@ -3,27 +3,31 @@
 //! These errors are designed to produce consistent JSON responses
 //! with appropriate HTTP status codes.
 
+use crate::entity_tag;
+
+use pm_core::ImportProblem;
 use pm_db::DbError;
 
 use std::panic::Location;
 
 use axum::{
     Json,
-    http::StatusCode,
+    http::{StatusCode, header},
     response::{IntoResponse, Response},
 };
 use error_location::ErrorLocation;
 use serde::Serialize;
 use thiserror::Error;
+use utoipa::ToSchema;
 
 /// JSON error response body
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ApiErrorResponse {
     pub error: ApiErrorBody,
 }
 
 /// Inner error body with code, message, and optional field
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ApiErrorBody {
     /// Machine-readable error code (e.g., "NOT_FOUND", "VALIDATION_ERROR")
     pub code: String,
@@ -52,6 +56,14 @@ pub enum ApiError {
         location: ErrorLocation,
     },
 
+    /// The user lacks the project role the operation needs, or their token
+    /// was issued for another tenant (403)
+    #[error("Forbidden: {message} {location}")]
+    Forbidden {
+        message: String,
+        location: ErrorLocation,
+    },
+
     /// Version conflict for optimistic locking (409)
     #[error("Conflict: resource was modified (current version: {current_version}) {location}")]
     Conflict {
@@ -60,6 +72,50 @@ pub enum ApiError {
         location: ErrorLocation,
     },
 
+    /// Conditional request failed (412); carries the current entity
+    #[error("Precondition failed: resource is at version {current_version} {location}")]
+    PreconditionFailed {
+        current: serde_json::Value,
+        current_version: i32,
+        location: ErrorLocation,
+    },
+
+    /// Sync import payload failed validation (400); lists every problem
+    #[error("Import rejected: {} problem(s) {location}", problems.len())]
+    ImportRejected {
+        problems: Vec<ImportProblem>,
+        location: ErrorLocation,
+    },
+
+    /// Idempotency key reused for a different request (422)
+    #[error("Idempotency key reused: {message} {location}")]
+    IdempotencyKeyReused {
+        message: String,
+        location: ErrorLocation,
+    },
+
+    /// Idempotency key still held by a request that has not finished (409)
+    #[error("Idempotency key in flight: {message} {location}")]
+    IdempotencyKeyInFlight {
+        message: String,
+        location: ErrorLocation,
+    },
+
+    /// Rate limit exceeded (429)
+    #[error("Too many requests: {message} {location}")]
+    TooManyRequests {
+        message: String,
+        retry_after_secs: u64,
+        location: ErrorLocation,
+    },
+
+    /// Request took longer than the configured timeout (408)
+    #[error("Timeout: {message} {location}")]
+    Timeout {
+        message: String,
+        location: ErrorLocation,
+    },
+
     /// Internal server error (500)
     #[error("Internal error: {message} {location}")]
     Internal {
@@ -73,6 +129,13 @@ pub enum ApiError {
         message: String,
         location: ErrorLocation,
     },
+
+    /// A sync peer could not be reached or refused the request (502)
+    #[error("Bad gateway: {message} {location}")]
+    BadGateway {
+        message: String,
+        location: ErrorLocation,
+    },
 }
 
 impl IntoResponse for ApiError {
@@ -81,6 +144,56 @@ impl IntoResponse for ApiError {
         log::error!("{}", self);
 
         let (status, body) = match self {
+            ApiError::PreconditionFailed {
+                current,
+                current_version,
+                ..
+            } => {
+                // The current entity sits beside the error, shaped as a GET returns it
+                let mut body = match current {
+                    serde_json::Value::Object(map) => map,
+                    _ => serde_json::Map::new(),
+                };
+                let error = ApiErrorBody {
+                    code: "PRECONDITION_FAILED".into(),
+                    message: format!(
+                        "If-Match does not match the current version ({})",
+                        current_version
+                    ),
+                    field: None,
+                };
+                body.insert(
+                    "error".into(),
+                    serde_json::to_value(error).unwrap_or_default(),
+                );
+                return (
+                    StatusCode::PRECONDITION_FAILED,
+                    [(header::ETAG, entity_tag(current_version))],
+                    Json(body),
+                )
+                    .into_response();
+            }
+            ApiError::ImportRejected { problems, .. } => {
+                // Problems sit beside the error so clients can act on each one
+                let summary: Vec<String> = problems
+                    .iter()
+                    .map(|p| format!("{} {}: {}", p.entity, p.id, p.message))
+                    .collect();
+                let error = ApiErrorBody {
+                    code: "IMPORT_REJECTED".into(),
+                    message: format!(
+                        "Import rejected with {} problem(s): {}",
+                        problems.len(),
+                        summary.join("; ")
+                    ),
+                    field: None,
+                };
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(serde_json::json!({ "error": error, "problems": problems })),
+                )
+                    .into_response();
+            }
             ApiError::NotFound { message, .. } => (
                 StatusCode::NOT_FOUND,
                 ApiErrorBody {
@@ -97,6 +210,14 @@ impl IntoResponse for ApiError {
                     field,
                 },
             ),
+            ApiError::Forbidden { message, .. } => (
+                StatusCode::FORBIDDEN,
+                ApiErrorBody {
+                    code: "FORBIDDEN".into(),
+                    message,
+                    field: None,
+                },
+            ),
             ApiError::Conflict {
                 message,
                 current_version,
@@ -109,6 +230,47 @@ impl IntoResponse for ApiError {
                     field: None,
                 },
             ),
+            ApiError::IdempotencyKeyReused { message, .. } => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ApiErrorBody {
+                    code: "IDEMPOTENCY_KEY_REUSED".into(),
+                    message,
+                    field: Some("Idempotency-Key".into()),
+                },
+            ),
+            ApiError::IdempotencyKeyInFlight { message, .. } => (
+                StatusCode::CONFLICT,
+                ApiErrorBody {
+                    code: "IDEMPOTENCY_KEY_IN_FLIGHT".into(),
+                    message,
+                    field: Some("Idempotency-Key".into()),
+                },
+            ),
+            ApiError::TooManyRequests {
+                message,
+                retry_after_secs,
+                ..
+            } => {
+                let body = ApiErrorBody {
+                    code: "RATE_LIMIT_EXCEEDED".into(),
+                    message,
+                    field: None,
+                };
+                return (
+                    StatusCode::TOO_MANY_REQUESTS,
+                    [(header::RETRY_AFTER, retry_after_secs.to_string())],
+                    Json(ApiErrorResponse { error: body }),
+                )
+                    .into_response();
+            }
+            ApiError::Timeout { message, .. } => (
+                StatusCode::REQUEST_TIMEOUT,
+                ApiErrorBody {
+                    code: "TIMEOUT".into(),
+                    message,
+                    field: None,
+                },
+            ),
             ApiError::Internal { message, .. } => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 ApiErrorBody {
@@ -125,6 +287,14 @@ impl IntoResponse for ApiError {
                     field: None,
                 },
             ),
+            ApiError::BadGateway { message, .. } => (
+                StatusCode::BAD_GATEWAY,
+                ApiErrorBody {
+                    code: "BAD_GATEWAY".into(),
+                    message,
+                    field: None,
+                },
+            ),
         };
 
         (status, Json(ApiErrorResponse { error: body })).into_response()
@@ -156,6 +326,25 @@ impl From<uuid::Error> for ApiError {
     }
 }
 
+/// Convert domain validation errors to API errors
+impl From<pm_core::CoreError> for ApiError {
+    #[track_caller]
+    fn from(e: pm_core::CoreError) -> Self {
+        match e {
+            pm_core::CoreError::Validation { message, field, .. } => ApiError::Validation {
+                message,
+                field,
+                location: ErrorLocation::from(Location::caller()),
+            },
+            other => ApiError::Validation {
+                message: other.to_string(),
+                field: None,
+                location: ErrorLocation::from(Location::caller()),
+            },
+        }
+    }
+}
+
 /// Convert pm-ws errors to API errors
 impl From<pm_ws::WsError> for ApiError {
     #[track_caller]
@@ -182,6 +371,10 @@ impl From<pm_ws::WsError> for ApiError {
                 message: format!("Unauthorized: {}", message),
                 location: ErrorLocation::from(Location::caller()),
             },
+            pm_ws::WsError::Forbidden { message, .. } => ApiError::Forbidden {
+                message,
+                location: ErrorLocation::from(Location::caller()),
+            },
             _ => ApiError::Internal {
                 message: e.to_string(),
                 location: ErrorLocation::from(Location::caller()),
@@ -190,6 +383,31 @@ impl From<pm_ws::WsError> for ApiError {
     }
 }
 
+/// Convert auth errors (rate limits) to API errors
+impl From<pm_auth::AuthError> for ApiError {
+    #[track_caller]
+    fn from(e: pm_auth::AuthError) -> Self {
+        match e {
+            pm_auth::AuthError::TooManyRequests {
+                scope,
+                retry_after_secs,
+                ..
+            } => ApiError::TooManyRequests {
+                message: format!(
+                    "Rate limit exceeded for this {}; retry after {}s",
+                    scope, retry_after_secs
+                ),
+                retry_after_secs,
+                location: ErrorLocation::from(Location::caller()),
+            },
+            other => ApiError::BadRequest {
+                message: other.to_string(),
+                location: ErrorLocation::from(Location::caller()),
+            },
+        }
+    }
+}
+
 /// Convert database errors to API errors
 impl From<DbError> for ApiError {
     #[track_caller]
@@ -215,6 +433,16 @@ impl From<DbError> for ApiError {
                     },
                 }
             }
+            DbError::QueryRejected { message, .. } => ApiError::Validation {
+                message,
+                field: Some("sql".into()),
+                location: ErrorLocation::from(Location::caller()),
+            },
+            DbError::QueryTimeout { timeout_ms, .. } => ApiError::Validation {
+                message: format!("Query exceeded the {}ms time limit", timeout_ms),
+                field: Some("sql".into()),
+                location: ErrorLocation::from(Location::caller()),
+            },
             DbError::Migration { message, .. } => ApiError::Internal {
                 message: format!("Database migration error: {}", message),
                 location: ErrorLocation::from(Location::caller()),
@@ -223,6 +451,10 @@ impl From<DbError> for ApiError {
                 message: format!("Database initialization error: {}", message),
                 location: ErrorLocation::from(Location::caller()),
             },
+            DbError::Backup { message, .. } => ApiError::Internal {
+                message: format!("Backup failed: {}", message),
+                location: ErrorLocation::from(Location::caller()),
+            },
         }
     }
 }
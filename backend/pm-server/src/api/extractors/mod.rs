@@ -1 +1,2 @@
+pub(crate) mod tenant_state;
 pub(crate) mod user_id;
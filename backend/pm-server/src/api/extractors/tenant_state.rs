@@ -0,0 +1,28 @@
+//! Axum extractor routing REST requests to the caller's tenant
+
+use crate::ApiError;
+
+use pm_ws::AppState;
+
+use std::future::Future;
+
+use axum::{extract::FromRequestParts, http::request::Parts};
+
+/// The app state scoped to the request's tenant
+///
+/// With tenant routing enabled, `pool` is the tenant's database and
+/// `registry` only broadcasts to the tenant's WebSocket connections (see
+/// [`AppState::for_request`]). Otherwise this is the shared state.
+pub struct TenantState(pub AppState);
+
+impl FromRequestParts<AppState> for TenantState {
+    type Rejection = ApiError;
+
+    #[allow(clippy::manual_async_fn)]
+    fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> impl Future<Output = Result<Self, Self::Rejection>> + Send {
+        async move { Ok(TenantState(state.for_request(&parts.headers).await?)) }
+    }
+}
@@ -0,0 +1,196 @@
+//! Work item graph REST API handler
+//!
+//! Renders the hierarchy of a project or subtree, plus the dependency
+//! edges touching it, as Mermaid or Graphviz DOT text.
+
+use crate::{ApiError, ApiResult, GraphQuery, TenantState, resolve_project, resolve_work_item};
+
+use pm_core::{DependencyType, GraphEdge, GraphFormat, GraphNode, WorkItem, WorkItemGraph};
+use pm_db::{DependencyRepository, ProjectRepository, WorkItemRepository};
+use pm_ws::compute_hierarchy_maps;
+
+use std::collections::{HashMap, HashSet};
+use std::{panic::Location, str::FromStr};
+
+use axum::{
+    extract::Query,
+    http::header,
+    response::{IntoResponse, Response},
+};
+use error_location::ErrorLocation;
+use uuid::Uuid;
+
+/// Which dependency edges to draw; None draws every type
+fn parse_dependency_filter(value: Option<&str>) -> ApiResult<Option<Vec<DependencyType>>> {
+    match value.unwrap_or("all") {
+        "all" => Ok(None),
+        "none" => Ok(Some(Vec::new())),
+        other => DependencyType::from_str(other)
+            .map(|t| Some(vec![t]))
+            .map_err(|_| ApiError::Validation {
+                message: format!(
+                    "Invalid dependency_type: {}. Valid values: all, blocks, relates_to, none",
+                    other
+                ),
+                field: Some("dependency_type".into()),
+                location: ErrorLocation::from(Location::caller()),
+            }),
+    }
+}
+
+/// GET /api/v1/graph
+///
+/// Render the work item hierarchy and dependency edges as text. Items at
+/// the far end of a dependency that fall outside the selection are drawn
+/// as external nodes.
+#[utoipa::path(
+    get,
+    path = "/api/v1/graph",
+    tag = "reports",
+    params(GraphQuery),
+    responses(
+        (status = 200, description = "Mermaid (default) or Graphviz DOT source", content((String = "text/plain"), (String = "text/vnd.graphviz"))),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn get_graph(
+    TenantState(state): TenantState,
+    Query(query): Query<GraphQuery>,
+) -> ApiResult<Response> {
+    // 1. Parse parameters
+    let format =
+        GraphFormat::from_str(query.format.as_deref().unwrap_or("mermaid")).map_err(|e| {
+            ApiError::Validation {
+                message: format!("{}. Valid values: mermaid, dot", e),
+                field: Some("format".into()),
+                location: ErrorLocation::from(Location::caller()),
+            }
+        })?;
+    let dependency_filter = parse_dependency_filter(query.dependency_type.as_deref())?;
+    let depth = query.depth.unwrap_or(usize::MAX);
+
+    // 2. Resolve the scope
+    let pool = &state.pool;
+    let (project_id, root) = match (&query.project_id, &query.root) {
+        (Some(project_id), None) => (resolve_project(pool, project_id).await?.id, None),
+        (None, Some(root)) => {
+            let root = resolve_work_item(pool, root).await?;
+            (root.project_id, Some(root.id))
+        }
+        _ => {
+            return Err(ApiError::Validation {
+                message: "Exactly one of project_id or root is required".into(),
+                field: None,
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+    };
+
+    let all_items = WorkItemRepository::find_all(pool, true).await?;
+    let items_by_id: HashMap<Uuid, &WorkItem> = all_items.iter().map(|w| (w.id, w)).collect();
+    let project_keys: HashMap<Uuid, String> = ProjectRepository::new(pool.clone())
+        .find_all()
+        .await?
+        .into_iter()
+        .map(|p| (p.id, p.key))
+        .collect();
+    let key_of = |item: &WorkItem| {
+        project_keys
+            .get(&item.project_id)
+            .cloned()
+            .unwrap_or_else(|| "UNKNOWN".to_string())
+    };
+
+    // 3. Select items by hierarchy and depth
+    let project_items: Vec<WorkItem> = all_items
+        .iter()
+        .filter(|w| w.project_id == project_id)
+        .cloned()
+        .collect();
+    let hierarchy = compute_hierarchy_maps(&project_items);
+    let mut selected: Vec<&WorkItem> = project_items
+        .iter()
+        .filter(|item| {
+            let ancestors = hierarchy
+                .get(&item.id)
+                .map(|h| h.ancestor_ids.as_slice())
+                .unwrap_or_default();
+            match root {
+                Some(root_id) if item.id == root_id => true,
+                Some(root_id) => ancestors
+                    .iter()
+                    .position(|a| *a == root_id.to_string())
+                    .is_some_and(|level| level < depth),
+                None => ancestors.len() <= depth,
+            }
+        })
+        .collect();
+    selected.sort_by_key(|w| w.item_number);
+    let selected_ids: HashSet<Uuid> = selected.iter().map(|w| w.id).collect();
+
+    let mut graph = WorkItemGraph {
+        nodes: selected
+            .iter()
+            .map(|w| GraphNode::new(w, &key_of(w), false))
+            .collect(),
+        edges: selected
+            .iter()
+            .filter_map(|w| {
+                let parent = items_by_id.get(&w.parent_id?)?;
+                selected_ids.contains(&parent.id).then(|| GraphEdge {
+                    from: parent.display_key(&key_of(parent)),
+                    to: w.display_key(&key_of(w)),
+                    dependency_type: None,
+                })
+            })
+            .collect(),
+    };
+
+    // 4. Dependency edges touching the selection
+    let mut external: Vec<&WorkItem> = Vec::new();
+    let mut dependency_edges = Vec::new();
+    for dep in DependencyRepository::new(pool.clone()).find_all().await? {
+        if dependency_filter
+            .as_ref()
+            .is_some_and(|types| !types.contains(&dep.dependency_type))
+        {
+            continue;
+        }
+        let (Some(blocking), Some(blocked)) = (
+            items_by_id.get(&dep.blocking_item_id),
+            items_by_id.get(&dep.blocked_item_id),
+        ) else {
+            continue;
+        };
+        let (blocking_in, blocked_in) = (
+            selected_ids.contains(&blocking.id),
+            selected_ids.contains(&blocked.id),
+        );
+        if !blocking_in && !blocked_in {
+            continue;
+        }
+        for (item, inside) in [(blocking, blocking_in), (blocked, blocked_in)] {
+            if !inside && !external.iter().any(|e| e.id == item.id) {
+                external.push(item);
+            }
+        }
+        dependency_edges.push(GraphEdge {
+            from: blocking.display_key(&key_of(blocking)),
+            to: blocked.display_key(&key_of(blocked)),
+            dependency_type: Some(dep.dependency_type),
+        });
+    }
+    external.sort_by_key(|w| (key_of(w), w.item_number));
+    graph
+        .nodes
+        .extend(external.iter().map(|w| GraphNode::new(w, &key_of(w), true)));
+    dependency_edges.sort_by(|a, b| (&a.from, &a.to).cmp(&(&b.from, &b.to)));
+    graph.edges.extend(dependency_edges);
+
+    let content_type = match format {
+        GraphFormat::Mermaid => "text/plain; charset=utf-8",
+        GraphFormat::Dot => "text/vnd.graphviz; charset=utf-8",
+    };
+    Ok(([(header::CONTENT_TYPE, content_type)], graph.render(format)).into_response())
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// Query parameters for graph rendering. Exactly one of `project_id` or
+/// `root` must be given.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GraphQuery {
+    /// Render every item in a project (UUID or project key)
+    pub project_id: Option<String>,
+    /// Render a subtree rooted at this item (UUID or display key)
+    pub root: Option<String>,
+    /// Levels of children to include below the root (or below top-level
+    /// items for a project). Unlimited when omitted.
+    pub depth: Option<usize>,
+    /// Dependency edges to draw: all (default), blocks, relates_to, or none
+    pub dependency_type: Option<String>,
+    /// Output format: mermaid (default) or dot
+    pub format: Option<String>,
+}
@@ -0,0 +1,3 @@
+#[allow(clippy::module_inception)]
+pub(crate) mod graph;
+pub(crate) mod graph_query;
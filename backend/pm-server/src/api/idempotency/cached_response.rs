@@ -0,0 +1,57 @@
+use axum::{
+    body::Body,
+    http::{HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use serde::{Deserialize, Serialize};
+
+/// Header set on responses replayed from an earlier request
+pub const REPLAYED_HEADER: &str = "Idempotent-Replayed";
+
+/// A REST response stored against an `Idempotency-Key`, with a fingerprint
+/// of the request that produced it
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CachedResponse {
+    /// SHA-256 of method, path and body
+    pub fingerprint: String,
+    /// `None` while the request that reserved the key is still running
+    #[serde(default)]
+    pub status: Option<u16>,
+    pub content_type: Option<String>,
+    pub body: String,
+}
+
+impl CachedResponse {
+    /// Placeholder that reserves a key until its request has a response
+    pub fn pending(fingerprint: String) -> Self {
+        Self {
+            fingerprint,
+            status: None,
+            content_type: None,
+            body: String::new(),
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.status.is_none()
+    }
+}
+
+impl IntoResponse for CachedResponse {
+    fn into_response(self) -> Response {
+        let status = self
+            .status
+            .and_then(|status| StatusCode::from_u16(status).ok())
+            .unwrap_or(StatusCode::OK);
+        let mut response = (status, Body::from(self.body)).into_response();
+        let headers = response.headers_mut();
+        if let Some(content_type) = self
+            .content_type
+            .and_then(|v| HeaderValue::from_str(&v).ok())
+        {
+            headers.insert(header::CONTENT_TYPE, content_type);
+        }
+        headers.insert(REPLAYED_HEADER, HeaderValue::from_static("true"));
+        response
+    }
+}
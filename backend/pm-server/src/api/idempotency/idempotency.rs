@@ -0,0 +1,205 @@
+//! `Idempotency-Key` support for REST mutations
+//!
+//! The REST counterpart of the WebSocket `check_idempotency` /
+//! `store_idempotency` pair: the first successful response to a key is stored
+//! in `pm_idempotency_keys` and replayed for retries, so a client that timed
+//! out can safely send the same request again. The key is reserved before
+//! the request runs, so a retry that arrives while the first attempt is
+//! still running gets 409 instead of repeating it.
+
+use crate::{ApiError, ApiResult, CachedResponse, UserId};
+
+use pm_db::IdempotencyRepository;
+use pm_ws::AppState;
+
+use std::panic::Location;
+
+use axum::{
+    body::{Body, to_bytes},
+    extract::{FromRequestParts, Request, State},
+    http::{Method, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use error_location::ErrorLocation;
+use sha2::{Digest, Sha256};
+
+/// Request header carrying the client's key
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+const MAX_KEY_LENGTH: usize = 255;
+
+/// Middleware for POST, PUT and DELETE requests that carry an
+/// `Idempotency-Key`. Keys are scoped per user. A repeat with the same
+/// method, path and body replays the stored status and body, or gets 409
+/// while the first request is still running; a different request under the
+/// same key is rejected with 422.
+///
+/// Every other request passes straight through, before the tenant is
+/// resolved, so routes that need no tenant keep working under tenant
+/// routing.
+pub async fn idempotency(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> ApiResult<Response> {
+    if !matches!(
+        *request.method(),
+        Method::POST | Method::PUT | Method::DELETE
+    ) {
+        return Ok(next.run(request).await);
+    }
+    let Some(key) = idempotency_key(&request)? else {
+        return Ok(next.run(request).await);
+    };
+
+    // 1. Find the caller's tenant and user, then fingerprint the request,
+    //    buffering at most the largest body any route accepts
+    let (mut parts, body) = request.into_parts();
+    let UserId(user_id) = UserId::from_request_parts(&mut parts, &state).await?;
+    let state = state.for_request(&parts.headers).await?;
+    let bytes = to_bytes(body, state.api_config.max_import_bytes)
+        .await
+        .map_err(|e| ApiError::BadRequest {
+            message: format!("Failed to read request body: {}", e),
+            location: ErrorLocation::from(Location::caller()),
+        })?;
+    let operation = format!("{} {}", parts.method, parts.uri.path());
+    let fingerprint = fingerprint(&operation, &bytes);
+    let message_id = format!("rest:{}:{}", user_id, key);
+
+    // 2. Reserve the key, or replay what the first request with it stored.
+    //    The reservation is a pending row, so a concurrent retry cannot run
+    //    the request a second time.
+    let repo = IdempotencyRepository::new(state.pool.clone());
+    let pending = encode(&CachedResponse::pending(fingerprint.clone()))?;
+    if !repo.reserve(&message_id, &operation, &pending).await? {
+        let cached = match repo.find_by_message_id(&message_id).await? {
+            Some(cached) => decode(&cached)?,
+            None => CachedResponse::pending(fingerprint.clone()),
+        };
+        if cached.fingerprint != fingerprint {
+            return Err(ApiError::IdempotencyKeyReused {
+                message: format!(
+                    "Idempotency-Key '{}' was already used for a different request",
+                    key
+                ),
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+        if cached.is_pending() {
+            return Err(ApiError::IdempotencyKeyInFlight {
+                message: format!(
+                    "A request with Idempotency-Key '{}' is still in progress; retry shortly",
+                    key
+                ),
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+        log::debug!("Replaying {} for idempotency key {}", operation, key);
+        return Ok(cached.into_response());
+    }
+
+    // 3. Run the request. Store a successful response; release the key
+    //    after a failure so the request can be corrected and sent again.
+    let response = next
+        .run(Request::from_parts(parts, Body::from(bytes)))
+        .await;
+    if !response.status().is_success() {
+        release(&repo, &message_id, &operation).await;
+        return Ok(response);
+    }
+    let (parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            release(&repo, &message_id, &operation).await;
+            return Err(ApiError::Internal {
+                message: format!("Failed to read response body: {}", e),
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+    };
+    let cached = CachedResponse {
+        fingerprint,
+        status: Some(parts.status.as_u16()),
+        content_type: parts
+            .headers
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string),
+        body: String::from_utf8_lossy(&bytes).into_owned(),
+    };
+    let stored = match encode(&cached) {
+        Ok(json) => repo
+            .complete(&message_id, &json)
+            .await
+            .map_err(|e| e.to_string()),
+        Err(e) => Err(e.to_string()),
+    };
+    if let Err(e) = stored {
+        log::warn!(
+            "Failed to store idempotency for {} (non-fatal): {}",
+            operation,
+            e
+        );
+        release(&repo, &message_id, &operation).await;
+    }
+
+    Ok(Response::from_parts(parts, Body::from(bytes)))
+}
+
+/// Drop a reservation; a key left pending would block retries until cleanup
+async fn release(repo: &IdempotencyRepository, message_id: &str, operation: &str) {
+    if let Err(e) = repo.delete(message_id).await {
+        log::warn!(
+            "Failed to release idempotency key for {} (non-fatal): {}",
+            operation,
+            e
+        );
+    }
+}
+
+#[track_caller]
+fn encode(cached: &CachedResponse) -> ApiResult<String> {
+    serde_json::to_string(cached).map_err(|e| ApiError::Internal {
+        message: format!("Failed to encode cached response: {}", e),
+        location: ErrorLocation::from(Location::caller()),
+    })
+}
+
+#[track_caller]
+fn decode(cached: &str) -> ApiResult<CachedResponse> {
+    serde_json::from_str(cached).map_err(|e| ApiError::Internal {
+        message: format!("Failed to decode cached response: {}", e),
+        location: ErrorLocation::from(Location::caller()),
+    })
+}
+
+fn idempotency_key(request: &Request) -> ApiResult<Option<String>> {
+    let Some(value) = request.headers().get(IDEMPOTENCY_KEY_HEADER) else {
+        return Ok(None);
+    };
+    match value.to_str().map(str::trim) {
+        Ok(key) if !key.is_empty() && key.len() <= MAX_KEY_LENGTH => Ok(Some(key.to_string())),
+        _ => Err(ApiError::Validation {
+            message: format!(
+                "Idempotency-Key must be 1-{} characters of visible text",
+                MAX_KEY_LENGTH
+            ),
+            field: Some(IDEMPOTENCY_KEY_HEADER.into()),
+            location: ErrorLocation::from(Location::caller()),
+        }),
+    }
+}
+
+fn fingerprint(operation: &str, body: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(operation.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(body);
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
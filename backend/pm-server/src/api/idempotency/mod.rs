@@ -0,0 +1,3 @@
+pub(crate) mod cached_response;
+#[allow(clippy::module_inception)]
+pub(crate) mod idempotency;
@@ -0,0 +1,2 @@
+pub(crate) mod rate_limit;
+pub(crate) mod request_timeout;
@@ -0,0 +1,62 @@
+//! Per-user and per-IP rate limiting for the REST API
+
+use crate::ApiResult;
+
+use pm_auth::{ApiRateLimiter, JwtValidator};
+use pm_ws::AppState;
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{HeaderMap, header},
+    middleware::Next,
+    response::Response,
+};
+
+/// State for [`rate_limit`]: the app state, whose JWT validator identifies
+/// the user, and the limiter shared by every route
+#[derive(Clone)]
+pub struct RateLimitState {
+    pub app: AppState,
+    pub limiter: Arc<ApiRateLimiter>,
+}
+
+/// Middleware taking a token from the caller's user and IP buckets for each
+/// `/api/` request. Exhausted buckets answer 429 with `Retry-After`. The IP
+/// is only known when the server runs with connect info.
+///
+/// The user bucket is keyed on the subject of a valid bearer token.
+/// `X-User-Id` is whatever the client sends, so keying on it would let a
+/// client take a fresh bucket per request, or drain someone else's; a
+/// request without a valid token is limited by its IP alone.
+pub async fn rate_limit(
+    State(state): State<RateLimitState>,
+    request: Request,
+    next: Next,
+) -> ApiResult<Response> {
+    if !request.uri().path().starts_with("/api/") {
+        return Ok(next.run(request).await);
+    }
+
+    let subject = verified_subject(request.headers(), state.app.jwt_validator.as_deref());
+    let ip = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|info| info.0.ip());
+    state.limiter.check(subject.as_deref(), ip)?;
+
+    Ok(next.run(request).await)
+}
+
+/// Subject of the request's bearer token, if there is a validator and the
+/// token passes it
+fn verified_subject(headers: &HeaderMap, validator: Option<&JwtValidator>) -> Option<String> {
+    let token = headers
+        .get(header::AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")?;
+    validator?.validate(token).ok().map(|claims| claims.sub)
+}
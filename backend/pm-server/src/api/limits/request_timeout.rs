@@ -0,0 +1,21 @@
+use crate::ApiError;
+
+use std::panic::Location;
+
+use error_location::ErrorLocation;
+use tower::{BoxError, timeout::error::Elapsed};
+
+/// Turn errors from the tower timeout and concurrency layers into API errors
+pub async fn handle_layer_error(error: BoxError) -> ApiError {
+    if error.is::<Elapsed>() {
+        ApiError::Timeout {
+            message: "Request took too long to process".to_string(),
+            location: ErrorLocation::from(Location::caller()),
+        }
+    } else {
+        ApiError::Internal {
+            message: format!("Request failed: {}", error),
+            location: ErrorLocation::from(Location::caller()),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+/// Request body for creating an LLM context entry
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateLlmContextRequest {
+    /// Project UUID or key; omit for a global entry
+    #[serde(default)]
+    pub project_id: Option<String>,
+    /// One of: schema_doc, query_pattern, business_rule, example, instruction
+    pub context_type: String,
+    pub category: String,
+    pub title: String,
+    pub content: String,
+    #[serde(default)]
+    pub example_sql: Option<String>,
+    #[serde(default)]
+    pub example_description: Option<String>,
+    /// 0-100, higher first (default 0)
+    #[serde(default)]
+    pub priority: i32,
+}
@@ -0,0 +1,347 @@
+//! LLM context REST API handlers
+//!
+//! LLM context entries document the schema, query patterns and business
+//! rules for agents working against the database. Entries without a project
+//! are global; project entries teach agents one repository's conventions.
+
+use crate::{
+    ApiError, ApiResult, CreateLlmContextRequest, DeleteResponse, LlmContextListResponse,
+    LlmContextQuery, LlmContextResponse, TenantState, UpdateLlmContextRequest, UserId,
+    api::resolve::resolve_project,
+};
+
+use pm_core::{ActivityLog, ExampleSqlReport, LlmContext, LlmContextDto, LlmContextType};
+use pm_db::{ActivityLogRepository, LlmContextRepository};
+use pm_ws::{
+    AppState, build_activity_log_created_event, build_llm_context_created_response,
+    build_llm_context_deleted_response, build_llm_context_updated_response, sanitize_string,
+};
+
+use std::{panic::Location, str::FromStr};
+
+use axum::{
+    Json,
+    extract::{Path, Query, ws::Message},
+};
+use chrono::Utc;
+use error_location::ErrorLocation;
+use prost::Message as ProstMessage;
+use uuid::Uuid;
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+/// Load a live entry by ID
+async fn find_entry(state: &AppState, id: &str) -> ApiResult<LlmContext> {
+    let entry_id = Uuid::parse_str(id)?;
+
+    LlmContextRepository::find_by_id(&state.pool, entry_id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound {
+            message: format!("LLM context entry {} not found", id),
+            location: ErrorLocation::from(Location::caller()),
+        })
+}
+
+/// Trimmed optional text; blank means "none"
+fn optional_text(value: Option<&str>) -> Option<String> {
+    value.map(sanitize_string).filter(|v| !v.is_empty())
+}
+
+/// Persist an activity log entry and notify project subscribers. Global
+/// entries have no project channel, so nothing is broadcast for them.
+/// `event` is the encoded created/updated/deleted message.
+async fn record_activity(
+    state: &AppState,
+    project_id: Option<Uuid>,
+    activity: ActivityLog,
+    event: Vec<u8>,
+) -> ApiResult<()> {
+    let mut tx = state.pool.begin().await?;
+    ActivityLogRepository::create(&mut *tx, &activity).await?;
+    tx.commit().await?;
+
+    let Some(project_id) = project_id else {
+        return Ok(());
+    };
+    let project_id = project_id.to_string();
+
+    let activity_event = build_activity_log_created_event(&activity);
+    if let Err(e) = state
+        .registry
+        .broadcast_activity_log_created(
+            &project_id,
+            None,
+            None,
+            Message::Binary(activity_event.encode_to_vec().into()),
+        )
+        .await
+    {
+        log::warn!(
+            "Failed to broadcast LLM context activity to WebSocket clients: {}",
+            e
+        );
+    }
+
+    if let Err(e) = state
+        .registry
+        .broadcast_to_project(&project_id, Message::Binary(event.into()))
+        .await
+    {
+        log::warn!(
+            "Failed to broadcast LLM context change to WebSocket clients: {}",
+            e
+        );
+    }
+
+    Ok(())
+}
+
+// =============================================================================
+// Handlers
+// =============================================================================
+
+/// GET /api/v1/llm-context
+///
+/// List LLM context entries, highest priority first. With `project_id`, the
+/// project's own entries are included alongside the global ones.
+#[utoipa::path(
+    get,
+    path = "/api/v1/llm-context",
+    tag = "llm-context",
+    params(LlmContextQuery),
+    responses(
+        (status = 200, description = "Matching entries, highest priority first", body = LlmContextListResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn list_llm_context(
+    TenantState(state): TenantState,
+    Query(query): Query<LlmContextQuery>,
+) -> ApiResult<Json<LlmContextListResponse>> {
+    let project_id = match query.project_id.as_deref() {
+        Some(project) => Some(resolve_project(&state.pool, project).await?.id),
+        None => None,
+    };
+
+    let entries = LlmContextRepository::list_filtered(
+        &state.pool,
+        project_id,
+        query.category.as_deref(),
+        query.context_type.as_deref(),
+        query.min_priority,
+    )
+    .await?;
+
+    Ok(Json(LlmContextListResponse {
+        entries: entries.into_iter().map(LlmContextDto::from).collect(),
+    }))
+}
+
+/// GET /api/v1/llm-context/verify
+///
+/// Prepare every entry's example SQL against the live schema and report the
+/// ones that no longer work
+#[utoipa::path(
+    get,
+    path = "/api/v1/llm-context/verify",
+    tag = "llm-context",
+    responses(
+        (status = 200, description = "Example SQL checked against the live schema", body = ExampleSqlReport),
+    )
+)]
+pub async fn verify_llm_context(
+    TenantState(state): TenantState,
+) -> ApiResult<Json<ExampleSqlReport>> {
+    let report = LlmContextRepository::verify_example_sql(&state.pool).await?;
+
+    Ok(Json(report))
+}
+
+/// GET /api/v1/llm-context/:id
+#[utoipa::path(
+    get,
+    path = "/api/v1/llm-context/{id}",
+    tag = "llm-context",
+    params(("id" = String, Path, description = "Entry UUID")),
+    responses(
+        (status = 200, description = "The entry", body = LlmContextResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn get_llm_context(
+    TenantState(state): TenantState,
+    Path(id): Path<String>,
+) -> ApiResult<Json<LlmContextResponse>> {
+    let entry = find_entry(&state, &id).await?;
+
+    Ok(Json(LlmContextResponse {
+        entry: entry.into(),
+    }))
+}
+
+/// POST /api/v1/llm-context
+///
+/// Create a global entry, or a project entry when `project_id` is given
+#[utoipa::path(
+    post,
+    path = "/api/v1/llm-context",
+    tag = "llm-context",
+    request_body = CreateLlmContextRequest,
+    responses(
+        (status = 200, description = "The created entry", body = LlmContextResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn create_llm_context(
+    TenantState(state): TenantState,
+    UserId(user_id): UserId,
+    Json(req): Json<CreateLlmContextRequest>,
+) -> ApiResult<Json<LlmContextResponse>> {
+    let project_id = match req.project_id.as_deref() {
+        Some(project) => Some(resolve_project(&state.pool, project).await?.id),
+        None => None,
+    };
+
+    let mut entry = LlmContext::new(
+        LlmContextType::from_str(&req.context_type)?,
+        sanitize_string(&req.category),
+        sanitize_string(&req.title),
+        sanitize_string(&req.content),
+    );
+    entry.project_id = project_id;
+    entry.example_sql = optional_text(req.example_sql.as_deref());
+    entry.example_description = optional_text(req.example_description.as_deref());
+    entry.priority = req.priority;
+    entry.validate()?;
+
+    let event = build_llm_context_created_response(&Uuid::new_v4().to_string(), &entry, user_id)
+        .encode_to_vec();
+    LlmContextRepository::create(&state.pool, &entry).await?;
+    record_activity(
+        &state,
+        entry.project_id,
+        ActivityLog::created("llm_context", entry.id, user_id),
+        event,
+    )
+    .await?;
+
+    log::info!(
+        "Created LLM context entry {} ({}) via REST API",
+        entry.id,
+        entry.context_type.as_str()
+    );
+
+    Ok(Json(LlmContextResponse {
+        entry: entry.into(),
+    }))
+}
+
+/// PUT /api/v1/llm-context/:id
+///
+/// Update an entry. Only the given fields change; an entry cannot move
+/// between projects.
+#[utoipa::path(
+    put,
+    path = "/api/v1/llm-context/{id}",
+    tag = "llm-context",
+    params(("id" = String, Path, description = "Entry UUID")),
+    request_body = UpdateLlmContextRequest,
+    responses(
+        (status = 200, description = "The updated entry", body = LlmContextResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn update_llm_context(
+    TenantState(state): TenantState,
+    UserId(user_id): UserId,
+    Path(id): Path<String>,
+    Json(req): Json<UpdateLlmContextRequest>,
+) -> ApiResult<Json<LlmContextResponse>> {
+    let mut entry = find_entry(&state, &id).await?;
+
+    if let Some(ref context_type) = req.context_type {
+        entry.context_type = LlmContextType::from_str(context_type)?;
+    }
+    if let Some(ref category) = req.category {
+        entry.category = sanitize_string(category);
+    }
+    if let Some(ref title) = req.title {
+        entry.title = sanitize_string(title);
+    }
+    if let Some(ref content) = req.content {
+        entry.content = sanitize_string(content);
+    }
+    if req.example_sql.is_some() {
+        entry.example_sql = optional_text(req.example_sql.as_deref());
+    }
+    if req.example_description.is_some() {
+        entry.example_description = optional_text(req.example_description.as_deref());
+    }
+    if let Some(priority) = req.priority {
+        entry.priority = priority;
+    }
+    entry.updated_at = Utc::now();
+    entry.validate()?;
+
+    let event = build_llm_context_updated_response(&Uuid::new_v4().to_string(), &entry, user_id)
+        .encode_to_vec();
+    LlmContextRepository::update(&state.pool, &entry).await?;
+    record_activity(
+        &state,
+        entry.project_id,
+        ActivityLog::updated("llm_context", entry.id, user_id, &[]),
+        event,
+    )
+    .await?;
+
+    log::info!("Updated LLM context entry {} via REST API", entry.id);
+
+    Ok(Json(LlmContextResponse {
+        entry: entry.into(),
+    }))
+}
+
+/// DELETE /api/v1/llm-context/:id
+///
+/// Soft-delete an entry
+#[utoipa::path(
+    delete,
+    path = "/api/v1/llm-context/{id}",
+    tag = "llm-context",
+    params(("id" = String, Path, description = "Entry UUID")),
+    responses(
+        (status = 200, description = "The entry was deleted", body = DeleteResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn delete_llm_context(
+    TenantState(state): TenantState,
+    UserId(user_id): UserId,
+    Path(id): Path<String>,
+) -> ApiResult<Json<DeleteResponse>> {
+    let entry = find_entry(&state, &id).await?;
+
+    let event = build_llm_context_deleted_response(&Uuid::new_v4().to_string(), &entry, user_id)
+        .encode_to_vec();
+    LlmContextRepository::soft_delete(&state.pool, entry.id, Utc::now().timestamp()).await?;
+    record_activity(
+        &state,
+        entry.project_id,
+        ActivityLog::deleted("llm_context", entry.id, user_id),
+        event,
+    )
+    .await?;
+
+    log::info!("Deleted LLM context entry {} via REST API", entry.id);
+
+    Ok(Json(DeleteResponse {
+        deleted_id: entry.id.to_string(),
+    }))
+}
@@ -0,0 +1,9 @@
+use pm_core::LlmContextDto;
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LlmContextListResponse {
+    pub entries: Vec<LlmContextDto>,
+}
@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// Query parameters for listing LLM context entries
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct LlmContextQuery {
+    /// Project UUID or key: include its entries alongside the global ones
+    pub project_id: Option<String>,
+    pub category: Option<String>,
+    /// schema_doc, query_pattern, business_rule, example, or instruction
+    pub context_type: Option<String>,
+    /// Only entries with at least this priority
+    pub min_priority: Option<i32>,
+}
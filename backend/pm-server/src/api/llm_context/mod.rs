@@ -0,0 +1,7 @@
+pub(crate) mod create_llm_context_request;
+#[allow(clippy::module_inception)]
+pub(crate) mod llm_context;
+pub(crate) mod llm_context_list_response;
+pub(crate) mod llm_context_query;
+pub(crate) mod llm_context_response;
+pub(crate) mod update_llm_context_request;
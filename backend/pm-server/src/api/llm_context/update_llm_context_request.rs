@@ -0,0 +1,22 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+/// Request body for updating an LLM context entry. Only the given fields
+/// change; an empty `example_sql` or `example_description` clears it.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateLlmContextRequest {
+    #[serde(default)]
+    pub context_type: Option<String>,
+    #[serde(default)]
+    pub category: Option<String>,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub content: Option<String>,
+    #[serde(default)]
+    pub example_sql: Option<String>,
+    #[serde(default)]
+    pub example_description: Option<String>,
+    #[serde(default)]
+    pub priority: Option<i32>,
+}
@@ -1,12 +1,25 @@
 pub(crate) mod comments;
+pub(crate) mod conditional;
+pub(crate) mod custom_fields;
 pub(crate) mod delete_response;
 pub(crate) mod dependencies;
 pub(crate) mod error;
 pub(crate) mod extractors;
+pub(crate) mod graph;
+pub(crate) mod idempotency;
+pub(crate) mod limits;
+pub(crate) mod llm_context;
+pub(crate) mod openapi;
+pub(crate) mod pagination;
 pub(crate) mod projects;
+pub(crate) mod query;
 pub(crate) mod resolve;
+pub(crate) mod schedule;
 pub(crate) mod sprints;
 pub(crate) mod swim_lanes;
 pub(crate) mod sync;
+pub(crate) mod templates;
 pub(crate) mod time_entries;
+pub(crate) mod time_reports;
+pub(crate) mod work_item_context;
 pub(crate) mod work_items;
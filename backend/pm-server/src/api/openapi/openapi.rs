@@ -0,0 +1,119 @@
+//! OpenAPI document for the REST API
+//!
+//! Generated from the `#[utoipa::path]` annotations on the handlers and the
+//! `ToSchema` derives on their request and response types, so the document
+//! follows the code.
+
+use axum::Json;
+use utoipa::OpenApi;
+
+/// The REST API description served at `/api/v1/openapi.json`
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "pm-server REST API",
+        description = "Project management REST API. Send `X-User-Id` to act as a user. \
+            Mutations accept an `Idempotency-Key` header for safe retries. \
+            Requests over the rate limit get 429 with `Retry-After`."
+    ),
+    paths(
+        crate::health::health,
+        crate::health::liveness,
+        crate::health::readiness,
+        crate::admin::checkpoint_handler,
+        crate::admin::backup_handler,
+        crate::admin::list_backups_handler,
+        crate::admin::restore_backup_handler,
+        crate::admin::shutdown_handler,
+        openapi_json,
+        crate::api::projects::projects::list_projects,
+        crate::api::projects::projects::create_project,
+        crate::api::projects::projects::get_project,
+        crate::api::projects::projects::update_project,
+        crate::api::projects::projects::delete_project,
+        crate::api::projects::projects::rename_project_key,
+        crate::api::sprints::sprints::list_sprints,
+        crate::api::sprints::sprints::create_sprint,
+        crate::api::sprints::sprints::get_sprint,
+        crate::api::sprints::sprints::update_sprint,
+        crate::api::sprints::sprints::delete_sprint,
+        crate::api::sprints::sprints::get_sprint_summary,
+        crate::api::work_items::work_items::list_work_items,
+        crate::api::work_items::work_items::create_work_item,
+        crate::api::work_items::work_items::get_work_item,
+        crate::api::work_items::work_items::update_work_item,
+        crate::api::work_items::work_items::delete_work_item,
+        crate::api::work_items::work_items::move_work_item,
+        crate::api::work_items::work_items::move_work_item_to_project,
+        crate::api::work_item_context::work_item_context::get_work_item_context,
+        crate::api::comments::comments::list_comments,
+        crate::api::comments::comments::create_comment,
+        crate::api::comments::comments::update_comment,
+        crate::api::comments::comments::delete_comment,
+        crate::api::dependencies::dependencies::list_dependencies,
+        crate::api::dependencies::dependencies::create_dependency,
+        crate::api::dependencies::dependencies::delete_dependency,
+        crate::api::swim_lanes::swim_lanes::list_swim_lanes,
+        crate::api::time_entries::time_entries::list_time_entries,
+        crate::api::time_entries::time_entries::create_time_entry,
+        crate::api::time_entries::time_entries::get_time_entry,
+        crate::api::time_entries::time_entries::update_time_entry,
+        crate::api::time_entries::time_entries::delete_time_entry,
+        crate::api::time_reports::time_reports::get_time_report,
+        crate::api::schedule::schedule::get_schedule,
+        crate::api::graph::graph::get_graph,
+        crate::api::llm_context::llm_context::list_llm_context,
+        crate::api::llm_context::llm_context::create_llm_context,
+        crate::api::llm_context::llm_context::verify_llm_context,
+        crate::api::llm_context::llm_context::get_llm_context,
+        crate::api::llm_context::llm_context::update_llm_context,
+        crate::api::llm_context::llm_context::delete_llm_context,
+        crate::api::query::query::run_query,
+        crate::api::custom_fields::custom_fields::list_custom_fields,
+        crate::api::custom_fields::custom_fields::create_custom_field,
+        crate::api::custom_fields::custom_fields::update_custom_field,
+        crate::api::custom_fields::custom_fields::delete_custom_field,
+        crate::api::sync::export::sync_export,
+        crate::api::sync::import::sync_import,
+        crate::api::sync::peers::sync_pull,
+        crate::api::sync::peers::sync_push,
+        crate::api::sync::peers::list_sync_peers,
+        crate::api::templates::templates::list_templates,
+        crate::api::templates::templates::create_template,
+        crate::api::templates::templates::get_template,
+        crate::api::templates::templates::delete_template,
+    ),
+    tags(
+        (name = "projects"),
+        (name = "sprints"),
+        (name = "work-items"),
+        (name = "comments"),
+        (name = "dependencies"),
+        (name = "swim-lanes", description = "Fixed board columns"),
+        (name = "time-entries"),
+        (name = "reports", description = "Time reports, schedules and graphs"),
+        (name = "llm-context", description = "Guidance entries for LLM clients"),
+        (name = "query", description = "Read-only SQL"),
+        (name = "custom-fields"),
+        (name = "sync", description = "Bulk export and import, and peer sync"),
+        (name = "templates", description = "Project templates"),
+        (name = "health"),
+        (name = "admin"),
+    )
+)]
+pub struct ApiDoc;
+
+/// GET /api/v1/openapi.json
+///
+/// Serve the OpenAPI 3 document for this server
+#[utoipa::path(
+    get,
+    path = "/api/v1/openapi.json",
+    tag = "health",
+    responses(
+        (status = 200, description = "This OpenAPI document", body = Object),
+    )
+)]
+pub async fn openapi_json() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
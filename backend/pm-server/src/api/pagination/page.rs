@@ -0,0 +1,11 @@
+use serde_json::Value;
+
+/// One page of a sorted, projected list
+#[derive(Debug)]
+pub struct Page {
+    pub items: Vec<Value>,
+    /// Items matching the request across all pages
+    pub total: usize,
+    /// Pass as `cursor` to fetch the next page; None on the last page
+    pub next_cursor: Option<String>,
+}
@@ -0,0 +1,246 @@
+use crate::{ApiError, ApiResult, Page};
+
+use pm_config::ApiConfig;
+
+use std::cmp::Ordering;
+use std::panic::Location;
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use error_location::ErrorLocation;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use utoipa::IntoParams;
+
+/// Tie-breaker appended to every sort so page boundaries are stable
+const TIE_BREAKER: &str = "id";
+
+/// Paging, sorting and field selection shared by the list endpoints.
+/// Extracted alongside each endpoint's own filter query.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PageQuery {
+    /// Items per page (default and maximum come from the `[api]` config)
+    pub limit: Option<usize>,
+    /// `next_cursor` from the previous page
+    pub cursor: Option<String>,
+    /// Comma-separated fields to sort by; prefix a field with `-` for descending
+    pub sort: Option<String>,
+    /// Comma-separated top-level fields to return; all fields when omitted
+    pub fields: Option<String>,
+}
+
+/// Decoded cursor: the sort it was issued for and the last item's sort key
+#[derive(Debug, Serialize, Deserialize)]
+struct Cursor {
+    sort: String,
+    after: Vec<Value>,
+}
+
+#[derive(Debug, Clone)]
+struct SortKey {
+    field: String,
+    descending: bool,
+}
+
+impl PageQuery {
+    /// Sort `items`, skip past the cursor, cut one page and project the
+    /// requested fields. `sortable` lists the fields callers may sort on;
+    /// `default_sort` applies when the request gives none.
+    pub fn paginate<T: Serialize>(
+        &self,
+        items: &[T],
+        config: &ApiConfig,
+        sortable: &[&str],
+        default_sort: &str,
+    ) -> ApiResult<Page> {
+        let limit = self.limit(config)?;
+        let keys = parse_sort(self.sort.as_deref().unwrap_or(default_sort), sortable)?;
+        let sort_spec = keys
+            .iter()
+            .map(|k| format!("{}{}", if k.descending { "-" } else { "" }, k.field))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let mut rows = items
+            .iter()
+            .map(serde_json::to_value)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to serialize list item: {}", e),
+                location: ErrorLocation::from(Location::caller()),
+            })?;
+        rows.sort_by(|a, b| compare_keys(&sort_values(a, &keys), &sort_values(b, &keys), &keys));
+
+        let start = match &self.cursor {
+            Some(cursor) => {
+                let after = decode_cursor(cursor, &sort_spec)?;
+                rows.partition_point(|row| {
+                    compare_keys(&sort_values(row, &keys), &after, &keys) != Ordering::Greater
+                })
+            }
+            None => 0,
+        };
+
+        let total = rows.len();
+        let end = (start + limit).min(total);
+        let next_cursor = (end < total)
+            .then(|| encode_cursor(&sort_spec, sort_values(&rows[end - 1], &keys)))
+            .transpose()?;
+
+        let fields = self.field_list();
+        let items = rows
+            .drain(start..end)
+            .map(|row| match &fields {
+                Some(fields) => project(row, fields),
+                None => row,
+            })
+            .collect();
+
+        Ok(Page {
+            items,
+            total,
+            next_cursor,
+        })
+    }
+
+    fn limit(&self, config: &ApiConfig) -> ApiResult<usize> {
+        match self.limit {
+            None => Ok(config.default_page_size),
+            Some(limit) if (1..=config.max_page_size).contains(&limit) => Ok(limit),
+            Some(limit) => Err(ApiError::Validation {
+                message: format!("limit must be 1-{}, got {}", config.max_page_size, limit),
+                field: Some("limit".into()),
+                location: ErrorLocation::from(Location::caller()),
+            }),
+        }
+    }
+
+    fn field_list(&self) -> Option<Vec<&str>> {
+        self.fields.as_deref().map(|fields| {
+            fields
+                .split(',')
+                .map(str::trim)
+                .filter(|f| !f.is_empty())
+                .collect()
+        })
+    }
+}
+
+fn parse_sort(spec: &str, sortable: &[&str]) -> ApiResult<Vec<SortKey>> {
+    let mut keys = Vec::new();
+    for part in spec.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (field, descending) = match part.strip_prefix('-') {
+            Some(field) => (field, true),
+            None => (part, false),
+        };
+        if field != TIE_BREAKER && !sortable.contains(&field) {
+            return Err(ApiError::Validation {
+                message: format!(
+                    "Cannot sort by '{}'. Valid fields: {}",
+                    field,
+                    sortable.join(", ")
+                ),
+                field: Some("sort".into()),
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+        keys.push(SortKey {
+            field: field.to_string(),
+            descending,
+        });
+    }
+    if !keys.iter().any(|k| k.field == TIE_BREAKER) {
+        keys.push(SortKey {
+            field: TIE_BREAKER.to_string(),
+            descending: false,
+        });
+    }
+    Ok(keys)
+}
+
+fn sort_values(row: &Value, keys: &[SortKey]) -> Vec<Value> {
+    keys.iter()
+        .map(|k| row.get(&k.field).cloned().unwrap_or(Value::Null))
+        .collect()
+}
+
+fn compare_keys(a: &[Value], b: &[Value], keys: &[SortKey]) -> Ordering {
+    for ((a, b), key) in a.iter().zip(b).zip(keys) {
+        let ordering = compare_values(a, b);
+        let ordering = if key.descending {
+            ordering.reverse()
+        } else {
+            ordering
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    Ordering::Equal
+}
+
+/// Nulls sort first, then booleans, numbers and strings
+fn compare_values(a: &Value, b: &Value) -> Ordering {
+    fn rank(value: &Value) -> u8 {
+        match value {
+            Value::Null => 0,
+            Value::Bool(_) => 1,
+            Value::Number(_) => 2,
+            Value::String(_) => 3,
+            Value::Array(_) | Value::Object(_) => 4,
+        }
+    }
+    match (a, b) {
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        (Value::Number(a), Value::Number(b)) => a
+            .as_f64()
+            .partial_cmp(&b.as_f64())
+            .unwrap_or(Ordering::Equal),
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        _ => rank(a).cmp(&rank(b)),
+    }
+}
+
+fn encode_cursor(sort: &str, after: Vec<Value>) -> ApiResult<String> {
+    let cursor = Cursor {
+        sort: sort.to_string(),
+        after,
+    };
+    let json = serde_json::to_vec(&cursor).map_err(|e| ApiError::Internal {
+        message: format!("Failed to encode cursor: {}", e),
+        location: ErrorLocation::from(Location::caller()),
+    })?;
+    Ok(URL_SAFE_NO_PAD.encode(json))
+}
+
+fn decode_cursor(cursor: &str, sort: &str) -> ApiResult<Vec<Value>> {
+    let invalid = |message: &str| ApiError::Validation {
+        message: message.to_string(),
+        field: Some("cursor".into()),
+        location: ErrorLocation::from(Location::caller()),
+    };
+    let cursor: Cursor = URL_SAFE_NO_PAD
+        .decode(cursor)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .ok_or_else(|| invalid("Invalid cursor"))?;
+    if cursor.sort != sort {
+        return Err(invalid(
+            "Cursor was issued for a different sort; restart without a cursor",
+        ));
+    }
+    Ok(cursor.after)
+}
+
+fn project(row: Value, fields: &[&str]) -> Value {
+    match row {
+        Value::Object(mut map) => Value::Object(
+            fields
+                .iter()
+                .filter_map(|f| map.remove_entry(*f))
+                .collect::<Map<_, _>>(),
+        ),
+        other => other,
+    }
+}
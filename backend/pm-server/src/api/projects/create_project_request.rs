@@ -1,6 +1,7 @@
 use serde::Deserialize;
+use utoipa::ToSchema;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateProjectRequest {
     /// Project title (required)
     pub title: String,
@@ -11,4 +12,9 @@ pub struct CreateProjectRequest {
     /// Optional description
     #[serde(default)]
     pub description: Option<String>,
+
+    /// Template UUID or name to create the project's custom fields, starter
+    /// work items and LLM context from
+    #[serde(default)]
+    pub template_id: Option<String>,
 }
@@ -3,4 +3,5 @@ pub(crate) mod project_list_response;
 pub(crate) mod project_response;
 #[allow(clippy::module_inception)]
 pub(crate) mod projects;
+pub(crate) mod rename_project_key_request;
 pub(crate) mod update_project_request;
@@ -1,9 +1,14 @@
-use pm_core::ProjectDto;
-
 use serde::Serialize;
+use serde_json::Value;
+use utoipa::ToSchema;
 
 /// List of projects response
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ProjectListResponse {
-    pub projects: Vec<ProjectDto>,
+    /// Project DTOs, reduced to the requested `fields`
+    pub projects: Vec<Value>,
+    /// Matching items across all pages
+    pub total: usize,
+    /// Cursor for the next page; None on the last page
+    pub next_cursor: Option<String>,
 }
@@ -1,9 +1,10 @@
 use pm_core::ProjectDto;
 
 use serde::Serialize;
+use utoipa::ToSchema;
 
 /// Single project response
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ProjectResponse {
     pub project: ProjectDto,
 }
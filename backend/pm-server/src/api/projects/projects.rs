@@ -1,16 +1,23 @@
 //! Project REST API handlers
 //!
-//! Read-only handlers for listing and retrieving projects.
+//! Handlers for listing, creating, updating and deleting projects, and for
+//! renaming a project's key. Old keys are kept as aliases so project keys
+//! and display keys issued before a rename keep resolving.
 
 use crate::{
-    ApiError, ApiResult, CreateProjectRequest, DeleteResponse, ProjectListResponse,
-    ProjectResponse, UpdateProjectRequest, UserId, api::resolve::resolve_project,
+    ApiError, ApiResult, CreateProjectRequest, DeleteResponse, PageQuery, Preconditions,
+    ProjectListResponse, ProjectResponse, RenameProjectKeyRequest, TenantState,
+    UpdateProjectRequest, UserId, api::resolve::resolve_project,
+    api::templates::templates::resolve_template, not_modified, precondition_failed, tagged_json,
 };
 
-use pm_core::{ActivityLog, Project, ProjectDto, ProjectStatus};
-use pm_db::{ActivityLogRepository, ProjectRepository};
+use pm_core::{ActivityLog, Permission, Project, ProjectDto, ProjectMember, ProjectStatus};
+use pm_db::{
+    ActivityLogRepository, CustomFieldRepository, LlmContextRepository, ProjectMemberRepository,
+    ProjectRepository, WorkItemRepository,
+};
 use pm_ws::{
-    AppState, build_activity_log_created_event, build_project_created_response,
+    FieldChangeBuilder, build_activity_log_created_event, build_project_created_response,
     build_project_deleted_response, build_project_updated_response, sanitize_string,
 };
 
@@ -18,48 +25,117 @@ use std::{panic::Location, str::FromStr};
 
 use axum::{
     Json,
-    extract::{Path, State, ws::Message},
+    extract::{Path, Query, ws::Message},
+    response::Response,
 };
 use chrono::Utc;
 use error_location::ErrorLocation;
 use prost::Message as ProstMessage;
 use uuid::Uuid;
 
+/// Fields project lists can be sorted on
+const PROJECT_SORT_FIELDS: [&str; 5] = ["key", "title", "status", "created_at", "updated_at"];
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+/// A key that is another project's old key stays with that project
+#[track_caller]
+fn key_alias_taken(key: &str, owner: Uuid) -> ApiError {
+    ApiError::Validation {
+        message: format!(
+            "Project key '{}' was used by project {} and still resolves to it",
+            key, owner
+        ),
+        field: Some("key".into()),
+        location: ErrorLocation::from(Location::caller()),
+    }
+}
+
 // =============================================================================
 // Handlers
 // =============================================================================
 
 /// GET /api/v1/projects
 ///
-/// List all projects
-pub async fn list_projects(State(state): State<AppState>) -> ApiResult<Json<ProjectListResponse>> {
+/// List projects, one page at a time
+#[utoipa::path(
+    get,
+    path = "/api/v1/projects",
+    tag = "projects",
+    params(PageQuery),
+    responses(
+        (status = 200, description = "One page of projects", body = ProjectListResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn list_projects(
+    TenantState(state): TenantState,
+    Query(page): Query<PageQuery>,
+) -> ApiResult<Json<ProjectListResponse>> {
     let repo = ProjectRepository::new(state.pool.clone());
     let projects = repo.find_all().await?;
 
+    let projects: Vec<ProjectDto> = projects.into_iter().map(ProjectDto::from).collect();
+    let page = page.paginate(&projects, &state.api_config, &PROJECT_SORT_FIELDS, "key")?;
     Ok(Json(ProjectListResponse {
-        projects: projects.into_iter().map(ProjectDto::from).collect(),
+        projects: page.items,
+        total: page.total,
+        next_cursor: page.next_cursor,
     }))
 }
 
 /// GET /api/v1/projects/:id
 ///
-/// Get a single project by ID
+/// Get a single project by ID. Tagged with its version; honors `If-None-Match`.
+#[utoipa::path(
+    get,
+    path = "/api/v1/projects/{id}",
+    tag = "projects",
+    params(("id" = String, Path, description = "Project UUID or key"), Preconditions),
+    responses(
+        (status = 200, description = "The project, with its version as `ETag`", body = ProjectResponse),
+        (status = 304, description = "`If-None-Match` matches the current version"),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
 pub async fn get_project(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     Path(id): Path<String>,
-) -> ApiResult<Json<ProjectResponse>> {
+    preconditions: Preconditions,
+) -> ApiResult<Response> {
     let project = resolve_project(&state.pool, &id).await?;
 
-    Ok(Json(ProjectResponse {
-        project: project.into(),
-    }))
+    if preconditions.is_not_modified(project.version) {
+        return Ok(not_modified(project.version));
+    }
+    Ok(tagged_json(
+        project.version,
+        ProjectResponse {
+            project: project.into(),
+        },
+    ))
 }
 
 /// POST /api/v1/projects
 ///
-/// Create a new project. Broadcasts activity to WebSocket clients.
+/// Create a new project, optionally from a template (fresh IDs, work items
+/// numbered from 1). Broadcasts activity to WebSocket clients.
+#[utoipa::path(
+    post,
+    path = "/api/v1/projects",
+    tag = "projects",
+    request_body = CreateProjectRequest,
+    responses(
+        (status = 200, description = "The created project", body = ProjectResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Template not found", body = crate::ApiErrorResponse),
+        (status = 409, description = "Version conflict or duplicate", body = crate::ApiErrorResponse),
+    )
+)]
 pub async fn create_project(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     UserId(user_id): UserId,
     Json(req): Json<CreateProjectRequest>,
 ) -> ApiResult<Json<ProjectResponse>> {
@@ -94,6 +170,10 @@ pub async fn create_project(
         });
     }
 
+    if let Some(owner) = repo.find_id_by_key_alias(&key).await? {
+        return Err(key_alias_taken(&key, owner));
+    }
+
     // 4. Build project
     let mut project = Project::new(title.to_string(), key.clone(), user_id);
     if let Some(desc) = &req.description {
@@ -103,17 +183,50 @@ pub async fn create_project(
         }
     }
 
-    // 5. Execute transaction
+    // 5. Instantiate the template, if any
+    let instance = match &req.template_id {
+        Some(template_id) => {
+            let template = resolve_template(&state.pool, template_id).await?;
+            let instance = template.structure.instantiate(project.id, user_id)?;
+            project.next_work_item_number = instance.next_work_item_number;
+            Some(instance)
+        }
+        None => None,
+    };
+
+    // 6. Execute transaction
     let activity = ActivityLog::created("project", project.id, user_id);
     let activity_clone = activity.clone();
     let project_clone = project.clone();
 
+    // Creator becomes project admin, as over WebSocket
+    let member = ProjectMember {
+        id: Uuid::new_v4(),
+        project_id: project.id,
+        user_id,
+        role: "admin".to_string(),
+        created_at: Utc::now(),
+    };
+
     let mut tx = state.pool.begin().await?;
-    repo.create(&project_clone).await?;
+    ProjectRepository::create_with(&mut *tx, &project_clone).await?;
+    ProjectMemberRepository::create_with(&mut *tx, &member).await?;
+    if let Some(instance) = &instance {
+        for field in &instance.custom_fields {
+            CustomFieldRepository::create_with(&mut *tx, field).await?;
+        }
+        // Parents come first, so parent_id always refers to an inserted row
+        for work_item in &instance.work_items {
+            WorkItemRepository::create(&mut *tx, work_item).await?;
+        }
+        for entry in &instance.llm_context {
+            LlmContextRepository::create(&mut *tx, entry).await?;
+        }
+    }
     ActivityLogRepository::create(&mut *tx, &activity_clone).await?;
     tx.commit().await?;
 
-    // 6. Broadcast ActivityLogCreated to WebSocket clients
+    // 7. Broadcast ActivityLogCreated to WebSocket clients
     let event = build_activity_log_created_event(&activity);
     let bytes = event.encode_to_vec();
     let message = Message::Binary(bytes.into());
@@ -128,7 +241,7 @@ pub async fn create_project(
         );
     }
 
-    // 7. Broadcast ProjectCreated to all project subscribers
+    // 8. Broadcast ProjectCreated to all project subscribers
     let broadcast = build_project_created_response(&Uuid::new_v4().to_string(), &project, user_id);
     let broadcast_bytes = broadcast.encode_to_vec();
     if let Err(e) = state
@@ -143,9 +256,12 @@ pub async fn create_project(
     }
 
     log::info!(
-        "Created project {} ({}) via REST API",
+        "Created project {} ({}) via REST API{}",
         project.id,
-        project.key
+        project.key,
+        instance
+            .map(|i| format!(" with {} template work items", i.work_items.len()))
+            .unwrap_or_default()
     );
 
     Ok(Json(ProjectResponse {
@@ -155,13 +271,28 @@ pub async fn create_project(
 
 /// PUT /api/v1/projects/:id
 ///
-/// Update a project. Uses optimistic locking via expected_version.
+/// Update a project. Uses optimistic locking via `If-Match` or expected_version.
+#[utoipa::path(
+    put,
+    path = "/api/v1/projects/{id}",
+    tag = "projects",
+    params(("id" = String, Path, description = "Project UUID or key"), Preconditions),
+    request_body = UpdateProjectRequest,
+    responses(
+        (status = 200, description = "The updated project", body = ProjectResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+        (status = 409, description = "Version conflict or duplicate", body = crate::ApiErrorResponse),
+        (status = 412, description = "`If-Match` does not match; the current entity is returned beside `error`", body = ProjectResponse),
+    )
+)]
 pub async fn update_project(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     UserId(user_id): UserId,
     Path(id): Path<String>,
+    preconditions: Preconditions,
     Json(req): Json<UpdateProjectRequest>,
-) -> ApiResult<Json<ProjectResponse>> {
+) -> ApiResult<Response> {
     // 1. Load existing project
     let mut project = resolve_project(&state.pool, &id).await?;
     let repo = ProjectRepository::new(state.pool.clone());
@@ -169,16 +300,15 @@ pub async fn update_project(
     // 2. refactored out
 
     // 3. Check optimistic locking
-    if project.version != req.expected_version {
-        return Err(ApiError::Conflict {
-            message: format!(
-                "Version mismatch: expected {}, found {}",
-                req.expected_version, project.version
-            ),
-            current_version: project.version,
-            location: ErrorLocation::from(Location::caller()),
-        });
+    if preconditions.if_match_fails(project.version) {
+        return Err(precondition_failed(
+            project.version,
+            &ProjectResponse {
+                project: project.into(),
+            },
+        ));
     }
+    preconditions.check_expected_version(project.version, req.expected_version)?;
 
     // 4. Apply updates
     let mut changed = false;
@@ -228,9 +358,12 @@ pub async fn update_project(
 
     if !changed {
         // No changes, return current state
-        return Ok(Json(ProjectResponse {
-            project: project.into(),
-        }));
+        return Ok(tagged_json(
+            project.version,
+            ProjectResponse {
+                project: project.into(),
+            },
+        ));
     }
 
     // 5. Update metadata
@@ -288,21 +421,190 @@ pub async fn update_project(
         project.key
     );
 
-    Ok(Json(ProjectResponse {
-        project: project.into(),
-    }))
+    Ok(tagged_json(
+        project.version,
+        ProjectResponse {
+            project: project.into(),
+        },
+    ))
+}
+
+/// PUT /api/v1/projects/:id/key
+///
+/// Rename a project's key. Admins only. The old key is kept as an alias, so
+/// it and display keys like `OLD-12` still resolve; no other project can
+/// take it. Uses optimistic locking via `If-Match` or expected_version.
+#[utoipa::path(
+    put,
+    path = "/api/v1/projects/{id}/key",
+    tag = "projects",
+    params(("id" = String, Path, description = "Project UUID, key or old key"), Preconditions),
+    request_body = RenameProjectKeyRequest,
+    responses(
+        (status = 200, description = "The renamed project", body = ProjectResponse),
+        (status = 400, description = "Invalid key", body = crate::ApiErrorResponse),
+        (status = 403, description = "Not a project admin", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+        (status = 409, description = "Version conflict or key in use", body = crate::ApiErrorResponse),
+        (status = 412, description = "`If-Match` does not match; the current entity is returned beside `error`", body = ProjectResponse),
+    )
+)]
+pub async fn rename_project_key(
+    TenantState(state): TenantState,
+    UserId(user_id): UserId,
+    Path(id): Path<String>,
+    preconditions: Preconditions,
+    Json(req): Json<RenameProjectKeyRequest>,
+) -> ApiResult<Response> {
+    // 1. Load existing project
+    let mut project = resolve_project(&state.pool, &id).await?;
+    let repo = ProjectRepository::new(state.pool.clone());
+
+    // 2. Only project admins may change the key
+    let member = ProjectMemberRepository::new(state.pool.clone())
+        .find_by_user_and_project(user_id, project.id)
+        .await?;
+    if !member.is_some_and(|m| m.has_permission(Permission::Admin)) {
+        return Err(ApiError::Forbidden {
+            message: format!("Only admins of project {} can change its key", project.key),
+            location: ErrorLocation::from(Location::caller()),
+        });
+    }
+
+    // 3. Check optimistic locking
+    if preconditions.if_match_fails(project.version) {
+        return Err(precondition_failed(
+            project.version,
+            &ProjectResponse {
+                project: project.into(),
+            },
+        ));
+    }
+    preconditions.check_expected_version(project.version, req.expected_version)?;
+
+    // 4. Validate the new key
+    let key = req.key.trim().to_uppercase();
+    Project::validate_key(&key)?;
+    if key == project.key {
+        return Ok(tagged_json(
+            project.version,
+            ProjectResponse {
+                project: project.into(),
+            },
+        ));
+    }
+
+    // 5. Check the key is free; a project may take back one of its own old keys
+    if let Some(existing) = repo.find_by_key(&key).await? {
+        return Err(ApiError::Conflict {
+            message: format!("Project with key '{}' already exists", key),
+            current_version: existing.version,
+            location: ErrorLocation::from(Location::caller()),
+        });
+    }
+    let reclaimed = match repo.find_id_by_key_alias(&key).await? {
+        Some(owner) if owner == project.id => true,
+        Some(owner) => return Err(key_alias_taken(&key, owner)),
+        None => false,
+    };
+
+    // 6. Update metadata
+    let old_key = std::mem::replace(&mut project.key, key);
+    project.version += 1;
+    project.updated_at = Utc::now();
+    project.updated_by = user_id;
+
+    let mut builder = FieldChangeBuilder::new();
+    builder.track("key", &old_key, &project.key);
+    let changes = builder.build();
+
+    // 7. Execute transaction
+    let activity = ActivityLog::updated("project", project.id, user_id, &changes);
+
+    let mut tx = state.pool.begin().await?;
+    if reclaimed {
+        ProjectRepository::delete_key_alias_with(&mut *tx, &project.key).await?;
+    }
+    ProjectRepository::update_with(&mut *tx, &project).await?;
+    ProjectRepository::create_key_alias_with(&mut *tx, &old_key, project.id).await?;
+    ActivityLogRepository::create(&mut *tx, &activity).await?;
+    tx.commit().await?;
+
+    // 8. Broadcast ActivityLogCreated
+    let event = build_activity_log_created_event(&activity);
+    let bytes = event.encode_to_vec();
+    let message = Message::Binary(bytes.into());
+    if let Err(e) = state
+        .registry
+        .broadcast_activity_log_created(&project.id.to_string(), None, None, message)
+        .await
+    {
+        log::warn!(
+            "Failed to broadcast project key change activity log to WebSocket clients: {}",
+            e
+        );
+    }
+
+    // 9. Broadcast ProjectUpdated, so clients re-render display keys
+    let broadcast =
+        build_project_updated_response(&Uuid::new_v4().to_string(), &project, &changes, user_id);
+    let broadcast_bytes = broadcast.encode_to_vec();
+    if let Err(e) = state
+        .registry
+        .broadcast_to_project(
+            &project.id.to_string(),
+            Message::Binary(broadcast_bytes.into()),
+        )
+        .await
+    {
+        log::warn!("Failed to broadcast ProjectUpdated via REST: {}", e);
+    }
+
+    log::info!(
+        "Renamed project {} key {} -> {} via REST API",
+        project.id,
+        old_key,
+        project.key
+    );
+
+    Ok(tagged_json(
+        project.version,
+        ProjectResponse {
+            project: project.into(),
+        },
+    ))
 }
 
 /// DELETE /api/v1/projects/:id
 ///
-/// Soft delete a project. Broadcasts activity to WebSocket clients.
+/// Soft delete a project. Broadcasts activity to WebSocket clients. Honors `If-Match`.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/projects/{id}",
+    tag = "projects",
+    params(("id" = String, Path, description = "Project UUID or key"), Preconditions),
+    responses(
+        (status = 200, description = "The project was deleted", body = DeleteResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+        (status = 412, description = "`If-Match` does not match; the current entity is returned beside `error`", body = ProjectResponse),
+    )
+)]
 pub async fn delete_project(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     UserId(user_id): UserId,
     Path(id): Path<String>,
+    preconditions: Preconditions,
 ) -> ApiResult<Json<DeleteResponse>> {
     // 1. Load existing project
     let project = resolve_project(&state.pool, &id).await?;
+    if preconditions.if_match_fails(project.version) {
+        return Err(precondition_failed(
+            project.version,
+            &ProjectResponse {
+                project: project.into(),
+            },
+        ));
+    }
     let project_id = project.id;
     let repo = ProjectRepository::new(state.pool.clone());
 
@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RenameProjectKeyRequest {
+    /// New key: 1-10 letters, uppercased
+    pub key: String,
+
+    /// Optimistic locking; may be replaced by an `If-Match` header
+    #[serde(default)]
+    pub expected_version: Option<i32>,
+}
@@ -1,6 +1,7 @@
 use serde::Deserialize;
+use utoipa::ToSchema;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateProjectRequest {
     #[serde(default)]
     pub title: Option<String>,
@@ -12,6 +13,7 @@ pub struct UpdateProjectRequest {
     #[serde(default)]
     pub status: Option<String>,
 
-    /// Required for optimistic locking
-    pub expected_version: i32,
+    /// Optimistic locking; may be replaced by an `If-Match` header
+    #[serde(default)]
+    pub expected_version: Option<i32>,
 }
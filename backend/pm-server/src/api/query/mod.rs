@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+pub(crate) mod query;
+pub(crate) mod query_request;
+pub(crate) mod query_response;
@@ -0,0 +1,66 @@
+//! Read-only SQL query REST API handler
+//!
+//! Lets agents answer ad-hoc questions with the schema and example queries
+//! documented in the LLM context. Queries run in the [`SqlSandbox`]: one
+//! SELECT, read-only, time- and row-limited, and restricted to projects the
+//! caller is a member of.
+
+use crate::{ApiError, ApiResult, QueryRequest, QueryResponse, TenantState, UserId};
+
+use pm_db::SqlSandbox;
+
+use std::panic::Location;
+use std::time::Duration;
+
+use axum::Json;
+use error_location::ErrorLocation;
+
+/// POST /api/v1/query
+///
+/// Run a read-only query. `max_rows` may lower, but not raise, the
+/// configured row limit.
+#[utoipa::path(
+    post,
+    path = "/api/v1/query",
+    tag = "query",
+    request_body = QueryRequest,
+    responses(
+        (status = 200, description = "Query results", body = QueryResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn run_query(
+    TenantState(state): TenantState,
+    UserId(user_id): UserId,
+    Json(req): Json<QueryRequest>,
+) -> ApiResult<Json<QueryResponse>> {
+    let limit = state.api_config.query_max_rows;
+    let max_rows = match req.max_rows {
+        Some(0) => {
+            return Err(ApiError::Validation {
+                message: "max_rows must be at least 1".to_string(),
+                field: Some("max_rows".into()),
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+        Some(max_rows) => max_rows.min(limit),
+        None => limit,
+    };
+    let timeout = Duration::from_millis(state.api_config.query_timeout_ms);
+
+    let result = SqlSandbox::query(&state.pool, user_id, &req.sql, max_rows, timeout).await?;
+
+    log::info!(
+        "User {} ran read-only query ({} rows{})",
+        user_id,
+        result.rows.len(),
+        if result.truncated { ", truncated" } else { "" }
+    );
+
+    Ok(Json(QueryResponse {
+        columns: result.columns,
+        row_count: result.rows.len(),
+        rows: result.rows,
+        truncated: result.truncated,
+    }))
+}
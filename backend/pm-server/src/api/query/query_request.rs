@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct QueryRequest {
+    /// A single SELECT (or WITH ... SELECT) statement
+    pub sql: String,
+    /// Lower the server's row limit for this query
+    #[serde(default)]
+    pub max_rows: Option<usize>,
+}
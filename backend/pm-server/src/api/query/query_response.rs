@@ -0,0 +1,13 @@
+use serde::Serialize;
+use serde_json::Value;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct QueryResponse {
+    pub columns: Vec<String>,
+    /// One array per row, values in column order
+    pub rows: Vec<Vec<Value>>,
+    pub row_count: usize,
+    /// True when more rows matched than `row_count`
+    pub truncated: bool,
+}
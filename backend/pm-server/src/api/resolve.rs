@@ -103,7 +103,8 @@ pub fn parse_display_key(s: &str) -> Result<(&str, i64), ApiError> {
 /// # Resolution Strategy
 /// 1. Try parsing as UUID → if success, look up by ID
 /// 2. If UUID parsing fails, treat as project key → look up by key
-/// 3. Return NotFound if no match in database
+/// 3. Failing that, follow a key the project had before it was renamed
+/// 4. Return NotFound if no match in database
 ///
 /// # Errors
 /// - `ApiError::NotFound` if project doesn't exist or is soft-deleted
@@ -123,12 +124,21 @@ pub async fn resolve_project(pool: &SqlitePool, identifier: &str) -> Result<Proj
     }
 
     // Not a UUID, treat as project key
-    repo.find_by_key(identifier)
-        .await?
-        .ok_or_else(|| ApiError::NotFound {
-            message: format!("Project with key '{}' not found", identifier),
-            location: ErrorLocation::from(Location::caller()),
-        })
+    if let Some(project) = repo.find_by_key(identifier).await? {
+        return Ok(project);
+    }
+
+    // The project may have been renamed; follow its old key
+    if let Some(project_id) = repo.find_id_by_key_alias(identifier).await?
+        && let Some(project) = repo.find_by_id(project_id).await?
+    {
+        return Ok(project);
+    }
+
+    Err(ApiError::NotFound {
+        message: format!("Project with key '{}' not found", identifier),
+        location: ErrorLocation::from(Location::caller()),
+    })
 }
 
 // =============================================================================
@@ -147,6 +157,8 @@ pub async fn resolve_project(pool: &SqlitePool, identifier: &str) -> Result<Proj
 ///    - Extract project key and item number using `parse_display_key`
 ///    - Resolve project key to project UUID using `resolve_project`
 ///    - Look up work item by project ID + item number
+///    - Failing that, follow an alias left when the item moved to another
+///      project, so its old display key still resolves
 /// 3. Return NotFound if no match in database
 ///
 /// # Errors
@@ -174,13 +186,25 @@ pub async fn resolve_work_item(
     let project = resolve_project(pool, project_key).await?;
 
     // Look up work item by project ID + item number
-    WorkItemRepository::find_by_project_and_number(pool, project.id, item_number as i32)
-        .await?
-        .ok_or_else(|| ApiError::NotFound {
-            message: format!(
-                "Work item {} not found in project {}",
-                identifier, project_key
-            ),
-            location: ErrorLocation::from(Location::caller()),
-        })
+    if let Some(work_item) =
+        WorkItemRepository::find_by_project_and_number(pool, project.id, item_number as i32).await?
+    {
+        return Ok(work_item);
+    }
+
+    // The item may have moved to another project; follow its old number
+    if let Some(work_item_id) =
+        WorkItemRepository::find_id_by_alias(pool, project.id, item_number as i32).await?
+        && let Some(work_item) = WorkItemRepository::find_by_id(pool, work_item_id).await?
+    {
+        return Ok(work_item);
+    }
+
+    Err(ApiError::NotFound {
+        message: format!(
+            "Work item {} not found in project {}",
+            identifier, project_key
+        ),
+        location: ErrorLocation::from(Location::caller()),
+    })
 }
@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+pub(crate) mod schedule;
+pub(crate) mod schedule_query;
+pub(crate) mod schedule_response;
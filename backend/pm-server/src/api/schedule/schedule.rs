@@ -0,0 +1,134 @@
+//! Schedule analysis REST API handler
+//!
+//! Orders a project, epic or sprint by its `blocks` dependencies and
+//! computes the critical path, per-item slack and Gantt offsets.
+
+use crate::{
+    ApiError, ApiResult, ScheduleQuery, ScheduleResponse, TenantState, resolve_project,
+    resolve_work_item,
+};
+
+use pm_core::{Schedule, ScheduleWeight, WorkItemType};
+use pm_db::{DependencyRepository, ProjectRepository, SprintRepository, WorkItemRepository};
+use pm_ws::compute_hierarchy_maps;
+
+use std::collections::HashSet;
+use std::{panic::Location, str::FromStr};
+
+use axum::{Json, extract::Query};
+use error_location::ErrorLocation;
+use uuid::Uuid;
+
+/// GET /api/v1/schedule
+///
+/// Compute a critical-path schedule for one of `project_id`, `epic_id` or
+/// `sprint_id`. Done items are left out unless `include_done` is set, so a
+/// finished blocker no longer delays the items it blocks.
+#[utoipa::path(
+    get,
+    path = "/api/v1/schedule",
+    tag = "reports",
+    params(ScheduleQuery),
+    responses(
+        (status = 200, description = "Critical-path schedule for the scope", body = ScheduleResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn get_schedule(
+    TenantState(state): TenantState,
+    Query(query): Query<ScheduleQuery>,
+) -> ApiResult<Json<ScheduleResponse>> {
+    // 1. Parse parameters
+    let weight =
+        ScheduleWeight::from_str(query.weight.as_deref().unwrap_or("estimate")).map_err(|e| {
+            ApiError::Validation {
+                message: format!("{}. Valid values: estimate, story_points", e),
+                field: Some("weight".into()),
+                location: ErrorLocation::from(Location::caller()),
+            }
+        })?;
+    let scopes = [&query.project_id, &query.epic_id, &query.sprint_id]
+        .iter()
+        .filter(|s| s.is_some())
+        .count();
+    if scopes != 1 {
+        return Err(ApiError::Validation {
+            message: "Exactly one of project_id, epic_id or sprint_id is required".into(),
+            field: None,
+            location: ErrorLocation::from(Location::caller()),
+        });
+    }
+
+    // 2. Resolve the scope to a project and the items inside it
+    let pool = &state.pool;
+    let (scope, scope_id, project_id) = if let Some(ref id) = query.project_id {
+        let project = resolve_project(pool, id).await?;
+        ("project", project.id, project.id)
+    } else if let Some(ref id) = query.epic_id {
+        let epic = resolve_work_item(pool, id).await?;
+        if epic.item_type != WorkItemType::Epic {
+            return Err(ApiError::Validation {
+                message: format!(
+                    "Work item {} is a {}, not an epic",
+                    id,
+                    epic.item_type.as_str()
+                ),
+                field: Some("epic_id".into()),
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+        ("epic", epic.id, epic.project_id)
+    } else {
+        let id = query.sprint_id.as_deref().unwrap_or_default();
+        let sprint = SprintRepository::new(pool.clone())
+            .find_by_id(Uuid::parse_str(id)?)
+            .await?
+            .ok_or_else(|| ApiError::NotFound {
+                message: format!("Sprint {} not found", id),
+                location: ErrorLocation::from(Location::caller()),
+            })?;
+        ("sprint", sprint.id, sprint.project_id)
+    };
+
+    let project = ProjectRepository::new(pool.clone())
+        .find_by_id(project_id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound {
+            message: format!("Project {} not found", project_id),
+            location: ErrorLocation::from(Location::caller()),
+        })?;
+
+    let all_items = WorkItemRepository::find_by_project(pool, project_id, true).await?;
+    let in_scope: HashSet<Uuid> = match scope {
+        "epic" => compute_hierarchy_maps(&all_items)
+            .get(&scope_id)
+            .map(|h| {
+                h.descendant_ids
+                    .iter()
+                    .filter_map(|id| Uuid::parse_str(id).ok())
+                    .collect()
+            })
+            .unwrap_or_default(),
+        "sprint" => all_items
+            .iter()
+            .filter(|i| i.sprint_id == Some(scope_id))
+            .map(|i| i.id)
+            .collect(),
+        _ => all_items.iter().map(|i| i.id).collect(),
+    };
+    let items: Vec<_> = all_items
+        .into_iter()
+        .filter(|i| in_scope.contains(&i.id) && (query.include_done || i.status != "done"))
+        .collect();
+
+    // 3. Compute
+    let dependencies = DependencyRepository::new(pool.clone()).find_all().await?;
+    let schedule = Schedule::compute(&items, &dependencies, weight, &project.key)?;
+
+    Ok(Json(ScheduleResponse {
+        scope: scope.to_string(),
+        scope_id: scope_id.to_string(),
+        schedule,
+    }))
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// Query parameters for schedule analysis. Exactly one scope must be given.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ScheduleQuery {
+    /// Schedule every item in a project (UUID or project key)
+    pub project_id: Option<String>,
+    /// Schedule the descendants of an epic (UUID or display key)
+    pub epic_id: Option<String>,
+    /// Schedule the items assigned to a sprint (UUID)
+    pub sprint_id: Option<String>,
+    /// Duration source: estimate (default) or story_points
+    pub weight: Option<String>,
+    /// Include items with status 'done' (excluded by default)
+    #[serde(default)]
+    pub include_done: bool,
+}
@@ -0,0 +1,13 @@
+use pm_core::Schedule;
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ScheduleResponse {
+    /// "project", "epic" or "sprint"
+    pub scope: String,
+    /// ID of the project, epic or sprint the schedule covers
+    pub scope_id: String,
+    pub schedule: Schedule,
+}
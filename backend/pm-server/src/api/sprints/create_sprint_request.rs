@@ -1,7 +1,8 @@
 use serde::Deserialize;
+use utoipa::ToSchema;
 
 /// Request body for creating a sprint
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateSprintRequest {
     pub project_id: String,
     pub name: String,
@@ -1,6 +1,7 @@
 pub(crate) mod create_sprint_request;
 pub(crate) mod sprint_list_response;
 pub(crate) mod sprint_response;
+pub(crate) mod sprint_summary_response;
 #[allow(clippy::module_inception)]
 pub(crate) mod sprints;
 pub(crate) mod update_sprint_request;
@@ -1,9 +1,14 @@
-use pm_core::SprintDto;
-
 use serde::Serialize;
+use serde_json::Value;
+use utoipa::ToSchema;
 
 /// Response wrapper for a list of sprints
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct SprintListResponse {
-    pub sprints: Vec<SprintDto>,
+    /// Sprint DTOs, reduced to the requested `fields`
+    pub sprints: Vec<Value>,
+    /// Matching items across all pages
+    pub total: usize,
+    /// Cursor for the next page; None on the last page
+    pub next_cursor: Option<String>,
 }
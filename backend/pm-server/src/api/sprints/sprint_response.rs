@@ -1,9 +1,10 @@
 use pm_core::SprintDto;
 
 use serde::Serialize;
+use utoipa::ToSchema;
 
 /// Response wrapper for a single sprint
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct SprintResponse {
     pub sprint: SprintDto,
 }
@@ -0,0 +1,10 @@
+use pm_core::SprintEstimateSummary;
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Response wrapper for a sprint's estimate summary
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SprintSummaryResponse {
+    pub summary: SprintEstimateSummary,
+}
@@ -4,38 +4,55 @@
 //! via WebSocket so connected clients see updates in real-time.
 
 use crate::{
-    ApiError, ApiResult, CreateSprintRequest, DeleteResponse, SprintListResponse, SprintResponse,
-    UpdateSprintRequest, UserId, api::resolve::resolve_project,
+    ApiError, ApiResult, CreateSprintRequest, DeleteResponse, PageQuery, Preconditions,
+    SprintListResponse, SprintResponse, SprintSummaryResponse, TenantState, UpdateSprintRequest,
+    UserId, api::resolve::resolve_project, not_modified, precondition_failed, tagged_json,
 };
 
-use pm_core::{ActivityLog, Sprint, SprintDto, SprintStatus};
-use pm_db::{ActivityLogRepository, SprintRepository};
+use pm_core::{ActivityLog, Sprint, SprintDto, SprintEstimateSummary, SprintStatus};
+use pm_db::{ActivityLogRepository, SprintRepository, TimeEntryRepository, WorkItemRepository};
 use pm_ws::{
-    AppState, build_activity_log_created_event, build_sprint_created_response,
-    build_sprint_deleted_response, build_sprint_updated_response, sanitize_string,
+    build_activity_log_created_event, build_sprint_created_response, build_sprint_deleted_response,
+    build_sprint_updated_response, sanitize_string,
 };
 
 use std::{panic::Location, str::FromStr};
 
 use axum::{
     Json,
-    extract::{Path, State, ws::Message},
+    extract::{Path, Query, ws::Message},
+    response::Response,
 };
 use chrono::{DateTime, Utc};
 use error_location::ErrorLocation;
 use prost::Message as ProstMessage;
 use uuid::Uuid;
 
+/// Fields sprint lists can be sorted on
+const SPRINT_SORT_FIELDS: [&str; 5] = ["start_date", "end_date", "name", "status", "created_at"];
+
 // =============================================================================
 // Handlers
 // =============================================================================
 
 /// GET /api/v1/projects/:project_id/sprints
 ///
-/// List all sprints for a project
+/// List sprints for a project, one page at a time
+#[utoipa::path(
+    get,
+    path = "/api/v1/projects/{project_id}/sprints",
+    tag = "sprints",
+    params(("project_id" = String, Path, description = "Project UUID or key"), PageQuery),
+    responses(
+        (status = 200, description = "One page of sprints", body = SprintListResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
 pub async fn list_sprints(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     Path(project_id): Path<String>,
+    Query(page): Query<PageQuery>,
 ) -> ApiResult<Json<SprintListResponse>> {
     let project = resolve_project(&state.pool, &project_id).await?;
     let project_uuid = project.id;
@@ -43,18 +60,39 @@ pub async fn list_sprints(
     let repo = SprintRepository::new(state.pool.clone());
     let sprints = repo.find_by_project(project_uuid).await?;
 
+    let sprints: Vec<SprintDto> = sprints.into_iter().map(SprintDto::from).collect();
+    let page = page.paginate(
+        &sprints,
+        &state.api_config,
+        &SPRINT_SORT_FIELDS,
+        "start_date",
+    )?;
     Ok(Json(SprintListResponse {
-        sprints: sprints.into_iter().map(SprintDto::from).collect(),
+        sprints: page.items,
+        total: page.total,
+        next_cursor: page.next_cursor,
     }))
 }
 
 /// GET /api/v1/sprints/:id
 ///
-/// Get a single sprint by ID
+/// Get a single sprint by ID. Tagged with its version; honors `If-None-Match`.
+#[utoipa::path(
+    get,
+    path = "/api/v1/sprints/{id}",
+    tag = "sprints",
+    params(("id" = String, Path, description = "Sprint UUID"), Preconditions),
+    responses(
+        (status = 200, description = "The sprint, with its version as `ETag`", body = SprintResponse),
+        (status = 304, description = "`If-None-Match` matches the current version"),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
 pub async fn get_sprint(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     Path(id): Path<String>,
-) -> ApiResult<Json<SprintResponse>> {
+    preconditions: Preconditions,
+) -> ApiResult<Response> {
     let sprint_id = Uuid::parse_str(&id)?;
 
     let repo = SprintRepository::new(state.pool.clone());
@@ -66,16 +104,72 @@ pub async fn get_sprint(
             location: ErrorLocation::from(Location::caller()),
         })?;
 
-    Ok(Json(SprintResponse {
-        sprint: sprint.into(),
+    if preconditions.is_not_modified(sprint.version) {
+        return Ok(not_modified(sprint.version));
+    }
+    Ok(tagged_json(
+        sprint.version,
+        SprintResponse {
+            sprint: sprint.into(),
+        },
+    ))
+}
+
+/// GET /api/v1/sprints/:id/summary
+///
+/// Summarize original and remaining estimates against logged time for the
+/// sprint's work items, including how many items are over their estimate
+#[utoipa::path(
+    get,
+    path = "/api/v1/sprints/{id}/summary",
+    tag = "sprints",
+    params(("id" = String, Path, description = "Sprint UUID")),
+    responses(
+        (status = 200, description = "Estimate summary for the sprint", body = SprintSummaryResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn get_sprint_summary(
+    TenantState(state): TenantState,
+    Path(id): Path<String>,
+) -> ApiResult<Json<SprintSummaryResponse>> {
+    let sprint_id = Uuid::parse_str(&id)?;
+
+    let sprint = SprintRepository::new(state.pool.clone())
+        .find_by_id(sprint_id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound {
+            message: format!("Sprint {} not found", id),
+            location: ErrorLocation::from(Location::caller()),
+        })?;
+
+    let all_items =
+        WorkItemRepository::find_by_project(&state.pool, sprint.project_id, true).await?;
+    let logged_seconds = TimeEntryRepository::new(state.pool.clone())
+        .logged_seconds_by_project(sprint.project_id)
+        .await?;
+
+    Ok(Json(SprintSummaryResponse {
+        summary: SprintEstimateSummary::compute(sprint.id, &all_items, &logged_seconds),
     }))
 }
 
 /// POST /api/v1/sprints
 ///
 /// Create a new sprint. Broadcasts activity to WebSocket clients.
+#[utoipa::path(
+    post,
+    path = "/api/v1/sprints",
+    tag = "sprints",
+    request_body = CreateSprintRequest,
+    responses(
+        (status = 200, description = "The created sprint", body = SprintResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
 pub async fn create_sprint(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     UserId(user_id): UserId,
     Json(req): Json<CreateSprintRequest>,
 ) -> ApiResult<Json<SprintResponse>> {
@@ -191,13 +285,28 @@ pub async fn create_sprint(
 
 /// PUT /api/v1/sprints/:id
 ///
-/// Update a sprint. Uses optimistic locking via expected_version.
+/// Update a sprint. Uses optimistic locking via `If-Match` or expected_version.
+#[utoipa::path(
+    put,
+    path = "/api/v1/sprints/{id}",
+    tag = "sprints",
+    params(("id" = String, Path, description = "Sprint UUID"), Preconditions),
+    request_body = UpdateSprintRequest,
+    responses(
+        (status = 200, description = "The updated sprint", body = SprintResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+        (status = 409, description = "Version conflict or duplicate", body = crate::ApiErrorResponse),
+        (status = 412, description = "`If-Match` does not match; the current entity is returned beside `error`", body = SprintResponse),
+    )
+)]
 pub async fn update_sprint(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     UserId(user_id): UserId,
     Path(id): Path<String>,
+    preconditions: Preconditions,
     Json(req): Json<UpdateSprintRequest>,
-) -> ApiResult<Json<SprintResponse>> {
+) -> ApiResult<Response> {
     // 1. Parse sprint ID
     let sprint_id = Uuid::parse_str(&id)?;
 
@@ -212,16 +321,15 @@ pub async fn update_sprint(
         })?;
 
     // 3. Check optimistic locking
-    if sprint.version != req.expected_version {
-        return Err(ApiError::Conflict {
-            message: format!(
-                "Version mismatch: expected {}, found {}",
-                req.expected_version, sprint.version
-            ),
-            current_version: sprint.version,
-            location: ErrorLocation::from(Location::caller()),
-        });
+    if preconditions.if_match_fails(sprint.version) {
+        return Err(precondition_failed(
+            sprint.version,
+            &SprintResponse {
+                sprint: sprint.into(),
+            },
+        ));
     }
+    preconditions.check_expected_version(sprint.version, req.expected_version)?;
 
     // 4. Apply updates
     let mut changed = false;
@@ -305,9 +413,12 @@ pub async fn update_sprint(
 
     if !changed {
         // No changes, return current state
-        return Ok(Json(SprintResponse {
-            sprint: sprint.into(),
-        }));
+        return Ok(tagged_json(
+            sprint.version,
+            SprintResponse {
+                sprint: sprint.into(),
+            },
+        ));
     }
 
     // 5. Update metadata
@@ -370,18 +481,33 @@ pub async fn update_sprint(
         sprint.name
     );
 
-    Ok(Json(SprintResponse {
-        sprint: sprint.into(),
-    }))
+    Ok(tagged_json(
+        sprint.version,
+        SprintResponse {
+            sprint: sprint.into(),
+        },
+    ))
 }
 
 /// DELETE /api/v1/sprints/:id
 ///
-/// Soft delete a sprint. Broadcasts activity to WebSocket clients.
+/// Soft delete a sprint. Broadcasts activity to WebSocket clients. Honors `If-Match`.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/sprints/{id}",
+    tag = "sprints",
+    params(("id" = String, Path, description = "Sprint UUID"), Preconditions),
+    responses(
+        (status = 200, description = "The sprint was deleted", body = DeleteResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+        (status = 412, description = "`If-Match` does not match; the current entity is returned beside `error`", body = SprintResponse),
+    )
+)]
 pub async fn delete_sprint(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     UserId(user_id): UserId,
     Path(id): Path<String>,
+    preconditions: Preconditions,
 ) -> ApiResult<Json<DeleteResponse>> {
     // 1. Parse sprint ID
     let sprint_id = Uuid::parse_str(&id)?;
@@ -395,6 +521,14 @@ pub async fn delete_sprint(
             message: format!("Sprint {} not found", id),
             location: ErrorLocation::from(Location::caller()),
         })?;
+    if preconditions.if_match_fails(sprint.version) {
+        return Err(precondition_failed(
+            sprint.version,
+            &SprintResponse {
+                sprint: sprint.into(),
+            },
+        ));
+    }
 
     // 3. Execute transaction
     let now = Utc::now();
@@ -1,7 +1,8 @@
 use serde::Deserialize;
+use utoipa::ToSchema;
 
 /// Request body for updating a sprint
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateSprintRequest {
     #[serde(default)]
     pub name: Option<String>,
@@ -19,6 +20,7 @@ pub struct UpdateSprintRequest {
     #[serde(default)]
     pub status: Option<String>,
 
-    /// Required for optimistic locking
-    pub expected_version: i32,
+    /// Optimistic locking; may be replaced by an `If-Match` header
+    #[serde(default)]
+    pub expected_version: Option<i32>,
 }
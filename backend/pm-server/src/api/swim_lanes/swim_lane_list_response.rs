@@ -1,9 +1,10 @@
 use pm_core::SwimLaneDto;
 
 use serde::Serialize;
+use utoipa::ToSchema;
 
 /// Response wrapper for list of swim lanes
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct SwimLaneListResponse {
     pub swim_lanes: Vec<SwimLaneDto>,
 }
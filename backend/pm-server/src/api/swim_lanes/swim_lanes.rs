@@ -3,16 +3,12 @@
 //! Swim lanes are fixed configuration created by migrations.
 //! This module provides read-only access via GET endpoint.
 
-use crate::{ApiResult, SwimLaneListResponse, api::resolve::resolve_project};
+use crate::{ApiResult, SwimLaneListResponse, TenantState, api::resolve::resolve_project};
 
 use pm_core::SwimLaneDto;
 use pm_db::SwimLaneRepository;
-use pm_ws::AppState;
 
-use axum::{
-    Json,
-    extract::{Path, State},
-};
+use axum::{Json, extract::Path};
 
 // =============================================================================
 // Handlers
@@ -21,8 +17,18 @@ use axum::{
 /// GET /api/v1/projects/:project_id/swim-lanes
 ///
 /// List all swim lanes for a project (ordered by position)
+#[utoipa::path(
+    get,
+    path = "/api/v1/projects/{project_id}/swim-lanes",
+    tag = "swim-lanes",
+    params(("project_id" = String, Path, description = "Project UUID or key")),
+    responses(
+        (status = 200, description = "Swim lanes in position order", body = SwimLaneListResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
 pub async fn list_swim_lanes(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     Path(project_id): Path<String>,
 ) -> ApiResult<Json<SwimLaneListResponse>> {
     let project = resolve_project(&state.pool, &project_id).await?;
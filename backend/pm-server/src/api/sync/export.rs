@@ -1,28 +1,33 @@
-use crate::{ApiResult, resolve_work_item};
+use crate::{ApiError, ApiResult, TenantState, resolve_work_item};
 
-use pm_core::ExportData;
 use pm_core::{
-    CommentDto, DependencyDto, ProjectDto, SprintDto, SwimLaneDto, TimeEntryDto, WorkItemDto,
+    ActivityLogDto, CommentDto, CustomFieldDefinitionDto, DependencyDto, LlmContextDto, ProjectDto,
+    ProjectMemberDto, SprintDto, SwimLaneDto, TimeEntryDto, WorkItemDto,
 };
+use pm_core::{CURRENT_EXPORT_SCHEMA_VERSION, ExportData};
 use pm_db::{
-    CommentRepository, DependencyRepository, ProjectRepository, SprintRepository,
+    ActivityLogRepository, CommentRepository, CustomFieldRepository, DependencyRepository,
+    LlmContextRepository, ProjectMemberRepository, ProjectRepository, SprintRepository,
     SwimLaneRepository, TimeEntryRepository, WorkItemRepository,
 };
-use pm_ws::AppState;
 
-use axum::{
-    Json,
-    extract::{Query, State},
-};
-use chrono::Utc;
+use std::collections::HashMap;
+use std::panic::Location;
+
+use axum::{Json, extract::Query};
+use chrono::{DateTime, Utc};
+use error_location::ErrorLocation;
 use serde::Deserialize;
+use sqlx::SqlitePool;
+use utoipa::IntoParams;
 use uuid::Uuid;
 
 // ============================================================================
 // Query Parameters
 // ============================================================================
 
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct ExportQuery {
     /// Scope export to a single work item (UUID)
     pub work_item: Option<String>,
@@ -41,6 +46,9 @@ pub struct ExportQuery {
     /// Include time entries for matched work items
     #[serde(default)]
     pub time_entries: bool,
+    /// Full export only: just the records created or changed at or after
+    /// this Unix timestamp
+    pub since: Option<i64>,
 }
 
 // ============================================================================
@@ -50,52 +58,37 @@ pub struct ExportQuery {
 /// Export data (GET /api/v1/sync/export)
 ///
 /// Without query params: exports the full database.
+/// With `?since=<unix seconds>`: exports only records changed since then, for
+/// incremental peer sync. Swim lanes and deletions are not included.
 /// With `?work_item=<UUID>`: exports only the specified work item and opted-in related data.
+#[utoipa::path(
+    get,
+    path = "/api/v1/sync/export",
+    tag = "sync",
+    params(ExportQuery),
+    responses(
+        (status = 200, description = "Exported data", body = ExportData),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
 pub async fn sync_export(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     Query(query): Query<ExportQuery>,
 ) -> ApiResult<Json<ExportData>> {
     let pool = &state.pool;
 
-    // Load projects for display_key resolution (project_id → key)
-    let projects = ProjectRepository::new(pool.clone()).find_all().await?;
-    let project_keys: std::collections::HashMap<Uuid, String> =
-        projects.iter().map(|p| (p.id, p.key.clone())).collect();
-
     // Full export (no work_item filter)
     if query.work_item.is_none() {
-        let sprints = SprintRepository::new(pool.clone()).find_all().await?;
-        let swim_lanes = SwimLaneRepository::new(pool.clone()).find_all().await?;
-        let work_items = WorkItemRepository::find_all(pool, true).await?;
-        let comments = CommentRepository::new(pool.clone()).find_all().await?;
-        let dependencies = DependencyRepository::new(pool.clone()).find_all().await?;
-        let time_entries = TimeEntryRepository::new(pool.clone()).find_all().await?;
-
-        let data = ExportData {
-            schema_version: 1,
-            exported_at: Utc::now().to_rfc3339(),
-            exported_by: "pm-server".to_string(),
-            projects: projects.into_iter().map(ProjectDto::from).collect(),
-            sprints: sprints.into_iter().map(SprintDto::from).collect(),
-            swim_lanes: swim_lanes.into_iter().map(SwimLaneDto::from).collect(),
-            work_items: work_items
-                .into_iter()
-                .map(|w| {
-                    let key = project_keys
-                        .get(&w.project_id)
-                        .map(|k| k.as_str())
-                        .unwrap_or("UNKNOWN");
-                    WorkItemDto::from_work_item(w, key)
-                })
-                .collect(),
-            comments: comments.into_iter().map(CommentDto::from).collect(),
-            dependencies: dependencies.into_iter().map(DependencyDto::from).collect(),
-            time_entries: time_entries.into_iter().map(TimeEntryDto::from).collect(),
-        };
-
-        return Ok(Json(data));
+        let since = query.since.map(parse_since).transpose()?;
+        return Ok(Json(export_all(pool, since).await?));
     }
 
+    // Load projects for display_key resolution (project_id → key)
+    let projects = ProjectRepository::new(pool.clone()).find_all().await?;
+    let project_keys: HashMap<Uuid, String> =
+        projects.iter().map(|p| (p.id, p.key.clone())).collect();
+
     // Scoped export: filter to a specific work item (+ optional descendants/related data)
     let work_item_id_str = query.work_item.as_ref().unwrap();
     let root_work_item = resolve_work_item(pool, work_item_id_str).await?;
@@ -198,8 +191,20 @@ pub async fn sync_export(
         vec![]
     };
 
+    // Custom field definitions for the projects of matched work items, so the
+    // exported values can be interpreted on import
+    let project_ids: std::collections::HashSet<&str> =
+        work_items.iter().map(|w| w.project_id.as_str()).collect();
+    let custom_fields = CustomFieldRepository::new(pool.clone())
+        .find_all()
+        .await?
+        .into_iter()
+        .filter(|f| project_ids.contains(f.project_id.to_string().as_str()))
+        .map(CustomFieldDefinitionDto::from)
+        .collect();
+
     let data = ExportData {
-        schema_version: 1,
+        schema_version: CURRENT_EXPORT_SCHEMA_VERSION,
         exported_at: Utc::now().to_rfc3339(),
         exported_by: "pm-server".to_string(),
         projects: vec![],
@@ -209,7 +214,114 @@ pub async fn sync_export(
         comments,
         dependencies,
         time_entries,
+        custom_fields,
+        project_members: vec![],
+        llm_context: vec![],
+        activity_log: vec![],
     };
 
     Ok(Json(data))
 }
+
+/// Export every record, or with `since` only those created or changed at or
+/// after it. Swim lanes are fixed configuration and only sent in full.
+pub(crate) async fn export_all(
+    pool: &SqlitePool,
+    since: Option<DateTime<Utc>>,
+) -> ApiResult<ExportData> {
+    // Taken first so records changed while loading are sent again next time
+    let exported_at = Utc::now();
+    let changed = |updated_at: DateTime<Utc>| since.is_none_or(|since| updated_at >= since);
+
+    let projects = ProjectRepository::new(pool.clone()).find_all().await?;
+    let project_keys: HashMap<Uuid, String> =
+        projects.iter().map(|p| (p.id, p.key.clone())).collect();
+    let sprints = SprintRepository::new(pool.clone()).find_all().await?;
+    let swim_lanes = if since.is_none() {
+        SwimLaneRepository::new(pool.clone()).find_all().await?
+    } else {
+        vec![]
+    };
+    let work_items = WorkItemRepository::find_all(pool, true).await?;
+    let comments = CommentRepository::new(pool.clone()).find_all().await?;
+    let dependencies = DependencyRepository::new(pool.clone()).find_all().await?;
+    let time_entries = TimeEntryRepository::new(pool.clone()).find_all().await?;
+    let custom_fields = CustomFieldRepository::new(pool.clone()).find_all().await?;
+    let project_members = ProjectMemberRepository::new(pool.clone())
+        .find_all()
+        .await?;
+    let llm_context = LlmContextRepository::list_all(pool).await?;
+    let activity_log = ActivityLogRepository::find_all(pool).await?;
+
+    Ok(ExportData {
+        schema_version: CURRENT_EXPORT_SCHEMA_VERSION,
+        exported_at: exported_at.to_rfc3339(),
+        exported_by: "pm-server".to_string(),
+        projects: projects
+            .into_iter()
+            .filter(|p| changed(p.updated_at))
+            .map(ProjectDto::from)
+            .collect(),
+        sprints: sprints
+            .into_iter()
+            .filter(|s| changed(s.updated_at))
+            .map(SprintDto::from)
+            .collect(),
+        swim_lanes: swim_lanes.into_iter().map(SwimLaneDto::from).collect(),
+        work_items: work_items
+            .into_iter()
+            .filter(|w| changed(w.updated_at))
+            .map(|w| {
+                let key = project_keys
+                    .get(&w.project_id)
+                    .map(|k| k.as_str())
+                    .unwrap_or("UNKNOWN");
+                WorkItemDto::from_work_item(w, key)
+            })
+            .collect(),
+        comments: comments
+            .into_iter()
+            .filter(|c| changed(c.updated_at))
+            .map(CommentDto::from)
+            .collect(),
+        dependencies: dependencies
+            .into_iter()
+            .filter(|d| changed(d.created_at))
+            .map(DependencyDto::from)
+            .collect(),
+        time_entries: time_entries
+            .into_iter()
+            .filter(|t| changed(t.updated_at))
+            .map(TimeEntryDto::from)
+            .collect(),
+        custom_fields: custom_fields
+            .into_iter()
+            .filter(|f| changed(f.updated_at))
+            .map(CustomFieldDefinitionDto::from)
+            .collect(),
+        project_members: project_members
+            .into_iter()
+            .filter(|m| changed(m.created_at))
+            .map(ProjectMemberDto::from)
+            .collect(),
+        llm_context: llm_context
+            .into_iter()
+            .filter(|c| changed(c.updated_at))
+            .map(LlmContextDto::from)
+            .collect(),
+        activity_log: activity_log
+            .into_iter()
+            .filter(|a| changed(a.timestamp))
+            .map(ActivityLogDto::from)
+            .collect(),
+    })
+}
+
+#[track_caller]
+fn parse_since(secs: i64) -> ApiResult<DateTime<Utc>> {
+    DateTime::from_timestamp(secs, 0).ok_or_else(|| ApiError::Validation {
+        message: format!("Invalid since timestamp: {}", secs),
+        field: Some("since".into()),
+        location: ErrorLocation::from(Location::caller()),
+    })
+}
@@ -1,52 +1,130 @@
+use crate::TenantState;
 use crate::api::error::{ApiError, Result as ApiResult};
+use crate::api::sync::validate::ImportRecords;
 
-use pm_core::{Comment, Dependency, Project, ProjectMember, Sprint, TimeEntry, WorkItem};
-use pm_core::{ExportData, ImportResult};
+use pm_core::{
+    ConflictStrategy, ExportData, FieldChange, ImportAction, ImportDiff, ImportOptions,
+    ImportResult, ProjectMember, WorkItem, upgrade_export_data,
+};
 use pm_db::{
-    CommentRepository, DependencyRepository, ProjectMemberRepository, ProjectRepository,
-    SprintRepository, TimeEntryRepository, WorkItemRepository,
+    ActivityLogRepository, CommentRepository, CustomFieldRepository, DependencyRepository,
+    LlmContextRepository, ProjectMemberRepository, ProjectRepository, SprintRepository,
+    TimeEntryRepository, WorkItemRepository,
 };
-use pm_ws::AppState;
 
-use axum::{Json, extract::State};
-use chrono::Utc;
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::panic::Location;
+
+use axum::{Json, extract::Query};
+use chrono::{DateTime, Utc};
+use error_location::ErrorLocation;
+use serde::Deserialize;
+use serde_json::Value;
+use sqlx::SqliteConnection;
+use utoipa::IntoParams;
 use uuid::Uuid;
 
+// ============================================================================
+// Query Parameters
+// ============================================================================
+
+#[derive(Debug, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ImportQuery {
+    /// Run the import and roll it back, reporting what would change
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Unix timestamp of the last sync with the sender, in this server's
+    /// clock. Local records changed since then that differ from the import
+    /// are conflicts instead of being decided by `updated_at`.
+    pub base: Option<i64>,
+    /// What to do with conflicts (only used with `base`)
+    #[serde(default)]
+    #[param(inline)]
+    pub on_conflict: ConflictStrategy,
+}
+
 // ============================================================================
 // Import Handler
 // ============================================================================
 
 /// Import data (POST /api/v1/sync/import)
+///
+/// The payload is validated as a whole first; any problem rejects the
+/// import with every problem listed. Records are matched by ID; an existing
+/// record is only overwritten when the imported one has a later
+/// `updated_at`. With `?base=<unix seconds>`, local records changed since
+/// then that differ from the import are reported as conflicts and left
+/// alone (see `on_conflict`). The whole import runs in one transaction,
+/// which `?dry_run=true` rolls back. Payloads from older schema versions
+/// are upgraded to the current one first.
+#[utoipa::path(
+    post,
+    path = "/api/v1/sync/import",
+    tag = "sync",
+    params(ImportQuery),
+    request_body = ExportData,
+    responses(
+        (status = 200, description = "Created, updated and skipped records per entity", body = ImportResult),
+        (status = 400, description = "Invalid payload; `IMPORT_REJECTED` lists every problem in `problems`", body = crate::ApiErrorResponse),
+        (status = 413, description = "Body exceeds `api.max_import_bytes`"),
+    )
+)]
 pub async fn sync_import(
-    State(state): State<AppState>,
-    Json(data): Json<ExportData>,
+    TenantState(state): TenantState,
+    Query(query): Query<ImportQuery>,
+    Json(payload): Json<Value>,
 ) -> ApiResult<Json<ImportResult>> {
-    if data.schema_version != 1 {
-        return Err(ApiError::Validation {
-            message: format!("Unsupported schema version: {}", data.schema_version),
-            field: Some("schema_version".into()),
-            location: error_location::ErrorLocation::from(std::panic::Location::caller()),
-        });
+    let data = upgrade_export_data(payload)?;
+    let options = ImportOptions {
+        base: query
+            .base
+            .map(|secs| {
+                DateTime::from_timestamp(secs, 0).ok_or_else(|| ApiError::Validation {
+                    message: format!("Invalid base timestamp: {}", secs),
+                    field: Some("base".into()),
+                    location: ErrorLocation::from(Location::caller()),
+                })
+            })
+            .transpose()?,
+        on_conflict: query.on_conflict,
+    };
+
+    // Any error drops the transaction, rolling the whole import back
+    let mut tx = state.pool.begin().await?;
+    let diff = import_data(&mut tx, data, options).await?;
+    if query.dry_run {
+        tx.rollback().await?;
+    } else {
+        tx.commit().await?;
     }
 
-    let pool = &state.pool;
-    let mut result = ImportResult::default();
+    Ok(Json(ImportResult::new(diff, query.dry_run)))
+}
 
-    for dto in data.projects {
-        let project: Project =
-            dto.try_into()
-                .map_err(|e: pm_core::CoreError| ApiError::Internal {
-                    message: format!("Failed to convert project DTO: {}", e),
-                    location: error_location::ErrorLocation::from(std::panic::Location::caller()),
-                })?;
-        let repo = ProjectRepository::new(pool.clone());
+/// Validate and write `data` on `conn`. The caller owns the transaction
+/// and has already upgraded `data` to the current schema version.
+pub(crate) async fn import_data(
+    conn: &mut SqliteConnection,
+    data: ExportData,
+    options: ImportOptions,
+) -> ApiResult<ImportDiff> {
+    let records = ImportRecords::validate(conn, data).await?;
+    apply_import(conn, records, options).await
+}
 
-        let member_repo = ProjectMemberRepository::new(pool.clone());
+/// Write validated `records` on `conn`, recording what each record did
+async fn apply_import(
+    conn: &mut SqliteConnection,
+    records: ImportRecords,
+    options: ImportOptions,
+) -> ApiResult<ImportDiff> {
+    let mut diff = ImportDiff::default();
 
-        match repo.find_by_id(project.id).await? {
+    for project in records.projects {
+        match ProjectRepository::find_by_id_with(&mut *conn, project.id).await? {
             None => {
-                repo.create(&project).await?;
+                ProjectRepository::create_with(&mut *conn, &project).await?;
                 // Add creator as admin member (mirrors normal project creation)
                 let member = ProjectMember {
                     id: Uuid::new_v4(),
@@ -55,147 +133,263 @@ pub async fn sync_import(
                     role: "admin".to_string(),
                     created_at: Utc::now(),
                 };
-                member_repo.create(&member).await?;
-                result.projects.created += 1;
-            }
-            Some(existing) if project.updated_at > existing.updated_at => {
-                repo.update(&project).await?;
-                result.projects.updated += 1;
+                ProjectMemberRepository::create_with(&mut *conn, &member).await?;
+                diff.projects.create(project.id);
             }
-            Some(_) => {
-                result.projects.skipped += 1;
+            Some(existing) => {
+                let changes = FieldChange::between(&existing, &project);
+                match options.resolve(existing.updated_at, project.updated_at, &changes) {
+                    ImportAction::Update => {
+                        ProjectRepository::update_with(&mut *conn, &project).await?;
+                        diff.projects.update(project.id, changes);
+                    }
+                    ImportAction::Skip(reason) => diff.projects.skip(project.id, reason),
+                    ImportAction::Conflict => diff.projects.conflict(
+                        project.id,
+                        existing.updated_at,
+                        project.updated_at,
+                        changes,
+                    ),
+                }
             }
         }
     }
 
-    for dto in data.sprints {
-        let sprint: Sprint =
-            dto.try_into()
-                .map_err(|e: pm_core::CoreError| ApiError::Internal {
-                    message: format!("Failed to convert sprint DTO: {}", e),
-                    location: error_location::ErrorLocation::from(std::panic::Location::caller()),
-                })?;
-        let repo = SprintRepository::new(pool.clone());
-
-        match repo.find_by_id(sprint.id).await? {
+    // Custom field definitions must exist before work item values reference them
+    for field in records.custom_fields {
+        match CustomFieldRepository::find_by_id_with(&mut *conn, field.id).await? {
             None => {
-                repo.create(&sprint).await?;
-                result.sprints.created += 1;
-            }
-            Some(existing) if sprint.updated_at > existing.updated_at => {
-                repo.update(&sprint).await?;
-                result.sprints.updated += 1;
+                // A different definition with the same name already owns this slot
+                if CustomFieldRepository::find_by_project_and_name_with(
+                    &mut *conn,
+                    field.project_id,
+                    &field.name,
+                )
+                .await?
+                .is_some()
+                {
+                    diff.custom_fields.skip(
+                        field.id,
+                        format!("a field named '{}' already exists", field.name),
+                    );
+                    continue;
+                }
+                CustomFieldRepository::create_with(&mut *conn, &field).await?;
+                diff.custom_fields.create(field.id);
             }
-            Some(_) => {
-                result.sprints.skipped += 1;
+            Some(existing) => {
+                let changes = FieldChange::between(&existing, &field);
+                match options.resolve(existing.updated_at, field.updated_at, &changes) {
+                    ImportAction::Update => {
+                        CustomFieldRepository::update_with(&mut *conn, &field).await?;
+                        diff.custom_fields.update(field.id, changes);
+                    }
+                    ImportAction::Skip(reason) => diff.custom_fields.skip(field.id, reason),
+                    ImportAction::Conflict => diff.custom_fields.conflict(
+                        field.id,
+                        existing.updated_at,
+                        field.updated_at,
+                        changes,
+                    ),
+                }
             }
         }
     }
 
-    result.swim_lanes.skipped = data.swim_lanes.len();
+    for sprint in records.sprints {
+        match SprintRepository::find_by_id_with(&mut *conn, sprint.id).await? {
+            None => {
+                SprintRepository::create_with(&mut *conn, &sprint).await?;
+                diff.sprints.create(sprint.id);
+            }
+            Some(existing) => {
+                let changes = FieldChange::between(&existing, &sprint);
+                match options.resolve(existing.updated_at, sprint.updated_at, &changes) {
+                    ImportAction::Update => {
+                        SprintRepository::update_with(&mut *conn, &sprint).await?;
+                        diff.sprints.update(sprint.id, changes);
+                    }
+                    ImportAction::Skip(reason) => diff.sprints.skip(sprint.id, reason),
+                    ImportAction::Conflict => diff.sprints.conflict(
+                        sprint.id,
+                        existing.updated_at,
+                        sprint.updated_at,
+                        changes,
+                    ),
+                }
+            }
+        }
+    }
 
-    // Convert all work item DTOs first, then topologically sort so parents
-    // are inserted before children (parent_id FK references pm_work_items).
-    let mut work_items: Vec<WorkItem> = data
-        .work_items
-        .into_iter()
-        .map(|dto| {
-            dto.try_into()
-                .map_err(|e: pm_core::CoreError| ApiError::Internal {
-                    message: format!("Failed to convert work item DTO: {}", e),
-                    location: error_location::ErrorLocation::from(std::panic::Location::caller()),
-                })
-        })
-        .collect::<ApiResult<Vec<_>>>()?;
+    for id in &records.swim_lane_ids {
+        diff.swim_lanes
+            .skip(id, "swim lanes are fixed configuration");
+    }
 
+    // Parents are inserted before children (parent_id FK references pm_work_items)
+    let mut work_items = records.work_items;
     topological_sort_work_items(&mut work_items);
 
     for work_item in &work_items {
-        match WorkItemRepository::find_by_id(pool, work_item.id).await? {
+        match WorkItemRepository::find_by_id(&mut *conn, work_item.id).await? {
             None => {
-                WorkItemRepository::create(pool, work_item).await?;
-                result.work_items.created += 1;
+                WorkItemRepository::create(&mut *conn, work_item).await?;
+                diff.work_items.create(work_item.id);
             }
-            Some(existing) if work_item.updated_at > existing.updated_at => {
-                WorkItemRepository::update(pool, work_item).await?;
-                result.work_items.updated += 1;
-            }
-            Some(_) => {
-                result.work_items.skipped += 1;
+            Some(existing) => {
+                let changes = FieldChange::between(&existing, work_item);
+                match options.resolve(existing.updated_at, work_item.updated_at, &changes) {
+                    ImportAction::Update => {
+                        WorkItemRepository::update(&mut *conn, work_item).await?;
+                        // `update` leaves ranks to moves; an import carries its own
+                        WorkItemRepository::set_rank(&mut *conn, work_item.id, &work_item.rank)
+                            .await?;
+                        diff.work_items.update(work_item.id, changes);
+                    }
+                    ImportAction::Skip(reason) => diff.work_items.skip(work_item.id, reason),
+                    ImportAction::Conflict => diff.work_items.conflict(
+                        work_item.id,
+                        existing.updated_at,
+                        work_item.updated_at,
+                        changes,
+                    ),
+                }
             }
         }
     }
 
-    for dto in data.comments {
-        let comment: Comment =
-            dto.try_into()
-                .map_err(|e: pm_core::CoreError| ApiError::Internal {
-                    message: format!("Failed to convert comment DTO: {}", e),
-                    location: error_location::ErrorLocation::from(std::panic::Location::caller()),
-                })?;
-        let repo = CommentRepository::new(pool.clone());
-
-        match repo.find_by_id(comment.id).await? {
+    for comment in records.comments {
+        match CommentRepository::find_by_id_with(&mut *conn, comment.id).await? {
             None => {
-                repo.create(&comment).await?;
-                result.comments.created += 1;
+                CommentRepository::create_with(&mut *conn, &comment).await?;
+                diff.comments.create(comment.id);
             }
-            Some(existing) if comment.updated_at > existing.updated_at => {
-                repo.update(&comment).await?;
-                result.comments.updated += 1;
+            Some(existing) => {
+                let changes = FieldChange::between(&existing, &comment);
+                match options.resolve(existing.updated_at, comment.updated_at, &changes) {
+                    ImportAction::Update => {
+                        CommentRepository::update_with(&mut *conn, &comment).await?;
+                        diff.comments.update(comment.id, changes);
+                    }
+                    ImportAction::Skip(reason) => diff.comments.skip(comment.id, reason),
+                    ImportAction::Conflict => diff.comments.conflict(
+                        comment.id,
+                        existing.updated_at,
+                        comment.updated_at,
+                        changes,
+                    ),
+                }
+            }
+        }
+    }
+
+    for dependency in records.dependencies {
+        match DependencyRepository::find_by_id_with(&mut *conn, dependency.id).await? {
+            None => {
+                DependencyRepository::create_with(&mut *conn, &dependency).await?;
+                diff.dependencies.create(dependency.id);
             }
             Some(_) => {
-                result.comments.skipped += 1;
+                diff.dependencies
+                    .skip(dependency.id, "already exists (dependencies are immutable)");
             }
         }
     }
 
-    for dto in data.dependencies {
-        let dependency: Dependency =
-            dto.try_into()
-                .map_err(|e: pm_core::CoreError| ApiError::Internal {
-                    message: format!("Failed to convert dependency DTO: {}", e),
-                    location: error_location::ErrorLocation::from(std::panic::Location::caller()),
-                })?;
-        let repo = DependencyRepository::new(pool.clone());
-
-        match repo.find_by_id(dependency.id).await? {
+    for time_entry in records.time_entries {
+        match TimeEntryRepository::find_by_id_with(&mut *conn, time_entry.id).await? {
             None => {
-                repo.create(&dependency).await?;
-                result.dependencies.created += 1;
+                TimeEntryRepository::create_with(&mut *conn, &time_entry).await?;
+                diff.time_entries.create(time_entry.id);
             }
-            Some(_) => {
-                // Dependencies are immutable - skip if exists
-                result.dependencies.skipped += 1;
+            Some(existing) => {
+                let changes = FieldChange::between(&existing, &time_entry);
+                match options.resolve(existing.updated_at, time_entry.updated_at, &changes) {
+                    ImportAction::Update => {
+                        TimeEntryRepository::update_with(&mut *conn, &time_entry).await?;
+                        diff.time_entries.update(time_entry.id, changes);
+                    }
+                    ImportAction::Skip(reason) => diff.time_entries.skip(time_entry.id, reason),
+                    ImportAction::Conflict => diff.time_entries.conflict(
+                        time_entry.id,
+                        existing.updated_at,
+                        time_entry.updated_at,
+                        changes,
+                    ),
+                }
             }
         }
     }
 
-    for dto in data.time_entries {
-        let time_entry: TimeEntry =
-            dto.try_into()
-                .map_err(|e: pm_core::CoreError| ApiError::Internal {
-                    message: format!("Failed to convert time entry DTO: {}", e),
-                    location: error_location::ErrorLocation::from(std::panic::Location::caller()),
-                })?;
-        let repo = TimeEntryRepository::new(pool.clone());
+    let members = ProjectMemberRepository::find_all_with(&mut *conn).await?;
+    let member_ids: HashSet<Uuid> = members.iter().map(|m| m.id).collect();
+    for member in records.project_members {
+        if member_ids.contains(&member.id) {
+            diff.project_members
+                .skip(member.id, "already exists (memberships are immutable)");
+            continue;
+        }
+        // Imported projects already made their creator an admin
+        if ProjectMemberRepository::find_by_user_and_project_with(
+            &mut *conn,
+            member.user_id,
+            member.project_id,
+        )
+        .await?
+        .is_some()
+        {
+            diff.project_members
+                .skip(member.id, "user is already a member of the project");
+            continue;
+        }
+        ProjectMemberRepository::create_with(&mut *conn, &member).await?;
+        diff.project_members.create(member.id);
+    }
 
-        match repo.find_by_id(time_entry.id).await? {
+    for entry in records.llm_context {
+        match LlmContextRepository::find_by_id(&mut *conn, entry.id).await? {
             None => {
-                repo.create(&time_entry).await?;
-                result.time_entries.created += 1;
+                LlmContextRepository::create(&mut *conn, &entry).await?;
+                diff.llm_context.create(entry.id);
             }
-            Some(existing) if time_entry.updated_at > existing.updated_at => {
-                repo.update(&time_entry).await?;
-                result.time_entries.updated += 1;
+            Some(existing) => {
+                // Seeded entries share IDs across databases but not
+                // timestamps, so only a content change counts
+                let changes: Vec<FieldChange> = FieldChange::between(&existing, &entry)
+                    .into_iter()
+                    .filter(|c| !matches!(c.field.as_str(), "created_at" | "updated_at"))
+                    .collect();
+                match options.resolve(existing.updated_at, entry.updated_at, &changes) {
+                    ImportAction::Update => {
+                        LlmContextRepository::update(&mut *conn, &entry).await?;
+                        diff.llm_context.update(entry.id, changes);
+                    }
+                    ImportAction::Skip(reason) => diff.llm_context.skip(entry.id, reason),
+                    ImportAction::Conflict => diff.llm_context.conflict(
+                        entry.id,
+                        existing.updated_at,
+                        entry.updated_at,
+                        changes,
+                    ),
+                }
+            }
+        }
+    }
+
+    for log in records.activity_log {
+        match ActivityLogRepository::find_by_id(&mut *conn, log.id).await? {
+            None => {
+                ActivityLogRepository::create(&mut *conn, &log).await?;
+                diff.activity_log.create(log.id);
             }
             Some(_) => {
-                result.time_entries.skipped += 1;
+                diff.activity_log
+                    .skip(log.id, "already exists (activity is append-only)");
             }
         }
     }
 
-    Ok(Json(result))
+    Ok(diff)
 }
 
 /// Topologically sort work items so parents appear before children.
@@ -1,2 +1,7 @@
 pub(crate) mod export;
 pub(crate) mod import;
+pub(crate) mod peer_sync_request;
+pub(crate) mod peer_sync_response;
+pub(crate) mod peers;
+pub(crate) mod sync_peer_list_response;
+pub(crate) mod validate;
@@ -0,0 +1,16 @@
+use pm_core::ConflictStrategy;
+
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PeerSyncRequest {
+    /// Base URL of the other pm-server, e.g. "http://10.0.0.5:8000"
+    pub remote: String,
+    /// What to do with records changed on both sides since the last sync.
+    /// `ours` keeps this server's version, `theirs` takes the peer's.
+    #[serde(default)]
+    pub on_conflict: ConflictStrategy,
+    /// Bearer token for the peer, when it has auth enabled
+    pub token: Option<String>,
+}
@@ -0,0 +1,12 @@
+use pm_core::{ImportResult, SyncPeer};
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PeerSyncResponse {
+    /// The peer's watermarks after the sync; unchanged while conflicts remain
+    pub peer: SyncPeer,
+    /// What the receiving side did: this server on pull, the peer on push
+    pub result: ImportResult,
+}
@@ -0,0 +1,235 @@
+//! Peer sync REST API handlers
+//!
+//! Two pm-servers exchange changes through each other's export and import
+//! endpoints. Each side keeps per-peer watermarks so only records changed
+//! since the last complete exchange are sent, and records changed on both
+//! sides are reported as conflicts instead of going to the newer one.
+//! Watermarks only advance once an exchange has no conflicts left, so
+//! conflicting records are offered again until they are resolved. Clocks
+//! on the two servers are assumed to be roughly in step.
+
+use crate::api::sync::{export::export_all, import::import_data};
+use crate::{
+    ApiError, ApiResult, PeerSyncRequest, PeerSyncResponse, SyncPeerListResponse, TenantState,
+};
+
+use pm_core::{ImportOptions, ImportResult, SyncPeer, upgrade_export_data};
+use pm_db::SyncPeerRepository;
+
+use std::panic::Location;
+use std::time::Duration;
+
+use axum::Json;
+use chrono::{DateTime, SubsecRound, Utc};
+use error_location::ErrorLocation;
+use serde::de::DeserializeOwned;
+
+const PEER_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Pull changes from a peer (POST /api/v1/sync/pull)
+///
+/// Imports the peer's records changed since the last complete pull. Local
+/// records changed since the last sync that differ from the peer's are
+/// conflicts, handled per `on_conflict`. Non-conflicting records are
+/// applied either way.
+#[utoipa::path(
+    post,
+    path = "/api/v1/sync/pull",
+    tag = "sync",
+    request_body = PeerSyncRequest,
+    responses(
+        (status = 200, description = "What the pull applied, skipped and found conflicting", body = PeerSyncResponse),
+        (status = 400, description = "Invalid request or the peer's data was rejected", body = crate::ApiErrorResponse),
+        (status = 502, description = "Peer unreachable or returned an error", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn sync_pull(
+    TenantState(state): TenantState,
+    Json(req): Json<PeerSyncRequest>,
+) -> ApiResult<Json<PeerSyncResponse>> {
+    let remote = parse_remote(&req.remote)?;
+    let peers = SyncPeerRepository::new(state.pool.clone());
+    let mut peer = peers
+        .find_by_url(&remote)
+        .await?
+        .unwrap_or_else(|| SyncPeer::new(&remote));
+
+    // Watermarks are stored in whole seconds; rounding down only resends
+    let started = Utc::now().trunc_subsecs(0);
+
+    let url = match peer.pulled_until {
+        Some(since) => format!("{}/api/v1/sync/export?since={}", remote, since.timestamp()),
+        None => format!("{}/api/v1/sync/export", remote),
+    };
+    // The peer may run an older release with an older export schema
+    let payload = send(peer_client()?.get(url), req.token.as_deref(), &remote).await?;
+    let data = upgrade_export_data(payload).map_err(|e| ApiError::BadGateway {
+        message: format!("Unusable export from {}: {}", remote, e),
+        location: ErrorLocation::from(Location::caller()),
+    })?;
+    let exported_at = DateTime::parse_from_rfc3339(&data.exported_at)
+        .map_err(|e| ApiError::BadGateway {
+            message: format!("Invalid exported_at from {}: {}", remote, e),
+            location: ErrorLocation::from(Location::caller()),
+        })?
+        .with_timezone(&Utc)
+        .trunc_subsecs(0);
+
+    // Before the first sync every differing local record is a conflict
+    let options = ImportOptions {
+        base: Some(peer.synced_at.unwrap_or(DateTime::UNIX_EPOCH)),
+        on_conflict: req.on_conflict,
+    };
+    let mut tx = state.pool.begin().await?;
+    let diff = import_data(&mut tx, data, options).await?;
+    tx.commit().await?;
+    let result = ImportResult::new(diff, false);
+
+    if result.conflicts() == 0 {
+        peer.pulled_until = Some(exported_at);
+        peer.synced_at = Some(started);
+    }
+    peer.updated_at = Utc::now();
+    peers.upsert(&peer).await?;
+
+    log::info!(
+        "Pulled from {} ({} conflict(s))",
+        remote,
+        result.conflicts()
+    );
+
+    Ok(Json(PeerSyncResponse { peer, result }))
+}
+
+/// Push changes to a peer (POST /api/v1/sync/push)
+///
+/// Sends local records changed since the last complete push to the peer's
+/// import, which reports conflicts against its own changes since the last
+/// sync. `on_conflict` is from this server's side: `ours` overwrites the
+/// peer's version.
+#[utoipa::path(
+    post,
+    path = "/api/v1/sync/push",
+    tag = "sync",
+    request_body = PeerSyncRequest,
+    responses(
+        (status = 200, description = "What the peer applied, skipped and found conflicting", body = PeerSyncResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 502, description = "Peer unreachable, returned an error or rejected the data", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn sync_push(
+    TenantState(state): TenantState,
+    Json(req): Json<PeerSyncRequest>,
+) -> ApiResult<Json<PeerSyncResponse>> {
+    let remote = parse_remote(&req.remote)?;
+    let peers = SyncPeerRepository::new(state.pool.clone());
+    let mut peer = peers
+        .find_by_url(&remote)
+        .await?
+        .unwrap_or_else(|| SyncPeer::new(&remote));
+    let started = Utc::now().trunc_subsecs(0);
+
+    let data = export_all(&state.pool, peer.pushed_until).await?;
+
+    // The peer's base is in its own clock: the export time of our last pull
+    let url = format!(
+        "{}/api/v1/sync/import?base={}&on_conflict={}",
+        remote,
+        peer.pulled_until
+            .unwrap_or(DateTime::UNIX_EPOCH)
+            .timestamp(),
+        req.on_conflict.reversed().as_str()
+    );
+    let result: ImportResult = send(
+        peer_client()?.post(url).json(&data),
+        req.token.as_deref(),
+        &remote,
+    )
+    .await?;
+
+    if result.conflicts() == 0 {
+        peer.pushed_until = Some(started);
+        peer.synced_at = Some(started);
+    }
+    peer.updated_at = Utc::now();
+    peers.upsert(&peer).await?;
+
+    log::info!("Pushed to {} ({} conflict(s))", remote, result.conflicts());
+
+    Ok(Json(PeerSyncResponse { peer, result }))
+}
+
+/// List sync peers and their watermarks (GET /api/v1/sync/peers)
+#[utoipa::path(
+    get,
+    path = "/api/v1/sync/peers",
+    tag = "sync",
+    responses(
+        (status = 200, description = "Known peers", body = SyncPeerListResponse),
+    )
+)]
+pub async fn list_sync_peers(
+    TenantState(state): TenantState,
+) -> ApiResult<Json<SyncPeerListResponse>> {
+    let peers = SyncPeerRepository::new(state.pool.clone())
+        .find_all()
+        .await?;
+
+    Ok(Json(SyncPeerListResponse { peers }))
+}
+
+/// The peer's base URL without a trailing slash, so it keys one row
+#[track_caller]
+fn parse_remote(remote: &str) -> ApiResult<String> {
+    let remote = remote.trim().trim_end_matches('/');
+    if !(remote.starts_with("http://") || remote.starts_with("https://")) {
+        return Err(ApiError::Validation {
+            message: format!("Remote must be an http(s) URL: {}", remote),
+            field: Some("remote".into()),
+            location: ErrorLocation::from(Location::caller()),
+        });
+    }
+    Ok(remote.to_string())
+}
+
+fn peer_client() -> ApiResult<reqwest::Client> {
+    reqwest::Client::builder()
+        .timeout(PEER_REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to build HTTP client: {}", e),
+            location: ErrorLocation::from(Location::caller()),
+        })
+}
+
+/// Send `request` to the peer and decode its JSON response
+async fn send<T: DeserializeOwned>(
+    request: reqwest::RequestBuilder,
+    token: Option<&str>,
+    remote: &str,
+) -> ApiResult<T> {
+    let request = match token {
+        Some(token) => request.bearer_auth(token),
+        None => request,
+    };
+
+    let response = request.send().await.map_err(|e| ApiError::BadGateway {
+        message: format!("Peer {} unreachable: {}", remote, e),
+        location: ErrorLocation::from(Location::caller()),
+    })?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(ApiError::BadGateway {
+            message: format!("Peer {} returned {}: {}", remote, status, body),
+            location: ErrorLocation::from(Location::caller()),
+        });
+    }
+
+    response.json().await.map_err(|e| ApiError::BadGateway {
+        message: format!("Invalid response from {}: {}", remote, e),
+        location: ErrorLocation::from(Location::caller()),
+    })
+}
@@ -0,0 +1,9 @@
+use pm_core::SyncPeer;
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SyncPeerListResponse {
+    pub peers: Vec<SyncPeer>,
+}
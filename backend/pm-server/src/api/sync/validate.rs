@@ -0,0 +1,334 @@
+//! Validation pre-pass for sync import
+//!
+//! Every DTO is converted and every reference checked before anything is
+//! written, so an import either applies completely or is rejected with all
+//! of its problems listed at once. References may point into the payload or
+//! at records already in the database.
+
+use crate::api::error::{ApiError, Result as ApiResult};
+
+use pm_core::{
+    ActivityLog, Comment, CoreError, CustomFieldDefinition, Dependency, ExportData, ImportProblem,
+    LlmContext, Project, ProjectMember, Sprint, TimeEntry, WorkItem, WorkItemType,
+};
+use pm_db::{ProjectRepository, SprintRepository, WorkItemRepository};
+use pm_ws::is_valid_parent;
+
+use std::collections::{HashMap, HashSet};
+use std::panic::Location;
+
+use error_location::ErrorLocation;
+use sqlx::SqliteConnection;
+use uuid::Uuid;
+
+/// An import payload converted to models and checked, ready to write
+pub(crate) struct ImportRecords {
+    pub projects: Vec<Project>,
+    pub custom_fields: Vec<CustomFieldDefinition>,
+    pub sprints: Vec<Sprint>,
+    pub swim_lane_ids: Vec<String>,
+    pub work_items: Vec<WorkItem>,
+    pub comments: Vec<Comment>,
+    pub dependencies: Vec<Dependency>,
+    pub time_entries: Vec<TimeEntry>,
+    pub project_members: Vec<ProjectMember>,
+    pub llm_context: Vec<LlmContext>,
+    pub activity_log: Vec<ActivityLog>,
+}
+
+impl ImportRecords {
+    /// Convert `data` and check its references against itself and the
+    /// database on `conn`. Fails with [`ApiError::ImportRejected`] listing
+    /// every problem found.
+    pub async fn validate(conn: &mut SqliteConnection, data: ExportData) -> ApiResult<Self> {
+        let mut problems = Vec::new();
+
+        let records = Self {
+            projects: convert(data.projects, "project", |d| d.id.clone(), &mut problems),
+            custom_fields: convert(
+                data.custom_fields,
+                "custom_field",
+                |d| d.id.clone(),
+                &mut problems,
+            ),
+            sprints: convert(data.sprints, "sprint", |d| d.id.clone(), &mut problems),
+            swim_lane_ids: data.swim_lanes.into_iter().map(|d| d.id).collect(),
+            work_items: convert(
+                data.work_items,
+                "work_item",
+                |d| d.id.clone(),
+                &mut problems,
+            ),
+            comments: convert(data.comments, "comment", |d| d.id.clone(), &mut problems),
+            dependencies: convert(
+                data.dependencies,
+                "dependency",
+                |d| d.id.clone(),
+                &mut problems,
+            ),
+            time_entries: convert(
+                data.time_entries,
+                "time_entry",
+                |d| d.id.clone(),
+                &mut problems,
+            ),
+            project_members: convert(
+                data.project_members,
+                "project_member",
+                |d| d.id.clone(),
+                &mut problems,
+            ),
+            llm_context: convert(
+                data.llm_context,
+                "llm_context",
+                |d| d.id.clone(),
+                &mut problems,
+            ),
+            activity_log: convert(
+                data.activity_log,
+                "activity_log",
+                |d| d.id.clone(),
+                &mut problems,
+            ),
+        };
+
+        records.check_references(conn, &mut problems).await?;
+
+        if !problems.is_empty() {
+            return Err(ApiError::ImportRejected {
+                problems,
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+
+        Ok(records)
+    }
+
+    async fn check_references(
+        &self,
+        conn: &mut SqliteConnection,
+        problems: &mut Vec<ImportProblem>,
+    ) -> ApiResult<()> {
+        let projects: HashSet<Uuid> = self.projects.iter().map(|p| p.id).collect();
+        let sprints: HashSet<Uuid> = self.sprints.iter().map(|s| s.id).collect();
+        let work_items: HashMap<Uuid, &WorkItem> =
+            self.work_items.iter().map(|w| (w.id, w)).collect();
+
+        for field in &self.custom_fields {
+            if !project_exists(conn, &projects, field.project_id).await? {
+                problems.push(missing(
+                    "custom_field",
+                    field.id,
+                    "project_id",
+                    field.project_id,
+                ));
+            }
+        }
+
+        for sprint in &self.sprints {
+            if !project_exists(conn, &projects, sprint.project_id).await? {
+                problems.push(missing(
+                    "sprint",
+                    sprint.id,
+                    "project_id",
+                    sprint.project_id,
+                ));
+            }
+        }
+
+        for item in &self.work_items {
+            if !project_exists(conn, &projects, item.project_id).await? {
+                problems.push(missing("work_item", item.id, "project_id", item.project_id));
+            }
+
+            if let Some(sprint_id) = item.sprint_id
+                && !sprints.contains(&sprint_id)
+                && SprintRepository::find_by_id_with(&mut *conn, sprint_id)
+                    .await?
+                    .is_none()
+            {
+                problems.push(missing("work_item", item.id, "sprint_id", sprint_id));
+            }
+
+            let Some(parent_id) = item.parent_id else {
+                continue;
+            };
+            match work_item_type(conn, &work_items, parent_id).await? {
+                None => problems.push(missing("work_item", item.id, "parent_id", parent_id)),
+                Some(parent_type) if !is_valid_parent(&parent_type, &item.item_type) => problems
+                    .push(ImportProblem::new(
+                        "work_item",
+                        item.id,
+                        Some("parent_id"),
+                        format!(
+                            "Invalid hierarchy: {:?} cannot be a child of {:?}",
+                            item.item_type, parent_type
+                        ),
+                    )),
+                Some(_) => {}
+            }
+
+            if has_parent_cycle(item, &work_items) {
+                problems.push(ImportProblem::new(
+                    "work_item",
+                    item.id,
+                    Some("parent_id"),
+                    "Parent chain forms a cycle",
+                ));
+            }
+        }
+
+        for comment in &self.comments {
+            if work_item_type(conn, &work_items, comment.work_item_id)
+                .await?
+                .is_none()
+            {
+                problems.push(missing(
+                    "comment",
+                    comment.id,
+                    "work_item_id",
+                    comment.work_item_id,
+                ));
+            }
+        }
+
+        for dependency in &self.dependencies {
+            if dependency.blocking_item_id == dependency.blocked_item_id {
+                problems.push(ImportProblem::new(
+                    "dependency",
+                    dependency.id,
+                    Some("blocked_item_id"),
+                    "A work item cannot depend on itself",
+                ));
+            }
+            for (field, id) in [
+                ("blocking_item_id", dependency.blocking_item_id),
+                ("blocked_item_id", dependency.blocked_item_id),
+            ] {
+                if work_item_type(conn, &work_items, id).await?.is_none() {
+                    problems.push(missing("dependency", dependency.id, field, id));
+                }
+            }
+        }
+
+        for entry in &self.time_entries {
+            if work_item_type(conn, &work_items, entry.work_item_id)
+                .await?
+                .is_none()
+            {
+                problems.push(missing(
+                    "time_entry",
+                    entry.id,
+                    "work_item_id",
+                    entry.work_item_id,
+                ));
+            }
+        }
+
+        for member in &self.project_members {
+            if !project_exists(conn, &projects, member.project_id).await? {
+                problems.push(missing(
+                    "project_member",
+                    member.id,
+                    "project_id",
+                    member.project_id,
+                ));
+            }
+        }
+
+        // Global entries have no project to check
+        for entry in &self.llm_context {
+            if let Some(project_id) = entry.project_id
+                && !project_exists(conn, &projects, project_id).await?
+            {
+                problems.push(missing("llm_context", entry.id, "project_id", project_id));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Convert DTOs, recording a problem for each one that doesn't convert
+fn convert<D, T>(
+    dtos: Vec<D>,
+    entity: &str,
+    id: impl Fn(&D) -> String,
+    problems: &mut Vec<ImportProblem>,
+) -> Vec<T>
+where
+    D: TryInto<T, Error = CoreError>,
+{
+    dtos.into_iter()
+        .filter_map(|dto| {
+            let dto_id = id(&dto);
+            match dto.try_into() {
+                Ok(record) => Some(record),
+                Err(CoreError::Validation { message, field, .. }) => {
+                    problems.push(ImportProblem::new(
+                        entity,
+                        dto_id,
+                        field.as_deref(),
+                        message,
+                    ));
+                    None
+                }
+                Err(e) => {
+                    problems.push(ImportProblem::new(entity, dto_id, None, e.to_string()));
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+fn missing(entity: &str, id: Uuid, field: &str, target: Uuid) -> ImportProblem {
+    ImportProblem::new(
+        entity,
+        id,
+        Some(field),
+        format!("{} {} is not in the import or the database", field, target),
+    )
+}
+
+async fn project_exists(
+    conn: &mut SqliteConnection,
+    payload: &HashSet<Uuid>,
+    id: Uuid,
+) -> ApiResult<bool> {
+    Ok(payload.contains(&id)
+        || ProjectRepository::find_by_id_with(&mut *conn, id)
+            .await?
+            .is_some())
+}
+
+/// The type of work item `id`, preferring the payload over the database
+async fn work_item_type(
+    conn: &mut SqliteConnection,
+    payload: &HashMap<Uuid, &WorkItem>,
+    id: Uuid,
+) -> ApiResult<Option<WorkItemType>> {
+    if let Some(item) = payload.get(&id) {
+        return Ok(Some(item.item_type.clone()));
+    }
+    Ok(WorkItemRepository::find_by_id(&mut *conn, id)
+        .await?
+        .map(|w| w.item_type))
+}
+
+/// Whether following `parent_id` through the payload leads back to `item`
+fn has_parent_cycle(item: &WorkItem, payload: &HashMap<Uuid, &WorkItem>) -> bool {
+    let mut seen = HashSet::new();
+    let mut next = item.parent_id;
+    while let Some(id) = next {
+        if id == item.id {
+            return true;
+        }
+        if !seen.insert(id) {
+            // A cycle further up, reported for the items on it
+            return false;
+        }
+        next = payload.get(&id).and_then(|w| w.parent_id);
+    }
+    false
+}
@@ -0,0 +1,24 @@
+use pm_core::TemplateStructure;
+
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+/// Request body for saving a project template. Give either `project_id` to
+/// capture an existing project or `structure` to save one as-is (e.g. read
+/// from a TOML file).
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateTemplateRequest {
+    /// Unique template name
+    pub name: String,
+
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Project UUID or key whose structure is saved
+    #[serde(default)]
+    pub project_id: Option<String>,
+
+    /// Template contents, when not captured from a project
+    #[serde(default)]
+    pub structure: Option<TemplateStructure>,
+}
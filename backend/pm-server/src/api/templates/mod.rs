@@ -0,0 +1,5 @@
+pub(crate) mod create_template_request;
+pub(crate) mod template_list_response;
+pub(crate) mod template_response;
+#[allow(clippy::module_inception)]
+pub(crate) mod templates;
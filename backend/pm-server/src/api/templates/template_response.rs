@@ -0,0 +1,9 @@
+use pm_core::ProjectTemplateDto;
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TemplateResponse {
+    pub template: ProjectTemplateDto,
+}
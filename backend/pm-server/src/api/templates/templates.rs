@@ -0,0 +1,261 @@
+//! Project template REST API handlers
+//!
+//! A template is a project's structure without its history: custom fields,
+//! starter work items and project LLM context. Templates are saved from an
+//! existing project or from a structure (e.g. a TOML file read by the CLI),
+//! and `POST /api/v1/projects` with `template_id` instantiates one.
+
+use crate::{
+    ApiError, ApiResult, CreateTemplateRequest, DeleteResponse, TemplateListResponse,
+    TemplateResponse, TenantState, UserId, api::resolve::resolve_project,
+};
+
+use pm_core::{ProjectTemplate, ProjectTemplateDto, TemplateStructure, WorkItemType};
+use pm_db::{
+    CustomFieldRepository, LlmContextRepository, ProjectTemplateRepository, WorkItemRepository,
+};
+use pm_ws::{is_valid_parent, sanitize_string, validate_priority};
+
+use std::collections::HashMap;
+use std::panic::Location;
+
+use axum::{Json, extract::Path};
+use error_location::ErrorLocation;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+/// Load a template by UUID or name
+pub(crate) async fn resolve_template(pool: &SqlitePool, id: &str) -> ApiResult<ProjectTemplate> {
+    let repo = ProjectTemplateRepository::new(pool.clone());
+    let template = match Uuid::parse_str(id) {
+        Ok(uuid) => repo.find_by_id(uuid).await?,
+        Err(_) => repo.find_by_name(id).await?,
+    };
+
+    template.ok_or_else(|| ApiError::NotFound {
+        message: format!("Template {} not found", id),
+        location: ErrorLocation::from(Location::caller()),
+    })
+}
+
+/// Capture the structure of project `id` (UUID or key)
+async fn capture_project(pool: &SqlitePool, id: &str) -> ApiResult<TemplateStructure> {
+    let project = resolve_project(pool, id).await?;
+    let work_items = WorkItemRepository::find_by_project(pool, project.id, true).await?;
+    let custom_fields = CustomFieldRepository::new(pool.clone())
+        .find_by_project(project.id)
+        .await?;
+    let llm_context: Vec<_> =
+        LlmContextRepository::list_filtered(pool, Some(project.id), None, None, None)
+            .await?
+            .into_iter()
+            .filter(|c| c.project_id == Some(project.id))
+            .collect();
+
+    Ok(TemplateStructure::from_project(
+        &work_items,
+        &custom_fields,
+        &llm_context,
+    ))
+}
+
+/// Sanitize the text of a structure supplied by a client and check it can
+/// be instantiated, including the work item hierarchy and priorities
+fn check_structure(mut structure: TemplateStructure) -> ApiResult<TemplateStructure> {
+    for item in &mut structure.work_items {
+        item.title = sanitize_string(&item.title);
+        item.description = item
+            .description
+            .as_deref()
+            .map(sanitize_string)
+            .filter(|d| !d.is_empty());
+        if item.title.is_empty() {
+            return Err(ApiError::Validation {
+                message: format!("Work item {} has an empty title", item.number),
+                field: Some("structure.work_items".into()),
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+        validate_priority(&item.priority)?;
+    }
+
+    structure.validate()?;
+
+    let types: HashMap<i32, &WorkItemType> = structure
+        .work_items
+        .iter()
+        .map(|w| (w.number, &w.item_type))
+        .collect();
+    for item in &structure.work_items {
+        if let Some(parent) = item.parent
+            && !is_valid_parent(types[&parent], &item.item_type)
+        {
+            return Err(ApiError::Validation {
+                message: format!(
+                    "Invalid hierarchy: {:?} {} cannot be a child of {:?} {}",
+                    item.item_type, item.number, types[&parent], parent
+                ),
+                field: Some("structure.work_items".into()),
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+    }
+
+    Ok(structure)
+}
+
+// =============================================================================
+// Handlers
+// =============================================================================
+
+/// GET /api/v1/templates
+///
+/// List project templates by name
+#[utoipa::path(
+    get,
+    path = "/api/v1/templates",
+    tag = "templates",
+    responses(
+        (status = 200, description = "All templates", body = TemplateListResponse),
+    )
+)]
+pub async fn list_templates(
+    TenantState(state): TenantState,
+) -> ApiResult<Json<TemplateListResponse>> {
+    let templates = ProjectTemplateRepository::new(state.pool.clone())
+        .find_all()
+        .await?;
+
+    Ok(Json(TemplateListResponse {
+        templates: templates
+            .into_iter()
+            .map(ProjectTemplateDto::from)
+            .collect(),
+    }))
+}
+
+/// GET /api/v1/templates/:id
+///
+/// Get a template by UUID or name
+#[utoipa::path(
+    get,
+    path = "/api/v1/templates/{id}",
+    tag = "templates",
+    params(("id" = String, Path, description = "Template UUID or name")),
+    responses(
+        (status = 200, description = "The template", body = TemplateResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn get_template(
+    TenantState(state): TenantState,
+    Path(id): Path<String>,
+) -> ApiResult<Json<TemplateResponse>> {
+    let template = resolve_template(&state.pool, &id).await?;
+
+    Ok(Json(TemplateResponse {
+        template: template.into(),
+    }))
+}
+
+/// POST /api/v1/templates
+///
+/// Save a template from a project or from a supplied structure
+#[utoipa::path(
+    post,
+    path = "/api/v1/templates",
+    tag = "templates",
+    request_body = CreateTemplateRequest,
+    responses(
+        (status = 200, description = "The saved template", body = TemplateResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Project not found", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn create_template(
+    TenantState(state): TenantState,
+    UserId(user_id): UserId,
+    Json(req): Json<CreateTemplateRequest>,
+) -> ApiResult<Json<TemplateResponse>> {
+    // 1. Validate the name and check it is free
+    let name = sanitize_string(&req.name);
+    ProjectTemplate::validate_name(&name)?;
+    let repo = ProjectTemplateRepository::new(state.pool.clone());
+    if repo.find_by_name(&name).await?.is_some() {
+        return Err(ApiError::Validation {
+            message: format!("Template '{}' already exists", name),
+            field: Some("name".into()),
+            location: ErrorLocation::from(Location::caller()),
+        });
+    }
+
+    // 2. Capture or check the structure
+    let structure = match (&req.project_id, req.structure) {
+        (Some(project_id), None) => capture_project(&state.pool, project_id).await?,
+        (None, Some(structure)) => check_structure(structure)?,
+        _ => {
+            return Err(ApiError::Validation {
+                message: "Give exactly one of project_id or structure".to_string(),
+                field: Some("project_id".into()),
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+    };
+
+    // 3. Save
+    let description = req
+        .description
+        .as_deref()
+        .map(sanitize_string)
+        .filter(|d| !d.is_empty());
+    let template = ProjectTemplate::new(name, description, structure, user_id);
+    repo.create(&template).await?;
+
+    log::info!(
+        "Saved project template {} ({}) via REST API",
+        template.id,
+        template.name
+    );
+
+    Ok(Json(TemplateResponse {
+        template: template.into(),
+    }))
+}
+
+/// DELETE /api/v1/templates/:id
+///
+/// Delete a template. Projects created from it are not affected.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/templates/{id}",
+    tag = "templates",
+    params(("id" = String, Path, description = "Template UUID or name")),
+    responses(
+        (status = 200, description = "The template was deleted", body = DeleteResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn delete_template(
+    TenantState(state): TenantState,
+    Path(id): Path<String>,
+) -> ApiResult<Json<DeleteResponse>> {
+    let template = resolve_template(&state.pool, &id).await?;
+
+    ProjectTemplateRepository::new(state.pool.clone())
+        .delete(template.id)
+        .await?;
+
+    log::info!(
+        "Deleted project template {} ({}) via REST API",
+        template.id,
+        template.name
+    );
+
+    Ok(Json(DeleteResponse {
+        deleted_id: template.id.to_string(),
+    }))
+}
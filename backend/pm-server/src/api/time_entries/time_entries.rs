@@ -4,22 +4,25 @@
 //! via WebSocket so connected clients see updates in real-time.
 
 use crate::{
-    ApiError, ApiResult, CreateTimeEntryRequest, DeleteResponse, TimeEntryListResponse,
-    TimeEntryResponse, UpdateTimeEntryRequest, UserId, api::resolve::resolve_work_item,
+    ApiError, ApiResult, CreateTimeEntryRequest, DeleteResponse, TenantState,
+    TimeEntryListResponse, TimeEntryResponse, UpdateTimeEntryRequest, UserId,
+    api::resolve::resolve_work_item,
 };
 
 use pm_core::{ActivityLog, TimeEntry, TimeEntryDto};
 use pm_db::{ActivityLogRepository, TimeEntryRepository, WorkItemRepository};
 use pm_ws::{
-    AppState, build_activity_log_created_event, build_time_entry_created_response,
-    build_time_entry_deleted_response, build_time_entry_updated_response, sanitize_string,
+    MessageValidator, apply_stopped_timer, build_activity_log_created_event,
+    build_time_entry_created_response, build_time_entry_deleted_response,
+    build_time_entry_updated_response, build_work_item_updated_response, compute_estimate_for_item,
+    compute_hierarchy_for_item, load_logged_seconds, sanitize_string,
 };
 
 use std::panic::Location;
 
 use axum::{
     Json,
-    extract::{Path, State, ws::Message},
+    extract::{Path, ws::Message},
 };
 use chrono::Utc;
 use error_location::ErrorLocation;
@@ -33,8 +36,18 @@ use uuid::Uuid;
 /// GET /api/v1/work-items/:id/time-entries
 ///
 /// List all time entries for a work item, ordered by started_at DESC (newest first)
+#[utoipa::path(
+    get,
+    path = "/api/v1/work-items/{id}/time-entries",
+    tag = "time-entries",
+    params(("id" = String, Path, description = "Work item UUID or display key")),
+    responses(
+        (status = 200, description = "Time entries, newest first", body = TimeEntryListResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
 pub async fn list_time_entries(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     Path(work_item_id): Path<String>,
 ) -> ApiResult<Json<TimeEntryListResponse>> {
     let work_item = resolve_work_item(&state.pool, &work_item_id).await?;
@@ -51,8 +64,19 @@ pub async fn list_time_entries(
 /// GET /api/v1/time-entries/:id
 ///
 /// Get a single time entry by ID
+#[utoipa::path(
+    get,
+    path = "/api/v1/time-entries/{id}",
+    tag = "time-entries",
+    params(("id" = String, Path, description = "Time entry UUID")),
+    responses(
+        (status = 200, description = "The time entry", body = TimeEntryResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
 pub async fn get_time_entry(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     Path(id): Path<String>,
 ) -> ApiResult<Json<TimeEntryResponse>> {
     let time_entry_id = Uuid::parse_str(&id)?;
@@ -74,8 +98,19 @@ pub async fn get_time_entry(
 /// POST /api/v1/time-entries
 ///
 /// Create a new time entry (start a timer). Broadcasts activity to WebSocket clients.
+#[utoipa::path(
+    post,
+    path = "/api/v1/time-entries",
+    tag = "time-entries",
+    request_body = CreateTimeEntryRequest,
+    responses(
+        (status = 200, description = "The started timer", body = TimeEntryResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
 pub async fn create_time_entry(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     UserId(user_id): UserId,
     Json(req): Json<CreateTimeEntryRequest>,
 ) -> ApiResult<Json<TimeEntryResponse>> {
@@ -163,8 +198,20 @@ pub async fn create_time_entry(
 /// PUT /api/v1/time-entries/:id
 ///
 /// Update a time entry (stop timer or edit description). Broadcasts activity to WebSocket clients.
+#[utoipa::path(
+    put,
+    path = "/api/v1/time-entries/{id}",
+    tag = "time-entries",
+    params(("id" = String, Path, description = "Time entry UUID")),
+    request_body = UpdateTimeEntryRequest,
+    responses(
+        (status = 200, description = "The updated time entry", body = TimeEntryResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
 pub async fn update_time_entry(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     UserId(user_id): UserId,
     Path(id): Path<String>,
     Json(req): Json<UpdateTimeEntryRequest>,
@@ -183,7 +230,7 @@ pub async fn update_time_entry(
             })?;
 
     // 3. Fetch work item to get project_id for broadcasts
-    let work_item = WorkItemRepository::find_by_id(&state.pool, time_entry.work_item_id)
+    let mut work_item = WorkItemRepository::find_by_id(&state.pool, time_entry.work_item_id)
         .await?
         .ok_or_else(|| ApiError::NotFound {
             message: format!("Work item {} not found", time_entry.work_item_id),
@@ -192,10 +239,34 @@ pub async fn update_time_entry(
 
     let project_id = work_item.project_id;
 
-    // 4. Apply stop if requested
+    // 4. Apply stop if requested, carrying the logged time into the
+    //    work item's remaining estimate
+    if let Some(remaining) = req.remaining_estimate_seconds {
+        MessageValidator::validate_estimate_seconds(remaining, "remaining_estimate_seconds")
+            .map_err(|e| ApiError::Validation {
+                message: e.to_string(),
+                field: Some("remaining_estimate_seconds".into()),
+                location: ErrorLocation::from(Location::caller()),
+            })?;
+    }
+    let mut estimate_change = None;
     if req.stop == Some(true) && time_entry.is_running() {
         time_entry.stop();
+        estimate_change = apply_stopped_timer(
+            &mut work_item,
+            &time_entry,
+            req.remaining_estimate_seconds,
+            user_id,
+        );
     }
+    let estimate_activity = estimate_change.as_ref().map(|change| {
+        ActivityLog::updated(
+            "work_item",
+            work_item.id,
+            user_id,
+            std::slice::from_ref(change),
+        )
+    });
 
     // 5. Apply description update if provided
     if let Some(desc) = req.description.as_ref() {
@@ -215,26 +286,63 @@ pub async fn update_time_entry(
     let mut tx = state.pool.begin().await?;
     repo.update(&time_entry_clone).await?;
     ActivityLogRepository::create(&mut *tx, &activity_clone).await?;
+    if let Some(ref estimate_activity) = estimate_activity {
+        WorkItemRepository::update(&mut *tx, &work_item).await?;
+        ActivityLogRepository::create(&mut *tx, estimate_activity).await?;
+    }
     tx.commit().await?;
 
     // 7. Broadcast ActivityLogCreated
-    let event = build_activity_log_created_event(&activity);
-    let bytes = event.encode_to_vec();
-    let message = Message::Binary(bytes.into());
-    if let Err(e) = state
-        .registry
-        .broadcast_activity_log_created(
-            &project_id.to_string(),
-            Some(&time_entry.work_item_id.to_string()),
-            None,
-            message,
-        )
-        .await
-    {
-        log::warn!(
-            "Failed to broadcast time entry update activity log to WebSocket clients: {}",
-            e
+    for activity in std::iter::once(&activity).chain(estimate_activity.as_ref()) {
+        let event = build_activity_log_created_event(activity);
+        let bytes = event.encode_to_vec();
+        let message = Message::Binary(bytes.into());
+        if let Err(e) = state
+            .registry
+            .broadcast_activity_log_created(
+                &project_id.to_string(),
+                Some(&time_entry.work_item_id.to_string()),
+                None,
+                message,
+            )
+            .await
+        {
+            log::warn!(
+                "Failed to broadcast time entry update activity log to WebSocket clients: {}",
+                e
+            );
+        }
+    }
+
+    // 7b. Broadcast the new remaining estimate
+    if let Some(change) = estimate_change {
+        let all_items = WorkItemRepository::find_by_project(&state.pool, project_id, true)
+            .await
+            .unwrap_or_else(|e| {
+                log::warn!("Failed to fetch items for hierarchy computation: {}", e);
+                vec![]
+            });
+        let hierarchy = compute_hierarchy_for_item(&all_items, work_item.id);
+        let logged_seconds = load_logged_seconds(&state.pool, project_id).await;
+        let broadcast = build_work_item_updated_response(
+            &Uuid::new_v4().to_string(),
+            &work_item,
+            &[change],
+            user_id,
+            hierarchy.ancestor_ids,
+            hierarchy.descendant_ids,
+            compute_estimate_for_item(&all_items, &logged_seconds, work_item.id),
         );
+        if let Err(e) = state
+            .registry
+            .broadcast_to_project(
+                &project_id.to_string(),
+                Message::Binary(broadcast.encode_to_vec().into()),
+            )
+            .await
+        {
+            log::warn!("Failed to broadcast WorkItemUpdated via REST: {}", e);
+        }
     }
 
     // 8. Broadcast TimeEntryUpdated
@@ -260,8 +368,19 @@ pub async fn update_time_entry(
 /// DELETE /api/v1/time-entries/:id
 ///
 /// Delete a time entry (soft delete). Broadcasts activity to WebSocket clients.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/time-entries/{id}",
+    tag = "time-entries",
+    params(("id" = String, Path, description = "Time entry UUID")),
+    responses(
+        (status = 200, description = "The time entry was deleted", body = DeleteResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
 pub async fn delete_time_entry(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     UserId(user_id): UserId,
     Path(id): Path<String>,
 ) -> ApiResult<Json<DeleteResponse>> {
@@ -1,8 +1,9 @@
 use pm_core::TimeEntryDto;
 
 use serde::Serialize;
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct TimeEntryListResponse {
     pub time_entries: Vec<TimeEntryDto>,
 }
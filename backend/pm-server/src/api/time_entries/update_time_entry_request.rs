@@ -1,11 +1,17 @@
 use serde::Deserialize;
+use utoipa::ToSchema;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateTimeEntryRequest {
     /// Set to true to stop the running timer
     #[serde(default)]
     pub stop: Option<bool>,
 
+    /// When stopping, set the work item's remaining estimate to this value
+    /// instead of decrementing it by the timer's duration
+    #[serde(default)]
+    pub remaining_estimate_seconds: Option<i32>,
+
     /// Update description
     #[serde(default)]
     pub description: Option<String>,
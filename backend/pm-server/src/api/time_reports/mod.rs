@@ -0,0 +1,4 @@
+pub(crate) mod time_report_query;
+pub(crate) mod time_report_response;
+#[allow(clippy::module_inception)]
+pub(crate) mod time_reports;
@@ -0,0 +1,22 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// Query parameters for time reports
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TimeReportQuery {
+    /// First day of the range (YYYY-MM-DD, inclusive, UTC)
+    pub from: String,
+    /// Last day of the range (YYYY-MM-DD, inclusive, UTC)
+    pub to: String,
+    /// Grouping: user, item, or sprint (default: user)
+    pub group_by: Option<String>,
+    /// Split rows into day or week buckets
+    pub period: Option<String>,
+    /// Restrict to a project (UUID or project key)
+    pub project_id: Option<String>,
+    /// Restrict to a single user's entries
+    pub user_id: Option<String>,
+    /// Response format: json (default) or csv
+    pub format: Option<String>,
+}
@@ -0,0 +1,9 @@
+use pm_core::TimeReport;
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TimeReportResponse {
+    pub report: TimeReport,
+}
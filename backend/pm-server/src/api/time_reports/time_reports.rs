@@ -0,0 +1,278 @@
+//! Time report REST API handler
+//!
+//! Aggregates time entries over a date range by user, work item (with
+//! rollup to ancestors) or sprint. Running timers count up to now.
+
+use crate::{
+    ApiError, ApiResult, TenantState, TimeReportQuery, TimeReportResponse, resolve_project,
+};
+
+use pm_core::{
+    TimeEntry, TimeReport, TimeReportGroupBy, TimeReportPeriod, TimeReportRow, WorkItem,
+};
+use pm_db::{ProjectRepository, SprintRepository, TimeEntryRepository, WorkItemRepository};
+use pm_ws::compute_hierarchy_maps;
+
+use std::collections::HashMap;
+use std::{panic::Location, str::FromStr};
+
+use axum::{
+    Json,
+    extract::Query,
+    http::header,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Days, NaiveDate, NaiveTime, Utc};
+use error_location::ErrorLocation;
+use uuid::Uuid;
+
+/// Per-(key, bucket) seconds accumulated from time entries
+type Totals<K> = HashMap<(K, Option<NaiveDate>), i64>;
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+fn parse_date(value: &str, field: &str) -> ApiResult<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| ApiError::Validation {
+        message: format!("Invalid {}: {} (expected YYYY-MM-DD)", field, value),
+        field: Some(field.into()),
+        location: ErrorLocation::from(Location::caller()),
+    })
+}
+
+fn start_of_day(date: NaiveDate) -> DateTime<Utc> {
+    date.and_time(NaiveTime::MIN).and_utc()
+}
+
+/// Sum each entry's in-range seconds under the key chosen by `key_of`
+fn accumulate<K, F>(
+    entries: &[TimeEntry],
+    range: (DateTime<Utc>, DateTime<Utc>),
+    now: DateTime<Utc>,
+    period: Option<TimeReportPeriod>,
+    key_of: F,
+) -> Totals<K>
+where
+    K: std::hash::Hash + Eq,
+    F: Fn(&TimeEntry) -> K,
+{
+    let mut totals = HashMap::new();
+    for entry in entries {
+        for (bucket, seconds) in entry.seconds_in_range(range.0, range.1, now, period) {
+            *totals.entry((key_of(entry), bucket)).or_insert(0) += seconds;
+        }
+    }
+    totals
+}
+
+fn row(key: String, label: String, bucket: Option<NaiveDate>, seconds: i64) -> TimeReportRow {
+    TimeReportRow {
+        key,
+        label,
+        period_start: bucket.map(|d| d.to_string()),
+        seconds,
+        rollup_seconds: None,
+    }
+}
+
+/// Item rows: direct time per item plus rollup through all descendants
+fn item_rows(
+    totals: &Totals<Uuid>,
+    work_items: &[WorkItem],
+    project_keys: &HashMap<Uuid, String>,
+) -> Vec<TimeReportRow> {
+    let buckets: std::collections::HashSet<Option<NaiveDate>> =
+        totals.keys().map(|(_, bucket)| *bucket).collect();
+    let hierarchy = compute_hierarchy_maps(work_items);
+
+    let mut rows = Vec::new();
+    for item in work_items {
+        let descendants: Vec<Uuid> = hierarchy
+            .get(&item.id)
+            .map(|h| {
+                h.descendant_ids
+                    .iter()
+                    .filter_map(|id| Uuid::parse_str(id).ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        for bucket in &buckets {
+            let direct = totals.get(&(item.id, *bucket)).copied().unwrap_or(0);
+            let rollup = direct
+                + descendants
+                    .iter()
+                    .filter_map(|d| totals.get(&(*d, *bucket)))
+                    .sum::<i64>();
+            if rollup == 0 {
+                continue;
+            }
+
+            let key = project_keys
+                .get(&item.project_id)
+                .map(|k| k.as_str())
+                .unwrap_or("UNKNOWN");
+            let mut r = row(
+                item.id.to_string(),
+                format!("{}-{} {}", key, item.item_number, item.title),
+                *bucket,
+                direct,
+            );
+            r.rollup_seconds = Some(rollup);
+            rows.push(r);
+        }
+    }
+    rows
+}
+
+// =============================================================================
+// Handlers
+// =============================================================================
+
+/// GET /api/v1/time-reports
+///
+/// Aggregate logged time between `from` and `to` (inclusive days, UTC).
+/// Deleted entries, and entries on deleted work items, are excluded.
+#[utoipa::path(
+    get,
+    path = "/api/v1/time-reports",
+    tag = "reports",
+    params(TimeReportQuery),
+    responses(
+        (status = 200, description = "Time report as JSON (default) or CSV", content((TimeReportResponse = "application/json"), (String = "text/csv"))),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn get_time_report(
+    TenantState(state): TenantState,
+    Query(query): Query<TimeReportQuery>,
+) -> ApiResult<Response> {
+    // 1. Parse parameters
+    let from = parse_date(&query.from, "from")?;
+    let to = parse_date(&query.to, "to")?;
+    if from > to {
+        return Err(ApiError::Validation {
+            message: format!("from ({}) must not be after to ({})", from, to),
+            field: Some("from".into()),
+            location: ErrorLocation::from(Location::caller()),
+        });
+    }
+    let group_by = TimeReportGroupBy::from_str(query.group_by.as_deref().unwrap_or("user"))
+        .map_err(|e| ApiError::Validation {
+            message: format!("{}. Valid values: user, item, sprint", e),
+            field: Some("group_by".into()),
+            location: ErrorLocation::from(Location::caller()),
+        })?;
+    let period = query
+        .period
+        .as_deref()
+        .map(TimeReportPeriod::from_str)
+        .transpose()
+        .map_err(|e| ApiError::Validation {
+            message: format!("{}. Valid values: day, week", e),
+            field: Some("period".into()),
+            location: ErrorLocation::from(Location::caller()),
+        })?;
+    let csv = match query.format.as_deref() {
+        None | Some("json") => false,
+        Some("csv") => true,
+        Some(other) => {
+            return Err(ApiError::Validation {
+                message: format!("Invalid format: {}. Valid values: json, csv", other),
+                field: Some("format".into()),
+                location: ErrorLocation::from(Location::caller()),
+            });
+        }
+    };
+    let user_filter = query.user_id.as_deref().map(Uuid::parse_str).transpose()?;
+
+    // 2. Load entries and the work items they belong to
+    let pool = &state.pool;
+    let work_items = match &query.project_id {
+        Some(project_id) => {
+            let project = resolve_project(pool, project_id).await?;
+            WorkItemRepository::find_by_project(pool, project.id, true).await?
+        }
+        None => WorkItemRepository::find_all(pool, true).await?,
+    };
+    let items_by_id: HashMap<Uuid, &WorkItem> = work_items.iter().map(|w| (w.id, w)).collect();
+
+    let range = (start_of_day(from), start_of_day(to + Days::new(1)));
+    let mut entries = TimeEntryRepository::new(pool.clone())
+        .find_in_range(range.0, range.1)
+        .await?;
+    entries.retain(|e| {
+        items_by_id.contains_key(&e.work_item_id) && user_filter.is_none_or(|u| e.user_id == u)
+    });
+
+    // 3. Aggregate
+    let now = Utc::now();
+    let mut rows = match group_by {
+        TimeReportGroupBy::User => accumulate(&entries, range, now, period, |e| e.user_id)
+            .into_iter()
+            .map(|((user_id, bucket), seconds)| {
+                row(user_id.to_string(), user_id.to_string(), bucket, seconds)
+            })
+            .collect::<Vec<_>>(),
+        TimeReportGroupBy::Sprint => {
+            let sprint_names: HashMap<Uuid, String> = SprintRepository::new(pool.clone())
+                .find_all()
+                .await?
+                .into_iter()
+                .map(|s| (s.id, s.name))
+                .collect();
+            accumulate(&entries, range, now, period, |e| {
+                items_by_id[&e.work_item_id].sprint_id
+            })
+            .into_iter()
+            .map(|((sprint_id, bucket), seconds)| match sprint_id {
+                Some(id) => row(
+                    id.to_string(),
+                    sprint_names
+                        .get(&id)
+                        .cloned()
+                        .unwrap_or_else(|| id.to_string()),
+                    bucket,
+                    seconds,
+                ),
+                None => row(String::new(), "(no sprint)".into(), bucket, seconds),
+            })
+            .collect()
+        }
+        TimeReportGroupBy::Item => {
+            let project_keys: HashMap<Uuid, String> = ProjectRepository::new(pool.clone())
+                .find_all()
+                .await?
+                .into_iter()
+                .map(|p| (p.id, p.key))
+                .collect();
+            let totals = accumulate(&entries, range, now, period, |e| e.work_item_id);
+            item_rows(&totals, &work_items, &project_keys)
+        }
+    };
+    rows.sort_by(|a, b| {
+        (&a.period_start, &a.label, &a.key).cmp(&(&b.period_start, &b.label, &b.key))
+    });
+
+    let report = TimeReport {
+        from: from.to_string(),
+        to: to.to_string(),
+        group_by: group_by.as_str().to_string(),
+        period: period.map(|p| p.as_str().to_string()),
+        generated_at: now.timestamp(),
+        total_seconds: rows.iter().map(|r| r.seconds).sum(),
+        rows,
+    };
+
+    if csv {
+        return Ok((
+            [(header::CONTENT_TYPE, "text/csv; charset=utf-8")],
+            report.to_csv(),
+        )
+            .into_response());
+    }
+
+    Ok(Json(TimeReportResponse { report }).into_response())
+}
@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+pub(crate) mod work_item_context;
+pub(crate) mod work_item_context_query;
+pub(crate) mod work_item_context_response;
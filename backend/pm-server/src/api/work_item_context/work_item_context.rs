@@ -0,0 +1,192 @@
+//! Work item context pack REST API handler
+//!
+//! Assembles everything an agent needs to pick up a work item - the item,
+//! its place in the hierarchy, blockers, recent discussion, activity and the
+//! project's rules - into one Markdown or JSON document.
+
+use crate::{
+    ApiError, ApiResult, TenantState, WorkItemContextQuery, WorkItemContextResponse,
+    resolve_work_item,
+};
+
+use pm_core::{
+    ContextActivity, ContextComment, ContextFormat, ContextItem, ContextOmitted, ContextRule,
+    DependencyType, LlmContextType, WorkItem, WorkItemContext,
+};
+use pm_db::{
+    ActivityLogRepository, CommentRepository, DependencyRepository, LlmContextRepository,
+    ProjectRepository, WorkItemRepository,
+};
+use pm_ws::compute_hierarchy_maps;
+
+use std::collections::HashMap;
+use std::{panic::Location, str::FromStr};
+
+use axum::{
+    Json,
+    extract::{Path, Query},
+    http::header,
+    response::{IntoResponse, Response},
+};
+use error_location::ErrorLocation;
+use uuid::Uuid;
+
+/// Size budget when the caller does not give one (roughly 6k tokens)
+const DEFAULT_MAX_CHARS: usize = 24_000;
+/// Smallest budget that still leaves room for the item itself
+const MIN_MAX_CHARS: usize = 500;
+const RECENT_COMMENTS: usize = 10;
+const RECENT_ACTIVITY: usize = 20;
+
+/// GET /api/v1/work-items/:id/context
+///
+/// Build a context pack for a work item. Lower-value sections (old activity,
+/// low-priority rules, siblings, old comments) are dropped first when the
+/// document exceeds `max_chars`.
+#[utoipa::path(
+    get,
+    path = "/api/v1/work-items/{id}/context",
+    tag = "work-items",
+    params(("id" = String, Path, description = "Work item UUID or display key"), WorkItemContextQuery),
+    responses(
+        (status = 200, description = "Context pack as Markdown (default) or JSON", content((String = "text/markdown"), (WorkItemContextResponse = "application/json"))),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn get_work_item_context(
+    TenantState(state): TenantState,
+    Path(id): Path<String>,
+    Query(query): Query<WorkItemContextQuery>,
+) -> ApiResult<Response> {
+    // 1. Parse parameters
+    let format =
+        ContextFormat::from_str(query.format.as_deref().unwrap_or("markdown")).map_err(|e| {
+            ApiError::Validation {
+                message: format!("{}. Valid values: markdown, json", e),
+                field: Some("format".into()),
+                location: ErrorLocation::from(Location::caller()),
+            }
+        })?;
+    let max_chars = query.max_chars.unwrap_or(DEFAULT_MAX_CHARS);
+    if max_chars < MIN_MAX_CHARS {
+        return Err(ApiError::Validation {
+            message: format!("max_chars must be at least {}", MIN_MAX_CHARS),
+            field: Some("max_chars".into()),
+            location: ErrorLocation::from(Location::caller()),
+        });
+    }
+
+    // 2. Load the item and its project
+    let pool = &state.pool;
+    let work_item = resolve_work_item(pool, &id).await?;
+    let project_keys: HashMap<Uuid, String> = ProjectRepository::new(pool.clone())
+        .find_all()
+        .await?
+        .into_iter()
+        .map(|p| (p.id, p.key))
+        .collect();
+    let key_of = |item: &WorkItem| {
+        project_keys
+            .get(&item.project_id)
+            .cloned()
+            .unwrap_or_else(|| "UNKNOWN".to_string())
+    };
+    let context_item = |item: &WorkItem| ContextItem::new(item, &key_of(item));
+
+    // 3. Hierarchy: ancestors (root first) and siblings
+    let project_items =
+        WorkItemRepository::find_by_project(pool, work_item.project_id, true).await?;
+    let items_by_id: HashMap<Uuid, &WorkItem> = project_items.iter().map(|w| (w.id, w)).collect();
+    let hierarchy = compute_hierarchy_maps(&project_items);
+    let ancestors: Vec<ContextItem> = hierarchy
+        .get(&work_item.id)
+        .map(|h| h.ancestor_ids.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .rev()
+        .filter_map(|a| items_by_id.get(&Uuid::parse_str(a).ok()?))
+        .map(|w| context_item(w))
+        .collect();
+    let mut siblings: Vec<&WorkItem> = project_items
+        .iter()
+        .filter(|w| w.parent_id == work_item.parent_id && w.id != work_item.id)
+        .collect();
+    siblings.sort_by(|a, b| (&a.rank, a.item_number).cmp(&(&b.rank, b.item_number)));
+
+    // 4. Blocking dependencies in both directions (may cross projects)
+    let dependency_repo = DependencyRepository::new(pool.clone());
+    let mut blocked_by = Vec::new();
+    for dep in dependency_repo.find_blocking(work_item.id).await? {
+        if dep.dependency_type != DependencyType::Blocks {
+            continue;
+        }
+        if let Some(item) = WorkItemRepository::find_by_id(pool, dep.blocking_item_id).await? {
+            blocked_by.push(context_item(&item));
+        }
+    }
+    let mut blocks = Vec::new();
+    for dep in dependency_repo.find_blocked(work_item.id).await? {
+        if dep.dependency_type != DependencyType::Blocks {
+            continue;
+        }
+        if let Some(item) = WorkItemRepository::find_by_id(pool, dep.blocked_item_id).await? {
+            blocks.push(context_item(&item));
+        }
+    }
+    blocked_by.sort_by(|a, b| a.key.cmp(&b.key));
+    blocks.sort_by(|a, b| a.key.cmp(&b.key));
+
+    // 5. Discussion, activity and rules
+    let comments: Vec<ContextComment> = CommentRepository::new(pool.clone())
+        .find_by_work_item(work_item.id)
+        .await?
+        .iter()
+        .rev()
+        .take(RECENT_COMMENTS)
+        .map(Into::into)
+        .collect();
+    let activity_log =
+        ActivityLogRepository::find_by_entity(pool, "work_item", work_item.id).await?;
+    let mut rules = Vec::new();
+    for context_type in [LlmContextType::BusinessRule, LlmContextType::Instruction] {
+        rules.extend(
+            LlmContextRepository::list_filtered(
+                pool,
+                Some(work_item.project_id),
+                None,
+                Some(context_type.as_str()),
+                None,
+            )
+            .await?
+            .iter()
+            .map(ContextRule::from),
+        );
+    }
+    rules.sort_by(|a, b| b.priority.cmp(&a.priority).then(a.title.cmp(&b.title)));
+
+    // 6. Assemble and fit to the budget
+    let mut item = context_item(&work_item);
+    item.description = work_item.description.clone();
+    let mut context = WorkItemContext {
+        item,
+        ancestors,
+        siblings: siblings.into_iter().map(context_item).collect(),
+        blocked_by,
+        blocks,
+        comments,
+        activity: ContextActivity::summarize(&activity_log, RECENT_ACTIVITY),
+        rules,
+        omitted: ContextOmitted::default(),
+    };
+    context.fit_to_budget(max_chars, format);
+
+    match format {
+        ContextFormat::Markdown => Ok((
+            [(header::CONTENT_TYPE, "text/markdown; charset=utf-8")],
+            context.to_markdown(),
+        )
+            .into_response()),
+        ContextFormat::Json => Ok(Json(WorkItemContextResponse { context }).into_response()),
+    }
+}
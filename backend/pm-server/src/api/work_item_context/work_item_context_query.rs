@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// Query parameters for a work item context pack
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct WorkItemContextQuery {
+    /// Output format: markdown (default) or json
+    pub format: Option<String>,
+    /// Size budget in characters for the rendered document
+    pub max_chars: Option<usize>,
+}
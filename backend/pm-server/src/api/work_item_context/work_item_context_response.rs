@@ -0,0 +1,10 @@
+use pm_core::WorkItemContext;
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Context pack response (JSON format)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorkItemContextResponse {
+    pub context: WorkItemContext,
+}
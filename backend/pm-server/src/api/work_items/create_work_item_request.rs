@@ -1,7 +1,10 @@
+use pm_core::CustomFieldValues;
+
 use serde::Deserialize;
+use utoipa::ToSchema;
 
 /// Request body for creating a work item
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateWorkItemRequest {
     pub project_id: String,
     pub item_type: String,
@@ -14,4 +17,11 @@ pub struct CreateWorkItemRequest {
     pub status: Option<String>,
     #[serde(default)]
     pub priority: Option<String>,
+    /// Original estimate in seconds; the remaining estimate starts equal to it
+    #[serde(default)]
+    pub original_estimate_seconds: Option<i32>,
+    /// Custom field values keyed by field name
+    #[serde(default)]
+    #[schema(value_type = Object)]
+    pub custom_fields: CustomFieldValues,
 }
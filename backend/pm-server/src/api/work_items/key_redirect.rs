@@ -0,0 +1,9 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// An old display key and the one it now resolves to
+#[derive(Debug, Serialize, ToSchema)]
+pub struct KeyRedirect {
+    pub from: String,
+    pub to: String,
+}
@@ -1,7 +1,9 @@
 use serde::Deserialize;
+use utoipa::IntoParams;
 
 /// Query parameters for listing work items
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct ListWorkItemsQuery {
     #[serde(rename = "type")]
     pub item_type: Option<String>,
@@ -18,4 +20,6 @@ pub struct ListWorkItemsQuery {
     /// When true, include work items with status 'done' (default: false)
     #[serde(default)]
     pub include_done: bool,
+    /// Custom field filters as comma-separated `name:value` pairs (all must match)
+    pub custom_field: Option<String>,
 }
@@ -1,5 +1,9 @@
 pub(crate) mod create_work_item_request;
+pub(crate) mod key_redirect;
 pub(crate) mod list_work_item_query;
+pub(crate) mod move_to_project_request;
+pub(crate) mod move_to_project_response;
+pub(crate) mod move_work_item_request;
 pub(crate) mod update_work_item_request;
 pub(crate) mod work_item_list_response;
 pub(crate) mod work_item_response;
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+/// Request body for moving a work item (and its descendants) to another project
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MoveToProjectRequest {
+    /// Target project UUID or key
+    pub project_id: String,
+    /// New parent in the target project (UUID or display key); omit to move
+    /// the item to the top level
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    /// Version of the moved item, unless `If-Match` is sent
+    #[serde(default)]
+    pub expected_version: Option<i32>,
+}
@@ -0,0 +1,14 @@
+use crate::KeyRedirect;
+
+use pm_core::WorkItemDto;
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// The moved work item, and the new display key of every item that moved
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MoveToProjectResponse {
+    pub work_item: WorkItemDto,
+    /// Parents before children, the moved item first
+    pub moved: Vec<KeyRedirect>,
+}
@@ -0,0 +1,18 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+/// Request body for moving a work item within its project's backlog. At
+/// least one neighbour is required; with one given, the item goes directly
+/// next to it.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MoveWorkItemRequest {
+    /// Item that should end up directly above the moved one (UUID or display key)
+    #[serde(default)]
+    pub before_id: Option<String>,
+    /// Item that should end up directly below the moved one (UUID or display key)
+    #[serde(default)]
+    pub after_id: Option<String>,
+    /// Version of the moved item, unless `If-Match` is sent
+    #[serde(default)]
+    pub expected_version: Option<i32>,
+}
@@ -1,7 +1,10 @@
+use pm_core::CustomFieldValues;
+
 use serde::Deserialize;
+use utoipa::ToSchema;
 
 /// Request body for updating a work item
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateWorkItemRequest {
     #[serde(default)]
     pub title: Option<String>,
@@ -18,12 +21,24 @@ pub struct UpdateWorkItemRequest {
     #[serde(default)]
     pub story_points: Option<i32>,
     #[serde(default)]
+    pub original_estimate_seconds: Option<i32>,
+    #[serde(default)]
+    pub remaining_estimate_seconds: Option<i32>,
+    /// Set to true to clear both estimates
+    #[serde(default)]
+    pub clear_estimate: bool,
+    #[serde(default)]
     pub parent_id: Option<String>,
     /// Set to true to update parent_id (allows clearing parent)
     #[serde(default)]
     pub update_parent: bool,
     #[serde(default)]
     pub position: Option<i32>,
-    /// Required: current version for optimistic locking
-    pub expected_version: i32,
+    /// Custom field values to set (null clears a field); omitted fields are unchanged
+    #[serde(default)]
+    #[schema(value_type = Object)]
+    pub custom_fields: CustomFieldValues,
+    /// Optimistic locking; may be replaced by an `If-Match` header
+    #[serde(default)]
+    pub expected_version: Option<i32>,
 }
@@ -1,9 +1,14 @@
-use pm_core::WorkItemDto;
-
 use serde::Serialize;
+use serde_json::Value;
+use utoipa::ToSchema;
 
 /// List of work items response
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct WorkItemListResponse {
-    pub work_items: Vec<WorkItemDto>,
+    /// Work item DTOs, reduced to the requested `fields`
+    pub work_items: Vec<Value>,
+    /// Matching items across all pages
+    pub total: usize,
+    /// Cursor for the next page; None on the last page
+    pub next_cursor: Option<String>,
 }
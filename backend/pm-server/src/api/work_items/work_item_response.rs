@@ -1,9 +1,10 @@
 use pm_core::WorkItemDto;
 
 use serde::Serialize;
+use utoipa::ToSchema;
 
 /// Single work item response
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct WorkItemResponse {
     pub work_item: WorkItemDto,
 }
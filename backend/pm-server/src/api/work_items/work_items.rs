@@ -4,28 +4,41 @@
 //! via WebSocket so connected clients see updates in real-time.
 
 use crate::{
-    ApiError, ApiResult, CreateWorkItemRequest, DeleteResponse, ListWorkItemsQuery,
-    UpdateWorkItemRequest, UserId, WorkItemListResponse, WorkItemResponse,
+    ApiError, ApiResult, CreateWorkItemRequest, DeleteResponse, KeyRedirect, ListWorkItemsQuery,
+    MoveToProjectRequest, MoveToProjectResponse, MoveWorkItemRequest, PageQuery, Preconditions,
+    TenantState, UpdateWorkItemRequest, UserId, WorkItemListResponse, WorkItemResponse,
     api::resolve::{resolve_project, resolve_work_item},
+    not_modified, precondition_failed, tagged_json,
 };
 
-use pm_core::{ActivityLog, WorkItem, WorkItemDto, WorkItemType};
-use pm_db::{ActivityLogRepository, ProjectRepository, WorkItemRepository};
+use pm_core::{
+    ActivityLog, CustomFieldDefinition, CustomFieldValues, EstimateRollup, WorkItem, WorkItemDto,
+    WorkItemType, apply_custom_field_values,
+};
+use pm_db::{
+    ActivityLogRepository, CustomFieldRepository, DependencyRepository, ProjectRepository,
+    WorkItemRepository,
+};
 use pm_ws::{
-    AppState, MessageValidator, build_activity_log_created_event, build_work_item_created_response,
-    build_work_item_deleted_response, build_work_item_updated_response, compute_hierarchy_for_item,
-    sanitize_string, validate_hierarchy, validate_priority, validate_status,
+    AppState, FieldChangeBuilder, MessageValidator, broadcast_rebalanced,
+    build_activity_log_created_event, build_work_item_created_response,
+    build_work_item_deleted_response, build_work_item_updated_response, compute_estimate_for_item,
+    compute_hierarchy_for_item, load_logged_seconds, next_rank, rank_for_move,
+    resolve_custom_field_values, sanitize_string, schedule_rebalance, track_custom_field_changes,
+    track_estimate_changes, validate_hierarchy, validate_priority, validate_status,
 };
 
 use std::{panic::Location, str::FromStr};
 
 use axum::{
     Json,
-    extract::{Path, Query, State, ws::Message},
+    extract::{Path, Query, ws::Message},
+    response::Response,
 };
 use chrono::Utc;
 use error_location::ErrorLocation;
 use prost::Message as ProstMessage;
+use sqlx::SqlitePool;
 use uuid::Uuid;
 
 // =============================================================================
@@ -76,19 +89,87 @@ fn collect_ancestor_ids(work_items: &[WorkItem], item_id: Uuid) -> std::collecti
     ancestors
 }
 
-// =============================================================================
-// Handlers
-// =============================================================================
+/// Display key of a work item in any project, or its UUID if it is gone
+async fn describe_work_item(pool: &SqlitePool, id: Uuid) -> ApiResult<String> {
+    let Some(work_item) = WorkItemRepository::find_by_id(pool, id).await? else {
+        return Ok(id.to_string());
+    };
+    let project = ProjectRepository::new(pool.clone())
+        .find_by_id(work_item.project_id)
+        .await?;
+    Ok(match project {
+        Some(project) => work_item.display_key(&project.key),
+        None => id.to_string(),
+    })
+}
 
-/// GET /api/v1/work-items/:id
-///
-/// Retrieve a single work item by ID
-pub async fn get_work_item(
-    State(state): State<AppState>,
-    Path(id): Path<String>,
-) -> ApiResult<Json<WorkItemResponse>> {
-    let work_item = resolve_work_item(&state.pool, &id).await?;
+/// An item and its descendants, parents before children and siblings in
+/// item number order
+fn collect_subtree(work_items: &[WorkItem], root: &WorkItem) -> Vec<WorkItem> {
+    let mut subtree = vec![root.clone()];
+    let mut next = 0;
+
+    while next < subtree.len() {
+        let parent_id = subtree[next].id;
+        let mut children: Vec<&WorkItem> = work_items
+            .iter()
+            .filter(|w| w.parent_id == Some(parent_id))
+            .collect();
+        children.sort_by_key(|w| w.item_number);
+        subtree.extend(children.into_iter().cloned());
+        next += 1;
+    }
+
+    subtree
+}
+
+/// Parse a `name:value,name:value` custom field filter into (definition, value) pairs.
+/// Unknown field names are rejected so typos don't silently match nothing.
+fn parse_custom_field_filters(
+    raw: &str,
+    definitions: &[CustomFieldDefinition],
+) -> ApiResult<Vec<(CustomFieldDefinition, String)>> {
+    raw.split(',')
+        .filter(|part| !part.trim().is_empty())
+        .map(|part| {
+            let (name, value) = part.split_once(':').ok_or_else(|| ApiError::Validation {
+                message: format!(
+                    "Invalid custom_field filter '{}': expected name:value",
+                    part
+                ),
+                field: Some("custom_field".into()),
+                location: ErrorLocation::from(Location::caller()),
+            })?;
+            let name = name.trim();
+            let definition = definitions.iter().find(|d| d.name == name).ok_or_else(|| {
+                ApiError::Validation {
+                    message: format!("Unknown custom field: {}", name),
+                    field: Some("custom_field".into()),
+                    location: ErrorLocation::from(Location::caller()),
+                }
+            })?;
+            Ok((definition.clone(), value.trim().to_string()))
+        })
+        .collect()
+}
+
+/// Reject negative estimates with a field-specific validation error
+fn validate_estimate(value: Option<i32>, field: &str) -> ApiResult<()> {
+    if let Some(seconds) = value {
+        MessageValidator::validate_estimate_seconds(seconds, field).map_err(|e| {
+            ApiError::Validation {
+                message: e.to_string(),
+                field: Some(field.into()),
+                location: ErrorLocation::from(Location::caller()),
+            }
+        })?;
+    }
+    Ok(())
+}
 
+/// Build the response a GET returns for a work item: display key plus the
+/// estimate rolled up over its subtree
+async fn work_item_response(state: &AppState, work_item: WorkItem) -> ApiResult<WorkItemResponse> {
     // Get project key for display_key
     let repo = ProjectRepository::new(state.pool.clone());
     let project = repo
@@ -99,19 +180,84 @@ pub async fn get_work_item(
             location: ErrorLocation::from(Location::caller()),
         })?;
 
-    Ok(Json(WorkItemResponse {
-        work_item: WorkItemDto::from_work_item(work_item, &project.key),
-    }))
+    // Roll estimates up over the item's subtree
+    let all_items = WorkItemRepository::find_by_project(&state.pool, project.id, true).await?;
+    let logged_seconds = load_logged_seconds(&state.pool, project.id).await;
+    let estimate = compute_estimate_for_item(&all_items, &logged_seconds, work_item.id);
+
+    let mut dto = WorkItemDto::from_work_item(work_item, &project.key);
+    dto.estimate = estimate;
+    Ok(WorkItemResponse { work_item: dto })
 }
 
+// =============================================================================
+// Handlers
+// =============================================================================
+
+/// GET /api/v1/work-items/:id
+///
+/// Retrieve a single work item by ID. Tagged with its version; honors `If-None-Match`.
+#[utoipa::path(
+    get,
+    path = "/api/v1/work-items/{id}",
+    tag = "work-items",
+    params(("id" = String, Path, description = "Work item UUID or display key"), Preconditions),
+    responses(
+        (status = 200, description = "The work item, with its version as `ETag`", body = WorkItemResponse),
+        (status = 304, description = "`If-None-Match` matches the current version"),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
+pub async fn get_work_item(
+    TenantState(state): TenantState,
+    Path(id): Path<String>,
+    preconditions: Preconditions,
+) -> ApiResult<Response> {
+    let work_item = resolve_work_item(&state.pool, &id).await?;
+
+    if preconditions.is_not_modified(work_item.version) {
+        return Ok(not_modified(work_item.version));
+    }
+    let version = work_item.version;
+    Ok(tagged_json(
+        version,
+        work_item_response(&state, work_item).await?,
+    ))
+}
+
+/// Fields work item lists can be sorted on
+const WORK_ITEM_SORT_FIELDS: [&str; 9] = [
+    "item_number",
+    "title",
+    "status",
+    "position",
+    "rank",
+    "story_points",
+    "created_at",
+    "updated_at",
+    "display_key",
+];
+
 /// GET /api/v1/projects/:project_id/work-items
 ///
-/// List work items in a project with optional filters
+/// List work items in a project with optional filters, one page at a time
 #[allow(clippy::unnecessary_map_or)]
+#[utoipa::path(
+    get,
+    path = "/api/v1/projects/{project_id}/work-items",
+    tag = "work-items",
+    params(("project_id" = String, Path, description = "Project UUID or key"), ListWorkItemsQuery, PageQuery),
+    responses(
+        (status = 200, description = "One page of work items", body = WorkItemListResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
 pub async fn list_work_items(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     Path(project_id): Path<String>,
     Query(query): Query<ListWorkItemsQuery>,
+    Query(page): Query<PageQuery>,
 ) -> ApiResult<Json<WorkItemListResponse>> {
     let project = resolve_project(&state.pool, &project_id).await?;
     let project_uuid = project.id;
@@ -129,6 +275,28 @@ pub async fn list_work_items(
     let work_items =
         WorkItemRepository::find_by_project(&state.pool, project_uuid, query.include_done).await?;
 
+    // Estimate roll-ups always cover the full hierarchy, done items included
+    let mut estimates = {
+        let all_items = if query.include_done {
+            work_items.clone()
+        } else {
+            WorkItemRepository::find_by_project(&state.pool, project_uuid, true).await?
+        };
+        let logged_seconds = load_logged_seconds(&state.pool, project_uuid).await;
+        EstimateRollup::compute(&all_items, &logged_seconds)
+    };
+
+    // Resolve custom field filters against the project's definitions
+    let custom_field_filters = match &query.custom_field {
+        Some(raw) => {
+            let definitions = CustomFieldRepository::new(state.pool.clone())
+                .find_by_project(project_uuid)
+                .await?;
+            parse_custom_field_filters(raw, &definitions)?
+        }
+        None => Vec::new(),
+    };
+
     // Pre-compute descendant IDs if descendants_of is requested
     let descendant_ids: Option<std::collections::HashSet<Uuid>> =
         if let Some(ancestor_str) = &query.descendants_of {
@@ -165,6 +333,15 @@ pub async fn list_work_items(
                 return false;
             }
 
+            // Custom field filters (all must match)
+            if !custom_field_filters.iter().all(|(def, expected)| {
+                w.custom_fields
+                    .get(&def.name)
+                    .is_some_and(|v| def.matches_filter(v, expected))
+            }) {
+                return false;
+            }
+
             query
                 .item_type
                 .as_ref()
@@ -181,19 +358,43 @@ pub async fn list_work_items(
                     })
                 }
         })
-        .map(|w| WorkItemDto::from_work_item(w, &project.key))
+        .map(|w| {
+            let estimate = estimates.remove(&w.id);
+            let mut dto = WorkItemDto::from_work_item(w, &project.key);
+            dto.estimate = estimate;
+            dto
+        })
         .collect();
 
+    let page = page.paginate(
+        &filtered,
+        &state.api_config,
+        &WORK_ITEM_SORT_FIELDS,
+        "item_number",
+    )?;
     Ok(Json(WorkItemListResponse {
-        work_items: filtered,
+        work_items: page.items,
+        total: page.total,
+        next_cursor: page.next_cursor,
     }))
 }
 
 /// POST /api/v1/work-items
 ///
 /// Create a new work item. Broadcasts activity to WebSocket clients.
+#[utoipa::path(
+    post,
+    path = "/api/v1/work-items",
+    tag = "work-items",
+    request_body = CreateWorkItemRequest,
+    responses(
+        (status = 200, description = "The created work item", body = WorkItemResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+    )
+)]
 pub async fn create_work_item(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     UserId(user_id): UserId,
     Json(req): Json<CreateWorkItemRequest>,
 ) -> ApiResult<Json<WorkItemResponse>> {
@@ -219,6 +420,7 @@ pub async fn create_work_item(
         field: None,
         location: ErrorLocation::from(Location::caller()),
     })?;
+    validate_estimate(req.original_estimate_seconds, "original_estimate_seconds")?;
 
     // 3. Parse IDs
     let project_id = Uuid::parse_str(&req.project_id)?;
@@ -250,6 +452,16 @@ pub async fn create_work_item(
             location: ErrorLocation::from(Location::caller()),
         })?;
 
+    // 5b. Validate custom field values and apply defaults
+    let custom_fields = resolve_custom_field_values(
+        &state.pool,
+        project_id,
+        &CustomFieldValues::new(),
+        &req.custom_fields,
+        true,
+    )
+    .await?;
+
     // 6. Get next position
     let max_position =
         WorkItemRepository::find_max_position(&state.pool, project_id, parent_id).await?;
@@ -262,13 +474,17 @@ pub async fn create_work_item(
         parent_id,
         project_id,
         position: (max_position + 1) as i32,
+        rank: String::new(), // Will be set by transaction
         title: sanitize_string(&req.title),
         description: req.description.as_ref().map(|d| sanitize_string(d)),
         status: req.status.unwrap_or_else(|| "backlog".to_string()),
         priority: req.priority.unwrap_or_else(|| "medium".to_string()),
         assignee_id: None,
         story_points: None,
+        original_estimate_seconds: req.original_estimate_seconds,
+        remaining_estimate_seconds: req.original_estimate_seconds,
         sprint_id: None,
+        custom_fields,
         item_number: 0, // Will be set by transaction
         version: 1,
         created_at: now,
@@ -293,13 +509,26 @@ pub async fn create_work_item(
             location: ErrorLocation::from(Location::caller()),
         })?;
 
+    // Rank it at the end of the backlog, now the write lock is held
+    let (rank, rebalanced) = next_rank(&mut tx, project_id, user_id).await?;
+
     work_item.item_number = item_number;
+    work_item.rank = rank.clone();
     let mut wi_to_insert = work_item_for_tx;
     wi_to_insert.item_number = item_number;
+    wi_to_insert.rank = rank;
 
     WorkItemRepository::create(&mut *tx, &wi_to_insert).await?;
     ActivityLogRepository::create(&mut *tx, &activity_clone).await?;
     tx.commit().await?;
+    broadcast_rebalanced(
+        &state.pool,
+        &state.registry,
+        project_id,
+        &rebalanced,
+        user_id,
+    )
+    .await;
 
     // Compute hierarchy for the newly created item
     let all_items = WorkItemRepository::find_by_project(&state.pool, project_id, true)
@@ -309,6 +538,8 @@ pub async fn create_work_item(
             vec![]
         });
     let hierarchy = compute_hierarchy_for_item(&all_items, work_item.id);
+    // A new item has no logged time or children yet
+    let estimate = compute_estimate_for_item(&all_items, &Default::default(), work_item.id);
 
     // 9. Broadcast to WebSocket clients
     let event = build_activity_log_created_event(&activity);
@@ -338,6 +569,7 @@ pub async fn create_work_item(
         user_id,
         hierarchy.ancestor_ids,
         hierarchy.descendant_ids,
+        estimate.clone(),
     );
     let broadcast_bytes = broadcast.encode_to_vec();
     if let Err(e) = state
@@ -357,31 +589,45 @@ pub async fn create_work_item(
         work_item.item_number
     );
 
-    Ok(Json(WorkItemResponse {
-        work_item: WorkItemDto::from_work_item(work_item, &project.key),
-    }))
+    let mut dto = WorkItemDto::from_work_item(work_item, &project.key);
+    dto.estimate = estimate;
+    Ok(Json(WorkItemResponse { work_item: dto }))
 }
 
 /// PUT /api/v1/work-items/:id
 ///
-/// Update an existing work item. Uses optimistic locking via expected_version.
+/// Update an existing work item. Uses optimistic locking via `If-Match` or expected_version.
+#[utoipa::path(
+    put,
+    path = "/api/v1/work-items/{id}",
+    tag = "work-items",
+    params(("id" = String, Path, description = "Work item UUID or display key"), Preconditions),
+    request_body = UpdateWorkItemRequest,
+    responses(
+        (status = 200, description = "The updated work item", body = WorkItemResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+        (status = 409, description = "Version conflict or duplicate", body = crate::ApiErrorResponse),
+        (status = 412, description = "`If-Match` does not match; the current entity is returned beside `error`", body = WorkItemResponse),
+    )
+)]
 pub async fn update_work_item(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     UserId(user_id): UserId,
     Path(id): Path<String>,
+    preconditions: Preconditions,
     Json(req): Json<UpdateWorkItemRequest>,
-) -> ApiResult<Json<WorkItemResponse>> {
+) -> ApiResult<Response> {
     // 1. Fetch existing work item
     let mut work_item = resolve_work_item(&state.pool, &id).await?;
 
     // 2. Check version (optimistic locking)
-    if work_item.version != req.expected_version {
-        return Err(ApiError::Conflict {
-            message: "Version mismatch - work item was modified by another user".into(),
-            current_version: work_item.version,
-            location: ErrorLocation::from(Location::caller()),
-        });
+    if preconditions.if_match_fails(work_item.version) {
+        let version = work_item.version;
+        let current = work_item_response(&state, work_item).await?;
+        return Err(precondition_failed(version, &current));
     }
+    preconditions.check_expected_version(work_item.version, req.expected_version)?;
 
     // 3. Get project for response
     let repo = ProjectRepository::new(state.pool.clone());
@@ -451,6 +697,23 @@ pub async fn update_work_item(
     if let Some(sp) = req.story_points {
         work_item.story_points = Some(sp);
     }
+    validate_estimate(req.original_estimate_seconds, "original_estimate_seconds")?;
+    validate_estimate(req.remaining_estimate_seconds, "remaining_estimate_seconds")?;
+    let (original_estimate, remaining_estimate) = if req.clear_estimate {
+        (None, None)
+    } else {
+        (
+            req.original_estimate_seconds
+                .or(work_item.original_estimate_seconds),
+            // Setting an estimate on unestimated work starts the remaining time at it
+            req.remaining_estimate_seconds
+                .or(work_item.remaining_estimate_seconds)
+                .or(req.original_estimate_seconds),
+        )
+    };
+    let mut changes = track_estimate_changes(&work_item, original_estimate, remaining_estimate);
+    work_item.original_estimate_seconds = original_estimate;
+    work_item.remaining_estimate_seconds = remaining_estimate;
     if req.update_parent {
         work_item.parent_id = req
             .parent_id
@@ -462,6 +725,19 @@ pub async fn update_work_item(
     if let Some(pos) = req.position {
         work_item.position = pos;
     }
+    let custom_fields = resolve_custom_field_values(
+        &state.pool,
+        work_item.project_id,
+        &work_item.custom_fields,
+        &req.custom_fields,
+        false,
+    )
+    .await?;
+    changes.extend(track_custom_field_changes(
+        &work_item.custom_fields,
+        &custom_fields,
+    ));
+    work_item.custom_fields = custom_fields;
 
     // 5. Update metadata
     work_item.updated_at = Utc::now();
@@ -469,7 +745,7 @@ pub async fn update_work_item(
     work_item.version += 1;
 
     // 6. Execute transaction
-    let activity = ActivityLog::updated("work_item", work_item.id, user_id, &[]);
+    let activity = ActivityLog::updated("work_item", work_item.id, user_id, &changes);
     let work_item_clone = work_item.clone();
     let activity_clone = activity.clone();
 
@@ -486,6 +762,8 @@ pub async fn update_work_item(
             vec![]
         });
     let hierarchy = compute_hierarchy_for_item(&all_items, work_item.id);
+    let logged_seconds = load_logged_seconds(&state.pool, work_item.project_id).await;
+    let estimate = compute_estimate_for_item(&all_items, &logged_seconds, work_item.id);
 
     // 7. Broadcast to WebSocket clients
     let event = build_activity_log_created_event(&activity);
@@ -512,10 +790,11 @@ pub async fn update_work_item(
     let broadcast = build_work_item_updated_response(
         &Uuid::new_v4().to_string(),
         &work_item,
-        &[], // REST API doesn't track field changes currently
+        &changes, // REST API only tracks estimate and custom field changes currently
         user_id,
         hierarchy.ancestor_ids,
         hierarchy.descendant_ids,
+        estimate.clone(),
     );
     let broadcast_bytes = broadcast.encode_to_vec();
     if let Err(e) = state
@@ -535,21 +814,456 @@ pub async fn update_work_item(
         work_item.version
     );
 
-    Ok(Json(WorkItemResponse {
-        work_item: WorkItemDto::from_work_item(work_item, &project.key),
+    let version = work_item.version;
+    let mut dto = WorkItemDto::from_work_item(work_item, &project.key);
+    dto.estimate = estimate;
+    Ok(tagged_json(version, WorkItemResponse { work_item: dto }))
+}
+
+/// POST /api/v1/work-items/:id/move
+///
+/// Move a work item within its project's backlog, directly below `before_id`
+/// and/or directly above `after_id`. Only the moved item's rank and version
+/// change, unless tied neighbours force a rebalance of the project first.
+/// Honors `If-Match`.
+#[utoipa::path(
+    post,
+    path = "/api/v1/work-items/{id}/move",
+    tag = "work-items",
+    params(("id" = String, Path, description = "Work item UUID or display key"), Preconditions),
+    request_body = MoveWorkItemRequest,
+    responses(
+        (status = 200, description = "The moved work item", body = WorkItemResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+        (status = 409, description = "Version conflict", body = crate::ApiErrorResponse),
+        (status = 412, description = "`If-Match` does not match; the current entity is returned beside `error`", body = WorkItemResponse),
+    )
+)]
+pub async fn move_work_item(
+    TenantState(state): TenantState,
+    UserId(user_id): UserId,
+    Path(id): Path<String>,
+    preconditions: Preconditions,
+    Json(req): Json<MoveWorkItemRequest>,
+) -> ApiResult<Response> {
+    // 1. Fetch the item and check its version
+    let mut work_item = resolve_work_item(&state.pool, &id).await?;
+    if preconditions.if_match_fails(work_item.version) {
+        let version = work_item.version;
+        let current = work_item_response(&state, work_item).await?;
+        return Err(precondition_failed(version, &current));
+    }
+    preconditions.check_expected_version(work_item.version, req.expected_version)?;
+
+    // 2. Resolve the neighbours and rank between them
+    let before_id = match req.before_id.as_deref() {
+        Some(before) => Some(resolve_work_item(&state.pool, before).await?.id),
+        None => None,
+    };
+    let after_id = match req.after_id.as_deref() {
+        Some(after) => Some(resolve_work_item(&state.pool, after).await?.id),
+        None => None,
+    };
+    let placed = rank_for_move(
+        &state.pool,
+        &state.registry,
+        &work_item,
+        before_id,
+        after_id,
+        user_id,
+    )
+    .await?;
+
+    let mut builder = FieldChangeBuilder::new();
+    builder.track("rank", &work_item.rank, &placed.rank);
+    let changes = builder.build();
+    work_item = placed;
+    work_item.updated_at = Utc::now();
+    work_item.updated_by = user_id;
+    work_item.version += 1;
+
+    // 3. Execute transaction: only this item's row changes
+    let activity = ActivityLog::updated("work_item", work_item.id, user_id, &changes);
+    let mut tx = state.pool.begin().await?;
+    WorkItemRepository::update_rank(&mut *tx, &work_item).await?;
+    ActivityLogRepository::create(&mut *tx, &activity).await?;
+    tx.commit().await?;
+
+    schedule_rebalance(
+        &state.pool,
+        &state.registry,
+        work_item.project_id,
+        user_id,
+        &work_item.rank,
+    );
+
+    // 4. Broadcast to WebSocket clients
+    let all_items = WorkItemRepository::find_by_project(&state.pool, work_item.project_id, true)
+        .await
+        .unwrap_or_else(|e| {
+            log::warn!("Failed to fetch items for hierarchy computation: {}", e);
+            vec![]
+        });
+    let hierarchy = compute_hierarchy_for_item(&all_items, work_item.id);
+    let logged_seconds = load_logged_seconds(&state.pool, work_item.project_id).await;
+    let estimate = compute_estimate_for_item(&all_items, &logged_seconds, work_item.id);
+
+    let event = build_activity_log_created_event(&activity);
+    let message = Message::Binary(event.encode_to_vec().into());
+    if let Err(e) = state
+        .registry
+        .broadcast_activity_log_created(
+            &work_item.project_id.to_string(),
+            Some(&work_item.id.to_string()),
+            None,
+            message,
+        )
+        .await
+    {
+        log::warn!(
+            "Failed to broadcast work item move to WebSocket clients: {}",
+            e
+        );
+    }
+
+    let broadcast = build_work_item_updated_response(
+        &Uuid::new_v4().to_string(),
+        &work_item,
+        &changes,
+        user_id,
+        hierarchy.ancestor_ids,
+        hierarchy.descendant_ids,
+        estimate,
+    );
+    if let Err(e) = state
+        .registry
+        .broadcast_to_project(
+            &work_item.project_id.to_string(),
+            Message::Binary(broadcast.encode_to_vec().into()),
+        )
+        .await
+    {
+        log::warn!("Failed to broadcast WorkItemUpdated via REST: {}", e);
+    }
+
+    log::info!(
+        "Moved work item {} to rank {} via REST API",
+        work_item.id,
+        work_item.rank
+    );
+
+    let version = work_item.version;
+    Ok(tagged_json(
+        version,
+        work_item_response(&state, work_item).await?,
+    ))
+}
+
+/// POST /api/v1/work-items/:id/move-to-project
+///
+/// Move a work item and its descendants to another project. Comments, time
+/// entries and dependencies stay attached; each item gets the next number in
+/// the target project and its old display key keeps resolving. Honors
+/// `If-Match` for the moved item.
+#[utoipa::path(
+    post,
+    path = "/api/v1/work-items/{id}/move-to-project",
+    tag = "work-items",
+    params(("id" = String, Path, description = "Work item UUID or display key"), Preconditions),
+    request_body = MoveToProjectRequest,
+    responses(
+        (status = 200, description = "The moved work item and the new display keys", body = MoveToProjectResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+        (status = 409, description = "Version conflict", body = crate::ApiErrorResponse),
+        (status = 412, description = "`If-Match` does not match; the current entity is returned beside `error`", body = WorkItemResponse),
+    )
+)]
+pub async fn move_work_item_to_project(
+    TenantState(state): TenantState,
+    UserId(user_id): UserId,
+    Path(id): Path<String>,
+    preconditions: Preconditions,
+    Json(req): Json<MoveToProjectRequest>,
+) -> ApiResult<Json<MoveToProjectResponse>> {
+    // 1. Fetch the item and check its version
+    let root = resolve_work_item(&state.pool, &id).await?;
+    if preconditions.if_match_fails(root.version) {
+        let version = root.version;
+        let current = work_item_response(&state, root).await?;
+        return Err(precondition_failed(version, &current));
+    }
+    preconditions.check_expected_version(root.version, req.expected_version)?;
+
+    // 2. Resolve the source and target projects
+    let source = ProjectRepository::new(state.pool.clone())
+        .find_by_id(root.project_id)
+        .await?
+        .ok_or_else(|| ApiError::Internal {
+            message: "Project not found for work item".to_string(),
+            location: ErrorLocation::from(Location::caller()),
+        })?;
+    let target = resolve_project(&state.pool, &req.project_id).await?;
+    if target.id == source.id {
+        return Err(ApiError::Validation {
+            message: format!(
+                "{}-{} is already in project {}",
+                source.key, root.item_number, target.key
+            ),
+            field: Some("project_id".into()),
+            location: ErrorLocation::from(Location::caller()),
+        });
+    }
+
+    // 3. Validate the new parent against the hierarchy rules
+    let parent_id = match req.parent_id.as_deref().filter(|s| !s.is_empty()) {
+        Some(parent) => {
+            let parent = resolve_work_item(&state.pool, parent).await?;
+            if parent.project_id != target.id {
+                return Err(ApiError::Validation {
+                    message: format!("Parent must be in project {}", target.key),
+                    field: Some("parent_id".into()),
+                    location: ErrorLocation::from(Location::caller()),
+                });
+            }
+            validate_hierarchy(&state.pool, root.item_type.clone(), parent.id)
+                .await
+                .map_err(|e| ApiError::Validation {
+                    message: e.to_string(),
+                    field: Some("parent_id".into()),
+                    location: ErrorLocation::from(Location::caller()),
+                })?;
+            Some(parent.id)
+        }
+        None => None,
+    };
+
+    // 4. Collect the subtree; dependencies must not cross into the source project
+    let source_items = WorkItemRepository::find_by_project(&state.pool, source.id, true).await?;
+    let subtree = collect_subtree(&source_items, &root);
+    let moving: std::collections::HashSet<Uuid> = subtree.iter().map(|w| w.id).collect();
+
+    let dependencies = DependencyRepository::new(state.pool.clone());
+    for item in &subtree {
+        let mut linked = dependencies.find_blocking(item.id).await?;
+        linked.extend(dependencies.find_blocked(item.id).await?);
+        for dependency in linked {
+            let other = if dependency.blocking_item_id == item.id {
+                dependency.blocked_item_id
+            } else {
+                dependency.blocking_item_id
+            };
+            if !moving.contains(&other) {
+                return Err(ApiError::Validation {
+                    message: format!(
+                        "{} has a dependency on {}, which is not being moved. \
+                         Remove the dependency or move both.",
+                        item.display_key(&source.key),
+                        describe_work_item(&state.pool, other).await?
+                    ),
+                    field: None,
+                    location: ErrorLocation::from(Location::caller()),
+                });
+            }
+        }
+    }
+
+    // 5. Re-home each item. Sprints belong to the source project, and only
+    //    custom fields the target project defines are kept.
+    let definitions = CustomFieldRepository::new(state.pool.clone())
+        .find_by_project(target.id)
+        .await?;
+    let position =
+        WorkItemRepository::find_max_position(&state.pool, target.id, parent_id).await? + 1;
+    let now = Utc::now();
+
+    let mut moved = Vec::with_capacity(subtree.len());
+    for current in subtree {
+        let kept: CustomFieldValues = current
+            .custom_fields
+            .iter()
+            .filter(|(name, _)| definitions.iter().any(|d| &d.name == *name))
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+        let custom_fields =
+            apply_custom_field_values(&definitions, &CustomFieldValues::new(), &kept, true)?;
+
+        let mut item = current.clone();
+        if item.id == root.id {
+            item.parent_id = parent_id;
+            item.position = position as i32;
+        }
+        item.project_id = target.id;
+        item.sprint_id = None;
+        item.custom_fields = custom_fields;
+        item.updated_at = now;
+        item.updated_by = user_id;
+        item.version += 1;
+        moved.push((current, item));
+    }
+
+    // 6. Execute transaction: new numbers, aliases for the old ones, and
+    //    ranks appended to the target backlog, parents before children
+    let mut activities = Vec::with_capacity(moved.len());
+    let mut rebalanced = Vec::new();
+    let mut tx = state.pool.begin().await?;
+    for (current, item) in &mut moved {
+        item.item_number =
+            ProjectRepository::get_and_increment_work_item_number(&mut tx, target.id)
+                .await
+                .map_err(|e| ApiError::Internal {
+                    message: e.to_string(),
+                    location: ErrorLocation::from(Location::caller()),
+                })?;
+        let (rank, respread) = next_rank(&mut tx, target.id, user_id).await?;
+        item.rank = rank;
+        rebalanced.extend(respread);
+
+        let mut builder = FieldChangeBuilder::new();
+        builder.track("project_id", &current.project_id, &item.project_id);
+        builder.track("item_number", &current.item_number, &item.item_number);
+        builder.track_option("parent_id", &current.parent_id, &item.parent_id);
+        builder.track("position", &current.position, &item.position);
+        builder.track("rank", &current.rank, &item.rank);
+        builder.track_option("sprint_id", &current.sprint_id, &item.sprint_id);
+        let mut changes = builder.build();
+        changes.extend(track_custom_field_changes(
+            &current.custom_fields,
+            &item.custom_fields,
+        ));
+
+        WorkItemRepository::move_to_project(&mut *tx, item).await?;
+        WorkItemRepository::create_alias(&mut *tx, source.id, current.item_number, item.id).await?;
+        let activity = ActivityLog::updated("work_item", item.id, user_id, &changes);
+        ActivityLogRepository::create(&mut *tx, &activity).await?;
+        activities.push(activity);
+    }
+    tx.commit().await?;
+    broadcast_rebalanced(
+        &state.pool,
+        &state.registry,
+        target.id,
+        &rebalanced,
+        user_id,
+    )
+    .await;
+
+    // 7. Broadcast: gone from the source project, created in the target
+    let target_items = WorkItemRepository::find_by_project(&state.pool, target.id, true)
+        .await
+        .unwrap_or_else(|e| {
+            log::warn!("Failed to fetch items for hierarchy computation: {}", e);
+            vec![]
+        });
+    let logged_seconds = load_logged_seconds(&state.pool, target.id).await;
+
+    for ((_, item), activity) in moved.iter().zip(&activities) {
+        let event = build_activity_log_created_event(activity);
+        let message = Message::Binary(event.encode_to_vec().into());
+        if let Err(e) = state
+            .registry
+            .broadcast_activity_log_created(
+                &target.id.to_string(),
+                Some(&item.id.to_string()),
+                None,
+                message,
+            )
+            .await
+        {
+            log::warn!(
+                "Failed to broadcast work item move to WebSocket clients: {}",
+                e
+            );
+        }
+
+        let deleted =
+            build_work_item_deleted_response(&Uuid::new_v4().to_string(), item.id, user_id);
+        if let Err(e) = state
+            .registry
+            .broadcast_to_project(
+                &source.id.to_string(),
+                Message::Binary(deleted.encode_to_vec().into()),
+            )
+            .await
+        {
+            log::warn!("Failed to broadcast WorkItemDeleted via REST: {}", e);
+        }
+
+        let hierarchy = compute_hierarchy_for_item(&target_items, item.id);
+        let estimate = compute_estimate_for_item(&target_items, &logged_seconds, item.id);
+        let created = build_work_item_created_response(
+            &Uuid::new_v4().to_string(),
+            item,
+            user_id,
+            hierarchy.ancestor_ids,
+            hierarchy.descendant_ids,
+            estimate,
+        );
+        if let Err(e) = state
+            .registry
+            .broadcast_to_project(
+                &target.id.to_string(),
+                Message::Binary(created.encode_to_vec().into()),
+            )
+            .await
+        {
+            log::warn!("Failed to broadcast WorkItemCreated via REST: {}", e);
+        }
+    }
+
+    log::info!(
+        "Moved work item {} and {} descendant(s) from {} to {} via REST API",
+        root.id,
+        moved.len() - 1,
+        source.key,
+        target.key
+    );
+
+    let redirects = moved
+        .iter()
+        .map(|(current, item)| KeyRedirect {
+            from: format!("{}-{}", source.key, current.item_number),
+            to: format!("{}-{}", target.key, item.item_number),
+        })
+        .collect();
+    let (_, moved_root) = moved.swap_remove(0);
+    let response = work_item_response(&state, moved_root).await?;
+
+    Ok(Json(MoveToProjectResponse {
+        work_item: response.work_item,
+        moved: redirects,
     }))
 }
 
 /// DELETE /api/v1/work-items/:id
 ///
-/// Soft-delete a work item. Fails if work item has children.
+/// Soft-delete a work item. Fails if work item has children. Honors `If-Match`.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/work-items/{id}",
+    tag = "work-items",
+    params(("id" = String, Path, description = "Work item UUID or display key"), Preconditions),
+    responses(
+        (status = 200, description = "The work item was deleted", body = DeleteResponse),
+        (status = 400, description = "Invalid request", body = crate::ApiErrorResponse),
+        (status = 404, description = "Not found", body = crate::ApiErrorResponse),
+        (status = 412, description = "`If-Match` does not match; the current entity is returned beside `error`", body = WorkItemResponse),
+    )
+)]
 pub async fn delete_work_item(
-    State(state): State<AppState>,
+    TenantState(state): TenantState,
     UserId(user_id): UserId,
     Path(id): Path<String>,
+    preconditions: Preconditions,
 ) -> ApiResult<Json<DeleteResponse>> {
     // 1. Fetch existing work item
     let work_item = resolve_work_item(&state.pool, &id).await?;
+    if preconditions.if_match_fails(work_item.version) {
+        let version = work_item.version;
+        let current = work_item_response(&state, work_item).await?;
+        return Err(precondition_failed(version, &current));
+    }
     let work_item_id = work_item.id;
 
     // 2. Check for children
@@ -2,8 +2,9 @@ use pm_ws::AppState;
 
 use axum::{Json, extract::State, http::StatusCode};
 use serde::Serialize;
+use utoipa::ToSchema;
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct HealthResponse {
     pub status: &'static str,
     pub version: &'static str,
@@ -13,25 +14,42 @@ pub struct HealthResponse {
     pub circuit_breaker: Option<CircuitBreakerHealth>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct DatabaseHealth {
     pub status: &'static str,
     pub latency_ms: u64,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct CircuitBreakerHealth {
     pub state: String,
 }
 
 /// Liveness probe - is the process running?
 /// Used by Kubernetes/container orchestrators
+#[utoipa::path(
+    get,
+    path = "/live",
+    tag = "health",
+    responses(
+        (status = 200, description = "The process is running"),
+    )
+)]
 pub async fn liveness() -> StatusCode {
     StatusCode::OK
 }
 
 /// Readiness probe - can we serve requests?
 /// Checks database connectivity and circuit breaker state
+#[utoipa::path(
+    get,
+    path = "/ready",
+    tag = "health",
+    responses(
+        (status = 200, description = "Ready to serve requests", body = HealthResponse),
+        (status = 503, description = "Database or circuit breaker unhealthy", body = HealthResponse),
+    )
+)]
 pub async fn readiness(State(state): State<AppState>) -> (StatusCode, Json<HealthResponse>) {
     let start = std::time::Instant::now();
 
@@ -74,6 +92,14 @@ pub async fn readiness(State(state): State<AppState>) -> (StatusCode, Json<Healt
 
 /// Simple health check for load balancers
 /// Returns immediately without checking dependencies
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "health",
+    responses(
+        (status = 200, description = "Server status and version", body = HealthResponse),
+    )
+)]
 pub async fn health() -> Json<HealthResponse> {
     Json(HealthResponse {
         status: "ok",
@@ -13,6 +13,19 @@ pub use api::{
         create_comment_request::CreateCommentRequest,
         update_comment_request::UpdateCommentRequest,
     },
+    conditional::{
+        entity_tag::{entity_tag, not_modified, tagged_json},
+        preconditions::{Preconditions, precondition_failed},
+    },
+    custom_fields::{
+        create_custom_field_request::CreateCustomFieldRequest,
+        custom_field_list_response::CustomFieldListResponse,
+        custom_field_response::CustomFieldResponse,
+        custom_fields::{
+            create_custom_field, delete_custom_field, list_custom_fields, update_custom_field,
+        },
+        update_custom_field_request::UpdateCustomFieldRequest,
+    },
     delete_response::DeleteResponse,
     dependencies::{
         create_dependency_request::CreateDependencyRequest,
@@ -20,25 +33,73 @@ pub use api::{
         dependency_list_response::DependencyListResponse,
     },
     error::ApiError,
+    error::ApiErrorResponse,
     error::Result as ApiResult,
-    extractors::user_id::UserId,
+    extractors::{tenant_state::TenantState, user_id::UserId},
+    graph::{graph::get_graph, graph_query::GraphQuery},
+    idempotency::{
+        cached_response::{CachedResponse, REPLAYED_HEADER},
+        idempotency::{IDEMPOTENCY_KEY_HEADER, idempotency},
+    },
+    limits::{
+        rate_limit::{RateLimitState, rate_limit},
+        request_timeout::handle_layer_error,
+    },
+    llm_context::{
+        create_llm_context_request::CreateLlmContextRequest,
+        llm_context::{
+            create_llm_context, delete_llm_context, get_llm_context, list_llm_context,
+            update_llm_context, verify_llm_context,
+        },
+        llm_context_list_response::LlmContextListResponse,
+        llm_context_query::LlmContextQuery,
+        llm_context_response::LlmContextResponse,
+        update_llm_context_request::UpdateLlmContextRequest,
+    },
+    openapi::openapi::{ApiDoc, openapi_json},
+    pagination::{page::Page, page_query::PageQuery},
     projects::{
         create_project_request::CreateProjectRequest,
         project_list_response::ProjectListResponse,
         project_response::ProjectResponse,
-        projects::{create_project, delete_project, get_project, list_projects, update_project},
+        projects::{
+            create_project, delete_project, get_project, list_projects, rename_project_key,
+            update_project,
+        },
+        rename_project_key_request::RenameProjectKeyRequest,
         update_project_request::UpdateProjectRequest,
     },
+    query::{query::run_query, query_request::QueryRequest, query_response::QueryResponse},
     resolve::{parse_display_key, resolve_project, resolve_work_item},
+    schedule::{
+        schedule::get_schedule, schedule_query::ScheduleQuery, schedule_response::ScheduleResponse,
+    },
     sprints::{
         create_sprint_request::CreateSprintRequest,
         sprint_list_response::SprintListResponse,
         sprint_response::SprintResponse,
-        sprints::{create_sprint, delete_sprint, get_sprint, list_sprints, update_sprint},
+        sprint_summary_response::SprintSummaryResponse,
+        sprints::{
+            create_sprint, delete_sprint, get_sprint, get_sprint_summary, list_sprints,
+            update_sprint,
+        },
         update_sprint_request::UpdateSprintRequest,
     },
     swim_lanes::{swim_lane_list_response::SwimLaneListResponse, swim_lanes::list_swim_lanes},
-    sync::{export::sync_export, import::sync_import},
+    sync::{
+        export::sync_export,
+        import::sync_import,
+        peer_sync_request::PeerSyncRequest,
+        peer_sync_response::PeerSyncResponse,
+        peers::{list_sync_peers, sync_pull, sync_push},
+        sync_peer_list_response::SyncPeerListResponse,
+    },
+    templates::{
+        create_template_request::CreateTemplateRequest,
+        template_list_response::TemplateListResponse,
+        template_response::TemplateResponse,
+        templates::{create_template, delete_template, get_template, list_templates},
+    },
     time_entries::{
         create_time_entry_request::CreateTimeEntryRequest, time_entries::create_time_entry,
         time_entries::delete_time_entry, time_entries::get_time_entry,
@@ -46,14 +107,27 @@ pub use api::{
         time_entry_list_response::TimeEntryListResponse, time_entry_response::TimeEntryResponse,
         update_time_entry_request::UpdateTimeEntryRequest,
     },
+    time_reports::{
+        time_report_query::TimeReportQuery, time_report_response::TimeReportResponse,
+        time_reports::get_time_report,
+    },
+    work_item_context::{
+        work_item_context::get_work_item_context, work_item_context_query::WorkItemContextQuery,
+        work_item_context_response::WorkItemContextResponse,
+    },
     work_items::{
         create_work_item_request::CreateWorkItemRequest,
+        key_redirect::KeyRedirect,
         list_work_item_query::ListWorkItemsQuery,
+        move_to_project_request::MoveToProjectRequest,
+        move_to_project_response::MoveToProjectResponse,
+        move_work_item_request::MoveWorkItemRequest,
         update_work_item_request::UpdateWorkItemRequest,
         work_item_list_response::WorkItemListResponse,
         work_item_response::WorkItemResponse,
         work_items::{
-            create_work_item, delete_work_item, get_work_item, list_work_items, update_work_item,
+            create_work_item, delete_work_item, get_work_item, list_work_items, move_work_item,
+            move_work_item_to_project, update_work_item,
         },
     },
 };
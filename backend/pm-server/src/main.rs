@@ -16,6 +16,19 @@ pub use api::{
         create_comment_request::CreateCommentRequest,
         update_comment_request::UpdateCommentRequest,
     },
+    conditional::{
+        entity_tag::{entity_tag, not_modified, tagged_json},
+        preconditions::{Preconditions, precondition_failed},
+    },
+    custom_fields::{
+        create_custom_field_request::CreateCustomFieldRequest,
+        custom_field_list_response::CustomFieldListResponse,
+        custom_field_response::CustomFieldResponse,
+        custom_fields::{
+            create_custom_field, delete_custom_field, list_custom_fields, update_custom_field,
+        },
+        update_custom_field_request::UpdateCustomFieldRequest,
+    },
     delete_response::DeleteResponse,
     dependencies::{
         create_dependency_request::CreateDependencyRequest,
@@ -23,25 +36,73 @@ pub use api::{
         dependency_list_response::DependencyListResponse,
     },
     error::ApiError,
+    error::ApiErrorResponse,
     error::Result as ApiResult,
-    extractors::user_id::UserId,
+    extractors::{tenant_state::TenantState, user_id::UserId},
+    graph::{graph::get_graph, graph_query::GraphQuery},
+    idempotency::{
+        cached_response::{CachedResponse, REPLAYED_HEADER},
+        idempotency::{IDEMPOTENCY_KEY_HEADER, idempotency},
+    },
+    limits::{
+        rate_limit::{RateLimitState, rate_limit},
+        request_timeout::handle_layer_error,
+    },
+    llm_context::{
+        create_llm_context_request::CreateLlmContextRequest,
+        llm_context::{
+            create_llm_context, delete_llm_context, get_llm_context, list_llm_context,
+            update_llm_context, verify_llm_context,
+        },
+        llm_context_list_response::LlmContextListResponse,
+        llm_context_query::LlmContextQuery,
+        llm_context_response::LlmContextResponse,
+        update_llm_context_request::UpdateLlmContextRequest,
+    },
+    openapi::openapi::{ApiDoc, openapi_json},
+    pagination::{page::Page, page_query::PageQuery},
     projects::{
         create_project_request::CreateProjectRequest,
         project_list_response::ProjectListResponse,
         project_response::ProjectResponse,
-        projects::{create_project, delete_project, get_project, list_projects, update_project},
+        projects::{
+            create_project, delete_project, get_project, list_projects, rename_project_key,
+            update_project,
+        },
+        rename_project_key_request::RenameProjectKeyRequest,
         update_project_request::UpdateProjectRequest,
     },
+    query::{query::run_query, query_request::QueryRequest, query_response::QueryResponse},
     resolve::{parse_display_key, resolve_project, resolve_work_item},
+    schedule::{
+        schedule::get_schedule, schedule_query::ScheduleQuery, schedule_response::ScheduleResponse,
+    },
     sprints::{
         create_sprint_request::CreateSprintRequest,
         sprint_list_response::SprintListResponse,
         sprint_response::SprintResponse,
-        sprints::{create_sprint, delete_sprint, get_sprint, list_sprints, update_sprint},
+        sprint_summary_response::SprintSummaryResponse,
+        sprints::{
+            create_sprint, delete_sprint, get_sprint, get_sprint_summary, list_sprints,
+            update_sprint,
+        },
         update_sprint_request::UpdateSprintRequest,
     },
     swim_lanes::{swim_lane_list_response::SwimLaneListResponse, swim_lanes::list_swim_lanes},
-    sync::{export::sync_export, import::sync_import},
+    sync::{
+        export::sync_export,
+        import::sync_import,
+        peer_sync_request::PeerSyncRequest,
+        peer_sync_response::PeerSyncResponse,
+        peers::{list_sync_peers, sync_pull, sync_push},
+        sync_peer_list_response::SyncPeerListResponse,
+    },
+    templates::{
+        create_template_request::CreateTemplateRequest,
+        template_list_response::TemplateListResponse,
+        template_response::TemplateResponse,
+        templates::{create_template, delete_template, get_template, list_templates},
+    },
     time_entries::{
         create_time_entry_request::CreateTimeEntryRequest, time_entries::create_time_entry,
         time_entries::delete_time_entry, time_entries::get_time_entry,
@@ -49,14 +110,27 @@ pub use api::{
         time_entry_list_response::TimeEntryListResponse, time_entry_response::TimeEntryResponse,
         update_time_entry_request::UpdateTimeEntryRequest,
     },
+    time_reports::{
+        time_report_query::TimeReportQuery, time_report_response::TimeReportResponse,
+        time_reports::get_time_report,
+    },
+    work_item_context::{
+        work_item_context::get_work_item_context, work_item_context_query::WorkItemContextQuery,
+        work_item_context_response::WorkItemContextResponse,
+    },
     work_items::{
         create_work_item_request::CreateWorkItemRequest,
+        key_redirect::KeyRedirect,
         list_work_item_query::ListWorkItemsQuery,
+        move_to_project_request::MoveToProjectRequest,
+        move_to_project_response::MoveToProjectResponse,
+        move_work_item_request::MoveWorkItemRequest,
         update_work_item_request::UpdateWorkItemRequest,
         work_item_list_response::WorkItemListResponse,
         work_item_response::WorkItemResponse,
         work_items::{
-            create_work_item, delete_work_item, get_work_item, list_work_items, update_work_item,
+            create_work_item, delete_work_item, get_work_item, list_work_items, move_work_item,
+            move_work_item_to_project, update_work_item,
         },
     },
 };
@@ -64,12 +138,14 @@ pub use api::{
 pub use crate::routes::build_router;
 
 use pm_auth::{JwtValidator, RateLimiterFactory};
+use pm_db::{TenantPoolOptions, TenantPools};
 use pm_ws::{
     AppState, CircuitBreaker, CircuitBreakerConfig, ConnectionConfig, ConnectionLimits,
-    ConnectionRegistry, Metrics, ShutdownCoordinator,
+    ConnectionRegistry, Metrics, ShutdownCoordinator, TenantResolver,
 };
 
 use std::error::Error;
+use std::net::SocketAddr;
 use std::sync::Arc;
 
 use log::{error, info, warn};
@@ -116,6 +192,19 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     // Initialize database pool
     let database_path = config.database_path()?;
+
+    // A restore staged by /admin/backup/restore replaces the database before
+    // it's opened; migrations below bring it up to the current schema
+    if pm_db::BackupStore::apply_staged_restore(&database_path)? {
+        info!("Database restored from staged backup");
+    }
+
+    let backups = pm_db::BackupStore::new(
+        config.backup_dir()?,
+        database_path.clone(),
+        config.backup.retention_count,
+    );
+
     info!("Connecting to database: {}", database_path.display());
 
     let pool = SqlitePoolOptions::new()
@@ -140,6 +229,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
     info!("Migrations complete");
 
     ensure_llm_user(&pool, &config).await;
+    verify_llm_example_sql(&pool).await;
 
     // Create circuit breaker
     let circuit_breaker = Arc::new(CircuitBreaker::new(CircuitBreakerConfig::default()));
@@ -177,11 +267,14 @@ async fn main() -> Result<(), Box<dyn Error>> {
     let rate_limiter_factory = RateLimiterFactory::new(pm_auth::RateLimitConfig {
         max_requests: config.rate_limit.max_requests,
         window_secs: config.rate_limit.window_secs,
+        api_requests_per_user: config.rate_limit.api_requests_per_user,
+        api_requests_per_ip: config.rate_limit.api_requests_per_ip,
     });
 
     // Create connection registry with limits
     let registry = ConnectionRegistry::new(ConnectionLimits {
         max_total: config.server.max_connections,
+        max_per_tenant: config.tenant.max_ws_connections_per_tenant,
     });
     let registry_for_idle = registry.clone();
 
@@ -198,6 +291,27 @@ async fn main() -> Result<(), Box<dyn Error>> {
         heartbeat_timeout_secs: config.websocket.heartbeat_timeout_secs,
     };
 
+    // Per-tenant databases (multi-tenant hosting, ADR-0002)
+    let tenants = if config.tenant.enabled {
+        let tenant_dir = config.tenant_dir()?;
+        info!("Tenant routing enabled: {}", tenant_dir.display());
+        let pools = TenantPools::new(TenantPoolOptions {
+            root: tenant_dir,
+            create_missing: config.tenant.create_missing,
+            max_open_pools: config.tenant.max_open_pools,
+            idle_timeout: (config.tenant.idle_timeout_secs > 0)
+                .then(|| std::time::Duration::from_secs(config.tenant.idle_timeout_secs)),
+            max_connections: config.tenant.max_connections_per_tenant,
+            system_user: Some((
+                config.api.llm_user_id.clone(),
+                config.api.llm_user_name.clone(),
+            )),
+        });
+        Some(TenantResolver::new(config.tenant.source, pools))
+    } else {
+        None
+    };
+
     // Build application state
     let app_state = AppState {
         pool,
@@ -211,8 +325,12 @@ async fn main() -> Result<(), Box<dyn Error>> {
         config: connection_config,
         api_config: config.api.clone(),
         validation: config.validation,
+        backups: Some(backups.clone()),
+        tenants: tenants.clone(),
     };
 
+    let pool_for_backups = app_state.pool.clone();
+
     // Build router
     let app = build_router(app_state);
 
@@ -247,6 +365,59 @@ async fn main() -> Result<(), Box<dyn Error>> {
         }
     });
 
+    // Scheduled backups (when configured)
+    if config.backup.interval_hours > 0 {
+        let interval = std::time::Duration::from_secs(config.backup.interval_hours * 3600);
+        let mut shutdown_monitor = shutdown.subscribe_guard();
+
+        info!(
+            "Scheduled backups enabled: every {}h",
+            config.backup.interval_hours
+        );
+
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = tokio::time::sleep(interval) => {
+                        match backups.create(&pool_for_backups).await {
+                            Ok(backup) => info!("Scheduled backup written: {}", backup.name),
+                            Err(e) => error!("Scheduled backup failed: {}", e),
+                        }
+                    },
+                    _ = shutdown_monitor.wait() => {
+                        info!("Backup scheduler: shutdown signal received");
+                        return;
+                    }
+                }
+            }
+        });
+    }
+
+    // Close tenant pools that have gone idle
+    if let Some(tenants) = tenants
+        && config.tenant.idle_timeout_secs > 0
+    {
+        let check_interval =
+            std::time::Duration::from_secs((config.tenant.idle_timeout_secs / 2).max(1));
+        let mut shutdown_monitor = shutdown.subscribe_guard();
+
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = tokio::time::sleep(check_interval) => {
+                        for tenant_id in tenants.pools.evict_idle().await {
+                            info!("Closed idle pool for tenant {}", tenant_id);
+                        }
+                    },
+                    _ = shutdown_monitor.wait() => {
+                        info!("Tenant pool monitor: shutdown signal received");
+                        return;
+                    }
+                }
+            }
+        });
+    }
+
     // Idle shutdown monitoring (when configured)
     if config.server.idle_shutdown_secs > 0 {
         let idle_timeout = config.server.idle_shutdown_secs;
@@ -309,12 +480,16 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     // Start server with graceful shutdown
     info!("Server ready to accept connections");
-    axum::serve(listener, app)
-        .with_graceful_shutdown(async move {
-            shutdown.subscribe_guard().wait().await;
-            info!("Graceful shutdown complete");
-        })
-        .await?;
+    // Connect info gives the rate limiter the client IP
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(async move {
+        shutdown.subscribe_guard().wait().await;
+        info!("Graceful shutdown complete");
+    })
+    .await?;
 
     // Clean up port discovery file
     if let Err(e) = pm_config::PortFileInfo::remove() {
@@ -324,6 +499,34 @@ async fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// Check that every LLM context example query still prepares against the
+/// migrated schema. Broken examples mislead agents but don't stop startup.
+async fn verify_llm_example_sql(pool: &sqlx::SqlitePool) {
+    match pm_db::LlmContextRepository::verify_example_sql(pool).await {
+        Ok(report) if report.is_ok() => {
+            log::info!("LLM example SQL: {} queries verified", report.checked);
+        }
+        Ok(report) => {
+            for problem in &report.problems {
+                log::warn!(
+                    "LLM example SQL broken in \"{}\" ({}): {}",
+                    problem.title,
+                    problem.entry_id,
+                    problem.error
+                );
+            }
+            log::warn!(
+                "LLM example SQL: {} of {} queries are broken; run `pm llm-context verify`",
+                report.problems.len(),
+                report.checked
+            );
+        }
+        Err(e) => {
+            log::warn!("Failed to verify LLM example SQL: {}", e);
+        }
+    }
+}
+
 /// Ensure the LLM user exists in the database
 async fn ensure_llm_user(pool: &sqlx::SqlitePool, config: &pm_config::Config) {
     let llm_user_id = &config.api.llm_user_id;
@@ -1,22 +1,41 @@
 use crate::{
-    admin, create_comment, create_dependency, create_project, create_sprint, create_time_entry,
-    create_work_item, delete_comment, delete_dependency, delete_project, delete_sprint,
-    delete_time_entry, delete_work_item, get_project, get_sprint, get_time_entry, get_work_item,
-    health, list_comments, list_dependencies, list_projects, list_sprints, list_swim_lanes,
-    list_time_entries, list_work_items, sync_export, sync_import, update_comment, update_project,
-    update_sprint, update_time_entry, update_work_item,
+    RateLimitState, admin, create_comment, create_custom_field, create_dependency,
+    create_llm_context, create_project, create_sprint, create_template, create_time_entry,
+    create_work_item, delete_comment, delete_custom_field, delete_dependency, delete_llm_context,
+    delete_project, delete_sprint, delete_template, delete_time_entry, delete_work_item, get_graph,
+    get_llm_context, get_project, get_schedule, get_sprint, get_sprint_summary, get_template,
+    get_time_entry, get_time_report, get_work_item, get_work_item_context, handle_layer_error,
+    health, idempotency, list_comments, list_custom_fields, list_dependencies, list_llm_context,
+    list_projects, list_sprints, list_swim_lanes, list_sync_peers, list_templates,
+    list_time_entries, list_work_items, move_work_item, move_work_item_to_project, openapi_json,
+    rate_limit, rename_project_key, run_query, sync_export, sync_import, sync_pull, sync_push,
+    update_comment, update_custom_field, update_llm_context, update_project, update_sprint,
+    update_time_entry, update_work_item, verify_llm_context,
 };
 
 use pm_ws::AppState;
 
+use std::{sync::Arc, time::Duration};
+
 use axum::{
     Router,
+    error_handling::HandleErrorLayer,
+    extract::DefaultBodyLimit,
+    http::header,
+    middleware,
     routing::{delete, get, post, put},
 };
+use tower::ServiceBuilder;
 use tower_http::cors::{Any, CorsLayer};
 
 /// Build the application router with all endpoints
 pub fn build_router(state: AppState) -> Router {
+    let limits = &state.api_config;
+    let rate_limit_state = RateLimitState {
+        app: state.clone(),
+        limiter: Arc::new(state.rate_limiter_factory.create_api_limiter()),
+    };
+
     Router::new()
         // WebSocket endpoint
         .route("/ws", get(pm_ws::handler))
@@ -26,19 +45,26 @@ pub fn build_router(state: AppState) -> Router {
         .route("/ready", get(health::readiness))
         // Admin endpoints
         .route("/admin/checkpoint", post(admin::checkpoint_handler))
+        .route("/admin/backup", post(admin::backup_handler))
+        .route("/admin/backups", get(admin::list_backups_handler))
+        .route("/admin/backup/restore", post(admin::restore_backup_handler))
         .route("/admin/shutdown", post(admin::shutdown_handler))
+        // REST API v1 - OpenAPI document
+        .route("/api/v1/openapi.json", get(openapi_json))
         // REST API v1 - Projects
         .route("/api/v1/projects", get(list_projects))
         .route("/api/v1/projects", post(create_project))
         .route("/api/v1/projects/{id}", get(get_project))
         .route("/api/v1/projects/{id}", put(update_project))
         .route("/api/v1/projects/{id}", delete(delete_project))
+        .route("/api/v1/projects/{id}/key", put(rename_project_key))
         // REST API v1 - Sprints
         .route("/api/v1/projects/{project_id}/sprints", get(list_sprints))
         .route("/api/v1/sprints", post(create_sprint))
         .route("/api/v1/sprints/{id}", get(get_sprint))
         .route("/api/v1/sprints/{id}", put(update_sprint))
         .route("/api/v1/sprints/{id}", delete(delete_sprint))
+        .route("/api/v1/sprints/{id}/summary", get(get_sprint_summary))
         // REST API v1 - Work Items
         .route(
             "/api/v1/projects/{project_id}/work-items",
@@ -48,6 +74,15 @@ pub fn build_router(state: AppState) -> Router {
         .route("/api/v1/work-items/{id}", get(get_work_item))
         .route("/api/v1/work-items/{id}", put(update_work_item))
         .route("/api/v1/work-items/{id}", delete(delete_work_item))
+        .route("/api/v1/work-items/{id}/move", post(move_work_item))
+        .route(
+            "/api/v1/work-items/{id}/move-to-project",
+            post(move_work_item_to_project),
+        )
+        .route(
+            "/api/v1/work-items/{id}/context",
+            get(get_work_item_context),
+        )
         // REST API v1 - Comments
         .route(
             "/api/v1/work-items/{work_item_id}/comments",
@@ -80,9 +115,56 @@ pub fn build_router(state: AppState) -> Router {
         .route("/api/v1/time-entries/{id}", get(get_time_entry))
         .route("/api/v1/time-entries/{id}", put(update_time_entry))
         .route("/api/v1/time-entries/{id}", delete(delete_time_entry))
+        // REST API v1 - Time Reports
+        .route("/api/v1/time-reports", get(get_time_report))
+        .route("/api/v1/schedule", get(get_schedule))
+        .route("/api/v1/graph", get(get_graph))
+        // REST API v1 - LLM Context
+        .route("/api/v1/llm-context", get(list_llm_context))
+        .route("/api/v1/llm-context", post(create_llm_context))
+        .route("/api/v1/llm-context/verify", get(verify_llm_context))
+        .route("/api/v1/llm-context/{id}", get(get_llm_context))
+        .route("/api/v1/llm-context/{id}", put(update_llm_context))
+        .route("/api/v1/llm-context/{id}", delete(delete_llm_context))
+        // REST API v1 - Read-only SQL
+        .route("/api/v1/query", post(run_query))
+        // REST API v1 - Custom Fields
+        .route(
+            "/api/v1/projects/{project_id}/custom-fields",
+            get(list_custom_fields),
+        )
+        .route(
+            "/api/v1/projects/{project_id}/custom-fields",
+            post(create_custom_field),
+        )
+        .route("/api/v1/custom-fields/{id}", put(update_custom_field))
+        .route("/api/v1/custom-fields/{id}", delete(delete_custom_field))
         // REST API v1 - Sync (bulk export/import)
         .route("/api/v1/sync/export", get(sync_export))
-        .route("/api/v1/sync/import", post(sync_import))
+        .route(
+            "/api/v1/sync/import",
+            post(sync_import).layer(DefaultBodyLimit::max(limits.max_import_bytes)),
+        )
+        .route("/api/v1/sync/pull", post(sync_pull))
+        .route("/api/v1/sync/push", post(sync_push))
+        .route("/api/v1/sync/peers", get(list_sync_peers))
+        // REST API v1 - Project templates
+        .route("/api/v1/templates", get(list_templates))
+        .route("/api/v1/templates", post(create_template))
+        .route("/api/v1/templates/{id}", get(get_template))
+        .route("/api/v1/templates/{id}", delete(delete_template))
+        // Replay retried mutations that carry an Idempotency-Key
+        .route_layer(middleware::from_fn_with_state(state.clone(), idempotency))
+        .route_layer(DefaultBodyLimit::max(limits.max_body_bytes))
+        // Per-user and per-IP token buckets (429 + Retry-After)
+        .route_layer(middleware::from_fn_with_state(rate_limit_state, rate_limit))
+        // Bound how long and how many requests run at once
+        .route_layer(
+            ServiceBuilder::new()
+                .layer(HandleErrorLayer::new(handle_layer_error))
+                .timeout(Duration::from_secs(limits.request_timeout_secs))
+                .concurrency_limit(limits.max_concurrent_requests),
+        )
         // Add shared state
         .with_state(state)
         // CORS middleware (allow all origins for WebSocket)
@@ -90,6 +172,7 @@ pub fn build_router(state: AppState) -> Router {
             CorsLayer::new()
                 .allow_origin(Any)
                 .allow_methods(Any)
-                .allow_headers(Any),
+                .allow_headers(Any)
+                .expose_headers([header::ETAG, header::RETRY_AFTER]),
         )
 }
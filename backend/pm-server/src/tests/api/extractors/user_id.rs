@@ -32,13 +32,16 @@ async fn create_test_state() -> AppState {
         rate_limiter_factory: RateLimiterFactory::new(pm_auth::RateLimitConfig {
             max_requests: 100,
             window_secs: 60,
+            ..Default::default()
         }),
-        registry: ConnectionRegistry::new(pm_ws::ConnectionLimits { max_total: 10000 }),
+        registry: ConnectionRegistry::new(pm_ws::ConnectionLimits::default()),
         metrics: Metrics::new(),
         shutdown,
         config: ConnectionConfig::default(),
         api_config: ApiConfig::default(),
         validation: pm_config::ValidationConfig::default(),
+        backups: None,
+        tenants: None,
     }
 }
 
@@ -107,6 +110,7 @@ async fn test_extractor_preserves_custom_llm_user_id() {
         enabled: true,
         llm_user_id: custom_llm_id.to_string(),
         llm_user_name: "Custom LLM".to_string(),
+        ..Default::default()
     };
 
     let state = AppState {
@@ -117,13 +121,16 @@ async fn test_extractor_preserves_custom_llm_user_id() {
         rate_limiter_factory: RateLimiterFactory::new(pm_auth::RateLimitConfig {
             max_requests: 100,
             window_secs: 60,
+            ..Default::default()
         }),
-        registry: ConnectionRegistry::new(pm_ws::ConnectionLimits { max_total: 10000 }),
+        registry: ConnectionRegistry::new(pm_ws::ConnectionLimits::default()),
         metrics: Metrics::new(),
         shutdown,
         config: ConnectionConfig::default(),
         api_config: custom_config,
         validation: pm_config::ValidationConfig::default(),
+        backups: None,
+        tenants: None,
     };
 
     let request = Request::builder().body(Body::empty()).unwrap();
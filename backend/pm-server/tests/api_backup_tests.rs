@@ -0,0 +1,142 @@
+mod common;
+
+use crate::common::{create_test_app_state, create_test_user};
+
+use pm_db::BackupStore;
+use pm_server::routes::build_router;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use sqlx::SqlitePool;
+use sqlx::sqlite::SqliteConnectOptions;
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+const USER_ID: &str = "00000000-0000-0000-0000-000000000001";
+
+async fn send(
+    state: &pm_ws::AppState,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let app = build_router(state.clone());
+    let builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Content-Type", "application/json");
+    let request = match body {
+        Some(b) => builder.body(Body::from(b.to_string())).unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    };
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+/// App state on a database file in a temp directory, backed up next to it
+/// and keeping two backups. `VACUUM INTO` needs a file-backed database.
+async fn setup() -> (TempDir, pm_ws::AppState) {
+    let temp = TempDir::new().unwrap();
+    let database_path = temp.path().join("data.db");
+    let mut state = create_test_app_state().await;
+    state.pool = SqlitePool::connect_with(
+        SqliteConnectOptions::new()
+            .filename(&database_path)
+            .create_if_missing(true),
+    )
+    .await
+    .expect("Failed to open database");
+    sqlx::migrate!("../crates/pm-db/migrations")
+        .run(&state.pool)
+        .await
+        .expect("Failed to run migrations");
+    create_test_user(&state.pool, USER_ID).await;
+    state.backups = Some(BackupStore::new(
+        temp.path().join("backups"),
+        database_path,
+        2,
+    ));
+    (temp, state)
+}
+
+#[tokio::test]
+async fn test_backup_is_created_and_listed() {
+    let (_temp, state) = setup().await;
+
+    let (status, created) = send(&state, "POST", "/admin/backup", None).await;
+    assert_eq!(status, StatusCode::OK, "{}", created);
+    assert!(created["size_bytes"].as_u64().unwrap() > 0);
+
+    let (status, listed) = send(&state, "GET", "/admin/backups", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(listed["backups"], json!([created]));
+}
+
+#[tokio::test]
+async fn test_backups_beyond_retention_are_pruned() {
+    let (_temp, state) = setup().await;
+
+    for _ in 0..3 {
+        let (status, json) = send(&state, "POST", "/admin/backup", None).await;
+        assert_eq!(status, StatusCode::OK, "{}", json);
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    }
+
+    let (_, listed) = send(&state, "GET", "/admin/backups", None).await;
+    assert_eq!(listed["backups"].as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_restore_stages_backup_and_shuts_down() {
+    let (temp, state) = setup().await;
+    let (_, created) = send(&state, "POST", "/admin/backup", None).await;
+    let mut shutdown = state.shutdown.subscribe();
+
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/admin/backup/restore",
+        Some(json!({ "name": created["name"] })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::ACCEPTED, "{}", json);
+    assert_eq!(json["backup"]["name"], created["name"]);
+    assert!(BackupStore::staged_restore_path(&temp.path().join("data.db")).exists());
+    assert!(shutdown.try_recv().is_ok());
+}
+
+#[tokio::test]
+async fn test_restore_unknown_backup_returns_404() {
+    let (_temp, state) = setup().await;
+    let mut shutdown = state.shutdown.subscribe();
+
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/admin/backup/restore",
+        Some(json!({ "name": "../data.db" })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(json["error"]["code"], "NOT_FOUND");
+    assert!(shutdown.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_backup_without_store_returns_400() {
+    let state = create_test_app_state().await;
+
+    let (status, json) = send(&state, "POST", "/admin/backup", None).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"]["code"], "BAD_REQUEST");
+}
@@ -0,0 +1,193 @@
+mod common;
+
+use crate::common::{create_test_app_state, create_test_project, create_test_user};
+
+use pm_server::routes::build_router;
+
+use axum::{
+    body::Body,
+    http::{HeaderMap, Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+const USER_ID: &str = "00000000-0000-0000-0000-000000000001";
+
+/// Send a request with extra headers. Empty bodies (304) come back as Null.
+async fn send(
+    state: &pm_ws::AppState,
+    method: &str,
+    uri: &str,
+    headers: &[(&str, &str)],
+    body: Option<Value>,
+) -> (StatusCode, HeaderMap, Value) {
+    let app = build_router(state.clone());
+    let mut builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .header("X-User-Id", USER_ID);
+    for (name, value) in headers {
+        builder = builder.header(*name, *value);
+    }
+    let request = match body {
+        Some(b) => builder.body(Body::from(b.to_string())).unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    };
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let headers = response.headers().clone();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let json = if bytes.is_empty() {
+        Value::Null
+    } else {
+        serde_json::from_slice(&bytes).unwrap()
+    };
+    (status, headers, json)
+}
+
+fn etag(headers: &HeaderMap) -> &str {
+    headers.get("etag").unwrap().to_str().unwrap()
+}
+
+async fn setup() -> (pm_ws::AppState, Uuid) {
+    let state = create_test_app_state().await;
+    create_test_user(&state.pool, USER_ID).await;
+    let project_id = create_test_project(&state.pool, USER_ID).await;
+    (state, project_id)
+}
+
+async fn create_item(state: &pm_ws::AppState, project_id: Uuid) -> String {
+    let (status, _, json) = send(
+        state,
+        "POST",
+        "/api/v1/work-items",
+        &[],
+        Some(json!({
+            "project_id": project_id.to_string(),
+            "item_type": "story",
+            "title": "Checkout",
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    json["work_item"]["id"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_get_returns_etag_and_honors_if_none_match() {
+    let (state, project_id) = setup().await;
+    let uri = format!("/api/v1/projects/{}", project_id);
+
+    let (status, headers, json) = send(&state, "GET", &uri, &[], None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(etag(&headers), "\"1\"");
+    assert_eq!(json["project"]["key"], "TEST");
+
+    let (status, headers, json) =
+        send(&state, "GET", &uri, &[("If-None-Match", "\"1\"")], None).await;
+    assert_eq!(status, StatusCode::NOT_MODIFIED);
+    assert_eq!(etag(&headers), "\"1\"");
+    assert!(json.is_null());
+
+    // A stale tag gets the full entity
+    let (status, _, _) = send(&state, "GET", &uri, &[("If-None-Match", "\"0\"")], None).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_put_with_if_match_replaces_expected_version() {
+    let (state, project_id) = setup().await;
+    let id = create_item(&state, project_id).await;
+    let uri = format!("/api/v1/work-items/{}", id);
+
+    let (status, headers, json) = send(
+        &state,
+        "PUT",
+        &uri,
+        &[("If-Match", "\"1\"")],
+        Some(json!({ "title": "Checkout v2" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    assert_eq!(etag(&headers), "\"2\"");
+    assert_eq!(json["work_item"]["version"], 2);
+
+    // The old tag no longer matches: 412 with the current entity
+    let (status, headers, json) = send(
+        &state,
+        "PUT",
+        &uri,
+        &[("If-Match", "\"1\"")],
+        Some(json!({ "title": "Lost update" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+    assert_eq!(etag(&headers), "\"2\"");
+    assert_eq!(json["error"]["code"], "PRECONDITION_FAILED");
+    assert_eq!(json["work_item"]["title"], "Checkout v2");
+    assert_eq!(json["work_item"]["display_key"], "TEST-1");
+
+    // Wildcard matches any version
+    let (status, _, json) = send(
+        &state,
+        "PUT",
+        &uri,
+        &[("If-Match", "*")],
+        Some(json!({ "title": "Checkout v3" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+}
+
+#[tokio::test]
+async fn test_put_without_precondition_is_rejected() {
+    let (state, project_id) = setup().await;
+    let uri = format!("/api/v1/projects/{}", project_id);
+
+    let (status, _, json) = send(&state, "PUT", &uri, &[], Some(json!({ "title": "X" }))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"]["field"], "expected_version");
+
+    // The body version check still answers 409, as over WebSocket
+    let (status, _, json) = send(
+        &state,
+        "PUT",
+        &uri,
+        &[],
+        Some(json!({ "title": "X", "expected_version": 7 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(json["error"]["code"], "CONFLICT");
+}
+
+#[tokio::test]
+async fn test_delete_honors_if_match() {
+    let (state, project_id) = setup().await;
+    let (status, _, json) = send(
+        &state,
+        "POST",
+        "/api/v1/sprints",
+        &[],
+        Some(json!({
+            "project_id": project_id.to_string(),
+            "name": "Sprint 1",
+            "start_date": 1_767_225_600,
+            "end_date": 1_768_435_200,
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    let uri = format!("/api/v1/sprints/{}", json["sprint"]["id"].as_str().unwrap());
+
+    let (status, _, json) = send(&state, "DELETE", &uri, &[("If-Match", "\"5\"")], None).await;
+    assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+    assert_eq!(json["sprint"]["name"], "Sprint 1");
+
+    let (status, _, json) = send(&state, "DELETE", &uri, &[("If-Match", "\"1\"")], None).await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+}
@@ -0,0 +1,341 @@
+mod common;
+
+use crate::common::{create_test_app_state, create_test_project, create_test_user};
+
+use pm_db::ActivityLogRepository;
+use pm_server::routes::build_router;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+const USER_ID: &str = "00000000-0000-0000-0000-000000000001";
+
+async fn send(
+    state: &pm_ws::AppState,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let app = build_router(state.clone());
+    let builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .header("X-User-Id", USER_ID);
+    let request = match body {
+        Some(b) => builder.body(Body::from(b.to_string())).unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    };
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+/// Create a project with a required `severity` select field (default "low")
+async fn setup_project_with_severity() -> (pm_ws::AppState, Uuid) {
+    let state = create_test_app_state().await;
+    create_test_user(&state.pool, USER_ID).await;
+    let project_id = create_test_project(&state.pool, USER_ID).await;
+
+    let (status, json) = send(
+        &state,
+        "POST",
+        &format!("/api/v1/projects/{}/custom-fields", project_id),
+        Some(json!({
+            "name": "severity",
+            "field_type": "single_select",
+            "options": ["low", "high"],
+            "required": true,
+            "default_value": "low",
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+
+    (state, project_id)
+}
+
+#[tokio::test]
+async fn test_create_and_list_custom_fields() {
+    let (state, _project_id) = setup_project_with_severity().await;
+
+    let (status, json) = send(&state, "GET", "/api/v1/projects/TEST/custom-fields", None).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let fields = json["custom_fields"].as_array().unwrap();
+    assert_eq!(fields.len(), 1);
+    assert_eq!(fields[0]["name"], "severity");
+    assert_eq!(fields[0]["field_type"], "single_select");
+    assert_eq!(fields[0]["label"], "severity");
+    assert_eq!(fields[0]["default_value"], "low");
+}
+
+#[tokio::test]
+async fn test_create_custom_field_duplicate_name_rejected() {
+    let (state, project_id) = setup_project_with_severity().await;
+
+    let (status, json) = send(
+        &state,
+        "POST",
+        &format!("/api/v1/projects/{}/custom-fields", project_id),
+        Some(json!({ "name": "severity", "field_type": "text" })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"]["code"], "VALIDATION_ERROR");
+    assert_eq!(json["error"]["field"], "name");
+}
+
+#[tokio::test]
+async fn test_create_work_item_applies_custom_field_default() {
+    let (state, project_id) = setup_project_with_severity().await;
+
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items",
+        Some(json!({
+            "project_id": project_id.to_string(),
+            "item_type": "task",
+            "title": "Defaulted",
+        })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["work_item"]["custom_fields"]["severity"], "low");
+}
+
+#[tokio::test]
+async fn test_create_work_item_invalid_custom_field_value_rejected() {
+    let (state, project_id) = setup_project_with_severity().await;
+
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items",
+        Some(json!({
+            "project_id": project_id.to_string(),
+            "item_type": "task",
+            "title": "Bad value",
+            "custom_fields": { "severity": "urgent" },
+        })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"]["field"], "custom_fields.severity");
+}
+
+#[tokio::test]
+async fn test_create_work_item_unknown_custom_field_rejected() {
+    let (state, project_id) = setup_project_with_severity().await;
+
+    let (status, _json) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items",
+        Some(json!({
+            "project_id": project_id.to_string(),
+            "item_type": "task",
+            "title": "Unknown field",
+            "custom_fields": { "nope": "x" },
+        })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_list_work_items_filters_by_custom_field() {
+    let (state, project_id) = setup_project_with_severity().await;
+
+    for (title, severity) in [("Low one", "low"), ("High one", "high")] {
+        let (status, _) = send(
+            &state,
+            "POST",
+            "/api/v1/work-items",
+            Some(json!({
+                "project_id": project_id.to_string(),
+                "item_type": "task",
+                "title": title,
+                "custom_fields": { "severity": severity },
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    let (status, json) = send(
+        &state,
+        "GET",
+        "/api/v1/projects/TEST/work-items?custom_field=severity:high",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let items = json["work_items"].as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["title"], "High one");
+
+    let (status, _) = send(
+        &state,
+        "GET",
+        "/api/v1/projects/TEST/work-items?custom_field=missing:x",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_update_work_item_custom_field_records_activity() {
+    let (state, project_id) = setup_project_with_severity().await;
+
+    let (_, created) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items",
+        Some(json!({
+            "project_id": project_id.to_string(),
+            "item_type": "task",
+            "title": "Escalate me",
+        })),
+    )
+    .await;
+    let id = created["work_item"]["id"].as_str().unwrap().to_string();
+
+    let (status, json) = send(
+        &state,
+        "PUT",
+        &format!("/api/v1/work-items/{}", id),
+        Some(json!({
+            "custom_fields": { "severity": "high" },
+            "expected_version": 1,
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["work_item"]["custom_fields"]["severity"], "high");
+
+    // Required field cannot be cleared
+    let (status, _) = send(
+        &state,
+        "PUT",
+        &format!("/api/v1/work-items/{}", id),
+        Some(json!({
+            "custom_fields": { "severity": null },
+            "expected_version": 2,
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let logs = ActivityLogRepository::find_by_entity(
+        &state.pool,
+        "work_item",
+        Uuid::parse_str(&id).unwrap(),
+    )
+    .await
+    .unwrap();
+    let update = logs.iter().find(|l| l.action == "updated").unwrap();
+    assert_eq!(update.comment.as_deref(), Some("1 fields changed"));
+}
+
+#[tokio::test]
+async fn test_update_and_delete_custom_field() {
+    let (state, _project_id) = setup_project_with_severity().await;
+
+    let (_, list) = send(&state, "GET", "/api/v1/projects/TEST/custom-fields", None).await;
+    let id = list["custom_fields"][0]["id"].as_str().unwrap().to_string();
+
+    // Stale version is rejected
+    let (status, _) = send(
+        &state,
+        "PUT",
+        &format!("/api/v1/custom-fields/{}", id),
+        Some(json!({ "label": "Severity", "expected_version": 7 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, json) = send(
+        &state,
+        "PUT",
+        &format!("/api/v1/custom-fields/{}", id),
+        Some(json!({ "label": "Severity", "clear_default": true, "expected_version": 1 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["custom_field"]["label"], "Severity");
+    assert_eq!(json["custom_field"]["version"], 2);
+    assert!(json["custom_field"]["default_value"].is_null());
+
+    let (status, _) = send(
+        &state,
+        "DELETE",
+        &format!("/api/v1/custom-fields/{}", id),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, list) = send(&state, "GET", "/api/v1/projects/TEST/custom-fields", None).await;
+    assert_eq!(list["custom_fields"].as_array().unwrap().len(), 0);
+}
+
+#[tokio::test]
+async fn test_user_custom_field_must_name_a_project_member() {
+    let (state, project_id) = setup_project_with_severity().await;
+    let outsider = "00000000-0000-0000-0000-000000000002";
+    create_test_user(&state.pool, outsider).await;
+    sqlx::query(
+        "INSERT INTO pm_project_members (id, project_id, user_id, role, created_at) VALUES (?, ?, ?, 'editor', 0)",
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(project_id.to_string())
+    .bind(USER_ID)
+    .execute(&state.pool)
+    .await
+    .unwrap();
+    let (status, json) = send(
+        &state,
+        "POST",
+        &format!("/api/v1/projects/{}/custom-fields", project_id),
+        Some(json!({ "name": "reviewer", "field_type": "user" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+
+    let create = |reviewer: &str| {
+        send(
+            &state,
+            "POST",
+            "/api/v1/work-items",
+            Some(json!({
+                "project_id": project_id.to_string(),
+                "item_type": "task",
+                "title": "Reviewed",
+                "custom_fields": { "reviewer": reviewer },
+            })),
+        )
+    };
+
+    let (status, json) = create(USER_ID).await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    assert_eq!(json["work_item"]["custom_fields"]["reviewer"], USER_ID);
+
+    let (status, json) = create(outsider).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"]["field"], "custom_fields.reviewer");
+}
@@ -0,0 +1,214 @@
+mod common;
+
+use crate::common::{create_test_app_state, create_test_project, create_test_user};
+
+use pm_server::routes::build_router;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+const USER_ID: &str = "00000000-0000-0000-0000-000000000001";
+
+async fn send(
+    state: &pm_ws::AppState,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let app = build_router(state.clone());
+    let builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .header("X-User-Id", USER_ID);
+    let request = match body {
+        Some(b) => builder.body(Body::from(b.to_string())).unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    };
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+async fn get_text(state: &pm_ws::AppState, uri: &str) -> (StatusCode, String, String) {
+    let app = build_router(state.clone());
+    let request = Request::builder()
+        .method("GET")
+        .uri(uri)
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get("content-type")
+        .map(|v| v.to_str().unwrap().to_string())
+        .unwrap_or_default();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (
+        status,
+        content_type,
+        String::from_utf8(bytes.to_vec()).unwrap(),
+    )
+}
+
+async fn create_item(
+    state: &pm_ws::AppState,
+    project_id: Uuid,
+    item_type: &str,
+    parent_id: Option<&str>,
+    title: &str,
+) -> String {
+    let (status, json) = send(
+        state,
+        "POST",
+        "/api/v1/work-items",
+        Some(json!({
+            "project_id": project_id.to_string(),
+            "item_type": item_type,
+            "title": title,
+            "parent_id": parent_id,
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    json["work_item"]["id"].as_str().unwrap().to_string()
+}
+
+async fn depend(state: &pm_ws::AppState, blocking: &str, blocked: &str, dependency_type: &str) {
+    let (status, json) = send(
+        state,
+        "POST",
+        "/api/v1/dependencies",
+        Some(json!({
+            "blocking_item_id": blocking,
+            "blocked_item_id": blocked,
+            "dependency_type": dependency_type,
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+}
+
+/// TEST-1 epic > TEST-2 story > TEST-3 task, plus a separate story TEST-4
+/// that blocks TEST-3 and relates to TEST-2
+async fn setup() -> pm_ws::AppState {
+    let state = create_test_app_state().await;
+    create_test_user(&state.pool, USER_ID).await;
+    let project_id = create_test_project(&state.pool, USER_ID).await;
+
+    let epic = create_item(&state, project_id, "epic", None, "Checkout").await;
+    let story = create_item(&state, project_id, "story", Some(&epic), "Pay \"now\"").await;
+    let task = create_item(&state, project_id, "task", Some(&story), "Card form").await;
+    let other = create_item(&state, project_id, "story", None, "Payments API").await;
+    depend(&state, &other, &task, "blocks").await;
+    depend(&state, &other, &story, "relates_to").await;
+
+    state
+}
+
+#[tokio::test]
+async fn test_graph_project_mermaid() {
+    // Given
+    let state = setup().await;
+
+    // When
+    let (status, content_type, text) = get_text(&state, "/api/v1/graph?project_id=TEST").await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK, "{}", text);
+    assert!(content_type.starts_with("text/plain"));
+    assert!(text.starts_with("graph TD\n"));
+    assert!(text.contains("TEST_1[\"TEST-1: Checkout<br/>epic · backlog\"]"));
+    assert!(text.contains("TEST_2[\"TEST-2: Pay #quot;now#quot;<br/>story · backlog\"]"));
+    assert!(text.contains("    TEST_1 --> TEST_2\n"));
+    assert!(text.contains("    TEST_2 --> TEST_3\n"));
+    assert!(text.contains("    TEST_4 -. blocks .-> TEST_3\n"));
+    assert!(text.contains("    TEST_4 -. relates_to .- TEST_2\n"));
+    assert!(!text.contains("style "));
+}
+
+#[tokio::test]
+async fn test_graph_root_depth_and_external_nodes() {
+    // Given
+    let state = setup().await;
+
+    // When: the epic and its direct children only, as DOT
+    let (status, content_type, text) = get_text(
+        &state,
+        "/api/v1/graph?root=TEST-1&depth=1&format=dot&dependency_type=relates_to",
+    )
+    .await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK, "{}", text);
+    assert!(content_type.starts_with("text/vnd.graphviz"));
+    assert!(text.contains("\"TEST-1\" -> \"TEST-2\";"));
+    assert!(!text.contains("\"TEST-3\""));
+    assert!(!text.contains("label=\"blocks\""));
+    // TEST-4 is outside the subtree but relates to TEST-2
+    assert!(
+        text.contains(
+            "\"TEST-4\" [label=\"TEST-4: Payments API\\nstory · backlog\", style=dashed];"
+        )
+    );
+    assert!(
+        text.contains("\"TEST-4\" -> \"TEST-2\" [style=dotted, dir=none, label=\"relates_to\"];")
+    );
+}
+
+#[tokio::test]
+async fn test_graph_without_dependencies() {
+    // Given
+    let state = setup().await;
+
+    // When
+    let (status, _, text) =
+        get_text(&state, "/api/v1/graph?root=TEST-2&dependency_type=none").await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK, "{}", text);
+    assert!(text.contains("    TEST_2 --> TEST_3\n"));
+    assert!(!text.contains("TEST_1"));
+    assert!(!text.contains("TEST_4"));
+}
+
+#[tokio::test]
+async fn test_graph_validation_errors() {
+    // Given
+    let state = setup().await;
+
+    // When / Then: no scope
+    let (status, _) = send(&state, "GET", "/api/v1/graph", None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // When / Then: bad format
+    let (status, json) = send(
+        &state,
+        "GET",
+        "/api/v1/graph?project_id=TEST&format=svg",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"]["field"], "format");
+
+    // When / Then: bad dependency type
+    let (status, json) = send(
+        &state,
+        "GET",
+        "/api/v1/graph?project_id=TEST&dependency_type=parent",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"]["field"], "dependency_type");
+}
@@ -0,0 +1,234 @@
+mod common;
+
+use crate::common::{create_test_app_state, create_test_project, create_test_user};
+
+use pm_server::routes::build_router;
+
+use axum::{
+    body::Body,
+    http::{HeaderMap, Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+const USER_ID: &str = "00000000-0000-0000-0000-000000000001";
+const OTHER_USER_ID: &str = "00000000-0000-0000-0000-000000000002";
+
+async fn send(
+    state: &pm_ws::AppState,
+    method: &str,
+    uri: &str,
+    user_id: &str,
+    key: Option<&str>,
+    body: Option<Value>,
+) -> (StatusCode, HeaderMap, Value) {
+    let app = build_router(state.clone());
+    let mut builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .header("X-User-Id", user_id);
+    if let Some(key) = key {
+        builder = builder.header("Idempotency-Key", key);
+    }
+    let request = match body {
+        Some(b) => builder.body(Body::from(b.to_string())).unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    };
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let headers = response.headers().clone();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, headers, serde_json::from_slice(&bytes).unwrap())
+}
+
+async fn setup() -> (pm_ws::AppState, Uuid) {
+    let state = create_test_app_state().await;
+    create_test_user(&state.pool, USER_ID).await;
+    create_test_user(&state.pool, OTHER_USER_ID).await;
+    let project_id = create_test_project(&state.pool, USER_ID).await;
+    (state, project_id)
+}
+
+fn new_item(project_id: Uuid, title: &str) -> Value {
+    json!({
+        "project_id": project_id.to_string(),
+        "item_type": "task",
+        "title": title,
+    })
+}
+
+async fn item_count(state: &pm_ws::AppState, project_id: Uuid) -> u64 {
+    let uri = format!("/api/v1/projects/{}/work-items", project_id);
+    let (status, _, json) = send(state, "GET", &uri, USER_ID, None, None).await;
+    assert_eq!(status, StatusCode::OK);
+    json["total"].as_u64().unwrap()
+}
+
+#[tokio::test]
+async fn test_retry_replays_stored_response() {
+    let (state, project_id) = setup().await;
+    let body = new_item(project_id, "Write docs");
+
+    let (status, headers, first) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items",
+        USER_ID,
+        Some("retry-1"),
+        Some(body.clone()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", first);
+    assert!(headers.get("idempotent-replayed").is_none());
+
+    let (status, headers, second) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items",
+        USER_ID,
+        Some("retry-1"),
+        Some(body),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers.get("idempotent-replayed").unwrap(), "true");
+    assert_eq!(second, first);
+    assert_eq!(item_count(&state, project_id).await, 1);
+}
+
+#[tokio::test]
+async fn test_reused_key_with_different_body_is_rejected() {
+    let (state, project_id) = setup().await;
+    let (status, _, _) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items",
+        USER_ID,
+        Some("retry-2"),
+        Some(new_item(project_id, "First")),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _, json) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items",
+        USER_ID,
+        Some("retry-2"),
+        Some(new_item(project_id, "Second")),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(json["error"]["code"], "IDEMPOTENCY_KEY_REUSED");
+    assert_eq!(item_count(&state, project_id).await, 1);
+}
+
+#[tokio::test]
+async fn test_keys_are_scoped_per_user() {
+    let (state, project_id) = setup().await;
+    let body = new_item(project_id, "Shared key");
+
+    for user_id in [USER_ID, OTHER_USER_ID] {
+        let (status, headers, json) = send(
+            &state,
+            "POST",
+            "/api/v1/work-items",
+            user_id,
+            Some("same-key"),
+            Some(body.clone()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}", json);
+        assert!(headers.get("idempotent-replayed").is_none());
+    }
+    assert_eq!(item_count(&state, project_id).await, 2);
+}
+
+#[tokio::test]
+async fn test_failed_requests_are_not_stored() {
+    let (state, project_id) = setup().await;
+
+    let (status, _, _) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items",
+        USER_ID,
+        Some("retry-3"),
+        Some(new_item(project_id, "")),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // The corrected request goes through under the same key
+    let (status, _, json) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items",
+        USER_ID,
+        Some("retry-3"),
+        Some(new_item(project_id, "Fixed")),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+}
+
+#[tokio::test]
+async fn test_delete_retry_replays_instead_of_404() {
+    let (state, project_id) = setup().await;
+    let (_, _, created) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items",
+        USER_ID,
+        None,
+        Some(new_item(project_id, "Doomed")),
+    )
+    .await;
+    let uri = format!(
+        "/api/v1/work-items/{}",
+        created["work_item"]["id"].as_str().unwrap()
+    );
+
+    for _ in 0..2 {
+        let (status, _, json) = send(&state, "DELETE", &uri, USER_ID, Some("del-1"), None).await;
+        assert_eq!(status, StatusCode::OK, "{}", json);
+        assert!(json["deleted_id"].is_string());
+    }
+}
+
+#[tokio::test]
+async fn test_concurrent_retries_run_the_request_once() {
+    let (state, project_id) = setup().await;
+    let body = new_item(project_id, "Raced");
+    let attempt = || {
+        send(
+            &state,
+            "POST",
+            "/api/v1/work-items",
+            USER_ID,
+            Some("race-1"),
+            Some(body.clone()),
+        )
+    };
+
+    let results = tokio::join!(attempt(), attempt(), attempt());
+
+    let mut created = 0;
+    for (status, headers, json) in [results.0, results.1, results.2] {
+        match status {
+            StatusCode::OK if headers.get("idempotent-replayed").is_none() => created += 1,
+            StatusCode::OK => {}
+            StatusCode::CONFLICT => {
+                assert_eq!(json["error"]["code"], "IDEMPOTENCY_KEY_IN_FLIGHT")
+            }
+            other => panic!("unexpected status {}: {}", other, json),
+        }
+    }
+    assert_eq!(created, 1);
+    assert_eq!(item_count(&state, project_id).await, 1);
+}
@@ -0,0 +1,240 @@
+mod common;
+
+use crate::common::{create_test_app_state, create_test_project, create_test_user};
+
+use pm_server::routes::build_router;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+const USER_ID: &str = "00000000-0000-0000-0000-000000000001";
+
+async fn send(
+    state: &pm_ws::AppState,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let app = build_router(state.clone());
+    let builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .header("X-User-Id", USER_ID);
+    let request = match body {
+        Some(b) => builder.body(Body::from(b.to_string())).unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    };
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+async fn setup() -> pm_ws::AppState {
+    let state = create_test_app_state().await;
+    create_test_user(&state.pool, USER_ID).await;
+    create_test_project(&state.pool, USER_ID).await;
+    state
+}
+
+fn titles(json: &Value) -> Vec<String> {
+    json["entries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["title"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_list_llm_context_returns_seeded_entries() {
+    // Given
+    let state = setup().await;
+
+    // When
+    let (status, json) = send(
+        &state,
+        "GET",
+        "/api/v1/llm-context?context_type=schema_doc",
+        None,
+    )
+    .await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    let entries = json["entries"].as_array().unwrap();
+    assert!(!entries.is_empty());
+    assert!(entries.iter().all(|e| e["context_type"] == "schema_doc"));
+    assert!(entries.iter().all(|e| e["project_id"].is_null()));
+}
+
+#[tokio::test]
+async fn test_project_entry_crud() {
+    // Given
+    let state = setup().await;
+
+    // When: create a project entry by project key
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/llm-context",
+        Some(json!({
+            "project_id": "TEST",
+            "context_type": "business_rule",
+            "category": "conventions",
+            "title": "Branch naming",
+            "content": "Branches are named after the work item key.",
+            "priority": 60,
+        })),
+    )
+    .await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    let id = json["entry"]["id"].as_str().unwrap().to_string();
+    assert!(json["entry"]["project_id"].is_string());
+    assert_eq!(json["entry"]["priority"], 60);
+
+    // Listed with the project, hidden from the global list
+    let (_, scoped) = send(&state, "GET", "/api/v1/llm-context?project_id=TEST", None).await;
+    let (_, global) = send(&state, "GET", "/api/v1/llm-context", None).await;
+    assert!(titles(&scoped).contains(&"Branch naming".to_string()));
+    assert!(!titles(&global).contains(&"Branch naming".to_string()));
+
+    // Update only some fields
+    let (status, json) = send(
+        &state,
+        "PUT",
+        &format!("/api/v1/llm-context/{}", id),
+        Some(json!({
+            "context_type": "instruction",
+            "example_sql": "SELECT key FROM pm_projects",
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    assert_eq!(json["entry"]["context_type"], "instruction");
+    assert_eq!(json["entry"]["example_sql"], "SELECT key FROM pm_projects");
+    assert_eq!(json["entry"]["title"], "Branch naming");
+
+    // Clear the example with an empty string
+    let (_, json) = send(
+        &state,
+        "PUT",
+        &format!("/api/v1/llm-context/{}", id),
+        Some(json!({ "example_sql": "" })),
+    )
+    .await;
+    assert!(json["entry"]["example_sql"].is_null());
+
+    // Delete
+    let (status, json) = send(
+        &state,
+        "DELETE",
+        &format!("/api/v1/llm-context/{}", id),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    assert_eq!(json["deleted_id"], id);
+
+    let (status, _) = send(&state, "GET", &format!("/api/v1/llm-context/{}", id), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_create_llm_context_validation() {
+    // Given
+    let state = setup().await;
+
+    // When: context_type outside the CHECK constraint
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/llm-context",
+        Some(json!({
+            "context_type": "tip",
+            "category": "general",
+            "title": "Tip",
+            "content": "Content",
+        })),
+    )
+    .await;
+
+    // Then
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{}", json);
+    assert_eq!(json["error"]["field"], "context_type");
+
+    // When: priority out of range
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/llm-context",
+        Some(json!({
+            "context_type": "instruction",
+            "category": "general",
+            "title": "Too important",
+            "content": "Content",
+            "priority": 101,
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{}", json);
+    assert_eq!(json["error"]["field"], "priority");
+
+    // When: unknown project
+    let (status, _) = send(
+        &state,
+        "POST",
+        "/api/v1/llm-context",
+        Some(json!({
+            "project_id": "NOPE",
+            "context_type": "instruction",
+            "category": "general",
+            "title": "Orphan",
+            "content": "Content",
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_verify_reports_broken_example_sql() {
+    // Given: the seeded examples plus one that references a missing column
+    let state = setup().await;
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/llm-context",
+        Some(json!({
+            "context_type": "query_pattern",
+            "category": "work_items",
+            "title": "Stale example",
+            "content": "Lists display keys.",
+            "example_sql": "SELECT display_key FROM pm_work_items",
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+
+    // When
+    let (status, json) = send(&state, "GET", "/api/v1/llm-context/verify", None).await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    assert_eq!(json["checked"], 9);
+    let problems = json["problems"].as_array().unwrap();
+    assert_eq!(problems.len(), 1);
+    assert_eq!(problems[0]["title"], "Stale example");
+    assert_eq!(problems[0]["issue"], "missing_column");
+    assert_eq!(problems[0]["missing"], "display_key");
+    assert_eq!(problems[0]["error"], "no such column: display_key");
+}
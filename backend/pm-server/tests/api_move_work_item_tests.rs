@@ -0,0 +1,272 @@
+mod common;
+
+use crate::common::{create_test_app_state, create_test_project, create_test_user};
+
+use pm_server::routes::build_router;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+const USER_ID: &str = "00000000-0000-0000-0000-000000000001";
+
+async fn send(
+    state: &pm_ws::AppState,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let app = build_router(state.clone());
+    let builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Content-Type", "application/json");
+    let request = match body {
+        Some(b) => builder.body(Body::from(b.to_string())).unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    };
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+async fn post(state: &pm_ws::AppState, uri: &str, body: Value) -> Value {
+    let (status, json) = send(state, "POST", uri, Some(body)).await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    json
+}
+
+/// UUID of the project with `key`
+async fn project_id(state: &pm_ws::AppState, key: &str) -> String {
+    let (status, json) = send(state, "GET", &format!("/api/v1/projects/{}", key), None).await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    json["project"]["id"].as_str().unwrap().to_string()
+}
+
+/// Create a work item in TEST and return its id
+async fn create_item(
+    state: &pm_ws::AppState,
+    item_type: &str,
+    title: &str,
+    parent_id: Option<&str>,
+) -> String {
+    let json = post(
+        state,
+        "/api/v1/work-items",
+        json!({
+            "project_id": project_id(state, "TEST").await,
+            "item_type": item_type,
+            "title": title,
+            "parent_id": parent_id,
+        }),
+    )
+    .await;
+    json["work_item"]["id"].as_str().unwrap().to_string()
+}
+
+/// TEST with an epic (TEST-1) > story (TEST-2) > task (TEST-3) and a loose
+/// task (TEST-4), plus an empty project OTHER
+async fn setup() -> (pm_ws::AppState, [String; 4]) {
+    let state = create_test_app_state().await;
+    create_test_user(&state.pool, USER_ID).await;
+    create_test_project(&state.pool, USER_ID).await;
+    post(
+        &state,
+        "/api/v1/projects",
+        json!({ "title": "Other Project", "key": "OTHER" }),
+    )
+    .await;
+
+    let epic = create_item(&state, "epic", "Epic", None).await;
+    let story = create_item(&state, "story", "Story", Some(&epic)).await;
+    let task = create_item(&state, "task", "Task", Some(&story)).await;
+    let loose = create_item(&state, "task", "Loose task", None).await;
+
+    (state, [epic, story, task, loose])
+}
+
+async fn depend(state: &pm_ws::AppState, blocking: &str, blocked: &str) {
+    post(
+        state,
+        "/api/v1/dependencies",
+        json!({
+            "blocking_item_id": blocking,
+            "blocked_item_id": blocked,
+            "dependency_type": "blocks",
+        }),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_move_carries_subtree_and_history_and_old_keys_redirect() {
+    let (state, [_epic, story, task, _loose]) = setup().await;
+    depend(&state, &story, &task).await;
+    post(
+        &state,
+        &format!("/api/v1/work-items/{}/comments", task),
+        json!({ "content": "Keep me" }),
+    )
+    .await;
+
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items/TEST-2/move-to-project",
+        Some(json!({ "project_id": "OTHER", "expected_version": 1 })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    assert_eq!(json["work_item"]["id"], json!(story));
+    assert_eq!(json["work_item"]["display_key"], json!("OTHER-1"));
+    assert_eq!(json["work_item"]["parent_id"], Value::Null);
+    assert_eq!(json["work_item"]["version"], json!(2));
+    assert_eq!(
+        json["moved"],
+        json!([
+            { "from": "TEST-2", "to": "OTHER-1" },
+            { "from": "TEST-3", "to": "OTHER-2" },
+        ])
+    );
+
+    // Old display keys resolve to the moved items
+    let (status, moved_task) = send(&state, "GET", "/api/v1/work-items/TEST-3", None).await;
+    assert_eq!(status, StatusCode::OK, "{}", moved_task);
+    assert_eq!(moved_task["work_item"]["id"], json!(task));
+    assert_eq!(moved_task["work_item"]["display_key"], json!("OTHER-2"));
+    assert_eq!(moved_task["work_item"]["parent_id"], json!(story));
+
+    // Comments and dependencies are still attached
+    let (_, comments) = send(
+        &state,
+        "GET",
+        &format!("/api/v1/work-items/{}/comments", task),
+        None,
+    )
+    .await;
+    assert_eq!(comments["comments"][0]["content"], json!("Keep me"));
+    let (_, dependencies) = send(
+        &state,
+        "GET",
+        &format!("/api/v1/work-items/{}/dependencies", task),
+        None,
+    )
+    .await;
+    assert_eq!(dependencies["dependencies"].as_array().unwrap().len(), 1);
+
+    // New items in OTHER continue after the moved ones; TEST keeps its counter
+    let next = post(
+        &state,
+        "/api/v1/work-items",
+        json!({
+            "project_id": project_id(&state, "OTHER").await,
+            "item_type": "task",
+            "title": "Next",
+        }),
+    )
+    .await;
+    assert_eq!(next["work_item"]["display_key"], json!("OTHER-3"));
+    let next = post(
+        &state,
+        "/api/v1/work-items",
+        json!({
+            "project_id": project_id(&state, "TEST").await,
+            "item_type": "task",
+            "title": "Next",
+        }),
+    )
+    .await;
+    assert_eq!(next["work_item"]["display_key"], json!("TEST-5"));
+}
+
+#[tokio::test]
+async fn test_move_under_parent_validates_hierarchy() {
+    let (state, _) = setup().await;
+    let other_task = post(
+        &state,
+        "/api/v1/work-items",
+        json!({
+            "project_id": project_id(&state, "OTHER").await,
+            "item_type": "task",
+            "title": "Task",
+        }),
+    )
+    .await;
+
+    // A story cannot sit under a task
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items/TEST-2/move-to-project",
+        Some(json!({
+            "project_id": "OTHER",
+            "parent_id": other_task["work_item"]["id"],
+            "expected_version": 1,
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{}", json);
+    assert_eq!(json["error"]["field"], json!("parent_id"));
+
+    // The parent has to be in the target project
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items/TEST-4/move-to-project",
+        Some(json!({
+            "project_id": "OTHER",
+            "parent_id": "TEST-2",
+            "expected_version": 1,
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{}", json);
+    assert_eq!(json["error"]["field"], json!("parent_id"));
+}
+
+#[tokio::test]
+async fn test_move_rejects_dependency_left_behind() {
+    let (state, [_epic, _story, task, loose]) = setup().await;
+    depend(&state, &loose, &task).await;
+
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items/TEST-2/move-to-project",
+        Some(json!({ "project_id": "OTHER", "expected_version": 1 })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{}", json);
+    assert!(
+        json["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("TEST-3 has a dependency on TEST-4")
+    );
+    let (_, story) = send(&state, "GET", "/api/v1/work-items/TEST-2", None).await;
+    assert_eq!(story["work_item"]["display_key"], json!("TEST-2"));
+}
+
+#[tokio::test]
+async fn test_move_to_same_project_is_rejected() {
+    let (state, _) = setup().await;
+
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items/TEST-4/move-to-project",
+        Some(json!({ "project_id": "TEST", "expected_version": 1 })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{}", json);
+    assert_eq!(json["error"]["field"], json!("project_id"));
+}
@@ -0,0 +1,100 @@
+mod common;
+
+use crate::common::create_test_app_state;
+
+use pm_server::routes::build_router;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::Value;
+use tower::ServiceExt;
+
+/// Routes that are not REST endpoints and so have no OpenAPI operation
+const UNDOCUMENTED_ROUTES: [&str; 1] = ["/ws"];
+
+/// (method, path) for every `.route(...)` registered in `build_router`
+fn registered_routes() -> Vec<(String, String)> {
+    let source: String = include_str!("../src/routes.rs")
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+
+    source
+        .split(".route(\"")
+        .skip(1)
+        .map(|rest| {
+            let (path, rest) = rest.split_once("\",").unwrap();
+            let method = rest.split('(').next().unwrap();
+            (method.to_string(), path.to_string())
+        })
+        .collect()
+}
+
+async fn fetch_spec() -> Value {
+    let state = create_test_app_state().await;
+    let request = Request::builder()
+        .uri("/api/v1/openapi.json")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = build_router(state).oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+#[tokio::test]
+async fn test_every_route_is_in_the_spec() {
+    let spec = fetch_spec().await;
+    let routes = registered_routes();
+    assert!(routes.len() > 50, "parsed only {} routes", routes.len());
+
+    let missing: Vec<String> = routes
+        .iter()
+        .filter(|(_, path)| !UNDOCUMENTED_ROUTES.contains(&path.as_str()))
+        .filter(|(method, path)| spec["paths"][path][method].is_null())
+        .map(|(method, path)| format!("{} {}", method.to_uppercase(), path))
+        .collect();
+    assert!(
+        missing.is_empty(),
+        "missing from OpenAPI spec: {:?}",
+        missing
+    );
+}
+
+#[tokio::test]
+async fn test_spec_describes_request_and_response_types() {
+    let spec = fetch_spec().await;
+    assert!(spec["openapi"].as_str().unwrap().starts_with("3."));
+
+    let create = &spec["paths"]["/api/v1/work-items"]["post"];
+    assert_eq!(
+        create["requestBody"]["content"]["application/json"]["schema"]["$ref"],
+        "#/components/schemas/CreateWorkItemRequest"
+    );
+
+    let schemas = &spec["components"]["schemas"];
+    let required = schemas["CreateWorkItemRequest"]["required"]
+        .as_array()
+        .unwrap();
+    assert!(required.contains(&Value::from("title")));
+    assert!(
+        schemas["WorkItemListResponse"]["properties"]["next_cursor"].is_object(),
+        "{}",
+        schemas["WorkItemListResponse"]
+    );
+    assert!(schemas["WorkItemDto"]["properties"]["display_key"].is_object());
+
+    // Conditional headers come from the Preconditions extractor
+    let params = spec["paths"]["/api/v1/projects/{id}"]["put"]["parameters"]
+        .as_array()
+        .unwrap();
+    assert!(
+        params
+            .iter()
+            .any(|p| p["name"] == "If-Match" && p["in"] == "header")
+    );
+}
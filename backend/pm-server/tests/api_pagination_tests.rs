@@ -0,0 +1,225 @@
+mod common;
+
+use crate::common::{create_test_app_state, create_test_project, create_test_user};
+
+use pm_server::routes::build_router;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+const USER_ID: &str = "00000000-0000-0000-0000-000000000001";
+
+async fn send(
+    state: &pm_ws::AppState,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let app = build_router(state.clone());
+    let builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .header("X-User-Id", USER_ID);
+    let request = match body {
+        Some(b) => builder.body(Body::from(b.to_string())).unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    };
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+async fn create_item(state: &pm_ws::AppState, project_id: Uuid, title: &str) {
+    let (status, json) = send(
+        state,
+        "POST",
+        "/api/v1/work-items",
+        Some(json!({
+            "project_id": project_id.to_string(),
+            "item_type": "task",
+            "title": title,
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+}
+
+/// Project TEST with tasks TEST-1..TEST-5 titled E, D, C, B, A
+async fn setup() -> pm_ws::AppState {
+    let state = create_test_app_state().await;
+    create_test_user(&state.pool, USER_ID).await;
+    let project_id = create_test_project(&state.pool, USER_ID).await;
+    for title in ["E", "D", "C", "B", "A"] {
+        create_item(&state, project_id, title).await;
+    }
+    state
+}
+
+fn keys(json: &Value) -> Vec<String> {
+    json["work_items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|w| w["display_key"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_work_items_follow_cursors_to_the_end() {
+    // Given
+    let state = setup().await;
+    let base = "/api/v1/projects/TEST/work-items?limit=2";
+
+    // When
+    let mut seen = Vec::new();
+    let mut uri = base.to_string();
+    let mut pages = 0;
+    loop {
+        let (status, json) = send(&state, "GET", &uri, None).await;
+        assert_eq!(status, StatusCode::OK, "{}", json);
+        assert_eq!(json["total"], 5);
+        seen.extend(keys(&json));
+        pages += 1;
+        match json["next_cursor"].as_str() {
+            Some(cursor) => uri = format!("{}&cursor={}", base, cursor),
+            None => break,
+        }
+    }
+
+    // Then
+    assert_eq!(pages, 3);
+    assert_eq!(seen, ["TEST-1", "TEST-2", "TEST-3", "TEST-4", "TEST-5"]);
+}
+
+#[tokio::test]
+async fn test_work_items_sort_and_fields() {
+    // Given
+    let state = setup().await;
+
+    // When
+    let (status, json) = send(
+        &state,
+        "GET",
+        "/api/v1/projects/TEST/work-items?sort=title&fields=display_key,title&limit=3",
+        None,
+    )
+    .await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    assert_eq!(keys(&json), ["TEST-5", "TEST-4", "TEST-3"]);
+    assert_eq!(
+        json["work_items"][0],
+        json!({ "display_key": "TEST-5", "title": "A" })
+    );
+
+    // When: descending, continuing from a cursor
+    let (_, first) = send(
+        &state,
+        "GET",
+        "/api/v1/projects/TEST/work-items?sort=-item_number&limit=2",
+        None,
+    )
+    .await;
+    let cursor = first["next_cursor"].as_str().unwrap();
+    let (status, next) = send(
+        &state,
+        "GET",
+        &format!(
+            "/api/v1/projects/TEST/work-items?sort=-item_number&limit=2&cursor={}",
+            cursor
+        ),
+        None,
+    )
+    .await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK, "{}", next);
+    assert_eq!(keys(&first), ["TEST-5", "TEST-4"]);
+    assert_eq!(keys(&next), ["TEST-3", "TEST-2"]);
+}
+
+#[tokio::test]
+async fn test_list_rejects_bad_page_parameters() {
+    let state = setup().await;
+
+    for (query, field) in [
+        ("sort=description", "sort"),
+        ("limit=0", "limit"),
+        ("limit=100000", "limit"),
+        ("cursor=not-a-cursor", "cursor"),
+    ] {
+        let (status, json) = send(
+            &state,
+            "GET",
+            &format!("/api/v1/projects/TEST/work-items?{}", query),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{}", query);
+        assert_eq!(json["error"]["field"], field, "{}", query);
+    }
+
+    // A cursor only continues the sort it was issued for
+    let (_, json) = send(
+        &state,
+        "GET",
+        "/api/v1/projects/TEST/work-items?limit=1",
+        None,
+    )
+    .await;
+    let cursor = json["next_cursor"].as_str().unwrap();
+    let (status, json) = send(
+        &state,
+        "GET",
+        &format!(
+            "/api/v1/projects/TEST/work-items?sort=title&cursor={}",
+            cursor
+        ),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"]["field"], "cursor");
+}
+
+#[tokio::test]
+async fn test_projects_comments_and_sprints_are_paged() {
+    // Given
+    let state = setup().await;
+
+    // When
+    let (status, projects) = send(&state, "GET", "/api/v1/projects?fields=key", None).await;
+    let (_, comments) = send(
+        &state,
+        "GET",
+        "/api/v1/work-items/TEST-1/comments?limit=5",
+        None,
+    )
+    .await;
+    let (_, sprints) = send(
+        &state,
+        "GET",
+        "/api/v1/projects/TEST/sprints?sort=-start_date",
+        None,
+    )
+    .await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK, "{}", projects);
+    assert_eq!(projects["projects"], json!([{ "key": "TEST" }]));
+    assert_eq!(projects["total"], 1);
+    assert_eq!(projects["next_cursor"], Value::Null);
+    assert_eq!(comments["comments"], json!([]));
+    assert_eq!(comments["total"], 0);
+    assert_eq!(sprints["sprints"], json!([]));
+}
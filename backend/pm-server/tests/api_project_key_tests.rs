@@ -0,0 +1,218 @@
+mod common;
+
+use crate::common::{create_test_app_state, create_test_user};
+
+use pm_server::routes::build_router;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+const USER_ID: &str = "00000000-0000-0000-0000-000000000001";
+const OTHER_USER_ID: &str = "00000000-0000-0000-0000-000000000002";
+
+async fn send_as(
+    state: &pm_ws::AppState,
+    user_id: &str,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let app = build_router(state.clone());
+    let builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .header("X-User-Id", user_id);
+    let request = match body {
+        Some(b) => builder.body(Body::from(b.to_string())).unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    };
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+async fn send(
+    state: &pm_ws::AppState,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    send_as(state, USER_ID, method, uri, body).await
+}
+
+async fn post(state: &pm_ws::AppState, uri: &str, body: Value) -> Value {
+    let (status, json) = send(state, "POST", uri, Some(body)).await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    json
+}
+
+/// Project PONE (created by USER_ID, so they are its admin) with one task
+/// PONE-1, and an empty project OTHER
+async fn setup() -> (pm_ws::AppState, String) {
+    let state = create_test_app_state().await;
+    create_test_user(&state.pool, USER_ID).await;
+    create_test_user(&state.pool, OTHER_USER_ID).await;
+    let project = post(
+        &state,
+        "/api/v1/projects",
+        json!({ "title": "Project One", "key": "PONE" }),
+    )
+    .await;
+    post(
+        &state,
+        "/api/v1/projects",
+        json!({ "title": "Other Project", "key": "OTHER" }),
+    )
+    .await;
+    let task = post(
+        &state,
+        "/api/v1/work-items",
+        json!({ "project_id": project["project"]["id"], "item_type": "task", "title": "Task" }),
+    )
+    .await;
+
+    (state, task["work_item"]["id"].as_str().unwrap().to_string())
+}
+
+async fn rename(state: &pm_ws::AppState, id: &str, body: Value) -> (StatusCode, Value) {
+    send(
+        state,
+        "PUT",
+        &format!("/api/v1/projects/{}/key", id),
+        Some(body),
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_rename_key_keeps_old_keys_resolving() {
+    let (state, task) = setup().await;
+
+    let (status, json) = rename(
+        &state,
+        "PONE",
+        json!({ "key": "alpha", "expected_version": 1 }),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    assert_eq!(json["project"]["key"], json!("ALPHA"));
+    assert_eq!(json["project"]["version"], json!(2));
+
+    // The old key and display keys built on it resolve
+    let (status, project) = send(&state, "GET", "/api/v1/projects/PONE", None).await;
+    assert_eq!(status, StatusCode::OK, "{}", project);
+    assert_eq!(project["project"]["key"], json!("ALPHA"));
+    let (status, item) = send(&state, "GET", "/api/v1/work-items/PONE-1", None).await;
+    assert_eq!(status, StatusCode::OK, "{}", item);
+    assert_eq!(item["work_item"]["id"], json!(task));
+    assert_eq!(item["work_item"]["display_key"], json!("ALPHA-1"));
+    let (status, _) = send(&state, "GET", "/api/v1/work-items/ALPHA-1", None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Numbering carries on under the new key
+    let next = post(
+        &state,
+        "/api/v1/work-items",
+        json!({ "project_id": project["project"]["id"], "item_type": "task", "title": "Next" }),
+    )
+    .await;
+    assert_eq!(next["work_item"]["display_key"], json!("ALPHA-2"));
+
+    // The project can take its old key back
+    let (status, json) = rename(
+        &state,
+        "ALPHA",
+        json!({ "key": "PONE", "expected_version": 2 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    assert_eq!(json["project"]["key"], json!("PONE"));
+    let (status, _) = send(&state, "GET", "/api/v1/work-items/ALPHA-1", None).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_old_key_cannot_be_taken_by_another_project() {
+    let (state, _) = setup().await;
+    rename(
+        &state,
+        "PONE",
+        json!({ "key": "ALPHA", "expected_version": 1 }),
+    )
+    .await;
+
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/projects",
+        Some(json!({ "title": "Impostor", "key": "PONE" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{}", json);
+    assert_eq!(json["error"]["field"], json!("key"));
+
+    let (status, json) = rename(
+        &state,
+        "OTHER",
+        json!({ "key": "PONE", "expected_version": 1 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{}", json);
+    assert_eq!(json["error"]["field"], json!("key"));
+}
+
+#[tokio::test]
+async fn test_rename_key_rejects_invalid_and_taken_keys() {
+    let (state, _) = setup().await;
+
+    for key in ["", "P1", "TOOLONGPROJECTKEY", "P-ONE"] {
+        let (status, json) =
+            rename(&state, "PONE", json!({ "key": key, "expected_version": 1 })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{}: {}", key, json);
+        assert_eq!(json["error"]["field"], json!("key"));
+    }
+
+    let (status, json) = rename(
+        &state,
+        "PONE",
+        json!({ "key": "OTHER", "expected_version": 1 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT, "{}", json);
+
+    let (status, json) = rename(
+        &state,
+        "PONE",
+        json!({ "key": "ALPHA", "expected_version": 7 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT, "{}", json);
+}
+
+#[tokio::test]
+async fn test_rename_key_requires_project_admin() {
+    let (state, _) = setup().await;
+
+    let (status, json) = send_as(
+        &state,
+        OTHER_USER_ID,
+        "PUT",
+        "/api/v1/projects/PONE/key",
+        Some(json!({ "key": "ALPHA", "expected_version": 1 })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::FORBIDDEN, "{}", json);
+    assert_eq!(json["error"]["code"], json!("FORBIDDEN"));
+    let (_, project) = send(&state, "GET", "/api/v1/projects/PONE", None).await;
+    assert_eq!(project["project"]["key"], json!("PONE"));
+}
@@ -0,0 +1,142 @@
+mod common;
+
+use crate::common::{
+    create_test_app_state, create_test_project, create_test_user, create_test_work_item,
+};
+
+use pm_server::routes::build_router;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+const USER_ID: &str = "00000000-0000-0000-0000-000000000001";
+
+async fn send(
+    state: &pm_ws::AppState,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let app = build_router(state.clone());
+    let builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .header("X-User-Id", USER_ID);
+    let request = match body {
+        Some(b) => builder.body(Body::from(b.to_string())).unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    };
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+/// A project the caller created (and so is a member of) with two items,
+/// alongside a seeded project the caller cannot see.
+async fn setup() -> pm_ws::AppState {
+    let state = create_test_app_state().await;
+    create_test_user(&state.pool, USER_ID).await;
+    let hidden = create_test_project(&state.pool, USER_ID).await;
+    create_test_work_item(&state.pool, hidden, 1, USER_ID).await;
+
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/projects",
+        Some(json!({ "title": "Mine", "key": "MINE" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    let project_id = json["project"]["id"].clone();
+    for title in ["First", "Second"] {
+        let (status, json) = send(
+            &state,
+            "POST",
+            "/api/v1/work-items",
+            Some(json!({ "project_id": project_id, "item_type": "task", "title": title })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}", json);
+    }
+
+    state
+}
+
+#[tokio::test]
+async fn test_query_returns_rows_from_member_projects_only() {
+    // Given
+    let state = setup().await;
+
+    // When
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/query",
+        Some(json!({
+            "sql": "SELECT p.key, w.title FROM pm_work_items w JOIN pm_projects p ON p.id = w.project_id ORDER BY w.title;"
+        })),
+    )
+    .await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    assert_eq!(json["columns"], json!(["key", "title"]));
+    assert_eq!(json["rows"], json!([["MINE", "First"], ["MINE", "Second"]]));
+    assert_eq!(json["row_count"], 2);
+    assert_eq!(json["truncated"], false);
+}
+
+#[tokio::test]
+async fn test_query_max_rows_truncates() {
+    let state = setup().await;
+
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/query",
+        Some(json!({ "sql": "SELECT title FROM pm_work_items", "max_rows": 1 })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    assert_eq!(json["row_count"], 1);
+    assert_eq!(json["truncated"], true);
+}
+
+#[tokio::test]
+async fn test_query_rejects_writes_and_pragmas() {
+    let state = setup().await;
+
+    for sql in [
+        "DELETE FROM pm_work_items",
+        "PRAGMA journal_mode",
+        "ATTACH DATABASE ':memory:' AS other",
+        "SELECT 1; SELECT 2",
+        "SELECT * FROM main.pm_work_items",
+    ] {
+        let (status, json) =
+            send(&state, "POST", "/api/v1/query", Some(json!({ "sql": sql }))).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{}: {}", sql, json);
+        assert_eq!(json["error"]["code"], "VALIDATION_ERROR");
+        assert_eq!(json["error"]["field"], "sql");
+    }
+
+    // Nothing was deleted
+    let (_, json) = send(
+        &state,
+        "POST",
+        "/api/v1/query",
+        Some(json!({ "sql": "SELECT COUNT(*) AS n FROM pm_work_items" })),
+    )
+    .await;
+    assert_eq!(json["rows"], json!([[2]]));
+}
@@ -0,0 +1,209 @@
+mod common;
+
+use crate::common::{create_test_app_state, create_test_project, create_test_user};
+
+use pm_auth::{Claims, JwtValidator, RateLimitConfig, RateLimiterFactory};
+use pm_server::routes::build_router;
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    body::Body,
+    extract::ConnectInfo,
+    http::{HeaderMap, Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
+use serde_json::{Value, json};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+const USER_ID: &str = "00000000-0000-0000-0000-000000000001";
+const OTHER_USER_ID: &str = "00000000-0000-0000-0000-000000000002";
+const JWT_SECRET: &[u8] = b"test-secret-key-at-least-32-bytes";
+
+/// A signed token for `user_id`
+fn token_for(user_id: &str) -> String {
+    let now = chrono::Utc::now().timestamp();
+    let claims = Claims {
+        sub: user_id.to_string(),
+        exp: now + 3600,
+        iat: now,
+        roles: vec![],
+        tenant_id: None,
+    };
+    encode(
+        &Header::new(Algorithm::HS256),
+        &claims,
+        &EncodingKey::from_secret(JWT_SECRET),
+    )
+    .unwrap()
+}
+
+/// Send through a router built once, so its token buckets carry over
+async fn send_as(
+    app: &Router,
+    uri: &str,
+    user_id: &str,
+    token: Option<&str>,
+    ip: Option<&str>,
+    body: Option<String>,
+) -> (StatusCode, HeaderMap, Value) {
+    let mut builder = Request::builder()
+        .method(if body.is_some() { "POST" } else { "GET" })
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .header("X-User-Id", user_id);
+    if let Some(token) = token {
+        builder = builder.header("Authorization", format!("Bearer {}", token));
+    }
+    let mut request = builder
+        .body(body.map(Body::from).unwrap_or_else(Body::empty))
+        .unwrap();
+    if let Some(ip) = ip {
+        let addr: SocketAddr = format!("{}:50000", ip).parse().unwrap();
+        request.extensions_mut().insert(ConnectInfo(addr));
+    }
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let headers = response.headers().clone();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let json = serde_json::from_slice(&bytes).unwrap_or(Value::Null);
+    (status, headers, json)
+}
+
+async fn send(
+    app: &Router,
+    uri: &str,
+    user_id: &str,
+    ip: Option<&str>,
+    body: Option<String>,
+) -> (StatusCode, HeaderMap, Value) {
+    send_as(app, uri, user_id, None, ip, body).await
+}
+
+async fn setup(rate_limit: RateLimitConfig) -> (Router, Uuid) {
+    let mut state = create_test_app_state().await;
+    state.rate_limiter_factory = RateLimiterFactory::new(rate_limit);
+    state.jwt_validator = Some(Arc::new(JwtValidator::with_hs256(JWT_SECRET)));
+    state.api_config.max_body_bytes = 4096;
+    create_test_user(&state.pool, USER_ID).await;
+    create_test_user(&state.pool, OTHER_USER_ID).await;
+    let project_id = create_test_project(&state.pool, USER_ID).await;
+    (build_router(state), project_id)
+}
+
+#[tokio::test]
+async fn test_user_over_limit_gets_429_with_retry_after() {
+    let (app, project_id) = setup(RateLimitConfig {
+        api_requests_per_user: 2,
+        ..Default::default()
+    })
+    .await;
+    let uri = format!("/api/v1/projects/{}", project_id);
+    let token = token_for(USER_ID);
+
+    for _ in 0..2 {
+        let (status, _, _) = send_as(&app, &uri, USER_ID, Some(&token), None, None).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    let (status, headers, json) = send_as(&app, &uri, USER_ID, Some(&token), None, None).await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(json["error"]["code"], "RATE_LIMIT_EXCEEDED");
+    let retry_after: u64 = headers["retry-after"].to_str().unwrap().parse().unwrap();
+    assert!(retry_after >= 1);
+
+    // Another user has their own bucket; health checks are never limited
+    let other_token = token_for(OTHER_USER_ID);
+    let (status, _, _) = send_as(&app, &uri, OTHER_USER_ID, Some(&other_token), None, None).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _, _) = send(&app, "/health", USER_ID, None, None).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_user_bucket_follows_the_token_not_the_user_header() {
+    let (app, project_id) = setup(RateLimitConfig {
+        api_requests_per_user: 1,
+        ..Default::default()
+    })
+    .await;
+    let uri = format!("/api/v1/projects/{}", project_id);
+
+    // Without a token X-User-Id picks no bucket, so it neither escapes nor
+    // drains the user's quota
+    for _ in 0..3 {
+        let (status, _, _) = send(&app, &uri, USER_ID, None, None).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    // With one, the token's subject is charged whatever the header says
+    let token = token_for(USER_ID);
+    let (status, _, _) = send_as(&app, &uri, OTHER_USER_ID, Some(&token), None, None).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _, _) = send_as(&app, &uri, USER_ID, Some(&token), None, None).await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+}
+
+#[tokio::test]
+async fn test_ip_limit_spans_users() {
+    let (app, project_id) = setup(RateLimitConfig {
+        api_requests_per_ip: 2,
+        ..Default::default()
+    })
+    .await;
+    let uri = format!("/api/v1/projects/{}", project_id);
+
+    for user_id in [USER_ID, OTHER_USER_ID] {
+        let (status, _, _) = send(&app, &uri, user_id, Some("10.0.0.1"), None).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+    let (status, _, _) = send(&app, &uri, USER_ID, Some("10.0.0.1"), None).await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+
+    let (status, _, _) = send(&app, &uri, USER_ID, Some("10.0.0.2"), None).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_oversized_body_is_rejected_except_on_import() {
+    let (app, project_id) = setup(RateLimitConfig::default()).await;
+    let padding = "x".repeat(8192);
+
+    let body = json!({
+        "project_id": project_id.to_string(),
+        "item_type": "task",
+        "title": "Big",
+        "description": padding,
+    });
+    let (status, _, _) = send(
+        &app,
+        "/api/v1/work-items",
+        USER_ID,
+        None,
+        Some(body.to_string()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+
+    // Sync import has its own, larger limit
+    let import = json!({
+        "version": "1.0",
+        "exported_at": "2026-01-01T00:00:00Z",
+        "exported_by": padding,
+        "data": {},
+    });
+    let (status, _, _) = send(
+        &app,
+        "/api/v1/sync/import",
+        USER_ID,
+        None,
+        Some(import.to_string()),
+    )
+    .await;
+    assert_ne!(status, StatusCode::PAYLOAD_TOO_LARGE);
+}
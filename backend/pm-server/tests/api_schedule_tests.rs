@@ -0,0 +1,230 @@
+mod common;
+
+use crate::common::{create_test_app_state, create_test_project, create_test_user};
+
+use pm_server::routes::build_router;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+const USER_ID: &str = "00000000-0000-0000-0000-000000000001";
+
+async fn send(
+    state: &pm_ws::AppState,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let app = build_router(state.clone());
+    let builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .header("X-User-Id", USER_ID);
+    let request = match body {
+        Some(b) => builder.body(Body::from(b.to_string())).unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    };
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+async fn create_item(
+    state: &pm_ws::AppState,
+    project_id: Uuid,
+    item_type: &str,
+    parent_id: Option<&str>,
+    estimate: Option<i32>,
+) -> String {
+    let (status, json) = send(
+        state,
+        "POST",
+        "/api/v1/work-items",
+        Some(json!({
+            "project_id": project_id.to_string(),
+            "item_type": item_type,
+            "title": format!("A {}", item_type),
+            "parent_id": parent_id,
+            "original_estimate_seconds": estimate,
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    json["work_item"]["id"].as_str().unwrap().to_string()
+}
+
+async fn blocks(state: &pm_ws::AppState, blocking: &str, blocked: &str) {
+    let (status, json) = send(
+        state,
+        "POST",
+        "/api/v1/dependencies",
+        Some(json!({
+            "blocking_item_id": blocking,
+            "blocked_item_id": blocked,
+            "dependency_type": "blocks",
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+}
+
+/// An epic (TEST-1) with three stories: TEST-2 (1h) blocks TEST-3 (2h) and
+/// TEST-4 (30m). A fourth story (TEST-5) sits outside the epic.
+async fn setup() -> (pm_ws::AppState, Uuid, Vec<String>) {
+    let state = create_test_app_state().await;
+    create_test_user(&state.pool, USER_ID).await;
+    let project_id = create_test_project(&state.pool, USER_ID).await;
+
+    let epic = create_item(&state, project_id, "epic", None, None).await;
+    let a = create_item(&state, project_id, "story", Some(&epic), Some(3600)).await;
+    let b = create_item(&state, project_id, "story", Some(&epic), Some(7200)).await;
+    let c = create_item(&state, project_id, "story", Some(&epic), Some(1800)).await;
+    let outside = create_item(&state, project_id, "story", None, Some(100_000)).await;
+    blocks(&state, &a, &b).await;
+    blocks(&state, &a, &c).await;
+
+    (state, project_id, vec![epic, a, b, c, outside])
+}
+
+#[tokio::test]
+async fn test_schedule_for_epic() {
+    // Given
+    let (state, _project_id, ids) = setup().await;
+
+    // When
+    let (status, json) = send(&state, "GET", "/api/v1/schedule?epic_id=TEST-1", None).await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    assert_eq!(json["scope"], "epic");
+    let schedule = &json["schedule"];
+    assert_eq!(schedule["unit"], "seconds");
+    assert_eq!(schedule["total_duration"], 10800);
+    assert_eq!(schedule["critical_path"], json!([ids[1], ids[2]]));
+
+    let items = schedule["items"].as_array().unwrap();
+    let keys: Vec<&str> = items
+        .iter()
+        .map(|i| i["display_key"].as_str().unwrap())
+        .collect();
+    assert_eq!(keys, ["TEST-2", "TEST-3", "TEST-4"]);
+    assert_eq!(items[2]["start_offset"], 3600);
+    assert_eq!(items[2]["end_offset"], 5400);
+    assert_eq!(items[2]["slack"], 5400);
+    assert_eq!(items[2]["critical"], false);
+}
+
+#[tokio::test]
+async fn test_schedule_for_project_skips_done_items() {
+    // Given: the first story is done, so it no longer blocks anything
+    let (state, _project_id, ids) = setup().await;
+    let (status, json) = send(
+        &state,
+        "PUT",
+        &format!("/api/v1/work-items/{}", ids[1]),
+        Some(json!({ "status": "done", "expected_version": 1 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+
+    // When
+    let (status, json) = send(&state, "GET", "/api/v1/schedule?project_id=TEST", None).await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    let schedule = &json["schedule"];
+    assert_eq!(schedule["total_duration"], 100_000);
+    assert_eq!(schedule["critical_path"], json!([ids[4]]));
+    assert_eq!(schedule["items"].as_array().unwrap().len(), 4);
+    // The epic has no estimate of its own
+    assert_eq!(schedule["unweighted_item_count"], 1);
+}
+
+#[tokio::test]
+async fn test_schedule_for_sprint() {
+    // Given
+    let (state, project_id, ids) = setup().await;
+    let now = chrono::Utc::now().timestamp();
+    let (_, json) = send(
+        &state,
+        "POST",
+        "/api/v1/sprints",
+        Some(json!({
+            "project_id": project_id.to_string(),
+            "name": "Sprint 1",
+            "start_date": now,
+            "end_date": now + 86400,
+        })),
+    )
+    .await;
+    let sprint_id = json["sprint"]["id"].as_str().unwrap().to_string();
+    for id in [&ids[1], &ids[3]] {
+        let (status, json) = send(
+            &state,
+            "PUT",
+            &format!("/api/v1/work-items/{}", id),
+            Some(json!({ "sprint_id": sprint_id, "expected_version": 1 })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}", json);
+    }
+
+    // When
+    let (status, json) = send(
+        &state,
+        "GET",
+        &format!("/api/v1/schedule?sprint_id={}", sprint_id),
+        None,
+    )
+    .await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    assert_eq!(json["schedule"]["total_duration"], 5400);
+    assert_eq!(json["schedule"]["items"].as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_schedule_validation_errors() {
+    // Given
+    let (state, _project_id, _ids) = setup().await;
+
+    // When / Then: no scope
+    let (status, _) = send(&state, "GET", "/api/v1/schedule", None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // When / Then: two scopes
+    let (status, _) = send(
+        &state,
+        "GET",
+        "/api/v1/schedule?project_id=TEST&epic_id=TEST-1",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // When / Then: a story is not an epic
+    let (status, json) = send(&state, "GET", "/api/v1/schedule?epic_id=TEST-2", None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"]["field"], "epic_id");
+
+    // When / Then: unknown weight
+    let (status, json) = send(
+        &state,
+        "GET",
+        "/api/v1/schedule?project_id=TEST&weight=hours",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"]["field"], "weight");
+}
@@ -0,0 +1,585 @@
+mod common;
+
+use crate::common::{
+    create_test_app_state, create_test_project, create_test_user, create_test_work_item,
+};
+
+use pm_server::routes::build_router;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+const USER_ID: &str = "00000000-0000-0000-0000-000000000001";
+
+async fn send(
+    state: &pm_ws::AppState,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let app = build_router(state.clone());
+    let builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Content-Type", "application/json");
+    let request = match body {
+        Some(b) => builder.body(Body::from(b.to_string())).unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    };
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+/// An export of one project, retitled with a later `updated_at`, plus a
+/// second project that doesn't exist locally
+async fn setup() -> (pm_ws::AppState, Uuid, Uuid, Value) {
+    let state = create_test_app_state().await;
+    create_test_user(&state.pool, USER_ID).await;
+    let project_id = create_test_project(&state.pool, USER_ID).await;
+
+    let (status, mut data) = send(&state, "GET", "/api/v1/sync/export", None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let project = &mut data["projects"][0];
+    project["title"] = json!("Renamed Project");
+    project["updated_at"] = json!(project["updated_at"].as_i64().unwrap() + 60);
+
+    let new_project_id = Uuid::new_v4();
+    let mut new_project = project.clone();
+    new_project["id"] = json!(new_project_id.to_string());
+    new_project["key"] = json!("NEW");
+    data["projects"].as_array_mut().unwrap().push(new_project);
+
+    (state, project_id, new_project_id, data)
+}
+
+#[tokio::test]
+async fn test_dry_run_reports_diff_without_writing() {
+    let (state, project_id, new_project_id, data) = setup().await;
+
+    let (status, result) = send(
+        &state,
+        "POST",
+        "/api/v1/sync/import?dry_run=true",
+        Some(data),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", result);
+
+    assert_eq!(result["dry_run"], json!(true));
+    assert_eq!(result["projects"]["created"], json!(1));
+    assert_eq!(result["projects"]["updated"], json!(1));
+    assert_eq!(
+        result["diff"]["projects"]["created"],
+        json!([new_project_id.to_string()])
+    );
+
+    let updated = &result["diff"]["projects"]["updated"][0];
+    assert_eq!(updated["id"], json!(project_id.to_string()));
+    let title_change = updated["changes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["field"] == "title")
+        .expect("title change");
+    assert_eq!(title_change["old"], json!("Test Project"));
+    assert_eq!(title_change["new"], json!("Renamed Project"));
+
+    // Nothing was written
+    let (_, project) = send(
+        &state,
+        "GET",
+        &format!("/api/v1/projects/{}", project_id),
+        None,
+    )
+    .await;
+    assert_eq!(project["project"]["title"], json!("Test Project"));
+    let (status, _) = send(
+        &state,
+        "GET",
+        &format!("/api/v1/projects/{}", new_project_id),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_import_applies_the_same_diff_as_the_dry_run() {
+    let (state, project_id, _, data) = setup().await;
+
+    let (_, dry_run) = send(
+        &state,
+        "POST",
+        "/api/v1/sync/import?dry_run=true",
+        Some(data.clone()),
+    )
+    .await;
+    let (status, result) = send(&state, "POST", "/api/v1/sync/import", Some(data)).await;
+    assert_eq!(status, StatusCode::OK, "{}", result);
+
+    assert_eq!(result["dry_run"], json!(false));
+    assert_eq!(result["diff"], dry_run["diff"]);
+
+    let (_, project) = send(
+        &state,
+        "GET",
+        &format!("/api/v1/projects/{}", project_id),
+        None,
+    )
+    .await;
+    assert_eq!(project["project"]["title"], json!("Renamed Project"));
+}
+
+#[tokio::test]
+async fn test_import_skips_older_records_with_reason() {
+    let (state, project_id, _, mut data) = setup().await;
+    data["projects"][0]["updated_at"] = json!(0);
+
+    let (status, result) = send(
+        &state,
+        "POST",
+        "/api/v1/sync/import?dry_run=true",
+        Some(data),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", result);
+
+    assert_eq!(
+        result["diff"]["projects"]["skipped"][0],
+        json!({ "id": project_id.to_string(), "reason": "local is newer" })
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_import_lists_every_problem_and_writes_nothing() {
+    let (state, project_id, new_project_id, _) = setup().await;
+    let task_id = create_test_work_item(&state.pool, project_id, 1, USER_ID).await;
+    let (_, mut data) = send(&state, "GET", "/api/v1/sync/export", None).await;
+
+    // A new project that would be valid on its own
+    let mut new_project = data["projects"][0].clone();
+    new_project["id"] = json!(new_project_id.to_string());
+    new_project["key"] = json!("NEW");
+    data["projects"].as_array_mut().unwrap().push(new_project);
+
+    // The task's parent doesn't exist
+    let missing_parent = Uuid::new_v4();
+    data["work_items"][0]["parent_id"] = json!(missing_parent.to_string());
+
+    // A task can't sit under an epic
+    let epic_id = Uuid::new_v4();
+    let mut epic = data["work_items"][0].clone();
+    epic["id"] = json!(epic_id.to_string());
+    epic["item_type"] = json!("epic");
+    epic["parent_id"] = json!(null);
+    epic["item_number"] = json!(2);
+    let mut child = data["work_items"][0].clone();
+    child["id"] = json!(Uuid::new_v4().to_string());
+    child["parent_id"] = json!(epic_id.to_string());
+    child["item_number"] = json!(3);
+    let child_id = child["id"].clone();
+    data["work_items"].as_array_mut().unwrap().push(epic);
+    data["work_items"].as_array_mut().unwrap().push(child);
+
+    // A self-dependency
+    let dependency_id = Uuid::new_v4();
+    data["dependencies"] = json!([{
+        "id": dependency_id.to_string(),
+        "blocking_item_id": task_id.to_string(),
+        "blocked_item_id": task_id.to_string(),
+        "dependency_type": "blocks",
+        "created_at": 0,
+        "created_by": USER_ID,
+    }]);
+
+    let (status, body) = send(&state, "POST", "/api/v1/sync/import", Some(data)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{}", body);
+    assert_eq!(body["error"]["code"], json!("IMPORT_REJECTED"));
+
+    let problems = body["problems"].as_array().unwrap();
+    let problem_ids: Vec<&Value> = problems.iter().map(|p| &p["id"]).collect();
+    assert_eq!(problems.len(), 3, "{:?}", problems);
+    assert!(problem_ids.contains(&&json!(task_id.to_string())));
+    assert!(problem_ids.contains(&&child_id));
+    assert!(problem_ids.contains(&&json!(dependency_id.to_string())));
+
+    // The valid parts weren't written either
+    let (status, _) = send(
+        &state,
+        "GET",
+        &format!("/api/v1/projects/{}", new_project_id),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_invalid_dto_is_reported_as_a_problem() {
+    let (state, _, _, mut data) = setup().await;
+    data["projects"][0]["id"] = json!("not-a-uuid");
+
+    let (status, body) = send(&state, "POST", "/api/v1/sync/import", Some(data)).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{}", body);
+    assert_eq!(body["problems"][0]["entity"], json!("project"));
+    assert_eq!(body["problems"][0]["id"], json!("not-a-uuid"));
+}
+
+/// Serve `state` on a free local port, returning its base URL
+async fn serve(state: pm_ws::AppState) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, build_router(state)).await.unwrap();
+    });
+    format!("http://{}", addr)
+}
+
+/// Set a project's title and `updated_at` directly, as a local edit would
+async fn edit_project(state: &pm_ws::AppState, project_id: Uuid, title: &str, updated_at: i64) {
+    sqlx::query("UPDATE pm_projects SET title = ?, updated_at = ? WHERE id = ?")
+        .bind(title)
+        .bind(updated_at)
+        .bind(project_id.to_string())
+        .execute(&state.pool)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_export_since_only_returns_changed_records() {
+    let state = create_test_app_state().await;
+    create_test_user(&state.pool, USER_ID).await;
+    let project_id = create_test_project(&state.pool, USER_ID).await;
+    create_test_work_item(&state.pool, project_id, 1, USER_ID).await;
+    let now = chrono::Utc::now().timestamp();
+
+    let (status, data) = send(
+        &state,
+        "GET",
+        &format!("/api/v1/sync/export?since={}", now - 3600),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(data["projects"].as_array().unwrap().len(), 1);
+    assert_eq!(data["work_items"].as_array().unwrap().len(), 1);
+    assert!(data["swim_lanes"].as_array().unwrap().is_empty());
+
+    let (status, data) = send(
+        &state,
+        "GET",
+        &format!("/api/v1/sync/export?since={}", now + 3600),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(data["projects"].as_array().unwrap().is_empty());
+    assert!(data["work_items"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_import_with_base_reports_local_changes_as_conflicts() {
+    let (state, project_id, _, data) = setup().await;
+    let base = chrono::Utc::now().timestamp() - 3600;
+
+    let (status, result) = send(
+        &state,
+        "POST",
+        &format!("/api/v1/sync/import?base={}", base),
+        Some(data.clone()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", result);
+
+    // The local project changed since the base, so the newer import doesn't win
+    assert_eq!(result["projects"]["conflicts"], json!(1));
+    assert_eq!(result["projects"]["updated"], json!(0));
+    let conflict = &result["diff"]["projects"]["conflicts"][0];
+    assert_eq!(conflict["id"], json!(project_id.to_string()));
+    assert_eq!(conflict["changes"][0]["field"], json!("title"));
+
+    let (_, project) = send(
+        &state,
+        "GET",
+        &format!("/api/v1/projects/{}", project_id),
+        None,
+    )
+    .await;
+    assert_eq!(project["project"]["title"], json!("Test Project"));
+
+    let (status, result) = send(
+        &state,
+        "POST",
+        &format!("/api/v1/sync/import?base={}&on_conflict=theirs", base),
+        Some(data),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", result);
+    assert_eq!(result["projects"]["conflicts"], json!(0));
+    assert_eq!(result["projects"]["updated"], json!(1));
+}
+
+#[tokio::test]
+async fn test_pull_copies_peer_records_and_records_watermarks() {
+    let peer = create_test_app_state().await;
+    create_test_user(&peer.pool, USER_ID).await;
+    let project_id = create_test_project(&peer.pool, USER_ID).await;
+    create_test_work_item(&peer.pool, project_id, 1, USER_ID).await;
+    let remote = serve(peer).await;
+
+    let local = create_test_app_state().await;
+    create_test_user(&local.pool, USER_ID).await;
+
+    let (status, body) = send(
+        &local,
+        "POST",
+        "/api/v1/sync/pull",
+        Some(json!({ "remote": format!("{}/", remote) })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["result"]["projects"]["created"], json!(1));
+    assert_eq!(body["result"]["work_items"]["created"], json!(1));
+    assert_eq!(body["peer"]["url"], json!(remote));
+    assert!(!body["peer"]["pulled_until"].is_null());
+    assert!(!body["peer"]["synced_at"].is_null());
+
+    let (status, peers) = send(&local, "GET", "/api/v1/sync/peers", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(peers["peers"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_pull_reports_conflicts_and_keeps_watermarks_until_resolved() {
+    let peer = create_test_app_state().await;
+    create_test_user(&peer.pool, USER_ID).await;
+    let project_id = create_test_project(&peer.pool, USER_ID).await;
+    let remote = serve(peer.clone()).await;
+
+    let local = create_test_app_state().await;
+    create_test_user(&local.pool, USER_ID).await;
+    let pull = json!({ "remote": remote });
+    let (status, first) = send(&local, "POST", "/api/v1/sync/pull", Some(pull.clone())).await;
+    assert_eq!(status, StatusCode::OK, "{}", first);
+
+    // Both sides edit the project after the first pull
+    let now = chrono::Utc::now().timestamp();
+    edit_project(&local, project_id, "Local title", now + 10).await;
+    edit_project(&peer, project_id, "Peer title", now + 20).await;
+
+    let (status, second) = send(&local, "POST", "/api/v1/sync/pull", Some(pull)).await;
+    assert_eq!(status, StatusCode::OK, "{}", second);
+    assert_eq!(second["result"]["projects"]["conflicts"], json!(1));
+    assert_eq!(
+        second["peer"]["pulled_until"],
+        first["peer"]["pulled_until"]
+    );
+    assert_eq!(second["peer"]["synced_at"], first["peer"]["synced_at"]);
+
+    let (_, project) = send(
+        &local,
+        "GET",
+        &format!("/api/v1/projects/{}", project_id),
+        None,
+    )
+    .await;
+    assert_eq!(project["project"]["title"], json!("Local title"));
+
+    let (status, resolved) = send(
+        &local,
+        "POST",
+        "/api/v1/sync/pull",
+        Some(json!({ "remote": remote, "on_conflict": "theirs" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", resolved);
+    assert_eq!(resolved["result"]["projects"]["updated"], json!(1));
+    // Watermarks have whole-second precision, so this pull may land in the
+    // same second as the first; it must not fall behind it
+    let pulled_until = |pull: &Value| {
+        chrono::DateTime::parse_from_rfc3339(pull["peer"]["pulled_until"].as_str().unwrap())
+            .unwrap()
+    };
+    assert!(pulled_until(&resolved) >= pulled_until(&first));
+
+    let (_, project) = send(
+        &local,
+        "GET",
+        &format!("/api/v1/projects/{}", project_id),
+        None,
+    )
+    .await;
+    assert_eq!(project["project"]["title"], json!("Peer title"));
+}
+
+#[tokio::test]
+async fn test_push_sends_local_records_to_peer() {
+    let peer = create_test_app_state().await;
+    create_test_user(&peer.pool, USER_ID).await;
+    let remote = serve(peer.clone()).await;
+
+    let local = create_test_app_state().await;
+    create_test_user(&local.pool, USER_ID).await;
+    let project_id = create_test_project(&local.pool, USER_ID).await;
+
+    let (status, body) = send(
+        &local,
+        "POST",
+        "/api/v1/sync/push",
+        Some(json!({ "remote": remote })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["result"]["projects"]["created"], json!(1));
+    assert!(!body["peer"]["pushed_until"].is_null());
+
+    let (status, _) = send(
+        &peer,
+        "GET",
+        &format!("/api/v1/projects/{}", project_id),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_pull_from_unreachable_peer_is_bad_gateway() {
+    let state = create_test_app_state().await;
+
+    let (status, body) = send(
+        &state,
+        "POST",
+        "/api/v1/sync/pull",
+        Some(json!({ "remote": "http://127.0.0.1:1" })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_GATEWAY, "{}", body);
+    assert_eq!(body["error"]["code"], json!("BAD_GATEWAY"));
+}
+
+/// Exports written by every schema version, oldest first
+const FIXTURES: [&str; 3] = [
+    include_str!("../../crates/pm-core/src/tests/sync/fixtures/export_v1.json"),
+    include_str!("../../crates/pm-core/src/tests/sync/fixtures/export_v2.json"),
+    include_str!("../../crates/pm-core/src/tests/sync/fixtures/export_v3.json"),
+];
+
+const ENTITIES: [&str; 11] = [
+    "projects",
+    "custom_fields",
+    "sprints",
+    "swim_lanes",
+    "work_items",
+    "comments",
+    "dependencies",
+    "time_entries",
+    "project_members",
+    "llm_context",
+    "activity_log",
+];
+
+#[tokio::test]
+async fn test_every_fixture_version_imports_and_round_trips() {
+    for (index, fixture) in FIXTURES.iter().enumerate() {
+        let version = index + 1;
+        let state = create_test_app_state().await;
+        create_test_user(&state.pool, USER_ID).await;
+        create_test_user(&state.pool, "00000000-0000-0000-0000-000000000002").await;
+        let data: Value = serde_json::from_str(fixture).unwrap();
+
+        let (status, result) = send(&state, "POST", "/api/v1/sync/import", Some(data)).await;
+        assert_eq!(status, StatusCode::OK, "v{}: {}", version, result);
+        assert_eq!(result["projects"]["created"], json!(1), "v{}", version);
+        assert_eq!(result["work_items"]["created"], json!(2), "v{}", version);
+        assert_eq!(result["comments"]["created"], json!(1), "v{}", version);
+        assert_eq!(result["time_entries"]["created"], json!(1), "v{}", version);
+
+        let (status, exported) = send(&state, "GET", "/api/v1/sync/export", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(exported["schema_version"], json!(3));
+        assert_eq!(exported["work_items"].as_array().unwrap().len(), 2);
+
+        // Importing our own export changes nothing
+        let (status, result) = send(&state, "POST", "/api/v1/sync/import", Some(exported)).await;
+        assert_eq!(status, StatusCode::OK, "v{}: {}", version, result);
+        for entity in ENTITIES {
+            assert_eq!(
+                result[entity]["created"],
+                json!(0),
+                "v{} {}",
+                version,
+                entity
+            );
+            assert_eq!(
+                result[entity]["updated"],
+                json!(0),
+                "v{} {}",
+                version,
+                entity
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_v2_fixture_imports_members_llm_context_and_activity() {
+    let state = create_test_app_state().await;
+    create_test_user(&state.pool, USER_ID).await;
+    create_test_user(&state.pool, "00000000-0000-0000-0000-000000000002").await;
+    let data: Value = serde_json::from_str(FIXTURES[1]).unwrap();
+
+    let (status, result) = send(&state, "POST", "/api/v1/sync/import", Some(data)).await;
+    assert_eq!(status, StatusCode::OK, "{}", result);
+    assert_eq!(result["custom_fields"]["created"], json!(1));
+    assert_eq!(result["project_members"]["created"], json!(1));
+    assert_eq!(result["llm_context"]["created"], json!(1));
+    assert_eq!(result["activity_log"]["created"], json!(1));
+
+    let (_, exported) = send(&state, "GET", "/api/v1/sync/export", None).await;
+    let has = |entity: &str, id: &str| {
+        exported[entity]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|r| r["id"] == json!(id))
+    };
+    assert!(has(
+        "project_members",
+        "88888888-8888-4888-8888-888888888888"
+    ));
+    assert!(has("llm_context", "99999999-9999-4999-8999-999999999999"));
+    assert!(has("activity_log", "aaaaaaaa-aaaa-4aaa-8aaa-aaaaaaaaaaaa"));
+}
+
+#[tokio::test]
+async fn test_import_of_newer_schema_version_is_rejected() {
+    let state = create_test_app_state().await;
+    let mut data: Value = serde_json::from_str(FIXTURES[1]).unwrap();
+    data["schema_version"] = json!(99);
+
+    let (status, body) = send(&state, "POST", "/api/v1/sync/import", Some(data)).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{}", body);
+    assert!(
+        body["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("Unsupported schema version: 99"),
+        "{}",
+        body
+    );
+}
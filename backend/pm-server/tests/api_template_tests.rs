@@ -0,0 +1,248 @@
+mod common;
+
+use crate::common::{create_test_app_state, create_test_project, create_test_user};
+
+use pm_server::routes::build_router;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+const USER_ID: &str = "00000000-0000-0000-0000-000000000001";
+
+async fn send(
+    state: &pm_ws::AppState,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let app = build_router(state.clone());
+    let builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Content-Type", "application/json");
+    let request = match body {
+        Some(b) => builder.body(Body::from(b.to_string())).unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    };
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+async fn post(state: &pm_ws::AppState, uri: &str, body: Value) -> Value {
+    let (status, json) = send(state, "POST", uri, Some(body)).await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    json
+}
+
+/// Project TEST with a custom field, an epic with a story (which has a
+/// comment) and a project LLM context entry
+async fn setup() -> (pm_ws::AppState, Value, Value) {
+    let state = create_test_app_state().await;
+    create_test_user(&state.pool, USER_ID).await;
+    let project_id = create_test_project(&state.pool, USER_ID).await.to_string();
+
+    post(
+        &state,
+        "/api/v1/projects/TEST/custom-fields",
+        json!({
+            "name": "severity",
+            "field_type": "single_select",
+            "options": ["low", "high"],
+            "default_value": "low",
+        }),
+    )
+    .await;
+    let epic = post(
+        &state,
+        "/api/v1/work-items",
+        json!({ "project_id": project_id, "item_type": "epic", "title": "Onboarding" }),
+    )
+    .await;
+    let story = post(
+        &state,
+        "/api/v1/work-items",
+        json!({
+            "project_id": project_id,
+            "item_type": "story",
+            "title": "Sign-up flow",
+            "parent_id": epic["work_item"]["id"],
+            "custom_fields": { "severity": "high" },
+        }),
+    )
+    .await;
+    post(
+        &state,
+        &format!(
+            "/api/v1/work-items/{}/comments",
+            story["work_item"]["id"].as_str().unwrap()
+        ),
+        json!({ "content": "History that templates leave behind" }),
+    )
+    .await;
+    post(
+        &state,
+        "/api/v1/llm-context",
+        json!({
+            "project_id": "TEST",
+            "context_type": "business_rule",
+            "category": "conventions",
+            "title": "Branch naming",
+            "content": "Branches are named after the work item key.",
+        }),
+    )
+    .await;
+
+    (state, epic["work_item"].clone(), story["work_item"].clone())
+}
+
+#[tokio::test]
+async fn test_save_template_from_project_and_create_project_from_it() {
+    let (state, epic, story) = setup().await;
+
+    // Save TEST as a template
+    let saved = post(
+        &state,
+        "/api/v1/templates",
+        json!({ "name": "onboarding", "project_id": "TEST" }),
+    )
+    .await;
+    let structure = &saved["template"]["structure"];
+    assert_eq!(structure["work_items"].as_array().unwrap().len(), 2);
+    assert_eq!(structure["work_items"][1]["parent"], json!(1));
+    assert_eq!(structure["custom_fields"][0]["name"], json!("severity"));
+    assert_eq!(structure["llm_context"][0]["title"], json!("Branch naming"));
+
+    let (status, list) = send(&state, "GET", "/api/v1/templates", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(list["templates"][0]["name"], json!("onboarding"));
+
+    // Create a project from it by name
+    let created = post(
+        &state,
+        "/api/v1/projects",
+        json!({ "title": "New Project", "key": "NEW", "template_id": "onboarding" }),
+    )
+    .await;
+    let project_id = created["project"]["id"].as_str().unwrap().to_string();
+
+    let (_, items) = send(&state, "GET", "/api/v1/projects/NEW/work-items", None).await;
+    let items = items["work_items"].as_array().unwrap();
+    assert_eq!(items.len(), 2);
+    let new_epic = items.iter().find(|w| w["item_type"] == "epic").unwrap();
+    let new_story = items.iter().find(|w| w["item_type"] == "story").unwrap();
+    assert_eq!(new_epic["display_key"], json!("NEW-1"));
+    assert_eq!(new_story["display_key"], json!("NEW-2"));
+    assert_ne!(new_epic["id"], epic["id"]);
+    assert_ne!(new_story["id"], story["id"]);
+    assert_eq!(new_story["parent_id"], new_epic["id"]);
+    assert_eq!(new_story["custom_fields"]["severity"], json!("high"));
+
+    // Numbering continues after the template's items
+    let next = post(
+        &state,
+        "/api/v1/work-items",
+        json!({ "project_id": project_id, "item_type": "task", "title": "Next" }),
+    )
+    .await;
+    assert_eq!(next["work_item"]["display_key"], json!("NEW-3"));
+
+    // History is not copied
+    let (_, comments) = send(
+        &state,
+        "GET",
+        &format!(
+            "/api/v1/work-items/{}/comments",
+            new_story["id"].as_str().unwrap()
+        ),
+        None,
+    )
+    .await;
+    assert_eq!(comments["comments"], json!([]));
+
+    let (_, fields) = send(&state, "GET", "/api/v1/projects/NEW/custom-fields", None).await;
+    assert_eq!(fields["custom_fields"][0]["name"], json!("severity"));
+
+    let (_, context) = send(
+        &state,
+        "GET",
+        &format!("/api/v1/llm-context?project_id={}", project_id),
+        None,
+    )
+    .await;
+    assert!(
+        context["entries"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|e| e["title"] == "Branch naming" && e["project_id"] == json!(project_id))
+    );
+}
+
+#[tokio::test]
+async fn test_create_template_rejects_invalid_hierarchy() {
+    let state = create_test_app_state().await;
+    create_test_user(&state.pool, USER_ID).await;
+
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/templates",
+        Some(json!({
+            "name": "upside-down",
+            "structure": {
+                "work_items": [
+                    { "number": 1, "item_type": "task", "title": "Parent task" },
+                    { "number": 2, "parent": 1, "item_type": "epic", "title": "Child epic" },
+                ],
+            },
+        })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{}", json);
+    assert!(
+        json["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("Invalid hierarchy")
+    );
+}
+
+#[tokio::test]
+async fn test_create_template_rejects_duplicate_name() {
+    let state = create_test_app_state().await;
+    create_test_user(&state.pool, USER_ID).await;
+    let body = json!({ "name": "empty", "structure": {} });
+
+    post(&state, "/api/v1/templates", body.clone()).await;
+    let (status, json) = send(&state, "POST", "/api/v1/templates", Some(body)).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{}", json);
+    assert_eq!(json["error"]["field"], json!("name"));
+}
+
+#[tokio::test]
+async fn test_create_project_with_unknown_template_returns_not_found() {
+    let state = create_test_app_state().await;
+    create_test_user(&state.pool, USER_ID).await;
+
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/projects",
+        Some(json!({ "title": "New Project", "key": "NEW", "template_id": "missing" })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND, "{}", json);
+    let (_, projects) = send(&state, "GET", "/api/v1/projects", None).await;
+    assert_eq!(projects["projects"], json!([]));
+}
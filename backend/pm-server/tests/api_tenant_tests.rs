@@ -0,0 +1,180 @@
+mod common;
+
+use crate::common::create_test_app_state;
+
+use pm_auth::{Claims, JwtValidator};
+use pm_config::TenantSource;
+use pm_db::{TenantPoolOptions, TenantPools};
+use pm_server::routes::build_router;
+use pm_ws::TenantResolver;
+
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
+use serde_json::{Value, json};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+const JWT_SECRET: &[u8] = b"test-secret-key-at-least-32-bytes";
+
+async fn send(
+    state: &pm_ws::AppState,
+    method: &str,
+    host: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let app = build_router(state.clone());
+    let builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Host", host)
+        .header("Content-Type", "application/json");
+    let request = match body {
+        Some(b) => builder.body(Body::from(b.to_string())).unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    };
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+/// A signed token issued for `tenant_id`
+fn token_for(tenant_id: &str) -> String {
+    let now = chrono::Utc::now().timestamp();
+    let claims = Claims {
+        sub: "00000000-0000-0000-0000-000000000001".to_string(),
+        exp: now + 3600,
+        iat: now,
+        roles: vec![],
+        tenant_id: Some(tenant_id.to_string()),
+    };
+    encode(
+        &Header::new(Algorithm::HS256),
+        &claims,
+        &EncodingKey::from_secret(JWT_SECRET),
+    )
+    .unwrap()
+}
+
+/// Status of listing projects on `host`, with an optional bearer token
+async fn list_projects(state: &pm_ws::AppState, host: &str, token: Option<&str>) -> StatusCode {
+    let app = build_router(state.clone());
+    let mut builder = Request::builder()
+        .method("GET")
+        .uri("/api/v1/projects")
+        .header("Host", host);
+    if let Some(token) = token {
+        builder = builder.header("Authorization", format!("Bearer {}", token));
+    }
+    let request = builder.body(Body::empty()).unwrap();
+
+    app.oneshot(request).await.unwrap().status()
+}
+
+/// App state routing on the Host header to tenants under a temp directory
+async fn setup(create_missing: bool) -> (TempDir, pm_ws::AppState) {
+    let temp = TempDir::new().unwrap();
+    let mut state = create_test_app_state().await;
+    let pools = TenantPools::new(TenantPoolOptions {
+        root: temp.path().to_path_buf(),
+        create_missing,
+        max_open_pools: 10,
+        idle_timeout: None,
+        max_connections: 2,
+        system_user: Some((
+            state.api_config.llm_user_id.clone(),
+            state.api_config.llm_user_name.clone(),
+        )),
+    });
+    state.tenants = Some(TenantResolver::new(TenantSource::Host, pools));
+    (temp, state)
+}
+
+#[tokio::test]
+async fn test_tenants_only_see_their_own_projects() {
+    let (_temp, state) = setup(true).await;
+
+    let (status, body) = send(
+        &state,
+        "POST",
+        "acme.board.test",
+        "/api/v1/projects",
+        Some(json!({ "title": "Acme", "key": "ACME" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+
+    let (status, acme) = send(&state, "GET", "acme.board.test", "/api/v1/projects", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(acme["projects"].as_array().unwrap().len(), 1);
+
+    let (status, globex) = send(&state, "GET", "globex.board.test", "/api/v1/projects", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(globex["projects"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_unknown_tenant_is_not_found() {
+    let (_temp, state) = setup(false).await;
+
+    let (status, _) = send(&state, "GET", "nobody.board.test", "/api/v1/projects", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_request_without_tenant_is_rejected() {
+    let (_temp, state) = setup(true).await;
+
+    let (status, _) = send(&state, "GET", "localhost", "/api/v1/projects", None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_routes_that_need_no_tenant_work_without_one() {
+    let (temp, state) = setup(true).await;
+
+    for uri in ["/health", "/live", "/api/v1/openapi.json"] {
+        let (status, body) = send(&state, "GET", "localhost", uri, None).await;
+        assert_eq!(status, StatusCode::OK, "{}: {}", uri, body);
+    }
+
+    // Nor do they open the database of a tenant the request does name
+    let (status, _) = send(&state, "GET", "acme.board.test", "/health", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(!temp.path().join("acme").exists());
+}
+
+#[tokio::test]
+async fn test_tenant_database_is_created_under_the_tenant_root() {
+    let (temp, state) = setup(true).await;
+
+    let (status, _) = send(&state, "GET", "acme.board.test", "/api/v1/projects", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(temp.path().join("acme").join("main.db").exists());
+}
+
+#[tokio::test]
+async fn test_token_for_another_tenant_is_forbidden() {
+    let (_temp, mut state) = setup(true).await;
+    state.jwt_validator = Some(Arc::new(JwtValidator::with_hs256(JWT_SECRET)));
+    let acme = token_for("acme");
+
+    let own = list_projects(&state, "acme.board.test", Some(&acme)).await;
+    let other = list_projects(&state, "globex.board.test", Some(&acme)).await;
+    let missing = list_projects(&state, "acme.board.test", None).await;
+
+    assert_eq!(own, StatusCode::OK);
+    assert_eq!(other, StatusCode::FORBIDDEN);
+    assert_eq!(missing, StatusCode::BAD_REQUEST);
+}
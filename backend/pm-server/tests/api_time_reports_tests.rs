@@ -0,0 +1,253 @@
+mod common;
+
+use crate::common::{
+    create_test_app_state, create_test_project, create_test_user, create_test_work_item,
+};
+
+use pm_server::routes::build_router;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use chrono::{Days, TimeZone, Utc};
+use http_body_util::BodyExt;
+use serde_json::Value;
+use sqlx::SqlitePool;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+const ALICE: &str = "00000000-0000-0000-0000-000000000001";
+const BOB: &str = "00000000-0000-0000-0000-000000000002";
+
+/// Insert a finished time entry on 2026-10-05 (UTC), given as
+/// (hour, minute) start and end times.
+async fn insert_entry(
+    pool: &SqlitePool,
+    work_item_id: Uuid,
+    user_id: &str,
+    start: (u32, u32),
+    end: (u32, u32),
+    deleted: bool,
+) {
+    let at = |(h, m): (u32, u32)| {
+        Utc.with_ymd_and_hms(2026, 10, 5, h, m, 0)
+            .unwrap()
+            .timestamp()
+    };
+    let ended_at = at(end);
+    sqlx::query(
+        r#"
+          INSERT INTO pm_time_entries (
+              id, work_item_id, user_id, started_at, ended_at, duration_seconds,
+              description, created_at, updated_at, deleted_at
+          )
+          VALUES (?, ?, ?, ?, ?, ?, NULL, ?, ?, ?)
+          "#,
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(work_item_id.to_string())
+    .bind(user_id)
+    .bind(at(start))
+    .bind(ended_at)
+    .bind(ended_at - at(start))
+    .bind(at(start))
+    .bind(at(start))
+    .bind(deleted.then(|| at(start)))
+    .execute(pool)
+    .await
+    .expect("Failed to create time entry");
+}
+
+async fn get(state: &pm_ws::AppState, uri: &str) -> (StatusCode, String) {
+    let app = build_router(state.clone());
+    let request = Request::builder()
+        .method("GET")
+        .uri(uri)
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, String::from_utf8(bytes.to_vec()).unwrap())
+}
+
+/// Two tasks, one under the other: 1h + 2h (Alice) on the parent, 30m (Bob)
+/// on the child, plus a deleted entry that must be ignored.
+async fn setup() -> (pm_ws::AppState, Uuid, Uuid) {
+    let state = create_test_app_state().await;
+    create_test_user(&state.pool, ALICE).await;
+    create_test_user(&state.pool, BOB).await;
+    let project_id = create_test_project(&state.pool, ALICE).await;
+    let parent = create_test_work_item(&state.pool, project_id, 1, ALICE).await;
+    let child = create_test_work_item(&state.pool, project_id, 2, ALICE).await;
+    sqlx::query("UPDATE pm_work_items SET parent_id = ? WHERE id = ?")
+        .bind(parent.to_string())
+        .bind(child.to_string())
+        .execute(&state.pool)
+        .await
+        .unwrap();
+
+    insert_entry(&state.pool, parent, ALICE, (9, 0), (10, 0), false).await;
+    insert_entry(&state.pool, parent, ALICE, (13, 0), (15, 0), false).await;
+    insert_entry(&state.pool, child, BOB, (11, 0), (11, 30), false).await;
+    insert_entry(&state.pool, child, BOB, (16, 0), (20, 0), true).await;
+
+    (state, parent, child)
+}
+
+#[tokio::test]
+async fn test_time_report_by_user() {
+    let (state, _, _) = setup().await;
+
+    let (status, body) = get(
+        &state,
+        "/api/v1/time-reports?from=2026-10-05&to=2026-10-05&group_by=user",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let json: Value = serde_json::from_str(&body).unwrap();
+    let report = &json["report"];
+    assert_eq!(report["total_seconds"], 3 * 3600 + 1800);
+    let rows = report["rows"].as_array().unwrap();
+    assert_eq!(rows.len(), 2);
+    let alice = rows.iter().find(|r| r["key"] == ALICE).unwrap();
+    assert_eq!(alice["seconds"], 3 * 3600);
+    let bob = rows.iter().find(|r| r["key"] == BOB).unwrap();
+    assert_eq!(bob["seconds"], 1800);
+}
+
+#[tokio::test]
+async fn test_time_report_by_item_rolls_up_to_parent() {
+    let (state, parent, child) = setup().await;
+
+    let (status, body) = get(
+        &state,
+        "/api/v1/time-reports?from=2026-10-05&to=2026-10-05&group_by=item&project_id=TEST",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let json: Value = serde_json::from_str(&body).unwrap();
+    let rows = json["report"]["rows"].as_array().unwrap();
+    let parent_row = rows
+        .iter()
+        .find(|r| r["key"] == parent.to_string())
+        .unwrap();
+    assert_eq!(parent_row["seconds"], 3 * 3600);
+    assert_eq!(parent_row["rollup_seconds"], 3 * 3600 + 1800);
+    assert_eq!(parent_row["label"], "TEST-1 Test Work Item 1");
+    let child_row = rows.iter().find(|r| r["key"] == child.to_string()).unwrap();
+    assert_eq!(child_row["rollup_seconds"], 1800);
+}
+
+#[tokio::test]
+async fn test_time_report_by_sprint_groups_unscheduled_time() {
+    let (state, _, _) = setup().await;
+
+    let (status, body) = get(
+        &state,
+        "/api/v1/time-reports?from=2026-10-05&to=2026-10-05&group_by=sprint",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let json: Value = serde_json::from_str(&body).unwrap();
+    let rows = json["report"]["rows"].as_array().unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0]["label"], "(no sprint)");
+    assert_eq!(rows[0]["seconds"], 3 * 3600 + 1800);
+}
+
+#[tokio::test]
+async fn test_time_report_running_timer_counts_until_now() {
+    let state = create_test_app_state().await;
+    create_test_user(&state.pool, ALICE).await;
+    let project_id = create_test_project(&state.pool, ALICE).await;
+    let item = create_test_work_item(&state.pool, project_id, 1, ALICE).await;
+
+    let started = Utc::now() - chrono::Duration::hours(1);
+    sqlx::query(
+        r#"
+          INSERT INTO pm_time_entries (
+              id, work_item_id, user_id, started_at, created_at, updated_at
+          )
+          VALUES (?, ?, ?, ?, ?, ?)
+          "#,
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(item.to_string())
+    .bind(ALICE)
+    .bind(started.timestamp())
+    .bind(started.timestamp())
+    .bind(started.timestamp())
+    .execute(&state.pool)
+    .await
+    .unwrap();
+
+    let today = Utc::now().date_naive();
+    let (status, body) = get(
+        &state,
+        &format!(
+            "/api/v1/time-reports?from={}&to={}",
+            today - Days::new(1),
+            today
+        ),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let json: Value = serde_json::from_str(&body).unwrap();
+    let seconds = json["report"]["total_seconds"].as_i64().unwrap();
+    assert!((3600..3700).contains(&seconds), "got {}", seconds);
+}
+
+#[tokio::test]
+async fn test_time_report_by_day_as_csv() {
+    let (state, _, _) = setup().await;
+
+    let (status, body) = get(
+        &state,
+        "/api/v1/time-reports?from=2026-10-04&to=2026-10-06&group_by=user&period=day&user_id=00000000-0000-0000-0000-000000000001&format=csv",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    let lines: Vec<&str> = body.lines().collect();
+    assert_eq!(
+        lines[0],
+        "key,label,period_start,seconds,hours,rollup_seconds,rollup_hours"
+    );
+    assert_eq!(
+        lines[1],
+        format!("{},{},2026-10-05,10800,3.00,,", ALICE, ALICE)
+    );
+    assert_eq!(lines.len(), 2);
+}
+
+#[tokio::test]
+async fn test_time_report_rejects_inverted_range() {
+    let state = create_test_app_state().await;
+
+    let (status, body) = get(&state, "/api/v1/time-reports?from=2026-10-06&to=2026-10-05").await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let json: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(json["error"]["field"], "from");
+}
+
+#[tokio::test]
+async fn test_time_report_rejects_unknown_group_by() {
+    let state = create_test_app_state().await;
+
+    let (status, body) = get(
+        &state,
+        "/api/v1/time-reports?from=2026-10-05&to=2026-10-05&group_by=team",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let json: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(json["error"]["field"], "group_by");
+}
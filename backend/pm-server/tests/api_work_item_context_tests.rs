@@ -0,0 +1,207 @@
+mod common;
+
+use crate::common::{create_test_app_state, create_test_project, create_test_user};
+
+use pm_server::routes::build_router;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+const USER_ID: &str = "00000000-0000-0000-0000-000000000001";
+
+async fn send(
+    state: &pm_ws::AppState,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let app = build_router(state.clone());
+    let builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .header("X-User-Id", USER_ID);
+    let request = match body {
+        Some(b) => builder.body(Body::from(b.to_string())).unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    };
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+async fn get_text(state: &pm_ws::AppState, uri: &str) -> (StatusCode, String, String) {
+    let app = build_router(state.clone());
+    let request = Request::builder()
+        .method("GET")
+        .uri(uri)
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get("content-type")
+        .map(|v| v.to_str().unwrap().to_string())
+        .unwrap_or_default();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (
+        status,
+        content_type,
+        String::from_utf8(bytes.to_vec()).unwrap(),
+    )
+}
+
+async fn create_item(
+    state: &pm_ws::AppState,
+    project_id: Uuid,
+    item_type: &str,
+    parent_id: Option<&str>,
+    title: &str,
+) -> String {
+    let (status, json) = send(
+        state,
+        "POST",
+        "/api/v1/work-items",
+        Some(json!({
+            "project_id": project_id.to_string(),
+            "item_type": item_type,
+            "title": title,
+            "parent_id": parent_id,
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    json["work_item"]["id"].as_str().unwrap().to_string()
+}
+
+async fn depend(state: &pm_ws::AppState, blocking: &str, blocked: &str, dependency_type: &str) {
+    let (status, json) = send(
+        state,
+        "POST",
+        "/api/v1/dependencies",
+        Some(json!({
+            "blocking_item_id": blocking,
+            "blocked_item_id": blocked,
+            "dependency_type": dependency_type,
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+}
+
+/// TEST-1 epic > TEST-2 and TEST-3 stories. TEST-4 (top level) blocks
+/// TEST-2, TEST-2 blocks TEST-3, and TEST-2 has two comments.
+async fn setup() -> pm_ws::AppState {
+    let state = create_test_app_state().await;
+    create_test_user(&state.pool, USER_ID).await;
+    let project_id = create_test_project(&state.pool, USER_ID).await;
+
+    let epic = create_item(&state, project_id, "epic", None, "Checkout").await;
+    let story = create_item(&state, project_id, "story", Some(&epic), "Pay now").await;
+    let sibling = create_item(&state, project_id, "story", Some(&epic), "Receipts").await;
+    let other = create_item(&state, project_id, "story", None, "Payments API").await;
+    depend(&state, &other, &story, "blocks").await;
+    depend(&state, &story, &sibling, "blocks").await;
+
+    for content in ["First thoughts", "Latest decision"] {
+        let (status, json) = send(
+            &state,
+            "POST",
+            &format!("/api/v1/work-items/{}/comments", story),
+            Some(json!({ "content": content })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}", json);
+    }
+
+    state
+}
+
+#[tokio::test]
+async fn test_work_item_context_markdown() {
+    // Given
+    let state = setup().await;
+
+    // When
+    let (status, content_type, text) = get_text(&state, "/api/v1/work-items/TEST-2/context").await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "text/markdown; charset=utf-8");
+    assert!(text.starts_with("# TEST-2: Pay now\n"));
+    assert!(text.contains("## Ancestors\n\n- TEST-1 (epic, backlog): Checkout\n"));
+    assert!(text.contains("## Blocked by\n\n- TEST-4 (story, backlog): Payments API\n"));
+    assert!(text.contains("## Blocks\n\n- TEST-3 (story, backlog): Receipts\n"));
+    assert!(text.contains("## Siblings\n\n- TEST-3 (story, backlog): Receipts\n"));
+    assert!(text.contains("## Recent comments"));
+    assert!(text.find("Latest decision").unwrap() < text.find("First thoughts").unwrap());
+    assert!(text.contains("## Activity\n\n1 entry (1 created)"));
+    assert!(text.contains("## Rules\n"));
+}
+
+#[tokio::test]
+async fn test_work_item_context_json_respects_budget() {
+    // Given
+    let state = setup().await;
+
+    // When
+    let (status, json) = send(
+        &state,
+        "GET",
+        "/api/v1/work-items/TEST-2/context?format=json&max_chars=2000",
+        None,
+    )
+    .await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    let context = &json["context"];
+    assert_eq!(context["item"]["key"], "TEST-2");
+    assert_eq!(context["ancestors"][0]["key"], "TEST-1");
+    assert_eq!(context["blocked_by"][0]["key"], "TEST-4");
+    assert!(context["omitted"]["rules"].as_u64().unwrap() > 0);
+    assert!(
+        serde_json::to_string_pretty(context)
+            .unwrap()
+            .chars()
+            .count()
+            <= 2000
+    );
+}
+
+#[tokio::test]
+async fn test_work_item_context_rejects_bad_parameters() {
+    let state = setup().await;
+
+    let (status, json) = send(
+        &state,
+        "GET",
+        "/api/v1/work-items/TEST-2/context?format=html",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"]["field"], "format");
+
+    let (status, json) = send(
+        &state,
+        "GET",
+        "/api/v1/work-items/TEST-2/context?max_chars=10",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"]["field"], "max_chars");
+
+    let (status, _) = send(&state, "GET", "/api/v1/work-items/TEST-99/context", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,365 @@
+mod common;
+
+use crate::common::{
+    create_test_app_state, create_test_project, create_test_user, create_test_work_item,
+};
+
+use pm_server::routes::build_router;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use chrono::Utc;
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+const USER_ID: &str = "00000000-0000-0000-0000-000000000001";
+
+async fn send(
+    state: &pm_ws::AppState,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let app = build_router(state.clone());
+    let builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .header("X-User-Id", USER_ID);
+    let request = match body {
+        Some(b) => builder.body(Body::from(b.to_string())).unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    };
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+async fn setup() -> (pm_ws::AppState, Uuid) {
+    let state = create_test_app_state().await;
+    create_test_user(&state.pool, USER_ID).await;
+    let project_id = create_test_project(&state.pool, USER_ID).await;
+    (state, project_id)
+}
+
+/// Create a work item via REST, optionally under a parent, with an original estimate
+async fn create_item(
+    state: &pm_ws::AppState,
+    project_id: Uuid,
+    item_type: &str,
+    title: &str,
+    parent_id: Option<&str>,
+    estimate: Option<i32>,
+) -> Value {
+    let (status, json) = send(
+        state,
+        "POST",
+        "/api/v1/work-items",
+        Some(json!({
+            "project_id": project_id.to_string(),
+            "item_type": item_type,
+            "title": title,
+            "parent_id": parent_id,
+            "original_estimate_seconds": estimate,
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    json["work_item"].clone()
+}
+
+/// Insert a completed time entry of the given length directly
+async fn log_time(state: &pm_ws::AppState, work_item_id: &str, seconds: i64) {
+    let now = Utc::now().timestamp();
+    sqlx::query(
+        r#"
+          INSERT INTO pm_time_entries (
+              id, work_item_id, user_id, started_at, ended_at, duration_seconds,
+              description, created_at, updated_at
+          )
+          VALUES (?, ?, ?, ?, ?, ?, NULL, ?, ?)
+          "#,
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(work_item_id)
+    .bind(USER_ID)
+    .bind(now - seconds)
+    .bind(now)
+    .bind(seconds)
+    .bind(now)
+    .bind(now)
+    .execute(&state.pool)
+    .await
+    .expect("Failed to create time entry");
+}
+
+#[tokio::test]
+async fn test_create_sets_original_and_remaining_estimate() {
+    // Given
+    let (state, project_id) = setup().await;
+
+    // When
+    let item = create_item(&state, project_id, "task", "Estimated", None, Some(7200)).await;
+
+    // Then
+    assert_eq!(item["original_estimate_seconds"], 7200);
+    assert_eq!(item["remaining_estimate_seconds"], 7200);
+    assert_eq!(item["estimate"]["original_estimate_seconds"], 7200);
+    assert_eq!(item["estimate"]["logged_seconds"], 0);
+    assert_eq!(item["estimate"]["over_estimate"], false);
+}
+
+#[tokio::test]
+async fn test_create_rejects_negative_estimate() {
+    // Given
+    let (state, project_id) = setup().await;
+
+    // When
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items",
+        Some(json!({
+            "project_id": project_id.to_string(),
+            "item_type": "task",
+            "title": "Bad",
+            "original_estimate_seconds": -1,
+        })),
+    )
+    .await;
+
+    // Then
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"]["field"], "original_estimate_seconds");
+}
+
+#[tokio::test]
+async fn test_update_remaining_and_clear_estimate() {
+    // Given
+    let (state, project_id) = setup().await;
+    let item = create_item(&state, project_id, "task", "Task", None, Some(3600)).await;
+    let id = item["id"].as_str().unwrap();
+
+    // When
+    let (status, json) = send(
+        &state,
+        "PUT",
+        &format!("/api/v1/work-items/{}", id),
+        Some(json!({ "remaining_estimate_seconds": 600, "expected_version": 1 })),
+    )
+    .await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    assert_eq!(json["work_item"]["original_estimate_seconds"], 3600);
+    assert_eq!(json["work_item"]["remaining_estimate_seconds"], 600);
+
+    // When
+    let (status, json) = send(
+        &state,
+        "PUT",
+        &format!("/api/v1/work-items/{}", id),
+        Some(json!({ "clear_estimate": true, "expected_version": 2 })),
+    )
+    .await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    assert!(json["work_item"]["original_estimate_seconds"].is_null());
+    assert!(json["work_item"]["remaining_estimate_seconds"].is_null());
+}
+
+#[tokio::test]
+async fn test_estimate_rolls_up_to_parent_with_over_estimate_flag() {
+    // Given: a parent story with two estimated children, one of which has
+    // logged more than its estimate allows
+    let (state, project_id) = setup().await;
+    let parent = create_item(&state, project_id, "story", "Parent", None, None).await;
+    let parent_id = parent["id"].as_str().unwrap();
+    let a = create_item(&state, project_id, "task", "A", Some(parent_id), Some(3600)).await;
+    let b = create_item(&state, project_id, "task", "B", Some(parent_id), Some(1800)).await;
+    log_time(&state, a["id"].as_str().unwrap(), 3000).await;
+    log_time(&state, b["id"].as_str().unwrap(), 600).await;
+
+    // When
+    let (status, json) = send(
+        &state,
+        "GET",
+        &format!("/api/v1/work-items/{}", parent_id),
+        None,
+    )
+    .await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK);
+    let estimate = &json["work_item"]["estimate"];
+    assert_eq!(estimate["original_estimate_seconds"], 5400);
+    assert_eq!(estimate["remaining_estimate_seconds"], 5400);
+    assert_eq!(estimate["logged_seconds"], 3600);
+    assert_eq!(estimate["over_estimate"], true);
+
+    // When
+    let (status, json) = send(
+        &state,
+        "GET",
+        &format!("/api/v1/projects/{}/work-items", project_id),
+        None,
+    )
+    .await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK);
+    let items = json["work_items"].as_array().unwrap();
+    let listed_a = items.iter().find(|i| i["id"] == a["id"]).unwrap();
+    assert_eq!(listed_a["estimate"]["logged_seconds"], 3000);
+    assert_eq!(listed_a["estimate"]["over_estimate"], true);
+}
+
+#[tokio::test]
+async fn test_stopping_timer_decrements_remaining_estimate() {
+    // Given: a running timer started an hour ago on a 2h task
+    let (state, project_id) = setup().await;
+    let item = create_item(&state, project_id, "task", "Timed", None, Some(7200)).await;
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/time-entries",
+        Some(json!({ "work_item_id": item["id"] })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    let entry_id = json["time_entry"]["id"].as_str().unwrap().to_string();
+    sqlx::query("UPDATE pm_time_entries SET started_at = started_at - 3600 WHERE id = ?")
+        .bind(&entry_id)
+        .execute(&state.pool)
+        .await
+        .unwrap();
+
+    // When
+    let (status, json) = send(
+        &state,
+        "PUT",
+        &format!("/api/v1/time-entries/{}", entry_id),
+        Some(json!({ "stop": true })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+
+    // Then
+    let (_, json) = send(
+        &state,
+        "GET",
+        &format!("/api/v1/work-items/{}", item["id"].as_str().unwrap()),
+        None,
+    )
+    .await;
+    let remaining = json["work_item"]["remaining_estimate_seconds"]
+        .as_i64()
+        .unwrap();
+    assert!(
+        (3595..=3600).contains(&remaining),
+        "remaining {}",
+        remaining
+    );
+    assert_eq!(json["work_item"]["version"], 2);
+}
+
+#[tokio::test]
+async fn test_stopping_timer_with_override_sets_remaining_estimate() {
+    // Given
+    let (state, project_id) = setup().await;
+    let item = create_item(&state, project_id, "task", "Timed", None, Some(7200)).await;
+    let (_, json) = send(
+        &state,
+        "POST",
+        "/api/v1/time-entries",
+        Some(json!({ "work_item_id": item["id"] })),
+    )
+    .await;
+    let entry_id = json["time_entry"]["id"].as_str().unwrap().to_string();
+
+    // When
+    let (status, json) = send(
+        &state,
+        "PUT",
+        &format!("/api/v1/time-entries/{}", entry_id),
+        Some(json!({ "stop": true, "remaining_estimate_seconds": 900 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+
+    // Then
+    let (_, json) = send(
+        &state,
+        "GET",
+        &format!("/api/v1/work-items/{}", item["id"].as_str().unwrap()),
+        None,
+    )
+    .await;
+    assert_eq!(json["work_item"]["remaining_estimate_seconds"], 900);
+}
+
+#[tokio::test]
+async fn test_sprint_summary_totals_estimates() {
+    // Given: a sprint holding one over-estimate item, one on-track item and
+    // one unestimated item
+    let (state, project_id) = setup().await;
+    let now = Utc::now().timestamp();
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/sprints",
+        Some(json!({
+            "project_id": project_id.to_string(),
+            "name": "Sprint 1",
+            "start_date": now,
+            "end_date": now + 14 * 86400,
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    let sprint_id = json["sprint"]["id"].as_str().unwrap().to_string();
+
+    let over = create_item(&state, project_id, "task", "Over", None, Some(3600)).await;
+    let on_track = create_item(&state, project_id, "task", "On track", None, Some(7200)).await;
+    let plain_id = create_test_work_item(&state.pool, project_id, 99, USER_ID).await;
+    for id in [
+        over["id"].as_str().unwrap().to_string(),
+        on_track["id"].as_str().unwrap().to_string(),
+        plain_id.to_string(),
+    ] {
+        let (status, json) = send(
+            &state,
+            "PUT",
+            &format!("/api/v1/work-items/{}", id),
+            Some(json!({ "sprint_id": sprint_id, "expected_version": 1 })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}", json);
+    }
+    log_time(&state, over["id"].as_str().unwrap(), 4000).await;
+
+    // When
+    let (status, json) = send(
+        &state,
+        "GET",
+        &format!("/api/v1/sprints/{}/summary", sprint_id),
+        None,
+    )
+    .await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    let summary = &json["summary"];
+    assert_eq!(summary["item_count"], 3);
+    assert_eq!(summary["estimated_item_count"], 2);
+    assert_eq!(summary["over_estimate_item_count"], 1);
+    assert_eq!(summary["original_estimate_seconds"], 10800);
+    assert_eq!(summary["logged_seconds"], 4000);
+}
@@ -0,0 +1,261 @@
+mod common;
+
+use crate::common::{create_test_app_state, create_test_project, create_test_user};
+
+use pm_server::routes::build_router;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+const USER_ID: &str = "00000000-0000-0000-0000-000000000001";
+
+async fn send(
+    state: &pm_ws::AppState,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let app = build_router(state.clone());
+    let builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Content-Type", "application/json");
+    let request = match body {
+        Some(b) => builder.body(Body::from(b.to_string())).unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    };
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+/// TEST with three tasks, TEST-1 to TEST-3, created in order
+async fn setup() -> pm_ws::AppState {
+    let state = create_test_app_state().await;
+    create_test_user(&state.pool, USER_ID).await;
+    let project_id = create_test_project(&state.pool, USER_ID).await.to_string();
+    for title in ["First", "Second", "Third"] {
+        let (status, json) = send(
+            &state,
+            "POST",
+            "/api/v1/work-items",
+            Some(json!({ "project_id": project_id, "item_type": "task", "title": title })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}", json);
+    }
+    state
+}
+
+/// Display keys and versions of TEST's items in rank order
+async fn backlog(state: &pm_ws::AppState) -> Vec<(String, i64)> {
+    let (status, json) = send(
+        state,
+        "GET",
+        "/api/v1/projects/TEST/work-items?sort=rank",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    json["work_items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|w| {
+            (
+                w["display_key"].as_str().unwrap().to_string(),
+                w["version"].as_i64().unwrap(),
+            )
+        })
+        .collect()
+}
+
+fn keys(backlog: &[(String, i64)]) -> Vec<&str> {
+    backlog.iter().map(|(key, _)| key.as_str()).collect()
+}
+
+#[tokio::test]
+async fn test_new_work_items_are_ranked_in_creation_order() {
+    let state = setup().await;
+
+    assert_eq!(keys(&backlog(&state).await), ["TEST-1", "TEST-2", "TEST-3"]);
+}
+
+#[tokio::test]
+async fn test_move_to_top_changes_only_the_moved_item() {
+    let state = setup().await;
+
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items/TEST-3/move",
+        Some(json!({ "after_id": "TEST-1", "expected_version": 1 })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    assert_eq!(json["work_item"]["version"], json!(2));
+    let backlog = backlog(&state).await;
+    assert_eq!(keys(&backlog), ["TEST-3", "TEST-1", "TEST-2"]);
+    assert_eq!(backlog[1].1, 1);
+    assert_eq!(backlog[2].1, 1);
+}
+
+#[tokio::test]
+async fn test_move_between_two_items() {
+    let state = setup().await;
+
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items/TEST-1/move",
+        Some(json!({ "before_id": "TEST-2", "after_id": "TEST-3", "expected_version": 1 })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    assert_eq!(keys(&backlog(&state).await), ["TEST-2", "TEST-1", "TEST-3"]);
+}
+
+#[tokio::test]
+async fn test_move_below_last_item_and_back() {
+    let state = setup().await;
+
+    let (status, _) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items/TEST-1/move",
+        Some(json!({ "before_id": "TEST-3", "expected_version": 1 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(keys(&backlog(&state).await), ["TEST-2", "TEST-3", "TEST-1"]);
+
+    let (status, _) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items/TEST-1/move",
+        Some(json!({ "after_id": "TEST-2", "expected_version": 2 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(keys(&backlog(&state).await), ["TEST-1", "TEST-2", "TEST-3"]);
+}
+
+#[tokio::test]
+async fn test_move_rejects_bad_neighbours() {
+    let state = setup().await;
+    let (status, other) = send(
+        &state,
+        "POST",
+        "/api/v1/projects",
+        Some(json!({ "title": "Other Project", "key": "OTHER" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items",
+        Some(json!({
+            "project_id": other["project"]["id"],
+            "item_type": "task",
+            "title": "Elsewhere",
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    for body in [
+        json!({ "expected_version": 1 }),
+        json!({ "after_id": "TEST-1", "before_id": "TEST-1", "expected_version": 1 }),
+        json!({ "after_id": "TEST-2", "expected_version": 1 }),
+        json!({ "after_id": "OTHER-1", "expected_version": 1 }),
+        json!({ "before_id": "TEST-3", "after_id": "TEST-1", "expected_version": 1 }),
+    ] {
+        let (status, json) = send(
+            &state,
+            "POST",
+            "/api/v1/work-items/TEST-2/move",
+            Some(body.clone()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{}: {}", body, json);
+    }
+    assert_eq!(keys(&backlog(&state).await), ["TEST-1", "TEST-2", "TEST-3"]);
+}
+
+#[tokio::test]
+async fn test_move_with_stale_version_conflicts() {
+    let state = setup().await;
+
+    let (status, _) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items/TEST-3/move",
+        Some(json!({ "after_id": "TEST-1", "expected_version": 7 })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_move_between_tied_items_rebalances_and_bumps_versions() {
+    let state = setup().await;
+    // Tied on a rank the respread does not hand out, so every row is rewritten
+    sqlx::query("UPDATE pm_work_items SET rank = 'm'")
+        .execute(&state.pool)
+        .await
+        .unwrap();
+
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items/TEST-3/move",
+        Some(json!({ "before_id": "TEST-1", "after_id": "TEST-2", "expected_version": 1 })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    assert_eq!(json["work_item"]["version"], json!(3));
+    let backlog = backlog(&state).await;
+    assert_eq!(keys(&backlog), ["TEST-1", "TEST-3", "TEST-2"]);
+    assert_eq!(backlog[0].1, 2);
+    assert_eq!(backlog[2].1, 2);
+}
+
+#[tokio::test]
+async fn test_create_after_malformed_rank_rebalances_the_backlog() {
+    let state = setup().await;
+    sqlx::query("UPDATE pm_work_items SET rank = 'z0' WHERE item_number = 3")
+        .execute(&state.pool)
+        .await
+        .unwrap();
+    let (_, project) = send(&state, "GET", "/api/v1/projects/TEST", None).await;
+
+    let (status, json) = send(
+        &state,
+        "POST",
+        "/api/v1/work-items",
+        Some(json!({
+            "project_id": project["project"]["id"],
+            "item_type": "task",
+            "title": "Fourth",
+        })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK, "{}", json);
+    let backlog = backlog(&state).await;
+    assert_eq!(keys(&backlog), ["TEST-1", "TEST-2", "TEST-3", "TEST-4"]);
+    assert!(backlog[..3].iter().all(|(_, version)| *version == 2));
+    assert_eq!(backlog[3].1, 1);
+}
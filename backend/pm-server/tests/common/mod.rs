@@ -32,7 +32,7 @@ pub async fn create_test_pool() -> SqlitePool {
 pub async fn create_test_app_state() -> AppState {
     let pool = create_test_pool().await;
     let circuit_breaker = Arc::new(CircuitBreaker::new(CircuitBreakerConfig::default()));
-    let limits = ConnectionLimits { max_total: 10000 };
+    let limits = ConnectionLimits::default();
     let registry = ConnectionRegistry::new(limits);
     let shutdown = ShutdownCoordinator::new();
 
@@ -48,6 +48,8 @@ pub async fn create_test_app_state() -> AppState {
         config: ConnectionConfig::default(),
         api_config: ApiConfig::default(),
         validation: pm_config::ValidationConfig::default(),
+        backups: None,
+        tenants: None,
     }
 }
 